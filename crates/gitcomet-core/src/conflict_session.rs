@@ -12,6 +12,7 @@ mod subchunk;
 use crate::text_utils::{LineEndingDetectionMode, detect_line_ending_from_texts};
 use autosolve::{
     compile_regex_patterns, regex_assisted_auto_resolve_pick_with_compiled, safe_auto_resolve,
+    try_resolve_single_block,
 };
 #[cfg(test)]
 use history::history_section_suffix;
@@ -31,6 +32,9 @@ pub use marker_parse::{
 };
 pub use subchunk::{Subchunk, split_conflict_into_subchunks};
 
+const LFS_POINTER_VERSION_PREFIX: &str = "version https://git-lfs.github.com/spec/";
+const LFS_POINTER_MAX_LEN: usize = 1024;
+
 /// The payload content for one side of a conflict.
 ///
 /// Supports text, raw bytes (for non-UTF8 files), or absent content
@@ -229,6 +233,16 @@ impl ConflictPayload {
         matches!(self, ConflictPayload::Binary(_))
     }
 
+    /// Returns `true` if this payload is a Git LFS pointer file rather than
+    /// the tracked content itself.
+    pub fn is_lfs_pointer(&self) -> bool {
+        self.as_text().is_some_and(|text| {
+            text.len() <= LFS_POINTER_MAX_LEN
+                && text.starts_with(LFS_POINTER_VERSION_PREFIX)
+                && text.lines().any(|line| line.starts_with("oid sha256:"))
+        })
+    }
+
     /// Try to create from raw bytes: if valid UTF-8, produce `Text`; otherwise `Binary`.
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
         match String::from_utf8(bytes) {
@@ -510,6 +524,42 @@ impl ConflictResolverStrategy {
     }
 }
 
+/// Per-file overview of a conflicted path, as shown by the multi-file
+/// conflict dashboard.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConflictFileSummary {
+    /// Path of the conflicted file relative to workdir.
+    pub path: PathBuf,
+    /// The kind of conflict from git status.
+    pub conflict_kind: FileConflictKind,
+    /// Resolver strategy the single-file resolver would use for this path.
+    pub strategy: ConflictResolverStrategy,
+    /// Total number of conflict regions.
+    pub total_regions: usize,
+    /// Number of regions already resolved.
+    pub resolved_regions: usize,
+    /// Number of unresolved regions that the safe autosolve rules can resolve.
+    pub auto_resolvable_regions: usize,
+    /// Any side of the conflict is non-UTF8 content.
+    pub is_binary: bool,
+    /// Any side of the conflict is a Git LFS pointer.
+    pub is_lfs: bool,
+}
+
+impl ConflictFileSummary {
+    /// Number of regions still needing a decision.
+    pub fn unresolved_regions(&self) -> usize {
+        self.total_regions.saturating_sub(self.resolved_regions)
+    }
+
+    /// Returns `true` when autosolve would leave no unresolved regions behind.
+    pub fn is_fully_auto_resolvable(&self) -> bool {
+        self.strategy == ConflictResolverStrategy::FullTextResolver
+            && self.unresolved_regions() > 0
+            && self.auto_resolvable_regions == self.unresolved_regions()
+    }
+}
+
 /// The main conflict session model. Holds all state for resolving conflicts
 /// in a single file during a merge/rebase/cherry-pick.
 ///
@@ -749,6 +799,38 @@ impl ConflictSession {
             && self.regions.iter().all(|r| r.resolution.is_resolved())
     }
 
+    /// Number of unresolved regions that the safe autosolve rules (including
+    /// subchunk splitting) would resolve, without modifying the session.
+    ///
+    /// Only marker-based text conflicts are considered; keep/delete and binary
+    /// conflicts always need an explicit decision.
+    pub fn auto_resolvable_count(&self) -> usize {
+        if self.strategy != ConflictResolverStrategy::FullTextResolver {
+            return 0;
+        }
+        self.regions
+            .iter()
+            .filter(|r| !r.resolution.is_resolved())
+            .filter(|r| try_resolve_single_block(r.base.as_deref(), &r.ours, &r.theirs).is_some())
+            .count()
+    }
+
+    /// Build the dashboard summary for this session.
+    pub fn summary(&self) -> ConflictFileSummary {
+        ConflictFileSummary {
+            path: self.path.clone(),
+            conflict_kind: self.conflict_kind,
+            strategy: self.strategy,
+            total_regions: self.total_regions(),
+            resolved_regions: self.solved_count(),
+            auto_resolvable_regions: self.auto_resolvable_count(),
+            is_binary: self.base.is_binary() || self.ours.is_binary() || self.theirs.is_binary(),
+            is_lfs: self.base.is_lfs_pointer()
+                || self.ours.is_lfs_pointer()
+                || self.theirs.is_lfs_pointer(),
+        }
+    }
+
     /// Find the index of the next unresolved region after `current`.
     /// Wraps around to the beginning if needed.
     /// Returns `None` if all regions are resolved.
@@ -26,7 +26,11 @@ pub(super) struct CompiledRegexAutosolvePattern {
 /// 4. Subchunk splitting (line-level re-merge) when base is available.
 ///
 /// Returns `Some(resolved_text)` if the block can be auto-resolved.
-fn try_resolve_single_block(base: Option<&str>, ours: &str, theirs: &str) -> Option<String> {
+pub(super) fn try_resolve_single_block(
+    base: Option<&str>,
+    ours: &str,
+    theirs: &str,
+) -> Option<String> {
     // Rule 1: identical sides.
     if ours == theirs {
         return Some(ours.to_string());
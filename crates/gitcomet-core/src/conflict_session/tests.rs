@@ -92,6 +92,17 @@ fn stage_parts_text_preferred_over_bytes() {
     assert!(!p.is_binary());
 }
 
+#[test]
+fn payload_detects_lfs_pointer() {
+    let pointer = ConflictPayload::Text(
+        "version https://git-lfs.github.com/spec/v1\noid sha256:4d7a2146\nsize 12345\n".into(),
+    );
+    assert!(pointer.is_lfs_pointer());
+    assert!(!ConflictPayload::Text("version 1\n".into()).is_lfs_pointer());
+    assert!(!ConflictPayload::Absent.is_lfs_pointer());
+    assert!(!ConflictPayload::Binary(vec![0xff].into()).is_lfs_pointer());
+}
+
 // -- ConflictRegionResolution tests --
 
 #[test]
@@ -1012,6 +1023,80 @@ fn session_auto_resolve_regex_applies_to_unresolved_regions() {
     ));
 }
 
+// -- ConflictFileSummary tests --
+
+#[test]
+fn summary_counts_resolved_and_auto_resolvable_regions() {
+    let mut resolved = make_region(Some("b\n"), "x\n", "y\n");
+    resolved.resolution = ConflictRegionResolution::PickOurs;
+    let session = make_session(vec![
+        resolved,
+        make_region(Some("b\n"), "same\n", "same\n"),
+        make_region(Some("b\n"), "b\n", "theirs\n"),
+        make_region(Some("b\n"), "ours\n", "theirs\n"),
+    ]);
+
+    let summary = session.summary();
+    assert_eq!(summary.path, PathBuf::from("test.txt"));
+    assert_eq!(summary.conflict_kind, FileConflictKind::BothModified);
+    assert_eq!(summary.total_regions, 4);
+    assert_eq!(summary.resolved_regions, 1);
+    assert_eq!(summary.unresolved_regions(), 3);
+    assert_eq!(summary.auto_resolvable_regions, 2);
+    assert!(!summary.is_fully_auto_resolvable());
+    assert!(!summary.is_binary);
+    assert!(!summary.is_lfs);
+    // Counting must not mutate the session.
+    assert_eq!(session.solved_count(), 1);
+}
+
+#[test]
+fn summary_fully_auto_resolvable_when_all_unresolved_regions_match_safe_rules() {
+    let session = make_session(vec![
+        make_region(Some("b\n"), "same\n", "same\n"),
+        make_region(Some("b\n"), "ours\n", "b\n"),
+    ]);
+    assert!(session.summary().is_fully_auto_resolvable());
+}
+
+#[test]
+fn summary_flags_binary_and_keep_delete_conflicts_as_not_auto_resolvable() {
+    let binary = ConflictSession::new(
+        PathBuf::from("image.png"),
+        FileConflictKind::BothModified,
+        ConflictPayload::Binary(vec![0x00].into()),
+        ConflictPayload::Binary(vec![0x01].into()),
+        ConflictPayload::Binary(vec![0x01].into()),
+    );
+    let summary = binary.summary();
+    assert!(summary.is_binary);
+    assert_eq!(summary.total_regions, 1);
+    assert_eq!(summary.auto_resolvable_regions, 0);
+
+    let keep_delete = ConflictSession::new(
+        PathBuf::from("gone.txt"),
+        FileConflictKind::DeletedByThem,
+        ConflictPayload::Text("base\n".into()),
+        ConflictPayload::Text("base\n".into()),
+        ConflictPayload::Absent,
+    );
+    assert_eq!(keep_delete.summary().auto_resolvable_regions, 0);
+    assert!(!keep_delete.summary().is_fully_auto_resolvable());
+}
+
+#[test]
+fn summary_flags_lfs_pointer_sides() {
+    let pointer = "version https://git-lfs.github.com/spec/v1\noid sha256:abc\nsize 3\n";
+    let session = ConflictSession::new(
+        PathBuf::from("asset.bin"),
+        FileConflictKind::BothModified,
+        ConflictPayload::Absent,
+        ConflictPayload::Text(pointer.into()),
+        ConflictPayload::Text("plain\n".into()),
+    );
+    assert!(session.summary().is_lfs);
+}
+
 // -- ConflictSession::new tests --
 
 #[test]
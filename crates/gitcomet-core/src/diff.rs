@@ -1,4 +1,12 @@
-use crate::domain::{Diff, DiffLineKind, SharedLineText};
+use crate::domain::{Diff, DiffLine, DiffLineKind, SharedLineText};
+use crate::services::{DiffIgnoreOptions, DiffWhitespaceIgnore};
+use regex::{Regex, RegexBuilder};
+use rustc_hash::FxHashMap as HashMap;
+use std::borrow::Cow;
+use std::ops::Range;
+
+const USER_REGEX_SIZE_LIMIT: usize = 1_048_576; // 1 MiB
+const USER_REGEX_DFA_SIZE_LIMIT: usize = 1_048_576; // 1 MiB
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AnnotatedDiffLine {
@@ -105,18 +113,465 @@ fn parse_range_start(s: &str) -> Option<u32> {
     start.parse::<u32>().ok()
 }
 
+/// Moved blocks shorter than this many alphanumeric characters are treated as
+/// ordinary edits, matching git's `--color-moved` heuristic so short lines such
+/// as `}` or `end` do not light up as moves.
+const MOVED_BLOCK_MIN_ALNUM_CHARS: usize = 20;
+
+/// Diffs with more added plus removed lines than this skip move detection.
+const MOVED_LINES_MAX_CHANGED_LINES: usize = 20_000;
+
+/// Upper bound on line comparisons while pairing blocks. Repetitive input
+/// (many identical removed lines) would otherwise scan every candidate for
+/// every added line; past this budget move detection is abandoned.
+const MOVED_LINES_MAX_COMPARISONS: usize = 2_000_000;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct MovedLineOptions {
+    /// Compare lines with all whitespace removed, like `--color-moved-ws=ignore-all-space`.
+    pub ignore_whitespace: bool,
+}
+
+/// A run of removed lines that reappears verbatim as a run of added lines.
+/// Both ranges index into `Diff::lines` and have the same length.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MovedBlock {
+    pub removed: Range<usize>,
+    pub added: Range<usize>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MovedLines {
+    pub blocks: Vec<MovedBlock>,
+    block_for_line: HashMap<usize, usize>,
+}
+
+impl MovedLines {
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Index into `blocks` for a diff line that belongs to a moved block.
+    pub fn block_for_line(&self, line_ix: usize) -> Option<usize> {
+        self.block_for_line.get(&line_ix).copied()
+    }
+
+    /// The line at the same offset on the other end of the move.
+    pub fn counterpart(&self, line_ix: usize) -> Option<usize> {
+        let block = self.blocks.get(self.block_for_line(line_ix)?)?;
+        if block.removed.contains(&line_ix) {
+            Some(block.added.start + (line_ix - block.removed.start))
+        } else {
+            Some(block.removed.start + (line_ix - block.added.start))
+        }
+    }
+}
+
+fn moved_line_key(line: &DiffLine, options: MovedLineOptions) -> Option<String> {
+    let text = line.text.as_ref();
+    let text = text.get(1..).unwrap_or_default();
+    let key: String = if options.ignore_whitespace {
+        text.chars().filter(|c| !c.is_whitespace()).collect()
+    } else {
+        text.trim_end_matches(['\r', '\n']).to_string()
+    };
+    (!key.trim().is_empty()).then_some(key)
+}
+
+/// Pair removed and added runs with identical content anywhere in the diff,
+/// including across files, so moved code can be told apart from real edits.
+///
+/// Returns no moves for diffs past [`MOVED_LINES_MAX_CHANGED_LINES`] or
+/// [`MOVED_LINES_MAX_COMPARISONS`], so the cost stays bounded.
+pub fn detect_moved_lines(lines: &[DiffLine], options: MovedLineOptions) -> MovedLines {
+    detect_moved_lines_with_limits(
+        lines,
+        options,
+        MOVED_LINES_MAX_CHANGED_LINES,
+        MOVED_LINES_MAX_COMPARISONS,
+    )
+}
+
+fn detect_moved_lines_with_limits(
+    lines: &[DiffLine],
+    options: MovedLineOptions,
+    max_changed_lines: usize,
+    max_comparisons: usize,
+) -> MovedLines {
+    let changed_lines = lines
+        .iter()
+        .filter(|line| matches!(line.kind, DiffLineKind::Add | DiffLineKind::Remove))
+        .count();
+    if changed_lines > max_changed_lines {
+        return MovedLines::default();
+    }
+
+    let keys: Vec<Option<String>> = lines
+        .iter()
+        .map(|line| match line.kind {
+            DiffLineKind::Add | DiffLineKind::Remove => moved_line_key(line, options),
+            DiffLineKind::Context | DiffLineKind::Header | DiffLineKind::Hunk => None,
+        })
+        .collect();
+
+    let mut removed_by_key: HashMap<&str, Vec<usize>> = HashMap::default();
+    for (ix, line) in lines.iter().enumerate() {
+        if line.kind == DiffLineKind::Remove
+            && let Some(key) = keys[ix].as_deref()
+        {
+            removed_by_key.entry(key).or_default().push(ix);
+        }
+    }
+
+    let mut matched = vec![false; lines.len()];
+    let mut moved = MovedLines::default();
+    let mut comparisons = 0usize;
+    let mut ix = 0;
+    while ix < lines.len() {
+        let Some(key) = keys[ix]
+            .as_deref()
+            .filter(|_| lines[ix].kind == DiffLineKind::Add)
+        else {
+            ix += 1;
+            continue;
+        };
+
+        let mut best: Option<(usize, usize)> = None;
+        for &start in removed_by_key.get(key).into_iter().flatten() {
+            if matched[start] {
+                continue;
+            }
+            let mut len = 0;
+            while ix + len < lines.len()
+                && start + len < lines.len()
+                && lines[ix + len].kind == DiffLineKind::Add
+                && lines[start + len].kind == DiffLineKind::Remove
+                && !matched[start + len]
+                && keys[ix + len].is_some()
+                && keys[ix + len] == keys[start + len]
+            {
+                len += 1;
+            }
+            comparisons += len + 1;
+            if comparisons > max_comparisons {
+                return MovedLines::default();
+            }
+            if best.is_none_or(|(_, best_len)| len > best_len) {
+                best = Some((start, len));
+            }
+        }
+
+        let Some((start, len)) = best.filter(|&(_, len)| len > 0) else {
+            ix += 1;
+            continue;
+        };
+        let alnum: usize = keys[ix..ix + len]
+            .iter()
+            .flatten()
+            .map(|key| key.chars().filter(|c| c.is_alphanumeric()).count())
+            .sum();
+        if alnum < MOVED_BLOCK_MIN_ALNUM_CHARS {
+            ix += 1;
+            continue;
+        }
+
+        let block_ix = moved.blocks.len();
+        for offset in 0..len {
+            matched[start + offset] = true;
+            moved.block_for_line.insert(start + offset, block_ix);
+            moved.block_for_line.insert(ix + offset, block_ix);
+        }
+        moved.blocks.push(MovedBlock {
+            removed: start..start + len,
+            added: ix..ix + len,
+        });
+        ix += len;
+    }
+
+    moved
+}
+
+/// [`DiffIgnoreOptions`] with compiled patterns, for applying git's ignore
+/// rules to in-process line comparisons.
+#[derive(Clone, Debug, Default)]
+pub struct DiffIgnoreMatcher {
+    whitespace: DiffWhitespaceIgnore,
+    ignore_blank_lines: bool,
+    ignore_cr_at_eol: bool,
+    ignore_matching_lines: Vec<Regex>,
+}
+
+impl DiffIgnoreMatcher {
+    /// Patterns that fail to compile are skipped; use
+    /// [`DiffIgnoreMatcher::invalid_pattern`] to reject them up front.
+    pub fn new(options: &DiffIgnoreOptions) -> Self {
+        Self {
+            whitespace: options.whitespace,
+            ignore_blank_lines: options.ignore_blank_lines,
+            ignore_cr_at_eol: options.ignore_cr_at_eol,
+            ignore_matching_lines: options
+                .ignore_matching_lines
+                .iter()
+                .filter_map(|pattern| compile_ignore_pattern(pattern))
+                .collect(),
+        }
+    }
+
+    /// The first pattern in `options` that is not a POSIX extended regex
+    /// this matcher can evaluate the way git does.
+    pub fn invalid_pattern(options: &DiffIgnoreOptions) -> Option<&str> {
+        options
+            .ignore_matching_lines
+            .iter()
+            .find(|pattern| compile_ignore_pattern(pattern).is_none())
+            .map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.whitespace == DiffWhitespaceIgnore::None
+            && !self.ignore_blank_lines
+            && !self.ignore_cr_at_eol
+            && self.ignore_matching_lines.is_empty()
+    }
+
+    /// Line text as compared under the whitespace and CR options; lines with
+    /// equal normalized text are considered unchanged.
+    pub fn normalize_line<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        let line = if self.ignore_cr_at_eol {
+            line.strip_suffix('\r').unwrap_or(line)
+        } else {
+            line
+        };
+        match self.whitespace {
+            DiffWhitespaceIgnore::None => Cow::Borrowed(line),
+            DiffWhitespaceIgnore::AtEol => Cow::Borrowed(line.trim_end()),
+            DiffWhitespaceIgnore::Change => {
+                let line = line.trim_end();
+                let mut out = String::with_capacity(line.len());
+                let mut in_space = false;
+                for ch in line.chars() {
+                    if ch.is_whitespace() {
+                        if !in_space {
+                            out.push(' ');
+                        }
+                        in_space = true;
+                    } else {
+                        out.push(ch);
+                        in_space = false;
+                    }
+                }
+                Cow::Owned(out)
+            }
+            DiffWhitespaceIgnore::All => {
+                Cow::Owned(line.chars().filter(|ch| !ch.is_whitespace()).collect())
+            }
+        }
+    }
+
+    /// Whether a changed line may be dropped from a change on its own, via
+    /// `--ignore-blank-lines` or `-I<regex>`.
+    pub fn is_ignorable_line(&self, line: &str) -> bool {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        (self.ignore_blank_lines && line.trim().is_empty())
+            || self
+                .ignore_matching_lines
+                .iter()
+                .any(|regex| regex.is_match(line))
+    }
+
+    /// Whether replacing `old` lines with `new` lines is a change git would
+    /// hide: the remaining lines match once ignorable ones are dropped.
+    pub fn change_is_ignored<'a>(
+        &self,
+        old: impl IntoIterator<Item = &'a str>,
+        new: impl IntoIterator<Item = &'a str>,
+    ) -> bool {
+        let kept = |line: &&'a str| !self.is_ignorable_line(line);
+        old.into_iter()
+            .filter(kept)
+            .map(|line| self.normalize_line(line))
+            .eq(new
+                .into_iter()
+                .filter(kept)
+                .map(|line| self.normalize_line(line)))
+    }
+}
+
+/// git compiles `-I<regex>` as a POSIX extended regex, so patterns are
+/// translated before compiling rather than read as `regex` crate syntax.
+fn compile_ignore_pattern(pattern: &str) -> Option<Regex> {
+    let translated = posix_ere_to_regex(pattern)?;
+    RegexBuilder::new(&translated)
+        .size_limit(USER_REGEX_SIZE_LIMIT)
+        .dfa_size_limit(USER_REGEX_DFA_SIZE_LIMIT)
+        .build()
+        .ok()
+}
+
+const POSIX_CHARACTER_CLASSES: [&str; 12] = [
+    "alnum", "alpha", "blank", "cntrl", "digit", "graph", "lower", "print", "punct", "space",
+    "upper", "xdigit",
+];
+
+/// Rewrites a POSIX extended regex into `regex` crate syntax. Escapes that
+/// mean something else to the `regex` crate (`\d`, `\n`, inline flags,
+/// collating elements) are rejected instead of matching differently from git.
+fn posix_ere_to_regex(pattern: &str) -> Option<String> {
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut chars = pattern.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next()? {
+                // GNU extensions accepted by git's regcomp.
+                escaped @ ('w' | 'W' | 's' | 'S' | 'b' | 'B' | '<' | '>') => {
+                    out.push('\\');
+                    out.push(escaped);
+                }
+                '`' => out.push_str(r"\A"),
+                '\'' => out.push_str(r"\z"),
+                escaped if escaped.is_ascii_alphanumeric() => return None,
+                escaped => out.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4]))),
+            },
+            '(' if chars.peek() == Some(&'?') => return None,
+            '{' if chars.peek() == Some(&',') => out.push_str("{0"),
+            '[' => {
+                out.push('[');
+                translate_posix_bracket(&mut chars, &mut out)?;
+            }
+            _ => out.push(ch),
+        }
+    }
+    Some(out)
+}
+
+/// Copies a bracket expression after its opening `[`. Inside brackets POSIX
+/// treats `\` and `[` literally, while the `regex` crate reads escapes,
+/// nested classes and `&&`/`--`/`~~` set operations.
+fn translate_posix_bracket(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    out: &mut String,
+) -> Option<()> {
+    if chars.next_if_eq(&'^').is_some() {
+        out.push('^');
+    }
+    if chars.next_if_eq(&']').is_some() {
+        out.push_str(r"\]");
+    }
+    loop {
+        match chars.next()? {
+            ']' => {
+                out.push(']');
+                return Some(());
+            }
+            '[' => match chars.next_if(|c| matches!(c, ':' | '.' | '=')) {
+                Some(':') => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next()? {
+                            ':' if chars.next_if_eq(&']').is_some() => break,
+                            c => name.push(c),
+                        }
+                    }
+                    if !POSIX_CHARACTER_CLASSES.contains(&name.as_str()) {
+                        return None;
+                    }
+                    out.push_str("[:");
+                    out.push_str(&name);
+                    out.push_str(":]");
+                }
+                Some(_) => return None,
+                None => out.push_str(r"\["),
+            },
+            '-' if chars.peek() == Some(&'-') => return None,
+            ch @ ('\\' | '&' | '~') => {
+                out.push('\\');
+                out.push(ch);
+            }
+            ch => out.push(ch),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::{DiffArea, DiffTarget};
     use std::path::PathBuf;
 
+    #[test]
+    fn ignore_matcher_normalizes_whitespace_like_git() {
+        let matcher = |whitespace| {
+            DiffIgnoreMatcher::new(&DiffIgnoreOptions {
+                whitespace,
+                ignore_cr_at_eol: true,
+                ..Default::default()
+            })
+        };
+        let at_eol = matcher(DiffWhitespaceIgnore::AtEol);
+        assert_eq!(at_eol.normalize_line("  a  b \r\n"), "  a  b");
+        let change = matcher(DiffWhitespaceIgnore::Change);
+        assert_eq!(change.normalize_line("  a \t b  "), " a b");
+        assert_ne!(change.normalize_line("ab"), change.normalize_line("a b"));
+        let all = matcher(DiffWhitespaceIgnore::All);
+        assert_eq!(all.normalize_line(" a \t b "), "ab");
+    }
+
+    #[test]
+    fn ignore_matcher_drops_blank_and_matching_lines_from_changes() {
+        let options = DiffIgnoreOptions {
+            ignore_blank_lines: true,
+            ignore_matching_lines: vec!["^Generated: ".to_string(), "(".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(DiffIgnoreMatcher::invalid_pattern(&options), Some("("));
+        let matcher = DiffIgnoreMatcher::new(&options);
+        assert!(matcher.change_is_ignored(["Generated: 1", ""], ["Generated: 2"]));
+        assert!(!matcher.change_is_ignored(["Generated: 1", "a"], ["Generated: 2", "b"]));
+        assert!(matcher.change_is_ignored(["a", "Generated: 1"], ["a"]));
+    }
+
+    #[test]
+    fn ignore_patterns_are_read_as_posix_extended_regexes() {
+        let matches = |pattern: &str, line: &str| {
+            compile_ignore_pattern(pattern)
+                .unwrap_or_else(|| panic!("{pattern} should compile"))
+                .is_match(line)
+        };
+        assert!(matches("^[[:digit:]]+$", "2024"));
+        assert!(!matches("^[[:digit:]]+$", "20x4"));
+        // Backslash is literal inside a bracket expression.
+        assert!(matches(r"^[\.]$", r"\"));
+        assert!(matches("^[]a]+$", "]a]"));
+        assert!(matches("^a{,2}b$", "aab"));
+        assert!(matches(r"\<id\>", "an id here"));
+        assert!(matches(r"^Generated: \(.*\)$", "Generated: (now)"));
+        assert!(matches("[a&&b]", "&"));
+
+        for invalid in [
+            r"\d+",
+            r"\n",
+            "(?i)x",
+            "[[:word:]]",
+            "[[.a.]]",
+            "[a--b]",
+            "(",
+            "x\\",
+        ] {
+            assert!(
+                compile_ignore_pattern(invalid).is_none(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
     #[test]
     fn annotate_tracks_line_numbers_through_hunks() {
         let diff = Diff::from_unified(
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs
@@ -177,4 +632,130 @@ index 1111111..2222222 100644
             Some((42, 100))
         );
     }
+
+    fn moved_fixture() -> Diff {
+        Diff::from_unified(
+            DiffTarget::Commit {
+                commit_id: crate::domain::CommitId("deadbeef".into()),
+                path: None,
+                old_path: None,
+            },
+            "\
+diff --git a/a.rs b/a.rs
+--- a/a.rs
++++ b/a.rs
+@@ -1,4 +1,1 @@
+ fn keep() {}
+-fn moved_helper(value: usize) -> usize {
+-    value.saturating_mul(2)
+-}
+diff --git a/b.rs b/b.rs
+--- a/b.rs
++++ b/b.rs
+@@ -1,1 +1,4 @@
+ fn other() {}
++fn moved_helper(value: usize) -> usize {
++        value.saturating_mul(2)
++}
+",
+        )
+    }
+
+    #[test]
+    fn detect_moved_lines_pairs_blocks_across_files() {
+        let diff = moved_fixture();
+        let moved = detect_moved_lines(&diff.lines, MovedLineOptions::default());
+        // The re-indented body breaks the exact match, leaving only the signature.
+        assert_eq!(moved.blocks.len(), 1);
+        let block = &moved.blocks[0];
+        assert_eq!(block.removed.len(), 1);
+        assert_eq!(
+            moved.counterpart(block.removed.start),
+            Some(block.added.start)
+        );
+        assert_eq!(
+            moved.counterpart(block.added.start),
+            Some(block.removed.start)
+        );
+    }
+
+    #[test]
+    fn detect_moved_lines_can_ignore_whitespace() {
+        let diff = moved_fixture();
+        let moved = detect_moved_lines(
+            &diff.lines,
+            MovedLineOptions {
+                ignore_whitespace: true,
+            },
+        );
+        assert_eq!(moved.blocks.len(), 1);
+        let block = moved.blocks[0].clone();
+        assert_eq!(block.removed.len(), 3);
+        assert_eq!(block.added.len(), 3);
+        for offset in 0..3 {
+            assert_eq!(
+                moved.counterpart(block.removed.start + offset),
+                Some(block.added.start + offset)
+            );
+            assert_eq!(moved.block_for_line(block.added.start + offset), Some(0));
+        }
+    }
+
+    #[test]
+    fn detect_moved_lines_ignores_short_blocks() {
+        let lines = vec![
+            DiffLine {
+                kind: DiffLineKind::Remove,
+                text: "-}".into(),
+            },
+            DiffLine {
+                kind: DiffLineKind::Add,
+                text: "+}".into(),
+            },
+        ];
+        let moved = detect_moved_lines(&lines, MovedLineOptions::default());
+        assert!(moved.is_empty());
+        assert_eq!(moved.counterpart(0), None);
+    }
+
+    #[test]
+    fn detect_moved_lines_gives_up_past_its_limits() {
+        let line = |kind, text: &str| DiffLine {
+            kind,
+            text: text.into(),
+        };
+        let mut lines = Vec::new();
+        for _ in 0..4 {
+            lines.push(line(
+                DiffLineKind::Remove,
+                "-let value = compute_something();",
+            ));
+        }
+        for _ in 0..4 {
+            lines.push(line(DiffLineKind::Add, "+let value = compute_something();"));
+        }
+        let options = MovedLineOptions::default();
+
+        let unlimited = detect_moved_lines_with_limits(&lines, options, usize::MAX, usize::MAX);
+        assert_eq!(unlimited.blocks.len(), 1);
+
+        assert!(detect_moved_lines_with_limits(&lines, options, 7, usize::MAX).is_empty());
+        assert!(detect_moved_lines_with_limits(&lines, options, usize::MAX, 3).is_empty());
+
+        // Many identical removed and added lines stay within the real budget
+        // only by abandoning detection rather than scanning every pair.
+        let mut repetitive = Vec::new();
+        for _ in 0..MOVED_LINES_MAX_CHANGED_LINES / 2 {
+            repetitive.push(line(
+                DiffLineKind::Remove,
+                "-let value = compute_something();",
+            ));
+            repetitive.push(line(DiffLineKind::Context, " }"));
+        }
+        for _ in 0..MOVED_LINES_MAX_CHANGED_LINES / 2 {
+            repetitive.push(line(DiffLineKind::Add, "+let value = compute_something();"));
+            repetitive.push(line(DiffLineKind::Context, " }"));
+        }
+        assert!(detect_moved_lines(&repetitive, options).is_empty());
+    }
 }
@@ -0,0 +1,437 @@
+//! Recursive comparison of two directory trees.
+//!
+//! Used by the directory-compare difftool window: [`compare_directories`]
+//! classifies every path found under either root, and [`sync_entry`] copies a
+//! single entry from one side to the other so the window can double as a
+//! folder sync tool.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DirCompareStatus {
+    /// Present only in the right-hand tree.
+    Added,
+    /// Present only in the left-hand tree.
+    Removed,
+    /// Present on both sides with different content (or a different type).
+    Modified,
+    /// Present on both sides with identical content.
+    Identical,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum DirCompareSide {
+    Left,
+    Right,
+}
+
+impl DirCompareSide {
+    pub fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// One row of a directory comparison.
+///
+/// `path` is relative to both roots. Directory entries are reported before
+/// their children and carry the aggregate status of everything below them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirCompareEntry {
+    pub path: PathBuf,
+    pub is_dir: bool,
+    pub status: DirCompareStatus,
+}
+
+impl DirCompareEntry {
+    pub fn depth(&self) -> usize {
+        self.path.components().count().saturating_sub(1)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum NodeKind {
+    File,
+    Dir,
+    Symlink(PathBuf),
+}
+
+fn collect_tree(root: &Path, out: &mut BTreeMap<PathBuf, NodeKind>) -> io::Result<()> {
+    fn walk(root: &Path, rel: &Path, out: &mut BTreeMap<PathBuf, NodeKind>) -> io::Result<()> {
+        let mut entries = fs::read_dir(root.join(rel))?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            let rel_path = rel.join(entry.file_name());
+            let file_type = entry.file_type()?;
+            if file_type.is_symlink() {
+                // `git difftool --dir-diff` links worktree files into its
+                // temporary tree, so links to regular files are compared by
+                // content. Other links are compared by target and never
+                // descended into.
+                let kind = if fs::metadata(entry.path()).is_ok_and(|meta| meta.is_file()) {
+                    NodeKind::File
+                } else {
+                    NodeKind::Symlink(fs::read_link(entry.path())?)
+                };
+                out.insert(rel_path, kind);
+            } else if file_type.is_dir() {
+                out.insert(rel_path.clone(), NodeKind::Dir);
+                walk(root, &rel_path, out)?;
+            } else {
+                out.insert(rel_path, NodeKind::File);
+            }
+        }
+        Ok(())
+    }
+    walk(root, Path::new(""), out)
+}
+
+fn files_identical(left: &Path, right: &Path) -> io::Result<bool> {
+    if fs::metadata(left)?.len() != fs::metadata(right)?.len() {
+        return Ok(false);
+    }
+
+    let mut left = io::BufReader::new(fs::File::open(left)?);
+    let mut right = io::BufReader::new(fs::File::open(right)?);
+    let mut left_buf = [0u8; 8192];
+    let mut right_buf = [0u8; 8192];
+    loop {
+        let n = left.read(&mut left_buf)?;
+        if n == 0 {
+            return Ok(right.read(&mut right_buf)? == 0);
+        }
+        right.read_exact(&mut right_buf[..n])?;
+        if left_buf[..n] != right_buf[..n] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Compare two directory trees and classify every path found in either.
+///
+/// Entries are sorted by path so that a directory is immediately followed by
+/// its contents. Symlinks to regular files are compared by the content they
+/// point at; any other symlink is compared by target and never followed.
+pub fn compare_directories(left: &Path, right: &Path) -> io::Result<Vec<DirCompareEntry>> {
+    let mut left_nodes = BTreeMap::new();
+    let mut right_nodes = BTreeMap::new();
+    collect_tree(left, &mut left_nodes)?;
+    collect_tree(right, &mut right_nodes)?;
+
+    let mut paths = left_nodes.keys().cloned().collect::<Vec<_>>();
+    paths.extend(right_nodes.keys().cloned());
+    paths.sort();
+    paths.dedup();
+
+    let mut entries = Vec::with_capacity(paths.len());
+    for path in paths {
+        let (is_dir, status) = match (left_nodes.get(&path), right_nodes.get(&path)) {
+            (Some(kind), None) => (*kind == NodeKind::Dir, DirCompareStatus::Removed),
+            (None, Some(kind)) => (*kind == NodeKind::Dir, DirCompareStatus::Added),
+            (Some(NodeKind::Dir), Some(NodeKind::Dir)) => (true, DirCompareStatus::Identical),
+            (Some(NodeKind::File), Some(NodeKind::File)) => {
+                let identical = files_identical(&left.join(&path), &right.join(&path))?;
+                let status = if identical {
+                    DirCompareStatus::Identical
+                } else {
+                    DirCompareStatus::Modified
+                };
+                (false, status)
+            }
+            (Some(NodeKind::Symlink(a)), Some(NodeKind::Symlink(b))) if a == b => {
+                (false, DirCompareStatus::Identical)
+            }
+            (Some(_), Some(right_kind)) => {
+                (*right_kind == NodeKind::Dir, DirCompareStatus::Modified)
+            }
+            (None, None) => continue,
+        };
+        entries.push(DirCompareEntry {
+            path,
+            is_dir,
+            status,
+        });
+    }
+
+    // A directory present on both sides is modified when anything below it
+    // differs. Walk in reverse so children are settled before their parents.
+    for ix in (0..entries.len()).rev() {
+        if entries[ix].status == DirCompareStatus::Identical {
+            continue;
+        }
+        let path = entries[ix].path.clone();
+        for dir in path.ancestors().skip(1) {
+            if dir.as_os_str().is_empty() {
+                break;
+            }
+            if let Ok(parent_ix) = entries[..ix].binary_search_by(|e| e.path.as_path().cmp(dir))
+                && entries[parent_ix].status == DirCompareStatus::Identical
+            {
+                entries[parent_ix].status = DirCompareStatus::Modified;
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+fn validate_relative_path(rel: &Path) -> io::Result<()> {
+    let valid = !rel.as_os_str().is_empty()
+        && rel
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid relative path: {}", rel.display()),
+        ))
+    }
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(meta) if meta.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+fn is_file_or_link_to_file(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|meta| meta.is_file())
+}
+
+fn copy_path(src: &Path, dst: &Path) -> io::Result<()> {
+    let meta = fs::symlink_metadata(src)?;
+    if meta.file_type().is_symlink() && !is_file_or_link_to_file(src) {
+        let target = fs::read_link(src)?;
+        #[cfg(unix)]
+        return std::os::unix::fs::symlink(target, dst);
+        #[cfg(windows)]
+        return if fs::metadata(src).is_ok_and(|m| m.is_dir()) {
+            std::os::windows::fs::symlink_dir(target, dst)
+        } else {
+            std::os::windows::fs::symlink_file(target, dst)
+        };
+    }
+    if meta.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            copy_path(&entry.path(), &dst.join(entry.file_name()))?;
+        }
+        return Ok(());
+    }
+    fs::copy(src, dst).map(|_| ())
+}
+
+/// Make `target`'s copy of `rel` match the opposite side.
+///
+/// The entry (file, symlink or whole directory) is copied over whatever is at
+/// the destination. When the entry does not exist on the source side it is
+/// removed from `target`, so syncing an added or removed path mirrors it.
+///
+/// Links to regular files are copied by content. When both sides hold a file
+/// and the destination is such a link, the content is written through it, so
+/// syncing into the worktree side of a `--dir-diff` updates the worktree.
+pub fn sync_entry(left: &Path, right: &Path, rel: &Path, target: DirCompareSide) -> io::Result<()> {
+    validate_relative_path(rel)?;
+    let (src_root, dst_root) = match target {
+        DirCompareSide::Left => (right, left),
+        DirCompareSide::Right => (left, right),
+    };
+    let src = src_root.join(rel);
+    let dst = dst_root.join(rel);
+
+    if is_file_or_link_to_file(&src) && is_file_or_link_to_file(&dst) {
+        return fs::copy(&src, &dst).map(|_| ());
+    }
+    remove_path(&dst)?;
+    if fs::symlink_metadata(&src).is_err() {
+        return Ok(());
+    }
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    copy_path(&src, &dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, contents: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn statuses(entries: &[DirCompareEntry]) -> Vec<(String, bool, DirCompareStatus)> {
+        entries
+            .iter()
+            .map(|e| {
+                (
+                    e.path.to_string_lossy().replace('\\', "/"),
+                    e.is_dir,
+                    e.status,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_directories_classifies_entries() {
+        let left = tempfile::tempdir().unwrap();
+        let right = tempfile::tempdir().unwrap();
+        write(left.path(), "same.txt", "a\n");
+        write(right.path(), "same.txt", "a\n");
+        write(left.path(), "src/changed.rs", "old\n");
+        write(right.path(), "src/changed.rs", "new\n");
+        write(left.path(), "src/gone.rs", "x\n");
+        write(right.path(), "docs/new.md", "y\n");
+        write(left.path(), "unchanged/a.txt", "z\n");
+        write(right.path(), "unchanged/a.txt", "z\n");
+
+        let entries = compare_directories(left.path(), right.path()).unwrap();
+        assert_eq!(
+            statuses(&entries),
+            vec![
+                ("docs".into(), true, DirCompareStatus::Added),
+                ("docs/new.md".into(), false, DirCompareStatus::Added),
+                ("same.txt".into(), false, DirCompareStatus::Identical),
+                ("src".into(), true, DirCompareStatus::Modified),
+                ("src/changed.rs".into(), false, DirCompareStatus::Modified),
+                ("src/gone.rs".into(), false, DirCompareStatus::Removed),
+                ("unchanged".into(), true, DirCompareStatus::Identical),
+                ("unchanged/a.txt".into(), false, DirCompareStatus::Identical),
+            ]
+        );
+        assert_eq!(entries[1].depth(), 1);
+    }
+
+    #[test]
+    fn compare_directories_marks_same_size_content_changes_as_modified() {
+        let left = tempfile::tempdir().unwrap();
+        let right = tempfile::tempdir().unwrap();
+        write(left.path(), "f", "abc");
+        write(right.path(), "f", "abd");
+
+        let entries = compare_directories(left.path(), right.path()).unwrap();
+        assert_eq!(entries[0].status, DirCompareStatus::Modified);
+    }
+
+    #[test]
+    fn sync_entry_copies_and_mirrors_deletions() {
+        let left = tempfile::tempdir().unwrap();
+        let right = tempfile::tempdir().unwrap();
+        write(left.path(), "dir/a.txt", "left\n");
+        write(right.path(), "dir/a.txt", "right\n");
+        write(right.path(), "only_right.txt", "r\n");
+
+        sync_entry(
+            left.path(),
+            right.path(),
+            Path::new("dir"),
+            DirCompareSide::Right,
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(right.path().join("dir/a.txt")).unwrap(),
+            "left\n"
+        );
+
+        sync_entry(
+            left.path(),
+            right.path(),
+            Path::new("only_right.txt"),
+            DirCompareSide::Right,
+        )
+        .unwrap();
+        assert!(!right.path().join("only_right.txt").exists());
+
+        let entries = compare_directories(left.path(), right.path()).unwrap();
+        assert!(
+            entries
+                .iter()
+                .all(|e| e.status == DirCompareStatus::Identical)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn compare_and_sync_follow_symlinks_to_files() {
+        let left = tempfile::tempdir().unwrap();
+        let right = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        write(left.path(), "same.txt", "a\n");
+        write(left.path(), "changed.txt", "old\n");
+        write(worktree.path(), "same.txt", "a\n");
+        write(worktree.path(), "changed.txt", "new\n");
+        for name in ["same.txt", "changed.txt"] {
+            std::os::unix::fs::symlink(worktree.path().join(name), right.path().join(name))
+                .unwrap();
+        }
+
+        let entries = compare_directories(left.path(), right.path()).unwrap();
+        assert_eq!(
+            statuses(&entries),
+            vec![
+                ("changed.txt".into(), false, DirCompareStatus::Modified),
+                ("same.txt".into(), false, DirCompareStatus::Identical),
+            ]
+        );
+
+        sync_entry(
+            left.path(),
+            right.path(),
+            Path::new("changed.txt"),
+            DirCompareSide::Right,
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(worktree.path().join("changed.txt")).unwrap(),
+            "old\n"
+        );
+        assert!(
+            fs::symlink_metadata(right.path().join("changed.txt"))
+                .unwrap()
+                .file_type()
+                .is_symlink()
+        );
+
+        sync_entry(
+            left.path(),
+            right.path(),
+            Path::new("same.txt"),
+            DirCompareSide::Left,
+        )
+        .unwrap();
+        assert!(
+            !fs::symlink_metadata(left.path().join("same.txt"))
+                .unwrap()
+                .file_type()
+                .is_symlink()
+        );
+    }
+
+    #[test]
+    fn sync_entry_rejects_escaping_paths() {
+        let left = tempfile::tempdir().unwrap();
+        let right = tempfile::tempdir().unwrap();
+        let err = sync_entry(
+            left.path(),
+            right.path(),
+            Path::new("../outside"),
+            DirCompareSide::Left,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
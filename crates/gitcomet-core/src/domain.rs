@@ -99,6 +99,23 @@ pub struct CommitFileChange {
     pub path: PathBuf,
     pub kind: FileStatusKind,
     pub is_submodule: bool,
+    /// Source path for renamed or copied files.
+    pub old_path: Option<PathBuf>,
+    /// Rename/copy similarity in percent, when the backend computed one.
+    pub similarity: Option<u8>,
+    /// Marked `linguist-generated` in `.gitattributes`.
+    pub generated: bool,
+}
+
+/// Diff-related `.gitattributes` settings resolved for one path.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DiffAttributes {
+    /// Set by `-diff`, `binary`, or a diff driver configured with `binary = true`.
+    pub binary: bool,
+    /// `diff.<driver>.textconv` command for the path's `diff=<driver>`.
+    pub textconv: Option<String>,
+    /// Set by `linguist-generated`.
+    pub generated: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -134,6 +151,103 @@ pub struct UpstreamDivergence {
     pub behind: usize,
 }
 
+/// Where an interrupted multi-step operation stopped, as recorded by git.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OperationProgress {
+    /// 1-based position of the step that stopped and the total step count.
+    pub step: Option<(usize, usize)>,
+    /// Subject of the commit or patch that was being applied.
+    pub subject: Option<String>,
+}
+
+/// An in-progress git operation that is waiting to be continued, skipped or
+/// aborted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RepoOperationState {
+    Rebase(OperationProgress),
+    Merge,
+    CherryPick(OperationProgress),
+    Revert(OperationProgress),
+    ApplyMailbox(OperationProgress),
+    Bisect,
+}
+
+impl RepoOperationState {
+    pub fn kind(&self) -> RepoOperationKind {
+        match self {
+            Self::Rebase(_) => RepoOperationKind::Rebase,
+            Self::Merge => RepoOperationKind::Merge,
+            Self::CherryPick(_) => RepoOperationKind::CherryPick,
+            Self::Revert(_) => RepoOperationKind::Revert,
+            Self::ApplyMailbox(_) => RepoOperationKind::ApplyMailbox,
+            Self::Bisect => RepoOperationKind::Bisect,
+        }
+    }
+
+    pub fn progress(&self) -> Option<&OperationProgress> {
+        match self {
+            Self::Rebase(progress)
+            | Self::CherryPick(progress)
+            | Self::Revert(progress)
+            | Self::ApplyMailbox(progress) => Some(progress),
+            Self::Merge | Self::Bisect => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RepoOperationKind {
+    Rebase,
+    Merge,
+    CherryPick,
+    Revert,
+    ApplyMailbox,
+    Bisect,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RepoOperationAction {
+    Continue,
+    Skip,
+    Abort,
+}
+
+impl RepoOperationKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Rebase => "Rebase",
+            Self::Merge => "Merge",
+            Self::CherryPick => "Cherry-pick",
+            Self::Revert => "Revert",
+            Self::ApplyMailbox => "Apply patches",
+            Self::Bisect => "Bisect",
+        }
+    }
+
+    /// The git subcommand that drives this operation.
+    pub fn git_subcommand(self) -> &'static str {
+        match self {
+            Self::Rebase => "rebase",
+            Self::Merge => "merge",
+            Self::CherryPick => "cherry-pick",
+            Self::Revert => "revert",
+            Self::ApplyMailbox => "am",
+            Self::Bisect => "bisect",
+        }
+    }
+
+    /// Whether git offers `action` for this operation. Merges cannot be
+    /// skipped and bisect has no continue step; its skip and abort map to
+    /// `git bisect skip` and `git bisect reset`.
+    pub fn supports(self, action: RepoOperationAction) -> bool {
+        !matches!(
+            (self, action),
+            (Self::Merge, RepoOperationAction::Skip)
+                | (Self::Bisect, RepoOperationAction::Continue)
+        )
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Remote {
     pub name: String,
@@ -146,6 +260,31 @@ pub struct Worktree {
     pub head: Option<CommitId>,
     pub branch: Option<String>,
     pub detached: bool,
+    /// `Some` when the worktree is locked, holding the reason (possibly empty).
+    pub locked: Option<String>,
+    /// `Some` when git would prune the worktree, holding git's explanation.
+    pub prunable: Option<String>,
+}
+
+impl Worktree {
+    pub fn is_locked(&self) -> bool {
+        self.locked.is_some()
+    }
+
+    pub fn is_prunable(&self) -> bool {
+        self.prunable.is_some()
+    }
+}
+
+/// Uncommitted work and upstream divergence of one worktree, for the sidebar
+/// overview.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WorktreeSummary {
+    pub path: PathBuf,
+    /// Changed, staged, conflicted and untracked files.
+    pub dirty_files: usize,
+    /// `None` when the checked-out branch has no upstream.
+    pub upstream: Option<UpstreamDivergence>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -199,6 +338,44 @@ pub struct RemoteBranch {
     pub target: CommitId,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevisionTreeEntryKind {
+    Directory,
+    File,
+    Executable,
+    Symlink,
+    Submodule,
+}
+
+/// One entry of a directory listing in the tree of a commit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevisionTreeEntry {
+    /// Repository-relative path.
+    pub path: PathBuf,
+    pub kind: RevisionTreeEntryKind,
+    /// Blob size in bytes; `None` for directories and submodules.
+    pub size: Option<u64>,
+}
+
+impl RevisionTreeEntry {
+    pub fn name(&self) -> std::borrow::Cow<'_, str> {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default()
+    }
+}
+
+/// Orders a directory listing the way file browsers do: directories first,
+/// then everything else, each group by name.
+pub fn sort_revision_tree_entries(entries: &mut [RevisionTreeEntry]) {
+    entries.sort_by(|a, b| {
+        let a_dir = a.kind == RevisionTreeEntryKind::Directory;
+        let b_dir = b.kind == RevisionTreeEntryKind::Directory;
+        b_dir.cmp(&a_dir).then_with(|| a.path.cmp(&b.path))
+    });
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FileConflictKind {
     BothDeleted,
@@ -215,6 +392,10 @@ pub struct FileStatus {
     pub path: PathBuf,
     pub kind: FileStatusKind,
     pub conflict: Option<FileConflictKind>,
+    /// Source path for renamed or copied files.
+    pub old_path: Option<PathBuf>,
+    /// Rename/copy similarity in percent, when the backend computed one.
+    pub similarity: Option<u8>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -274,6 +455,7 @@ pub enum FileStatusKind {
     Added,
     Deleted,
     Renamed,
+    Copied,
     Conflicted,
 }
 
@@ -288,10 +470,14 @@ pub enum DiffTarget {
     WorkingTree {
         path: PathBuf,
         area: DiffArea,
+        /// Rename/copy source; the diff compares `old_path` against `path`.
+        old_path: Option<PathBuf>,
     },
     Commit {
         commit_id: CommitId,
         path: Option<PathBuf>,
+        /// Rename/copy source; the diff compares `old_path` against `path`.
+        old_path: Option<PathBuf>,
     },
     CommitRange {
         from_commit_id: CommitId,
@@ -450,6 +636,11 @@ impl FileDiffTextSource {
         }
     }
 
+    /// Git object id of the source, when it was read from the object database.
+    pub fn blob_id(&self) -> Option<&str> {
+        self.identity.strip_prefix("blob:")
+    }
+
     fn filesystem_identity(path: &std::path::Path) -> Arc<str> {
         let mut hasher = FxHasher::default();
         path.hash(&mut hasher);
@@ -474,6 +665,7 @@ pub struct FileDiffText {
     pub new_source: Option<FileDiffTextSource>,
     pub old: Option<Arc<str>>,
     pub new: Option<Arc<str>>,
+    binary: bool,
     content_signature: u64,
 }
 
@@ -483,14 +675,21 @@ impl FileDiffText {
     }
 
     pub fn new_shared(path: PathBuf, old: Option<Arc<str>>, new: Option<Arc<str>>) -> Self {
-        let content_signature =
-            Self::content_signature_for_parts(&path, None, None, old.as_deref(), new.as_deref());
+        let content_signature = Self::content_signature_for_parts(
+            &path,
+            None,
+            None,
+            old.as_deref(),
+            new.as_deref(),
+            false,
+        );
         Self {
             path,
             old_source: None,
             new_source: None,
             old,
             new,
+            binary: false,
             content_signature,
         }
     }
@@ -506,6 +705,7 @@ impl FileDiffText {
             new_source.as_ref(),
             None,
             None,
+            false,
         );
         Self {
             path,
@@ -513,10 +713,41 @@ impl FileDiffText {
             new_source,
             old: None,
             new: None,
+            binary: false,
             content_signature,
         }
     }
 
+    /// A binary file, either by content or by `.gitattributes`. The sources
+    /// are only read byte-wise, never as text.
+    pub fn new_binary(
+        path: PathBuf,
+        old_source: Option<FileDiffTextSource>,
+        new_source: Option<FileDiffTextSource>,
+    ) -> Self {
+        let content_signature = Self::content_signature_for_parts(
+            &path,
+            old_source.as_ref(),
+            new_source.as_ref(),
+            None,
+            None,
+            true,
+        );
+        Self {
+            path,
+            old_source,
+            new_source,
+            old: None,
+            new: None,
+            binary: true,
+            content_signature,
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        self.binary
+    }
+
     pub fn content_signature(&self) -> u64 {
         self.content_signature
     }
@@ -527,6 +758,7 @@ impl FileDiffText {
         new_source: Option<&FileDiffTextSource>,
         old: Option<&str>,
         new: Option<&str>,
+        binary: bool,
     ) -> u64 {
         let mut hasher = FxHasher::default();
         path.hash(&mut hasher);
@@ -538,6 +770,7 @@ impl FileDiffText {
             .hash(&mut hasher);
         old.hash(&mut hasher);
         new.hash(&mut hasher);
+        binary.hash(&mut hasher);
         hasher.finish()
     }
 }
@@ -788,6 +1021,20 @@ pub struct LogPage {
     pub next_cursor: Option<LogCursor>,
 }
 
+/// A commit that touched a tracked line range, with its diff limited to the
+/// lines of that range as they evolved through history.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineRangeLogEntry {
+    pub commit: Commit,
+    pub diff: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineRangeLogPage {
+    pub entries: Vec<LineRangeLogEntry>,
+    pub next_cursor: Option<LogCursor>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LogCursor {
     pub last_seen: CommitId,
@@ -809,6 +1056,22 @@ mod tests {
     use std::path::PathBuf;
     use std::time::{Duration, SystemTime};
 
+    #[test]
+    fn repo_operation_kind_reports_supported_actions() {
+        use RepoOperationAction::*;
+        assert!(RepoOperationKind::Rebase.supports(Skip));
+        assert!(RepoOperationKind::CherryPick.supports(Continue));
+        assert!(RepoOperationKind::Merge.supports(Continue));
+        assert!(!RepoOperationKind::Merge.supports(Skip));
+        assert!(!RepoOperationKind::Bisect.supports(Continue));
+        assert!(RepoOperationKind::Bisect.supports(Abort));
+        assert_eq!(
+            RepoOperationState::CherryPick(OperationProgress::default()).kind(),
+            RepoOperationKind::CherryPick
+        );
+        assert!(RepoOperationState::Bisect.progress().is_none());
+    }
+
     #[test]
     fn submodule_status_maps_known_git_markers() {
         assert_eq!(
@@ -840,11 +1103,21 @@ mod tests {
         assert_eq!(status.git_status_marker(), 'M');
     }
 
+    #[test]
+    fn file_diff_text_source_blob_id_reads_object_identity() {
+        let blob = FileDiffTextSource::with_identity(PathBuf::from("/tmp/a"), "blob:1a2b3c");
+        assert_eq!(blob.blob_id(), Some("1a2b3c"));
+        let worktree =
+            FileDiffTextSource::with_identity(PathBuf::from("/tmp/b"), "worktree-git:00ff");
+        assert_eq!(worktree.blob_id(), None);
+    }
+
     #[test]
     fn unified_reader_matches_string_parser() {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/main.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let unified = "\
 diff --git a/src/main.rs b/src/main.rs\n\
@@ -874,6 +1147,7 @@ index 1111111..2222222 100644\n\
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("README.md"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let unified = "\
 @@ -1 +1 @@\r\n\
@@ -893,6 +1167,7 @@ index 1111111..2222222 100644\n\
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let unified = "\
 diff --git a/src/lib.rs b/src/lib.rs\r\n\
@@ -924,6 +1199,7 @@ diff --git a/src/lib.rs b/src/lib.rs\r\n\
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("README.md"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let unified = "\
 @@ -1 +1 @@\n\
@@ -943,6 +1219,7 @@ diff --git a/src/lib.rs b/src/lib.rs\r\n\
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let unified = "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -1018,6 +1295,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n\
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let text = "\
@@ -1044,6 +1322,7 @@ index 0000000..1111111 100644
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let diff = Diff::from_unified(
@@ -1081,4 +1360,27 @@ index 0000000..1111111 100644
         assert!(Arc::ptr_eq(&reflog.message, &reflog_clone.message));
         assert!(Arc::ptr_eq(&reflog.selector, &reflog_clone.selector));
     }
+
+    #[test]
+    fn revision_tree_entries_sort_directories_first() {
+        let entry = |path: &str, kind| RevisionTreeEntry {
+            path: PathBuf::from(path),
+            kind,
+            size: None,
+        };
+        let mut entries = vec![
+            entry("src/main.rs", RevisionTreeEntryKind::File),
+            entry("src/vendor", RevisionTreeEntryKind::Submodule),
+            entry("src/util", RevisionTreeEntryKind::Directory),
+            entry("src/bin", RevisionTreeEntryKind::Directory),
+            entry("src/build.sh", RevisionTreeEntryKind::Executable),
+        ];
+        sort_revision_tree_entries(&mut entries);
+
+        let names: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.name().into_owned())
+            .collect();
+        assert_eq!(names, ["bin", "util", "build.sh", "main.rs", "vendor"]);
+    }
 }
@@ -1,3 +1,8 @@
+use crate::conflict_output::{
+    ConflictMarkerLabels, ConflictOutputBlockRef, ConflictOutputChoice,
+    render_unresolved_marker_block,
+};
+use crate::diff::DiffIgnoreMatcher;
 use crate::domain::SharedLineText;
 use rustc_hash::FxHasher;
 use std::borrow::Cow;
@@ -30,6 +35,10 @@ const SIDE_BY_SIDE_LINEAR_FALLBACK_LINE_THRESHOLD: usize = 100_000;
 const PATIENCE_POSITIONAL_FALLBACK_LINE_THRESHOLD: usize = 2_048;
 const SIDE_BY_SIDE_SPARSE_POSITIONAL_MAX_CHANGED_RATIO_DENOMINATOR: usize = 4;
 const SIDE_BY_SIDE_SPARSE_POSITIONAL_MAX_BLOCK_LEN: usize = 1;
+/// Token budget for the changed middle of a structural diff. Myers trace memory
+/// grows with the square of the edit distance, so larger changes fall back to
+/// the line-based plan.
+const STRUCTURAL_DIFF_MAX_CHANGED_TOKENS: usize = 4_096;
 // UTF-8 code points are at most 4 bytes wide, so 3 bytes of lookaround is
 // enough to recover the nearest character boundary around any requested slice.
 const UTF8_SUBSLICE_BOUNDARY_LOOKAROUND_BYTES: usize = 3;
@@ -742,6 +751,186 @@ pub fn side_by_side_plan_from_lines(
     )
 }
 
+/// A leaf syntax token used for structural alignment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StructuralToken<'a> {
+    pub text: &'a str,
+    /// Zero-based first and last line the token spans.
+    pub first_line: usize,
+    pub last_line: usize,
+}
+
+/// Result of aligning two files by syntax tokens instead of raw lines.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuralDiff {
+    pub plan: FileDiffPlan,
+    /// Per-line flags for lines that contain at least one unmatched token.
+    /// Lines that only moved, re-indented or re-wrapped stay `false`.
+    pub old_changed_lines: Vec<bool>,
+    pub new_changed_lines: Vec<bool>,
+}
+
+/// Build a side-by-side plan whose rows are aligned on token content rather
+/// than exact line text, so formatting-only edits collapse to context.
+///
+/// Returns `None` when the changed region exceeds the alignment budget; callers
+/// should fall back to [`side_by_side_plan_from_lines`].
+pub fn structural_side_by_side_plan(
+    old_text: &str,
+    new_text: &str,
+    old_tokens: &[StructuralToken<'_>],
+    new_tokens: &[StructuralToken<'_>],
+) -> Option<StructuralDiff> {
+    let old_line_count = split_lines(old_text).len();
+    let new_line_count = split_lines(new_text).len();
+
+    let prefix = old_tokens
+        .iter()
+        .zip(new_tokens)
+        .take_while(|(old, new)| old.text == new.text)
+        .count();
+    let suffix = old_tokens[prefix..]
+        .iter()
+        .rev()
+        .zip(new_tokens[prefix..].iter().rev())
+        .take_while(|(old, new)| old.text == new.text)
+        .count();
+    let old_mid = &old_tokens[prefix..old_tokens.len() - suffix];
+    let new_mid = &new_tokens[prefix..new_tokens.len() - suffix];
+    if old_mid.len().saturating_add(new_mid.len()) > STRUCTURAL_DIFF_MAX_CHANGED_TOKENS {
+        return None;
+    }
+
+    let mut old_changed_lines = vec![false; old_line_count];
+    let mut new_changed_lines = vec![false; new_line_count];
+    let mark = |lines: &mut [bool], token: &StructuralToken<'_>| {
+        let end = token.last_line.saturating_add(1).min(lines.len());
+        for line in lines.iter_mut().take(end).skip(token.first_line) {
+            *line = true;
+        }
+    };
+
+    let old_texts = old_mid.iter().map(|token| token.text).collect::<Vec<_>>();
+    let new_texts = new_mid.iter().map(|token| token.text).collect::<Vec<_>>();
+    let (mut old_ix, mut new_ix) = (0usize, 0usize);
+    for edit in myers_edits(&old_texts, &new_texts) {
+        match edit.kind {
+            EditKind::Equal => {
+                old_ix += 1;
+                new_ix += 1;
+            }
+            EditKind::Delete => {
+                mark(&mut old_changed_lines, &old_mid[old_ix]);
+                old_ix += 1;
+            }
+            EditKind::Insert => {
+                mark(&mut new_changed_lines, &new_mid[new_ix]);
+                new_ix += 1;
+            }
+        }
+    }
+
+    let old_keys = structural_line_keys(old_tokens, old_line_count);
+    let new_keys = structural_line_keys(new_tokens, new_line_count);
+    let old_lines = old_keys.iter().map(String::as_str).collect::<Vec<_>>();
+    let new_lines = new_keys.iter().map(String::as_str).collect::<Vec<_>>();
+    let plan = side_by_side_plan_from_lines(old_text, new_text, &old_lines, &new_lines);
+
+    Some(StructuralDiff {
+        plan,
+        old_changed_lines,
+        new_changed_lines,
+    })
+}
+
+/// Line keys made of the token text on each line, so indentation and spacing
+/// between tokens do not affect alignment.
+fn structural_line_keys(tokens: &[StructuralToken<'_>], line_count: usize) -> Vec<String> {
+    let mut keys = vec![String::new(); line_count];
+    for token in tokens {
+        for (line_ix, part) in (token.first_line..).zip(token.text.lines()) {
+            let Some(key) = keys.get_mut(line_ix) else {
+                break;
+            };
+            if !key.is_empty() {
+                key.push(' ');
+            }
+            key.push_str(part.trim());
+        }
+    }
+    keys
+}
+
+/// Build a side-by-side plan under `git diff`'s ignore options: rows align on
+/// normalized line text, and change groups the options hide are left out of
+/// the changed-line masks, in the same shape as a structural diff.
+pub fn side_by_side_plan_with_ignore(
+    old_text: &str,
+    new_text: &str,
+    matcher: &DiffIgnoreMatcher,
+) -> StructuralDiff {
+    let old_lines = split_lines(old_text);
+    let new_lines = split_lines(new_text);
+    let old_keys = old_lines
+        .iter()
+        .map(|line| matcher.normalize_line(line))
+        .collect::<Vec<_>>();
+    let new_keys = new_lines
+        .iter()
+        .map(|line| matcher.normalize_line(line))
+        .collect::<Vec<_>>();
+    let old_key_refs = old_keys.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    let new_key_refs = new_keys.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    let plan = side_by_side_plan_from_lines(old_text, new_text, &old_key_refs, &new_key_refs);
+
+    let mut old_changed_lines = vec![false; old_lines.len()];
+    let mut new_changed_lines = vec![false; new_lines.len()];
+    let mut old_group = 0..0;
+    let mut new_group = 0..0;
+    for run in plan.runs.iter().map(Some).chain([None]) {
+        let (old_range, new_range) = match run {
+            Some(FileDiffPlanRun::Context { .. }) | None => {
+                if !matcher.change_is_ignored(
+                    old_lines[old_group.clone()].iter().copied(),
+                    new_lines[new_group.clone()].iter().copied(),
+                ) {
+                    old_changed_lines[old_group.clone()].fill(true);
+                    new_changed_lines[new_group.clone()].fill(true);
+                }
+                old_group = 0..0;
+                new_group = 0..0;
+                continue;
+            }
+            Some(&FileDiffPlanRun::Remove { old_start, len }) => (old_start..old_start + len, 0..0),
+            Some(&FileDiffPlanRun::Add { new_start, len }) => (0..0, new_start..new_start + len),
+            Some(&FileDiffPlanRun::Modify {
+                old_start,
+                new_start,
+                len,
+            }) => (old_start..old_start + len, new_start..new_start + len),
+        };
+        extend_line_group(&mut old_group, old_range);
+        extend_line_group(&mut new_group, new_range);
+    }
+
+    StructuralDiff {
+        plan,
+        old_changed_lines,
+        new_changed_lines,
+    }
+}
+
+fn extend_line_group(group: &mut Range<usize>, range: Range<usize>) {
+    if range.start == range.end {
+        return;
+    }
+    if group.start == group.end {
+        *group = range;
+    } else {
+        group.end = group.end.max(range.end);
+    }
+}
+
 #[cfg(feature = "benchmarks")]
 pub fn benchmark_side_by_side_plan_with_replacement_backend(
     old: &str,
@@ -819,6 +1008,128 @@ pub fn append_side_by_side_rows_with_offsets(
     )
 }
 
+/// One aligned row of a three-pane comparison.
+///
+/// `left_kind` describes the left/middle pair and `right_kind` the
+/// middle/right pair, using the same meaning as [`FileDiffRow::kind`] with the
+/// left (respectively middle) file as the old side.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThreeWayDiffRow {
+    pub left_kind: FileDiffRowKind,
+    pub right_kind: FileDiffRowKind,
+    pub left_line: Option<u32>,
+    pub middle_line: Option<u32>,
+    pub right_line: Option<u32>,
+    pub left: Option<FileDiffLineText>,
+    pub middle: Option<FileDiffLineText>,
+    pub right: Option<FileDiffLineText>,
+}
+
+/// Align three versions of a file using the middle one as the pivot.
+///
+/// The left/middle and middle/right side-by-side diffs are computed
+/// independently and zipped on middle lines. Lines that exist only on an
+/// outer side are inserted where they occur; a left-only and right-only line
+/// at the same position share one row.
+pub fn three_way_rows(left: &str, middle: &str, right: &str) -> Vec<ThreeWayDiffRow> {
+    let left_rows = side_by_side_rows(left, middle);
+    let right_rows = side_by_side_rows(middle, right);
+    let mut out = Vec::with_capacity(left_rows.len().max(right_rows.len()));
+    let mut left_iter = left_rows.into_iter().peekable();
+    let mut right_iter = right_rows.into_iter().peekable();
+
+    loop {
+        let left_only = left_iter.peek().is_some_and(|row| row.new_line.is_none());
+        let right_only = right_iter.peek().is_some_and(|row| row.old_line.is_none());
+        let (l, r) = match (left_only, right_only) {
+            (true, true) => (left_iter.next(), right_iter.next()),
+            (true, false) => (left_iter.next(), None),
+            (false, true) => (None, right_iter.next()),
+            (false, false) => match (left_iter.next(), right_iter.next()) {
+                (None, None) => break,
+                pair => pair,
+            },
+        };
+
+        let (left_kind, left_line, left_text, l_middle_line, l_middle) = match l {
+            Some(row) => (row.kind, row.old_line, row.old, row.new_line, row.new),
+            None => (FileDiffRowKind::Context, None, None, None, None),
+        };
+        let (right_kind, r_middle_line, r_middle, right_line, right_text) = match r {
+            Some(row) => (row.kind, row.old_line, row.old, row.new_line, row.new),
+            None => (FileDiffRowKind::Context, None, None, None, None),
+        };
+        out.push(ThreeWayDiffRow {
+            left_kind,
+            right_kind,
+            left_line,
+            middle_line: l_middle_line.or(r_middle_line),
+            right_line,
+            left: left_text,
+            middle: l_middle.or(r_middle),
+            right: right_text,
+        });
+    }
+
+    out
+}
+
+/// Render three versions of a file as one text with diff3-style conflict
+/// markers around every region where they differ.
+///
+/// `middle` is the pivot and fills the base section; `left` and `right` fill
+/// the local and remote sides. This lets a three-file comparison be opened in
+/// the conflict resolver, which shows one block per differing region.
+pub fn three_way_comparison_marker_text(
+    left: &str,
+    middle: &str,
+    right: &str,
+    labels: ConflictMarkerLabels<'_>,
+) -> String {
+    fn flush(out: &mut String, sides: &mut [String; 3], labels: ConflictMarkerLabels<'_>) {
+        let [ours, base, theirs] = sides;
+        out.push_str(&render_unresolved_marker_block(
+            ConflictOutputBlockRef {
+                base: Some(base.as_str()),
+                ours: ours.as_str(),
+                theirs: theirs.as_str(),
+                choice: ConflictOutputChoice::Base,
+                resolved: false,
+            },
+            labels,
+        ));
+        sides.iter_mut().for_each(String::clear);
+    }
+
+    let mut out = String::with_capacity(left.len() + middle.len() + right.len());
+    let mut sides: [String; 3] = Default::default();
+    let mut in_block = false;
+    for row in three_way_rows(left, middle, right) {
+        if row.left_kind == FileDiffRowKind::Context && row.right_kind == FileDiffRowKind::Context {
+            if std::mem::take(&mut in_block) {
+                flush(&mut out, &mut sides, labels);
+            }
+            if let Some(line) = row.middle.as_ref() {
+                out.push_str(line.as_str());
+                out.push('\n');
+            }
+            continue;
+        }
+
+        in_block = true;
+        for (side, line) in sides.iter_mut().zip([&row.left, &row.middle, &row.right]) {
+            if let Some(line) = line {
+                side.push_str(line.as_str());
+                side.push('\n');
+            }
+        }
+    }
+    if in_block {
+        flush(&mut out, &mut sides, labels);
+    }
+    out
+}
+
 pub fn side_by_side_rows_with_anchors(old: &str, new: &str) -> FileDiffRowsWithAnchors {
     let rows = side_by_side_rows(old, new);
     let anchors = compute_row_region_anchors(&rows);
@@ -4211,4 +4522,204 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn three_way_rows_align_on_middle_lines() {
+        let left = "a\nb\nc\n";
+        let middle = "a\nB\nc\n";
+        let right = "a\nB\nc\nd\n";
+        let rows = three_way_rows(left, middle, right);
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].left_kind, FileDiffRowKind::Context);
+        assert_eq!(rows[0].right_kind, FileDiffRowKind::Context);
+        assert_eq!(rows[1].left_kind, FileDiffRowKind::Modify);
+        assert_eq!(rows[1].right_kind, FileDiffRowKind::Context);
+        assert_eq!(rows[1].left.as_deref(), Some("b"));
+        assert_eq!(rows[1].middle.as_deref(), Some("B"));
+        assert_eq!(rows[1].right.as_deref(), Some("B"));
+        assert_eq!(rows[3].right_kind, FileDiffRowKind::Add);
+        assert_eq!(rows[3].middle_line, None);
+        assert_eq!(rows[3].right_line, Some(4));
+        assert_eq!(rows[3].right.as_deref(), Some("d"));
+    }
+
+    #[test]
+    fn three_way_rows_share_row_for_outer_only_lines() {
+        let rows = three_way_rows("x\nkeep\n", "keep\n", "y\nkeep\n");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].left.as_deref(), Some("x"));
+        assert_eq!(rows[0].middle, None);
+        assert_eq!(rows[0].right.as_deref(), Some("y"));
+        assert_eq!(rows[0].left_kind, FileDiffRowKind::Remove);
+        assert_eq!(rows[0].right_kind, FileDiffRowKind::Add);
+        assert_eq!(rows[1].middle_line, Some(1));
+    }
+
+    #[test]
+    fn three_way_comparison_marker_text_wraps_each_differing_region() {
+        use crate::conflict_session::{ParsedConflictSegment, parse_conflict_marker_segments};
+
+        let text = three_way_comparison_marker_text(
+            "a\nb\nc\nd\n",
+            "a\nB\nc\nd\n",
+            "a\nB\nc\nD\ne\n",
+            ConflictMarkerLabels {
+                local: "main",
+                remote: "patch",
+                base: "release",
+            },
+        );
+
+        assert!(text.contains("<<<<<<< main\nb\n||||||| release\nB\n=======\nB\n>>>>>>> patch\n"));
+        let blocks: Vec<_> = parse_conflict_marker_segments(&text)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                ParsedConflictSegment::Conflict(block) => Some(block),
+                ParsedConflictSegment::Text(_) => None,
+            })
+            .collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].ours, "d\n");
+        assert_eq!(blocks[1].base.as_deref(), Some("d\n"));
+        assert_eq!(blocks[1].theirs, "D\ne\n");
+    }
+
+    #[test]
+    fn three_way_comparison_marker_text_is_plain_when_inputs_match() {
+        let labels = ConflictMarkerLabels {
+            local: "a",
+            remote: "c",
+            base: "b",
+        };
+        assert_eq!(
+            three_way_comparison_marker_text("x\ny\n", "x\ny\n", "x\ny\n", labels),
+            "x\ny\n"
+        );
+    }
+
+    fn simple_structural_tokens(text: &str) -> Vec<StructuralToken<'_>> {
+        let mut tokens = Vec::new();
+        let mut line_start = 0usize;
+        for (line_ix, line) in text.split('\n').enumerate() {
+            let mut token_start = None;
+            for (offset, ch) in line
+                .char_indices()
+                .chain(std::iter::once((line.len(), ' ')))
+            {
+                let is_word = ch.is_alphanumeric() || ch == '_';
+                if let Some(start) = token_start.filter(|_| !is_word) {
+                    tokens.push(StructuralToken {
+                        text: &text[line_start + start..line_start + offset],
+                        first_line: line_ix,
+                        last_line: line_ix,
+                    });
+                    token_start = None;
+                }
+                if is_word {
+                    token_start.get_or_insert(offset);
+                } else if !ch.is_whitespace() {
+                    let end = offset + ch.len_utf8();
+                    tokens.push(StructuralToken {
+                        text: &text[line_start + offset..line_start + end],
+                        first_line: line_ix,
+                        last_line: line_ix,
+                    });
+                }
+            }
+            line_start += line.len() + 1;
+        }
+        tokens
+    }
+
+    #[test]
+    fn structural_plan_treats_reindent_and_rewrap_as_unchanged() {
+        let old = "fn main() {\n    call(a, b);\n}\n";
+        let new = "fn main() {\n        call(\n            a,\n            b,\n        );\n}\n";
+        let old_tokens = simple_structural_tokens(old);
+        let mut new_tokens = simple_structural_tokens(new);
+        // Drop the trailing comma so the token streams match exactly.
+        let comma = new_tokens
+            .iter()
+            .rposition(|token| token.text == ",")
+            .expect("trailing comma");
+        new_tokens.remove(comma);
+
+        let diff = structural_side_by_side_plan(old, new, &old_tokens, &new_tokens)
+            .expect("small diff fits the budget");
+        assert!(diff.old_changed_lines.iter().all(|changed| !changed));
+        assert!(diff.new_changed_lines.iter().all(|changed| !changed));
+        assert_eq!(diff.new_changed_lines.len(), 6);
+        assert_eq!(
+            diff.plan.runs.first(),
+            Some(&FileDiffPlanRun::Context {
+                old_start: 0,
+                new_start: 0,
+                len: 1
+            })
+        );
+    }
+
+    #[test]
+    fn structural_plan_marks_only_lines_with_new_tokens() {
+        let old = "let a = 1;\nlet b = 2;\n";
+        let new = "let a = 1;\n  let b = 3;\n";
+        let diff = structural_side_by_side_plan(
+            old,
+            new,
+            &simple_structural_tokens(old),
+            &simple_structural_tokens(new),
+        )
+        .expect("small diff fits the budget");
+        assert_eq!(diff.old_changed_lines, vec![false, true]);
+        assert_eq!(diff.new_changed_lines, vec![false, true]);
+    }
+
+    #[test]
+    fn ignore_plan_hides_whitespace_and_matching_line_changes() {
+        let old = "fn a() {\n    one();\n}\n// generated 1\nmid\nkeep\n";
+        let new = "fn a() {\n  one();  \n\n}\n// generated 2\nmid\nkept\n";
+        let matcher = DiffIgnoreMatcher::new(&crate::services::DiffIgnoreOptions {
+            whitespace: crate::services::DiffWhitespaceIgnore::Change,
+            ignore_blank_lines: true,
+            ignore_matching_lines: vec!["^// generated".to_string()],
+            ..Default::default()
+        });
+
+        let diff = side_by_side_plan_with_ignore(old, new, &matcher);
+        assert_eq!(
+            diff.old_changed_lines,
+            vec![false, false, false, false, false, true]
+        );
+        assert_eq!(
+            diff.new_changed_lines,
+            vec![false, false, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn ignore_plan_without_options_matches_line_plan() {
+        let old = "a\nb\nc\n";
+        let new = "a\nB\nc\nd\n";
+        let diff = side_by_side_plan_with_ignore(old, new, &DiffIgnoreMatcher::default());
+        assert_eq!(diff.plan, side_by_side_plan(old, new));
+        assert_eq!(diff.old_changed_lines, vec![false, true, false]);
+        assert_eq!(diff.new_changed_lines, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn structural_plan_gives_up_past_token_budget() {
+        let old = "a\n".repeat(STRUCTURAL_DIFF_MAX_CHANGED_TOKENS);
+        let new = "b\n".repeat(STRUCTURAL_DIFF_MAX_CHANGED_TOKENS);
+        assert!(
+            structural_side_by_side_plan(
+                &old,
+                &new,
+                &simple_structural_tokens(&old),
+                &simple_structural_tokens(&new),
+            )
+            .is_none()
+        );
+    }
 }
@@ -3,10 +3,12 @@ pub mod conflict_labels;
 pub mod conflict_output;
 pub mod conflict_session;
 pub mod diff;
+pub mod dir_compare;
 pub mod domain;
 pub mod error;
 pub mod file_diff;
 pub mod git_ops_trace;
+pub mod mailbox;
 pub mod merge;
 pub mod merge_extraction;
 pub mod mergetool_trace;
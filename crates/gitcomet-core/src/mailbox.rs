@@ -0,0 +1,506 @@
+//! Reading `git format-patch` output for review before `git am`.
+//!
+//! [`parse_mailbox`] splits an mbox into its patches and [`read_patch_series`]
+//! accepts either a single mbox file or a directory of `*.patch` files, in the
+//! order `git am` would apply them.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// One patch from a mailbox, split into the parts shown in the import preview.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MailboxPatch {
+    /// Subject with the `[PATCH n/m]` prefix removed.
+    pub subject: String,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    /// Full commit message: subject, blank line, body.
+    pub message: String,
+    /// Everything from the first `diff --git` line up to the signature.
+    pub diff: String,
+}
+
+const PATCH_FILE_EXTENSIONS: &[&str] = &["patch", "eml", "mbox"];
+
+fn strip_subject_prefix(subject: &str) -> &str {
+    let mut subject = subject.trim();
+    while let Some(rest) = subject.strip_prefix('[')
+        && let Some(end) = rest.find(']')
+    {
+        subject = rest[end + 1..].trim_start();
+    }
+    subject
+}
+
+fn is_cover_letter_subject(subject: &str) -> bool {
+    let subject = subject.trim_start();
+    subject
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .is_some_and(|(tag, _)| {
+            tag.split_whitespace()
+                .any(|part| part.starts_with("0/") || part.starts_with("00/"))
+        })
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u32> {
+        match byte {
+            b'A'..=b'Z' => Some(u32::from(byte - b'A')),
+            b'a'..=b'z' => Some(u32::from(byte - b'a') + 26),
+            b'0'..=b'9' => Some(u32::from(byte - b'0') + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    for byte in text.bytes().filter(|&byte| byte != b'=') {
+        acc = (acc << 6) | value(byte)?;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
+
+fn decode_quoted_printable_word(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut ix = 0;
+    while ix < bytes.len() {
+        match bytes[ix] {
+            b'_' => out.push(b' '),
+            b'=' => {
+                let hex = text.get(ix + 1..ix + 3)?;
+                out.push(u8::from_str_radix(hex, 16).ok()?);
+                ix += 2;
+            }
+            byte => out.push(byte),
+        }
+        ix += 1;
+    }
+    Some(out)
+}
+
+/// Decode one RFC 2047 encoded word, `=?charset?Q|B?text?=`. Charsets other
+/// than Latin-1 are read as UTF-8.
+fn decode_encoded_word(word: &str) -> Option<String> {
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let text = parts.next()?;
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_quoted_printable_word(text)?,
+        _ => return None,
+    };
+    // RFC 2231 allows a language suffix, e.g. `utf-8*en`.
+    let charset = charset.split('*').next().unwrap_or(charset);
+    if charset.eq_ignore_ascii_case("iso-8859-1") || charset.eq_ignore_ascii_case("latin1") {
+        Some(bytes.into_iter().map(char::from).collect())
+    } else {
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// Decode the RFC 2047 encoded words in a header value. Whitespace between
+/// two adjacent encoded words is dropped, as the RFC requires.
+fn decode_header_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    let mut pending_space = "";
+    let mut after_encoded_word = false;
+    while !rest.is_empty() {
+        let token_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(token_end);
+        let space_end = tail
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(tail.len());
+        let (space, tail) = tail.split_at(space_end);
+
+        match decode_encoded_word(token) {
+            Some(decoded) => {
+                if !after_encoded_word {
+                    out.push_str(pending_space);
+                }
+                out.push_str(&decoded);
+                after_encoded_word = true;
+            }
+            None => {
+                out.push_str(pending_space);
+                out.push_str(token);
+                after_encoded_word = false;
+            }
+        }
+        pending_space = space;
+        rest = tail;
+    }
+    out
+}
+
+/// Line counts `(old, new)` from a hunk header, `@@ -a[,b] +c[,d] @@`.
+fn hunk_line_counts(line: &str) -> Option<(u32, u32)> {
+    let ranges = line.strip_prefix("@@ -")?;
+    let (old, rest) = ranges.split_once(" +")?;
+    let (new, _) = rest.split_once(" @@")?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, count)) => count.parse().ok(),
+        None => Some(1),
+    };
+    Some((count(old)?, count(new)?))
+}
+
+fn parse_message(text: &str) -> Option<MailboxPatch> {
+    let mut lines = text.lines().peekable();
+    if lines.peek().is_some_and(|line| line.starts_with("From ")) {
+        lines.next();
+    }
+
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    let raw_subject = decode_header_value(&header("subject")?);
+    if is_cover_letter_subject(&raw_subject) {
+        return None;
+    }
+    let subject = strip_subject_prefix(&raw_subject).to_string();
+
+    let mut body = Vec::new();
+    let mut diff = Vec::new();
+    let mut in_diff = false;
+    let mut past_separator = false;
+    // Old and new lines still to come in the current hunk. A `-- ` line is
+    // only the signature marker outside a hunk; inside one it is a removed
+    // `- ` line.
+    let mut hunk_remaining = (0u32, 0u32);
+    for line in lines {
+        if in_diff {
+            if hunk_remaining != (0, 0) {
+                let (old, new) = &mut hunk_remaining;
+                match line.as_bytes().first() {
+                    Some(b'-') => *old = old.saturating_sub(1),
+                    Some(b'+') => *new = new.saturating_sub(1),
+                    Some(b'\\') => {}
+                    _ => {
+                        *old = old.saturating_sub(1);
+                        *new = new.saturating_sub(1);
+                    }
+                }
+            } else if line == "-- " {
+                break;
+            } else if let Some(counts) = hunk_line_counts(line) {
+                hunk_remaining = counts;
+            }
+            diff.push(line);
+        } else if line.starts_with("diff --git ") {
+            in_diff = true;
+            diff.push(line);
+        } else if line == "---" {
+            past_separator = true;
+        } else if !past_separator {
+            body.push(line);
+        }
+    }
+
+    let body = body.join("\n");
+    let body = body.trim();
+    let message = if body.is_empty() {
+        subject.clone()
+    } else {
+        format!("{subject}\n\n{body}")
+    };
+    let mut diff = diff.join("\n");
+    if !diff.is_empty() {
+        diff.push('\n');
+    }
+
+    Some(MailboxPatch {
+        subject,
+        author: header("from").map(|from| decode_header_value(&from)),
+        date: header("date"),
+        message,
+        diff,
+    })
+}
+
+/// Split an mbox into patches. Cover letters (`[PATCH 0/n]`) are skipped
+/// since they carry no change to apply.
+pub fn parse_mailbox(text: &str) -> Vec<MailboxPatch> {
+    let mut messages = Vec::new();
+    let mut current = String::new();
+    let mut previous_blank = true;
+    for line in text.split_inclusive('\n') {
+        if line.starts_with("From ") && previous_blank && !current.trim().is_empty() {
+            messages.push(std::mem::take(&mut current));
+        }
+        previous_blank = line.trim_end_matches(['\r', '\n']).is_empty();
+        current.push_str(line);
+    }
+    if !current.trim().is_empty() {
+        messages.push(current);
+    }
+    messages
+        .iter()
+        .filter_map(|msg| parse_message(msg))
+        .collect()
+}
+
+/// The patch files in `dir` in application order, leaving out the cover
+/// letter written by `git format-patch --cover-letter`.
+pub fn patch_series_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let has_patch_extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| PATCH_FILE_EXTENSIONS.contains(&ext));
+        let is_cover_letter = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.contains("cover-letter"));
+        if has_patch_extension && !is_cover_letter {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Patches may carry content in any encoding; invalid UTF-8 is replaced
+/// rather than failing the whole series.
+fn read_lossy(path: &Path) -> io::Result<String> {
+    Ok(String::from_utf8_lossy(&fs::read(path)?).into_owned())
+}
+
+/// Read the patches in `source`, which is either an mbox file or a directory
+/// of patch files.
+pub fn read_patch_series(source: &Path) -> io::Result<Vec<MailboxPatch>> {
+    if source.is_dir() {
+        let mut patches = Vec::new();
+        for file in patch_series_files(source)? {
+            patches.extend(parse_mailbox(&read_lossy(&file)?));
+        }
+        Ok(patches)
+    } else {
+        Ok(parse_mailbox(&read_lossy(source)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERIES: &str = "\
+From 1111111111111111111111111111111111111111 Mon Sep 17 00:00:00 2001
+From: Ada Lovelace <ada@example.com>
+Date: Sat, 1 Feb 2025 10:00:00 +0000
+Subject: [PATCH 0/2] Parser fixes
+
+*** BLURB HERE ***
+
+--\x20
+2.45.0
+
+From 2222222222222222222222222222222222222222 Mon Sep 17 00:00:00 2001
+From: Ada Lovelace <ada@example.com>
+Date: Sat, 1 Feb 2025 10:01:00 +0000
+Subject: [PATCH 1/2] Fix the parser when the input
+ spans two lines
+
+Longer explanation.
+---
+ a.txt | 2 +-
+ 1 file changed, 1 insertion(+), 1 deletion(-)
+
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-old
++new
+--\x20
+2.45.0
+
+From 3333333333333333333333333333333333333333 Mon Sep 17 00:00:00 2001
+From: Grace Hopper <grace@example.com>
+Subject: [PATCH 2/2] Add b
+
+---
+diff --git a/b.txt b/b.txt
+new file mode 100644
+--- /dev/null
++++ b/b.txt
+@@ -0,0 +1 @@
++b
+--\x20
+2.45.0
+";
+
+    #[test]
+    fn parse_mailbox_splits_patches_and_skips_cover_letter() {
+        let patches = parse_mailbox(SERIES);
+        assert_eq!(patches.len(), 2);
+
+        assert_eq!(
+            patches[0].subject,
+            "Fix the parser when the input spans two lines"
+        );
+        assert_eq!(
+            patches[0].author.as_deref(),
+            Some("Ada Lovelace <ada@example.com>")
+        );
+        assert_eq!(
+            patches[0].message,
+            "Fix the parser when the input spans two lines\n\nLonger explanation."
+        );
+        assert!(patches[0].diff.starts_with("diff --git a/a.txt b/a.txt\n"));
+        assert!(patches[0].diff.ends_with("+new\n"));
+
+        assert_eq!(patches[1].subject, "Add b");
+        assert_eq!(patches[1].message, "Add b");
+        assert_eq!(patches[1].date, None);
+        assert!(patches[1].diff.contains("+++ b/b.txt"));
+    }
+
+    #[test]
+    fn parse_mailbox_decodes_encoded_subject_and_author() {
+        let text = "\
+From 4444444444444444444444444444444444444444 Mon Sep 17 00:00:00 2001
+From: =?UTF-8?q?J=C3=B6rg=20M=C3=BCller?= <jorg@example.com>
+Subject: [PATCH] =?UTF-8?B?R3LDvMOfZQ==?=
+ =?UTF-8?q?_an_alle?= und =?ISO-8859-1?Q?caf=E9?=
+
+---
+diff --git a/a.txt b/a.txt
+--- a/a.txt
++++ b/a.txt
+@@ -1 +1 @@
+-old
++new
+";
+        let patches = parse_mailbox(text);
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].subject, "Grüße an alle und café");
+        assert_eq!(
+            patches[0].author.as_deref(),
+            Some("Jörg Müller <jorg@example.com>")
+        );
+        assert_eq!(
+            decode_header_value("=?utf-8?x?bad?= plain"),
+            "=?utf-8?x?bad?= plain"
+        );
+    }
+
+    #[test]
+    fn parse_mailbox_only_treats_dash_dash_space_after_the_last_hunk_as_signature() {
+        let text = "\
+From 5555555555555555555555555555555555555555 Mon Sep 17 00:00:00 2001
+From: Ada Lovelace <ada@example.com>
+Subject: [PATCH] Drop the bullet
+
+---
+diff --git a/list.md b/list.md
+--- a/list.md
++++ b/list.md
+@@ -1,3 +1,2 @@
+ items
+--\x20
+ end
+@@ -10 +9 @@
+-old
++new
+--\x20
+2.45.0
+";
+        let patches = parse_mailbox(text);
+        assert_eq!(patches.len(), 1);
+        assert!(patches[0].diff.contains("\n--\x20\n end\n"));
+        assert!(patches[0].diff.ends_with("+new\n"));
+        assert!(!patches[0].diff.contains("2.45.0"));
+    }
+
+    #[test]
+    fn strip_subject_prefix_removes_every_bracket_tag() {
+        assert_eq!(strip_subject_prefix("[PATCH v2 3/7] Fix"), "Fix");
+        assert_eq!(strip_subject_prefix("[RFC] [PATCH] Fix"), "Fix");
+        assert_eq!(strip_subject_prefix("Fix [not a tag]"), "Fix [not a tag]");
+    }
+
+    #[test]
+    fn read_patch_series_orders_directory_and_skips_cover_letter() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut parts = SERIES.split("\nFrom ");
+        let cover = parts.next().unwrap();
+        let first = format!("From {}", parts.next().unwrap());
+        let second = format!("From {}", parts.next().unwrap());
+        fs::write(dir.path().join("0000-cover-letter.patch"), cover).unwrap();
+        fs::write(dir.path().join("0002-Add-b.patch"), second).unwrap();
+        fs::write(dir.path().join("0001-Fix-the-parser.patch"), first).unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let files = patch_series_files(dir.path()).unwrap();
+        assert_eq!(
+            files
+                .iter()
+                .map(|f| f.file_name().unwrap().to_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["0001-Fix-the-parser.patch", "0002-Add-b.patch"]
+        );
+
+        let patches = read_patch_series(dir.path()).unwrap();
+        assert_eq!(
+            patches
+                .iter()
+                .map(|p| p.subject.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Fix the parser when the input spans two lines", "Add b"]
+        );
+    }
+
+    #[test]
+    fn read_patch_series_replaces_invalid_utf8() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("latin1.mbox");
+        let mut bytes = SERIES.replace("+new", "+caf\u{0}").into_bytes();
+        let nul = bytes.iter().position(|&b| b == 0).unwrap();
+        bytes[nul] = 0xe9;
+        fs::write(&path, bytes).unwrap();
+
+        let patches = read_patch_series(&path).unwrap();
+        assert_eq!(patches.len(), 2);
+        assert!(patches[0].diff.contains("+caf\u{fffd}\n"));
+    }
+}
@@ -1,6 +1,7 @@
-use crate::conflict_session::ConflictSession;
+use crate::conflict_session::{ConflictFileSummary, ConflictSession};
 use crate::domain::*;
 use crate::error::{Error, ErrorKind};
+use crate::mailbox::MailboxPatch;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -107,6 +108,15 @@ pub enum ResetMode {
     Hard,
 }
 
+/// Which autosquash marker a commit created for an earlier commit carries.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixupCommitKind {
+    /// `fixup!`: the message is dropped when squashed.
+    Fixup,
+    /// `squash!`: the message is appended when squashed.
+    Squash,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RemoteUrlKind {
     Fetch,
@@ -126,6 +136,83 @@ pub enum SubmoduleTrustDecision {
     Prompt { sources: Vec<SubmoduleTrustTarget> },
 }
 
+/// A git command run in every initialized submodule, one at a time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubmoduleBatchCommand {
+    Fetch,
+    Pull,
+    /// Arguments after `git`, e.g. `["status", "--short"]`.
+    Git {
+        args: Vec<String>,
+    },
+}
+
+impl SubmoduleBatchCommand {
+    /// Arguments passed to `git` inside each submodule.
+    pub fn git_args(&self) -> Vec<String> {
+        match self {
+            Self::Fetch => vec!["fetch".to_string()],
+            Self::Pull => vec!["pull".to_string()],
+            Self::Git { args } => args.clone(),
+        }
+    }
+
+    /// Parses a command typed as `status --short` or `git status --short`.
+    /// Single or double quotes keep spaces inside one argument.
+    pub fn from_command_line(text: &str) -> Option<Self> {
+        let mut args = Vec::new();
+        let mut current = String::new();
+        let mut in_word = false;
+        let mut quote = None;
+        for ch in text.chars() {
+            match quote {
+                Some(q) if ch == q => quote = None,
+                Some(_) => current.push(ch),
+                None if ch == '\'' || ch == '"' => {
+                    quote = Some(ch);
+                    in_word = true;
+                }
+                None if ch.is_whitespace() => {
+                    if in_word {
+                        args.push(std::mem::take(&mut current));
+                        in_word = false;
+                    }
+                }
+                None => {
+                    current.push(ch);
+                    in_word = true;
+                }
+            }
+        }
+        if in_word {
+            args.push(current);
+        }
+        if args.first().is_some_and(|arg| arg == "git") {
+            args.remove(0);
+        }
+        (!args.is_empty()).then_some(Self::Git { args })
+    }
+
+    /// The command as typed, e.g. `git fetch`.
+    pub fn display(&self) -> String {
+        let mut text = "git".to_string();
+        for arg in self.git_args() {
+            text.push(' ');
+            text.push_str(&arg);
+        }
+        text
+    }
+}
+
+/// Outcome of a [`SubmoduleBatchCommand`] in one submodule. A failure in one
+/// submodule does not stop the others.
+#[derive(Debug)]
+pub struct SubmoduleCommandResult {
+    /// Path relative to the repository root, including parent submodules.
+    pub path: PathBuf,
+    pub result: Result<CommandOutput>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BlameLine {
     pub commit_id: Arc<str>,
@@ -133,6 +220,145 @@ pub struct BlameLine {
     pub author_time_unix: Option<i64>,
     pub summary: Arc<str>,
     pub line: String,
+    /// Path the line came from in `commit_id`, when move/copy detection
+    /// traced it to a different file.
+    pub original_path: Option<Arc<str>>,
+    /// 1-based line number in `commit_id`'s version of the file.
+    pub original_line: Option<u32>,
+    /// Whether the line can be re-blamed at `commit_id^`. False for root
+    /// commits and, when the backend can tell, commits that added the file.
+    pub has_parent: bool,
+}
+
+/// Tuning knobs for [`GitRepository::blame_file_with_options`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BlameOptions {
+    /// Ignore whitespace-only changes when assigning lines (`-w`).
+    pub ignore_whitespace: bool,
+    /// Follow lines moved within the file (`-M`).
+    pub detect_moves: bool,
+    /// Follow lines moved or copied from other files in the same commit (`-C`).
+    pub detect_copies: bool,
+    /// Skip commits listed in `blame.ignoreRevsFile` or `.git-blame-ignore-revs`.
+    pub use_ignore_revs: bool,
+}
+
+impl Default for BlameOptions {
+    fn default() -> Self {
+        Self {
+            ignore_whitespace: false,
+            detect_moves: false,
+            detect_copies: false,
+            use_ignore_revs: true,
+        }
+    }
+}
+
+/// How a text query is matched by diff search and
+/// [`GitRepository::search_commit_files`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TextSearchOptions {
+    /// Treat the query as a regular expression instead of literal text.
+    pub regex: bool,
+    /// Match letter case exactly.
+    pub case_sensitive: bool,
+    /// Only match the query as a whole word.
+    pub whole_word: bool,
+}
+
+/// One line found by [`GitRepository::search_commit_files`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitFileSearchMatch {
+    pub path: PathBuf,
+    /// 1-based line number in the commit's version of `path`.
+    pub line: u32,
+    pub text: String,
+}
+
+/// Similarity thresholds for pairing removed and added paths as renames or
+/// copies in status, commit details and diffs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct RenameDetection {
+    /// Minimum similarity, in percent, for a rename (`-M<n>%`).
+    pub rename_threshold: u8,
+    /// Also pair added paths with unchanged or modified sources (`-C`).
+    pub detect_copies: bool,
+    /// Minimum similarity, in percent, for a copy (`-C<n>%`).
+    pub copy_threshold: u8,
+}
+
+impl Default for RenameDetection {
+    fn default() -> Self {
+        Self {
+            rename_threshold: 50,
+            detect_copies: false,
+            copy_threshold: 50,
+        }
+    }
+}
+
+impl RenameDetection {
+    /// `git diff`/`git show` arguments selecting these thresholds.
+    pub fn git_args(self) -> Vec<String> {
+        let mut args = vec![format!("-M{}%", self.rename_threshold.min(100))];
+        if self.detect_copies {
+            args.push(format!("-C{}%", self.copy_threshold.min(100)));
+        }
+        args
+    }
+}
+
+/// Which whitespace differences diffs treat as unchanged.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum DiffWhitespaceIgnore {
+    #[default]
+    None,
+    /// Ignore whitespace at end of line (`--ignore-space-at-eol`).
+    AtEol,
+    /// Ignore changes in the amount of whitespace (`-b`).
+    Change,
+    /// Ignore all whitespace when comparing lines (`-w`).
+    All,
+}
+
+/// Per-repository counterparts of `git diff`'s ignore flags.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DiffIgnoreOptions {
+    /// Set app-wide from the diff whitespace mode rather than per repository.
+    pub whitespace: DiffWhitespaceIgnore,
+    /// Ignore changes whose lines are all blank (`--ignore-blank-lines`).
+    pub ignore_blank_lines: bool,
+    /// Ignore carriage returns at end of line (`--ignore-cr-at-eol`).
+    pub ignore_cr_at_eol: bool,
+    /// Ignore changes whose lines all match one of these regexes (`-I<regex>`).
+    pub ignore_matching_lines: Vec<String>,
+}
+
+impl DiffIgnoreOptions {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// `git diff`/`git show` arguments selecting these options.
+    pub fn git_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        match self.whitespace {
+            DiffWhitespaceIgnore::None => {}
+            DiffWhitespaceIgnore::AtEol => args.push("--ignore-space-at-eol".to_string()),
+            DiffWhitespaceIgnore::Change => args.push("-b".to_string()),
+            DiffWhitespaceIgnore::All => args.push("-w".to_string()),
+        }
+        if self.ignore_blank_lines {
+            args.push("--ignore-blank-lines".to_string());
+        }
+        if self.ignore_cr_at_eol {
+            args.push("--ignore-cr-at-eol".to_string());
+        }
+        for pattern in &self.ignore_matching_lines {
+            args.push(format!("-I{pattern}"));
+        }
+        args
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -215,6 +441,22 @@ pub trait GitRepository: Send + Sync {
             "file history is not implemented for this backend",
         )))
     }
+    /// History of lines `start..=end` (1-based, as of `rev`, or `HEAD` when
+    /// `None`) of `path`, following the range across edits and renames like
+    /// `git log -L`.
+    fn log_line_range_page(
+        &self,
+        _path: &Path,
+        _start: u32,
+        _end: u32,
+        _rev: Option<&str>,
+        _limit: usize,
+        _cursor: Option<&LogCursor>,
+    ) -> Result<LineRangeLogPage> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "line range history is not implemented for this backend",
+        )))
+    }
     fn commit_details(&self, id: &CommitId) -> Result<CommitDetails>;
     fn recent_commit_messages(&self, _limit: usize) -> Result<Vec<RecentCommitMessage>> {
         Err(Error::new(ErrorKind::Unsupported(
@@ -296,6 +538,31 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Summarize every conflicted path for the multi-file conflict dashboard.
+    ///
+    /// Default implementation loads one conflict session per conflicted status
+    /// entry; backends may override with a cheaper bulk read of the index.
+    fn conflict_summaries(&self) -> Result<Vec<ConflictFileSummary>> {
+        let status = self.status()?;
+        let mut paths: Vec<&Path> = status
+            .unstaged
+            .iter()
+            .chain(status.staged.iter())
+            .filter(|entry| entry.conflict.is_some())
+            .map(|entry| entry.path.as_path())
+            .collect();
+        paths.sort_unstable();
+        paths.dedup();
+
+        let mut summaries = Vec::with_capacity(paths.len());
+        for path in paths {
+            if let Some(session) = self.conflict_session(path)? {
+                summaries.push(session.summary());
+            }
+        }
+        Ok(summaries)
+    }
+
     fn create_branch(&self, name: &str, target: &CommitId) -> Result<()>;
     fn delete_branch(&self, name: &str) -> Result<()>;
     fn delete_branch_force(&self, _name: &str) -> Result<()> {
@@ -339,12 +606,43 @@ pub trait GitRepository: Send + Sync {
         self.commit_amend(message)?;
         Ok(CommitOperationOutcome::default())
     }
+    /// Why `id` can't be reworded in place, or `None` when it can: it must be
+    /// an unpublished, unsigned commit on the current branch.
+    fn reword_commit_blocker(&self, _id: &CommitId) -> Result<Option<String>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "rewording commits is not implemented for this backend",
+        )))
+    }
+    /// Replace the message of `id`, an unpublished commit on the current
+    /// branch, and rewrite the commits above it without touching the worktree.
+    fn reword_commit_with_output(&self, _id: &CommitId, _message: &str) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "rewording commits is not implemented for this backend",
+        )))
+    }
+    /// Commit the staged changes as a `fixup!`/`squash!` commit for `target`.
+    fn commit_fixup_with_output(
+        &self,
+        _target: &CommitId,
+        _kind: FixupCommitKind,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "fixup commits are not implemented for this backend",
+        )))
+    }
 
     fn rebase_with_output(&self, _onto: &str) -> Result<CommandOutput> {
         Err(Error::new(ErrorKind::Unsupported(
             "git rebase is not implemented for this backend",
         )))
     }
+    /// Rebase the current branch onto its upstream, folding `fixup!` and
+    /// `squash!` commits into their targets without opening an editor.
+    fn rebase_autosquash_with_output(&self) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "git rebase --autosquash is not implemented for this backend",
+        )))
+    }
     fn rebase_continue_with_output(&self) -> Result<CommandOutput> {
         Err(Error::new(ErrorKind::Unsupported(
             "git rebase --continue is not implemented for this backend",
@@ -363,6 +661,20 @@ pub trait GitRepository: Send + Sync {
     fn rebase_in_progress(&self) -> Result<bool> {
         Ok(false)
     }
+    /// Detect an interrupted rebase, merge, cherry-pick, revert, `git am`
+    /// session or bisect, with progress where git records it.
+    fn operation_state(&self) -> Result<Option<RepoOperationState>> {
+        Ok(None)
+    }
+    fn operation_command_with_output(
+        &self,
+        _kind: RepoOperationKind,
+        _action: RepoOperationAction,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "continuing or aborting git operations is not implemented for this backend",
+        )))
+    }
 
     fn merge_commit_message(&self) -> Result<Option<String>> {
         Ok(None)
@@ -523,6 +835,31 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Rename a local branch. `force` overwrites an existing `new_name`.
+    fn rename_branch_with_output(
+        &self,
+        _name: &str,
+        _new_name: &str,
+        _force: bool,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "branch rename is not implemented for this backend",
+        )))
+    }
+
+    /// Move `branch`'s upstream to `new_name` on the same remote: push the
+    /// branch there, delete the old remote branch and track the new one.
+    fn rename_upstream_branch_with_output(
+        &self,
+        _branch: &str,
+        _upstream: &Upstream,
+        _new_name: &str,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "remote branch rename is not implemented for this backend",
+        )))
+    }
+
     fn commit_amend_with_output(&self, message: &str) -> Result<CommandOutput> {
         self.commit_amend(message)?;
         Ok(CommandOutput::empty_success("git commit --amend"))
@@ -552,12 +889,82 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Lines of `paths`, as of commit `id`, that match `query`.
+    fn search_commit_files(
+        &self,
+        _id: &CommitId,
+        _paths: &[&Path],
+        _query: &str,
+        _options: TextSearchOptions,
+    ) -> Result<Vec<CommitFileSearchMatch>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "commit file search is not implemented for this backend",
+        )))
+    }
+
     fn blame_file(&self, _path: &Path, _rev: Option<&str>) -> Result<Vec<BlameLine>> {
         Err(Error::new(ErrorKind::Unsupported(
             "git blame is not implemented for this backend",
         )))
     }
 
+    fn blame_file_with_options(
+        &self,
+        path: &Path,
+        rev: Option<&str>,
+        options: BlameOptions,
+    ) -> Result<Vec<BlameLine>> {
+        if options == BlameOptions::default() {
+            return self.blame_file(path, rev);
+        }
+        Err(Error::new(ErrorKind::Unsupported(
+            "git blame options are not implemented for this backend",
+        )))
+    }
+
+    /// Entries directly under `dir` (empty for the root) in the tree of `rev`,
+    /// sorted with [`sort_revision_tree_entries`].
+    fn list_revision_tree(&self, _rev: &str, _dir: &Path) -> Result<Vec<RevisionTreeEntry>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "revision tree browsing is not implemented for this backend",
+        )))
+    }
+
+    /// Contents of the file at `path` as committed in `rev`.
+    fn read_revision_file(&self, _rev: &str, _path: &Path) -> Result<Vec<u8>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "reading files at a revision is not implemented for this backend",
+        )))
+    }
+
+    /// Unified diff from `path` as committed in `rev` to its working tree copy.
+    fn diff_revision_to_worktree_unified(&self, _rev: &str, _path: &Path) -> Result<String> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "diffing a revision against the working tree is not implemented for this backend",
+        )))
+    }
+
+    /// Write the file or directory at `path` in `rev` to `dest`, which must not
+    /// exist yet. An empty `path` saves the whole tree.
+    fn save_revision_path_with_output(
+        &self,
+        _rev: &str,
+        _path: &Path,
+        _dest: &Path,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "saving files from a revision is not implemented for this backend",
+        )))
+    }
+
+    /// Apply rename/copy thresholds to later status, commit details and diff
+    /// calls. Backends without rename detection ignore this.
+    fn set_rename_detection(&self, _detection: RenameDetection) {}
+
+    /// Apply whitespace and ignore-regex options to later unified diffs.
+    /// Backends that cannot filter diffs ignore this.
+    fn set_diff_ignore_options(&self, _options: DiffIgnoreOptions) {}
+
     fn checkout_conflict_side(&self, _path: &Path, _side: ConflictSide) -> Result<CommandOutput> {
         Err(Error::new(ErrorKind::Unsupported(
             "conflict resolution is not implemented for this backend",
@@ -605,12 +1012,69 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Write `ids` as a `git format-patch` series, oldest commit first. Without
+    /// a cover letter `dest` is a single mbox ready for `git am`; with one,
+    /// `dest` is a directory of numbered patch files led by the cover letter.
+    fn export_patch_series_with_output(
+        &self,
+        _ids: &[CommitId],
+        _dest: &Path,
+        _cover_letter: bool,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "patch series export is not implemented for this backend",
+        )))
+    }
+
+    /// Cherry-pick `ids` onto HEAD in topological order, whatever order they
+    /// were selected in.
+    fn cherry_pick_commits_with_output(&self, _ids: &[CommitId]) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "cherry-picking several commits is not implemented for this backend",
+        )))
+    }
+
+    /// Revert `ids` newest first, one revert commit each.
+    fn revert_commits_with_output(&self, _ids: &[CommitId]) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "reverting several commits is not implemented for this backend",
+        )))
+    }
+
+    /// Replace a contiguous run of non-merge commits on the current branch
+    /// with one commit and replay the commits above it.
+    fn squash_commits_with_output(&self, _ids: &[CommitId]) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "squashing commits is not implemented for this backend",
+        )))
+    }
+
     fn apply_patch_with_output(&self, _patch: &Path) -> Result<CommandOutput> {
         Err(Error::new(ErrorKind::Unsupported(
             "patch apply is not implemented for this backend",
         )))
     }
 
+    /// Parse the patches in an mbox file or a directory of patch files
+    /// without applying them, for previewing an import.
+    fn read_patch_series(&self, _source: &Path) -> Result<Vec<MailboxPatch>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "reading patch series is not implemented for this backend",
+        )))
+    }
+
+    /// Apply an mbox file or a directory of patch files with `git am`. A patch
+    /// that does not apply stops the series for conflict resolution.
+    fn apply_patch_series_with_output(
+        &self,
+        _source: &Path,
+        _three_way: bool,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "patch series apply is not implemented for this backend",
+        )))
+    }
+
     fn apply_unified_patch_to_index_with_output(
         &self,
         _patch: &str,
@@ -659,6 +1123,43 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Lock a linked worktree so `git worktree prune` and `remove` leave it alone.
+    fn lock_worktree_with_output(
+        &self,
+        _path: &Path,
+        _reason: Option<&str>,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "worktree lock is not implemented for this backend",
+        )))
+    }
+
+    fn unlock_worktree_with_output(&self, _path: &Path) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "worktree unlock is not implemented for this backend",
+        )))
+    }
+
+    fn move_worktree_with_output(&self, _path: &Path, _new_path: &Path) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "worktree move is not implemented for this backend",
+        )))
+    }
+
+    /// Remove administrative data for worktrees whose directories are gone.
+    fn prune_worktrees_with_output(&self) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "worktree prune is not implemented for this backend",
+        )))
+    }
+
+    /// Dirty-file count and upstream divergence of the worktree at `path`.
+    fn worktree_summary(&self, _path: &Path) -> Result<WorktreeSummary> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "worktree summary is not implemented for this backend",
+        )))
+    }
+
     fn list_submodules(&self) -> Result<Vec<Submodule>> {
         Err(Error::new(ErrorKind::Unsupported(
             "submodule listing is not implemented for this backend",
@@ -745,6 +1246,42 @@ pub trait GitRepository: Send + Sync {
         )))
     }
 
+    /// Copy submodule URLs from `.gitmodules` into the local config.
+    fn sync_submodules_with_output(&self) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "submodule sync is not implemented for this backend",
+        )))
+    }
+
+    /// Set the branch `update --remote` follows, or reset it to the remote
+    /// default when `branch` is `None`.
+    fn set_submodule_branch_with_output(
+        &self,
+        _path: &Path,
+        _branch: Option<&str>,
+    ) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "submodule branch tracking is not implemented for this backend",
+        )))
+    }
+
+    /// Move the submodule to the tip of its tracked remote branch.
+    fn update_submodule_remote_with_output(&self, _path: &Path) -> Result<CommandOutput> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "submodule update is not implemented for this backend",
+        )))
+    }
+
+    /// Run `command` in each initialized submodule, nested ones included.
+    fn run_in_submodules(
+        &self,
+        _command: &SubmoduleBatchCommand,
+    ) -> Result<Vec<SubmoduleCommandResult>> {
+        Err(Error::new(ErrorKind::Unsupported(
+            "submodule commands are not implemented for this backend",
+        )))
+    }
+
     fn discard_worktree_changes(&self, paths: &[&Path]) -> Result<()>;
 }
 
@@ -791,7 +1328,8 @@ pub trait GitBackend: Send + Sync {
 #[cfg(test)]
 mod tests {
     use super::{
-        BlameLine, CommandOutput, GitRepository, decode_utf8_optional,
+        BlameLine, BlameOptions, CommandOutput, DiffIgnoreOptions, DiffWhitespaceIgnore,
+        GitRepository, RenameDetection, SubmoduleBatchCommand, decode_utf8_optional,
         validate_conflict_resolution_text,
     };
     use crate::domain::{
@@ -1152,6 +1690,9 @@ mod tests {
             author_time_unix: Some(1_700_000_000),
             summary: "Initial import".into(),
             line: "hello".to_string(),
+            original_path: None,
+            original_line: Some(1),
+            has_parent: true,
         };
 
         let cloned = line.clone();
@@ -1161,6 +1702,100 @@ mod tests {
         assert_eq!(line.line, cloned.line);
     }
 
+    #[test]
+    fn rename_detection_git_args_include_copies_only_when_enabled() {
+        assert_eq!(RenameDetection::default().git_args(), vec!["-M50%"]);
+        assert_eq!(
+            RenameDetection {
+                rename_threshold: 90,
+                detect_copies: true,
+                copy_threshold: 120,
+            }
+            .git_args(),
+            vec!["-M90%", "-C100%"]
+        );
+    }
+
+    #[test]
+    fn submodule_batch_command_display_lists_git_args() {
+        assert_eq!(SubmoduleBatchCommand::Fetch.display(), "git fetch");
+        assert_eq!(SubmoduleBatchCommand::Pull.git_args(), vec!["pull"]);
+        assert_eq!(
+            SubmoduleBatchCommand::Git {
+                args: vec!["status".to_string(), "--short".to_string()],
+            }
+            .display(),
+            "git status --short"
+        );
+    }
+
+    #[test]
+    fn submodule_batch_command_parses_command_line() {
+        assert_eq!(
+            SubmoduleBatchCommand::from_command_line("git log -1 --format='%h %s'"),
+            Some(SubmoduleBatchCommand::Git {
+                args: vec!["log".into(), "-1".into(), "--format=%h %s".into()],
+            })
+        );
+        assert_eq!(
+            SubmoduleBatchCommand::from_command_line("  status  \"\" "),
+            Some(SubmoduleBatchCommand::Git {
+                args: vec!["status".into(), String::new()],
+            })
+        );
+        assert_eq!(SubmoduleBatchCommand::from_command_line("git"), None);
+        assert_eq!(SubmoduleBatchCommand::from_command_line("   "), None);
+    }
+
+    #[test]
+    fn diff_ignore_options_git_args_follow_git_flag_order() {
+        assert!(DiffIgnoreOptions::default().git_args().is_empty());
+        assert_eq!(
+            DiffIgnoreOptions {
+                whitespace: DiffWhitespaceIgnore::Change,
+                ignore_blank_lines: true,
+                ignore_cr_at_eol: true,
+                ignore_matching_lines: vec!["^// generated at .*$".to_string()],
+            }
+            .git_args(),
+            vec![
+                "-b",
+                "--ignore-blank-lines",
+                "--ignore-cr-at-eol",
+                "-I^// generated at .*$",
+            ]
+        );
+    }
+
+    #[test]
+    fn blame_file_with_options_falls_back_only_for_default_options() {
+        let repo = RecordingHistoryModeRepo::new();
+        let path = Path::new("src/lib.rs");
+
+        let err = repo
+            .blame_file_with_options(path, None, BlameOptions::default())
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::Unsupported("git blame is not implemented for this backend")
+        ));
+
+        let err = repo
+            .blame_file_with_options(
+                path,
+                None,
+                BlameOptions {
+                    ignore_whitespace: true,
+                    ..BlameOptions::default()
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::Unsupported("git blame options are not implemented for this backend")
+        ));
+    }
+
     #[test]
     fn log_history_mode_page_delegates_current_branch_modes_to_head_log() {
         let repo = RecordingHistoryModeRepo::new();
@@ -0,0 +1,291 @@
+use super::GixRepo;
+use crate::util::{bytes_to_text_preserving_utf8, git_command_failed_error, run_git_capture_bytes};
+use gitcomet_core::domain::{DiffAttributes, FileDiffTextSource};
+use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::process::background_command;
+use gitcomet_core::services::Result;
+use rustc_hash::FxHashSet as HashSet;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DIFF_ATTRIBUTE_NAMES: [&str; 3] = ["diff", "binary", "linguist-generated"];
+const CHECK_ATTR_PATHS_PER_BATCH: usize = 512;
+
+/// Value of one attribute as printed by `git check-attr`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum AttrValue {
+    Set,
+    Unset,
+    Value(String),
+}
+
+fn parse_attr_value(value: &str) -> Option<AttrValue> {
+    match value {
+        "unspecified" | "" => None,
+        "set" => Some(AttrValue::Set),
+        "unset" => Some(AttrValue::Unset),
+        value => Some(AttrValue::Value(value.to_string())),
+    }
+}
+
+/// Parse `git check-attr -z` output: repeated `<path> NUL <attr> NUL <value> NUL`.
+fn parse_check_attr_z(output: &[u8]) -> Vec<(PathBuf, String, AttrValue)> {
+    let mut fields = output.split(|&b| b == 0);
+    let mut entries = Vec::new();
+    while let (Some(path), Some(attr), Some(value)) = (fields.next(), fields.next(), fields.next())
+    {
+        if path.is_empty() {
+            break;
+        }
+        let value = bytes_to_text_preserving_utf8(value);
+        let Some(value) = parse_attr_value(&value) else {
+            continue;
+        };
+        let path = PathBuf::from(bytes_to_text_preserving_utf8(path));
+        entries.push((path, bytes_to_text_preserving_utf8(attr), value));
+    }
+    entries
+}
+
+fn attr_value_is_true(value: &AttrValue) -> bool {
+    match value {
+        AttrValue::Set => true,
+        AttrValue::Unset => false,
+        AttrValue::Value(value) => !matches!(value.as_str(), "false" | "0" | "no" | "off"),
+    }
+}
+
+/// Attribute state before the diff driver's config is consulted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct PathDiffAttrs {
+    binary: bool,
+    driver: Option<String>,
+    generated: bool,
+}
+
+fn path_diff_attrs(entries: &[(PathBuf, String, AttrValue)]) -> PathDiffAttrs {
+    let mut attrs = PathDiffAttrs::default();
+    for (_, attr, value) in entries {
+        match (attr.as_str(), value) {
+            ("binary", value) => attrs.binary |= attr_value_is_true(value),
+            ("diff", AttrValue::Unset) => attrs.binary = true,
+            ("diff", AttrValue::Value(driver)) => attrs.driver = Some(driver.clone()),
+            ("linguist-generated", value) => attrs.generated = attr_value_is_true(value),
+            _ => {}
+        }
+    }
+    attrs
+}
+
+impl GixRepo {
+    /// Resolve the `.gitattributes` that change how `path` is diffed,
+    /// including the `textconv` command of its `diff=<driver>`.
+    pub(super) fn diff_attributes_impl(&self, path: &Path) -> Result<DiffAttributes> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("check-attr")
+            .arg("-z")
+            .args(DIFF_ATTRIBUTE_NAMES)
+            .arg("--")
+            .arg(path);
+        let output = run_git_capture_bytes(cmd, "git check-attr")?;
+        let attrs = path_diff_attrs(&parse_check_attr_z(&output));
+
+        let mut textconv = None;
+        let mut binary = attrs.binary;
+        if let Some(driver) = attrs.driver.as_deref() {
+            let repo = self._repo.to_thread_local();
+            let config = repo.config_snapshot();
+            textconv = config
+                .plumbing()
+                .string(format!("diff.{driver}.textconv").as_str())
+                .map(|value| bytes_to_text_preserving_utf8(value.as_ref().as_ref()))
+                .filter(|value| !value.trim().is_empty());
+            if config
+                .plumbing()
+                .boolean(format!("diff.{driver}.binary").as_str())
+                .and_then(|value| value.ok())
+                .unwrap_or(false)
+            {
+                binary = true;
+            }
+        }
+
+        Ok(DiffAttributes {
+            binary,
+            textconv,
+            generated: attrs.generated,
+        })
+    }
+
+    /// The subset of `paths` marked `linguist-generated` by the
+    /// `.gitattributes` of `rev`. Git before 2.40 has no `check-attr
+    /// --source`, so those fall back to the worktree's attributes. The flag
+    /// only collapses diffs, so a failed lookup is logged and leaves every
+    /// path unmarked.
+    pub(super) fn linguist_generated_paths(&self, paths: &[&Path], rev: &str) -> HashSet<PathBuf> {
+        self.check_attr_generated(paths, Some(rev))
+            .or_else(|_| self.check_attr_generated(paths, None))
+            .unwrap_or_else(|err| {
+                eprintln!("gitcomet-git-gix: linguist-generated lookup at {rev} failed: {err}");
+                HashSet::default()
+            })
+    }
+
+    fn check_attr_generated(
+        &self,
+        paths: &[&Path],
+        source: Option<&str>,
+    ) -> Result<HashSet<PathBuf>> {
+        let mut generated = HashSet::default();
+        for batch in paths.chunks(CHECK_ATTR_PATHS_PER_BATCH) {
+            let mut cmd = self.git_workdir_cmd();
+            cmd.arg("check-attr");
+            if let Some(source) = source {
+                cmd.arg(format!("--source={source}"));
+            }
+            cmd.arg("-z")
+                .arg("linguist-generated")
+                .arg("--")
+                .args(batch);
+            let output = run_git_capture_bytes(cmd, "git check-attr linguist-generated")?;
+            generated.extend(
+                parse_check_attr_z(&output)
+                    .into_iter()
+                    .filter(|(_, _, value)| attr_value_is_true(value))
+                    .map(|(path, _, _)| path),
+            );
+        }
+        Ok(generated)
+    }
+
+    /// Run a diff driver's `textconv` command on `source` and cache its output
+    /// next to the other diff sources.
+    pub(super) fn textconv_file_diff_source(
+        &self,
+        textconv: &str,
+        source: FileDiffTextSource,
+    ) -> Result<FileDiffTextSource> {
+        let mut hasher = rustc_hash::FxHasher::default();
+        textconv.hash(&mut hasher);
+        source.identity.hash(&mut hasher);
+        let identity = format!("{:016x}", hasher.finish());
+        let cache_path =
+            std::env::temp_dir().join(format!("gitcomet-diff-textconv-{identity}.txt"));
+
+        if !std::fs::metadata(&cache_path).is_ok_and(|m| m.is_file()) {
+            let mut cmd = textconv_command(textconv, &source.path);
+            cmd.current_dir(&self.spec.workdir);
+            let output = cmd
+                .output()
+                .map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+            if !output.status.success() {
+                return Err(git_command_failed_error(
+                    &format!("textconv {textconv}"),
+                    output,
+                ));
+            }
+            let mut tmp_file = tempfile::NamedTempFile::new_in(std::env::temp_dir())
+                .map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+            tmp_file
+                .write_all(&output.stdout)
+                .map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+            tmp_file
+                .persist(&cache_path)
+                .map_err(|e| Error::new(ErrorKind::Io(e.error.kind())))?;
+        }
+
+        Ok(FileDiffTextSource::with_identity(
+            cache_path,
+            format!("textconv:{identity}"),
+        ))
+    }
+}
+
+/// Git runs textconv through the shell with the file appended as `$1`.
+#[cfg(windows)]
+fn textconv_command(textconv: &str, path: &Path) -> Command {
+    let mut command = background_command("cmd");
+    command
+        .arg("/C")
+        .arg(format!("{textconv} \"{}\"", path.display()));
+    command
+}
+
+#[cfg(not(windows))]
+fn textconv_command(textconv: &str, path: &Path) -> Command {
+    let mut command = background_command("sh");
+    command
+        .arg("-c")
+        .arg(format!("{textconv} \"$@\""))
+        .arg(textconv)
+        .arg(path);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_attr_output(entries: &[(&str, &str, &str)]) -> Vec<u8> {
+        let mut output = Vec::new();
+        for (path, attr, value) in entries {
+            for field in [path, attr, value] {
+                output.extend_from_slice(field.as_bytes());
+                output.push(0);
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn parse_check_attr_z_skips_unspecified_attributes() {
+        let output = check_attr_output(&[
+            ("a.bin", "diff", "unset"),
+            ("a.bin", "binary", "unspecified"),
+            ("doc.docx", "diff", "word"),
+        ]);
+        assert_eq!(
+            parse_check_attr_z(&output),
+            vec![
+                (PathBuf::from("a.bin"), "diff".to_string(), AttrValue::Unset),
+                (
+                    PathBuf::from("doc.docx"),
+                    "diff".to_string(),
+                    AttrValue::Value("word".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn path_diff_attrs_treats_unset_diff_as_binary() {
+        let output = check_attr_output(&[
+            ("lock.json", "diff", "unset"),
+            ("lock.json", "linguist-generated", "true"),
+        ]);
+        assert_eq!(
+            path_diff_attrs(&parse_check_attr_z(&output)),
+            PathDiffAttrs {
+                binary: true,
+                driver: None,
+                generated: true,
+            }
+        );
+
+        let output = check_attr_output(&[
+            ("doc.docx", "diff", "word"),
+            ("doc.docx", "binary", "set"),
+            ("doc.docx", "linguist-generated", "false"),
+        ]);
+        assert_eq!(
+            path_diff_attrs(&parse_check_attr_z(&output)),
+            PathDiffAttrs {
+                binary: true,
+                driver: Some("word".to_string()),
+                generated: false,
+            }
+        );
+    }
+}
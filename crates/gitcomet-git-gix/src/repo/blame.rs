@@ -3,11 +3,12 @@ use super::{
     conflict_stages::{gix_index_stage_blob_bytes_optional, gix_index_stage_exists},
     oid_to_arc_str,
 };
-use crate::util::{bytes_to_text_preserving_utf8, run_git_with_output};
+use crate::util::{bytes_to_text_preserving_utf8, run_git_capture_bytes, run_git_with_output};
 use gitcomet_core::error::{Error, ErrorKind};
-use gitcomet_core::services::{BlameLine, CommandOutput, ConflictSide, Result};
+use gitcomet_core::services::{BlameLine, BlameOptions, CommandOutput, ConflictSide, Result};
 use gix::bstr::ByteSlice as _;
 use rustc_hash::FxHashMap as HashMap;
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::fs;
 use std::path::Path;
@@ -18,6 +19,7 @@ struct BlameCommitMetadata {
     author: Arc<str>,
     author_time_unix: Option<i64>,
     summary: Arc<str>,
+    has_parent: bool,
 }
 
 fn blame_commit_metadata<'a>(
@@ -53,6 +55,7 @@ fn blame_commit_metadata<'a>(
                 author,
                 author_time_unix,
                 summary,
+                has_parent: commit.parent_ids().next().is_some(),
             }))
         }
     }
@@ -97,7 +100,235 @@ fn blame_blob_lines(blob: &[u8]) -> BlameBlobLines<'_> {
     BlameBlobLines { blob, cursor: 0 }
 }
 
+const DEFAULT_IGNORE_REVS_FILE: &str = ".git-blame-ignore-revs";
+
+/// Where blame's ignore-revs list comes from. `blame.ignoreRevsFile` is
+/// honoured by git itself; the conventional `.git-blame-ignore-revs` file has
+/// to be passed explicitly when nothing is configured.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum IgnoreRevsSource {
+    Config,
+    DefaultFile,
+}
+
+/// Undo git's C-style path quoting.
+///
+/// Porcelain output wraps names containing `"`, `\`, control characters or
+/// (without `core.quotePath=false`) non-ASCII bytes in double quotes, with
+/// backslash and octal escapes inside. Unquoted names are returned as-is.
+fn unquote_c_style_path(value: &[u8]) -> Cow<'_, [u8]> {
+    let Some(inner) = value
+        .strip_prefix(b"\"")
+        .and_then(|rest| rest.strip_suffix(b"\""))
+    else {
+        return Cow::Borrowed(value);
+    };
+
+    let mut out = Vec::with_capacity(inner.len());
+    let mut bytes = inner.iter().copied();
+    while let Some(byte) = bytes.next() {
+        if byte != b'\\' {
+            out.push(byte);
+            continue;
+        }
+        match bytes.next() {
+            Some(b'a') => out.push(0x07),
+            Some(b'b') => out.push(0x08),
+            Some(b't') => out.push(b'\t'),
+            Some(b'n') => out.push(b'\n'),
+            Some(b'v') => out.push(0x0b),
+            Some(b'f') => out.push(0x0c),
+            Some(b'r') => out.push(b'\r'),
+            Some(digit @ b'0'..=b'3') => {
+                let mut code = digit - b'0';
+                for _ in 0..2 {
+                    match bytes.next() {
+                        Some(digit @ b'0'..=b'7') => code = code * 8 + (digit - b'0'),
+                        // Git never emits a short octal escape; leave the
+                        // name alone rather than guess.
+                        _ => return Cow::Borrowed(value),
+                    }
+                }
+                out.push(code);
+            }
+            Some(other) => out.push(other),
+            None => out.push(b'\\'),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Parse `git blame --line-porcelain` output.
+///
+/// Every line record starts with `<sha> <orig-line> <final-line>[ <count>]`,
+/// followed by `key value` headers and finally the tab-prefixed line text.
+/// `original_path` is only set when the line was traced to a file other than
+/// `blamed_path`.
+fn parse_blame_porcelain(output: &[u8], blamed_path: &str) -> Result<Vec<BlameLine>> {
+    fn malformed(line: &[u8]) -> Error {
+        Error::new(ErrorKind::Backend(format!(
+            "unexpected git blame porcelain line: {}",
+            bytes_to_text_preserving_utf8(line)
+        )))
+    }
+
+    let mut metadata_cache: HashMap<Vec<u8>, BlameCommitMetadata> = HashMap::default();
+    let mut path_cache: HashMap<Vec<u8>, Arc<str>> = HashMap::default();
+    let mut lines = Vec::new();
+
+    let mut commit_id: Option<&[u8]> = None;
+    let mut original_line = None;
+    let mut author = None;
+    let mut author_time_unix = None;
+    let mut summary = None;
+    let mut filename = None;
+    let mut has_previous = false;
+
+    for raw in output.split(|byte| *byte == b'\n') {
+        if let Some(text) = raw.strip_prefix(b"\t") {
+            let id = commit_id.take().ok_or_else(|| malformed(raw))?;
+            let metadata = match metadata_cache.entry(id.to_vec()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(BlameCommitMetadata {
+                    commit_id_text: bstr_to_arc_str(id),
+                    author: author.take().map(bstr_to_arc_str).unwrap_or_default(),
+                    author_time_unix: author_time_unix.take(),
+                    summary: summary.take().map(bstr_to_arc_str).unwrap_or_default(),
+                    // Porcelain reports parents per line via `previous`.
+                    has_parent: false,
+                }),
+            };
+            let original_path = filename
+                .take()
+                .map(unquote_c_style_path)
+                .filter(|name| name.as_ref() != blamed_path.as_bytes())
+                .map(|name| {
+                    path_cache
+                        .entry(name.to_vec())
+                        .or_insert_with(|| bstr_to_arc_str(&name))
+                        .clone()
+                });
+            lines.push(BlameLine {
+                commit_id: metadata.commit_id_text.clone(),
+                author: metadata.author.clone(),
+                author_time_unix: metadata.author_time_unix,
+                summary: metadata.summary.clone(),
+                line: blame_line_text(text),
+                original_path,
+                original_line: original_line.take(),
+                has_parent: std::mem::take(&mut has_previous),
+            });
+            continue;
+        }
+        if raw.is_empty() {
+            continue;
+        }
+
+        if commit_id.is_none() {
+            let mut fields = raw.split(|byte| *byte == b' ');
+            let id = fields
+                .next()
+                .filter(|id| !id.is_empty() && id.iter().all(|byte| byte.is_ascii_hexdigit()));
+            let Some(id) = id else {
+                return Err(malformed(raw));
+            };
+            commit_id = Some(id);
+            original_line = fields
+                .next()
+                .and_then(|n| std::str::from_utf8(n).ok())
+                .and_then(|n| n.parse::<u32>().ok());
+            author = None;
+            author_time_unix = None;
+            summary = None;
+            filename = None;
+            has_previous = false;
+            continue;
+        }
+
+        let (key, value) = match raw.iter().position(|byte| *byte == b' ') {
+            Some(ix) => (&raw[..ix], &raw[ix + 1..]),
+            None => (raw, &b""[..]),
+        };
+        match key {
+            b"author" => author = Some(value),
+            b"author-time" => {
+                author_time_unix = std::str::from_utf8(value)
+                    .ok()
+                    .and_then(|value| value.parse::<i64>().ok());
+            }
+            b"summary" => summary = Some(value),
+            b"filename" => filename = Some(value),
+            // Only present when the commit has a parent that has the file.
+            b"previous" => has_previous = true,
+            _ => {}
+        }
+    }
+
+    Ok(lines)
+}
+
 impl GixRepo {
+    fn blame_ignore_revs_source(&self) -> Option<IgnoreRevsSource> {
+        let repo = self._repo.to_thread_local();
+        let configured = repo
+            .config_snapshot()
+            .plumbing()
+            .string("blame.ignoreRevsFile")
+            .is_some_and(|value| !value.is_empty());
+        if configured {
+            Some(IgnoreRevsSource::Config)
+        } else if self.spec.workdir.join(DEFAULT_IGNORE_REVS_FILE).is_file() {
+            Some(IgnoreRevsSource::DefaultFile)
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn blame_file_with_options_impl(
+        &self,
+        path: &Path,
+        rev: Option<&str>,
+        options: BlameOptions,
+    ) -> Result<Vec<BlameLine>> {
+        let ignore_revs = if options.use_ignore_revs {
+            self.blame_ignore_revs_source()
+        } else {
+            None
+        };
+        let needs_cli = options.ignore_whitespace
+            || options.detect_moves
+            || options.detect_copies
+            || ignore_revs.is_some();
+        if !needs_cli {
+            return self.blame_file_impl(path, rev);
+        }
+
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("blame").arg("--line-porcelain");
+        if options.ignore_whitespace {
+            cmd.arg("-w");
+        }
+        if options.detect_moves {
+            cmd.arg("-M");
+        }
+        if options.detect_copies {
+            cmd.arg("-C");
+        }
+        if !options.use_ignore_revs {
+            cmd.arg("--ignore-revs-file=");
+        } else if ignore_revs == Some(IgnoreRevsSource::DefaultFile) {
+            cmd.arg("--ignore-revs-file").arg(DEFAULT_IGNORE_REVS_FILE);
+        }
+        if let Some(rev) = rev {
+            cmd.arg(rev);
+        }
+        cmd.arg("--").arg(path);
+        let output = run_git_capture_bytes(cmd, "git blame --line-porcelain")?;
+
+        let blamed_path = path.to_string_lossy().replace('\\', "/");
+        parse_blame_porcelain(&output, &blamed_path)
+    }
+
     pub(super) fn blame_file_impl(&self, path: &Path, rev: Option<&str>) -> Result<Vec<BlameLine>> {
         const BLOB_LINE_MISMATCH: &str = "gix blame blob line count did not match blame entries";
 
@@ -140,19 +371,23 @@ impl GixRepo {
                 blob_line_ix += 1;
             }
             let metadata = blame_commit_metadata(&repo, &mut metadata_cache, entry.commit_id)?;
-            for _ in 0..entry_len {
+            for line_offset in 0..entry_len {
                 let Some(line) = blob_lines.next() else {
                     return Err(Error::new(ErrorKind::Backend(
                         BLOB_LINE_MISMATCH.to_string(),
                     )));
                 };
                 blob_line_ix += 1;
+                let original_line = entry.start_in_source_file as usize + line_offset + 1;
                 lines.push(BlameLine {
                     commit_id: metadata.commit_id_text.clone(),
                     author: metadata.author.clone(),
                     author_time_unix: metadata.author_time_unix,
                     summary: metadata.summary.clone(),
                     line: blame_line_text(line),
+                    original_path: None,
+                    original_line: u32::try_from(original_line).ok(),
+                    has_parent: metadata.has_parent,
                 });
             }
         }
@@ -268,4 +503,81 @@ mod tests {
     fn blame_blob_lines_is_empty_for_empty_blob() {
         assert_eq!(blame_blob_lines(b"").count(), 0);
     }
+
+    #[test]
+    fn parse_blame_porcelain_reads_line_records() {
+        let output = b"\
+1111111111111111111111111111111111111111 3 1 2
+author Alice
+author-mail <alice@example.com>
+author-time 1700000000
+author-tz +0000
+summary Move helpers
+previous 2222222222222222222222222222222222222222 src/old.rs
+filename src/old.rs
+\tfn helper() {}
+1111111111111111111111111111111111111111 4 2
+author Alice
+author-mail <alice@example.com>
+author-time 1700000000
+author-tz +0000
+summary Move helpers
+filename src/new.rs
+\tfn other() {}\r
+";
+        let lines = parse_blame_porcelain(output, "src/new.rs").unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].commit_id.as_ref(),
+            "1111111111111111111111111111111111111111"
+        );
+        assert_eq!(lines[0].author.as_ref(), "Alice");
+        assert_eq!(lines[0].author_time_unix, Some(1_700_000_000));
+        assert_eq!(lines[0].summary.as_ref(), "Move helpers");
+        assert_eq!(lines[0].line, "fn helper() {}");
+        assert_eq!(lines[0].original_path.as_deref(), Some("src/old.rs"));
+        assert_eq!(lines[0].original_line, Some(3));
+        assert!(lines[0].has_parent);
+        assert_eq!(lines[1].line, "fn other() {}");
+        assert!(!lines[1].has_parent);
+        assert_eq!(lines[1].original_path, None);
+        assert_eq!(lines[1].original_line, Some(4));
+        assert!(Arc::ptr_eq(&lines[0].author, &lines[1].author));
+    }
+
+    #[test]
+    fn parse_blame_porcelain_unquotes_c_style_filenames() {
+        let output = b"\
+1111111111111111111111111111111111111111 1 1 1
+author Alice
+author-time 1700000000
+summary Add notes
+filename \"docs/caf\\303\\251 \\\"notes\\\".txt\"
+\tsame file
+1111111111111111111111111111111111111111 2 2 1
+author Alice
+author-time 1700000000
+summary Add notes
+filename \"docs/tab\\there.txt\"
+\tmoved in
+";
+        let lines = parse_blame_porcelain(output, "docs/caf\u{e9} \"notes\".txt").unwrap();
+        assert_eq!(lines[0].original_path, None);
+        assert_eq!(
+            lines[1].original_path.as_deref(),
+            Some("docs/tab\there.txt")
+        );
+    }
+
+    #[test]
+    fn unquote_c_style_path_leaves_plain_and_malformed_names() {
+        assert_eq!(unquote_c_style_path(b"src/a.rs").as_ref(), b"src/a.rs");
+        assert_eq!(unquote_c_style_path(b"\"a\\\\b\"").as_ref(), b"a\\b");
+        assert_eq!(unquote_c_style_path(b"\"a\\09\"").as_ref(), b"\"a\\09\"");
+    }
+
+    #[test]
+    fn parse_blame_porcelain_rejects_garbage() {
+        assert!(parse_blame_porcelain(b"not a header\n", "a.txt").is_err());
+    }
 }
@@ -0,0 +1,294 @@
+use super::GixRepo;
+use crate::util::{
+    run_git_capture, run_git_raw_output, run_git_with_output, validate_hex_commit_id,
+};
+use gitcomet_core::domain::CommitId;
+use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::services::{CommandOutput, Result};
+use rustc_hash::FxHashSet as HashSet;
+
+/// `git log` format for [`parse_batch_commits`]: id, parents, author and raw
+/// message, one record per commit.
+pub(super) const BATCH_COMMIT_FORMAT: &str = "--format=%H%x00%P%x00%an%x00%ae%x00%ad%x00%B%x1e";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct BatchCommit {
+    pub(super) id: String,
+    pub(super) parents: Vec<String>,
+    pub(super) author_name: String,
+    pub(super) author_email: String,
+    pub(super) author_date: String,
+    pub(super) message: String,
+}
+
+pub(super) fn parse_batch_commits(output: &str) -> Vec<BatchCommit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(6, '\0');
+            let id = fields.next()?.trim();
+            if id.is_empty() {
+                return None;
+            }
+            Some(BatchCommit {
+                id: id.to_string(),
+                parents: fields
+                    .next()?
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect(),
+                author_name: fields.next()?.to_string(),
+                author_email: fields.next()?.to_string(),
+                author_date: fields.next()?.to_string(),
+                message: fields.next()?.trim_end().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Check that `commits` (oldest first) form one first-parent chain without
+/// merges, which is what squashing them into one commit requires.
+fn validate_squash_chain(commits: &[BatchCommit]) -> Result<()> {
+    let invalid = |message: &str| Err(Error::new(ErrorKind::Backend(message.to_string())));
+    if commits.len() < 2 {
+        return invalid("Select at least two commits to squash.");
+    }
+    if commits.iter().any(|commit| commit.parents.len() > 1) {
+        return invalid("Merge commits cannot be squashed.");
+    }
+    if commits[0].parents.is_empty() {
+        return invalid("The root commit cannot be squashed into its parent.");
+    }
+    for pair in commits.windows(2) {
+        if pair[1].parents.first() != Some(&pair[0].id) {
+            return invalid("Only a contiguous run of commits can be squashed.");
+        }
+    }
+    Ok(())
+}
+
+fn concat_outputs(outputs: Vec<CommandOutput>) -> CommandOutput {
+    fn join<'a>(texts: impl Iterator<Item = &'a str>, separator: &str) -> String {
+        texts
+            .filter(|text| !text.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+    CommandOutput {
+        command: join(outputs.iter().map(|o| o.command.as_str()), " && "),
+        stdout: join(outputs.iter().map(|o| o.stdout.as_str()), "\n"),
+        stderr: join(outputs.iter().map(|o| o.stderr.as_str()), "\n"),
+        exit_code: outputs.last().and_then(|output| output.exit_code),
+    }
+}
+
+impl GixRepo {
+    /// `ids` ordered parents before children.
+    pub(super) fn topo_sorted_commits(&self, ids: &[CommitId]) -> Result<Vec<CommitId>> {
+        for id in ids {
+            validate_hex_commit_id(id)?;
+        }
+        let mut seen = HashSet::default();
+        let ids: Vec<&CommitId> = ids.iter().filter(|id| seen.insert(*id)).collect();
+        if ids.len() < 2 {
+            return Ok(ids.into_iter().cloned().collect());
+        }
+
+        // Stop the walk below the selection's common ancestor instead of
+        // walking all of history; unrelated selections fall back to a full walk.
+        let mut base_cmd = self.git_workdir_cmd();
+        base_cmd
+            .arg("merge-base")
+            .arg("--octopus")
+            .args(ids.iter().map(|id| id.as_ref()));
+        let base = run_git_capture(base_cmd, "git merge-base --octopus")
+            .ok()
+            .map(|base| base.trim().to_string())
+            .filter(|base| !base.is_empty());
+
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("rev-list")
+            .arg("--topo-order")
+            .arg("--reverse")
+            .args(ids.iter().map(|id| id.as_ref()));
+        if let Some(base) = base {
+            cmd.arg("--not").arg(format!("{base}^@"));
+        }
+        let output = run_git_capture(cmd, "git rev-list --topo-order")?;
+        let wanted: HashSet<&str> = ids.iter().map(|id| id.as_ref()).collect();
+        let sorted: Vec<CommitId> = output
+            .lines()
+            .filter(|line| wanted.contains(line))
+            .map(|line| CommitId(line.into()))
+            .collect();
+        if sorted.len() != ids.len() {
+            return Err(Error::new(ErrorKind::Backend(
+                "selected commits could not be ordered; use full commit ids".to_string(),
+            )));
+        }
+        Ok(sorted)
+    }
+
+    pub(super) fn cherry_pick_commits_with_output_impl(
+        &self,
+        ids: &[CommitId],
+    ) -> Result<CommandOutput> {
+        let sorted = self.topo_sorted_commits(ids)?;
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("cherry-pick")
+            .arg("--")
+            .args(sorted.iter().map(|id| id.as_ref()));
+        run_git_with_output(cmd, &format!("git cherry-pick ({} commits)", sorted.len()))
+    }
+
+    pub(super) fn revert_commits_with_output_impl(
+        &self,
+        ids: &[CommitId],
+    ) -> Result<CommandOutput> {
+        let sorted = self.topo_sorted_commits(ids)?;
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("revert")
+            .arg("--no-edit")
+            .arg("--")
+            .args(sorted.iter().rev().map(|id| id.as_ref()));
+        run_git_with_output(cmd, &format!("git revert ({} commits)", sorted.len()))
+    }
+
+    /// Build the squashed commit from the newest commit's tree on top of the
+    /// oldest commit's parent, keeping the oldest author, then move the
+    /// commits above the range onto it. The worktree is untouched when the
+    /// range ends at HEAD. Like a reword, the squash refuses to rewrite
+    /// published commits or commits whose signature it would drop.
+    pub(super) fn squash_commits_with_output_impl(
+        &self,
+        ids: &[CommitId],
+    ) -> Result<CommandOutput> {
+        let sorted = self.topo_sorted_commits(ids)?;
+        let mut log_cmd = self.git_workdir_cmd();
+        log_cmd
+            .arg("log")
+            .arg("--no-walk=unsorted")
+            .arg("--date=raw")
+            .arg(BATCH_COMMIT_FORMAT)
+            .args(sorted.iter().map(|id| id.as_ref()));
+        let commits = parse_batch_commits(&run_git_capture(log_cmd, "git log --no-walk")?);
+        validate_squash_chain(&commits)?;
+        let (Some(oldest), Some(newest)) = (commits.first(), commits.last()) else {
+            return Err(Error::new(ErrorKind::Backend(
+                "Select at least two commits to squash.".to_string(),
+            )));
+        };
+
+        let mut ancestor_cmd = self.git_workdir_cmd();
+        ancestor_cmd
+            .arg("merge-base")
+            .arg("--is-ancestor")
+            .arg(&newest.id)
+            .arg("HEAD");
+        let ancestor = run_git_raw_output(ancestor_cmd, "git merge-base --is-ancestor")?;
+        if !ancestor.status.success() {
+            return Err(Error::new(ErrorKind::Backend(
+                "Only commits on the current branch can be squashed.".to_string(),
+            )));
+        }
+
+        let mut head_cmd = self.git_workdir_cmd();
+        head_cmd.arg("rev-parse").arg("HEAD");
+        let head = run_git_capture(head_cmd, "git rev-parse HEAD")?
+            .trim()
+            .to_string();
+        let rewritten: Vec<BatchCommit> = commits[1..]
+            .iter()
+            .cloned()
+            .chain(self.descendants_up_to(&newest.id, &head)?)
+            .collect();
+        if let Some(reason) = self.history_rewrite_blocker("Squashing", oldest, &rewritten)? {
+            return Err(Error::new(ErrorKind::Backend(reason)));
+        }
+
+        let message = commits
+            .iter()
+            .map(|commit| commit.message.as_str())
+            .filter(|message| !message.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let base = &oldest.parents[0];
+        let mut commit_tree = self.git_workdir_cmd();
+        commit_tree
+            .env("GIT_AUTHOR_NAME", &oldest.author_name)
+            .env("GIT_AUTHOR_EMAIL", &oldest.author_email)
+            .env("GIT_AUTHOR_DATE", &oldest.author_date)
+            .arg("commit-tree")
+            .arg(format!("{}^{{tree}}", newest.id))
+            .arg("-p")
+            .arg(base)
+            .arg("-m")
+            .arg(&message);
+        let squashed = run_git_capture(commit_tree, "git commit-tree")?
+            .trim()
+            .to_string();
+
+        let squash_output = CommandOutput {
+            command: format!("Squash {} commits", commits.len()),
+            stdout: format!("Created {squashed}"),
+            stderr: String::new(),
+            exit_code: Some(0),
+        };
+        let move_output = if head == newest.id {
+            let mut cmd = self.git_workdir_cmd();
+            cmd.arg("update-ref")
+                .arg("-m")
+                .arg(format!("squash {} commits", commits.len()))
+                .arg("HEAD")
+                .arg(&squashed)
+                .arg(&head);
+            run_git_with_output(cmd, "git update-ref HEAD")?
+        } else {
+            let mut cmd = self.git_workdir_cmd();
+            cmd.arg("rebase")
+                .arg("--rebase-merges")
+                .arg("--onto")
+                .arg(&squashed)
+                .arg(&newest.id);
+            run_git_with_output(cmd, "git rebase --onto <squashed>")?
+        };
+        Ok(concat_outputs(vec![squash_output, move_output]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(id: &str, parents: &[&str]) -> BatchCommit {
+        BatchCommit {
+            id: id.to_string(),
+            parents: parents.iter().map(|parent| parent.to_string()).collect(),
+            author_name: "A".to_string(),
+            author_email: "a@example.com".to_string(),
+            author_date: "1700000000 +0000".to_string(),
+            message: format!("commit {id}"),
+        }
+    }
+
+    #[test]
+    fn parse_batch_commits_splits_records_and_parents() {
+        let output = "aaa\0p1 p2\0Ann\0ann@x\01 +0000\0subject\n\nbody\n\x1e\nbbb\0\0Bob\0bob@x\02 +0100\0root\n\x1e\n";
+        let commits = parse_batch_commits(output);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].parents, vec!["p1", "p2"]);
+        assert_eq!(commits[0].message, "subject\n\nbody");
+        assert_eq!(commits[1].id, "bbb");
+        assert!(commits[1].parents.is_empty());
+        assert_eq!(commits[1].author_date, "2 +0100");
+    }
+
+    #[test]
+    fn squash_chain_requires_contiguous_non_merge_commits() {
+        assert!(validate_squash_chain(&[commit("b", &["a"]), commit("c", &["b"])]).is_ok());
+        assert!(validate_squash_chain(&[commit("b", &["a"])]).is_err());
+        assert!(validate_squash_chain(&[commit("b", &["a"]), commit("d", &["c"])]).is_err());
+        assert!(validate_squash_chain(&[commit("b", &["a"]), commit("c", &["b", "x"])]).is_err());
+        assert!(validate_squash_chain(&[commit("a", &[]), commit("b", &["a"])]).is_err());
+    }
+}
@@ -4,7 +4,9 @@ use super::{
         ConflictStageData, gix_index_conflict_stage_data, gix_index_stage_object_id_optional,
     },
 };
-use crate::util::{git_command_failed_error, run_git_parsed_stdout, run_git_raw_output};
+use crate::util::{
+    git_command_failed_error, run_git_capture, run_git_parsed_stdout, run_git_raw_output,
+};
 use gitcomet_core::conflict_session::{ConflictPayload, ConflictSession, canonicalize_stage_parts};
 use gitcomet_core::domain::{
     Diff, DiffArea, DiffPreviewTextSide, DiffTarget, FileDiffImage, FileDiffText,
@@ -30,7 +32,11 @@ impl GixRepo {
         cmd.arg("-c").arg("color.ui=false").arg("--no-pager");
 
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree {
+                path,
+                area,
+                old_path,
+            } => {
                 cmd.arg("diff").arg("--no-ext-diff");
                 if matches!(area, DiffArea::Unstaged) {
                     // Match the staged view on Windows by suppressing CR-at-EOL-only
@@ -40,15 +46,29 @@ impl GixRepo {
                 if matches!(area, DiffArea::Staged) {
                     cmd.arg("--cached");
                 }
+                self.push_rename_args(&mut cmd, old_path.as_deref());
+                self.push_ignore_args(&mut cmd);
                 cmd.arg("--").arg(path);
+                if let Some(old_path) = old_path {
+                    cmd.arg(old_path);
+                }
             }
-            DiffTarget::Commit { commit_id, path } => {
+            DiffTarget::Commit {
+                commit_id,
+                path,
+                old_path,
+            } => {
                 cmd.arg("show")
                     .arg("--no-ext-diff")
                     .arg("--pretty=format:")
                     .arg(commit_id.as_ref());
+                self.push_rename_args(&mut cmd, old_path.as_deref());
+                self.push_ignore_args(&mut cmd);
                 if let Some(path) = path {
                     cmd.arg("--").arg(path);
+                    if let Some(old_path) = old_path {
+                        cmd.arg(old_path);
+                    }
                 }
             }
             DiffTarget::CommitRange {
@@ -60,6 +80,7 @@ impl GixRepo {
                     .arg("--no-ext-diff")
                     .arg(from_commit_id.as_ref())
                     .arg(to_commit_id.as_ref());
+                self.push_ignore_args(&mut cmd);
                 if let Some(path) = path {
                     cmd.arg("--").arg(path);
                 }
@@ -69,6 +90,26 @@ impl GixRepo {
         cmd
     }
 
+    /// Rename detection only pairs the two sides when both paths are part of
+    /// the pathspec, so the thresholds are passed along with the source path.
+    fn push_rename_args(&self, cmd: &mut Command, old_path: Option<&Path>) {
+        if old_path.is_none() {
+            return;
+        }
+        match self.rename_detection() {
+            Some(detection) => {
+                cmd.args(detection.git_args());
+            }
+            None => {
+                cmd.arg("-M");
+            }
+        }
+    }
+
+    fn push_ignore_args(&self, cmd: &mut Command) {
+        cmd.args(self.diff_ignore_options().git_args());
+    }
+
     pub(super) fn diff_unified_impl(&self, target: &DiffTarget) -> Result<String> {
         let label = "git diff";
         let output = run_git_raw_output(self.build_unified_diff_command(target), label)?;
@@ -200,9 +241,73 @@ impl GixRepo {
         )))
     }
 
+    /// Repo-relative path for the old side of a working-tree diff, which is
+    /// the rename/copy source when the status entry recorded one.
+    fn old_repo_path(&self, old_path: Option<&Path>, repo_path: &Path) -> Result<PathBuf> {
+        match old_path {
+            Some(old_path) => to_repo_path(old_path, &self.spec.workdir),
+            None => Ok(repo_path.to_path_buf()),
+        }
+    }
+
     pub(super) fn diff_file_text_impl(&self, target: &DiffTarget) -> Result<Option<FileDiffText>> {
+        let Some(text) = self.diff_file_text_sources(target)? else {
+            return Ok(None);
+        };
+        let repo_path = to_repo_path(&text.path, &self.spec.workdir)?;
+        let attributes = self.diff_attributes_impl(&repo_path)?;
+        if let Some(textconv) = attributes.textconv.as_deref() {
+            let convert = |source: Option<FileDiffTextSource>| {
+                source
+                    .map(|source| self.textconv_file_diff_source(textconv, source))
+                    .transpose()
+            };
+            let old = convert(text.old_source)?;
+            let new = convert(text.new_source)?;
+            return Ok(Some(FileDiffText::new_sources(text.path, old, new)));
+        }
+        if attributes.binary
+            || file_diff_source_looks_binary(text.old_source.as_ref())?
+            || file_diff_source_looks_binary(text.new_source.as_ref())?
+        {
+            let old = text
+                .old_source
+                .map(|source| self.binary_file_diff_source(source))
+                .transpose()?;
+            let new = text
+                .new_source
+                .map(|source| self.binary_file_diff_source(source))
+                .transpose()?;
+            return Ok(Some(FileDiffText::new_binary(text.path, old, new)));
+        }
+        Ok(Some(text))
+    }
+
+    /// Binary sides are labelled by object id; worktree content is hashed
+    /// without writing it to the object database.
+    fn binary_file_diff_source(&self, source: FileDiffTextSource) -> Result<FileDiffTextSource> {
+        if source.blob_id().is_some() {
+            return Ok(source);
+        }
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("hash-object")
+            .arg("--no-filters")
+            .arg("--")
+            .arg(&source.path);
+        let blob_id = run_git_capture(cmd, "git hash-object")?;
+        Ok(FileDiffTextSource::with_identity(
+            source.path,
+            format!("blob:{}", blob_id.trim()),
+        ))
+    }
+
+    fn diff_file_text_sources(&self, target: &DiffTarget) -> Result<Option<FileDiffText>> {
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree {
+                path,
+                area,
+                old_path,
+            } => {
                 let full_path = if path.is_absolute() {
                     path.clone()
                 } else {
@@ -214,33 +319,37 @@ impl GixRepo {
 
                 let repo = self._repo.to_thread_local();
                 let repo_path = to_repo_path(path, &self.spec.workdir)?;
+                let old_repo_path = self.old_repo_path(old_path.as_deref(), &repo_path)?;
                 let (old, new) = match area {
                     DiffArea::Unstaged => {
-                        let old = match gix_index_unconflicted_blob_id_optional(&repo, &repo_path)?
-                        {
-                            IndexUnconflictedBlobId::Present(blob_id) => {
-                                self.file_diff_source_from_blob_id(blob_id, &repo_path)?
-                            }
-                            IndexUnconflictedBlobId::Missing => None,
-                            IndexUnconflictedBlobId::Unmerged => {
-                                let ours =
-                                    self.file_diff_source_from_index_stage(&repo, &repo_path, 2)?;
-                                let theirs =
-                                    self.file_diff_source_from_index_stage(&repo, &repo_path, 3)?;
-                                return Ok(Some(FileDiffText::new_sources(
-                                    path.clone(),
-                                    ours,
-                                    theirs,
-                                )));
-                            }
-                        };
+                        let old =
+                            match gix_index_unconflicted_blob_id_optional(&repo, &old_repo_path)? {
+                                IndexUnconflictedBlobId::Present(blob_id) => {
+                                    self.file_diff_source_from_blob_id(blob_id, &old_repo_path)?
+                                }
+                                IndexUnconflictedBlobId::Missing => None,
+                                IndexUnconflictedBlobId::Unmerged => {
+                                    let ours = self
+                                        .file_diff_source_from_index_stage(&repo, &repo_path, 2)?;
+                                    let theirs = self
+                                        .file_diff_source_from_index_stage(&repo, &repo_path, 3)?;
+                                    return Ok(Some(FileDiffText::new_sources(
+                                        path.clone(),
+                                        ours,
+                                        theirs,
+                                    )));
+                                }
+                            };
                         let new =
                             self.file_diff_source_from_worktree_path_optional(&repo, &repo_path)?;
                         (old, new)
                     }
                     DiffArea::Staged => {
-                        let old =
-                            self.file_diff_source_from_revision_path(&repo, "HEAD", &repo_path)?;
+                        let old = self.file_diff_source_from_revision_path(
+                            &repo,
+                            "HEAD",
+                            &old_repo_path,
+                        )?;
                         let new = match gix_index_unconflicted_blob_id_optional(&repo, &repo_path)?
                         {
                             IndexUnconflictedBlobId::Present(blob_id) => {
@@ -257,17 +366,22 @@ impl GixRepo {
 
                 Ok(Some(FileDiffText::new_sources(path.clone(), old, new)))
             }
-            DiffTarget::Commit { commit_id, path } => {
+            DiffTarget::Commit {
+                commit_id,
+                path,
+                old_path,
+            } => {
                 let Some(path) = path else {
                     return Ok(None);
                 };
 
                 let repo = self._repo.to_thread_local();
                 let parent = gix_first_parent_optional(&repo, commit_id.as_ref())?;
+                let old_path = old_path.as_ref().unwrap_or(path);
 
                 let old = match parent {
                     Some(parent) => {
-                        self.file_diff_source_from_revision_path(&repo, &parent, path)?
+                        self.file_diff_source_from_revision_path(&repo, &parent, old_path)?
                     }
                     None => None,
                 };
@@ -302,7 +416,11 @@ impl GixRepo {
         side: DiffPreviewTextSide,
     ) -> Result<Option<std::path::PathBuf>> {
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree {
+                path,
+                area,
+                old_path,
+            } => {
                 let full_path = if path.is_absolute() {
                     path.clone()
                 } else {
@@ -314,58 +432,76 @@ impl GixRepo {
 
                 let repo = self._repo.to_thread_local();
                 let repo_path = to_repo_path(path, &self.spec.workdir)?;
+                let old_repo_path = self.old_repo_path(old_path.as_deref(), &repo_path)?;
                 match (area, side) {
                     (DiffArea::Unstaged, DiffPreviewTextSide::New) => {
                         Ok(worktree_file_path_optional(&self.spec.workdir, &repo_path))
                     }
                     (DiffArea::Unstaged, DiffPreviewTextSide::Old)
                     | (DiffArea::Staged, DiffPreviewTextSide::New) => {
+                        let index_path = if matches!(area, DiffArea::Unstaged) {
+                            &old_repo_path
+                        } else {
+                            &repo_path
+                        };
                         let blob_id =
-                            match gix_index_unconflicted_blob_id_optional(&repo, &repo_path)? {
+                            match gix_index_unconflicted_blob_id_optional(&repo, index_path)? {
                                 IndexUnconflictedBlobId::Present(id) => Some(id),
                                 IndexUnconflictedBlobId::Missing
                                 | IndexUnconflictedBlobId::Unmerged => None,
                             };
                         match blob_id {
                             Some(blob_id) => {
-                                self.cached_preview_blob_file_path(blob_id, &repo_path)
+                                self.cached_preview_blob_file_path(blob_id, index_path)
                             }
                             None => Ok(None),
                         }
                     }
                     (DiffArea::Staged, DiffPreviewTextSide::Old) => {
-                        let blob_id =
-                            gix_revision_path_blob_object_id_optional(&repo, "HEAD", &repo_path)?;
+                        let blob_id = gix_revision_path_blob_object_id_optional(
+                            &repo,
+                            "HEAD",
+                            &old_repo_path,
+                        )?;
                         match blob_id {
                             Some(blob_id) => {
-                                self.cached_preview_blob_file_path(blob_id, &repo_path)
+                                self.cached_preview_blob_file_path(blob_id, &old_repo_path)
                             }
                             None => Ok(None),
                         }
                     }
                 }
             }
-            DiffTarget::Commit { commit_id, path } => {
+            DiffTarget::Commit {
+                commit_id,
+                path,
+                old_path,
+            } => {
                 let Some(path) = path else {
                     return Ok(None);
                 };
 
                 let repo = self._repo.to_thread_local();
-                let blob_id = match side {
-                    DiffPreviewTextSide::New => {
-                        gix_revision_path_blob_object_id_optional(&repo, commit_id.as_ref(), path)?
-                    }
+                let (blob_id, blob_path) = match side {
+                    DiffPreviewTextSide::New => (
+                        gix_revision_path_blob_object_id_optional(&repo, commit_id.as_ref(), path)?,
+                        path,
+                    ),
                     DiffPreviewTextSide::Old => {
                         let Some(parent) = gix_first_parent_optional(&repo, commit_id.as_ref())?
                         else {
                             return Ok(None);
                         };
-                        gix_revision_path_blob_object_id_optional(&repo, &parent, path)?
+                        let old_path = old_path.as_ref().unwrap_or(path);
+                        (
+                            gix_revision_path_blob_object_id_optional(&repo, &parent, old_path)?,
+                            old_path,
+                        )
                     }
                 };
 
                 match blob_id {
-                    Some(blob_id) => self.cached_preview_blob_file_path(blob_id, path),
+                    Some(blob_id) => self.cached_preview_blob_file_path(blob_id, blob_path),
                     None => Ok(None),
                 }
             }
@@ -455,7 +591,11 @@ impl GixRepo {
         target: &DiffTarget,
     ) -> Result<Option<FileDiffImage>> {
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree {
+                path,
+                area,
+                old_path,
+            } => {
                 let full_path = if path.is_absolute() {
                     path.clone()
                 } else {
@@ -467,10 +607,12 @@ impl GixRepo {
 
                 let repo = self._repo.to_thread_local();
                 let repo_path = to_repo_path(path, &self.spec.workdir)?;
+                let old_repo_path = self.old_repo_path(old_path.as_deref(), &repo_path)?;
                 let (old, new) = match area {
                     DiffArea::Unstaged => {
                         let old = match gix_index_unconflicted_image_blob_bytes_optional(
-                            &repo, &repo_path,
+                            &repo,
+                            &old_repo_path,
                         )? {
                             IndexUnconflictedBlob::Present(bytes) => Some(bytes),
                             IndexUnconflictedBlob::Missing => None,
@@ -495,8 +637,11 @@ impl GixRepo {
                         (old, new)
                     }
                     DiffArea::Staged => {
-                        let old =
-                            gix_revision_path_image_blob_bytes_optional(&repo, "HEAD", &repo_path)?;
+                        let old = gix_revision_path_image_blob_bytes_optional(
+                            &repo,
+                            "HEAD",
+                            &old_repo_path,
+                        )?;
                         let new = match gix_index_unconflicted_image_blob_bytes_optional(
                             &repo, &repo_path,
                         )? {
@@ -520,17 +665,22 @@ impl GixRepo {
                     new,
                 }))
             }
-            DiffTarget::Commit { commit_id, path } => {
+            DiffTarget::Commit {
+                commit_id,
+                path,
+                old_path,
+            } => {
                 let Some(path) = path else {
                     return Ok(None);
                 };
 
                 let repo = self._repo.to_thread_local();
                 let parent = gix_first_parent_optional(&repo, commit_id.as_ref())?;
+                let old_path = old_path.as_ref().unwrap_or(path);
 
                 let old = match parent {
                     Some(parent) => {
-                        gix_revision_path_image_blob_bytes_optional(&repo, &parent, path)?
+                        gix_revision_path_image_blob_bytes_optional(&repo, &parent, old_path)?
                     }
                     None => None,
                 };
@@ -633,6 +783,11 @@ impl GixRepo {
     }
 
     fn synthetic_simple_commit_path_diff(&self, target: &DiffTarget) -> Result<Option<Diff>> {
+        // Blank-line and regex filters can drop hunks of an added or deleted
+        // file, so filtered diffs always go through git.
+        if !self.diff_ignore_options().is_empty() {
+            return Ok(None);
+        }
         let repo = self._repo.to_thread_local();
         let Some((path, old_revision, new_revision)) = commit_path_diff_revisions(target, &repo)?
         else {
@@ -685,9 +840,12 @@ fn commit_path_diff_revisions(
     repo: &gix::Repository,
 ) -> Result<Option<(std::path::PathBuf, Option<String>, String)>> {
     match target {
+        // Renames and copies need git's pairing of both sides, so only plain
+        // single-path targets take the synthetic fast path.
         DiffTarget::Commit {
             commit_id,
             path: Some(path),
+            old_path: None,
         } => Ok(Some((
             path.clone(),
             gix_first_parent_optional(repo, commit_id.as_ref())?,
@@ -1091,6 +1249,26 @@ fn copy_and_hash(
     }
 }
 
+/// Git's heuristic: content is binary when a NUL byte appears in its first
+/// 8000 bytes.
+const BINARY_SNIFF_LEN: u64 = 8000;
+
+fn file_diff_source_looks_binary(source: Option<&FileDiffTextSource>) -> Result<bool> {
+    let Some(source) = source else {
+        return Ok(false);
+    };
+    let file = std::fs::File::open(&source.path).map_err(io_err_to_error)?;
+    let mut head = Vec::with_capacity(BINARY_SNIFF_LEN as usize);
+    file.take(BINARY_SNIFF_LEN)
+        .read_to_end(&mut head)
+        .map_err(io_err_to_error)?;
+    Ok(bytes_look_binary(&head))
+}
+
+fn bytes_look_binary(head: &[u8]) -> bool {
+    head.contains(&0)
+}
+
 fn persist_worktree_git_cache_file(
     tmp_file: tempfile::NamedTempFile,
     cache_path: &Path,
@@ -1412,6 +1590,7 @@ mod tests {
             .diff_file_text_impl(&DiffTarget::WorkingTree {
                 path: "vendor/sub".into(),
                 area: DiffArea::Staged,
+                old_path: None,
             })
             .expect("gitlink text diff should not error")
             .expect("file diff text object");
@@ -1420,4 +1599,39 @@ mod tests {
         assert!(diff.old_source.is_none());
         assert!(diff.new_source.is_none());
     }
+
+    #[test]
+    fn diff_file_text_marks_nul_content_binary_with_blob_ids() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        init_test_repo(tmp.path());
+        std::fs::write(tmp.path().join("data.bin"), b"\x00\x01\x02old").expect("write binary");
+        run_git(tmp.path(), &["add", "data.bin"]);
+        run_git(tmp.path(), &["commit", "-m", "add binary"]);
+        std::fs::write(tmp.path().join("data.bin"), b"\x00\x01\x02new").expect("modify binary");
+
+        let repo = open_repo(tmp.path());
+        let diff = repo
+            .diff_file_text_impl(&DiffTarget::WorkingTree {
+                path: "data.bin".into(),
+                area: DiffArea::Unstaged,
+                old_path: None,
+            })
+            .expect("binary text diff should not error")
+            .expect("file diff text object");
+
+        assert!(diff.is_binary());
+        let old_id = diff
+            .old_source
+            .as_ref()
+            .and_then(|source| source.blob_id())
+            .expect("old blob id");
+        let new_id = diff
+            .new_source
+            .as_ref()
+            .and_then(|source| source.blob_id())
+            .expect("worktree blob id");
+        assert_eq!(old_id.len(), 40);
+        assert_eq!(new_id.len(), 40);
+        assert_ne!(old_id, new_id);
+    }
 }
@@ -53,7 +53,9 @@ impl GixRepo {
 
             match entry.kind {
                 FileStatusKind::Conflicted => has_conflicts = true,
-                FileStatusKind::Added if !unstaged_selected.contains(path) => {
+                FileStatusKind::Added | FileStatusKind::Copied
+                    if !unstaged_selected.contains(path) =>
+                {
                     remove_paths.push(path)
                 }
                 _ => {}
@@ -0,0 +1,112 @@
+use super::GixRepo;
+use crate::util::{
+    bytes_to_text_preserving_utf8, git_command_failed_error, path_buf_from_git_bytes,
+    run_git_raw_output, validate_hex_commit_id,
+};
+use gitcomet_core::domain::CommitId;
+use gitcomet_core::services::{CommitFileSearchMatch, Result, TextSearchOptions};
+use std::path::Path;
+
+const GREP_PATHS_PER_BATCH: usize = 512;
+
+/// Parse `git grep -n -z <rev>` output: `<rev>:<path> NUL <line> NUL <text> LF`.
+fn parse_grep_z(output: &[u8], rev: &str) -> Result<Vec<CommitFileSearchMatch>> {
+    let mut matches = Vec::new();
+    for record in output.split(|&b| b == b'\n') {
+        let mut fields = record.splitn(3, |&b| b == 0);
+        let (Some(path), Some(line), Some(text)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let path = path
+            .strip_prefix(rev.as_bytes())
+            .and_then(|path| path.strip_prefix(b":"))
+            .unwrap_or(path);
+        let Some(line) = std::str::from_utf8(line)
+            .ok()
+            .and_then(|line| line.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let text = text.strip_suffix(b"\r").unwrap_or(text);
+        matches.push(CommitFileSearchMatch {
+            path: path_buf_from_git_bytes(path, "git grep")?,
+            line,
+            text: bytes_to_text_preserving_utf8(text),
+        });
+    }
+    Ok(matches)
+}
+
+impl GixRepo {
+    pub(super) fn search_commit_files_impl(
+        &self,
+        id: &CommitId,
+        paths: &[&Path],
+        query: &str,
+        options: TextSearchOptions,
+    ) -> Result<Vec<CommitFileSearchMatch>> {
+        validate_hex_commit_id(id)?;
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut matches = Vec::new();
+        for batch in paths.chunks(GREP_PATHS_PER_BATCH) {
+            let mut cmd = self.git_workdir_cmd();
+            cmd.arg("grep")
+                .arg("-I")
+                .arg("-n")
+                .arg("-z")
+                .arg("--no-color")
+                .arg(if options.regex { "-E" } else { "-F" });
+            if !options.case_sensitive {
+                cmd.arg("-i");
+            }
+            if options.whole_word {
+                cmd.arg("-w");
+            }
+            cmd.arg("-e")
+                .arg(query)
+                .arg(id.as_ref())
+                .arg("--")
+                .args(batch);
+
+            let label = format!("git grep {}", id.as_ref());
+            let output = run_git_raw_output(cmd, &label)?;
+            // Exit code 1 means nothing matched.
+            match output.status.code() {
+                Some(0) => matches.extend(parse_grep_z(&output.stdout, id.as_ref())?),
+                Some(1) => {}
+                _ => return Err(git_command_failed_error(&label, output)),
+            }
+        }
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_grep_z_strips_revision_prefix() {
+        let output = b"abc123:src/a b.rs\x0012\x00let x = 1;\nabc123:doc.txt\x003\x00tab\there\r\n";
+        assert_eq!(
+            parse_grep_z(output, "abc123").unwrap(),
+            vec![
+                CommitFileSearchMatch {
+                    path: PathBuf::from("src/a b.rs"),
+                    line: 12,
+                    text: "let x = 1;".to_string(),
+                },
+                CommitFileSearchMatch {
+                    path: PathBuf::from("doc.txt"),
+                    line: 3,
+                    text: "tab\there".to_string(),
+                },
+            ]
+        );
+    }
+}
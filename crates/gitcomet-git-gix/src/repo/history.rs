@@ -1,7 +1,8 @@
 use super::GixRepo;
-use crate::util::{run_git_with_output, validate_ref_like_arg};
+use crate::util::{run_git_with_output, validate_hex_commit_id, validate_ref_like_arg};
+use gitcomet_core::domain::CommitId;
 use gitcomet_core::error::{Error, ErrorKind};
-use gitcomet_core::services::{CommandOutput, ResetMode, Result};
+use gitcomet_core::services::{CommandOutput, FixupCommitKind, ResetMode, Result};
 
 /// Returns the HEAD commit id, or `None` when HEAD is unborn / empty.
 pub(super) fn gix_head_id_or_none(repo: &gix::Repository) -> Result<Option<gix::ObjectId>> {
@@ -41,6 +42,40 @@ impl GixRepo {
         run_git_with_output(cmd, &format!("git rebase {onto}"))
     }
 
+    pub(super) fn commit_fixup_with_output_impl(
+        &self,
+        target: &CommitId,
+        kind: FixupCommitKind,
+    ) -> Result<CommandOutput> {
+        validate_hex_commit_id(target)?;
+        let flag = match kind {
+            FixupCommitKind::Fixup => "--fixup",
+            FixupCommitKind::Squash => "--squash",
+        };
+
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("commit").arg(format!("{flag}={}", target.as_ref()));
+        if kind == FixupCommitKind::Squash {
+            // Keep the generated `squash! <subject>` message instead of
+            // opening an editor.
+            cmd.arg("--no-edit");
+        }
+        run_git_with_output(cmd, &format!("git commit {flag}={}", target.as_ref()))
+    }
+
+    pub(super) fn rebase_autosquash_with_output_impl(&self) -> Result<CommandOutput> {
+        // `--autosquash` only reorders the todo list of an interactive rebase;
+        // accept the list and the combined squash messages as generated.
+        let mut cmd = self.git_workdir_cmd();
+        cmd.env("GIT_SEQUENCE_EDITOR", ":")
+            .env("GIT_EDITOR", ":")
+            .arg("rebase")
+            .arg("--interactive")
+            .arg("--autosquash")
+            .arg("@{upstream}");
+        run_git_with_output(cmd, "git rebase --autosquash @{upstream}")
+    }
+
     pub(super) fn rebase_continue_with_output_impl(&self) -> Result<CommandOutput> {
         let mut cmd = self.git_workdir_cmd();
         cmd.arg("rebase").arg("--continue");
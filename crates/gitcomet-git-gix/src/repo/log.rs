@@ -2,15 +2,16 @@ use super::history::gix_head_id_or_none;
 use super::{GixRepo, bstr_to_arc_str, oid_to_arc_str};
 use crate::util::{
     bytes_to_text_preserving_utf8, parse_git_log_pretty_records_from_reader,
-    path_buf_from_git_bytes, run_git_parsed_stdout, unix_seconds_to_system_time,
+    path_buf_from_git_bytes, run_git_capture, run_git_parsed_stdout, unix_seconds_to_system_time,
     unix_seconds_to_system_time_or_epoch,
 };
 use gitcomet_core::domain::{
-    Commit, CommitDetails, CommitFileChange, CommitId, CommitParentIds, HistoryMode, LogCursor,
-    LogPage, RecentCommitMessage, ReflogEntry, StashEntry,
+    Commit, CommitDetails, CommitFileChange, CommitId, CommitParentIds, HistoryMode,
+    LineRangeLogEntry, LineRangeLogPage, LogCursor, LogPage, RecentCommitMessage, ReflogEntry,
+    StashEntry,
 };
 use gitcomet_core::error::{Error, ErrorKind, GitFailure, GitFailureId};
-use gitcomet_core::services::Result;
+use gitcomet_core::services::{RenameDetection, Result};
 use gix::bstr::ByteSlice as _;
 use gix::objs::FindExt as _;
 use gix::traverse::commit::simple::CommitTimeOrder;
@@ -284,7 +285,7 @@ fn commit_file_change_from_diff(
     use gitcomet_core::domain::FileStatusKind;
     use gix::object::tree::diff::ChangeDetached;
 
-    let (location, is_tree, is_submodule, kind) = match change {
+    let (location, is_tree, is_submodule, kind, source, similarity) = match change {
         ChangeDetached::Addition {
             entry_mode,
             location,
@@ -294,6 +295,8 @@ fn commit_file_change_from_diff(
             entry_mode.is_tree(),
             entry_mode.is_commit(),
             FileStatusKind::Added,
+            None,
+            None,
         ),
         ChangeDetached::Deletion {
             entry_mode,
@@ -304,6 +307,8 @@ fn commit_file_change_from_diff(
             entry_mode.is_tree(),
             entry_mode.is_commit(),
             FileStatusKind::Deleted,
+            None,
+            None,
         ),
         ChangeDetached::Modification {
             previous_entry_mode,
@@ -315,11 +320,15 @@ fn commit_file_change_from_diff(
             previous_entry_mode.is_tree() || entry_mode.is_tree(),
             previous_entry_mode.is_commit() || entry_mode.is_commit(),
             FileStatusKind::Modified,
+            None,
+            None,
         ),
         ChangeDetached::Rewrite {
+            source_location,
             source_entry_mode,
             entry_mode,
             location,
+            diff,
             copy,
             ..
         } => (
@@ -327,10 +336,12 @@ fn commit_file_change_from_diff(
             source_entry_mode.is_tree() || entry_mode.is_tree(),
             source_entry_mode.is_commit() || entry_mode.is_commit(),
             if copy {
-                FileStatusKind::Added
+                FileStatusKind::Copied
             } else {
                 FileStatusKind::Renamed
             },
+            Some(source_location),
+            super::similarity_percent(diff),
         ),
     };
 
@@ -341,6 +352,13 @@ fn commit_file_change_from_diff(
         path: path_buf_from_git_bytes(location.as_ref(), "gix commit details diff path")?,
         kind,
         is_submodule,
+        old_path: source
+            .map(|source| {
+                path_buf_from_git_bytes(source.as_ref(), "gix commit details diff source path")
+            })
+            .transpose()?,
+        similarity,
+        generated: false,
     }))
 }
 
@@ -348,6 +366,7 @@ fn commit_file_changes(
     repo: &gix::Repository,
     commit: &gix::Commit<'_>,
     parent_ids: &[gix::ObjectId],
+    rename_detection: Option<RenameDetection>,
 ) -> Result<Vec<CommitFileChange>> {
     if parent_ids.len() > 1 {
         return Ok(Vec::new());
@@ -365,8 +384,12 @@ fn commit_file_changes(
                 .map_err(|e| Error::new(ErrorKind::Backend(format!("gix parent tree: {e}"))))
         })
         .transpose()?;
+    // `None` lets gix read `diff.renames` from the repository config.
+    let options = rename_detection.map(|detection| {
+        gix::diff::Options::default().with_rewrites(Some(super::gix_rewrites(detection)))
+    });
     let changes = repo
-        .diff_tree_to_tree(parent_tree.as_ref(), &commit_tree, None)
+        .diff_tree_to_tree(parent_tree.as_ref(), &commit_tree, options)
         .map_err(|e| Error::new(ErrorKind::Backend(format!("gix diff_tree_to_tree: {e}"))))?;
 
     changes
@@ -476,6 +499,61 @@ fn paginate_commits(
     })
 }
 
+/// Parse `git log -L` output produced with [`LINE_RANGE_LOG_FORMAT`]: each
+/// record is `\x1d<pretty header>\x1e` followed by the range-limited diff.
+fn parse_line_range_log(output: &str) -> Result<Vec<LineRangeLogEntry>> {
+    let mut entries = Vec::new();
+    for record in output.split('\u{001d}') {
+        if record.trim().is_empty() {
+            continue;
+        }
+        let Some((header, diff)) = record.split_once('\u{001e}') else {
+            return Err(Error::new(ErrorKind::Backend(
+                "git log -L produced a record without a header terminator".to_string(),
+            )));
+        };
+        let page = parse_git_log_pretty_records_from_reader(header.as_bytes())?;
+        let Some(commit) = page.commits.into_iter().next() else {
+            continue;
+        };
+        entries.push(LineRangeLogEntry {
+            commit,
+            diff: diff.trim_matches('\n').to_string(),
+        });
+    }
+    Ok(entries)
+}
+
+const LINE_RANGE_LOG_FORMAT: &str = "--pretty=format:%x1d%H%x1f%P%x1f%an%x1f%ct%x1f%s%x1e";
+
+fn paginate_line_range_entries(
+    entries: Vec<LineRangeLogEntry>,
+    limit: usize,
+    cursor: Option<&LogCursor>,
+) -> LineRangeLogPage {
+    let mut cursor_gate = CursorGate::new(cursor);
+    let mut result = Vec::with_capacity(limit.min(entries.len()));
+    let mut next_cursor = None;
+    for entry in entries {
+        if cursor_gate.should_skip(entry.commit.id.as_ref()) {
+            continue;
+        }
+        if result.len() >= limit {
+            next_cursor = result.last().map(|e: &LineRangeLogEntry| LogCursor {
+                last_seen: e.commit.id.clone(),
+                resume_from: None,
+                resume_token: None,
+            });
+            break;
+        }
+        result.push(entry);
+    }
+    LineRangeLogPage {
+        entries: result,
+        next_cursor,
+    }
+}
+
 fn log_page_from_walk<'repo, E>(
     walk: impl Iterator<Item = std::result::Result<gix::revision::walk::Info<'repo>, E>>,
     limit: usize,
@@ -768,6 +846,53 @@ impl GixRepo {
         })
     }
 
+    pub(super) fn log_line_range_page_impl(
+        &self,
+        path: &Path,
+        start: u32,
+        end: u32,
+        rev: Option<&str>,
+        limit: usize,
+        cursor: Option<&LogCursor>,
+    ) -> Result<LineRangeLogPage> {
+        if start == 0 || end < start {
+            return Err(Error::new(ErrorKind::Backend(format!(
+                "invalid line range {start}..{end}"
+            ))));
+        }
+        if rev.is_some_and(|rev| rev.is_empty() || rev.starts_with('-')) {
+            return Err(Error::new(ErrorKind::Backend(format!(
+                "invalid revision for line history: {}",
+                rev.unwrap_or_default()
+            ))));
+        }
+        if limit == 0 {
+            return Ok(LineRangeLogPage {
+                entries: Vec::new(),
+                next_cursor: None,
+            });
+        }
+
+        // `git log -L` tracks the range through renames on its own but cannot
+        // be combined with `--skip`, so later pages rescan and skip past the
+        // cursor like `log_file_page` does.
+        let git_path = path.to_string_lossy().replace('\\', "/");
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("log")
+            .arg("--no-color")
+            .arg(LINE_RANGE_LOG_FORMAT)
+            .arg(format!("-L{start},{end}:{git_path}"));
+        if cursor.is_none() {
+            cmd.arg(format!("-n{}", limit.saturating_add(1)));
+        }
+        if let Some(rev) = rev {
+            cmd.arg(rev);
+        }
+        let output = run_git_capture(cmd, "git log -L")?;
+        let entries = parse_line_range_log(&output)?;
+        Ok(paginate_line_range_entries(entries, limit, cursor))
+    }
+
     pub(super) fn log_head_page_impl(
         &self,
         limit: usize,
@@ -1038,7 +1163,15 @@ impl GixRepo {
             .iter()
             .map(|parent| CommitId(oid_to_arc_str(parent)))
             .collect::<Vec<_>>();
-        let files = commit_file_changes(&repo, &commit, &parent_oids)?;
+        let mut files = commit_file_changes(&repo, &commit, &parent_oids, self.rename_detection())?;
+        let paths = files
+            .iter()
+            .map(|file| file.path.as_path())
+            .collect::<Vec<_>>();
+        let generated = self.linguist_generated_paths(&paths, spec);
+        for file in &mut files {
+            file.generated = generated.contains(&file.path);
+        }
 
         Ok(CommitDetails {
             id: id.clone(),
@@ -1191,6 +1324,104 @@ mod tests {
         assert!(!gate.should_skip("c4"));
     }
 
+    #[test]
+    fn parse_line_range_log_splits_commits_and_diffs() {
+        let output = "\u{1d}c2\u{1f}c1\u{1f}Alice\u{1f}1700000100\u{1f}Tweak\u{1e}\n\n\
+diff --git a/b.txt b/b.txt\n@@ -2,1 +2,1 @@\n-two\n+TWO\n\n\
+\u{1d}c1\u{1f}\u{1f}Bob\u{1f}1700000000\u{1f}Add\u{1e}\n\n\
+diff --git a/a.txt b/a.txt\n@@ -0,0 +2,1 @@\n+two\n";
+        let entries = parse_line_range_log(output).expect("parse");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].commit.id.as_ref(), "c2");
+        assert_eq!(entries[0].commit.summary.as_ref(), "Tweak");
+        assert!(entries[0].diff.starts_with("diff --git a/b.txt"));
+        assert!(entries[0].diff.ends_with("+TWO"));
+        assert_eq!(entries[1].commit.author.as_ref(), "Bob");
+        assert!(entries[1].commit.parent_ids.is_empty());
+    }
+
+    #[test]
+    fn log_line_range_page_follows_range_across_renames() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let workdir = tmp.path();
+        init_test_repo(workdir);
+
+        commit_file(workdir, "a.txt", "one\ntwo\nthree\n", "add");
+        commit_file(workdir, "a.txt", "one\nTWO\nthree\n", "tweak two");
+        commit_file(workdir, "a.txt", "one\nTWO\nthree\nfour\n", "append four");
+        git_success(workdir, &["mv", "a.txt", "b.txt"]);
+        git_success(workdir, &["commit", "-m", "rename"]);
+
+        let repo = open_repo(workdir);
+        let page = repo
+            .log_line_range_page_impl(Path::new("b.txt"), 2, 2, None, 10, None)
+            .expect("line range log");
+        let summaries = page
+            .entries
+            .iter()
+            .map(|entry| entry.commit.summary.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(summaries, vec!["tweak two", "add"]);
+        assert!(page.entries[0].diff.contains("+TWO"));
+        assert!(!page.entries[0].diff.contains("four"));
+
+        let first = repo
+            .log_line_range_page_impl(Path::new("b.txt"), 2, 2, None, 1, None)
+            .expect("first page");
+        assert_eq!(first.entries.len(), 1);
+        let second = repo
+            .log_line_range_page_impl(
+                Path::new("b.txt"),
+                2,
+                2,
+                None,
+                1,
+                first.next_cursor.as_ref(),
+            )
+            .expect("second page");
+        assert_eq!(second.entries[0].commit.summary.as_ref(), "add");
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[test]
+    fn log_line_range_page_reads_range_as_of_given_revision() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let workdir = tmp.path();
+        init_test_repo(workdir);
+
+        commit_file(workdir, "a.txt", "one\ntwo\nthree\n", "add");
+        commit_file(workdir, "a.txt", "one\nTWO\nthree\n", "tweak two");
+        commit_file(workdir, "a.txt", "zero\none\nTWO\nthree\n", "prepend zero");
+
+        let repo = open_repo(workdir);
+        // Line 2 of the previous commit is `TWO`; at HEAD it is `one`.
+        let page = repo
+            .log_line_range_page_impl(Path::new("a.txt"), 2, 2, Some("HEAD~1"), 10, None)
+            .expect("line range log at HEAD~1");
+        let summaries = page
+            .entries
+            .iter()
+            .map(|entry| entry.commit.summary.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(summaries, vec!["tweak two", "add"]);
+        assert!(page.entries[0].diff.contains("+TWO"));
+
+        let head = repo
+            .log_line_range_page_impl(Path::new("a.txt"), 2, 2, None, 10, None)
+            .expect("line range log at HEAD");
+        let summaries = head
+            .entries
+            .iter()
+            .map(|entry| entry.commit.summary.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(summaries, vec!["add"]);
+
+        assert!(
+            repo.log_line_range_page_impl(Path::new("a.txt"), 2, 2, Some("--all"), 10, None)
+                .is_err()
+        );
+    }
+
     #[test]
     fn object_id_from_commit_id_rejects_invalid_hex() {
         assert!(object_id_from_commit_id(&CommitId("not-a-sha".into())).is_none());
@@ -2,17 +2,21 @@ use crate::util::git_workdir_cmd_for as util_git_workdir_cmd_for;
 use gitcomet_core::conflict_session::ConflictSession;
 use gitcomet_core::domain::{
     Branch, Commit, CommitDetails, CommitId, Diff, DiffPreviewTextSide, DiffTarget, FileDiffImage,
-    FileDiffText, HistoryMode, LogCursor, LogPage, RecentCommitMessage, ReflogEntry, Remote,
-    RemoteBranch, RemoteTag, RepoSpec, RepoStatus, StashEntry, Submodule, SubmoduleDiffSummary,
-    Tag, UpstreamDivergence, Worktree,
+    FileDiffText, HistoryMode, LineRangeLogPage, LogCursor, LogPage, RecentCommitMessage,
+    ReflogEntry, Remote, RemoteBranch, RemoteTag, RepoOperationAction, RepoOperationKind,
+    RepoOperationState, RepoSpec, RepoStatus, RevisionTreeEntry, StashEntry, Submodule,
+    SubmoduleDiffSummary, Tag, Upstream, UpstreamDivergence, Worktree, WorktreeSummary,
 };
 use gitcomet_core::error::{Error, ErrorKind};
 use gitcomet_core::git_ops_trace::{self, GitOpTraceKind};
+use gitcomet_core::mailbox::MailboxPatch;
 use gitcomet_core::services::{
-    BlameLine, CommandOutput, CommitOperationOutcome, ConflictFileStages, ConflictSide,
-    ForcePushLease, GitRepository, MergetoolResult, PullMode, RemoteUrlKind, ResetMode, Result,
+    BlameLine, BlameOptions, CommandOutput, CommitFileSearchMatch, CommitOperationOutcome,
+    ConflictFileStages, ConflictSide, DiffIgnoreOptions, FixupCommitKind, ForcePushLease,
+    GitRepository, MergetoolResult, PullMode, RemoteUrlKind, RenameDetection, ResetMode, Result,
     SafePushAfterCommitContext, SafePushAfterCommitDecision, SafePushAfterCommitTarget,
-    SubmoduleTrustDecision, SubmoduleTrustTarget,
+    SubmoduleBatchCommand, SubmoduleCommandResult, SubmoduleTrustDecision, SubmoduleTrustTarget,
+    TextSearchOptions,
 };
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -37,17 +41,47 @@ pub(super) fn bstr_to_arc_str(bytes: &[u8]) -> Arc<str> {
     }
 }
 
+/// Build gix rewrite tracking options matching `git diff -M<n>% [-C<n>%]`.
+pub(super) fn gix_rewrites(detection: RenameDetection) -> gix::diff::Rewrites {
+    let fraction = |percent: u8| f32::from(percent.min(100)) / 100.0;
+    gix::diff::Rewrites {
+        copies: detection
+            .detect_copies
+            .then(|| gix::diff::rewrites::Copies {
+                source: gix::diff::rewrites::CopySource::FromSetOfModifiedFiles,
+                percentage: Some(fraction(detection.copy_threshold)),
+            }),
+        percentage: Some(fraction(detection.rename_threshold)),
+        ..Default::default()
+    }
+}
+
+/// Convert gix's 0.0..=1.0 rewrite similarity into a whole percentage. gix
+/// only computes line stats for inexact matches, so a rewrite without them is
+/// an identical-content rename or copy.
+pub(super) fn similarity_percent(stats: Option<gix::diff::blob::DiffLineStats>) -> Option<u8> {
+    Some(stats.map_or(100, |stats| {
+        (stats.similarity * 100.0).round().clamp(0.0, 100.0) as u8
+    }))
+}
+
+mod attributes;
 mod blame;
+mod commit_batch;
 mod conflict_stages;
 mod diff;
 mod discard;
 mod git_ops;
+mod grep;
 mod history;
 mod log;
 mod mergetool;
 mod patch;
 mod porcelain;
 mod remotes;
+mod revision_tree;
+mod reword;
+mod sequencer;
 mod status;
 mod submodules;
 mod tags;
@@ -144,6 +178,9 @@ pub(crate) struct GixRepo {
     log_head_page_cache: std::sync::Mutex<Vec<LogHeadPageCacheEntry>>,
     log_file_follow_cache: std::sync::Mutex<Vec<LogFileFollowCacheEntry>>,
     log_paged_walk_cache: std::sync::Mutex<LogPagedWalkCache>,
+    /// Explicit rename/copy thresholds; `None` defers to the repository's git config.
+    rename_detection: std::sync::Mutex<Option<RenameDetection>>,
+    diff_ignore_options: std::sync::Mutex<DiffIgnoreOptions>,
 }
 
 impl GixRepo {
@@ -157,9 +194,25 @@ impl GixRepo {
             log_head_page_cache: std::sync::Mutex::new(Vec::new()),
             log_file_follow_cache: std::sync::Mutex::new(Vec::new()),
             log_paged_walk_cache: std::sync::Mutex::new(LogPagedWalkCache::default()),
+            rename_detection: std::sync::Mutex::new(None),
+            diff_ignore_options: std::sync::Mutex::new(DiffIgnoreOptions::default()),
         }
     }
 
+    pub(super) fn rename_detection(&self) -> Option<RenameDetection> {
+        *self
+            .rename_detection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(super) fn diff_ignore_options(&self) -> DiffIgnoreOptions {
+        self.diff_ignore_options
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
     /// Returns a `Command` pre-configured with `git -C <workdir>`.
     pub(super) fn git_workdir_cmd(&self) -> Command {
         util_git_workdir_cmd_for(&self.spec.workdir)
@@ -213,10 +266,33 @@ impl GitRepository for GixRepo {
         self.log_file_page_impl(path, limit, cursor)
     }
 
+    fn log_line_range_page(
+        &self,
+        path: &Path,
+        start: u32,
+        end: u32,
+        rev: Option<&str>,
+        limit: usize,
+        cursor: Option<&LogCursor>,
+    ) -> Result<LineRangeLogPage> {
+        let _scope = git_ops_trace::scope(GitOpTraceKind::LogWalk);
+        self.log_line_range_page_impl(path, start, end, rev, limit, cursor)
+    }
+
     fn commit_details(&self, id: &CommitId) -> Result<CommitDetails> {
         self.commit_details_impl(id)
     }
 
+    fn search_commit_files(
+        &self,
+        id: &CommitId,
+        paths: &[&Path],
+        query: &str,
+        options: TextSearchOptions,
+    ) -> Result<Vec<CommitFileSearchMatch>> {
+        self.search_commit_files_impl(id, paths, query, options)
+    }
+
     fn recent_commit_messages(&self, limit: usize) -> Result<Vec<RecentCommitMessage>> {
         self.recent_commit_messages_impl(limit)
     }
@@ -395,6 +471,22 @@ impl GitRepository for GixRepo {
         self.commit_amend_with_outcome_impl(message)
     }
 
+    fn reword_commit_blocker(&self, id: &CommitId) -> Result<Option<String>> {
+        self.reword_commit_blocker_impl(id)
+    }
+
+    fn reword_commit_with_output(&self, id: &CommitId, message: &str) -> Result<CommandOutput> {
+        self.reword_commit_with_output_impl(id, message)
+    }
+
+    fn commit_fixup_with_output(
+        &self,
+        target: &CommitId,
+        kind: FixupCommitKind,
+    ) -> Result<CommandOutput> {
+        self.commit_fixup_with_output_impl(target, kind)
+    }
+
     fn fetch_all(&self) -> Result<()> {
         self.fetch_all_impl(true)
     }
@@ -464,6 +556,10 @@ impl GitRepository for GixRepo {
         self.rebase_with_output_impl(onto)
     }
 
+    fn rebase_autosquash_with_output(&self) -> Result<CommandOutput> {
+        self.rebase_autosquash_with_output_impl()
+    }
+
     fn rebase_continue_with_output(&self) -> Result<CommandOutput> {
         self.rebase_continue_with_output_impl()
     }
@@ -480,6 +576,18 @@ impl GitRepository for GixRepo {
         self.rebase_in_progress_impl()
     }
 
+    fn operation_state(&self) -> Result<Option<RepoOperationState>> {
+        self.operation_state_impl()
+    }
+
+    fn operation_command_with_output(
+        &self,
+        kind: RepoOperationKind,
+        action: RepoOperationAction,
+    ) -> Result<CommandOutput> {
+        self.operation_command_with_output_impl(kind, action)
+    }
+
     fn merge_commit_message(&self) -> Result<Option<String>> {
         self.merge_commit_message_impl()
     }
@@ -553,11 +661,79 @@ impl GitRepository for GixRepo {
         self.delete_remote_branch_with_output_impl(remote, branch)
     }
 
+    fn rename_branch_with_output(
+        &self,
+        name: &str,
+        new_name: &str,
+        force: bool,
+    ) -> Result<CommandOutput> {
+        self.rename_branch_with_output_impl(name, new_name, force)
+    }
+
+    fn rename_upstream_branch_with_output(
+        &self,
+        branch: &str,
+        upstream: &Upstream,
+        new_name: &str,
+    ) -> Result<CommandOutput> {
+        self.rename_upstream_branch_with_output_impl(branch, upstream, new_name)
+    }
+
     fn blame_file(&self, path: &Path, rev: Option<&str>) -> Result<Vec<BlameLine>> {
         let _scope = git_ops_trace::scope(GitOpTraceKind::Blame);
         self.blame_file_impl(path, rev)
     }
 
+    fn blame_file_with_options(
+        &self,
+        path: &Path,
+        rev: Option<&str>,
+        options: BlameOptions,
+    ) -> Result<Vec<BlameLine>> {
+        let _scope = git_ops_trace::scope(GitOpTraceKind::Blame);
+        self.blame_file_with_options_impl(path, rev, options)
+    }
+
+    fn list_revision_tree(&self, rev: &str, dir: &Path) -> Result<Vec<RevisionTreeEntry>> {
+        self.list_revision_tree_impl(rev, dir)
+    }
+
+    fn read_revision_file(&self, rev: &str, path: &Path) -> Result<Vec<u8>> {
+        self.read_revision_file_impl(rev, path)
+    }
+
+    fn diff_revision_to_worktree_unified(&self, rev: &str, path: &Path) -> Result<String> {
+        self.diff_revision_to_worktree_unified_impl(rev, path)
+    }
+
+    fn save_revision_path_with_output(
+        &self,
+        rev: &str,
+        path: &Path,
+        dest: &Path,
+    ) -> Result<CommandOutput> {
+        self.save_revision_path_with_output_impl(rev, path, dest)
+    }
+
+    fn set_rename_detection(&self, detection: RenameDetection) {
+        *self
+            .rename_detection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(detection);
+        // Cached staged entries were paired with the previous thresholds.
+        *self
+            .tree_index_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    }
+
+    fn set_diff_ignore_options(&self, options: DiffIgnoreOptions) {
+        *self
+            .diff_ignore_options
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = options;
+    }
+
     fn checkout_conflict_side(&self, path: &Path, side: ConflictSide) -> Result<CommandOutput> {
         self.checkout_conflict_side_impl(path, side)
     }
@@ -578,10 +754,43 @@ impl GitRepository for GixRepo {
         self.export_patch_with_output_impl(commit_id, dest)
     }
 
+    fn export_patch_series_with_output(
+        &self,
+        ids: &[CommitId],
+        dest: &Path,
+        cover_letter: bool,
+    ) -> Result<CommandOutput> {
+        self.export_patch_series_with_output_impl(ids, dest, cover_letter)
+    }
+
+    fn cherry_pick_commits_with_output(&self, ids: &[CommitId]) -> Result<CommandOutput> {
+        self.cherry_pick_commits_with_output_impl(ids)
+    }
+
+    fn revert_commits_with_output(&self, ids: &[CommitId]) -> Result<CommandOutput> {
+        self.revert_commits_with_output_impl(ids)
+    }
+
+    fn squash_commits_with_output(&self, ids: &[CommitId]) -> Result<CommandOutput> {
+        self.squash_commits_with_output_impl(ids)
+    }
+
     fn apply_patch_with_output(&self, patch: &Path) -> Result<CommandOutput> {
         self.apply_patch_with_output_impl(patch)
     }
 
+    fn read_patch_series(&self, source: &Path) -> Result<Vec<MailboxPatch>> {
+        self.read_patch_series_impl(source)
+    }
+
+    fn apply_patch_series_with_output(
+        &self,
+        source: &Path,
+        three_way: bool,
+    ) -> Result<CommandOutput> {
+        self.apply_patch_series_with_output_impl(source, three_way)
+    }
+
     fn apply_unified_patch_to_index_with_output(
         &self,
         patch: &str,
@@ -618,6 +827,30 @@ impl GitRepository for GixRepo {
         self.force_remove_worktree_with_output_impl(path)
     }
 
+    fn lock_worktree_with_output(
+        &self,
+        path: &Path,
+        reason: Option<&str>,
+    ) -> Result<CommandOutput> {
+        self.lock_worktree_with_output_impl(path, reason)
+    }
+
+    fn unlock_worktree_with_output(&self, path: &Path) -> Result<CommandOutput> {
+        self.unlock_worktree_with_output_impl(path)
+    }
+
+    fn move_worktree_with_output(&self, path: &Path, new_path: &Path) -> Result<CommandOutput> {
+        self.move_worktree_with_output_impl(path, new_path)
+    }
+
+    fn prune_worktrees_with_output(&self) -> Result<CommandOutput> {
+        self.prune_worktrees_with_output_impl()
+    }
+
+    fn worktree_summary(&self, path: &Path) -> Result<WorktreeSummary> {
+        self.worktree_summary_impl(path)
+    }
+
     fn list_submodules(&self) -> Result<Vec<Submodule>> {
         self.list_submodules_impl()
     }
@@ -677,6 +910,29 @@ impl GitRepository for GixRepo {
         self.remove_submodule_with_output_impl(path)
     }
 
+    fn sync_submodules_with_output(&self) -> Result<CommandOutput> {
+        self.sync_submodules_with_output_impl()
+    }
+
+    fn set_submodule_branch_with_output(
+        &self,
+        path: &Path,
+        branch: Option<&str>,
+    ) -> Result<CommandOutput> {
+        self.set_submodule_branch_with_output_impl(path, branch)
+    }
+
+    fn update_submodule_remote_with_output(&self, path: &Path) -> Result<CommandOutput> {
+        self.update_submodule_remote_with_output_impl(path)
+    }
+
+    fn run_in_submodules(
+        &self,
+        command: &SubmoduleBatchCommand,
+    ) -> Result<Vec<SubmoduleCommandResult>> {
+        self.run_in_submodules_impl(command)
+    }
+
     fn discard_worktree_changes(&self, paths: &[&Path]) -> Result<()> {
         self.discard_worktree_changes_impl(paths)
     }
@@ -2,6 +2,7 @@ use super::GixRepo;
 use crate::util::{run_git_capture, run_git_with_output};
 use gitcomet_core::domain::CommitId;
 use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::mailbox::{MailboxPatch, patch_series_files, read_patch_series};
 use gitcomet_core::services::{CommandOutput, Result};
 use std::io::Write;
 use std::path::Path;
@@ -36,6 +37,83 @@ impl GixRepo {
         run_git_with_output(cmd, &format!("git am --3way {}", patch.display()))
     }
 
+    /// Run `git format-patch` over `ids` oldest first. Without a cover letter
+    /// the series is saved to `dest` as one mbox; with one, `dest` is the
+    /// directory that receives the numbered patch files.
+    pub(super) fn export_patch_series_with_output_impl(
+        &self,
+        ids: &[CommitId],
+        dest: &Path,
+        cover_letter: bool,
+    ) -> Result<CommandOutput> {
+        let sorted = self.topo_sorted_commits(ids)?;
+        let mut cmd = self.git_workdir_cmd();
+        // A lone revision would be read as `<since>`; the count keeps it to
+        // the commit itself.
+        cmd.arg("format-patch")
+            .arg("--binary")
+            .arg("--no-walk")
+            .arg(format!("--max-count={}", sorted.len()));
+        if cover_letter {
+            cmd.arg("--cover-letter").arg("-o").arg(dest);
+        } else {
+            cmd.arg("--stdout");
+        }
+        // `--no-walk` emits revisions in the reverse of the order given.
+        cmd.args(sorted.iter().rev().map(|id| id.as_ref()))
+            .arg("--");
+        let label = format!("git format-patch ({} commits)", sorted.len());
+        if cover_letter {
+            return run_git_with_output(cmd, &label);
+        }
+
+        let series = run_git_capture(cmd, &label)?;
+        std::fs::write(dest, series.as_bytes()).map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+        Ok(CommandOutput {
+            command: format!("Export {} patches", sorted.len()),
+            stdout: format!("Saved patch series to {}", dest.display()),
+            stderr: String::new(),
+            exit_code: Some(0),
+        })
+    }
+
+    pub(super) fn read_patch_series_impl(&self, source: &Path) -> Result<Vec<MailboxPatch>> {
+        read_patch_series(source).map_err(|e| Error::new(ErrorKind::Io(e.kind())))
+    }
+
+    pub(super) fn apply_patch_series_with_output_impl(
+        &self,
+        source: &Path,
+        three_way: bool,
+    ) -> Result<CommandOutput> {
+        let files = if source.is_dir() {
+            let files =
+                patch_series_files(source).map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+            if files.is_empty() {
+                return Err(Error::new(ErrorKind::Backend(format!(
+                    "No patch files found in {}",
+                    source.display()
+                ))));
+            }
+            files
+        } else {
+            vec![source.to_path_buf()]
+        };
+
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("am");
+        if three_way {
+            cmd.arg("--3way");
+        }
+        cmd.arg("--").args(&files);
+        let label = if three_way {
+            format!("git am --3way {}", source.display())
+        } else {
+            format!("git am {}", source.display())
+        };
+        run_git_with_output(cmd, &label)
+    }
+
     pub(super) fn apply_unified_patch_to_index_with_output_impl(
         &self,
         patch: &str,
@@ -761,6 +761,7 @@ impl GixRepo {
                         | FileStatusKind::Added
                         | FileStatusKind::Deleted
                         | FileStatusKind::Renamed
+                        | FileStatusKind::Copied
                         // `git stash apply` also refuses to start when tracked
                         // stash payload would overwrite an untracked path.
                         | FileStatusKind::Untracked
@@ -1012,6 +1012,69 @@ impl GixRepo {
         Ok(output)
     }
 
+    pub(super) fn rename_branch_with_output_impl(
+        &self,
+        name: &str,
+        new_name: &str,
+        force: bool,
+    ) -> Result<CommandOutput> {
+        validate_ref_like_arg(name, "branch name")?;
+        validate_ref_like_arg(new_name, "branch name")?;
+
+        // `git branch -m` also repoints HEAD in every worktree that has the
+        // branch checked out and carries the branch's config section over.
+        let flag = if force { "-M" } else { "-m" };
+        let label = format!("git branch {flag} {name} {new_name}");
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("branch")
+            .arg(flag)
+            .arg("--")
+            .arg(name)
+            .arg(new_name);
+        run_git_with_output(cmd, &label)
+    }
+
+    pub(super) fn rename_upstream_branch_with_output_impl(
+        &self,
+        branch: &str,
+        upstream: &Upstream,
+        new_name: &str,
+    ) -> Result<CommandOutput> {
+        validate_ref_like_arg(branch, "branch name")?;
+        validate_ref_like_arg(&upstream.remote, "remote name")?;
+        validate_ref_like_arg(&upstream.branch, "branch name")?;
+        validate_ref_like_arg(new_name, "branch name")?;
+
+        let remote = upstream.remote.as_str();
+        let label = format!("git push --set-upstream {remote} {branch}:refs/heads/{new_name}");
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("push")
+            .arg("--set-upstream")
+            .arg("--")
+            .arg(remote)
+            .arg(format!("refs/heads/{branch}:refs/heads/{new_name}"));
+        let push_output = run_git_with_output(cmd, &label)?;
+        if upstream.branch == new_name {
+            return Ok(push_output);
+        }
+
+        let delete_output = self.delete_remote_branch_with_output_impl(remote, &upstream.branch)?;
+        Ok(CommandOutput {
+            command: format!("{} && {}", push_output.command, delete_output.command),
+            stdout: [push_output.stdout, delete_output.stdout]
+                .into_iter()
+                .filter(|text| !text.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            stderr: [push_output.stderr, delete_output.stderr]
+                .into_iter()
+                .filter(|text| !text.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            exit_code: delete_output.exit_code,
+        })
+    }
+
     pub(super) fn prune_merged_branches_with_output_impl(&self) -> Result<CommandOutput> {
         let fetch_output = self.fetch_all_with_output_impl(true)?;
 
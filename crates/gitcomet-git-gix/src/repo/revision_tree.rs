@@ -0,0 +1,389 @@
+use super::GixRepo;
+use crate::util::{path_buf_from_git_bytes, run_git_capture, validate_ref_like_arg};
+use gitcomet_core::domain::{RevisionTreeEntry, RevisionTreeEntryKind, sort_revision_tree_entries};
+use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::services::{CommandOutput, Result};
+use std::fs;
+use std::io::Write as _;
+use std::path::Path;
+
+/// Files written and entries left out while saving part of a revision.
+#[derive(Default)]
+struct SaveStats {
+    files: usize,
+    skipped_submodules: usize,
+}
+
+impl GixRepo {
+    pub(super) fn list_revision_tree_impl(
+        &self,
+        rev: &str,
+        dir: &Path,
+    ) -> Result<Vec<RevisionTreeEntry>> {
+        let repo = self._repo.to_thread_local();
+        let tree = revision_subtree(&repo, rev, dir)?;
+
+        let mut entries = Vec::new();
+        for entry in tree.iter() {
+            let entry = entry.map_err(|e| {
+                Error::new(ErrorKind::Backend(format!(
+                    "gix decode tree {rev}:{}: {e}",
+                    dir.display()
+                )))
+            })?;
+            let name = path_buf_from_git_bytes(entry.filename(), "gix tree entry name")?;
+            let kind = revision_tree_entry_kind(entry.mode());
+            let size = match kind {
+                RevisionTreeEntryKind::Directory | RevisionTreeEntryKind::Submodule => None,
+                RevisionTreeEntryKind::File
+                | RevisionTreeEntryKind::Executable
+                | RevisionTreeEntryKind::Symlink => blob_size(&repo, entry.object_id())?,
+            };
+            entries.push(RevisionTreeEntry {
+                path: dir.join(name),
+                kind,
+                size,
+            });
+        }
+        sort_revision_tree_entries(&mut entries);
+        Ok(entries)
+    }
+
+    pub(super) fn read_revision_file_impl(&self, rev: &str, path: &Path) -> Result<Vec<u8>> {
+        let repo = self._repo.to_thread_local();
+        let tree = revision_root_tree(&repo, rev)?;
+        let entry = tree
+            .lookup_entry_by_path(path)
+            .map_err(|e| {
+                Error::new(ErrorKind::Backend(format!(
+                    "gix lookup {rev}:{}: {e}",
+                    path.display()
+                )))
+            })?
+            .ok_or_else(|| revision_path_missing_error(rev, path))?;
+        if entry.mode().is_tree() || entry.mode().is_commit() {
+            return Err(Error::new(ErrorKind::Backend(format!(
+                "{rev}:{} is not a file",
+                path.display()
+            ))));
+        }
+        read_blob(&repo, entry.object_id(), path)
+    }
+
+    pub(super) fn diff_revision_to_worktree_unified_impl(
+        &self,
+        rev: &str,
+        path: &Path,
+    ) -> Result<String> {
+        validate_ref_like_arg(rev, "revision")?;
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("-c")
+            .arg("color.ui=false")
+            .arg("--no-pager")
+            .arg("diff")
+            .arg("--no-ext-diff")
+            .arg(rev)
+            .arg("--")
+            .arg(path);
+        run_git_capture(cmd, &format!("git diff {rev} -- {}", path.display()))
+    }
+
+    pub(super) fn save_revision_path_with_output_impl(
+        &self,
+        rev: &str,
+        path: &Path,
+        dest: &Path,
+    ) -> Result<CommandOutput> {
+        if fs::symlink_metadata(dest).is_ok() {
+            return Err(Error::new(ErrorKind::Backend(format!(
+                "{} already exists",
+                dest.display()
+            ))));
+        }
+
+        let repo = self._repo.to_thread_local();
+        let tree = revision_root_tree(&repo, rev)?;
+        let mut stats = SaveStats::default();
+        if path.as_os_str().is_empty() {
+            write_tree(&repo, &tree, dest, &mut stats)?;
+        } else {
+            let entry = tree
+                .lookup_entry_by_path(path)
+                .map_err(|e| {
+                    Error::new(ErrorKind::Backend(format!(
+                        "gix lookup {rev}:{}: {e}",
+                        path.display()
+                    )))
+                })?
+                .ok_or_else(|| revision_path_missing_error(rev, path))?;
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent).map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+            }
+            write_entry(
+                &repo,
+                revision_tree_entry_kind(entry.mode()),
+                entry.object_id(),
+                dest,
+                &mut stats,
+            )?;
+        }
+
+        let mut stdout = match stats.files {
+            1 => format!("Saved 1 file to {}", dest.display()),
+            n => format!("Saved {n} files to {}", dest.display()),
+        };
+        if stats.skipped_submodules > 0 {
+            stdout.push_str(&format!(
+                "\nSkipped {} submodule(s)",
+                stats.skipped_submodules
+            ));
+        }
+        Ok(CommandOutput {
+            command: format!("Save {rev}:{}", path.display()),
+            stdout,
+            stderr: String::new(),
+            exit_code: Some(0),
+        })
+    }
+}
+
+fn revision_root_tree<'repo>(repo: &'repo gix::Repository, rev: &str) -> Result<gix::Tree<'repo>> {
+    let object_id = repo
+        .rev_parse_single(rev)
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix rev-parse {rev}: {e}"))))?
+        .detach();
+    repo.find_object(object_id)
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix find object {rev}: {e}"))))?
+        .peel_to_tree()
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix peel tree {rev}: {e}"))))
+}
+
+fn revision_subtree<'repo>(
+    repo: &'repo gix::Repository,
+    rev: &str,
+    dir: &Path,
+) -> Result<gix::Tree<'repo>> {
+    let root = revision_root_tree(repo, rev)?;
+    if dir.as_os_str().is_empty() {
+        return Ok(root);
+    }
+    let entry = root
+        .lookup_entry_by_path(dir)
+        .map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix lookup {rev}:{}: {e}",
+                dir.display()
+            )))
+        })?
+        .ok_or_else(|| revision_path_missing_error(rev, dir))?;
+    if !entry.mode().is_tree() {
+        return Err(Error::new(ErrorKind::Backend(format!(
+            "{rev}:{} is not a directory",
+            dir.display()
+        ))));
+    }
+    find_tree(repo, entry.object_id(), dir)
+}
+
+fn revision_path_missing_error(rev: &str, path: &Path) -> Error {
+    Error::new(ErrorKind::Backend(format!(
+        "'{}' does not exist in {rev}",
+        path.display()
+    )))
+}
+
+fn revision_tree_entry_kind(mode: gix::objs::tree::EntryMode) -> RevisionTreeEntryKind {
+    match mode.kind() {
+        gix::objs::tree::EntryKind::Tree => RevisionTreeEntryKind::Directory,
+        gix::objs::tree::EntryKind::Blob => RevisionTreeEntryKind::File,
+        gix::objs::tree::EntryKind::BlobExecutable => RevisionTreeEntryKind::Executable,
+        gix::objs::tree::EntryKind::Link => RevisionTreeEntryKind::Symlink,
+        gix::objs::tree::EntryKind::Commit => RevisionTreeEntryKind::Submodule,
+    }
+}
+
+fn blob_size(repo: &gix::Repository, object_id: gix::ObjectId) -> Result<Option<u64>> {
+    let header = repo
+        .try_find_header(object_id)
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix try_find_header: {e}"))))?;
+    Ok(header.map(|header| header.size()))
+}
+
+fn find_tree<'repo>(
+    repo: &'repo gix::Repository,
+    object_id: gix::ObjectId,
+    path: &Path,
+) -> Result<gix::Tree<'repo>> {
+    repo.find_object(object_id)
+        .map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix find tree {}: {e}",
+                path.display()
+            )))
+        })?
+        .try_into_tree()
+        .map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix tree {}: {e}",
+                path.display()
+            )))
+        })
+}
+
+fn read_blob(repo: &gix::Repository, object_id: gix::ObjectId, path: &Path) -> Result<Vec<u8>> {
+    let mut blob = repo
+        .find_object(object_id)
+        .map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix find blob {}: {e}",
+                path.display()
+            )))
+        })?
+        .try_into_blob()
+        .map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix blob {}: {e}",
+                path.display()
+            )))
+        })?;
+    Ok(blob.take_data())
+}
+
+fn write_tree(
+    repo: &gix::Repository,
+    tree: &gix::Tree<'_>,
+    dest: &Path,
+    stats: &mut SaveStats,
+) -> Result<()> {
+    match fs::symlink_metadata(dest) {
+        Ok(meta) if meta.is_dir() => {}
+        Ok(_) => return Err(refuse_existing_path(dest)),
+        Err(_) => fs::create_dir_all(dest).map_err(|e| Error::new(ErrorKind::Io(e.kind())))?,
+    }
+    for entry in tree.iter() {
+        let entry = entry.map_err(|e| {
+            Error::new(ErrorKind::Backend(format!(
+                "gix decode tree {}: {e}",
+                dest.display()
+            )))
+        })?;
+        validate_tree_entry_name(entry.filename(), dest)?;
+        let name = path_buf_from_git_bytes(entry.filename(), "gix tree entry name")?;
+        write_entry(
+            repo,
+            revision_tree_entry_kind(entry.mode()),
+            entry.object_id(),
+            &dest.join(name),
+            stats,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_entry(
+    repo: &gix::Repository,
+    kind: RevisionTreeEntryKind,
+    object_id: gix::ObjectId,
+    dest: &Path,
+    stats: &mut SaveStats,
+) -> Result<()> {
+    match kind {
+        RevisionTreeEntryKind::Directory => {
+            let tree = find_tree(repo, object_id, dest)?;
+            write_tree(repo, &tree, dest, stats)
+        }
+        RevisionTreeEntryKind::Submodule => {
+            stats.skipped_submodules += 1;
+            Ok(())
+        }
+        RevisionTreeEntryKind::Symlink => {
+            let target = read_blob(repo, object_id, dest)?;
+            write_symlink(&target, dest)?;
+            stats.files += 1;
+            Ok(())
+        }
+        RevisionTreeEntryKind::File | RevisionTreeEntryKind::Executable => {
+            let bytes = read_blob(repo, object_id, dest)?;
+            write_new_file(dest, &bytes)?;
+            if kind == RevisionTreeEntryKind::Executable {
+                mark_executable(dest)?;
+            }
+            stats.files += 1;
+            Ok(())
+        }
+    }
+}
+
+/// Reject tree entry names that could climb out of the destination or write
+/// into a repository, the way git's `verify_path` does before checkout.
+fn validate_tree_entry_name(name: &[u8], dir: &Path) -> Result<()> {
+    let unsafe_name = name.is_empty()
+        || name == b"."
+        || name == b".."
+        || name.eq_ignore_ascii_case(b".git")
+        || name.iter().any(|&b| matches!(b, b'/' | b'\\' | 0));
+    if unsafe_name {
+        return Err(Error::new(ErrorKind::Backend(format!(
+            "refusing to save unsafe tree entry {:?} in {}",
+            String::from_utf8_lossy(name),
+            dir.display()
+        ))));
+    }
+    Ok(())
+}
+
+/// Entries only ever land on fresh paths, so anything already there (a
+/// symlink written earlier in the same tree, most likely) is refused rather
+/// than followed.
+fn refuse_existing_path(path: &Path) -> Error {
+    Error::new(ErrorKind::Backend(format!(
+        "refusing to write through existing {}",
+        path.display()
+    )))
+}
+
+fn save_io_error(e: std::io::Error, path: &Path) -> Error {
+    match e.kind() {
+        std::io::ErrorKind::AlreadyExists => refuse_existing_path(path),
+        kind => Error::new(ErrorKind::Io(kind)),
+    }
+}
+
+/// `create_new` never follows or replaces whatever is already at `dest`.
+fn write_new_file(dest: &Path, bytes: &[u8]) -> Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(dest)
+        .map_err(|e| save_io_error(e, dest))?;
+    file.write_all(bytes)
+        .map_err(|e| Error::new(ErrorKind::Io(e.kind())))
+}
+
+#[cfg(unix)]
+fn write_symlink(target: &[u8], dest: &Path) -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt as _;
+
+    std::os::unix::fs::symlink(OsStr::from_bytes(target), dest).map_err(|e| save_io_error(e, dest))
+}
+
+/// Without reliable symlink support the link target is written as a plain
+/// file, matching what git does with `core.symlinks=false`.
+#[cfg(not(unix))]
+fn write_symlink(target: &[u8], dest: &Path) -> Result<()> {
+    write_new_file(dest, target)
+}
+
+#[cfg(unix)]
+fn mark_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .map_err(|e| Error::new(ErrorKind::Io(e.kind())))
+}
+
+#[cfg(not(unix))]
+fn mark_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
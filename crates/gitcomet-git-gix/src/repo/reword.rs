@@ -0,0 +1,279 @@
+use super::GixRepo;
+use super::commit_batch::{BATCH_COMMIT_FORMAT, BatchCommit, parse_batch_commits};
+use crate::util::{
+    run_git_capture, run_git_capture_bytes, run_git_raw_output, run_git_with_output,
+    validate_hex_commit_id,
+};
+use gitcomet_core::domain::CommitId;
+use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::services::{CommandOutput, Result};
+use rustc_hash::FxHashMap as HashMap;
+
+fn reword_error(message: String) -> Error {
+    Error::new(ErrorKind::Backend(message))
+}
+
+enum RewordPlan {
+    Blocked(String),
+    Ready {
+        head: String,
+        target: BatchCommit,
+        descendants: Vec<BatchCommit>,
+    },
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(0..8).unwrap_or(sha)
+}
+
+impl GixRepo {
+    fn is_ancestor(&self, ancestor: &str, descendant: &str) -> Result<bool> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("merge-base")
+            .arg("--is-ancestor")
+            .arg(ancestor)
+            .arg(descendant);
+        let output = run_git_raw_output(cmd, "git merge-base --is-ancestor")?;
+        Ok(output.status.success())
+    }
+
+    /// The first published ref that already contains `sha`: the current
+    /// branch's upstream, any remote-tracking branch, or a tag.
+    fn published_ref_containing(&self, sha: &str) -> Result<Option<String>> {
+        let mut upstream_cmd = self.git_workdir_cmd();
+        upstream_cmd
+            .arg("rev-parse")
+            .arg("--abbrev-ref")
+            .arg("--symbolic-full-name")
+            .arg("@{upstream}");
+        let upstream = run_git_raw_output(upstream_cmd, "git rev-parse @{upstream}")?;
+        if upstream.status.success() {
+            let upstream = String::from_utf8_lossy(&upstream.stdout).trim().to_string();
+            if !upstream.is_empty() && self.is_ancestor(sha, &upstream)? {
+                return Ok(Some(upstream));
+            }
+        }
+
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("for-each-ref")
+            .arg("--count=1")
+            .arg("--format=%(refname:short)")
+            .arg(format!("--contains={sha}"))
+            .arg("refs/remotes")
+            .arg("refs/tags");
+        let contained_in = run_git_capture(cmd, "git for-each-ref --contains")?;
+        Ok(contained_in
+            .lines()
+            .map(str::trim)
+            .find(|name| !name.is_empty())
+            .map(str::to_string))
+    }
+
+    fn commit_tree(&self, commit: &BatchCommit, parents: &[&str], message: &str) -> Result<String> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.env("GIT_AUTHOR_NAME", &commit.author_name)
+            .env("GIT_AUTHOR_EMAIL", &commit.author_email)
+            .env("GIT_AUTHOR_DATE", &commit.author_date)
+            .arg("commit-tree")
+            .arg(format!("{}^{{tree}}", commit.id));
+        for parent in parents {
+            cmd.arg("-p").arg(parent);
+        }
+        cmd.arg("-m").arg(message);
+        Ok(run_git_capture(cmd, "git commit-tree")?.trim().to_string())
+    }
+
+    /// A header that `git commit-tree` would not carry over to the rewritten
+    /// commit: a signature, a merged tag or a non-UTF-8 encoding.
+    fn unpreserved_commit_header(&self, sha: &str) -> Result<Option<String>> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("cat-file").arg("commit").arg(sha);
+        let raw = run_git_capture_bytes(cmd, "git cat-file commit")?;
+        let headers = raw
+            .split(|&b| b == b'\n')
+            .take_while(|line| !line.is_empty())
+            .filter(|line| !line.starts_with(b" "))
+            .map(String::from_utf8_lossy);
+        for header in headers {
+            let (name, value) = header.split_once(' ').unwrap_or((header.as_ref(), ""));
+            match name {
+                "gpgsig" | "gpgsig-sha256" => return Ok(Some("is signed".to_string())),
+                "mergetag" => return Ok(Some("records a merged tag".to_string())),
+                "encoding"
+                    if !value.eq_ignore_ascii_case("utf-8")
+                        && !value.eq_ignore_ascii_case("utf8") =>
+                {
+                    return Ok(Some(format!("uses the {value} encoding")));
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// The commits between `id` and `head` that rewriting `id` has to
+    /// recreate, parents first.
+    pub(super) fn descendants_up_to(&self, id: &str, head: &str) -> Result<Vec<BatchCommit>> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("log")
+            .arg("--reverse")
+            .arg("--topo-order")
+            .arg("--ancestry-path")
+            .arg("--date=raw")
+            .arg(BATCH_COMMIT_FORMAT)
+            .arg(format!("{id}..{head}"));
+        Ok(parse_batch_commits(&run_git_capture(
+            cmd,
+            "git log --ancestry-path",
+        )?))
+    }
+
+    /// Why rewriting `target` and the `rewritten` commits after it must not
+    /// happen: `target` is already in a published ref, or one of them carries
+    /// a header that `commit-tree` would drop. `verb` names the rewrite in the
+    /// message, e.g. "Rewording".
+    pub(super) fn history_rewrite_blocker(
+        &self,
+        verb: &str,
+        target: &BatchCommit,
+        rewritten: &[BatchCommit],
+    ) -> Result<Option<String>> {
+        let short = short_sha(&target.id);
+        // Every published ref that contains a later commit contains `target`.
+        if let Some(published) = self.published_ref_containing(&target.id)? {
+            return Ok(Some(format!(
+                "{short} is already in {published}. {verb} it would rewrite published history."
+            )));
+        }
+
+        for commit in std::iter::once(target).chain(rewritten) {
+            if let Some(reason) = self.unpreserved_commit_header(&commit.id)? {
+                let commit_short = short_sha(&commit.id);
+                return Ok(Some(if commit.id == target.id {
+                    format!(
+                        "{short} {reason}, which {} would not preserve.",
+                        verb.to_lowercase()
+                    )
+                } else {
+                    format!(
+                        "{verb} {short} would also rewrite {commit_short}, which {reason}, \
+                         and that cannot be preserved."
+                    )
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Everything a reword of `id` rewrites, or why it must not happen: the
+    /// commit has to be on the current branch, absent from every published
+    /// ref, and neither it nor a descendant may carry headers that
+    /// `commit-tree` would drop.
+    fn plan_reword(&self, id: &CommitId) -> Result<RewordPlan> {
+        validate_hex_commit_id(id)?;
+
+        let mut head_cmd = self.git_workdir_cmd();
+        head_cmd.arg("rev-parse").arg("HEAD");
+        let head = run_git_capture(head_cmd, "git rev-parse HEAD")?
+            .trim()
+            .to_string();
+
+        let mut target_cmd = self.git_workdir_cmd();
+        target_cmd
+            .arg("log")
+            .arg("-1")
+            .arg("--date=raw")
+            .arg(BATCH_COMMIT_FORMAT)
+            .arg(id.as_ref());
+        let target = parse_batch_commits(&run_git_capture(target_cmd, "git log -1")?)
+            .into_iter()
+            .next()
+            .ok_or_else(|| reword_error(format!("Commit {} not found.", id.as_ref())))?;
+        let short = short_sha(&target.id).to_string();
+
+        if !self.is_ancestor(&target.id, &head)? {
+            return Ok(RewordPlan::Blocked(format!(
+                "{short} is not on the current branch; only its commits can be reworded."
+            )));
+        }
+        let descendants = self.descendants_up_to(&target.id, &head)?;
+        if let Some(reason) = self.history_rewrite_blocker("Rewording", &target, &descendants)? {
+            return Ok(RewordPlan::Blocked(reason));
+        }
+
+        Ok(RewordPlan::Ready {
+            head,
+            target,
+            descendants,
+        })
+    }
+
+    pub(super) fn reword_commit_blocker_impl(&self, id: &CommitId) -> Result<Option<String>> {
+        Ok(match self.plan_reword(id)? {
+            RewordPlan::Blocked(reason) => Some(reason),
+            RewordPlan::Ready { .. } => None,
+        })
+    }
+
+    /// Give `id` a new message and recreate every commit between it and HEAD
+    /// on top of it. Rewording never changes a tree, so each descendant,
+    /// merges included, keeps its tree and only has its rewritten parents
+    /// swapped in; the index and worktree are left alone.
+    pub(super) fn reword_commit_with_output_impl(
+        &self,
+        id: &CommitId,
+        message: &str,
+    ) -> Result<CommandOutput> {
+        validate_hex_commit_id(id)?;
+        if message.trim().is_empty() {
+            return Err(reword_error(
+                "The commit message cannot be empty.".to_string(),
+            ));
+        }
+
+        let (head, target, descendants) = match self.plan_reword(id)? {
+            RewordPlan::Blocked(reason) => return Err(reword_error(reason)),
+            RewordPlan::Ready {
+                head,
+                target,
+                descendants,
+            } => (head, target, descendants),
+        };
+        let short = short_sha(&target.id);
+
+        let mut rewritten: HashMap<String, String> = HashMap::default();
+        let target_parents: Vec<&str> = target.parents.iter().map(String::as_str).collect();
+        let new_target = self.commit_tree(&target, &target_parents, message)?;
+        rewritten.insert(target.id.clone(), new_target);
+        for commit in &descendants {
+            let parents: Vec<&str> = commit
+                .parents
+                .iter()
+                .map(|parent| rewritten.get(parent).unwrap_or(parent).as_str())
+                .collect();
+            let new_id = self.commit_tree(commit, &parents, &commit.message)?;
+            rewritten.insert(commit.id.clone(), new_id);
+        }
+
+        let new_head = rewritten
+            .get(&head)
+            .cloned()
+            .ok_or_else(|| reword_error("HEAD could not be rewritten.".to_string()))?;
+        let mut update_cmd = self.git_workdir_cmd();
+        update_cmd
+            .arg("update-ref")
+            .arg("-m")
+            .arg(format!("reword {short}"))
+            .arg("HEAD")
+            .arg(&new_head)
+            .arg(&head);
+        let mut output = run_git_with_output(update_cmd, "git update-ref HEAD")?;
+        output.command = format!("Reword {short}");
+        output.stdout = format!(
+            "Rewrote {} commit{}",
+            descendants.len() + 1,
+            if descendants.is_empty() { "" } else { "s" }
+        );
+        Ok(output)
+    }
+}
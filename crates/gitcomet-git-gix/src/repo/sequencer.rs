@@ -0,0 +1,249 @@
+use super::GixRepo;
+use crate::util::{run_git_capture, run_git_with_output};
+use gitcomet_core::domain::{
+    OperationProgress, RepoOperationAction, RepoOperationKind, RepoOperationState,
+};
+use gitcomet_core::error::{Error, ErrorKind};
+use gitcomet_core::services::{CommandOutput, Result};
+use std::path::Path;
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let trimmed = contents.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+fn read_count(path: &Path) -> Option<usize> {
+    read_trimmed(path)?.parse().ok()
+}
+
+fn first_line(text: &str) -> Option<String> {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Extracts the subject from a sequencer todo line such as
+/// `pick 1a2b3c4 Fix the parser`.
+fn todo_line_subject(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let mut parts = line.splitn(3, char::is_whitespace);
+    let command = parts.next()?;
+    if !matches!(
+        command,
+        "pick" | "p" | "reword" | "r" | "edit" | "e" | "squash" | "s" | "fixup" | "f" | "revert"
+    ) {
+        return None;
+    }
+    let _sha = parts.next()?;
+    let subject = parts.next()?.trim();
+    (!subject.is_empty()).then(|| subject.to_string())
+}
+
+fn todo_command_count(todo: &str) -> usize {
+    todo.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .count()
+}
+
+/// `(k, n)` for a cherry-pick or revert sequence whose first remaining todo
+/// entry is the commit being applied.
+fn pick_step(remaining: usize, done: usize) -> Option<(usize, usize)> {
+    step(Some(done + 1), Some(done + remaining.max(1)))
+}
+
+fn step(current: Option<usize>, total: Option<usize>) -> Option<(usize, usize)> {
+    match (current, total) {
+        (Some(current), Some(total)) if total > 0 => Some((current.min(total), total)),
+        _ => None,
+    }
+}
+
+/// Progress of a merge-backend rebase, read from `.git/rebase-merge`.
+fn rebase_merge_progress(dir: &Path) -> OperationProgress {
+    let subject = read_trimmed(&dir.join("message"))
+        .and_then(|message| first_line(&message))
+        .or_else(|| {
+            let done = std::fs::read_to_string(dir.join("done")).ok()?;
+            done.lines().rev().find_map(todo_line_subject)
+        });
+    OperationProgress {
+        step: step(
+            read_count(&dir.join("msgnum")),
+            read_count(&dir.join("end")),
+        ),
+        subject,
+    }
+}
+
+/// Progress of `git am` or an apply-backend rebase, read from
+/// `.git/rebase-apply`.
+fn rebase_apply_progress(dir: &Path) -> OperationProgress {
+    let subject = std::fs::read_to_string(dir.join("info"))
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .find_map(|line| line.strip_prefix("Subject: "))
+                .map(|subject| subject.trim().to_string())
+        })
+        .filter(|subject| !subject.is_empty())
+        .or_else(|| read_trimmed(&dir.join("final-commit")).and_then(|msg| first_line(&msg)));
+    OperationProgress {
+        step: step(read_count(&dir.join("next")), read_count(&dir.join("last"))),
+        subject,
+    }
+}
+
+impl GixRepo {
+    fn commit_subject(&self, sha: &str) -> Option<String> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("log")
+            .arg("-1")
+            .arg("--format=%s")
+            .arg(sha)
+            .arg("--");
+        run_git_capture(cmd, "git log -1 --format=%s")
+            .ok()
+            .and_then(|subject| first_line(&subject))
+    }
+
+    fn commits_since(&self, sha: &str) -> Option<usize> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("rev-list")
+            .arg("--count")
+            .arg(format!("{sha}..HEAD"));
+        run_git_capture(cmd, "git rev-list --count")
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    /// Progress of a cherry-pick or revert, read from `.git/sequencer`. The
+    /// remaining picks are in `todo`; git writes no `done` list for these
+    /// sequences, so without one the finished picks are the commits made
+    /// since the sequence started at `sequencer/head`. A single pick has no
+    /// sequencer directory and is step 1 of 1.
+    fn pick_progress(&self, git_dir: &Path, head_file: &str) -> OperationProgress {
+        let sequencer = git_dir.join("sequencer");
+        let todo = std::fs::read_to_string(sequencer.join("todo")).ok();
+        let subject = read_trimmed(&git_dir.join(head_file))
+            .and_then(|sha| self.commit_subject(&sha))
+            .or_else(|| todo.as_deref()?.lines().find_map(todo_line_subject));
+        let step = match todo.as_deref() {
+            None => pick_step(1, 0),
+            Some(todo) => {
+                let done = match std::fs::read_to_string(sequencer.join("done")) {
+                    Ok(done) => Some(todo_command_count(&done)),
+                    Err(_) => read_trimmed(&sequencer.join("head"))
+                        .and_then(|head| self.commits_since(&head)),
+                };
+                done.and_then(|done| pick_step(todo_command_count(todo), done))
+            }
+        };
+        OperationProgress { step, subject }
+    }
+
+    pub(super) fn operation_state_impl(&self) -> Result<Option<RepoOperationState>> {
+        let repo = self._repo.to_thread_local();
+        let git_dir = repo.path();
+        let state = match repo.state() {
+            None => return Ok(None),
+            Some(gix::state::InProgress::Rebase | gix::state::InProgress::RebaseInteractive) => {
+                RepoOperationState::Rebase(rebase_merge_progress(&git_dir.join("rebase-merge")))
+            }
+            Some(gix::state::InProgress::ApplyMailboxRebase) => {
+                RepoOperationState::Rebase(rebase_apply_progress(&git_dir.join("rebase-apply")))
+            }
+            Some(gix::state::InProgress::ApplyMailbox) => RepoOperationState::ApplyMailbox(
+                rebase_apply_progress(&git_dir.join("rebase-apply")),
+            ),
+            Some(gix::state::InProgress::Merge) => RepoOperationState::Merge,
+            Some(
+                gix::state::InProgress::CherryPick | gix::state::InProgress::CherryPickSequence,
+            ) => RepoOperationState::CherryPick(self.pick_progress(git_dir, "CHERRY_PICK_HEAD")),
+            Some(gix::state::InProgress::Revert | gix::state::InProgress::RevertSequence) => {
+                RepoOperationState::Revert(self.pick_progress(git_dir, "REVERT_HEAD"))
+            }
+            Some(gix::state::InProgress::Bisect) => RepoOperationState::Bisect,
+        };
+        Ok(Some(state))
+    }
+
+    pub(super) fn operation_command_with_output_impl(
+        &self,
+        kind: RepoOperationKind,
+        action: RepoOperationAction,
+    ) -> Result<CommandOutput> {
+        if !kind.supports(action) {
+            return Err(Error::new(ErrorKind::Backend(format!(
+                "{} cannot be {}",
+                kind.label(),
+                match action {
+                    RepoOperationAction::Continue => "continued",
+                    RepoOperationAction::Skip => "skipped",
+                    RepoOperationAction::Abort => "aborted",
+                }
+            ))));
+        }
+
+        let subcommand = kind.git_subcommand();
+        let flag = match (kind, action) {
+            (RepoOperationKind::Bisect, RepoOperationAction::Skip) => "skip",
+            (RepoOperationKind::Bisect, _) => "reset",
+            (_, RepoOperationAction::Continue) => "--continue",
+            (_, RepoOperationAction::Skip) => "--skip",
+            (_, RepoOperationAction::Abort) => "--abort",
+        };
+
+        let mut cmd = self.git_workdir_cmd();
+        if action == RepoOperationAction::Continue {
+            // Keep the recorded message instead of waiting on an editor.
+            cmd.env("GIT_EDITOR", ":");
+        }
+        cmd.arg(subcommand).arg(flag);
+        run_git_with_output(cmd, &format!("git {subcommand} {flag}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn todo_line_subject_skips_comments_and_non_pick_commands() {
+        assert_eq!(
+            todo_line_subject("pick 1a2b3c4 Fix the parser"),
+            Some("Fix the parser".to_string())
+        );
+        assert_eq!(
+            todo_line_subject("fixup abcdef0 fixup! Fix the parser"),
+            Some("fixup! Fix the parser".to_string())
+        );
+        assert_eq!(todo_line_subject("# pick 1a2b3c4 Commented"), None);
+        assert_eq!(todo_line_subject("exec make test"), None);
+        assert_eq!(todo_line_subject("pick 1a2b3c4"), None);
+    }
+
+    #[test]
+    fn pick_step_counts_the_current_todo_entry() {
+        let todo = "pick 1a2b3c4 c2\n# comment\npick 5d6e7f8 c3\n\npick 9a0b1c2 c4\n";
+        assert_eq!(todo_command_count(todo), 3);
+        assert_eq!(pick_step(todo_command_count(todo), 1), Some((2, 4)));
+        assert_eq!(pick_step(1, 0), Some((1, 1)));
+        assert_eq!(pick_step(0, 3), Some((4, 4)));
+    }
+
+    #[test]
+    fn step_requires_both_counts_and_clamps_to_total() {
+        assert_eq!(step(Some(2), Some(5)), Some((2, 5)));
+        assert_eq!(step(Some(7), Some(5)), Some((5, 5)));
+        assert_eq!(step(None, Some(5)), None);
+        assert_eq!(step(Some(1), Some(0)), None);
+    }
+}
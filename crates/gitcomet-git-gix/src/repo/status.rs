@@ -96,6 +96,7 @@ impl GixRepo {
                 // `git status` parity, so skip gix's default submodule probing on the
                 // common no-submodule path.
                 .index_worktree_submodules(None)
+                .tree_index_track_renames(self.tree_index_track_renames())
                 .untracked_files(gix::status::UntrackedFiles::Files);
             let mut staged = Vec::new();
             let mut iter = platform
@@ -203,7 +204,11 @@ impl GixRepo {
         // `tree_index_status()` diffs a tree against the index, so resolve HEAD to HEAD^{tree}
         // while continuing to cache by commit id.
         let head_tree_id = tree_id_for_commit(&repo, &head_oid)?;
-        let mut staged = collect_staged_status_from_tree_index(&repo, &head_tree_id)?;
+        let mut staged = collect_staged_status_from_tree_index(
+            &repo,
+            &head_tree_id,
+            self.tree_index_track_renames(),
+        )?;
         if self.may_have_gitlink_status_supplement(&repo) {
             supplement_gitlink_status_from_porcelain(
                 &self.spec.workdir,
@@ -227,6 +232,15 @@ impl GixRepo {
         head_upstream_divergence(&repo)
     }
 
+    fn tree_index_track_renames(&self) -> gix::status::tree_index::TrackRenames {
+        match self.rename_detection() {
+            Some(detection) => {
+                gix::status::tree_index::TrackRenames::Given(super::gix_rewrites(detection))
+            }
+            None => gix::status::tree_index::TrackRenames::AsConfigured,
+        }
+    }
+
     fn cached_staged_status(
         &self,
         head_oid: Option<gix::ObjectId>,
@@ -312,6 +326,8 @@ fn apply_unmerged_conflicts(repo: &gix::Repository, unstaged: &mut Vec<FileStatu
                 path,
                 kind: FileStatusKind::Conflicted,
                 conflict: Some(conflict_kind),
+                old_path: None,
+                similarity: None,
             });
         }
     }
@@ -329,21 +345,16 @@ fn tree_id_for_commit(repo: &gix::Repository, commit_id: &gix::ObjectId) -> Resu
 fn collect_staged_status_from_tree_index(
     repo: &gix::Repository,
     head_oid: &gix::ObjectId,
+    track_renames: gix::status::tree_index::TrackRenames,
 ) -> Result<Vec<FileStatus>> {
     let index = repo
         .index_or_empty()
         .map_err(|e| Error::new(ErrorKind::Backend(format!("gix index: {e}"))))?;
     let mut staged = Vec::new();
-    repo.tree_index_status(
-        head_oid,
-        &index,
-        None,
-        gix::status::tree_index::TrackRenames::AsConfigured,
-        |change, _, _| {
-            collect_tree_index_change(change, &mut staged)?;
-            Ok::<_, Error>(std::ops::ControlFlow::Continue(()))
-        },
-    )
+    repo.tree_index_status(head_oid, &index, None, track_renames, |change, _, _| {
+        collect_tree_index_change(change, &mut staged)?;
+        Ok::<_, Error>(std::ops::ControlFlow::Continue(()))
+    })
     .map_err(|e| Error::new(ErrorKind::Backend(format!("gix tree/index status: {e}"))))?;
     Ok(staged)
 }
@@ -351,7 +362,7 @@ fn collect_staged_status_from_tree_index(
 fn kind_priority(kind: FileStatusKind) -> u8 {
     match kind {
         FileStatusKind::Conflicted => 5,
-        FileStatusKind::Renamed => 4,
+        FileStatusKind::Renamed | FileStatusKind::Copied => 4,
         FileStatusKind::Deleted => 3,
         FileStatusKind::Added => 2,
         FileStatusKind::Modified => 1,
@@ -463,6 +474,8 @@ fn collect_index_worktree_item(
                     path,
                     kind,
                     conflict,
+                    old_path: None,
+                    similarity: None,
                 },
             );
         }
@@ -481,16 +494,20 @@ fn collect_index_worktree_item(
                     path,
                     kind,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             );
         }
         gix::status::index_worktree::Item::Rewrite {
+            source,
             dirwalk_entry,
+            diff,
             copy,
             ..
         } => {
             let kind = if copy {
-                FileStatusKind::Added
+                FileStatusKind::Copied
             } else {
                 FileStatusKind::Renamed
             };
@@ -498,6 +515,8 @@ fn collect_index_worktree_item(
                 dirwalk_entry.rela_path.as_ref(),
                 "gix status rewrite path",
             )?;
+            let old_path =
+                path_buf_from_git_bytes(source.rela_path(), "gix status rewrite source path")?;
             push_unstaged_status(
                 unstaged,
                 has_conflicted_unstaged,
@@ -505,6 +524,8 @@ fn collect_index_worktree_item(
                     path,
                     kind,
                     conflict: None,
+                    old_path: Some(old_path),
+                    similarity: super::similarity_percent(diff),
                 },
             );
         }
@@ -799,6 +820,8 @@ fn collect_index_worktree_status_entry<U>(
                     path,
                     kind,
                     conflict,
+                    old_path: None,
+                    similarity: None,
                 },
             );
         }
@@ -820,16 +843,20 @@ fn collect_index_worktree_status_entry<U>(
                     path,
                     kind,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             );
         }
         gix::status::plumbing::index_as_worktree_with_renames::Entry::Rewrite {
+            source,
             dirwalk_entry,
+            diff,
             copy,
             ..
         } => {
             let kind = if copy {
-                FileStatusKind::Added
+                FileStatusKind::Copied
             } else {
                 FileStatusKind::Renamed
             };
@@ -837,6 +864,8 @@ fn collect_index_worktree_status_entry<U>(
                 dirwalk_entry.rela_path.as_ref(),
                 "gix status rewrite path",
             )?;
+            let old_path =
+                path_buf_from_git_bytes(source.rela_path(), "gix status rewrite source path")?;
             push_unstaged_status(
                 unstaged,
                 has_conflicted_unstaged,
@@ -844,6 +873,8 @@ fn collect_index_worktree_status_entry<U>(
                     path,
                     kind,
                     conflict: None,
+                    old_path: Some(old_path),
+                    similarity: super::similarity_percent(diff),
                 },
             );
         }
@@ -902,33 +933,48 @@ fn collect_tree_index_change(
 ) -> Result<()> {
     use gix::diff::index::ChangeRef;
 
-    let (path, kind) = match change {
+    let (path, kind, old_path) = match change {
         ChangeRef::Addition { location, .. } => (
             path_buf_from_git_bytes(location.as_ref(), "gix status staged addition path")?,
             FileStatusKind::Added,
+            None,
         ),
         ChangeRef::Deletion { location, .. } => (
             path_buf_from_git_bytes(location.as_ref(), "gix status staged deletion path")?,
             FileStatusKind::Deleted,
+            None,
         ),
         ChangeRef::Modification { location, .. } => (
             path_buf_from_git_bytes(location.as_ref(), "gix status staged modification path")?,
             FileStatusKind::Modified,
+            None,
         ),
-        ChangeRef::Rewrite { location, copy, .. } => (
+        ChangeRef::Rewrite {
+            source_location,
+            location,
+            copy,
+            ..
+        } => (
             path_buf_from_git_bytes(location.as_ref(), "gix status staged rewrite path")?,
             if copy {
-                FileStatusKind::Added
+                FileStatusKind::Copied
             } else {
                 FileStatusKind::Renamed
             },
+            Some(path_buf_from_git_bytes(
+                source_location.as_ref(),
+                "gix status staged rewrite source path",
+            )?),
         ),
     };
 
+    // Tree/index rewrites carry no line stats, so the similarity stays unknown.
     staged.push(FileStatus {
         path,
         kind,
         conflict: None,
+        old_path,
+        similarity: None,
     });
     Ok(())
 }
@@ -983,6 +1029,7 @@ fn map_porcelain_v2_status_char(ch: char) -> Option<FileStatusKind> {
         'A' => Some(FileStatusKind::Added),
         'D' => Some(FileStatusKind::Deleted),
         'R' => Some(FileStatusKind::Renamed),
+        'C' => Some(FileStatusKind::Copied),
         'U' => Some(FileStatusKind::Conflicted),
         _ => None,
     }
@@ -994,6 +1041,8 @@ fn push_status_entry(entries: &mut Vec<FileStatus>, path: PathBuf, kind: FileSta
         path,
         kind,
         conflict: None,
+        old_path: None,
+        similarity: None,
     });
 }
 
@@ -1228,6 +1277,8 @@ mod tests {
             path: PathBuf::from(path),
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -1236,6 +1287,8 @@ mod tests {
             path: PathBuf::from(path),
             kind: FileStatusKind::Conflicted,
             conflict: Some(conflict),
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -1503,6 +1556,7 @@ mod tests {
             ('A', Some(FileStatusKind::Added)),
             ('D', Some(FileStatusKind::Deleted)),
             ('R', Some(FileStatusKind::Renamed)),
+            ('C', Some(FileStatusKind::Copied)),
             ('U', Some(FileStatusKind::Conflicted)),
             ('.', None),
             ('?', None),
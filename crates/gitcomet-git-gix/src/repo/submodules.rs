@@ -2,7 +2,7 @@ use super::GixRepo;
 use super::history::gix_head_id_or_none;
 use crate::util::{
     bytes_to_text_preserving_utf8, git_workdir_cmd_for, path_buf_from_git_bytes,
-    run_git_raw_output, run_git_simple, run_git_with_output,
+    run_git_raw_output, run_git_simple, run_git_with_output, validate_ref_like_arg,
 };
 use gitcomet_core::domain::{
     CommitId, DiffTarget, FileStatus, RepoStatus, Submodule, SubmoduleDiffRange,
@@ -12,7 +12,8 @@ use gitcomet_core::domain::{
 use gitcomet_core::error::{Error, ErrorKind, GitFailure};
 use gitcomet_core::path_utils::canonicalize_or_original;
 use gitcomet_core::services::{
-    CommandOutput, Result, SubmoduleTrustDecision, SubmoduleTrustTarget,
+    CommandOutput, Result, SubmoduleBatchCommand, SubmoduleCommandResult, SubmoduleTrustDecision,
+    SubmoduleTrustTarget,
 };
 use gix::bstr::ByteSlice as _;
 use std::collections::BTreeMap;
@@ -56,6 +57,7 @@ impl GixRepo {
             DiffTarget::Commit {
                 commit_id,
                 path: Some(path),
+                ..
             } => submodule_commit_diff_summary(&repo, commit_id, path),
             _ => Err(Error::new(ErrorKind::Unsupported(
                 "submodule summaries require a submodule working-tree target or committed submodule path",
@@ -113,10 +115,7 @@ impl GixRepo {
             &mut sources,
         )?;
         if !found {
-            return Err(Error::new(ErrorKind::Backend(format!(
-                "submodule '{}' is not configured in this repository",
-                path.display()
-            ))));
+            return Err(submodule_not_configured_error(path));
         }
         if sources.is_empty() {
             Ok(SubmoduleTrustDecision::Proceed)
@@ -213,10 +212,7 @@ impl GixRepo {
         let found =
             load_target_submodule_recursive(&repo, &trust_root, Path::new(""), path, &mut outputs)?;
         if !found {
-            return Err(Error::new(ErrorKind::Backend(format!(
-                "submodule '{}' is not configured in this repository",
-                path.display()
-            ))));
+            return Err(submodule_not_configured_error(path));
         }
         if outputs.is_empty() {
             Ok(CommandOutput::empty_success(format!(
@@ -321,6 +317,185 @@ impl GixRepo {
             exit_code: Some(0),
         })
     }
+
+    pub(super) fn sync_submodules_with_output_impl(&self) -> Result<CommandOutput> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("submodule").arg("sync").arg("--recursive");
+        run_git_with_output(cmd, "git submodule sync --recursive")
+    }
+
+    pub(super) fn set_submodule_branch_with_output_impl(
+        &self,
+        path: &Path,
+        branch: Option<&str>,
+    ) -> Result<CommandOutput> {
+        let repo = self.reopen_repo()?;
+        let located = locate_submodule(&repo, Path::new(""), path)?
+            .ok_or_else(|| submodule_not_configured_error(path))?;
+
+        let mut cmd = git_workdir_cmd_for(&located.parent_workdir);
+        cmd.arg("submodule").arg("set-branch");
+        let command = match branch {
+            Some(branch) => {
+                validate_ref_like_arg(branch, "branch")?;
+                cmd.arg("--branch").arg(branch);
+                format!(
+                    "git submodule set-branch --branch {branch} -- {}",
+                    path.display()
+                )
+            }
+            None => {
+                cmd.arg("--default");
+                format!("git submodule set-branch --default -- {}", path.display())
+            }
+        };
+        cmd.arg("--").arg(&located.relative_path);
+        run_git_with_output(cmd, &command)
+    }
+
+    pub(super) fn update_submodule_remote_with_output_impl(
+        &self,
+        path: &Path,
+    ) -> Result<CommandOutput> {
+        let repo = self.reopen_repo()?;
+        let trust_root = repo_workdir_for_submodule_trust(&repo).to_path_buf();
+        let located = locate_submodule(&repo, Path::new(""), path)?
+            .ok_or_else(|| submodule_not_configured_error(path))?;
+
+        let mut cmd = git_workdir_cmd_for(&located.parent_workdir);
+        if let Some(target) = located.local_source.as_ref() {
+            if !submodule_source_trusted(&trust_root, target)? {
+                return Err(untrusted_local_submodule_error(target, "update"));
+            }
+            allow_file_submodule_transport(&mut cmd);
+        }
+        cmd.arg("submodule")
+            .arg("update")
+            .arg("--init")
+            .arg("--remote")
+            .arg("--")
+            .arg(&located.relative_path);
+        run_git_with_output(
+            cmd,
+            &format!("git submodule update --init --remote -- {}", path.display()),
+        )
+    }
+
+    pub(super) fn run_in_submodules_impl(
+        &self,
+        command: &SubmoduleBatchCommand,
+    ) -> Result<Vec<SubmoduleCommandResult>> {
+        let args = command.git_args();
+        if args.is_empty() {
+            return Err(Error::new(ErrorKind::Backend(
+                "no git command given to run in submodules".to_string(),
+            )));
+        }
+
+        let repo = self.reopen_repo()?;
+        let mut targets = Vec::new();
+        collect_initialized_submodules(&repo, Path::new(""), &mut targets)?;
+
+        let display = command.display();
+        Ok(targets
+            .into_iter()
+            .map(|(path, workdir)| {
+                let mut cmd = git_workdir_cmd_for(&workdir);
+                cmd.args(&args);
+                let result = run_git_with_output(cmd, &format!("{display} in {}", path.display()));
+                SubmoduleCommandResult { path, result }
+            })
+            .collect())
+    }
+}
+
+/// Where a submodule lives: the workdir of the repository that directly
+/// contains it, and its path within that repository.
+struct LocatedSubmodule {
+    parent_workdir: PathBuf,
+    relative_path: PathBuf,
+    local_source: Option<SubmoduleTrustTarget>,
+}
+
+fn locate_submodule(
+    repo: &gix::Repository,
+    prefix: &Path,
+    target_path: &Path,
+) -> Result<Option<LocatedSubmodule>> {
+    let Some(submodules) = repo
+        .submodules()
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix submodules: {e}"))))?
+    else {
+        return Ok(None);
+    };
+
+    let current_workdir = repo_workdir_for_submodule_trust(repo);
+    for submodule in submodules {
+        let relative_path = submodule
+            .path()
+            .map_err(|e| Error::new(ErrorKind::Backend(format!("gix submodule path: {e}"))))
+            .and_then(|path| pathbuf_from_gix_path(path.as_ref()))?;
+        let full_path = prefix.join(&relative_path);
+
+        if full_path == target_path {
+            let local_source =
+                trust_target_from_submodule(current_workdir, &full_path, &submodule)?;
+            return Ok(Some(LocatedSubmodule {
+                parent_workdir: current_workdir.to_path_buf(),
+                relative_path,
+                local_source,
+            }));
+        }
+
+        if target_path.starts_with(&full_path)
+            && let Some(nested_repo) = open_gitlink_repo(repo, &relative_path)?
+            && let Some(located) = locate_submodule(&nested_repo, &full_path, target_path)?
+        {
+            return Ok(Some(located));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Checked-out submodules as `(path from the top-level workdir, workdir)`,
+/// each parent listed before its own submodules.
+fn collect_initialized_submodules(
+    repo: &gix::Repository,
+    prefix: &Path,
+    out: &mut Vec<(PathBuf, PathBuf)>,
+) -> Result<()> {
+    let Some(submodules) = repo
+        .submodules()
+        .map_err(|e| Error::new(ErrorKind::Backend(format!("gix submodules: {e}"))))?
+    else {
+        return Ok(());
+    };
+
+    for submodule in submodules {
+        let relative_path = submodule
+            .path()
+            .map_err(|e| Error::new(ErrorKind::Backend(format!("gix submodule path: {e}"))))
+            .and_then(|path| pathbuf_from_gix_path(path.as_ref()))?;
+        let Some(nested_repo) = open_gitlink_repo(repo, &relative_path)? else {
+            continue;
+        };
+        let full_path = prefix.join(&relative_path);
+        out.push((
+            full_path.clone(),
+            repo_workdir_for_submodule_trust(&nested_repo).to_path_buf(),
+        ));
+        collect_initialized_submodules(&nested_repo, &full_path, out)?;
+    }
+
+    Ok(())
+}
+
+fn submodule_not_configured_error(path: &Path) -> Error {
+    Error::new(ErrorKind::Backend(format!(
+        "submodule '{}' is not configured in this repository",
+        path.display()
+    )))
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -965,7 +1140,8 @@ fn git_range_status_changes(
             continue;
         };
         let kind = match status_code {
-            b'A' | b'C' => gitcomet_core::domain::FileStatusKind::Added,
+            b'A' => gitcomet_core::domain::FileStatusKind::Added,
+            b'C' => gitcomet_core::domain::FileStatusKind::Copied,
             b'D' => gitcomet_core::domain::FileStatusKind::Deleted,
             b'R' => gitcomet_core::domain::FileStatusKind::Renamed,
             b'U' => gitcomet_core::domain::FileStatusKind::Conflicted,
@@ -1856,6 +2032,7 @@ mod tests {
             .submodule_diff_summary_impl(&DiffTarget::WorkingTree {
                 path: submodule_path.into(),
                 area: DiffArea::Staged,
+                old_path: None,
             })
             .expect("staged submodule removal summary");
         let staged_range = summary
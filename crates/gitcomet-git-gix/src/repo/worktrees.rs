@@ -1,6 +1,8 @@
 use super::GixRepo;
-use crate::util::{path_buf_from_git_bytes, run_git_capture_bytes, run_git_with_output};
-use gitcomet_core::domain::{CommitId, Worktree};
+use crate::util::{
+    git_workdir_cmd_for, path_buf_from_git_bytes, run_git_capture_bytes, run_git_with_output,
+};
+use gitcomet_core::domain::{CommitId, UpstreamDivergence, Worktree, WorktreeSummary};
 use gitcomet_core::path_utils::canonicalize_or_original;
 use gitcomet_core::services::{CommandOutput, Result};
 use std::path::Path;
@@ -46,6 +48,102 @@ impl GixRepo {
             &format!("git worktree remove --force {}", path.display()),
         )
     }
+
+    pub(super) fn lock_worktree_with_output_impl(
+        &self,
+        path: &Path,
+        reason: Option<&str>,
+    ) -> Result<CommandOutput> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("worktree").arg("lock");
+        if let Some(reason) = reason.filter(|reason| !reason.trim().is_empty()) {
+            cmd.arg("--reason").arg(reason.trim());
+        }
+        cmd.arg(path);
+        run_git_with_output(cmd, &format!("git worktree lock {}", path.display()))
+    }
+
+    pub(super) fn unlock_worktree_with_output_impl(&self, path: &Path) -> Result<CommandOutput> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("worktree").arg("unlock").arg(path);
+        run_git_with_output(cmd, &format!("git worktree unlock {}", path.display()))
+    }
+
+    pub(super) fn move_worktree_with_output_impl(
+        &self,
+        path: &Path,
+        new_path: &Path,
+    ) -> Result<CommandOutput> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("worktree").arg("move").arg(path).arg(new_path);
+        run_git_with_output(
+            cmd,
+            &format!(
+                "git worktree move {} {}",
+                path.display(),
+                new_path.display()
+            ),
+        )
+    }
+
+    pub(super) fn prune_worktrees_with_output_impl(&self) -> Result<CommandOutput> {
+        let mut cmd = self.git_workdir_cmd();
+        cmd.arg("worktree").arg("prune").arg("--verbose");
+        run_git_with_output(cmd, "git worktree prune --verbose")
+    }
+
+    pub(super) fn worktree_summary_impl(&self, path: &Path) -> Result<WorktreeSummary> {
+        let mut cmd = git_workdir_cmd_for(path);
+        cmd.arg("status")
+            .arg("--porcelain=v2")
+            .arg("--branch")
+            .arg("-z");
+        let output = run_git_capture_bytes(cmd, "git status --porcelain=v2 --branch -z")?;
+        let (dirty_files, upstream) = parse_worktree_status_summary(&output);
+        Ok(WorktreeSummary {
+            path: path.to_path_buf(),
+            dirty_files,
+            upstream,
+        })
+    }
+}
+
+/// Counts the changed entries in `git status --porcelain=v2 --branch -z`
+/// output and reads the `# branch.ab` header when the branch has an upstream.
+fn parse_worktree_status_summary(output: &[u8]) -> (usize, Option<UpstreamDivergence>) {
+    let mut dirty_files = 0;
+    let mut upstream = None;
+    let mut fields = output.split(|b| *b == b'\0');
+    while let Some(field) = fields.next() {
+        if field.is_empty() {
+            continue;
+        }
+        if let Some(rest) = field.strip_prefix(b"# branch.ab ") {
+            let rest = String::from_utf8_lossy(rest);
+            let mut counts = rest.split_whitespace();
+            let ahead = counts
+                .next()
+                .and_then(|count| count.strip_prefix('+'))
+                .and_then(|count| count.parse().ok());
+            let behind = counts
+                .next()
+                .and_then(|count| count.strip_prefix('-'))
+                .and_then(|count| count.parse().ok());
+            if let (Some(ahead), Some(behind)) = (ahead, behind) {
+                upstream = Some(UpstreamDivergence { ahead, behind });
+            }
+            continue;
+        }
+        if field.starts_with(b"#") {
+            continue;
+        }
+        dirty_files += 1;
+        if field.starts_with(b"2 ") {
+            // Renames and copies carry their original path as a separate field.
+            fields.next();
+        }
+    }
+    (dirty_files, upstream)
 }
 
 fn parse_git_worktree_list_porcelain_z(output: &[u8]) -> Result<Vec<Worktree>> {
@@ -71,6 +169,8 @@ fn parse_git_worktree_list_porcelain_z(output: &[u8]) -> Result<Vec<Worktree>> {
                 head: None,
                 branch: None,
                 detached: false,
+                locked: None,
+                prunable: None,
             });
             continue;
         }
@@ -93,6 +193,14 @@ fn parse_git_worktree_list_porcelain_z(output: &[u8]) -> Result<Vec<Worktree>> {
         } else if field == b"detached" {
             wt.detached = true;
             wt.branch = None;
+        } else if field == b"locked" {
+            wt.locked = Some(String::new());
+        } else if let Some(rest) = field.strip_prefix(b"locked ") {
+            wt.locked = Some(String::from_utf8_lossy(rest).into_owned());
+        } else if field == b"prunable" {
+            wt.prunable = Some(String::new());
+        } else if let Some(rest) = field.strip_prefix(b"prunable ") {
+            wt.prunable = Some(String::from_utf8_lossy(rest).into_owned());
         }
     }
 
@@ -110,7 +218,8 @@ fn canonicalize_worktree_path(worktree: &mut Worktree) {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_git_worktree_list_porcelain_z;
+    use super::{parse_git_worktree_list_porcelain_z, parse_worktree_status_summary};
+    use gitcomet_core::domain::UpstreamDivergence;
     use gitcomet_core::path_utils::canonicalize_or_original;
     use std::path::PathBuf;
 
@@ -140,6 +249,49 @@ mod tests {
         assert!(parsed[1].detached);
     }
 
+    #[test]
+    fn parse_git_worktree_list_porcelain_z_reads_locked_and_prunable_state() {
+        let parsed = parse_git_worktree_list_porcelain_z(
+            b"worktree /repo\0branch refs/heads/main\0\0worktree /usb\0detached\0locked on a usb drive\0\0worktree /bare-lock\0detached\0locked\0\0worktree /gone\0detached\0prunable gitdir file points to non-existent location\0\0",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.len(), 4);
+        assert!(!parsed[0].is_locked());
+        assert!(!parsed[0].is_prunable());
+        assert_eq!(parsed[1].locked.as_deref(), Some("on a usb drive"));
+        assert_eq!(parsed[2].locked.as_deref(), Some(""));
+        assert!(!parsed[2].is_prunable());
+        assert_eq!(
+            parsed[3].prunable.as_deref(),
+            Some("gitdir file points to non-existent location")
+        );
+        assert!(!parsed[3].is_locked());
+    }
+
+    #[test]
+    fn parse_worktree_status_summary_counts_entries_and_reads_divergence() {
+        let (dirty, upstream) = parse_worktree_status_summary(
+            b"# branch.oid 1111111111111111111111111111111111111111\0# branch.head main\0# branch.upstream origin/main\0# branch.ab +2 -3\0\
+1 .M N... 100644 100644 100644 aaaa aaaa a.txt\0\
+2 R. N... 100644 100644 100644 aaaa aaaa R100 new.txt\0old.txt\0\
+? untracked.txt\0",
+        );
+        assert_eq!(dirty, 3);
+        assert_eq!(
+            upstream,
+            Some(UpstreamDivergence {
+                ahead: 2,
+                behind: 3
+            })
+        );
+
+        let (dirty, upstream) =
+            parse_worktree_status_summary(b"# branch.oid (initial)\0# branch.head main\0");
+        assert_eq!(dirty, 0);
+        assert_eq!(upstream, None);
+    }
+
     #[test]
     fn parse_git_worktree_list_porcelain_z_ignores_noise_before_first_worktree() {
         let parsed = parse_git_worktree_list_porcelain_z(
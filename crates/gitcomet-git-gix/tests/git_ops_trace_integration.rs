@@ -93,6 +93,7 @@ fn git_op_trace_captures_backend_entry_points_once_per_operation() {
         .diff_parsed(&DiffTarget::WorkingTree {
             path: PathBuf::from("story.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .expect("diff parsed");
     assert!(!diff.lines.is_empty());
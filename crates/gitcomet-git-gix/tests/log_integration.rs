@@ -1343,6 +1343,9 @@ fn commit_details_reports_root_and_rename_file_changes() {
             path: Path::new("old name.txt").to_path_buf(),
             kind: FileStatusKind::Added,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         }]
     );
 
@@ -1355,10 +1358,57 @@ fn commit_details_reports_root_and_rename_file_changes() {
             path: Path::new("new name.txt").to_path_buf(),
             kind: FileStatusKind::Renamed,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         }]
     );
 }
 
+#[test]
+fn commit_details_reads_linguist_generated_from_the_commit() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+
+    run_git(repo, &["init", "-b", "main"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+
+    std::fs::write(repo.join(".gitattributes"), "gen.js linguist-generated\n").unwrap();
+    std::fs::write(repo.join("gen.js"), "one\n").unwrap();
+    run_git(repo, &["add", "."]);
+    run_git(repo, &["-c", "commit.gpgsign=false", "commit", "-m", "A"]);
+    let marked_id = git_stdout(repo, &["rev-parse", "HEAD"]);
+
+    std::fs::write(repo.join(".gitattributes"), "").unwrap();
+    std::fs::write(repo.join("gen.js"), "two\n").unwrap();
+    run_git(repo, &["-c", "commit.gpgsign=false", "commit", "-am", "B"]);
+    let unmarked_id = git_stdout(repo, &["rev-parse", "HEAD"]);
+
+    // `check-attr --source` needs git 2.40; older git reads the worktree.
+    let supports_source = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["check-attr", "--source=HEAD", "diff", "--", "gen.js"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).unwrap();
+    let generated_in = |id: String| {
+        opened
+            .commit_details(&CommitId(id.into()))
+            .expect("commit details")
+            .files
+            .into_iter()
+            .find(|file| file.path == Path::new("gen.js"))
+            .map(|file| file.generated)
+    };
+    assert_eq!(generated_in(marked_id), Some(supports_source));
+    assert_eq!(generated_in(unmarked_id), Some(false));
+}
+
 #[test]
 fn reflog_head_returns_recent_entries_with_indices() {
     let dir = tempfile::tempdir().unwrap();
@@ -1,5 +1,5 @@
 use gitcomet_core::domain::CommitId;
-use gitcomet_core::services::GitBackend;
+use gitcomet_core::services::{FixupCommitKind, GitBackend};
 use gitcomet_git_gix::GixBackend;
 #[path = "support/test_git_env.rs"]
 mod test_git_env;
@@ -168,3 +168,382 @@ fn apply_unified_patch_to_worktree_applies_and_reverses() {
         "base\n"
     );
 }
+
+fn init_repo_with_commits(repo: &Path, subjects: &[&str]) -> Vec<CommitId> {
+    run_git(repo, &["init", "-b", "main"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+    run_git(repo, &["config", "core.autocrlf", "false"]);
+
+    subjects
+        .iter()
+        .map(|subject| {
+            fs::write(repo.join(format!("{subject}.txt")), format!("{subject}\n"))
+                .expect("write file");
+            run_git(repo, &["add", "."]);
+            run_git(repo, &["commit", "-m", subject]);
+            CommitId(run_git_capture(repo, &["rev-parse", "HEAD"]).trim().into())
+        })
+        .collect()
+}
+
+fn subjects(repo: &Path, range: &str) -> Vec<String> {
+    run_git_capture(repo, &["log", "--reverse", "--pretty=%s", range])
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn commit_batch_operations_use_topological_order() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path().join("repo");
+    fs::create_dir(&repo).expect("create repo dir");
+    let ids = init_repo_with_commits(&repo, &["base", "a", "b", "c"]);
+    let (a, b, c) = (ids[1].clone(), ids[2].clone(), ids[3].clone());
+
+    let backend = GixBackend;
+    let opened = backend.open(&repo).expect("open repository");
+
+    let series_path = dir.path().join("series.mbox");
+    opened
+        .export_patch_series_with_output(&[c.clone(), a.clone(), b.clone()], &series_path, false)
+        .expect("export patch series");
+    let series = fs::read_to_string(&series_path).expect("read series");
+    let positions: Vec<usize> = ["] a\n", "] b\n", "] c\n"]
+        .iter()
+        .map(|subject| series.find(subject).expect("subject in series"))
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+    run_git(&repo, &["checkout", "-b", "other", ids[0].as_ref()]);
+    opened
+        .cherry_pick_commits_with_output(&[c.clone(), a.clone()])
+        .expect("cherry-pick selection");
+    assert_eq!(subjects(&repo, "main~3..other"), vec!["a", "c"]);
+
+    run_git(&repo, &["checkout", "main"]);
+    opened
+        .revert_commits_with_output(&[a, b])
+        .expect("revert selection");
+    assert_eq!(
+        subjects(&repo, "main~2..main"),
+        vec!["Revert \"b\"", "Revert \"a\""]
+    );
+}
+
+#[test]
+fn squash_commits_replaces_range_and_replays_descendants() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path();
+    let ids = init_repo_with_commits(repo, &["base", "a", "b", "c", "d"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).expect("open repository");
+
+    opened
+        .squash_commits_with_output(&[ids[1].clone(), ids[3].clone()])
+        .expect_err("non-contiguous selection is rejected");
+
+    opened
+        .squash_commits_with_output(&[ids[2].clone(), ids[1].clone()])
+        .expect("squash a and b");
+    assert_eq!(subjects(repo, "HEAD"), vec!["base", "a", "c", "d"]);
+    assert_eq!(
+        run_git_capture(repo, &["log", "-1", "--pretty=%B", "HEAD~2"]).trim(),
+        "a\n\nb"
+    );
+    assert!(run_git_capture(repo, &["status", "--porcelain"]).is_empty());
+
+    let head = CommitId(run_git_capture(repo, &["rev-parse", "HEAD"]).trim().into());
+    let parent = CommitId(
+        run_git_capture(repo, &["rev-parse", "HEAD~1"])
+            .trim()
+            .into(),
+    );
+    opened
+        .squash_commits_with_output(&[parent, head])
+        .expect("squash at HEAD");
+    assert_eq!(subjects(repo, "HEAD"), vec!["base", "a", "c"]);
+    assert!(repo.join("d.txt").exists());
+}
+
+#[test]
+fn squash_commits_refuses_published_and_signed_commits() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path();
+    let ids = init_repo_with_commits(repo, &["base", "a", "b", "c"]);
+    run_git(repo, &["branch", "upstream", ids[1].as_ref()]);
+    run_git(repo, &["branch", "--set-upstream-to=upstream"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).expect("open repository");
+
+    let err = opened
+        .squash_commits_with_output(&[ids[1].clone(), ids[2].clone()])
+        .expect_err("squash of an upstream commit is refused");
+    assert!(err.to_string().contains("upstream"), "{err}");
+
+    let tree = run_git_capture(repo, &["rev-parse", "HEAD^{tree}"]);
+    let raw = format!(
+        "tree {}\nparent {}\nauthor You <you@example.com> 1700000000 +0000\n\
+         committer You <you@example.com> 1700000000 +0000\n\
+         gpgsig -----BEGIN PGP SIGNATURE-----\n \n -----END PGP SIGNATURE-----\n\nsigned\n",
+        tree.trim(),
+        ids[3].as_ref()
+    );
+    let raw_path = dir.path().join("signed-commit");
+    fs::write(&raw_path, raw).expect("write raw commit");
+    let signed = run_git_capture(
+        repo,
+        &[
+            "hash-object",
+            "-t",
+            "commit",
+            "-w",
+            raw_path.to_str().expect("utf-8 path"),
+        ],
+    );
+    let signed = CommitId(signed.trim().into());
+    run_git(repo, &["update-ref", "HEAD", signed.as_ref()]);
+
+    let err = opened
+        .squash_commits_with_output(&[ids[2].clone(), ids[3].clone()])
+        .expect_err("squash below a signed commit is refused");
+    assert!(err.to_string().contains("also rewrite"), "{err}");
+    let err = opened
+        .squash_commits_with_output(&[ids[3].clone(), signed.clone()])
+        .expect_err("squash of a signed commit is refused");
+    assert!(err.to_string().contains("is signed"), "{err}");
+    assert_eq!(
+        run_git_capture(repo, &["rev-parse", "HEAD"]).trim(),
+        signed.as_ref()
+    );
+}
+
+#[test]
+fn fixup_commits_fold_into_targets_on_autosquash() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path();
+    let ids = init_repo_with_commits(repo, &["base", "a", "b"]);
+    run_git(repo, &["branch", "upstream", ids[0].as_ref()]);
+    run_git(repo, &["branch", "--set-upstream-to=upstream"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).expect("open repository");
+
+    fs::write(repo.join("a.txt"), "a\nfixed\n").expect("write a");
+    run_git(repo, &["add", "a.txt"]);
+    opened
+        .commit_fixup_with_output(&ids[1], FixupCommitKind::Fixup)
+        .expect("create fixup commit");
+    fs::write(repo.join("b.txt"), "b\nmore\n").expect("write b");
+    run_git(repo, &["add", "b.txt"]);
+    opened
+        .commit_fixup_with_output(&ids[2], FixupCommitKind::Squash)
+        .expect("create squash commit");
+    assert_eq!(
+        subjects(repo, "upstream..HEAD"),
+        vec!["a", "b", "fixup! a", "squash! b"]
+    );
+
+    opened
+        .rebase_autosquash_with_output()
+        .expect("autosquash onto upstream");
+    assert_eq!(subjects(repo, "upstream..HEAD"), vec!["a", "b"]);
+    assert_eq!(
+        fs::read_to_string(repo.join("a.txt")).expect("read a"),
+        "a\nfixed\n"
+    );
+    assert_eq!(
+        run_git_capture(repo, &["show", "HEAD~1:a.txt"]),
+        "a\nfixed\n"
+    );
+    assert!(run_git_capture(repo, &["status", "--porcelain"]).is_empty());
+}
+
+#[test]
+fn reword_commit_rewrites_descendants_and_refuses_published_commits() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path();
+    let ids = init_repo_with_commits(repo, &["base", "a", "b"]);
+    run_git(repo, &["checkout", "-q", "-b", "side", ids[1].as_ref()]);
+    fs::write(repo.join("side.txt"), "side\n").expect("write side");
+    run_git(repo, &["add", "side.txt"]);
+    run_git(repo, &["commit", "-q", "-m", "side"]);
+    run_git(repo, &["checkout", "-q", "main"]);
+    run_git(repo, &["merge", "-q", "--no-edit", "side"]);
+    run_git(repo, &["branch", "upstream", ids[0].as_ref()]);
+    run_git(repo, &["branch", "--set-upstream-to=upstream"]);
+    fs::write(repo.join("b.txt"), "b\nlocal edit\n").expect("write dirty file");
+    let tree_before = run_git_capture(repo, &["rev-parse", "HEAD^{tree}"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).expect("open repository");
+
+    opened
+        .reword_commit_with_output(&ids[0], "rewritten base")
+        .expect_err("commit in upstream is refused");
+    let blocker = opened
+        .reword_commit_blocker(&ids[0])
+        .expect("check base")
+        .expect("published base is blocked");
+    assert!(blocker.contains("upstream"), "{blocker}");
+
+    opened
+        .reword_commit_with_output(&ids[1], "a reworded\n\nwith body")
+        .expect("reword a");
+    assert_eq!(
+        run_git_capture(repo, &["log", "-1", "--pretty=%B", "HEAD^1~1"]).trim(),
+        "a reworded\n\nwith body"
+    );
+    assert_eq!(
+        run_git_capture(repo, &["rev-parse", "HEAD^1~1"]),
+        run_git_capture(repo, &["rev-parse", "HEAD^2~1"])
+    );
+    assert_eq!(
+        run_git_capture(repo, &["rev-parse", "HEAD^{tree}"]),
+        tree_before
+    );
+    assert_eq!(
+        fs::read_to_string(repo.join("b.txt")).expect("read dirty file"),
+        "b\nlocal edit\n"
+    );
+}
+
+#[test]
+fn reword_commit_refuses_commits_whose_headers_it_would_drop() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path();
+    let ids = init_repo_with_commits(repo, &["base", "a"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).expect("open repository");
+    assert_eq!(
+        opened.reword_commit_blocker(&ids[1]).expect("check a"),
+        None
+    );
+
+    let tree = run_git_capture(repo, &["rev-parse", "HEAD^{tree}"]);
+    let raw = format!(
+        "tree {}\nparent {}\nauthor You <you@example.com> 1700000000 +0000\n\
+         committer You <you@example.com> 1700000000 +0000\n\
+         gpgsig -----BEGIN PGP SIGNATURE-----\n \n -----END PGP SIGNATURE-----\n\nsigned\n",
+        tree.trim(),
+        ids[1].as_ref()
+    );
+    let raw_path = dir.path().join("signed-commit");
+    fs::write(&raw_path, raw).expect("write raw commit");
+    let signed = run_git_capture(
+        repo,
+        &[
+            "hash-object",
+            "-t",
+            "commit",
+            "-w",
+            raw_path.to_str().expect("utf-8 path"),
+        ],
+    );
+    let signed = CommitId(signed.trim().into());
+    run_git(repo, &["update-ref", "HEAD", signed.as_ref()]);
+
+    let blocker = opened
+        .reword_commit_blocker(&signed)
+        .expect("check signed commit")
+        .expect("signed commit is refused");
+    assert!(blocker.contains("is signed"), "{blocker}");
+    let blocker = opened
+        .reword_commit_blocker(&ids[1])
+        .expect("check parent of signed commit")
+        .expect("rewriting a signed descendant is refused");
+    assert!(blocker.contains("also rewrite"), "{blocker}");
+
+    opened
+        .reword_commit_with_output(&ids[1], "a reworded")
+        .expect_err("reword below a signed commit is refused");
+    assert_eq!(
+        run_git_capture(repo, &["rev-parse", "HEAD"]).trim(),
+        signed.as_ref()
+    );
+}
+
+#[test]
+fn export_patch_series_with_cover_letter_round_trips_through_git_am() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path().join("repo");
+    fs::create_dir(&repo).expect("create repo dir");
+    let ids = init_repo_with_commits(&repo, &["base", "a", "b"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(&repo).expect("open repository");
+
+    let out_dir = dir.path().join("outgoing");
+    assert!(
+        opened
+            .export_patch_series_with_output(&[CommitId("--all".into())], &out_dir, true)
+            .is_err(),
+        "non-hex ids never reach git format-patch"
+    );
+    opened
+        .export_patch_series_with_output(&[ids[2].clone(), ids[1].clone()], &out_dir, true)
+        .expect("format patch series");
+    let mut written: Vec<String> = fs::read_dir(&out_dir)
+        .expect("read outgoing dir")
+        .map(|entry| {
+            entry
+                .expect("dir entry")
+                .file_name()
+                .to_string_lossy()
+                .into()
+        })
+        .collect();
+    written.sort();
+    assert_eq!(
+        written,
+        vec!["0000-cover-letter.patch", "0001-a.patch", "0002-b.patch"]
+    );
+
+    let preview = opened.read_patch_series(&out_dir).expect("read series");
+    assert_eq!(
+        preview
+            .iter()
+            .map(|p| p.subject.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert!(preview[0].diff.contains("+a"));
+
+    run_git(&repo, &["reset", "--hard", ids[0].as_ref()]);
+    opened
+        .apply_patch_series_with_output(&out_dir, true)
+        .expect("apply patch series");
+    assert_eq!(subjects(&repo, "main~2..main"), vec!["a", "b"]);
+}
+
+#[test]
+fn apply_patch_series_stops_on_conflicting_patch() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path().join("repo");
+    fs::create_dir(&repo).expect("create repo dir");
+    let ids = init_repo_with_commits(&repo, &["base", "a"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(&repo).expect("open repository");
+
+    let mbox = dir.path().join("series.mbox");
+    opened
+        .export_patch_series_with_output(&[ids[1].clone()], &mbox, false)
+        .expect("export patch series");
+
+    run_git(&repo, &["reset", "--hard", ids[0].as_ref()]);
+    fs::write(repo.join("a.txt"), "different\n").expect("write conflicting file");
+    run_git(&repo, &["add", "a.txt"]);
+    run_git(&repo, &["commit", "-m", "conflicting a"]);
+
+    assert!(opened.apply_patch_series_with_output(&mbox, false).is_err());
+    assert!(
+        opened.operation_state().expect("operation state").is_some(),
+        "git am should be left in progress"
+    );
+}
@@ -648,3 +648,84 @@ fn list_branches_reflects_removed_upstream_without_reopen() {
         .expect("feature branch present");
     assert_eq!(feature_after.upstream, None);
 }
+
+#[test]
+fn rename_branch_moves_upstream_and_linked_worktree_head() {
+    if !require_git_shell_for_refs_integration_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+
+    let remote_repo = root.join("remote.git");
+    let work_repo = root.join("work");
+    let linked_repo = root.join("linked");
+    fs::create_dir_all(&remote_repo).unwrap();
+    fs::create_dir_all(&work_repo).unwrap();
+
+    run_git(&remote_repo, &["init", "--bare", "-b", "main"]);
+
+    run_git(&work_repo, &["init", "-b", "main"]);
+    run_git(&work_repo, &["config", "user.email", "you@example.com"]);
+    run_git(&work_repo, &["config", "user.name", "You"]);
+    run_git(&work_repo, &["config", "commit.gpgsign", "false"]);
+    let origin_url = git_remote_url(&remote_repo);
+    run_git(
+        &work_repo,
+        &["remote", "add", "origin", origin_url.as_str()],
+    );
+
+    fs::write(work_repo.join("file.txt"), "base\n").unwrap();
+    run_git(&work_repo, &["add", "file.txt"]);
+    run_git(
+        &work_repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", "base"],
+    );
+    run_git(&work_repo, &["branch", "feature"]);
+    run_git(&work_repo, &["push", "-u", "origin", "feature"]);
+    run_git(
+        &work_repo,
+        &["worktree", "add", linked_repo.to_str().unwrap(), "feature"],
+    );
+
+    let backend = GixBackend;
+    let opened = backend.open(&work_repo).unwrap();
+
+    opened
+        .rename_branch_with_output("feature", "topic", false)
+        .unwrap();
+    assert_eq!(
+        run_git_capture(&linked_repo, &["symbolic-ref", "HEAD"]).trim(),
+        "refs/heads/topic"
+    );
+    assert!(
+        opened
+            .rename_branch_with_output("topic", "main", false)
+            .is_err(),
+        "renaming onto an existing branch needs force"
+    );
+
+    let upstream = Upstream {
+        remote: "origin".to_string(),
+        branch: "feature".to_string(),
+    };
+    opened
+        .rename_upstream_branch_with_output("topic", &upstream, "topic")
+        .unwrap();
+
+    let branches = opened.list_branches().unwrap();
+    let topic = branches
+        .iter()
+        .find(|branch| branch.name == "topic")
+        .expect("topic branch present");
+    assert_eq!(
+        topic.upstream,
+        Some(Upstream {
+            remote: "origin".to_string(),
+            branch: "topic".to_string(),
+        })
+    );
+    let remote_heads = run_git_capture(&remote_repo, &["for-each-ref", "--format=%(refname)"]);
+    assert!(remote_heads.contains("refs/heads/topic"));
+    assert!(!remote_heads.contains("refs/heads/feature"));
+}
@@ -0,0 +1,226 @@
+use gitcomet_core::domain::RevisionTreeEntryKind;
+use gitcomet_core::services::GitBackend;
+use gitcomet_git_gix::GixBackend;
+#[path = "support/test_git_env.rs"]
+mod test_git_env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn git_command() -> Command {
+    let mut cmd = Command::new("git");
+    // Keep tests deterministic by isolating from host git config.
+    test_git_env::apply(&mut cmd);
+    cmd
+}
+
+fn run_git(repo: &Path, args: &[&str]) {
+    let status = git_command()
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .status()
+        .expect("git command to run");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+fn init_repo(repo: &Path) {
+    run_git(repo, &["init"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+    run_git(repo, &["config", "core.autocrlf", "false"]);
+    run_git(repo, &["config", "core.eol", "lf"]);
+}
+
+fn commit_all(repo: &Path, message: &str) {
+    run_git(repo, &["add", "-A"]);
+    run_git(
+        repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", message],
+    );
+}
+
+fn git_with_stdin(repo: &Path, args: &[&str], input: &str) -> String {
+    use std::io::Write as _;
+    use std::process::Stdio;
+
+    let mut child = git_command()
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("git command to spawn");
+    child
+        .stdin
+        .take()
+        .expect("git stdin")
+        .write_all(input.as_bytes())
+        .expect("write git stdin");
+    let output = child.wait_with_output().expect("git command to finish");
+    assert!(output.status.success(), "git {:?} failed", args);
+    String::from_utf8(output.stdout)
+        .expect("git output is utf-8")
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn revision_tree_lists_reads_diffs_and_saves_old_contents() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path().join("repo");
+    fs::create_dir_all(repo.join("src/nested")).expect("create source dirs");
+    init_repo(&repo);
+
+    fs::write(repo.join("README.md"), "hello\n").expect("write readme");
+    fs::write(repo.join("src/lib.rs"), "pub fn one() {}\n").expect("write lib");
+    fs::write(repo.join("src/nested/deep.txt"), "deep\n").expect("write nested file");
+    commit_all(&repo, "initial");
+
+    fs::write(repo.join("src/lib.rs"), "pub fn two() {}\n").expect("rewrite lib");
+    fs::remove_file(repo.join("src/nested/deep.txt")).expect("remove nested file");
+    commit_all(&repo, "second");
+
+    let backend = GixBackend;
+    let opened = backend.open(&repo).expect("open repository");
+
+    let root = opened
+        .list_revision_tree("HEAD~1", Path::new(""))
+        .expect("list root");
+    let root: Vec<_> = root
+        .iter()
+        .map(|entry| (entry.path.clone(), entry.kind, entry.size))
+        .collect();
+    assert_eq!(
+        root,
+        vec![
+            (PathBuf::from("src"), RevisionTreeEntryKind::Directory, None),
+            (
+                PathBuf::from("README.md"),
+                RevisionTreeEntryKind::File,
+                Some(6)
+            ),
+        ]
+    );
+
+    let src = opened
+        .list_revision_tree("HEAD~1", Path::new("src"))
+        .expect("list src");
+    let src_paths: Vec<_> = src.iter().map(|entry| entry.path.clone()).collect();
+    assert_eq!(
+        src_paths,
+        vec![PathBuf::from("src/nested"), PathBuf::from("src/lib.rs")]
+    );
+    assert!(
+        opened
+            .list_revision_tree("HEAD", Path::new("src/nested"))
+            .is_err(),
+        "directory removed at HEAD should not be listable"
+    );
+
+    let old_lib = opened
+        .read_revision_file("HEAD~1", Path::new("src/lib.rs"))
+        .expect("read old file");
+    assert_eq!(old_lib, b"pub fn one() {}\n");
+    assert!(
+        opened
+            .read_revision_file("HEAD~1", Path::new("src"))
+            .is_err()
+    );
+
+    let diff = opened
+        .diff_revision_to_worktree_unified("HEAD~1", Path::new("src/lib.rs"))
+        .expect("diff against worktree");
+    assert!(diff.contains("-pub fn one() {}"), "unexpected diff: {diff}");
+    assert!(diff.contains("+pub fn two() {}"), "unexpected diff: {diff}");
+
+    let export = dir.path().join("export");
+    let output = opened
+        .save_revision_path_with_output("HEAD~1", Path::new("src"), &export.join("src"))
+        .expect("save folder");
+    assert!(output.stdout.contains("Saved 2 files"), "{}", output.stdout);
+    assert_eq!(
+        fs::read_to_string(export.join("src/lib.rs")).expect("read saved lib"),
+        "pub fn one() {}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(export.join("src/nested/deep.txt")).expect("read saved nested"),
+        "deep\n"
+    );
+
+    let saved_file = export.join("README.md");
+    opened
+        .save_revision_path_with_output("HEAD~1", Path::new("README.md"), &saved_file)
+        .expect("save file");
+    assert_eq!(
+        fs::read_to_string(&saved_file).expect("read saved file"),
+        "hello\n"
+    );
+    assert!(
+        opened
+            .save_revision_path_with_output("HEAD~1", Path::new("README.md"), &saved_file)
+            .is_err(),
+        "saving over an existing path should fail"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn save_revision_path_refuses_unsafe_tree_entries() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let repo = dir.path().join("repo");
+    let outside = dir.path().join("outside");
+    let exports = dir.path().join("exports");
+    fs::create_dir_all(&repo).expect("create repo dir");
+    fs::create_dir_all(&outside).expect("create outside dir");
+    fs::create_dir_all(&exports).expect("create exports dir");
+    init_repo(&repo);
+
+    let hash = |input: &str| git_with_stdin(&repo, &["hash-object", "-w", "--stdin"], input);
+    let blob = hash("evil\n");
+    let dir_link = hash(outside.to_str().expect("utf-8 tempdir"));
+    let file_link = hash(outside.join("pwned.txt").to_str().expect("utf-8 tempdir"));
+    let payload = git_with_stdin(
+        &repo,
+        &["mktree"],
+        &format!("100644 blob {blob}\tpwned.txt\n"),
+    );
+
+    let trees = [
+        ("parent", format!("040000 tree {payload}\t..\n")),
+        ("git-dir", format!("040000 tree {payload}\t.GIT\n")),
+        (
+            "through-dir-link",
+            format!("120000 blob {dir_link}\tlink\n040000 tree {payload}\tlink\n"),
+        ),
+        (
+            "through-file-link",
+            format!("120000 blob {file_link}\tpwned.txt\n100644 blob {blob}\tpwned.txt\n"),
+        ),
+    ];
+
+    let backend = GixBackend;
+    let opened = backend.open(&repo).expect("open repository");
+    for (name, listing) in trees {
+        let tree = git_with_stdin(&repo, &["mktree"], &listing);
+        let dest = exports.join(name);
+        assert!(
+            opened
+                .save_revision_path_with_output(&tree, Path::new(""), &dest)
+                .is_err(),
+            "{name} tree should be refused"
+        );
+        assert!(!dest.join(".GIT").exists(), "{name} wrote into .GIT");
+    }
+    assert!(
+        !exports.join("pwned.txt").exists(),
+        "an entry escaped its destination"
+    );
+    assert_eq!(
+        fs::read_dir(&outside).expect("read outside dir").count(),
+        0,
+        "an entry was written through a symlink"
+    );
+}
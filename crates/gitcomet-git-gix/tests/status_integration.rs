@@ -1,11 +1,13 @@
 use gitcomet_core::conflict_session::{ConflictPayload, ConflictResolverStrategy};
 use gitcomet_core::domain::{
     CommitId, DiffArea, DiffLineKind, DiffPreviewTextSide, DiffTarget, FileConflictKind,
-    FileDiffText, FileDiffTextSource, FileStatusKind,
+    FileDiffText, FileDiffTextSource, FileStatusKind, OperationProgress, RepoOperationAction,
+    RepoOperationKind, RepoOperationState,
 };
 use gitcomet_core::error::{Error, ErrorKind, GitFailureId};
 use gitcomet_core::services::ConflictSide;
 use gitcomet_core::services::GitBackend;
+use gitcomet_core::services::{DiffIgnoreOptions, DiffWhitespaceIgnore};
 use gitcomet_git_gix::GixBackend;
 use std::fs;
 use std::io::Write;
@@ -821,6 +823,52 @@ fn status_separates_staged_and_unstaged() {
     assert_eq!(status.unstaged[0].kind, FileStatusKind::Untracked);
 }
 
+#[test]
+fn status_reports_staged_rename_source_and_commit_details_keep_it() {
+    if !require_git_shell_for_status_integration_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+
+    run_git(repo, &["init"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+
+    let contents = (0..20).map(|ix| format!("line {ix}\n")).collect::<String>();
+    write(repo, "old.txt", &contents);
+    run_git(repo, &["add", "old.txt"]);
+    run_git(
+        repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", "init"],
+    );
+    run_git(repo, &["mv", "old.txt", "new.txt"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).unwrap();
+    let status = opened.status().unwrap();
+
+    assert_eq!(status.staged.len(), 1);
+    assert_eq!(status.staged[0].path, PathBuf::from("new.txt"));
+    assert_eq!(status.staged[0].kind, FileStatusKind::Renamed);
+    assert_eq!(status.staged[0].old_path, Some(PathBuf::from("old.txt")));
+
+    run_git(
+        repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", "rename"],
+    );
+    let head = run_git_output(repo, &["rev-parse", "HEAD"]);
+    let details = opened
+        .commit_details(&gitcomet_core::domain::CommitId(head.trim().into()))
+        .unwrap();
+
+    assert_eq!(details.files.len(), 1);
+    assert_eq!(details.files[0].kind, FileStatusKind::Renamed);
+    assert_eq!(details.files[0].old_path, Some(PathBuf::from("old.txt")));
+    assert_eq!(details.files[0].similarity, Some(100));
+}
+
 #[test]
 fn repeated_status_on_same_repo_instance_reuses_staged_state_and_invalidates_on_index_change() {
     if !require_git_shell_for_status_integration_tests() {
@@ -1108,6 +1156,7 @@ fn diff_unified_works_for_staged_and_unstaged() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert!(unstaged.contains("@@"));
@@ -1118,11 +1167,83 @@ fn diff_unified_works_for_staged_and_unstaged() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
     assert!(staged.contains("@@"));
 }
 
+#[test]
+fn diff_unified_applies_diff_ignore_options() {
+    if !require_git_shell_for_status_integration_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+
+    run_git(repo, &["init"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+
+    write(
+        repo,
+        "a.txt",
+        "fn main() {\n    call(a, b);\n}\n// built 1\n",
+    );
+    run_git(repo, &["add", "a.txt"]);
+    run_git(
+        repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", "init"],
+    );
+
+    write(
+        repo,
+        "a.txt",
+        "fn main() {\n  call(a,  b);\n}\n// built 2\n",
+    );
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).unwrap();
+    let target = DiffTarget::WorkingTree {
+        path: PathBuf::from("a.txt"),
+        area: DiffArea::Unstaged,
+        old_path: None,
+    };
+    assert!(opened.diff_unified(&target).unwrap().contains("@@"));
+
+    opened.set_diff_ignore_options(DiffIgnoreOptions {
+        whitespace: DiffWhitespaceIgnore::Change,
+        ignore_matching_lines: vec!["^// built".to_string()],
+        ..DiffIgnoreOptions::default()
+    });
+    let unified = opened.diff_unified(&target).unwrap();
+    assert!(
+        !unified.contains("@@"),
+        "expected whitespace and ignored-line changes to be hidden:\n{unified}"
+    );
+
+    opened.set_diff_ignore_options(DiffIgnoreOptions {
+        whitespace: DiffWhitespaceIgnore::Change,
+        ..DiffIgnoreOptions::default()
+    });
+    let parsed = opened.diff_parsed(&target).unwrap();
+    assert!(
+        parsed
+            .lines
+            .iter()
+            .any(|line| line.kind == DiffLineKind::Add && line.text.as_ref().contains("// built 2")),
+        "expected the unignored line change to remain: {parsed:?}"
+    );
+    assert!(
+        !parsed
+            .lines
+            .iter()
+            .any(|line| line.kind == DiffLineKind::Add && line.text.as_ref().contains("call(")),
+        "expected the whitespace-only change to be hidden: {parsed:?}"
+    );
+}
+
 #[test]
 fn diff_working_tree_unstaged_ignores_crlf_only_line_ending_changes() {
     if !require_git_shell_for_status_integration_tests() {
@@ -1152,6 +1273,7 @@ fn diff_working_tree_unstaged_ignores_crlf_only_line_ending_changes() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("a.txt"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
 
     let unified = opened.diff_unified(&target).unwrap();
@@ -1196,6 +1318,7 @@ fn diff_file_text_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for unstaged changes");
@@ -1208,6 +1331,7 @@ fn diff_file_text_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for staged changes");
@@ -1230,6 +1354,7 @@ fn diff_file_text_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_text(&DiffTarget::Commit {
             commit_id: gitcomet_core::domain::CommitId(head.into()),
             path: Some(PathBuf::from("a.txt")),
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for commit");
@@ -1265,6 +1390,7 @@ fn diff_file_text_unstaged_uses_git_normalized_worktree_content() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for unstaged crlf-only change");
@@ -1304,6 +1430,7 @@ fn diff_file_text_root_commit_has_no_parent_side() {
         .diff_file_text(&DiffTarget::Commit {
             commit_id: gitcomet_core::domain::CommitId(head.into()),
             path: Some(PathBuf::from("a.txt")),
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for root commit");
@@ -1342,6 +1469,7 @@ fn diff_file_text_staged_add_and_delete_report_missing_sides() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("b.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for staged added file");
@@ -1352,6 +1480,7 @@ fn diff_file_text_staged_add_and_delete_report_missing_sides() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for staged deleted file");
@@ -1387,6 +1516,7 @@ fn diff_preview_text_file_commit_added_file_returns_new_side_blob_path() {
             &DiffTarget::Commit {
                 commit_id,
                 path: Some(PathBuf::from("docs/added.txt")),
+                old_path: None,
             },
             DiffPreviewTextSide::New,
         )
@@ -1433,6 +1563,7 @@ fn diff_preview_text_file_commit_deleted_file_returns_old_side_blob_path() {
             &DiffTarget::Commit {
                 commit_id,
                 path: Some(PathBuf::from("docs/delete-me.txt")),
+                old_path: None,
             },
             DiffPreviewTextSide::Old,
         )
@@ -1474,6 +1605,7 @@ fn diff_preview_text_file_staged_deleted_file_returns_head_blob_path() {
             &DiffTarget::WorkingTree {
                 path: PathBuf::from("a.txt"),
                 area: DiffArea::Staged,
+                old_path: None,
             },
             DiffPreviewTextSide::Old,
         )
@@ -1505,6 +1637,7 @@ fn diff_file_text_returns_none_for_directories() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("dir"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
 
@@ -1515,6 +1648,7 @@ fn diff_file_text_returns_none_for_directories() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("dir"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
 
@@ -1553,6 +1687,7 @@ fn diff_file_image_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: PathBuf::from("img.png"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("image diff for unstaged changes");
@@ -1566,6 +1701,7 @@ fn diff_file_image_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: PathBuf::from("img.png"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("image diff for staged changes");
@@ -1589,6 +1725,7 @@ fn diff_file_image_reports_old_and_new_for_working_tree_and_commits() {
         .diff_file_image(&DiffTarget::Commit {
             commit_id: gitcomet_core::domain::CommitId(head.into()),
             path: Some(PathBuf::from("img.png")),
+            old_path: None,
         })
         .unwrap()
         .expect("image diff for commit");
@@ -1614,6 +1751,7 @@ fn diff_file_image_returns_none_for_directories() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: PathBuf::from("dir"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
 
@@ -1624,6 +1762,7 @@ fn diff_file_image_returns_none_for_directories() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: PathBuf::from("dir"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
 
@@ -1690,6 +1829,7 @@ fn gitlink_added_and_unstaged_modified_reports_expected_status_and_diff() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("chess3"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert!(
@@ -1701,6 +1841,7 @@ fn gitlink_added_and_unstaged_modified_reports_expected_status_and_diff() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("chess3"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert!(
@@ -1766,6 +1907,7 @@ fn committed_gitlink_unstaged_modified_reports_modified_status_and_diff() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("chess3"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert!(
@@ -1862,6 +2004,7 @@ fn diff_file_commit_target_without_path_returns_none() {
     let target = DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId(head.into()),
         path: None,
+        old_path: None,
     };
 
     let backend = GixBackend;
@@ -1898,6 +2041,7 @@ fn diff_unified_outside_repository_path_returns_structured_git_error() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: outside,
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .expect_err("expected diff_unified to fail for outside path");
     assert_git_failure(&err, "git diff", GitFailureId::CommandFailed);
@@ -1935,6 +2079,7 @@ fn diff_parsed_outside_repository_path_returns_structured_git_error() {
         .diff_parsed(&DiffTarget::WorkingTree {
             path: outside,
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .expect_err("expected diff_parsed to fail for outside path");
     assert_git_failure(&err, "git diff", GitFailureId::CommandFailed);
@@ -1985,6 +2130,7 @@ fn diff_parsed_commit_rename_preserves_rename_headers_and_hunks() {
         .diff_parsed(&DiffTarget::Commit {
             commit_id,
             path: None,
+            old_path: None,
         })
         .expect("parse rename commit diff");
 
@@ -2039,6 +2185,7 @@ fn diff_parsed_commit_added_file_matches_git_show_output() {
         .diff_parsed(&DiffTarget::Commit {
             commit_id: commit_id.clone(),
             path: Some(PathBuf::from("docs/added.txt")),
+            old_path: None,
         })
         .expect("parse added file commit diff");
     let expected = run_git_output(
@@ -2106,6 +2253,7 @@ fn diff_parsed_commit_deleted_file_matches_git_show_output() {
         .diff_parsed(&DiffTarget::Commit {
             commit_id: commit_id.clone(),
             path: Some(PathBuf::from("docs/delete-me.txt")),
+            old_path: None,
         })
         .expect("parse deleted file commit diff");
     let expected = run_git_output(
@@ -2171,6 +2319,7 @@ fn diff_working_tree_with_absolute_file_path_reads_current_file() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: absolute.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("text diff for absolute path");
@@ -2180,6 +2329,7 @@ fn diff_working_tree_with_absolute_file_path_reads_current_file() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: absolute,
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("image diff for absolute path");
@@ -2221,6 +2371,7 @@ fn diff_working_tree_with_absolute_file_path_through_symlinked_repo_reads_curren
         .diff_file_text(&DiffTarget::WorkingTree {
             path: absolute.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("text diff for symlinked absolute path");
@@ -2230,6 +2381,7 @@ fn diff_working_tree_with_absolute_file_path_through_symlinked_repo_reads_curren
         .diff_file_image(&DiffTarget::WorkingTree {
             path: absolute,
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("image diff for symlinked absolute path");
@@ -2253,6 +2405,7 @@ fn staged_diff_for_unmerged_conflict_prefers_ours_for_text_and_image() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("staged text diff for conflict");
@@ -2262,6 +2415,7 @@ fn staged_diff_for_unmerged_conflict_prefers_ours_for_text_and_image() {
         .diff_file_image(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap()
         .expect("staged image diff for conflict");
@@ -2296,6 +2450,7 @@ fn diff_commit_with_unknown_revision_and_outside_conflict_path_are_handled() {
     let unknown_target = DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId("not-a-real-revision".into()),
         path: Some(PathBuf::from("a.txt")),
+        old_path: None,
     };
 
     let text = opened
@@ -2373,6 +2528,7 @@ fn diff_file_text_uses_ours_and_theirs_for_conflicted_paths() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for conflicted changes");
@@ -3168,6 +3324,7 @@ fn diff_file_text_handles_modify_delete_conflicts() {
         .diff_file_text(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap()
         .expect("file diff for conflicted changes");
@@ -5167,6 +5324,108 @@ fn rebase_abort_falls_back_to_git_am_abort() {
     assert_eq!(fs::read_to_string(repo.join("a.txt")).unwrap(), "main\n");
 }
 
+#[test]
+fn operation_state_reports_cherry_pick_and_rebase_progress() {
+    if !require_git_shell_for_status_integration_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path();
+
+    run_git(repo, &["init", "-b", "main"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+
+    write(repo, "a.txt", "base\n");
+    run_git(repo, &["add", "a.txt"]);
+    run_git(repo, &["commit", "-m", "base"]);
+
+    run_git(repo, &["checkout", "-b", "feature"]);
+    write(repo, "a.txt", "feature\n");
+    run_git(repo, &["commit", "-am", "feature change"]);
+    write(repo, "b.txt", "extra\n");
+    run_git(repo, &["add", "b.txt"]);
+    run_git(repo, &["commit", "-m", "feature extra"]);
+
+    run_git(repo, &["checkout", "main"]);
+    write(repo, "a.txt", "main\n");
+    run_git(repo, &["commit", "-am", "main change"]);
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).unwrap();
+    assert_eq!(opened.operation_state().unwrap(), None);
+
+    let cherry_pick = git_command()
+        .arg("-C")
+        .arg(repo)
+        .args(["cherry-pick", "feature~1"])
+        .output()
+        .expect("git cherry-pick to run");
+    assert!(
+        !cherry_pick.status.success(),
+        "expected cherry-pick conflict"
+    );
+    assert_eq!(
+        opened.operation_state().unwrap(),
+        Some(RepoOperationState::CherryPick(OperationProgress {
+            step: Some((1, 1)),
+            subject: Some("feature change".to_string()),
+        }))
+    );
+    assert!(!opened.rebase_in_progress().unwrap());
+    assert!(
+        opened
+            .operation_command_with_output(RepoOperationKind::Bisect, RepoOperationAction::Continue)
+            .is_err()
+    );
+    let abort = opened
+        .operation_command_with_output(RepoOperationKind::CherryPick, RepoOperationAction::Abort)
+        .unwrap();
+    assert_eq!(abort.command, "git cherry-pick --abort");
+    assert_eq!(opened.operation_state().unwrap(), None);
+
+    let sequence = git_command()
+        .arg("-C")
+        .arg(repo)
+        .args(["cherry-pick", "feature", "feature~1"])
+        .output()
+        .expect("git cherry-pick to run");
+    assert!(
+        !sequence.status.success(),
+        "expected the second pick to conflict"
+    );
+    assert_eq!(
+        opened.operation_state().unwrap(),
+        Some(RepoOperationState::CherryPick(OperationProgress {
+            step: Some((2, 2)),
+            subject: Some("feature change".to_string()),
+        }))
+    );
+    opened
+        .operation_command_with_output(RepoOperationKind::CherryPick, RepoOperationAction::Abort)
+        .unwrap();
+    assert_eq!(opened.operation_state().unwrap(), None);
+
+    run_git(repo, &["checkout", "feature"]);
+    assert!(opened.rebase_with_output("main").is_err());
+    assert_eq!(
+        opened.operation_state().unwrap(),
+        Some(RepoOperationState::Rebase(OperationProgress {
+            step: Some((1, 2)),
+            subject: Some("feature change".to_string()),
+        }))
+    );
+
+    write(repo, "a.txt", "resolved\n");
+    run_git(repo, &["add", "a.txt"]);
+    opened
+        .operation_command_with_output(RepoOperationKind::Rebase, RepoOperationAction::Continue)
+        .unwrap();
+    assert_eq!(opened.operation_state().unwrap(), None);
+    assert_eq!(fs::read_to_string(repo.join("b.txt")).unwrap(), "extra\n");
+}
+
 #[test]
 fn merge_abort_with_output_clears_conflict_state() {
     if !require_git_shell_for_status_integration_tests() {
@@ -5862,6 +6121,80 @@ fn delete_branch_force_rejects_branch_checked_out_in_linked_worktree() {
     );
 }
 
+#[test]
+fn linked_worktree_lock_move_summary_and_prune() {
+    if !require_git_shell_for_status_integration_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    fs::create_dir_all(&repo).unwrap();
+    let repo = repo.as_path();
+    let linked = dir.path().join("feature-worktree");
+    let moved = dir.path().join("moved-worktree");
+
+    run_git(repo, &["init", "-b", "main"]);
+    run_git(repo, &["config", "user.email", "you@example.com"]);
+    run_git(repo, &["config", "user.name", "You"]);
+    run_git(repo, &["config", "commit.gpgsign", "false"]);
+    write(repo, "a.txt", "one\n");
+    run_git(repo, &["add", "a.txt"]);
+    run_git(
+        repo,
+        &["-c", "commit.gpgsign=false", "commit", "-m", "init"],
+    );
+    run_git(repo, &["branch", "feature"]);
+    run_git(
+        repo,
+        &["worktree", "add", &git_path_arg(&linked), "feature"],
+    );
+
+    let backend = GixBackend;
+    let opened = backend.open(repo).unwrap();
+    let find = |worktrees: &[gitcomet_core::domain::Worktree], path: &Path| {
+        let path = fs::canonicalize(path).unwrap();
+        worktrees.iter().find(|wt| wt.path == path).cloned()
+    };
+
+    opened
+        .lock_worktree_with_output(&linked, Some("on a usb drive"))
+        .expect("lock worktree");
+    let worktrees = opened.list_worktrees().unwrap();
+    let wt = find(&worktrees, &linked).expect("linked worktree listed");
+    assert_eq!(wt.locked.as_deref(), Some("on a usb drive"));
+
+    opened
+        .unlock_worktree_with_output(&linked)
+        .expect("unlock worktree");
+    opened
+        .move_worktree_with_output(&linked, &moved)
+        .expect("move worktree");
+    assert!(!linked.exists());
+    let worktrees = opened.list_worktrees().unwrap();
+    let wt = find(&worktrees, &moved).expect("moved worktree listed");
+    assert!(!wt.is_locked());
+    assert_eq!(wt.branch.as_deref(), Some("feature"));
+
+    write(&moved, "a.txt", "two\n");
+    write(&moved, "new.txt", "new\n");
+    let summary = opened.worktree_summary(&moved).expect("worktree summary");
+    assert_eq!(summary.dirty_files, 2);
+    assert_eq!(summary.upstream, None);
+    let summary = opened.worktree_summary(repo).expect("main summary");
+    assert_eq!(summary.dirty_files, 0);
+
+    fs::remove_dir_all(&moved).unwrap();
+    let worktrees = opened.list_worktrees().unwrap();
+    assert!(
+        worktrees.iter().any(|wt| wt.is_prunable()),
+        "deleted worktree should be reported as prunable: {worktrees:?}"
+    );
+    opened
+        .prune_worktrees_with_output()
+        .expect("prune worktrees");
+    assert_eq!(opened.list_worktrees().unwrap().len(), 1);
+}
+
 #[test]
 fn delete_branch_force_rejects_branch_checked_out_in_main_worktree_when_opened_from_linked() {
     if !require_git_shell_for_status_integration_tests() {
@@ -8111,6 +8444,7 @@ fn stage_hunk_applies_only_part_of_a_file_to_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     let hunk_count_before = unstaged_before
@@ -8150,6 +8484,7 @@ fn stage_hunk_applies_only_part_of_a_file_to_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
     assert_eq!(
@@ -8165,6 +8500,7 @@ fn stage_hunk_applies_only_part_of_a_file_to_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert_eq!(
@@ -8216,6 +8552,7 @@ fn unstage_hunk_reverts_only_that_part_in_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert_eq!(
@@ -8256,6 +8593,7 @@ fn unstage_hunk_reverts_only_that_part_in_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
     assert_eq!(
@@ -8275,6 +8613,7 @@ fn unstage_hunk_reverts_only_that_part_in_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .unwrap();
     assert!(
@@ -8286,6 +8625,7 @@ fn unstage_hunk_reverts_only_that_part_in_index() {
         .diff_unified(&DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .unwrap();
     assert_eq!(
@@ -1,7 +1,7 @@
 use gitcomet_core::domain::{
     CommitId, DiffArea, DiffTarget, SubmoduleDiffRangeKind, SubmoduleStatus,
 };
-use gitcomet_core::services::{GitBackend, SubmoduleTrustDecision};
+use gitcomet_core::services::{GitBackend, SubmoduleBatchCommand, SubmoduleTrustDecision};
 use gitcomet_git_gix::GixBackend;
 #[path = "support/test_git_env.rs"]
 mod test_git_env;
@@ -614,6 +614,7 @@ fn submodule_worktree_summary_treats_new_submodule_head_gitlink_as_missing() {
         .submodule_diff_summary(&DiffTarget::WorkingTree {
             path: submodule_path.to_path_buf(),
             area: DiffArea::Staged,
+            old_path: None,
         })
         .expect("load staged added submodule summary");
     let staged_range = summary
@@ -688,6 +689,7 @@ fn submodule_commit_summary_treats_missing_submodule_history_as_unavailable() {
         .submodule_diff_summary(&DiffTarget::Commit {
             commit_id: CommitId(parent_commit.into()),
             path: Some(submodule_path.to_path_buf()),
+            old_path: None,
         })
         .expect("load committed submodule summary");
     let range = summary
@@ -805,6 +807,138 @@ fn submodule_add_update_remove_round_trip() {
     assert!(!parent_repo.join(".git/modules/mods").exists());
 }
 
+#[test]
+fn submodule_branch_tracking_sync_and_batch_commands() {
+    if !require_git_shell_for_submodule_tests() {
+        return;
+    }
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let root = dir.path();
+
+    let sub_repo = root.join("sub source");
+    let parent_repo = root.join("parent repo");
+    fs::create_dir_all(&sub_repo).expect("create sub repository directory");
+    fs::create_dir_all(&parent_repo).expect("create parent repository directory");
+
+    init_repo_with_seed(&sub_repo, "file.txt", "hello\n", "seed submodule");
+    init_repo_with_seed(&parent_repo, "seed.txt", "seed\n", "seed parent");
+
+    let backend = GixBackend;
+    let opened = backend.open(&parent_repo).expect("open parent repository");
+
+    let submodule_path = Path::new("mods/sub");
+    let approved_sources = match opened
+        .check_submodule_add_trust(sub_repo.to_string_lossy().as_ref(), submodule_path)
+        .expect("check local submodule trust")
+    {
+        SubmoduleTrustDecision::Prompt { sources } => sources,
+        other => panic!("expected trust prompt for local submodule, got {other:?}"),
+    };
+    opened
+        .add_submodule_with_output(
+            sub_repo.to_string_lossy().as_ref(),
+            submodule_path,
+            None,
+            None,
+            false,
+            &approved_sources,
+        )
+        .expect("add submodule");
+    run_git(
+        &parent_repo,
+        &[
+            "-c",
+            "commit.gpgsign=false",
+            "commit",
+            "-m",
+            "add submodule",
+        ],
+    );
+
+    run_git(&sub_repo, &["checkout", "-b", "feature"]);
+    fs::write(sub_repo.join("file.txt"), "feature\n").expect("write feature change");
+    run_git(
+        &sub_repo,
+        &["-c", "commit.gpgsign=false", "commit", "-am", "feature"],
+    );
+    let feature_head = git_stdout(&sub_repo, &["rev-parse", "HEAD"]);
+
+    opened
+        .set_submodule_branch_with_output(submodule_path, Some("feature"))
+        .expect("set tracked branch");
+    assert_eq!(
+        git_stdout(
+            &parent_repo,
+            &["config", "-f", ".gitmodules", "submodule.mods/sub.branch"],
+        ),
+        "feature"
+    );
+
+    opened
+        .update_submodule_remote_with_output(submodule_path)
+        .expect("update submodule from remote");
+    assert_eq!(
+        git_stdout(&parent_repo.join("mods/sub"), &["rev-parse", "HEAD"]),
+        feature_head
+    );
+
+    opened
+        .set_submodule_branch_with_output(submodule_path, None)
+        .expect("reset tracked branch");
+    assert!(
+        !git_output(
+            &parent_repo,
+            &["config", "-f", ".gitmodules", "submodule.mods/sub.branch"],
+        )
+        .status
+        .success()
+    );
+
+    let moved_source = root.join("moved source");
+    fs::rename(&sub_repo, &moved_source).expect("move submodule source");
+    run_git(
+        &parent_repo,
+        &[
+            "config",
+            "-f",
+            ".gitmodules",
+            "submodule.mods/sub.url",
+            moved_source.to_string_lossy().as_ref(),
+        ],
+    );
+    opened
+        .sync_submodules_with_output()
+        .expect("sync submodules");
+    assert_eq!(
+        git_stdout(&parent_repo, &["config", "submodule.mods/sub.url"]),
+        moved_source.to_string_lossy()
+    );
+
+    let fetched = opened
+        .run_in_submodules(&SubmoduleBatchCommand::Fetch)
+        .expect("fetch in submodules");
+    assert_eq!(fetched.len(), 1);
+    assert_eq!(fetched[0].path, PathBuf::from("mods/sub"));
+    assert!(fetched[0].result.is_ok());
+
+    let heads = opened
+        .run_in_submodules(&SubmoduleBatchCommand::Git {
+            args: vec!["rev-parse".to_string(), "HEAD".to_string()],
+        })
+        .expect("run rev-parse in submodules");
+    let output = heads[0].result.as_ref().expect("rev-parse succeeds");
+    assert_eq!(output.stdout.trim(), feature_head);
+    assert_eq!(output.command, "git rev-parse HEAD in mods/sub");
+
+    let failed = opened
+        .run_in_submodules(&SubmoduleBatchCommand::Git {
+            args: vec!["checkout".to_string(), "no-such-branch".to_string()],
+        })
+        .expect("run failing command in submodules");
+    assert_eq!(failed.len(), 1);
+    assert!(failed[0].result.is_err());
+}
+
 #[test]
 fn add_submodule_does_not_restrict_https_or_ssh_transports() {
     if !require_git_shell_for_submodule_tests() {
@@ -135,11 +135,14 @@ impl GitRepository for NoopRepo {
 #[cfg(test)]
 mod tests {
     use super::{NoopBackend, NoopRepo};
-    use gitcomet_core::domain::{CommitId, DiffArea, DiffTarget, LogCursor, RepoSpec};
+    use gitcomet_core::domain::{
+        CommitId, DiffArea, DiffTarget, LogCursor, RepoOperationAction, RepoOperationKind,
+        RepoSpec, Upstream,
+    };
     use gitcomet_core::error::ErrorKind;
     use gitcomet_core::services::{
-        ConflictSide, GitBackend, GitRepository, PullMode, RemoteUrlKind, ResetMode, Result,
-        SafePushAfterCommitTarget,
+        ConflictSide, FixupCommitKind, GitBackend, GitRepository, PullMode, RemoteUrlKind,
+        ResetMode, Result, SafePushAfterCommitTarget,
     };
     use std::path::{Path, PathBuf};
 
@@ -185,6 +188,7 @@ mod tests {
         let diff_target = DiffTarget::WorkingTree {
             path: PathBuf::from("file.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let paths = [Path::new("file.txt")];
 
@@ -235,6 +239,7 @@ mod tests {
         let diff_target = DiffTarget::WorkingTree {
             path: PathBuf::from("file.txt"),
             area: DiffArea::Staged,
+            old_path: None,
         };
         let path = Path::new("file.txt");
 
@@ -250,11 +255,20 @@ mod tests {
         assert_unsupported(repo.delete_branch_force("feature"));
         assert_unsupported(repo.checkout_remote_branch("origin", "main", "feature"));
         assert_unsupported(repo.commit_amend("message"));
+        assert_unsupported(repo.reword_commit_blocker(&commit));
+        assert_unsupported(repo.reword_commit_with_output(&commit, "message"));
+        assert_unsupported(repo.commit_fixup_with_output(&commit, FixupCommitKind::Fixup));
         assert_unsupported(repo.rebase_with_output("main"));
+        assert_unsupported(repo.rebase_autosquash_with_output());
         assert_unsupported(repo.rebase_continue_with_output());
         assert_unsupported(repo.rebase_abort_with_output());
         assert_unsupported(repo.merge_abort_with_output());
         assert!(!repo.rebase_in_progress().unwrap());
+        assert_eq!(repo.operation_state().unwrap(), None);
+        assert_unsupported(repo.operation_command_with_output(
+            RepoOperationKind::CherryPick,
+            RepoOperationAction::Continue,
+        ));
         assert_eq!(repo.merge_commit_message().unwrap(), None);
         assert_unsupported(repo.create_tag_with_output("v1.0.0", "HEAD"));
         assert_unsupported(repo.delete_tag_with_output("v1.0.0"));
@@ -282,24 +296,48 @@ mod tests {
         assert_unsupported(repo.set_upstream_branch_with_output("main", "origin/main"));
         assert_unsupported(repo.unset_upstream_branch_with_output("main"));
         assert_unsupported(repo.delete_remote_branch_with_output("origin", "main"));
+        assert_unsupported(repo.rename_branch_with_output("main", "trunk", false));
+        assert_unsupported(repo.rename_upstream_branch_with_output(
+            "trunk",
+            &Upstream {
+                remote: "origin".to_string(),
+                branch: "main".to_string(),
+            },
+            "trunk",
+        ));
         assert_unsupported(repo.commit_amend_with_output("message"));
         assert_unsupported(repo.pull_branch_with_output("origin", "main"));
         assert_unsupported(repo.merge_ref_with_output("origin/main"));
         assert_unsupported(repo.squash_ref_with_output("origin/main"));
         assert_unsupported(repo.reset_with_output("HEAD~1", ResetMode::Mixed));
         assert_unsupported(repo.blame_file(path, None));
+        assert_unsupported(repo.list_revision_tree("HEAD", Path::new("")));
+        assert_unsupported(repo.read_revision_file("HEAD", path));
+        assert_unsupported(repo.diff_revision_to_worktree_unified("HEAD", path));
+        assert_unsupported(repo.save_revision_path_with_output("HEAD", path, path));
         assert_unsupported(repo.checkout_conflict_side(path, ConflictSide::Ours));
         assert_unsupported(repo.accept_conflict_deletion(path));
         assert_unsupported(repo.checkout_conflict_base(path));
         assert_unsupported(repo.launch_mergetool(path));
         assert_unsupported(repo.export_patch_with_output(&commit, path));
+        assert_unsupported(repo.export_patch_series_with_output(&[commit.clone()], path, true));
+        assert_unsupported(repo.cherry_pick_commits_with_output(&[commit.clone()]));
+        assert_unsupported(repo.revert_commits_with_output(&[commit.clone()]));
+        assert_unsupported(repo.squash_commits_with_output(&[commit.clone()]));
         assert_unsupported(repo.apply_patch_with_output(path));
+        assert_unsupported(repo.read_patch_series(path));
+        assert_unsupported(repo.apply_patch_series_with_output(path, true));
         assert_unsupported(repo.apply_unified_patch_to_index_with_output("@@ -1 +1 @@", false));
         assert_unsupported(repo.apply_unified_patch_to_worktree_with_output("@@ -1 +1 @@", true));
         assert_unsupported(repo.list_worktrees());
         assert_unsupported(repo.add_worktree_with_output(path, Some("main")));
         assert_unsupported(repo.remove_worktree_with_output(path));
         assert_unsupported(repo.force_remove_worktree_with_output(path));
+        assert_unsupported(repo.lock_worktree_with_output(path, Some("on a usb drive")));
+        assert_unsupported(repo.unlock_worktree_with_output(path));
+        assert_unsupported(repo.move_worktree_with_output(path, path));
+        assert_unsupported(repo.prune_worktrees_with_output());
+        assert_unsupported(repo.worktree_summary(path));
         assert_unsupported(repo.list_submodules());
         assert_unsupported(repo.check_submodule_add_trust("https://example.com/repo.git", path));
         assert_unsupported(repo.check_submodule_update_trust());
@@ -313,5 +351,11 @@ mod tests {
         ));
         assert_unsupported(repo.update_submodules_with_output(&[]));
         assert_unsupported(repo.remove_submodule_with_output(path));
+        assert_unsupported(repo.sync_submodules_with_output());
+        assert_unsupported(repo.set_submodule_branch_with_output(path, Some("main")));
+        assert_unsupported(repo.update_submodule_remote_with_output(path));
+        assert_unsupported(
+            repo.run_in_submodules(&gitcomet_core::services::SubmoduleBatchCommand::Fetch),
+        );
     }
 }
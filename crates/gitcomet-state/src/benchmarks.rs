@@ -237,6 +237,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/lib.rs"),
             area: DiffArea::Staged,
+            old_path: None,
         };
 
         with_select_diff_sync(&mut state, RepoId(1), target.clone(), |state, effects| {
@@ -2,12 +2,16 @@ use crate::msg::RepoCommandKind;
 use crate::msg::RepoPath;
 use crate::session;
 use gitcomet_core::conflict_session::{
-    ConflictPayload, ConflictSession, ConflictStageParts, canonicalize_stage_parts,
+    ConflictFileSummary, ConflictPayload, ConflictSession, ConflictStageParts,
+    canonicalize_stage_parts,
 };
 use gitcomet_core::domain::*;
+use gitcomet_core::mailbox::MailboxPatch;
 use gitcomet_core::process::GitRuntimeState;
 use gitcomet_core::services::{
-    BlameLine, ForcePushLease, SafePushAfterCommitContext, SubmoduleTrustTarget,
+    BlameLine, BlameOptions, CommitFileSearchMatch, DiffIgnoreOptions, DiffWhitespaceIgnore,
+    ForcePushLease, RenameDetection, SafePushAfterCommitContext, SubmoduleTrustTarget,
+    TextSearchOptions,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -280,6 +284,11 @@ pub struct AppState {
     pub submodule_trust_prompt: Option<SubmoduleTrustPromptState>,
     pub git_runtime: GitRuntimeState,
     pub git_log_settings: GitLogSettings,
+    /// Rename/copy thresholds chosen in settings; `None` defers to git config.
+    pub rename_detection: Option<RenameDetection>,
+    /// App-wide whitespace handling for diffs; it overrides the `whitespace`
+    /// of every repository's `diff_ignore_options`.
+    pub diff_whitespace: DiffWhitespaceIgnore,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -430,6 +439,42 @@ pub struct PendingCommitRetry {
     pub push_after_commit: bool,
 }
 
+/// A "find in all files of this commit" query and its results.
+#[derive(Clone, Debug)]
+pub struct CommitFileSearch {
+    pub commit_id: CommitId,
+    pub query: String,
+    pub options: TextSearchOptions,
+    pub matches: Loadable<Shared<Vec<CommitFileSearchMatch>>>,
+}
+
+/// Patches read from an mbox file or patch directory, shown for review
+/// before the series is applied with `git am`.
+#[derive(Clone, Debug)]
+pub struct PatchSeriesPreview {
+    pub source: PathBuf,
+    pub patches: Loadable<Shared<Vec<MailboxPatch>>>,
+}
+
+/// A file opened from the revision tree browser: its contents at the browsed
+/// revision, or its diff against the working tree copy.
+#[derive(Clone, Debug)]
+pub struct RevisionFilePreview {
+    pub path: PathBuf,
+    pub against_worktree: bool,
+    pub text: Loadable<Shared<String>>,
+}
+
+/// One directory of a commit's tree, as shown by the tree browser.
+#[derive(Clone, Debug)]
+pub struct RevisionTreeBrowser {
+    pub rev: String,
+    /// Repository-relative directory; empty for the root.
+    pub dir: PathBuf,
+    pub entries: Loadable<Shared<Vec<RevisionTreeEntry>>>,
+    pub file: Option<RevisionFilePreview>,
+}
+
 #[derive(Clone, Debug)]
 pub struct HistoryState {
     pub history_scope: LogScope,
@@ -441,11 +486,21 @@ pub struct HistoryState {
     pub file_history: Loadable<Shared<LogPage>>,
     pub blame_path: Option<PathBuf>,
     pub blame_rev: Option<String>,
+    pub blame_options: BlameOptions,
     pub blame: Loadable<Shared<Vec<BlameLine>>>,
+    pub line_history_path: Option<PathBuf>,
+    pub line_history_range: Option<(u32, u32)>,
+    pub line_history_rev: Option<String>,
+    pub line_history: Loadable<Shared<LineRangeLogPage>>,
     pub selected_commit: Option<CommitId>,
     pub selected_commit_rev: u64,
     pub commit_details: Loadable<Shared<CommitDetails>>,
     pub commit_details_rev: u64,
+    pub commit_file_search: Option<CommitFileSearch>,
+    pub commit_file_search_rev: u64,
+    pub reword_blocker_commit: Option<CommitId>,
+    /// Why `reword_blocker_commit` can't be reworded; `Ready(None)` once it can.
+    pub reword_blocker: Loadable<Option<String>>,
 }
 
 impl Default for HistoryState {
@@ -460,11 +515,20 @@ impl Default for HistoryState {
             file_history: Loadable::NotLoaded,
             blame_path: None,
             blame_rev: None,
+            blame_options: BlameOptions::default(),
             blame: Loadable::NotLoaded,
+            line_history_path: None,
+            line_history_range: None,
+            line_history_rev: None,
+            line_history: Loadable::NotLoaded,
             selected_commit: None,
             selected_commit_rev: 0,
             commit_details: Loadable::NotLoaded,
             commit_details_rev: 0,
+            commit_file_search: None,
+            commit_file_search_rev: 0,
+            reword_blocker_commit: None,
+            reword_blocker: Loadable::NotLoaded,
         }
     }
 }
@@ -545,6 +609,7 @@ pub struct ConflictState {
     pub conflict_file: Loadable<Option<ConflictFile>>,
     pub conflict_session: Option<ConflictSession>,
     pub conflict_hide_resolved: bool,
+    pub conflict_summaries: Loadable<Arc<Vec<ConflictFileSummary>>>,
     pub conflict_rev: u64,
 }
 
@@ -556,6 +621,7 @@ impl Default for ConflictState {
             conflict_file: Loadable::NotLoaded,
             conflict_session: None,
             conflict_hide_resolved: false,
+            conflict_summaries: Loadable::NotLoaded,
             conflict_rev: 0,
         }
     }
@@ -599,6 +665,8 @@ pub struct RepoState {
     pub open: Loadable<()>,
     pub history_state: HistoryState,
     pub fetch_prune_deleted_remote_tracking_branches: bool,
+    /// Whitespace and ignore-regex options applied to this repository's diffs.
+    pub diff_ignore_options: DiffIgnoreOptions,
     pub head_branch: Loadable<String>,
     pub detached_head_commit: Option<CommitId>,
     pub head_branch_rev: u64,
@@ -630,12 +698,20 @@ pub struct RepoState {
     pub stashes: Loadable<Arc<Vec<StashEntry>>>,
     pub stashes_rev: u64,
     pub reflog: Loadable<Vec<ReflogEntry>>,
+    pub patch_series_preview: Option<PatchSeriesPreview>,
+    pub patch_series_preview_rev: u64,
+    pub revision_tree: Option<RevisionTreeBrowser>,
+    pub revision_tree_rev: u64,
     pub recent_commit_messages: Loadable<Arc<Vec<RecentCommitMessage>>>,
     pub recent_commit_messages_rev: u64,
     pub rebase_in_progress: Loadable<bool>,
+    pub operation_state: Loadable<Option<RepoOperationState>>,
+    pub operation_state_rev: u64,
     pub merge_commit_message: Loadable<Option<String>>,
     pub merge_message_rev: u64,
     pub worktrees: Loadable<Arc<Vec<Worktree>>>,
+    /// Dirty-file counts and upstream divergence for the sidebar overview.
+    pub worktree_summaries: Loadable<Arc<Vec<WorktreeSummary>>>,
     pub worktrees_rev: u64,
     pub submodules: Loadable<Arc<Vec<Submodule>>>,
     pub submodules_rev: u64,
@@ -676,6 +752,7 @@ impl RepoState {
             open: Loadable::Loading,
             history_state: HistoryState::default(),
             fetch_prune_deleted_remote_tracking_branches: true,
+            diff_ignore_options: DiffIgnoreOptions::default(),
             head_branch: Loadable::NotLoaded,
             detached_head_commit: None,
             head_branch_rev: 0,
@@ -704,12 +781,19 @@ impl RepoState {
             stashes: Loadable::NotLoaded,
             stashes_rev: 0,
             reflog: Loadable::NotLoaded,
+            patch_series_preview: None,
+            patch_series_preview_rev: 0,
+            revision_tree: None,
+            revision_tree_rev: 0,
             recent_commit_messages: Loadable::NotLoaded,
             recent_commit_messages_rev: 0,
             rebase_in_progress: Loadable::NotLoaded,
+            operation_state: Loadable::NotLoaded,
+            operation_state_rev: 0,
             merge_commit_message: Loadable::NotLoaded,
             merge_message_rev: 0,
             worktrees: Loadable::NotLoaded,
+            worktree_summaries: Loadable::NotLoaded,
             worktrees_rev: 0,
             submodules: Loadable::NotLoaded,
             submodules_rev: 0,
@@ -840,6 +924,23 @@ impl RepoState {
         self.bump_branch_sidebar_rev();
     }
 
+    pub(crate) fn set_worktree_summaries(&mut self, summaries: Loadable<Vec<WorktreeSummary>>) {
+        let summaries = loadable_into_arc(summaries);
+        if self.worktree_summaries == summaries {
+            return;
+        }
+        self.worktree_summaries = summaries;
+        self.worktrees_rev = self.worktrees_rev.wrapping_add(1);
+        self.bump_branch_sidebar_rev();
+    }
+
+    pub fn worktree_summary(&self, path: &std::path::Path) -> Option<&WorktreeSummary> {
+        match &self.worktree_summaries {
+            Loadable::Ready(summaries) => summaries.iter().find(|summary| summary.path == path),
+            _ => None,
+        }
+    }
+
     pub(crate) fn set_submodules(&mut self, submodules: Loadable<Vec<Submodule>>) {
         let submodules = loadable_into_arc(submodules);
         if self.submodules == submodules {
@@ -1064,6 +1165,22 @@ impl RepoState {
             self.history_state.commit_details_rev.wrapping_add(1);
     }
 
+    pub(crate) fn set_commit_file_search(&mut self, v: Option<CommitFileSearch>) {
+        self.history_state.commit_file_search = v;
+        self.history_state.commit_file_search_rev =
+            self.history_state.commit_file_search_rev.wrapping_add(1);
+    }
+
+    pub(crate) fn set_patch_series_preview(&mut self, v: Option<PatchSeriesPreview>) {
+        self.patch_series_preview = v;
+        self.patch_series_preview_rev = self.patch_series_preview_rev.wrapping_add(1);
+    }
+
+    pub(crate) fn set_revision_tree(&mut self, v: Option<RevisionTreeBrowser>) {
+        self.revision_tree = v;
+        self.revision_tree_rev = self.revision_tree_rev.wrapping_add(1);
+    }
+
     pub(crate) fn set_merge_commit_message(&mut self, v: Loadable<Option<String>>) {
         self.merge_commit_message = v;
         self.merge_message_rev = self.merge_message_rev.wrapping_add(1);
@@ -1074,6 +1191,11 @@ impl RepoState {
         self.merge_message_rev = self.merge_message_rev.wrapping_add(1);
     }
 
+    pub(crate) fn set_operation_state(&mut self, v: Loadable<Option<RepoOperationState>>) {
+        self.operation_state = v;
+        self.operation_state_rev = self.operation_state_rev.wrapping_add(1);
+    }
+
     pub(crate) fn set_upstream_divergence(&mut self, v: Loadable<Option<UpstreamDivergence>>) {
         self.upstream_divergence = v;
         self.upstream_divergence_rev = self.upstream_divergence_rev.wrapping_add(1);
@@ -1102,6 +1224,15 @@ impl RepoState {
         self.conflict_state.conflict_rev = self.conflict_state.conflict_rev.wrapping_add(1);
     }
 
+    pub(crate) fn set_conflict_summaries(&mut self, v: Loadable<Vec<ConflictFileSummary>>) {
+        let v = loadable_into_arc(v);
+        if self.conflict_state.conflict_summaries == v {
+            return;
+        }
+        self.conflict_state.conflict_summaries = v;
+        self.conflict_state.conflict_rev = self.conflict_state.conflict_rev.wrapping_add(1);
+    }
+
     pub(crate) fn set_conflict_session(&mut self, v: Option<ConflictSession>) {
         self.conflict_state.conflict_session = v;
         self.conflict_state.conflict_rev = self.conflict_state.conflict_rev.wrapping_add(1);
@@ -1211,6 +1342,9 @@ mod tests {
             author_time_unix: None,
             summary: "s1".into(),
             line: "line".to_string(),
+            original_path: None,
+            original_line: Some(1),
+            has_parent: true,
         }]));
         repo.history_state.commit_details = Loadable::Ready(Arc::new(CommitDetails {
             id: CommitId("c1".into()),
@@ -1223,6 +1357,7 @@ mod tests {
             target: DiffTarget::Commit {
                 commit_id: CommitId("c1".into()),
                 path: None,
+                old_path: None,
             },
             lines: Vec::new(),
         }));
@@ -1274,6 +1409,8 @@ mod tests {
             path: PathBuf::from(path),
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -1532,6 +1669,17 @@ mod tests {
         assert_eq!(repo.merge_message_rev, before + 1);
     }
 
+    #[test]
+    fn set_operation_state_bumps_operation_state_rev() {
+        let mut repo = new_repo();
+        let before = (repo.operation_state_rev, repo.merge_message_rev);
+        repo.set_operation_state(Loadable::Ready(Some(RepoOperationState::Bisect)));
+        assert_eq!(
+            (repo.operation_state_rev, repo.merge_message_rev),
+            (before.0 + 1, before.1)
+        );
+    }
+
     #[test]
     fn merge_message_and_rebase_share_same_rev_counter() {
         let mut repo = new_repo();
@@ -1627,6 +1775,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         repo.set_diff_target(Some(target.clone()));
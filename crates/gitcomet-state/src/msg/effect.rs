@@ -2,8 +2,9 @@ use crate::model::{ConflictFileLoadMode, RepoId};
 use gitcomet_core::auth::StagedGitAuth;
 use gitcomet_core::domain::*;
 use gitcomet_core::services::{
-    ConflictSide, ForcePushLease, PullMode, RemoteUrlKind, ResetMode, SafePushAfterCommitContext,
-    SafePushAfterCommitTarget, SubmoduleTrustTarget,
+    BlameOptions, ConflictSide, FixupCommitKind, ForcePushLease, PullMode, RemoteUrlKind,
+    ResetMode, SafePushAfterCommitContext, SafePushAfterCommitTarget, SubmoduleBatchCommand,
+    SubmoduleTrustTarget, TextSearchOptions,
 };
 use std::path::PathBuf;
 
@@ -89,10 +90,26 @@ pub enum Effect {
         path: PathBuf,
         limit: usize,
     },
+    LoadLineHistory {
+        repo_id: RepoId,
+        path: PathBuf,
+        start: u32,
+        end: u32,
+        rev: Option<String>,
+        limit: usize,
+    },
     LoadBlame {
         repo_id: RepoId,
         path: PathBuf,
         rev: Option<String>,
+        options: BlameOptions,
+    },
+    SearchCommitFiles {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        paths: Vec<PathBuf>,
+        query: String,
+        options: TextSearchOptions,
     },
     LoadWorktrees {
         repo_id: RepoId,
@@ -113,6 +130,10 @@ pub enum Effect {
         repo_id: RepoId,
         commit_id: CommitId,
     },
+    LoadRewordBlocker {
+        repo_id: RepoId,
+        commit_id: CommitId,
+    },
     LoadDiff {
         repo_id: RepoId,
         target: DiffTarget,
@@ -223,10 +244,64 @@ pub enum Effect {
         commit_id: CommitId,
         dest: PathBuf,
     },
+    ExportPatchSeries {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+        dest: PathBuf,
+        cover_letter: bool,
+    },
+    CherryPickCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    RevertCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    SquashCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    RewordCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        message: String,
+    },
+    CreateFixupCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        kind: FixupCommitKind,
+    },
     ApplyPatch {
         repo_id: RepoId,
         patch: PathBuf,
     },
+    LoadPatchSeriesPreview {
+        repo_id: RepoId,
+        source: PathBuf,
+    },
+    LoadRevisionTree {
+        repo_id: RepoId,
+        rev: String,
+        dir: PathBuf,
+    },
+    LoadRevisionFile {
+        repo_id: RepoId,
+        rev: String,
+        path: PathBuf,
+        against_worktree: bool,
+    },
+    SaveRevisionPath {
+        repo_id: RepoId,
+        rev: String,
+        path: PathBuf,
+        dest: PathBuf,
+    },
+    ApplyPatchSeries {
+        repo_id: RepoId,
+        source: PathBuf,
+        three_way: bool,
+    },
     AddWorktree {
         repo_id: RepoId,
         path: PathBuf,
@@ -240,6 +315,23 @@ pub enum Effect {
         repo_id: RepoId,
         path: PathBuf,
     },
+    LockWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+        reason: Option<String>,
+    },
+    UnlockWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+    },
+    MoveWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+        new_path: PathBuf,
+    },
+    PruneWorktrees {
+        repo_id: RepoId,
+    },
     CheckSubmoduleAddTrust {
         repo_id: RepoId,
         url: String,
@@ -285,6 +377,24 @@ pub enum Effect {
         repo_id: RepoId,
         path: PathBuf,
     },
+    SyncSubmodules {
+        repo_id: RepoId,
+    },
+    SetSubmoduleBranch {
+        repo_id: RepoId,
+        path: PathBuf,
+        branch: Option<String>,
+    },
+    UpdateSubmoduleRemote {
+        repo_id: RepoId,
+        path: PathBuf,
+        auth: Option<StagedGitAuth>,
+    },
+    RunInSubmodules {
+        repo_id: RepoId,
+        command: SubmoduleBatchCommand,
+        auth: Option<StagedGitAuth>,
+    },
     StageHunk {
         repo_id: RepoId,
         patch: String,
@@ -407,6 +517,14 @@ pub enum Effect {
         branch: String,
         auth: Option<StagedGitAuth>,
     },
+    RenameBranch {
+        repo_id: RepoId,
+        name: String,
+        new_name: String,
+        force: bool,
+        upstream: Option<Upstream>,
+        auth: Option<StagedGitAuth>,
+    },
     Reset {
         repo_id: RepoId,
         target: String,
@@ -416,12 +534,20 @@ pub enum Effect {
         repo_id: RepoId,
         onto: String,
     },
+    AutosquashRebase {
+        repo_id: RepoId,
+    },
     RebaseContinue {
         repo_id: RepoId,
     },
     RebaseAbort {
         repo_id: RepoId,
     },
+    OperationAction {
+        repo_id: RepoId,
+        kind: RepoOperationKind,
+        action: RepoOperationAction,
+    },
     MergeAbort {
         repo_id: RepoId,
     },
@@ -478,6 +604,18 @@ pub enum Effect {
         repo_id: RepoId,
         path: PathBuf,
     },
+    LoadConflictSummaries {
+        repo_id: RepoId,
+    },
+    CheckoutConflictSidePaths {
+        repo_id: RepoId,
+        paths: RepoPathList,
+        side: ConflictSide,
+    },
+    AutosolveConflictPaths {
+        repo_id: RepoId,
+        paths: RepoPathList,
+    },
     Stash {
         repo_id: RepoId,
         message: String,
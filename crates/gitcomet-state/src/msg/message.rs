@@ -1,15 +1,17 @@
 use crate::model::GitLogTagFetchMode;
 use crate::model::{ConflictFileLoadMode, RepoId, SidebarDataRequest};
 use gitcomet_core::auth::StagedGitAuth;
-use gitcomet_core::conflict_session::ConflictSession;
+use gitcomet_core::conflict_session::{ConflictFileSummary, ConflictSession};
 use gitcomet_core::domain::*;
 use gitcomet_core::error::Error;
 use gitcomet_core::process::GitRuntimeState;
 use gitcomet_core::services::GitRepository;
 use gitcomet_core::services::{
-    CommandOutput, CommitOperationOutcome, ConflictSide, ForcePushLease, PullMode, RemoteUrlKind,
-    ResetMode, SafePushAfterCommitContext, SafePushAfterCommitDecision, SafePushAfterCommitTarget,
-    SubmoduleTrustDecision, SubmoduleTrustTarget,
+    BlameOptions, CommandOutput, CommitOperationOutcome, ConflictSide, DiffIgnoreOptions,
+    DiffWhitespaceIgnore, FixupCommitKind, ForcePushLease, PullMode, RemoteUrlKind,
+    RenameDetection, ResetMode, SafePushAfterCommitContext, SafePushAfterCommitDecision,
+    SafePushAfterCommitTarget, SubmoduleBatchCommand, SubmoduleCommandResult,
+    SubmoduleTrustDecision, SubmoduleTrustTarget, TextSearchOptions,
 };
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -123,6 +125,13 @@ pub enum Msg {
         show_history_tags: bool,
         tag_fetch_mode: GitLogTagFetchMode,
     },
+    SetRenameDetection {
+        detection: RenameDetection,
+    },
+    /// Apply the UI's whitespace mode to diffs of every repository.
+    SetDiffWhitespace {
+        whitespace: DiffWhitespaceIgnore,
+    },
     SetActiveRepo {
         repo_id: RepoId,
     },
@@ -145,6 +154,10 @@ pub enum Msg {
         repo_id: RepoId,
         enabled: bool,
     },
+    SetDiffIgnoreOptions {
+        repo_id: RepoId,
+        options: DiffIgnoreOptions,
+    },
     LoadMoreHistory {
         repo_id: RepoId,
     },
@@ -209,6 +222,28 @@ pub enum Msg {
         path: PathBuf,
         rev: Option<String>,
     },
+    /// History of lines `start..=end` of `path` as of `rev` (`HEAD` when
+    /// `None`).
+    LoadLineHistory {
+        repo_id: RepoId,
+        path: PathBuf,
+        start: u32,
+        end: u32,
+        rev: Option<String>,
+    },
+    /// Search every file of the selected commit for `query`. An empty query
+    /// clears the previous results.
+    SearchCommitFiles {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        query: String,
+        options: TextSearchOptions,
+    },
+    /// Change how blame is computed and reload the open blame, if any.
+    SetBlameOptions {
+        repo_id: RepoId,
+        options: BlameOptions,
+    },
     LoadWorktrees {
         repo_id: RepoId,
     },
@@ -259,6 +294,37 @@ pub enum Msg {
         repo_id: RepoId,
         commit_id: CommitId,
     },
+    /// Range-aware variants of the single-commit actions above, used for a
+    /// multi-commit history selection. The backend orders `commit_ids`.
+    CherryPickCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    RevertCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    SquashCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    /// Check whether `commit_id` may be reworded in place.
+    LoadRewordBlocker {
+        repo_id: RepoId,
+        commit_id: CommitId,
+    },
+    /// Replace the message of an unpublished commit on the current branch.
+    RewordCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        message: String,
+    },
+    /// Commit the staged changes as a `fixup!`/`squash!` commit for `commit_id`.
+    CreateFixupCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        kind: FixupCommitKind,
+    },
     CreateBranch {
         repo_id: RepoId,
         name: String,
@@ -289,10 +355,50 @@ pub enum Msg {
         commit_id: CommitId,
         dest: PathBuf,
     },
+    /// Export the commits as one mbox at `dest`, or with `cover_letter` as
+    /// numbered patch files in the `dest` directory.
+    ExportPatchSeries {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+        dest: PathBuf,
+        cover_letter: bool,
+    },
     ApplyPatch {
         repo_id: RepoId,
         patch: PathBuf,
     },
+    /// Read the patches in an mbox file or patch directory for review.
+    /// `None` drops the current preview.
+    LoadPatchSeriesPreview {
+        repo_id: RepoId,
+        source: Option<PathBuf>,
+    },
+    ApplyPatchSeries {
+        repo_id: RepoId,
+        source: PathBuf,
+        three_way: bool,
+    },
+    /// List `dir` in the tree of `rev` for the tree browser, closing any file
+    /// opened from it.
+    LoadRevisionTree {
+        repo_id: RepoId,
+        rev: String,
+        dir: PathBuf,
+    },
+    /// Show a file of the browsed revision, or its diff against the working
+    /// tree. `None` returns to the directory listing.
+    LoadRevisionFile {
+        repo_id: RepoId,
+        path: Option<PathBuf>,
+        against_worktree: bool,
+    },
+    /// Write the file or directory at `path` in `rev` to `dest`.
+    SaveRevisionPath {
+        repo_id: RepoId,
+        rev: String,
+        path: PathBuf,
+        dest: PathBuf,
+    },
     AddWorktree {
         repo_id: RepoId,
         path: PathBuf,
@@ -306,6 +412,23 @@ pub enum Msg {
         repo_id: RepoId,
         path: PathBuf,
     },
+    LockWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+        reason: Option<String>,
+    },
+    UnlockWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+    },
+    MoveWorktree {
+        repo_id: RepoId,
+        path: PathBuf,
+        new_path: PathBuf,
+    },
+    PruneWorktrees {
+        repo_id: RepoId,
+    },
     AddSubmodule {
         repo_id: RepoId,
         url: String,
@@ -350,6 +473,22 @@ pub enum Msg {
         repo_id: RepoId,
         path: PathBuf,
     },
+    SyncSubmodules {
+        repo_id: RepoId,
+    },
+    SetSubmoduleBranch {
+        repo_id: RepoId,
+        path: PathBuf,
+        branch: Option<String>,
+    },
+    UpdateSubmoduleRemote {
+        repo_id: RepoId,
+        path: PathBuf,
+    },
+    RunInSubmodules {
+        repo_id: RepoId,
+        command: SubmoduleBatchCommand,
+    },
     StagePath {
         repo_id: RepoId,
         path: PathBuf,
@@ -454,6 +593,15 @@ pub enum Msg {
         remote: String,
         branch: String,
     },
+    /// Rename a local branch. With `upstream` set, the remote branch is
+    /// renamed to `new_name` as well and tracking moves with it.
+    RenameBranch {
+        repo_id: RepoId,
+        name: String,
+        new_name: String,
+        force: bool,
+        upstream: Option<Upstream>,
+    },
     Reset {
         repo_id: RepoId,
         target: String,
@@ -463,12 +611,21 @@ pub enum Msg {
         repo_id: RepoId,
         onto: String,
     },
+    AutosquashRebase {
+        repo_id: RepoId,
+    },
     RebaseContinue {
         repo_id: RepoId,
     },
     RebaseAbort {
         repo_id: RepoId,
     },
+    /// Continue, skip or abort the interrupted operation of the given kind.
+    OperationAction {
+        repo_id: RepoId,
+        kind: RepoOperationKind,
+        action: RepoOperationAction,
+    },
     MergeAbort {
         repo_id: RepoId,
     },
@@ -523,6 +680,18 @@ pub enum Msg {
         repo_id: RepoId,
         path: PathBuf,
     },
+    LoadConflictSummaries {
+        repo_id: RepoId,
+    },
+    CheckoutConflictSidePaths {
+        repo_id: RepoId,
+        paths: RepoPathList,
+        side: ConflictSide,
+    },
+    AutosolveConflictPaths {
+        repo_id: RepoId,
+        paths: RepoPathList,
+    },
     RecordConflictAutosolveTelemetry {
         repo_id: RepoId,
         path: Option<PathBuf>,
@@ -672,6 +841,10 @@ pub enum InternalMsg {
         repo_id: RepoId,
         result: Result<bool, Error>,
     },
+    OperationStateLoaded {
+        repo_id: RepoId,
+        result: Result<Option<RepoOperationState>, Error>,
+    },
     MergeCommitMessageLoaded {
         repo_id: RepoId,
         result: Result<Option<String>, Error>,
@@ -681,12 +854,50 @@ pub enum InternalMsg {
         path: PathBuf,
         result: Result<LogPage, Error>,
     },
+    LineHistoryLoaded {
+        repo_id: RepoId,
+        path: PathBuf,
+        start: u32,
+        end: u32,
+        rev: Option<String>,
+        result: Result<LineRangeLogPage, Error>,
+    },
     BlameLoaded {
         repo_id: RepoId,
         path: PathBuf,
         rev: Option<String>,
+        options: BlameOptions,
         result: Result<Vec<gitcomet_core::services::BlameLine>, Error>,
     },
+    CommitFilesSearched {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        query: String,
+        options: TextSearchOptions,
+        result: Result<Vec<gitcomet_core::services::CommitFileSearchMatch>, Error>,
+    },
+    ConflictSummariesLoaded {
+        repo_id: RepoId,
+        result: Result<Vec<ConflictFileSummary>, Error>,
+    },
+    PatchSeriesPreviewLoaded {
+        repo_id: RepoId,
+        source: PathBuf,
+        result: Result<Vec<gitcomet_core::mailbox::MailboxPatch>, Error>,
+    },
+    RevisionTreeLoaded {
+        repo_id: RepoId,
+        rev: String,
+        dir: PathBuf,
+        result: Result<Vec<gitcomet_core::domain::RevisionTreeEntry>, Error>,
+    },
+    RevisionFileLoaded {
+        repo_id: RepoId,
+        rev: String,
+        path: PathBuf,
+        against_worktree: bool,
+        result: Result<String, Error>,
+    },
     ConflictFileLoaded {
         repo_id: RepoId,
         path: PathBuf,
@@ -697,6 +908,12 @@ pub enum InternalMsg {
         repo_id: RepoId,
         result: Result<Vec<Worktree>, Error>,
     },
+    /// Per-worktree overview, sent after each successful worktree listing.
+    /// Worktrees whose status could not be read are left out.
+    WorktreeSummariesLoaded {
+        repo_id: RepoId,
+        summaries: Vec<WorktreeSummary>,
+    },
     SubmodulesLoaded {
         repo_id: RepoId,
         result: Result<Vec<Submodule>, Error>,
@@ -724,6 +941,11 @@ pub enum InternalMsg {
         commit_id: CommitId,
         result: Result<CommitDetails, Error>,
     },
+    RewordBlockerLoaded {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        result: Result<Option<String>, Error>,
+    },
     DiffLoaded {
         repo_id: RepoId,
         target: DiffTarget,
@@ -792,6 +1014,11 @@ pub enum InternalMsg {
         command: RepoCommandKind,
         result: Result<CommandOutput, Error>,
     },
+    SubmoduleBatchFinished {
+        repo_id: RepoId,
+        command: SubmoduleBatchCommand,
+        result: Result<Vec<SubmoduleCommandResult>, Error>,
+    },
 }
 
 impl From<InternalMsg> for Msg {
@@ -127,6 +127,11 @@ impl std::fmt::Debug for InternalMsg {
                 .field("repo_id", repo_id)
                 .field("result", result)
                 .finish(),
+            InternalMsg::OperationStateLoaded { repo_id, result } => f
+                .debug_struct("OperationStateLoaded")
+                .field("repo_id", repo_id)
+                .field("result", result)
+                .finish(),
             InternalMsg::MergeCommitMessageLoaded { repo_id, result } => f
                 .debug_struct("MergeCommitMessageLoaded")
                 .field("repo_id", repo_id)
@@ -142,16 +147,89 @@ impl std::fmt::Debug for InternalMsg {
                 .field("path", path)
                 .field("result", result)
                 .finish(),
+            InternalMsg::LineHistoryLoaded {
+                repo_id,
+                path,
+                start,
+                end,
+                rev,
+                result,
+            } => f
+                .debug_struct("LineHistoryLoaded")
+                .field("repo_id", repo_id)
+                .field("path", path)
+                .field("start", start)
+                .field("end", end)
+                .field("rev", rev)
+                .field("result", result)
+                .finish(),
             InternalMsg::BlameLoaded {
                 repo_id,
                 path,
                 rev,
+                options,
                 result,
             } => f
                 .debug_struct("BlameLoaded")
                 .field("repo_id", repo_id)
                 .field("path", path)
                 .field("rev", rev)
+                .field("options", options)
+                .field("result", result)
+                .finish(),
+            InternalMsg::CommitFilesSearched {
+                repo_id,
+                commit_id,
+                query,
+                options,
+                result,
+            } => f
+                .debug_struct("CommitFilesSearched")
+                .field("repo_id", repo_id)
+                .field("commit_id", commit_id)
+                .field("query", query)
+                .field("options", options)
+                .field("result", result)
+                .finish(),
+            InternalMsg::ConflictSummariesLoaded { repo_id, result } => f
+                .debug_struct("ConflictSummariesLoaded")
+                .field("repo_id", repo_id)
+                .field("result", result)
+                .finish(),
+            InternalMsg::PatchSeriesPreviewLoaded {
+                repo_id,
+                source,
+                result,
+            } => f
+                .debug_struct("PatchSeriesPreviewLoaded")
+                .field("repo_id", repo_id)
+                .field("source", source)
+                .field("result", result)
+                .finish(),
+            InternalMsg::RevisionTreeLoaded {
+                repo_id,
+                rev,
+                dir,
+                result,
+            } => f
+                .debug_struct("RevisionTreeLoaded")
+                .field("repo_id", repo_id)
+                .field("rev", rev)
+                .field("dir", dir)
+                .field("result", result)
+                .finish(),
+            InternalMsg::RevisionFileLoaded {
+                repo_id,
+                rev,
+                path,
+                against_worktree,
+                result,
+            } => f
+                .debug_struct("RevisionFileLoaded")
+                .field("repo_id", repo_id)
+                .field("rev", rev)
+                .field("path", path)
+                .field("against_worktree", against_worktree)
                 .field("result", result)
                 .finish(),
             InternalMsg::ConflictFileLoaded {
@@ -171,6 +249,11 @@ impl std::fmt::Debug for InternalMsg {
                 .field("repo_id", repo_id)
                 .field("result", result)
                 .finish(),
+            InternalMsg::WorktreeSummariesLoaded { repo_id, summaries } => f
+                .debug_struct("WorktreeSummariesLoaded")
+                .field("repo_id", repo_id)
+                .field("summaries", summaries)
+                .finish(),
             InternalMsg::SubmodulesLoaded { repo_id, result } => f
                 .debug_struct("SubmodulesLoaded")
                 .field("repo_id", repo_id)
@@ -219,6 +302,16 @@ impl std::fmt::Debug for InternalMsg {
                 .field("commit_id", commit_id)
                 .field("result", result)
                 .finish(),
+            InternalMsg::RewordBlockerLoaded {
+                repo_id,
+                commit_id,
+                result,
+            } => f
+                .debug_struct("RewordBlockerLoaded")
+                .field("repo_id", repo_id)
+                .field("commit_id", commit_id)
+                .field("result", result)
+                .finish(),
             InternalMsg::DiffLoaded {
                 repo_id,
                 target,
@@ -349,6 +442,16 @@ impl std::fmt::Debug for InternalMsg {
                 .field("command", command)
                 .field("result", result)
                 .finish(),
+            InternalMsg::SubmoduleBatchFinished {
+                repo_id,
+                command,
+                result,
+            } => f
+                .debug_struct("SubmoduleBatchFinished")
+                .field("repo_id", repo_id)
+                .field("command", command)
+                .field("result", result)
+                .finish(),
         }
     }
 }
@@ -1,7 +1,7 @@
-use gitcomet_core::domain::CommitId;
+use gitcomet_core::domain::{CommitId, RepoOperationAction, RepoOperationKind, Upstream};
 use gitcomet_core::services::{
-    ConflictSide, ForcePushLease, PullMode, RemoteUrlKind, ResetMode, SafePushAfterCommitTarget,
-    SubmoduleTrustTarget,
+    ConflictSide, FixupCommitKind, ForcePushLease, PullMode, RemoteUrlKind, ResetMode,
+    SafePushAfterCommitTarget, SubmoduleBatchCommand, SubmoduleTrustTarget,
 };
 use std::path::PathBuf;
 
@@ -47,6 +47,12 @@ pub enum RepoCommandKind {
         remote: String,
         branch: String,
     },
+    RenameBranch {
+        name: String,
+        new_name: String,
+        force: bool,
+        upstream: Option<Upstream>,
+    },
     Reset {
         mode: ResetMode,
         target: String,
@@ -54,8 +60,13 @@ pub enum RepoCommandKind {
     Rebase {
         onto: String,
     },
+    AutosquashRebase,
     RebaseContinue,
     RebaseAbort,
+    OperationAction {
+        kind: RepoOperationKind,
+        action: RepoOperationAction,
+    },
     MergeAbort,
     CreateTag {
         name: String,
@@ -97,6 +108,13 @@ pub enum RepoCommandKind {
     LaunchMergetool {
         path: PathBuf,
     },
+    CheckoutConflictPaths {
+        paths: Vec<PathBuf>,
+        side: ConflictSide,
+    },
+    AutosolveConflicts {
+        paths: Vec<PathBuf>,
+    },
     SaveWorktreeFile {
         path: PathBuf,
         stage: bool,
@@ -105,9 +123,40 @@ pub enum RepoCommandKind {
         commit_id: CommitId,
         dest: PathBuf,
     },
+    ExportPatchSeries {
+        commit_ids: Vec<CommitId>,
+        dest: PathBuf,
+        cover_letter: bool,
+    },
+    CherryPickCommits {
+        commit_ids: Vec<CommitId>,
+    },
+    RevertCommits {
+        commit_ids: Vec<CommitId>,
+    },
+    SquashCommits {
+        commit_ids: Vec<CommitId>,
+    },
+    RewordCommit {
+        commit_id: CommitId,
+        message: String,
+    },
+    CreateFixupCommit {
+        commit_id: CommitId,
+        kind: FixupCommitKind,
+    },
     ApplyPatch {
         patch: PathBuf,
     },
+    ApplyPatchSeries {
+        source: PathBuf,
+        three_way: bool,
+    },
+    SaveRevisionPath {
+        rev: String,
+        path: PathBuf,
+        dest: PathBuf,
+    },
     AddWorktree {
         path: PathBuf,
         reference: Option<String>,
@@ -118,6 +167,18 @@ pub enum RepoCommandKind {
     ForceRemoveWorktree {
         path: PathBuf,
     },
+    LockWorktree {
+        path: PathBuf,
+        reason: Option<String>,
+    },
+    UnlockWorktree {
+        path: PathBuf,
+    },
+    MoveWorktree {
+        path: PathBuf,
+        new_path: PathBuf,
+    },
+    PruneWorktrees,
     AddSubmodule {
         url: String,
         path: PathBuf,
@@ -140,6 +201,17 @@ pub enum RepoCommandKind {
     RemoveSubmodule {
         path: PathBuf,
     },
+    SyncSubmodules,
+    SetSubmoduleBranch {
+        path: PathBuf,
+        branch: Option<String>,
+    },
+    UpdateSubmoduleRemote {
+        path: PathBuf,
+    },
+    RunInSubmodules {
+        command: SubmoduleBatchCommand,
+    },
     StageHunk,
     UnstageHunk,
     ApplyWorktreePatch {
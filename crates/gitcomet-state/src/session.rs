@@ -1,5 +1,6 @@
 use crate::model::{AppState, GitLogTagFetchMode, RepoId};
 use gitcomet_core::domain::{HistoryMode, LogScope};
+use gitcomet_core::services::{DiffIgnoreOptions, DiffWhitespaceIgnore, RenameDetection};
 use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
@@ -74,6 +75,72 @@ impl From<HistoryScopeSetting> for LogScope {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+/// Whitespace handling is app-wide (the UI's diff whitespace mode), so only
+/// the remaining options are stored per repository.
+struct DiffIgnoreOptionsSetting {
+    ignore_blank_lines: bool,
+    ignore_cr_at_eol: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ignore_matching_lines: Vec<String>,
+}
+
+impl From<&DiffIgnoreOptions> for DiffIgnoreOptionsSetting {
+    fn from(value: &DiffIgnoreOptions) -> Self {
+        Self {
+            ignore_blank_lines: value.ignore_blank_lines,
+            ignore_cr_at_eol: value.ignore_cr_at_eol,
+            ignore_matching_lines: value.ignore_matching_lines.clone(),
+        }
+    }
+}
+
+impl From<DiffIgnoreOptionsSetting> for DiffIgnoreOptions {
+    fn from(value: DiffIgnoreOptionsSetting) -> Self {
+        Self {
+            whitespace: DiffWhitespaceIgnore::None,
+            ignore_blank_lines: value.ignore_blank_lines,
+            ignore_cr_at_eol: value.ignore_cr_at_eol,
+            ignore_matching_lines: value.ignore_matching_lines,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+struct RenameDetectionSetting {
+    rename_threshold: u8,
+    detect_copies: bool,
+    copy_threshold: u8,
+}
+
+impl Default for RenameDetectionSetting {
+    fn default() -> Self {
+        RenameDetection::default().into()
+    }
+}
+
+impl From<RenameDetection> for RenameDetectionSetting {
+    fn from(value: RenameDetection) -> Self {
+        Self {
+            rename_threshold: value.rename_threshold,
+            detect_copies: value.detect_copies,
+            copy_threshold: value.copy_threshold,
+        }
+    }
+}
+
+impl From<RenameDetectionSetting> for RenameDetection {
+    fn from(value: RenameDetectionSetting) -> Self {
+        Self {
+            rename_threshold: value.rename_threshold.min(100),
+            detect_copies: value.detect_copies,
+            copy_threshold: value.copy_threshold.min(100),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 enum HistoryModeSetting {
@@ -152,6 +219,8 @@ struct UiSessionFile {
     repo_history_modes: Option<BTreeMap<String, HistoryModeSetting>>,
     repo_history_scopes: Option<BTreeMap<String, HistoryScopeSetting>>,
     repo_fetch_prune_deleted_remote_tracking_branches: Option<BTreeMap<String, bool>>,
+    repo_diff_ignore_options: Option<BTreeMap<String, DiffIgnoreOptionsSetting>>,
+    rename_detection: Option<RenameDetectionSetting>,
     survey_prompt: Option<SurveyPromptSession>,
 }
 
@@ -241,6 +310,8 @@ pub(crate) struct RepoSessionPreferences {
     pub(crate) repo_history_modes: BTreeMap<String, HistoryMode>,
     pub(crate) repo_history_scopes: BTreeMap<String, LogScope>,
     pub(crate) repo_fetch_prune_deleted_remote_tracking_branches: BTreeMap<String, bool>,
+    pub(crate) repo_diff_ignore_options: BTreeMap<String, DiffIgnoreOptions>,
+    pub(crate) rename_detection: Option<RenameDetection>,
 }
 
 pub(crate) fn load_repo_session_preferences() -> RepoSessionPreferences {
@@ -274,6 +345,13 @@ pub(crate) fn load_repo_session_preferences_from_path(
         repo_fetch_prune_deleted_remote_tracking_branches: file
             .repo_fetch_prune_deleted_remote_tracking_branches
             .unwrap_or_default(),
+        repo_diff_ignore_options: file
+            .repo_diff_ignore_options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(k, v)| (k, v.into()))
+            .collect(),
+        rename_detection: file.rename_detection.map(Into::into),
     }
 }
 
@@ -870,6 +948,57 @@ pub fn persist_repo_fetch_prune_deleted_remote_tracking_branches_to_path(
     persist_to_path(session_file_path, &file)
 }
 
+pub fn persist_repo_diff_ignore_options(
+    workdir: &Path,
+    options: &DiffIgnoreOptions,
+) -> io::Result<()> {
+    let Some(session_file_path) = default_session_file_path() else {
+        return Ok(());
+    };
+    persist_repo_diff_ignore_options_to_path(workdir, options, &session_file_path)
+}
+
+/// Default options are removed rather than stored, so the map only lists
+/// repositories with custom diff filtering.
+pub fn persist_repo_diff_ignore_options_to_path(
+    workdir: &Path,
+    options: &DiffIgnoreOptions,
+    session_file_path: &Path,
+) -> io::Result<()> {
+    let mut file = load_file(session_file_path).unwrap_or_default();
+    file.version = CURRENT_SESSION_FILE_VERSION;
+    let workdir_key = path_storage_key(workdir);
+    let settings = file
+        .repo_diff_ignore_options
+        .get_or_insert_with(BTreeMap::new);
+    let setting = DiffIgnoreOptionsSetting::from(options);
+    if setting == DiffIgnoreOptionsSetting::default() {
+        settings.remove(&workdir_key);
+    } else {
+        settings.insert(workdir_key, setting);
+    }
+
+    persist_to_path(session_file_path, &file)
+}
+
+pub fn persist_rename_detection(detection: RenameDetection) -> io::Result<()> {
+    let Some(session_file_path) = default_session_file_path() else {
+        return Ok(());
+    };
+    persist_rename_detection_to_path(detection, &session_file_path)
+}
+
+pub fn persist_rename_detection_to_path(
+    detection: RenameDetection,
+    session_file_path: &Path,
+) -> io::Result<()> {
+    let mut file = load_file(session_file_path).unwrap_or_default();
+    file.version = CURRENT_SESSION_FILE_VERSION;
+    file.rename_detection = Some(detection.into());
+
+    persist_to_path(session_file_path, &file)
+}
+
 pub fn should_show_survey_prompt(survey_id: &str) -> bool {
     let Some(session_file_path) = default_session_file_path() else {
         return false;
@@ -1516,6 +1645,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn persist_repo_diff_ignore_options_round_trips_and_drops_defaults() {
+        let dir = unique_session_test_dir("repo-diff-ignore-options");
+        let session_file = dir.join("session.json");
+        let repo = dir.join("repo");
+        let _ = fs::create_dir_all(&repo);
+        let options = DiffIgnoreOptions {
+            whitespace: DiffWhitespaceIgnore::AtEol,
+            ignore_blank_lines: true,
+            ignore_cr_at_eol: false,
+            ignore_matching_lines: vec!["^# Generated .*$".to_string()],
+        };
+
+        persist_repo_diff_ignore_options_to_path(&repo, &options, &session_file)
+            .expect("persist diff ignore options");
+        let loaded = load_repo_session_preferences_from_path(&session_file);
+        assert_eq!(
+            loaded
+                .repo_diff_ignore_options
+                .get(&path_storage_key(&repo)),
+            Some(&DiffIgnoreOptions {
+                whitespace: DiffWhitespaceIgnore::None,
+                ..options
+            })
+        );
+
+        // Whitespace follows the app-wide mode and is not stored per repository.
+        persist_repo_diff_ignore_options_to_path(
+            &repo,
+            &DiffIgnoreOptions {
+                whitespace: DiffWhitespaceIgnore::All,
+                ..DiffIgnoreOptions::default()
+            },
+            &session_file,
+        )
+        .expect("reset diff ignore options");
+        let loaded = load_repo_session_preferences_from_path(&session_file);
+        assert!(loaded.repo_diff_ignore_options.is_empty());
+    }
+
+    #[test]
+    fn persist_rename_detection_round_trips() {
+        let dir = unique_session_test_dir("rename-detection");
+        let session_file = dir.join("session.json");
+        assert_eq!(
+            load_repo_session_preferences_from_path(&session_file).rename_detection,
+            None
+        );
+
+        let detection = RenameDetection {
+            rename_threshold: 75,
+            detect_copies: true,
+            copy_threshold: 60,
+        };
+        persist_rename_detection_to_path(detection, &session_file)
+            .expect("persist rename detection");
+        assert_eq!(
+            load_repo_session_preferences_from_path(&session_file).rename_detection,
+            Some(detection)
+        );
+    }
+
     #[test]
     fn persist_repo_history_modes_batch_skips_empty_and_unchanged_updates() {
         let dir = unique_session_test_dir("repo-history-mode-batch");
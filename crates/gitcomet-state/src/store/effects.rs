@@ -4,6 +4,7 @@ mod repo_actions;
 mod repo_commands;
 mod repo_load;
 mod util;
+mod worktree_summaries;
 
 use crate::model::AppState;
 use crate::msg::{Effect, Msg, RepoActionKind, RepoCommandKind};
@@ -239,14 +240,48 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             }))
         }
-        Effect::LoadBlame { repo_id, path, rev } => {
-            send(Msg::Internal(crate::msg::InternalMsg::BlameLoaded {
+        Effect::LoadLineHistory {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+            ..
+        } => send(Msg::Internal(crate::msg::InternalMsg::LineHistoryLoaded {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+            result: Err(git_unavailable_error(runtime)),
+        })),
+        Effect::LoadBlame {
+            repo_id,
+            path,
+            rev,
+            options,
+        } => send(Msg::Internal(crate::msg::InternalMsg::BlameLoaded {
+            repo_id,
+            path,
+            rev,
+            options,
+            result: Err(git_unavailable_error(runtime)),
+        })),
+        Effect::SearchCommitFiles {
+            repo_id,
+            commit_id,
+            query,
+            options,
+            ..
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::CommitFilesSearched {
                 repo_id,
-                path,
-                rev,
+                commit_id,
+                query,
+                options,
                 result: Err(git_unavailable_error(runtime)),
-            }))
-        }
+            },
+        )),
         Effect::LoadWorktrees { repo_id } => {
             send(Msg::Internal(crate::msg::InternalMsg::WorktreesLoaded {
                 repo_id,
@@ -288,6 +323,12 @@ fn send_unavailable_git_effect_result(
                 repo_id,
                 result: Err(git_unavailable_error(runtime)),
             }));
+            send(Msg::Internal(
+                crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: Err(git_unavailable_error(runtime)),
+                },
+            ));
             send(Msg::Internal(
                 crate::msg::InternalMsg::MergeCommitMessageLoaded {
                     repo_id,
@@ -299,7 +340,13 @@ fn send_unavailable_git_effect_result(
             send(Msg::Internal(crate::msg::InternalMsg::RebaseStateLoaded {
                 repo_id,
                 result: Err(git_unavailable_error(runtime)),
-            }))
+            }));
+            send(Msg::Internal(
+                crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: Err(git_unavailable_error(runtime)),
+                },
+            ));
         }
         Effect::LoadMergeCommitMessage { repo_id } => send(Msg::Internal(
             crate::msg::InternalMsg::MergeCommitMessageLoaded {
@@ -314,6 +361,13 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::LoadRewordBlocker { repo_id, commit_id } => send(Msg::Internal(
+            crate::msg::InternalMsg::RewordBlockerLoaded {
+                repo_id,
+                commit_id,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::LoadDiff { repo_id, target } => {
             send(Msg::Internal(crate::msg::InternalMsg::DiffLoaded {
                 repo_id,
@@ -570,6 +624,74 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::ExportPatchSeries {
+            repo_id,
+            commit_ids,
+            dest,
+            cover_letter,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::ExportPatchSeries {
+                    commit_ids,
+                    dest,
+                    cover_letter,
+                },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::CherryPickCommits {
+            repo_id,
+            commit_ids,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::CherryPickCommits { commit_ids },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::RevertCommits {
+            repo_id,
+            commit_ids,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::RevertCommits { commit_ids },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::SquashCommits {
+            repo_id,
+            commit_ids,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::SquashCommits { commit_ids },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::RewordCommit {
+            repo_id,
+            commit_id,
+            message,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::RewordCommit { commit_id, message },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::CreateFixupCommit {
+            repo_id,
+            commit_id,
+            kind,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::CreateFixupCommit { commit_id, kind },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::ApplyPatch { repo_id, patch } => send(Msg::Internal(
             crate::msg::InternalMsg::RepoCommandFinished {
                 repo_id,
@@ -577,6 +699,56 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::LoadPatchSeriesPreview { repo_id, source } => send(Msg::Internal(
+            crate::msg::InternalMsg::PatchSeriesPreviewLoaded {
+                repo_id,
+                source,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::LoadRevisionTree { repo_id, rev, dir } => {
+            send(Msg::Internal(crate::msg::InternalMsg::RevisionTreeLoaded {
+                repo_id,
+                rev,
+                dir,
+                result: Err(git_unavailable_error(runtime)),
+            }))
+        }
+        Effect::LoadRevisionFile {
+            repo_id,
+            rev,
+            path,
+            against_worktree,
+        } => send(Msg::Internal(crate::msg::InternalMsg::RevisionFileLoaded {
+            repo_id,
+            rev,
+            path,
+            against_worktree,
+            result: Err(git_unavailable_error(runtime)),
+        })),
+        Effect::SaveRevisionPath {
+            repo_id,
+            rev,
+            path,
+            dest,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::SaveRevisionPath { rev, path, dest },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::ApplyPatchSeries {
+            repo_id,
+            source,
+            three_way,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::ApplyPatchSeries { source, three_way },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::AddWorktree {
             repo_id,
             path,
@@ -602,6 +774,42 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::LockWorktree {
+            repo_id,
+            path,
+            reason,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::LockWorktree { path, reason },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::UnlockWorktree { repo_id, path } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::UnlockWorktree { path },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::MoveWorktree {
+            repo_id,
+            path,
+            new_path,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::MoveWorktree { path, new_path },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::PruneWorktrees { repo_id } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::PruneWorktrees,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::AddSubmodule {
             repo_id,
             url,
@@ -676,6 +884,40 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::SyncSubmodules { repo_id } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::SyncSubmodules,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::SetSubmoduleBranch {
+            repo_id,
+            path,
+            branch,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::SetSubmoduleBranch { path, branch },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::UpdateSubmoduleRemote { repo_id, path, .. } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::UpdateSubmoduleRemote { path },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::RunInSubmodules {
+            repo_id, command, ..
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::SubmoduleBatchFinished {
+                repo_id,
+                command,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::StageHunk { repo_id, .. } => send(Msg::Internal(
             crate::msg::InternalMsg::RepoCommandFinished {
                 repo_id,
@@ -855,6 +1097,25 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::RenameBranch {
+            repo_id,
+            name,
+            new_name,
+            force,
+            upstream,
+            ..
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::RenameBranch {
+                    name,
+                    new_name,
+                    force,
+                    upstream,
+                },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::Reset {
             repo_id,
             target,
@@ -873,6 +1134,13 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::AutosquashRebase { repo_id } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::AutosquashRebase,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::RebaseContinue { repo_id } => send(Msg::Internal(
             crate::msg::InternalMsg::RepoCommandFinished {
                 repo_id,
@@ -880,6 +1148,17 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::OperationAction {
+            repo_id,
+            kind,
+            action,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::OperationAction { kind, action },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
         Effect::RebaseAbort { repo_id } => send(Msg::Internal(
             crate::msg::InternalMsg::RepoCommandFinished {
                 repo_id,
@@ -994,6 +1273,35 @@ fn send_unavailable_git_effect_result(
                 result: Err(git_unavailable_error(runtime)),
             },
         )),
+        Effect::LoadConflictSummaries { repo_id } => send(Msg::Internal(
+            crate::msg::InternalMsg::ConflictSummariesLoaded {
+                repo_id,
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::CheckoutConflictSidePaths {
+            repo_id,
+            paths,
+            side,
+        } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::CheckoutConflictPaths {
+                    paths: paths.as_slice().to_vec(),
+                    side,
+                },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
+        Effect::AutosolveConflictPaths { repo_id, paths } => send(Msg::Internal(
+            crate::msg::InternalMsg::RepoCommandFinished {
+                repo_id,
+                command: RepoCommandKind::AutosolveConflicts {
+                    paths: paths.as_slice().to_vec(),
+                },
+                result: Err(git_unavailable_error(runtime)),
+            },
+        )),
     }
 }
 
@@ -1176,8 +1484,34 @@ pub(super) fn schedule_effect(
             path,
             limit,
         } => repo_load::schedule_load_file_history(executor, repos, msg_tx, repo_id, path, limit),
-        Effect::LoadBlame { repo_id, path, rev } => {
-            repo_load::schedule_load_blame(executor, repos, msg_tx, repo_id, path, rev);
+        Effect::LoadLineHistory {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+            limit,
+        } => repo_load::schedule_load_line_history(
+            executor, repos, msg_tx, repo_id, path, start, end, rev, limit,
+        ),
+        Effect::LoadBlame {
+            repo_id,
+            path,
+            rev,
+            options,
+        } => {
+            repo_load::schedule_load_blame(executor, repos, msg_tx, repo_id, path, rev, options);
+        }
+        Effect::SearchCommitFiles {
+            repo_id,
+            commit_id,
+            paths,
+            query,
+            options,
+        } => {
+            repo_load::schedule_search_commit_files(
+                executor, repos, msg_tx, repo_id, commit_id, paths, query, options,
+            );
         }
         Effect::LoadWorktrees { repo_id } => {
             repo_load::schedule_load_worktrees(executor, repos, msg_tx, repo_id);
@@ -1211,6 +1545,9 @@ pub(super) fn schedule_effect(
         Effect::LoadCommitDetails { repo_id, commit_id } => {
             repo_load::schedule_load_commit_details(executor, repos, msg_tx, repo_id, commit_id);
         }
+        Effect::LoadRewordBlocker { repo_id, commit_id } => {
+            repo_load::schedule_load_reword_blocker(executor, repos, msg_tx, repo_id, commit_id);
+        }
         Effect::LoadDiff { repo_id, target } => {
             repo_load::schedule_load_diff(executor, repos, msg_tx, repo_id, target);
         }
@@ -1364,9 +1701,86 @@ pub(super) fn schedule_effect(
         } => {
             repo_commands::schedule_export_patch(executor, repos, msg_tx, repo_id, commit_id, dest)
         }
+        Effect::ExportPatchSeries {
+            repo_id,
+            commit_ids,
+            dest,
+            cover_letter,
+        } => repo_commands::schedule_export_patch_series(
+            executor,
+            repos,
+            msg_tx,
+            repo_id,
+            commit_ids,
+            dest,
+            cover_letter,
+        ),
+        Effect::CherryPickCommits {
+            repo_id,
+            commit_ids,
+        } => repo_commands::schedule_cherry_pick_commits(
+            executor, repos, msg_tx, repo_id, commit_ids,
+        ),
+        Effect::RevertCommits {
+            repo_id,
+            commit_ids,
+        } => repo_commands::schedule_revert_commits(executor, repos, msg_tx, repo_id, commit_ids),
+        Effect::SquashCommits {
+            repo_id,
+            commit_ids,
+        } => repo_commands::schedule_squash_commits(executor, repos, msg_tx, repo_id, commit_ids),
+        Effect::RewordCommit {
+            repo_id,
+            commit_id,
+            message,
+        } => repo_commands::schedule_reword_commit(
+            executor, repos, msg_tx, repo_id, commit_id, message,
+        ),
+        Effect::CreateFixupCommit {
+            repo_id,
+            commit_id,
+            kind,
+        } => repo_commands::schedule_create_fixup_commit(
+            executor, repos, msg_tx, repo_id, commit_id, kind,
+        ),
         Effect::ApplyPatch { repo_id, patch } => {
             repo_commands::schedule_apply_patch(executor, repos, msg_tx, repo_id, patch);
         }
+        Effect::LoadPatchSeriesPreview { repo_id, source } => {
+            repo_load::schedule_load_patch_series_preview(executor, repos, msg_tx, repo_id, source);
+        }
+        Effect::LoadRevisionTree { repo_id, rev, dir } => {
+            repo_load::schedule_load_revision_tree(executor, repos, msg_tx, repo_id, rev, dir);
+        }
+        Effect::LoadRevisionFile {
+            repo_id,
+            rev,
+            path,
+            against_worktree,
+        } => repo_load::schedule_load_revision_file(
+            executor,
+            repos,
+            msg_tx,
+            repo_id,
+            rev,
+            path,
+            against_worktree,
+        ),
+        Effect::SaveRevisionPath {
+            repo_id,
+            rev,
+            path,
+            dest,
+        } => repo_commands::schedule_save_revision_path(
+            executor, repos, msg_tx, repo_id, rev, path, dest,
+        ),
+        Effect::ApplyPatchSeries {
+            repo_id,
+            source,
+            three_way,
+        } => repo_commands::schedule_apply_patch_series(
+            executor, repos, msg_tx, repo_id, source, three_way,
+        ),
         Effect::AddWorktree {
             repo_id,
             path,
@@ -1380,6 +1794,26 @@ pub(super) fn schedule_effect(
         Effect::ForceRemoveWorktree { repo_id, path } => {
             repo_commands::schedule_force_remove_worktree(executor, repos, msg_tx, repo_id, path);
         }
+        Effect::LockWorktree {
+            repo_id,
+            path,
+            reason,
+        } => {
+            repo_commands::schedule_lock_worktree(executor, repos, msg_tx, repo_id, path, reason);
+        }
+        Effect::UnlockWorktree { repo_id, path } => {
+            repo_commands::schedule_unlock_worktree(executor, repos, msg_tx, repo_id, path);
+        }
+        Effect::MoveWorktree {
+            repo_id,
+            path,
+            new_path,
+        } => {
+            repo_commands::schedule_move_worktree(executor, repos, msg_tx, repo_id, path, new_path);
+        }
+        Effect::PruneWorktrees { repo_id } => {
+            repo_commands::schedule_prune_worktrees(executor, repos, msg_tx, repo_id);
+        }
         Effect::CheckSubmoduleAddTrust {
             repo_id,
             url,
@@ -1468,6 +1902,36 @@ pub(super) fn schedule_effect(
         Effect::RemoveSubmodule { repo_id, path } => {
             repo_commands::schedule_remove_submodule(executor, repos, msg_tx, repo_id, path);
         }
+        Effect::SyncSubmodules { repo_id } => {
+            repo_commands::schedule_sync_submodules(executor, repos, msg_tx, repo_id);
+        }
+        Effect::SetSubmoduleBranch {
+            repo_id,
+            path,
+            branch,
+        } => {
+            repo_commands::schedule_set_submodule_branch(
+                executor, repos, msg_tx, repo_id, path, branch,
+            );
+        }
+        Effect::UpdateSubmoduleRemote {
+            repo_id,
+            path,
+            auth,
+        } => {
+            repo_commands::schedule_update_submodule_remote(
+                executor, repos, msg_tx, repo_id, path, auth,
+            );
+        }
+        Effect::RunInSubmodules {
+            repo_id,
+            command,
+            auth,
+        } => {
+            repo_commands::schedule_run_in_submodules(
+                executor, repos, msg_tx, repo_id, command, auth,
+            );
+        }
         Effect::StageHunk { repo_id, patch } => {
             repo_commands::schedule_stage_hunk(executor, repos, msg_tx, repo_id, patch);
         }
@@ -1609,6 +2073,16 @@ pub(super) fn schedule_effect(
         } => repo_commands::schedule_delete_remote_branch(
             executor, repos, msg_tx, repo_id, remote, branch, auth,
         ),
+        Effect::RenameBranch {
+            repo_id,
+            name,
+            new_name,
+            force,
+            upstream,
+            auth,
+        } => repo_commands::schedule_rename_branch(
+            executor, repos, msg_tx, repo_id, name, new_name, force, upstream, auth,
+        ),
         Effect::Reset {
             repo_id,
             target,
@@ -1617,12 +2091,22 @@ pub(super) fn schedule_effect(
         Effect::Rebase { repo_id, onto } => {
             repo_commands::schedule_rebase(executor, repos, msg_tx, repo_id, onto)
         }
+        Effect::AutosquashRebase { repo_id } => {
+            repo_commands::schedule_autosquash_rebase(executor, repos, msg_tx, repo_id)
+        }
         Effect::RebaseContinue { repo_id } => {
             repo_commands::schedule_rebase_continue(executor, repos, msg_tx, repo_id);
         }
         Effect::RebaseAbort { repo_id } => {
             repo_commands::schedule_rebase_abort(executor, repos, msg_tx, repo_id)
         }
+        Effect::OperationAction {
+            repo_id,
+            kind,
+            action,
+        } => {
+            repo_commands::schedule_operation_action(executor, repos, msg_tx, repo_id, kind, action)
+        }
         Effect::MergeAbort { repo_id } => {
             repo_commands::schedule_merge_abort(executor, repos, msg_tx, repo_id)
         }
@@ -1678,6 +2162,21 @@ pub(super) fn schedule_effect(
         Effect::LaunchMergetool { repo_id, path } => {
             repo_commands::schedule_launch_mergetool(executor, repos, msg_tx, repo_id, path);
         }
+        Effect::LoadConflictSummaries { repo_id } => {
+            repo_load::schedule_load_conflict_summaries(executor, repos, msg_tx, repo_id);
+        }
+        Effect::CheckoutConflictSidePaths {
+            repo_id,
+            paths,
+            side,
+        } => repo_commands::schedule_checkout_conflict_side_paths(
+            executor, repos, msg_tx, repo_id, paths, side,
+        ),
+        Effect::AutosolveConflictPaths { repo_id, paths } => {
+            repo_commands::schedule_autosolve_conflict_paths(
+                executor, repos, msg_tx, repo_id, paths,
+            )
+        }
         Effect::Stash {
             repo_id,
             message,
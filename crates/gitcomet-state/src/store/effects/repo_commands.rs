@@ -1,11 +1,14 @@
-use crate::msg::{Msg, RepoCommandKind};
+use crate::msg::{Msg, RepoCommandKind, RepoPathList};
 use gitcomet_core::auth::{
     StagedGitAuth, clear_staged_git_auth, stage_git_auth_for_current_thread,
 };
+use gitcomet_core::conflict_session::try_autosolve_merged_text;
+use gitcomet_core::domain::Upstream;
 use gitcomet_core::error::{Error, ErrorKind};
 use gitcomet_core::services::{
     CommandOutput, ConflictSide, ForcePushLease, GitRepository, PullMode, RemoteUrlKind, ResetMode,
-    SafePushAfterCommitContext, SafePushAfterCommitTarget, SubmoduleTrustTarget,
+    SafePushAfterCommitContext, SafePushAfterCommitTarget, SubmoduleBatchCommand,
+    SubmoduleTrustTarget,
 };
 use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
@@ -157,6 +160,104 @@ pub(super) fn schedule_export_patch(
     );
 }
 
+pub(super) fn schedule_export_patch_series(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+    dest: PathBuf,
+    cover_letter: bool,
+) {
+    let command = RepoCommandKind::ExportPatchSeries {
+        commit_ids: commit_ids.clone(),
+        dest: dest.clone(),
+        cover_letter,
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.export_patch_series_with_output(&commit_ids, &dest, cover_letter)
+    });
+}
+
+pub(super) fn schedule_cherry_pick_commits(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) {
+    let command = RepoCommandKind::CherryPickCommits {
+        commit_ids: commit_ids.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.cherry_pick_commits_with_output(&commit_ids)
+    });
+}
+
+pub(super) fn schedule_revert_commits(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) {
+    let command = RepoCommandKind::RevertCommits {
+        commit_ids: commit_ids.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.revert_commits_with_output(&commit_ids)
+    });
+}
+
+pub(super) fn schedule_squash_commits(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) {
+    let command = RepoCommandKind::SquashCommits {
+        commit_ids: commit_ids.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.squash_commits_with_output(&commit_ids)
+    });
+}
+
+pub(super) fn schedule_reword_commit(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_id: gitcomet_core::domain::CommitId,
+    message: String,
+) {
+    let command = RepoCommandKind::RewordCommit {
+        commit_id: commit_id.clone(),
+        message: message.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.reword_commit_with_output(&commit_id, &message)
+    });
+}
+
+pub(super) fn schedule_create_fixup_commit(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_id: gitcomet_core::domain::CommitId,
+    kind: gitcomet_core::services::FixupCommitKind,
+) {
+    let command = RepoCommandKind::CreateFixupCommit {
+        commit_id: commit_id.clone(),
+        kind,
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.commit_fixup_with_output(&commit_id, kind)
+    });
+}
+
 pub(super) fn schedule_apply_patch(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -177,6 +278,42 @@ pub(super) fn schedule_apply_patch(
     );
 }
 
+pub(super) fn schedule_apply_patch_series(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    source: PathBuf,
+    three_way: bool,
+) {
+    let command = RepoCommandKind::ApplyPatchSeries {
+        source: source.clone(),
+        three_way,
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.apply_patch_series_with_output(&source, three_way)
+    });
+}
+
+pub(super) fn schedule_save_revision_path(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    rev: String,
+    path: PathBuf,
+    dest: PathBuf,
+) {
+    let command = RepoCommandKind::SaveRevisionPath {
+        rev: rev.clone(),
+        path: path.clone(),
+        dest: dest.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        repo.save_revision_path_with_output(&rev, &path, &dest)
+    });
+}
+
 pub(super) fn schedule_add_worktree(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -236,6 +373,86 @@ pub(super) fn schedule_force_remove_worktree(
     );
 }
 
+pub(super) fn schedule_lock_worktree(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+    reason: Option<String>,
+) {
+    let command_path = path.clone();
+    let command_reason = reason.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::LockWorktree {
+            path: command_path,
+            reason: command_reason,
+        },
+        move |repo| repo.lock_worktree_with_output(&path, reason.as_deref()),
+    );
+}
+
+pub(super) fn schedule_unlock_worktree(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+) {
+    let command_path = path.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::UnlockWorktree { path: command_path },
+        move |repo| repo.unlock_worktree_with_output(&path),
+    );
+}
+
+pub(super) fn schedule_move_worktree(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+    new_path: PathBuf,
+) {
+    let command_path = path.clone();
+    let command_new_path = new_path.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::MoveWorktree {
+            path: command_path,
+            new_path: command_new_path,
+        },
+        move |repo| repo.move_worktree_with_output(&path, &new_path),
+    );
+}
+
+pub(super) fn schedule_prune_worktrees(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+) {
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::PruneWorktrees,
+        move |repo| repo.prune_worktrees_with_output(),
+    );
+}
+
 pub(super) fn schedule_add_submodule(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -442,6 +659,85 @@ pub(super) fn schedule_remove_submodule(
     );
 }
 
+pub(super) fn schedule_sync_submodules(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+) {
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::SyncSubmodules,
+        move |repo| repo.sync_submodules_with_output(),
+    );
+}
+
+pub(super) fn schedule_set_submodule_branch(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+    branch: Option<String>,
+) {
+    let command_path = path.clone();
+    let command_branch = branch.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::SetSubmoduleBranch {
+            path: command_path,
+            branch: command_branch,
+        },
+        move |repo| repo.set_submodule_branch_with_output(&path, branch.as_deref()),
+    );
+}
+
+pub(super) fn schedule_update_submodule_remote(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+    auth: Option<StagedGitAuth>,
+) {
+    let command_path = path.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::UpdateSubmoduleRemote { path: command_path },
+        move |repo| run_with_git_auth(auth, || repo.update_submodule_remote_with_output(&path)),
+    );
+}
+
+pub(super) fn schedule_run_in_submodules(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    command: SubmoduleBatchCommand,
+    auth: Option<StagedGitAuth>,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let result = run_with_git_auth(auth, || repo.run_in_submodules(&command));
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::SubmoduleBatchFinished {
+                repo_id,
+                command,
+                result,
+            }),
+        );
+    });
+}
+
 pub(super) fn schedule_stage_hunk(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -835,6 +1131,38 @@ pub(super) fn schedule_delete_remote_branch(
     );
 }
 
+pub(super) fn schedule_rename_branch(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    name: String,
+    new_name: String,
+    force: bool,
+    upstream: Option<Upstream>,
+    auth: Option<StagedGitAuth>,
+) {
+    let command = RepoCommandKind::RenameBranch {
+        name: name.clone(),
+        new_name: new_name.clone(),
+        force,
+        upstream: upstream.clone(),
+    };
+    schedule_repo_command(executor, repos, msg_tx, repo_id, command, move |repo| {
+        let renamed = repo.rename_branch_with_output(&name, &new_name, force)?;
+        let Some(upstream) = upstream else {
+            return Ok(renamed);
+        };
+        let moved = run_with_git_auth(auth, || {
+            repo.rename_upstream_branch_with_output(&new_name, &upstream, &new_name)
+        })?;
+        Ok(join_command_outputs(
+            format!("{} && {}", renamed.command, moved.command),
+            vec![renamed, moved],
+        ))
+    });
+}
+
 pub(super) fn schedule_reset(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -875,6 +1203,22 @@ pub(super) fn schedule_rebase(
     );
 }
 
+pub(super) fn schedule_autosquash_rebase(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+) {
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::AutosquashRebase,
+        |repo| repo.rebase_autosquash_with_output(),
+    );
+}
+
 pub(super) fn schedule_rebase_continue(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -907,6 +1251,24 @@ pub(super) fn schedule_rebase_abort(
     );
 }
 
+pub(super) fn schedule_operation_action(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    kind: gitcomet_core::domain::RepoOperationKind,
+    action: gitcomet_core::domain::RepoOperationAction,
+) {
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::OperationAction { kind, action },
+        move |repo| repo.operation_command_with_output(kind, action),
+    );
+}
+
 pub(super) fn schedule_merge_abort(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -1100,6 +1462,124 @@ pub(super) fn schedule_checkout_conflict_side(
     );
 }
 
+pub(super) fn schedule_checkout_conflict_side_paths(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    paths: RepoPathList,
+    side: ConflictSide,
+) {
+    let paths = paths.as_slice().to_vec();
+    let command_paths = paths.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::CheckoutConflictPaths {
+            paths: command_paths,
+            side,
+        },
+        move |repo| {
+            let mut outputs = Vec::with_capacity(paths.len());
+            for path in &paths {
+                outputs.push(repo.checkout_conflict_side(path, side)?);
+            }
+            let flag = match side {
+                ConflictSide::Ours => "--ours",
+                ConflictSide::Theirs => "--theirs",
+            };
+            Ok(join_command_outputs(
+                format!("git checkout {flag} ({} files)", paths.len()),
+                outputs,
+            ))
+        },
+    );
+}
+
+pub(super) fn schedule_autosolve_conflict_paths(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    paths: RepoPathList,
+) {
+    let paths = paths.as_slice().to_vec();
+    let command_paths = paths.clone();
+    schedule_repo_command(
+        executor,
+        repos,
+        msg_tx,
+        repo_id,
+        RepoCommandKind::AutosolveConflicts {
+            paths: command_paths,
+        },
+        move |repo| {
+            let mut resolved = Vec::new();
+            let mut remaining = Vec::new();
+            for path in &paths {
+                let relative_path = normalize_worktree_relative_path(path)?;
+                let full = repo.spec().workdir.join(&relative_path);
+                // Deleted and binary sides always need an explicit decision, and
+                // files without markers were already edited by hand.
+                let text = match std::fs::read(&full) {
+                    Ok(bytes) => String::from_utf8(bytes).ok(),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                    Err(e) => return Err(Error::new(ErrorKind::Io(e.kind()))),
+                };
+                let clean = text
+                    .filter(|text| text.contains("<<<<<<<"))
+                    .and_then(|text| try_autosolve_merged_text(&text));
+                let Some(clean) = clean else {
+                    remaining.push(relative_path);
+                    continue;
+                };
+                std::fs::write(&full, clean.as_bytes())
+                    .map_err(|e| Error::new(ErrorKind::Io(e.kind())))?;
+                let path_ref: &Path = &relative_path;
+                repo.stage(&[path_ref])?;
+                resolved.push(relative_path);
+            }
+
+            let mut stdout = String::new();
+            for path in &resolved {
+                stdout.push_str(&format!("Resolved {}\n", path.display()));
+            }
+            for path in &remaining {
+                stdout.push_str(&format!("Needs manual resolution: {}\n", path.display()));
+            }
+            Ok(CommandOutput {
+                command: format!("Autosolve conflicts ({} files)", paths.len()),
+                stdout,
+                stderr: String::new(),
+                exit_code: Some(0),
+            })
+        },
+    );
+}
+
+fn join_command_outputs(command: String, outputs: Vec<CommandOutput>) -> CommandOutput {
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let mut exit_code = Some(0);
+    for output in outputs {
+        if !output.stdout.trim().is_empty() {
+            stdout.push(output.stdout);
+        }
+        if !output.stderr.trim().is_empty() {
+            stderr.push(output.stderr);
+        }
+        exit_code = output.exit_code.or(exit_code);
+    }
+    CommandOutput {
+        command,
+        stdout: stdout.join("\n"),
+        stderr: stderr.join("\n"),
+        exit_code,
+    }
+}
+
 pub(super) fn schedule_checkout_conflict_base(
     executor: &TaskExecutor,
     repos: &RepoMap,
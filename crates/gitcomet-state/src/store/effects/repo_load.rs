@@ -1,18 +1,23 @@
 use crate::model::{AppState, ConflictFileLoadMode};
 use crate::msg::Msg;
 use gitcomet_core::conflict_session::{ConflictPayload, ConflictSession, ConflictStageParts};
-use gitcomet_core::domain::{DiffArea, DiffPreviewTextSide, DiffTarget, LogCursor, LogScope};
+use gitcomet_core::domain::{
+    CommitId, DiffArea, DiffPreviewTextSide, DiffTarget, LogCursor, LogScope,
+};
 use gitcomet_core::error::{Error, ErrorKind};
 use gitcomet_core::mergetool_trace::{
     self, MergetoolTraceEvent, MergetoolTraceSideStats, MergetoolTraceStage,
 };
-use gitcomet_core::services::{ConflictFileStages, GitBackend, GitRepository};
-use std::path::PathBuf;
+use gitcomet_core::services::{
+    BlameOptions, ConflictFileStages, GitBackend, GitRepository, TextSearchOptions,
+};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
 use super::super::{RepoId, executor::TaskExecutor, worker_channel::StoreWorkerSender};
 use super::util::{RepoMap, send_or_log, spawn_with_repo, spawn_with_repo_or_else};
+use super::worktree_summaries;
 
 pub(super) struct SelectedDiffLoadOptions {
     pub(super) load_patch_diff: bool,
@@ -84,6 +89,7 @@ mod selected_diff_guard_tests {
         DiffTarget::WorkingTree {
             path: PathBuf::from(path),
             area: DiffArea::Unstaged,
+            old_path: None,
         }
     }
 
@@ -643,6 +649,7 @@ pub(super) fn schedule_load_conflict_file(
                     .diff_file_text(&DiffTarget::WorkingTree {
                         path: path.clone(),
                         area: DiffArea::Unstaged,
+                        old_path: None,
                     })
                     .map(|opt| {
                         opt.map(|d| {
@@ -811,6 +818,33 @@ pub(super) fn schedule_load_file_history(
     });
 }
 
+pub(super) fn schedule_load_line_history(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    path: PathBuf,
+    start: u32,
+    end: u32,
+    rev: Option<String>,
+    limit: usize,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let result = repo.log_line_range_page(&path, start, end, rev.as_deref(), limit, None);
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::LineHistoryLoaded {
+                repo_id,
+                path,
+                start,
+                end,
+                rev,
+                result,
+            }),
+        );
+    });
+}
+
 pub(super) fn schedule_load_blame(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -818,22 +852,136 @@ pub(super) fn schedule_load_blame(
     repo_id: RepoId,
     path: PathBuf,
     rev: Option<String>,
+    options: BlameOptions,
 ) {
     spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
-        let result = repo.blame_file(&path, rev.as_deref());
+        let result = repo.blame_file_with_options(&path, rev.as_deref(), options);
         send_or_log(
             &msg_tx,
             Msg::Internal(crate::msg::InternalMsg::BlameLoaded {
                 repo_id,
                 path: path.clone(),
                 rev: rev.clone(),
+                options,
                 result,
             }),
         );
     });
 }
 
-pub(super) fn schedule_load_worktrees(
+pub(super) fn schedule_search_commit_files(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_id: CommitId,
+    paths: Vec<PathBuf>,
+    query: String,
+    options: TextSearchOptions,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let path_refs = paths.iter().map(PathBuf::as_path).collect::<Vec<_>>();
+        let result = repo.search_commit_files(&commit_id, &path_refs, &query, options);
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::CommitFilesSearched {
+                repo_id,
+                commit_id,
+                query,
+                options,
+                result,
+            }),
+        );
+    });
+}
+
+pub(super) fn schedule_load_patch_series_preview(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    source: PathBuf,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let result = repo.read_patch_series(&source);
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::PatchSeriesPreviewLoaded {
+                repo_id,
+                source,
+                result,
+            }),
+        );
+    });
+}
+
+pub(super) fn schedule_load_revision_tree(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    rev: String,
+    dir: PathBuf,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let result = repo.list_revision_tree(&rev, &dir);
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::RevisionTreeLoaded {
+                repo_id,
+                rev,
+                dir,
+                result,
+            }),
+        );
+    });
+}
+
+pub(super) fn schedule_load_revision_file(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    rev: String,
+    path: PathBuf,
+    against_worktree: bool,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        let result = if against_worktree {
+            repo.diff_revision_to_worktree_unified(&rev, &path)
+        } else {
+            repo.read_revision_file(&rev, &path)
+                .and_then(|bytes| revision_file_text(&path, bytes))
+        };
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::RevisionFileLoaded {
+                repo_id,
+                rev,
+                path,
+                against_worktree,
+                result,
+            }),
+        );
+    });
+}
+
+/// Binary blobs are refused rather than shown as mojibake; the sniff window
+/// matches git's own binary detection.
+fn revision_file_text(path: &Path, bytes: Vec<u8>) -> Result<String, Error> {
+    const BINARY_SNIFF_BYTES: usize = 8000;
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return Err(Error::new(ErrorKind::Backend(format!(
+            "{} is a binary file ({} bytes)",
+            path.display(),
+            bytes.len()
+        ))));
+    }
+    Ok(String::from_utf8(bytes)
+        .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned()))
+}
+
+pub(super) fn schedule_load_conflict_summaries(
     executor: &TaskExecutor,
     repos: &RepoMap,
     msg_tx: StoreWorkerSender,
@@ -847,12 +995,57 @@ pub(super) fn schedule_load_worktrees(
         move |repo, msg_tx| {
             send_or_log(
                 &msg_tx,
-                Msg::Internal(crate::msg::InternalMsg::WorktreesLoaded {
+                Msg::Internal(crate::msg::InternalMsg::ConflictSummariesLoaded {
                     repo_id,
-                    result: repo.list_worktrees(),
+                    result: repo.conflict_summaries(),
                 }),
             );
         },
+        move |msg_tx| {
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::ConflictSummariesLoaded {
+                    repo_id,
+                    result: Err(missing_repo_error(repo_id)),
+                }),
+            );
+        },
+    );
+}
+
+pub(super) fn schedule_load_worktrees(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+) {
+    spawn_with_repo_or_else(
+        executor,
+        repos,
+        repo_id,
+        msg_tx,
+        move |repo, msg_tx| {
+            let result = repo.list_worktrees();
+            let listed = result.as_ref().ok().cloned();
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::WorktreesLoaded { repo_id, result }),
+            );
+
+            // Status of every worktree is slower than the listing, so the
+            // sidebar shows the worktrees first and fills in the overview after.
+            if let Some(worktrees) = listed {
+                let summaries =
+                    worktree_summaries::load_worktree_summaries(repo.as_ref(), repo_id, &worktrees);
+                send_or_log(
+                    &msg_tx,
+                    Msg::Internal(crate::msg::InternalMsg::WorktreeSummariesLoaded {
+                        repo_id,
+                        summaries,
+                    }),
+                );
+            }
+        },
         move |msg_tx| {
             send_or_log(
                 &msg_tx,
@@ -916,6 +1109,13 @@ pub(super) fn schedule_load_rebase_state(
                     result: repo.rebase_in_progress(),
                 }),
             );
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: repo.operation_state(),
+                }),
+            );
         },
         move |msg_tx| {
             send_or_log(
@@ -925,6 +1125,13 @@ pub(super) fn schedule_load_rebase_state(
                     result: Err(missing_repo_error(repo_id)),
                 }),
             );
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: Err(missing_repo_error(repo_id)),
+                }),
+            );
         },
     );
 }
@@ -948,6 +1155,13 @@ pub(super) fn schedule_load_rebase_and_merge_state(
                     result: repo.rebase_in_progress(),
                 }),
             );
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: repo.operation_state(),
+                }),
+            );
             send_or_log(
                 &msg_tx,
                 Msg::Internal(crate::msg::InternalMsg::MergeCommitMessageLoaded {
@@ -964,6 +1178,13 @@ pub(super) fn schedule_load_rebase_and_merge_state(
                     result: Err(missing_repo_error(repo_id)),
                 }),
             );
+            send_or_log(
+                &msg_tx,
+                Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded {
+                    repo_id,
+                    result: Err(missing_repo_error(repo_id)),
+                }),
+            );
             send_or_log(
                 &msg_tx,
                 Msg::Internal(crate::msg::InternalMsg::MergeCommitMessageLoaded {
@@ -1026,6 +1247,25 @@ pub(super) fn schedule_load_commit_details(
     });
 }
 
+pub(super) fn schedule_load_reword_blocker(
+    executor: &TaskExecutor,
+    repos: &RepoMap,
+    msg_tx: StoreWorkerSender,
+    repo_id: RepoId,
+    commit_id: gitcomet_core::domain::CommitId,
+) {
+    spawn_with_repo(executor, repos, repo_id, msg_tx, move |repo, msg_tx| {
+        send_or_log(
+            &msg_tx,
+            Msg::Internal(crate::msg::InternalMsg::RewordBlockerLoaded {
+                repo_id,
+                commit_id: commit_id.clone(),
+                result: repo.reword_commit_blocker(&commit_id),
+            }),
+        );
+    });
+}
+
 pub(super) fn schedule_load_recent_commit_messages(
     executor: &TaskExecutor,
     repos: &RepoMap,
@@ -0,0 +1,222 @@
+//! The per-worktree status overview shown in the sidebar.
+//!
+//! `git status` in every worktree is the slow part of a worktree reload, so
+//! the statuses run in parallel and a summary is reused across reloads while
+//! its worktree keeps the same HEAD and branch and the summary is recent.
+
+use gitcomet_core::domain::{CommitId, Worktree, WorktreeSummary};
+use gitcomet_core::services::GitRepository;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use super::super::RepoId;
+use super::super::executor::default_worker_threads;
+
+/// How long a cached summary stands in for `git status`. Edits in another
+/// worktree change neither its HEAD nor its branch, so summaries still expire.
+const MAX_SUMMARY_AGE: Duration = Duration::from_secs(30);
+
+struct CachedSummary {
+    head: Option<CommitId>,
+    branch: Option<String>,
+    loaded_at: Instant,
+    summary: WorktreeSummary,
+}
+
+#[derive(Default)]
+struct WorktreeSummaryCache {
+    entries: HashMap<PathBuf, CachedSummary>,
+}
+
+impl WorktreeSummaryCache {
+    fn get(&self, worktree: &Worktree, now: Instant) -> Option<&WorktreeSummary> {
+        let cached = self.entries.get(&worktree.path)?;
+        (cached.head == worktree.head
+            && cached.branch == worktree.branch
+            && now.saturating_duration_since(cached.loaded_at) < MAX_SUMMARY_AGE)
+            .then_some(&cached.summary)
+    }
+
+    fn insert(&mut self, worktree: &Worktree, summary: WorktreeSummary, now: Instant) {
+        self.entries.insert(
+            worktree.path.clone(),
+            CachedSummary {
+                head: worktree.head.clone(),
+                branch: worktree.branch.clone(),
+                loaded_at: now,
+                summary,
+            },
+        );
+    }
+
+    /// Drop the summaries of worktrees that are no longer listed.
+    fn retain_listed(&mut self, worktrees: &[&Worktree]) {
+        self.entries
+            .retain(|path, _| worktrees.iter().any(|worktree| &worktree.path == path));
+    }
+}
+
+fn summary_caches() -> &'static Mutex<HashMap<RepoId, WorktreeSummaryCache>> {
+    static CACHES: OnceLock<Mutex<HashMap<RepoId, WorktreeSummaryCache>>> = OnceLock::new();
+    CACHES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Run `summarize` for every path on up to `threads` threads, keeping the
+/// results in path order.
+fn summarize_in_parallel(
+    paths: &[&Path],
+    threads: usize,
+    summarize: impl Fn(&Path) -> Option<WorktreeSummary> + Sync,
+) -> Vec<Option<WorktreeSummary>> {
+    let threads = threads.min(paths.len());
+    if threads <= 1 {
+        return paths.iter().map(|path| summarize(path)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<WorktreeSummary>> = vec![None; paths.len()];
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let ix = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = paths.get(ix) else {
+                            break;
+                        };
+                        done.push((ix, summarize(path)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        for worker in workers {
+            for (ix, summary) in worker.join().unwrap_or_default() {
+                results[ix] = summary;
+            }
+        }
+    });
+    results
+}
+
+/// Summaries of the listed, non-prunable worktrees. The repository's own
+/// worktree is always re-read since its status is the one that changes most.
+pub(super) fn load_worktree_summaries(
+    repo: &dyn GitRepository,
+    repo_id: RepoId,
+    worktrees: &[Worktree],
+) -> Vec<WorktreeSummary> {
+    let now = Instant::now();
+    let workdir = &repo.spec().workdir;
+    let canonical_workdir = std::fs::canonicalize(workdir).ok();
+    let is_own_worktree =
+        |path: &Path| path == workdir || canonical_workdir.as_deref() == Some(path);
+    let listed: Vec<&Worktree> = worktrees
+        .iter()
+        .filter(|worktree| !worktree.is_prunable())
+        .collect();
+
+    let mut summaries: Vec<Option<WorktreeSummary>> = {
+        let mut caches = summary_caches().lock().unwrap_or_else(|e| e.into_inner());
+        let cache = caches.entry(repo_id).or_default();
+        cache.retain_listed(&listed);
+        listed
+            .iter()
+            .map(|worktree| {
+                if is_own_worktree(&worktree.path) {
+                    None
+                } else {
+                    cache.get(worktree, now).cloned()
+                }
+            })
+            .collect()
+    };
+
+    let stale: Vec<usize> = (0..listed.len())
+        .filter(|&ix| summaries[ix].is_none())
+        .collect();
+    let stale_paths: Vec<&Path> = stale.iter().map(|&ix| listed[ix].path.as_path()).collect();
+    let fresh = summarize_in_parallel(&stale_paths, default_worker_threads(), |path| {
+        repo.worktree_summary(path).ok()
+    });
+
+    {
+        let mut caches = summary_caches().lock().unwrap_or_else(|e| e.into_inner());
+        let cache = caches.entry(repo_id).or_default();
+        for (ix, summary) in stale.into_iter().zip(fresh) {
+            if let Some(summary) = &summary {
+                cache.insert(listed[ix], summary.clone(), now);
+            }
+            summaries[ix] = summary;
+        }
+    }
+
+    summaries.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worktree(path: &str, head: &str) -> Worktree {
+        Worktree {
+            path: PathBuf::from(path),
+            head: Some(CommitId(head.into())),
+            branch: Some("refs/heads/main".to_string()),
+            detached: false,
+            locked: None,
+            prunable: None,
+        }
+    }
+
+    fn summary(path: &str, dirty_files: usize) -> WorktreeSummary {
+        WorktreeSummary {
+            path: PathBuf::from(path),
+            dirty_files,
+            upstream: None,
+        }
+    }
+
+    #[test]
+    fn cached_summary_is_reused_until_head_changes_or_it_expires() {
+        let mut cache = WorktreeSummaryCache::default();
+        let now = Instant::now();
+        let feature = worktree("/tmp/feature", "aaa");
+        cache.insert(&feature, summary("/tmp/feature", 2), now);
+
+        assert_eq!(
+            cache.get(&feature, now + Duration::from_secs(1)),
+            Some(&summary("/tmp/feature", 2))
+        );
+        assert_eq!(cache.get(&worktree("/tmp/feature", "bbb"), now), None);
+        assert_eq!(cache.get(&feature, now + MAX_SUMMARY_AGE), None);
+
+        cache.retain_listed(&[&worktree("/tmp/other", "aaa")]);
+        assert_eq!(cache.get(&feature, now), None);
+    }
+
+    #[test]
+    fn summarize_in_parallel_keeps_path_order() {
+        let paths: Vec<PathBuf> = (0..9)
+            .map(|ix| PathBuf::from(format!("/wt/{ix}")))
+            .collect();
+        let paths: Vec<&Path> = paths.iter().map(PathBuf::as_path).collect();
+
+        let results = summarize_in_parallel(&paths, 4, |path| {
+            let ix: usize = path.file_name()?.to_str()?.parse().ok()?;
+            (ix != 3).then(|| summary(path.to_str().unwrap_or_default(), ix))
+        });
+
+        assert_eq!(results.len(), 9);
+        for (ix, result) in results.iter().enumerate() {
+            match result {
+                Some(summary) => assert_eq!(summary.dirty_files, ix),
+                None => assert_eq!(ix, 3),
+            }
+        }
+    }
+}
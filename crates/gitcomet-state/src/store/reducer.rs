@@ -100,7 +100,11 @@ pub(crate) fn msg_requires_available_git(msg: &Msg) -> bool {
             | Msg::LoadReflog { .. }
             | Msg::LoadRecentCommitMessages { .. }
             | Msg::LoadFileHistory { .. }
+            | Msg::LoadLineHistory { .. }
+            | Msg::LoadRewordBlocker { .. }
             | Msg::LoadBlame { .. }
+            | Msg::SearchCommitFiles { .. }
+            | Msg::LoadConflictSummaries { .. }
             | Msg::LoadWorktrees { .. }
             | Msg::LoadSubmodules { .. }
             | Msg::LoadSubmodule { .. }
@@ -121,14 +125,33 @@ pub(crate) fn msg_requires_available_git(msg: &Msg) -> bool {
             | Msg::ForceDeleteBranch { .. }
             | Msg::CloneRepo { .. }
             | Msg::ExportPatch { .. }
+            | Msg::ExportPatchSeries { .. }
+            | Msg::CherryPickCommits { .. }
+            | Msg::RevertCommits { .. }
+            | Msg::SquashCommits { .. }
+            | Msg::RewordCommit { .. }
+            | Msg::CreateFixupCommit { .. }
             | Msg::ApplyPatch { .. }
+            | Msg::LoadPatchSeriesPreview { .. }
+            | Msg::ApplyPatchSeries { .. }
+            | Msg::LoadRevisionTree { .. }
+            | Msg::LoadRevisionFile { .. }
+            | Msg::SaveRevisionPath { .. }
             | Msg::AddWorktree { .. }
             | Msg::RemoveWorktree { .. }
             | Msg::ForceRemoveWorktree { .. }
+            | Msg::LockWorktree { .. }
+            | Msg::UnlockWorktree { .. }
+            | Msg::MoveWorktree { .. }
+            | Msg::PruneWorktrees { .. }
             | Msg::AddSubmodule { .. }
             | Msg::UpdateSubmodules { .. }
             | Msg::ChangeSubmodulePointer { .. }
             | Msg::RemoveSubmodule { .. }
+            | Msg::SyncSubmodules { .. }
+            | Msg::SetSubmoduleBranch { .. }
+            | Msg::UpdateSubmoduleRemote { .. }
+            | Msg::RunInSubmodules { .. }
             | Msg::StagePath { .. }
             | Msg::StagePaths { .. }
             | Msg::UnstagePath { .. }
@@ -154,10 +177,13 @@ pub(crate) fn msg_requires_available_git(msg: &Msg) -> bool {
             | Msg::SetUpstreamBranch { .. }
             | Msg::UnsetUpstreamBranch { .. }
             | Msg::DeleteRemoteBranch { .. }
+            | Msg::RenameBranch { .. }
             | Msg::Reset { .. }
             | Msg::Rebase { .. }
+            | Msg::AutosquashRebase { .. }
             | Msg::RebaseContinue { .. }
             | Msg::RebaseAbort { .. }
+            | Msg::OperationAction { .. }
             | Msg::MergeAbort { .. }
             | Msg::CreateTag { .. }
             | Msg::DeleteTag { .. }
@@ -170,6 +196,8 @@ pub(crate) fn msg_requires_available_git(msg: &Msg) -> bool {
             | Msg::AcceptConflictDeletion { .. }
             | Msg::CheckoutConflictBase { .. }
             | Msg::LaunchMergetool { .. }
+            | Msg::CheckoutConflictSidePaths { .. }
+            | Msg::AutosolveConflictPaths { .. }
             | Msg::Stash { .. }
             | Msg::ApplyStash { .. }
             | Msg::PopStash { .. }
@@ -355,14 +383,32 @@ fn retry_msg_for_repo_command(repo_id: RepoId, command: RepoCommandKind) -> Opti
             remote,
             branch,
         },
+        RepoCommandKind::RenameBranch {
+            name,
+            new_name,
+            force,
+            upstream,
+        } => Msg::RenameBranch {
+            repo_id,
+            name,
+            new_name,
+            force,
+            upstream,
+        },
         RepoCommandKind::Reset { mode, target } => Msg::Reset {
             repo_id,
             target,
             mode,
         },
         RepoCommandKind::Rebase { onto } => Msg::Rebase { repo_id, onto },
+        RepoCommandKind::AutosquashRebase => Msg::AutosquashRebase { repo_id },
         RepoCommandKind::RebaseContinue => Msg::RebaseContinue { repo_id },
         RepoCommandKind::RebaseAbort => Msg::RebaseAbort { repo_id },
+        RepoCommandKind::OperationAction { kind, action } => Msg::OperationAction {
+            repo_id,
+            kind,
+            action,
+        },
         RepoCommandKind::MergeAbort => Msg::MergeAbort { repo_id },
         RepoCommandKind::CreateTag { name, target } => Msg::CreateTag {
             repo_id,
@@ -400,12 +446,64 @@ fn retry_msg_for_repo_command(repo_id: RepoId, command: RepoCommandKind) -> Opti
             Msg::CheckoutConflictBase { repo_id, path }
         }
         RepoCommandKind::LaunchMergetool { path } => Msg::LaunchMergetool { repo_id, path },
+        RepoCommandKind::CheckoutConflictPaths { paths, side } => Msg::CheckoutConflictSidePaths {
+            repo_id,
+            paths: paths.into(),
+            side,
+        },
+        RepoCommandKind::AutosolveConflicts { paths } => Msg::AutosolveConflictPaths {
+            repo_id,
+            paths: paths.into(),
+        },
         RepoCommandKind::ExportPatch { commit_id, dest } => Msg::ExportPatch {
             repo_id,
             commit_id,
             dest,
         },
+        RepoCommandKind::ExportPatchSeries {
+            commit_ids,
+            dest,
+            cover_letter,
+        } => Msg::ExportPatchSeries {
+            repo_id,
+            commit_ids,
+            dest,
+            cover_letter,
+        },
+        RepoCommandKind::CherryPickCommits { commit_ids } => Msg::CherryPickCommits {
+            repo_id,
+            commit_ids,
+        },
+        RepoCommandKind::RevertCommits { commit_ids } => Msg::RevertCommits {
+            repo_id,
+            commit_ids,
+        },
+        RepoCommandKind::SquashCommits { commit_ids } => Msg::SquashCommits {
+            repo_id,
+            commit_ids,
+        },
+        RepoCommandKind::RewordCommit { commit_id, message } => Msg::RewordCommit {
+            repo_id,
+            commit_id,
+            message,
+        },
+        RepoCommandKind::CreateFixupCommit { commit_id, kind } => Msg::CreateFixupCommit {
+            repo_id,
+            commit_id,
+            kind,
+        },
         RepoCommandKind::ApplyPatch { patch } => Msg::ApplyPatch { repo_id, patch },
+        RepoCommandKind::ApplyPatchSeries { source, three_way } => Msg::ApplyPatchSeries {
+            repo_id,
+            source,
+            three_way,
+        },
+        RepoCommandKind::SaveRevisionPath { rev, path, dest } => Msg::SaveRevisionPath {
+            repo_id,
+            rev,
+            path,
+            dest,
+        },
         RepoCommandKind::AddWorktree { path, reference } => Msg::AddWorktree {
             repo_id,
             path,
@@ -413,6 +511,18 @@ fn retry_msg_for_repo_command(repo_id: RepoId, command: RepoCommandKind) -> Opti
         },
         RepoCommandKind::RemoveWorktree { path } => Msg::RemoveWorktree { repo_id, path },
         RepoCommandKind::ForceRemoveWorktree { path } => Msg::ForceRemoveWorktree { repo_id, path },
+        RepoCommandKind::LockWorktree { path, reason } => Msg::LockWorktree {
+            repo_id,
+            path,
+            reason,
+        },
+        RepoCommandKind::UnlockWorktree { path } => Msg::UnlockWorktree { repo_id, path },
+        RepoCommandKind::MoveWorktree { path, new_path } => Msg::MoveWorktree {
+            repo_id,
+            path,
+            new_path,
+        },
+        RepoCommandKind::PruneWorktrees => Msg::PruneWorktrees { repo_id },
         RepoCommandKind::AddSubmodule {
             url,
             path,
@@ -449,6 +559,16 @@ fn retry_msg_for_repo_command(repo_id: RepoId, command: RepoCommandKind) -> Opti
             }
         }
         RepoCommandKind::RemoveSubmodule { path } => Msg::RemoveSubmodule { repo_id, path },
+        RepoCommandKind::SyncSubmodules => Msg::SyncSubmodules { repo_id },
+        RepoCommandKind::SetSubmoduleBranch { path, branch } => Msg::SetSubmoduleBranch {
+            repo_id,
+            path,
+            branch,
+        },
+        RepoCommandKind::UpdateSubmoduleRemote { path } => {
+            Msg::UpdateSubmoduleRemote { repo_id, path }
+        }
+        RepoCommandKind::RunInSubmodules { command } => Msg::RunInSubmodules { repo_id, command },
         // Not replayable because command metadata does not retain original content.
         RepoCommandKind::SaveWorktreeFile { .. }
         | RepoCommandKind::StageHunk
@@ -467,6 +587,8 @@ fn attach_git_auth_to_effects(mut effects: Vec<Effect>, auth: StagedGitAuth) ->
         | Effect::AddSubmodule { auth: slot, .. }
         | Effect::UpdateSubmodules { auth: slot, .. }
         | Effect::LoadSubmodule { auth: slot, .. }
+        | Effect::UpdateSubmoduleRemote { auth: slot, .. }
+        | Effect::RunInSubmodules { auth: slot, .. }
         | Effect::Commit { auth: slot, .. }
         | Effect::CommitAmend { auth: slot, .. }
         | Effect::SafePushAfterCommit { auth: slot, .. }
@@ -479,6 +601,7 @@ fn attach_git_auth_to_effects(mut effects: Vec<Effect>, auth: StagedGitAuth) ->
         | Effect::ForcePushWithLease { auth: slot, .. }
         | Effect::PushSetUpstream { auth: slot, .. }
         | Effect::DeleteRemoteBranch { auth: slot, .. }
+        | Effect::RenameBranch { auth: slot, .. }
         | Effect::PushTag { auth: slot, .. }
         | Effect::DeleteRemoteTag { auth: slot, .. } => {
             *slot = Some(auth);
@@ -675,6 +798,12 @@ pub(super) fn reduce(
             state.git_log_settings.tag_fetch_mode = tag_fetch_mode;
             Vec::new()
         }
+        Msg::SetRenameDetection { detection } => {
+            repo_management::set_rename_detection(repos, state, detection)
+        }
+        Msg::SetDiffWhitespace { whitespace } => {
+            repo_management::set_diff_whitespace(repos, state, whitespace)
+        }
         Msg::SetActiveRepo { repo_id } => repo_management::set_active_repo(state, repo_id),
         Msg::ReorderRepoTabs {
             repo_id,
@@ -705,6 +834,9 @@ pub(super) fn reduce(
                 state, repo_id, enabled,
             )
         }
+        Msg::SetDiffIgnoreOptions { repo_id, options } => {
+            repo_management::set_diff_ignore_options(repos, state, repo_id, options)
+        }
         Msg::LoadMoreHistory { repo_id } => external_and_history::load_more_history(state, repo_id),
         Msg::SelectCommit { repo_id, commit_id } => {
             effects::select_commit(state, repo_id, commit_id)
@@ -754,7 +886,23 @@ pub(super) fn reduce(
             path,
             limit,
         } => effects::load_file_history(state, repo_id, path, limit),
+        Msg::LoadLineHistory {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+        } => effects::load_line_history(state, repo_id, path, start, end, rev, 200),
         Msg::LoadBlame { repo_id, path, rev } => effects::load_blame(state, repo_id, path, rev),
+        Msg::SearchCommitFiles {
+            repo_id,
+            commit_id,
+            query,
+            options,
+        } => effects::search_commit_files(state, repo_id, commit_id, query, options),
+        Msg::SetBlameOptions { repo_id, options } => {
+            effects::set_blame_options(state, repo_id, options)
+        }
         Msg::LoadWorktrees { repo_id } => effects::load_worktrees(state, repo_id),
         Msg::LoadSubmodules { repo_id } => effects::load_submodules(state, repo_id),
         Msg::LoadTags { repo_id } => effects::load_tags(state, repo_id),
@@ -862,10 +1010,90 @@ pub(super) fn reduce(
             begin_local_action(state, repo_id);
             actions_emit_effects::export_patch(repo_id, commit_id, dest)
         }
+        Msg::ExportPatchSeries {
+            repo_id,
+            commit_ids,
+            dest,
+            cover_letter,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::export_patch_series(repo_id, commit_ids, dest, cover_letter)
+        }
+        Msg::CherryPickCommits {
+            repo_id,
+            commit_ids,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::cherry_pick_commits(repo_id, commit_ids)
+        }
+        Msg::RevertCommits {
+            repo_id,
+            commit_ids,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::revert_commits(repo_id, commit_ids)
+        }
+        Msg::SquashCommits {
+            repo_id,
+            commit_ids,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::squash_commits(repo_id, commit_ids)
+        }
+        Msg::LoadRewordBlocker { repo_id, commit_id } => {
+            effects::load_reword_blocker(state, repo_id, commit_id)
+        }
+        Msg::RewordCommit {
+            repo_id,
+            commit_id,
+            message,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::reword_commit(repo_id, commit_id, message)
+        }
+        Msg::CreateFixupCommit {
+            repo_id,
+            commit_id,
+            kind,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::create_fixup_commit(repo_id, commit_id, kind)
+        }
         Msg::ApplyPatch { repo_id, patch } => {
             begin_local_action(state, repo_id);
             actions_emit_effects::apply_patch(repo_id, patch)
         }
+        Msg::LoadPatchSeriesPreview { repo_id, source } => {
+            effects::load_patch_series_preview(state, repo_id, source)
+        }
+        Msg::ApplyPatchSeries {
+            repo_id,
+            source,
+            three_way,
+        } => {
+            if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+                repo_state.set_patch_series_preview(None);
+            }
+            begin_local_action(state, repo_id);
+            actions_emit_effects::apply_patch_series(repo_id, source, three_way)
+        }
+        Msg::LoadRevisionTree { repo_id, rev, dir } => {
+            effects::load_revision_tree(state, repo_id, rev, dir)
+        }
+        Msg::LoadRevisionFile {
+            repo_id,
+            path,
+            against_worktree,
+        } => effects::load_revision_file(state, repo_id, path, against_worktree),
+        Msg::SaveRevisionPath {
+            repo_id,
+            rev,
+            path,
+            dest,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::save_revision_path(repo_id, rev, path, dest)
+        }
         Msg::AddWorktree {
             repo_id,
             path,
@@ -898,6 +1126,56 @@ pub(super) fn reduce(
             };
             actions_emit_effects::force_remove_worktree(repo_id, normalized_path)
         }
+        Msg::LockWorktree {
+            repo_id,
+            path,
+            reason,
+        } => {
+            let normalized_path = if let Some(repo_state) =
+                state.repos.iter_mut().find(|r| r.id == repo_id)
+            {
+                repo_state.worktrees_in_flight = repo_state.worktrees_in_flight.saturating_add(1);
+                normalize_repo_relative_path(&repo_state.spec.workdir, path)
+            } else {
+                path
+            };
+            actions_emit_effects::lock_worktree(repo_id, normalized_path, reason)
+        }
+        Msg::UnlockWorktree { repo_id, path } => {
+            let normalized_path = if let Some(repo_state) =
+                state.repos.iter_mut().find(|r| r.id == repo_id)
+            {
+                repo_state.worktrees_in_flight = repo_state.worktrees_in_flight.saturating_add(1);
+                normalize_repo_relative_path(&repo_state.spec.workdir, path)
+            } else {
+                path
+            };
+            actions_emit_effects::unlock_worktree(repo_id, normalized_path)
+        }
+        Msg::MoveWorktree {
+            repo_id,
+            path,
+            new_path,
+        } => {
+            let (path, new_path) = if let Some(repo_state) =
+                state.repos.iter_mut().find(|r| r.id == repo_id)
+            {
+                repo_state.worktrees_in_flight = repo_state.worktrees_in_flight.saturating_add(1);
+                (
+                    normalize_repo_relative_path(&repo_state.spec.workdir, path),
+                    normalize_repo_relative_path(&repo_state.spec.workdir, new_path),
+                )
+            } else {
+                (path, new_path)
+            };
+            actions_emit_effects::move_worktree(repo_id, path, new_path)
+        }
+        Msg::PruneWorktrees { repo_id } => {
+            if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+                repo_state.worktrees_in_flight = repo_state.worktrees_in_flight.saturating_add(1);
+            }
+            actions_emit_effects::prune_worktrees(repo_id)
+        }
         Msg::AddSubmodule {
             repo_id,
             url,
@@ -1010,6 +1288,26 @@ pub(super) fn reduce(
             begin_local_action(state, repo_id);
             actions_emit_effects::remove_submodule(repo_id, path)
         }
+        Msg::SyncSubmodules { repo_id } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::sync_submodules(repo_id)
+        }
+        Msg::SetSubmoduleBranch {
+            repo_id,
+            path,
+            branch,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::set_submodule_branch(repo_id, path, branch)
+        }
+        Msg::UpdateSubmoduleRemote { repo_id, path } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::update_submodule_remote(repo_id, path)
+        }
+        Msg::RunInSubmodules { repo_id, command } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::run_in_submodules(repo_id, command)
+        }
         Msg::StagePath { repo_id, path } => {
             begin_local_action(state, repo_id);
             actions_emit_effects::stage_path(repo_id, path)
@@ -1129,6 +1427,20 @@ pub(super) fn reduce(
             remote,
             branch,
         } => actions_emit_effects::delete_remote_branch(repos, state, repo_id, remote, branch),
+        Msg::RenameBranch {
+            repo_id,
+            name,
+            new_name,
+            force,
+            upstream,
+        } => {
+            if upstream.is_none() {
+                begin_local_action(state, repo_id);
+            }
+            actions_emit_effects::rename_branch(
+                repos, state, repo_id, name, new_name, force, upstream,
+            )
+        }
         Msg::Reset {
             repo_id,
             target,
@@ -1141,6 +1453,10 @@ pub(super) fn reduce(
             begin_local_action(state, repo_id);
             actions_emit_effects::rebase(repo_id, onto)
         }
+        Msg::AutosquashRebase { repo_id } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::autosquash_rebase(repo_id)
+        }
         Msg::RebaseContinue { repo_id } => {
             begin_local_action(state, repo_id);
             actions_emit_effects::rebase_continue(repo_id)
@@ -1149,6 +1465,14 @@ pub(super) fn reduce(
             begin_local_action(state, repo_id);
             actions_emit_effects::rebase_abort(repo_id)
         }
+        Msg::OperationAction {
+            repo_id,
+            kind,
+            action,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::operation_action(repo_id, kind, action)
+        }
         Msg::MergeAbort { repo_id } => {
             begin_local_action(state, repo_id);
             actions_emit_effects::merge_abort(repo_id)
@@ -1212,6 +1536,19 @@ pub(super) fn reduce(
             begin_local_action(state, repo_id);
             actions_emit_effects::launch_mergetool(repo_id, path)
         }
+        Msg::LoadConflictSummaries { repo_id } => effects::load_conflict_summaries(state, repo_id),
+        Msg::CheckoutConflictSidePaths {
+            repo_id,
+            paths,
+            side,
+        } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::checkout_conflict_side_paths(repo_id, paths, side)
+        }
+        Msg::AutosolveConflictPaths { repo_id, paths } => {
+            begin_local_action(state, repo_id);
+            actions_emit_effects::autosolve_conflict_paths(repo_id, paths)
+        }
         Msg::RecordConflictAutosolveTelemetry {
             repo_id,
             path,
@@ -1348,6 +1685,9 @@ pub(super) fn reduce(
         Msg::Internal(crate::msg::InternalMsg::RebaseStateLoaded { repo_id, result }) => {
             external_and_history::rebase_state_loaded(state, repo_id, result)
         }
+        Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded { repo_id, result }) => {
+            external_and_history::operation_state_loaded(state, repo_id, result)
+        }
         Msg::Internal(crate::msg::InternalMsg::MergeCommitMessageLoaded { repo_id, result }) => {
             external_and_history::merge_commit_message_loaded(state, repo_id, result)
         }
@@ -1356,21 +1696,61 @@ pub(super) fn reduce(
             path,
             result,
         }) => effects::file_history_loaded(state, repo_id, path, result),
+        Msg::Internal(crate::msg::InternalMsg::LineHistoryLoaded {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+            result,
+        }) => effects::line_history_loaded(state, repo_id, path, (start, end), rev, result),
         Msg::Internal(crate::msg::InternalMsg::BlameLoaded {
             repo_id,
             path,
             rev,
+            options,
             result,
-        }) => effects::blame_loaded(state, repo_id, path, rev, result),
+        }) => effects::blame_loaded(state, repo_id, path, rev, options, result),
+        Msg::Internal(crate::msg::InternalMsg::CommitFilesSearched {
+            repo_id,
+            commit_id,
+            query,
+            options,
+            result,
+        }) => effects::commit_files_searched(state, repo_id, commit_id, query, options, result),
         Msg::Internal(crate::msg::InternalMsg::ConflictFileLoaded {
             repo_id,
             path,
             result,
             conflict_session,
         }) => effects::conflict_file_loaded(state, repo_id, path, *result, conflict_session),
+        Msg::Internal(crate::msg::InternalMsg::ConflictSummariesLoaded { repo_id, result }) => {
+            effects::conflict_summaries_loaded(state, repo_id, result)
+        }
+        Msg::Internal(crate::msg::InternalMsg::PatchSeriesPreviewLoaded {
+            repo_id,
+            source,
+            result,
+        }) => effects::patch_series_preview_loaded(state, repo_id, source, result),
+        Msg::Internal(crate::msg::InternalMsg::RevisionTreeLoaded {
+            repo_id,
+            rev,
+            dir,
+            result,
+        }) => effects::revision_tree_loaded(state, repo_id, rev, dir, result),
+        Msg::Internal(crate::msg::InternalMsg::RevisionFileLoaded {
+            repo_id,
+            rev,
+            path,
+            against_worktree,
+            result,
+        }) => effects::revision_file_loaded(state, repo_id, rev, path, against_worktree, result),
         Msg::Internal(crate::msg::InternalMsg::WorktreesLoaded { repo_id, result }) => {
             effects::worktrees_loaded(state, repo_id, result)
         }
+        Msg::Internal(crate::msg::InternalMsg::WorktreeSummariesLoaded { repo_id, summaries }) => {
+            effects::worktree_summaries_loaded(state, repo_id, summaries)
+        }
         Msg::Internal(crate::msg::InternalMsg::SubmodulesLoaded { repo_id, result }) => {
             effects::submodules_loaded(state, repo_id, result)
         }
@@ -1471,6 +1851,11 @@ pub(super) fn reduce(
             commit_id,
             result,
         }) => effects::commit_details_loaded(state, repo_id, commit_id, result),
+        Msg::Internal(crate::msg::InternalMsg::RewordBlockerLoaded {
+            repo_id,
+            commit_id,
+            result,
+        }) => effects::reword_blocker_loaded(state, repo_id, commit_id, result),
         Msg::Internal(crate::msg::InternalMsg::RecentCommitMessagesLoaded {
             repo_id,
             request_rev,
@@ -1640,6 +2025,7 @@ pub(super) fn reduce(
             let removed_worktree_path = match (&command, &result) {
                 (RepoCommandKind::RemoveWorktree { path }, Ok(_)) => Some(path.clone()),
                 (RepoCommandKind::ForceRemoveWorktree { path }, Ok(_)) => Some(path.clone()),
+                (RepoCommandKind::MoveWorktree { path, .. }, Ok(_)) => Some(path.clone()),
                 _ => None,
             };
 
@@ -1663,6 +2049,32 @@ pub(super) fn reduce(
                 state.auth_prompt = Some(prompt);
             }
 
+            effects
+        }
+        Msg::Internal(crate::msg::InternalMsg::SubmoduleBatchFinished {
+            repo_id,
+            command,
+            result,
+        }) => {
+            let retry_command = RepoCommandKind::RunInSubmodules {
+                command: command.clone(),
+            };
+            let auth_prompt = match &result {
+                Ok(results) => results
+                    .iter()
+                    .filter_map(|outcome| outcome.result.as_ref().err())
+                    .find_map(|error| auth_prompt_for_repo_command(repo_id, &retry_command, error)),
+                Err(error) => auth_prompt_for_repo_command(repo_id, &retry_command, error),
+            };
+
+            let effects =
+                actions_emit_effects::submodule_batch_finished(state, repo_id, command, result);
+
+            if let Some(prompt) = auth_prompt {
+                util::clear_staged_git_auth_env();
+                state.auth_prompt = Some(prompt);
+            }
+
             effects
         }
     }
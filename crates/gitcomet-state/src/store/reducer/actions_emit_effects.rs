@@ -1,17 +1,19 @@
 use super::util::{
     SelectedConflictTarget, apply_selected_diff_load_plan_state, clear_banner_error_for_repo,
     diff_reload_effects, format_failure_summary, push_action_log, push_command_log,
-    refresh_full_effects, refresh_primary_effects, selected_conflict_target,
-    selected_diff_load_plan, start_conflict_target_reload, start_current_conflict_target_reload,
+    push_submodule_batch_log, refresh_full_effects, refresh_primary_effects,
+    selected_conflict_target, selected_diff_load_plan, start_conflict_target_reload,
+    start_current_conflict_target_reload, submodule_batch_outcome,
 };
 use crate::model::{AppState, Loadable, RepoId, RepoState};
 use crate::msg::{Effect, RepoCommandKind, RepoPathList};
 use gitcomet_core::auth::StagedGitAuth;
 use gitcomet_core::conflict_session::{ConflictRegionResolution, ConflictResolverStrategy};
-use gitcomet_core::domain::{DiffTarget, FileConflictKind};
+use gitcomet_core::domain::{DiffTarget, FileConflictKind, Upstream};
 use gitcomet_core::error::Error;
 use gitcomet_core::services::{
     CommandOutput, GitRepository, PullMode, RemoteUrlKind, ResetMode, SafePushAfterCommitTarget,
+    SubmoduleBatchCommand, SubmoduleCommandResult,
 };
 use rustc_hash::FxHashMap as HashMap;
 use std::path::PathBuf;
@@ -96,10 +98,100 @@ pub(super) fn export_patch(
     }]
 }
 
+pub(super) fn export_patch_series(
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+    dest: PathBuf,
+    cover_letter: bool,
+) -> Vec<Effect> {
+    vec![Effect::ExportPatchSeries {
+        repo_id,
+        commit_ids,
+        dest,
+        cover_letter,
+    }]
+}
+
+pub(super) fn cherry_pick_commits(
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) -> Vec<Effect> {
+    vec![Effect::CherryPickCommits {
+        repo_id,
+        commit_ids,
+    }]
+}
+
+pub(super) fn revert_commits(
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) -> Vec<Effect> {
+    vec![Effect::RevertCommits {
+        repo_id,
+        commit_ids,
+    }]
+}
+
+pub(super) fn squash_commits(
+    repo_id: RepoId,
+    commit_ids: Vec<gitcomet_core::domain::CommitId>,
+) -> Vec<Effect> {
+    vec![Effect::SquashCommits {
+        repo_id,
+        commit_ids,
+    }]
+}
+
+pub(super) fn reword_commit(
+    repo_id: RepoId,
+    commit_id: gitcomet_core::domain::CommitId,
+    message: String,
+) -> Vec<Effect> {
+    vec![Effect::RewordCommit {
+        repo_id,
+        commit_id,
+        message,
+    }]
+}
+
+pub(super) fn create_fixup_commit(
+    repo_id: RepoId,
+    commit_id: gitcomet_core::domain::CommitId,
+    kind: gitcomet_core::services::FixupCommitKind,
+) -> Vec<Effect> {
+    vec![Effect::CreateFixupCommit {
+        repo_id,
+        commit_id,
+        kind,
+    }]
+}
+
 pub(super) fn apply_patch(repo_id: RepoId, patch: PathBuf) -> Vec<Effect> {
     vec![Effect::ApplyPatch { repo_id, patch }]
 }
 
+pub(super) fn apply_patch_series(repo_id: RepoId, source: PathBuf, three_way: bool) -> Vec<Effect> {
+    vec![Effect::ApplyPatchSeries {
+        repo_id,
+        source,
+        three_way,
+    }]
+}
+
+pub(super) fn save_revision_path(
+    repo_id: RepoId,
+    rev: String,
+    path: PathBuf,
+    dest: PathBuf,
+) -> Vec<Effect> {
+    vec![Effect::SaveRevisionPath {
+        repo_id,
+        rev,
+        path,
+        dest,
+    }]
+}
+
 pub(super) fn add_worktree(
     repo_id: RepoId,
     path: PathBuf,
@@ -120,6 +212,30 @@ pub(super) fn force_remove_worktree(repo_id: RepoId, path: PathBuf) -> Vec<Effec
     vec![Effect::ForceRemoveWorktree { repo_id, path }]
 }
 
+pub(super) fn lock_worktree(repo_id: RepoId, path: PathBuf, reason: Option<String>) -> Vec<Effect> {
+    vec![Effect::LockWorktree {
+        repo_id,
+        path,
+        reason,
+    }]
+}
+
+pub(super) fn unlock_worktree(repo_id: RepoId, path: PathBuf) -> Vec<Effect> {
+    vec![Effect::UnlockWorktree { repo_id, path }]
+}
+
+pub(super) fn move_worktree(repo_id: RepoId, path: PathBuf, new_path: PathBuf) -> Vec<Effect> {
+    vec![Effect::MoveWorktree {
+        repo_id,
+        path,
+        new_path,
+    }]
+}
+
+pub(super) fn prune_worktrees(repo_id: RepoId) -> Vec<Effect> {
+    vec![Effect::PruneWorktrees { repo_id }]
+}
+
 pub(super) fn add_submodule(
     repo_id: RepoId,
     url: String,
@@ -181,6 +297,38 @@ pub(super) fn remove_submodule(repo_id: RepoId, path: PathBuf) -> Vec<Effect> {
     vec![Effect::RemoveSubmodule { repo_id, path }]
 }
 
+pub(super) fn sync_submodules(repo_id: RepoId) -> Vec<Effect> {
+    vec![Effect::SyncSubmodules { repo_id }]
+}
+
+pub(super) fn set_submodule_branch(
+    repo_id: RepoId,
+    path: PathBuf,
+    branch: Option<String>,
+) -> Vec<Effect> {
+    vec![Effect::SetSubmoduleBranch {
+        repo_id,
+        path,
+        branch,
+    }]
+}
+
+pub(super) fn update_submodule_remote(repo_id: RepoId, path: PathBuf) -> Vec<Effect> {
+    vec![Effect::UpdateSubmoduleRemote {
+        repo_id,
+        path,
+        auth: None,
+    }]
+}
+
+pub(super) fn run_in_submodules(repo_id: RepoId, command: SubmoduleBatchCommand) -> Vec<Effect> {
+    vec![Effect::RunInSubmodules {
+        repo_id,
+        command,
+        auth: None,
+    }]
+}
+
 pub(super) fn stage_path(repo_id: RepoId, path: PathBuf) -> Vec<Effect> {
     vec![Effect::StagePath { repo_id, path }]
 }
@@ -460,6 +608,29 @@ pub(super) fn delete_remote_branch(
     }]
 }
 
+pub(super) fn rename_branch(
+    repos: &HashMap<RepoId, Arc<dyn GitRepository>>,
+    state: &mut AppState,
+    repo_id: RepoId,
+    name: String,
+    new_name: String,
+    force: bool,
+    upstream: Option<Upstream>,
+) -> Vec<Effect> {
+    // Renaming the remote branch pushes, so it shows as a push in flight.
+    if upstream.is_some() {
+        bump_in_flight(repos, state, repo_id, InFlightKind::Push);
+    }
+    vec![Effect::RenameBranch {
+        repo_id,
+        name,
+        new_name,
+        force,
+        upstream,
+        auth: None,
+    }]
+}
+
 pub(super) fn reset(repo_id: RepoId, target: String, mode: ResetMode) -> Vec<Effect> {
     vec![Effect::Reset {
         repo_id,
@@ -472,6 +643,10 @@ pub(super) fn rebase(repo_id: RepoId, onto: String) -> Vec<Effect> {
     vec![Effect::Rebase { repo_id, onto }]
 }
 
+pub(super) fn autosquash_rebase(repo_id: RepoId) -> Vec<Effect> {
+    vec![Effect::AutosquashRebase { repo_id }]
+}
+
 pub(super) fn rebase_continue(repo_id: RepoId) -> Vec<Effect> {
     vec![Effect::RebaseContinue { repo_id }]
 }
@@ -480,6 +655,18 @@ pub(super) fn rebase_abort(repo_id: RepoId) -> Vec<Effect> {
     vec![Effect::RebaseAbort { repo_id }]
 }
 
+pub(super) fn operation_action(
+    repo_id: RepoId,
+    kind: gitcomet_core::domain::RepoOperationKind,
+    action: gitcomet_core::domain::RepoOperationAction,
+) -> Vec<Effect> {
+    vec![Effect::OperationAction {
+        repo_id,
+        kind,
+        action,
+    }]
+}
+
 pub(super) fn merge_abort(repo_id: RepoId) -> Vec<Effect> {
     vec![Effect::MergeAbort { repo_id }]
 }
@@ -574,6 +761,22 @@ pub(super) fn launch_mergetool(repo_id: RepoId, path: PathBuf) -> Vec<Effect> {
     vec![Effect::LaunchMergetool { repo_id, path }]
 }
 
+pub(super) fn checkout_conflict_side_paths(
+    repo_id: RepoId,
+    paths: RepoPathList,
+    side: gitcomet_core::services::ConflictSide,
+) -> Vec<Effect> {
+    vec![Effect::CheckoutConflictSidePaths {
+        repo_id,
+        paths,
+        side,
+    }]
+}
+
+pub(super) fn autosolve_conflict_paths(repo_id: RepoId, paths: RepoPathList) -> Vec<Effect> {
+    vec![Effect::AutosolveConflictPaths { repo_id, paths }]
+}
+
 pub(super) fn stash(repo_id: RepoId, message: String, include_untracked: bool) -> Vec<Effect> {
     vec![Effect::Stash {
         repo_id,
@@ -764,8 +967,10 @@ fn tracks_local_actions_in_flight(command: &RepoCommandKind) -> bool {
             | RepoCommandKind::SquashRef { .. }
             | RepoCommandKind::Reset { .. }
             | RepoCommandKind::Rebase { .. }
+            | RepoCommandKind::AutosquashRebase
             | RepoCommandKind::RebaseContinue
             | RepoCommandKind::RebaseAbort
+            | RepoCommandKind::OperationAction { .. }
             | RepoCommandKind::MergeAbort
             | RepoCommandKind::CreateTag { .. }
             | RepoCommandKind::DeleteTag { .. }
@@ -774,18 +979,33 @@ fn tracks_local_actions_in_flight(command: &RepoCommandKind) -> bool {
             | RepoCommandKind::SetRemoteUrl { .. }
             | RepoCommandKind::SetUpstreamBranch { .. }
             | RepoCommandKind::UnsetUpstreamBranch { .. }
+            | RepoCommandKind::RenameBranch { upstream: None, .. }
             | RepoCommandKind::CheckoutConflict { .. }
             | RepoCommandKind::AcceptConflictDeletion { .. }
             | RepoCommandKind::CheckoutConflictBase { .. }
             | RepoCommandKind::LaunchMergetool { .. }
+            | RepoCommandKind::CheckoutConflictPaths { .. }
+            | RepoCommandKind::AutosolveConflicts { .. }
             | RepoCommandKind::SaveWorktreeFile { .. }
             | RepoCommandKind::ExportPatch { .. }
+            | RepoCommandKind::ExportPatchSeries { .. }
+            | RepoCommandKind::CherryPickCommits { .. }
+            | RepoCommandKind::RevertCommits { .. }
+            | RepoCommandKind::SquashCommits { .. }
+            | RepoCommandKind::RewordCommit { .. }
+            | RepoCommandKind::CreateFixupCommit { .. }
             | RepoCommandKind::ApplyPatch { .. }
+            | RepoCommandKind::ApplyPatchSeries { .. }
+            | RepoCommandKind::SaveRevisionPath { .. }
             | RepoCommandKind::AddSubmodule { .. }
             | RepoCommandKind::UpdateSubmodules { .. }
             | RepoCommandKind::LoadSubmodule { .. }
             | RepoCommandKind::ChangeSubmodulePointer { .. }
             | RepoCommandKind::RemoveSubmodule { .. }
+            | RepoCommandKind::SyncSubmodules
+            | RepoCommandKind::SetSubmoduleBranch { .. }
+            | RepoCommandKind::UpdateSubmoduleRemote { .. }
+            | RepoCommandKind::RunInSubmodules { .. }
             | RepoCommandKind::StageHunk
             | RepoCommandKind::UnstageHunk
             | RepoCommandKind::ApplyWorktreePatch { .. }
@@ -806,8 +1026,10 @@ fn command_clears_pending_force_push_lease(command: &RepoCommandKind) -> bool {
             | RepoCommandKind::PushSetUpstream { .. }
             | RepoCommandKind::Reset { .. }
             | RepoCommandKind::Rebase { .. }
+            | RepoCommandKind::AutosquashRebase
             | RepoCommandKind::RebaseContinue
             | RepoCommandKind::RebaseAbort
+            | RepoCommandKind::OperationAction { .. }
             | RepoCommandKind::MergeAbort
     )
 }
@@ -815,7 +1037,8 @@ fn command_clears_pending_force_push_lease(command: &RepoCommandKind) -> bool {
 fn changed_submodule_path(command: &RepoCommandKind) -> Option<&std::path::Path> {
     match command {
         RepoCommandKind::LoadSubmodule { path, .. }
-        | RepoCommandKind::ChangeSubmodulePointer { path, .. } => Some(path.as_path()),
+        | RepoCommandKind::ChangeSubmodulePointer { path, .. }
+        | RepoCommandKind::UpdateSubmoduleRemote { path } => Some(path.as_path()),
         _ => None,
     }
 }
@@ -832,7 +1055,10 @@ fn selected_submodule_target_changed_by_command(
         if path.as_path() != changed_path {
             return None;
         }
-    } else if !matches!(command, RepoCommandKind::UpdateSubmodules { .. }) {
+    } else if !matches!(
+        command,
+        RepoCommandKind::UpdateSubmodules { .. } | RepoCommandKind::RunInSubmodules { .. }
+    ) {
         return None;
     }
 
@@ -852,6 +1078,11 @@ pub(super) fn repo_command_finished(
         RepoCommandKind::AddWorktree { .. }
             | RepoCommandKind::RemoveWorktree { .. }
             | RepoCommandKind::ForceRemoveWorktree { .. }
+            | RepoCommandKind::LockWorktree { .. }
+            | RepoCommandKind::UnlockWorktree { .. }
+            | RepoCommandKind::MoveWorktree { .. }
+            | RepoCommandKind::PruneWorktrees
+            | RepoCommandKind::RenameBranch { .. }
     ) && result.is_ok();
     let refresh_submodules = matches!(
         &command,
@@ -860,6 +1091,10 @@ pub(super) fn repo_command_finished(
             | RepoCommandKind::LoadSubmodule { .. }
             | RepoCommandKind::ChangeSubmodulePointer { .. }
             | RepoCommandKind::RemoveSubmodule { .. }
+            | RepoCommandKind::SyncSubmodules
+            | RepoCommandKind::SetSubmoduleBranch { .. }
+            | RepoCommandKind::UpdateSubmoduleRemote { .. }
+            | RepoCommandKind::RunInSubmodules { .. }
     ) && result.is_ok();
     let command_succeeded = result.is_ok();
     let mut clear_banner = false;
@@ -884,6 +1119,9 @@ pub(super) fn repo_command_finished(
         | RepoCommandKind::ForcePushWithLease { .. }
         | RepoCommandKind::PushSetUpstream { .. }
         | RepoCommandKind::DeleteRemoteBranch { .. }
+        | RepoCommandKind::RenameBranch {
+            upstream: Some(_), ..
+        }
         | RepoCommandKind::PushTag { .. }
         | RepoCommandKind::DeleteRemoteTag { .. } => {
             repo_state.push_in_flight = repo_state.push_in_flight.saturating_sub(1);
@@ -891,7 +1129,11 @@ pub(super) fn repo_command_finished(
         }
         RepoCommandKind::AddWorktree { .. }
         | RepoCommandKind::RemoveWorktree { .. }
-        | RepoCommandKind::ForceRemoveWorktree { .. } => {
+        | RepoCommandKind::ForceRemoveWorktree { .. }
+        | RepoCommandKind::LockWorktree { .. }
+        | RepoCommandKind::UnlockWorktree { .. }
+        | RepoCommandKind::MoveWorktree { .. }
+        | RepoCommandKind::PruneWorktrees => {
             repo_state.worktrees_in_flight = repo_state.worktrees_in_flight.saturating_sub(1);
         }
         _ if tracks_local_actions_in_flight(&command) => {
@@ -918,9 +1160,13 @@ pub(super) fn repo_command_finished(
                 &command,
                 RepoCommandKind::Reset { .. }
                     | RepoCommandKind::Rebase { .. }
+                    | RepoCommandKind::AutosquashRebase
                     | RepoCommandKind::RebaseContinue
                     | RepoCommandKind::RebaseAbort
+                    | RepoCommandKind::OperationAction { .. }
                     | RepoCommandKind::MergeAbort
+                    | RepoCommandKind::SquashCommits { .. }
+                    | RepoCommandKind::RewordCommit { .. }
             ) {
                 repo_state.set_diff_target(None);
                 repo_state.diff_state.diff = Loadable::NotLoaded;
@@ -1006,6 +1252,26 @@ pub(super) fn repo_command_finished(
     effects
 }
 
+pub(super) fn submodule_batch_finished(
+    state: &mut AppState,
+    repo_id: RepoId,
+    command: SubmoduleBatchCommand,
+    result: std::result::Result<Vec<SubmoduleCommandResult>, Error>,
+) -> Vec<Effect> {
+    let overall = result.and_then(|results| {
+        if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+            push_submodule_batch_log(repo_state, &command, &results);
+        }
+        submodule_batch_outcome(&command, &results)
+    });
+    repo_command_finished(
+        state,
+        repo_id,
+        RepoCommandKind::RunInSubmodules { command },
+        overall,
+    )
+}
+
 fn sync_conflict_session_after_resolution_command(
     repo_state: &mut RepoState,
     command: &RepoCommandKind,
@@ -75,6 +75,7 @@ fn inline_submodule_entries_from_summary(
                 target: DiffTarget::WorkingTree {
                     path: change.path.clone(),
                     area: DiffArea::Staged,
+                    old_path: None,
                 },
                 section: InlineSubmoduleDiffSection::LiveStaged,
             }),
@@ -89,6 +90,7 @@ fn inline_submodule_entries_from_summary(
                 target: DiffTarget::WorkingTree {
                     path: change.path.clone(),
                     area: DiffArea::Unstaged,
+                    old_path: None,
                 },
                 section: InlineSubmoduleDiffSection::LiveUnstaged,
             }),
@@ -245,6 +247,7 @@ pub(super) fn select_conflict_diff(
     let target = DiffTarget::WorkingTree {
         path: path.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_diff_target(Some(target));
     repo_state.diff_state.diff = Loadable::NotLoaded;
@@ -3,17 +3,20 @@ use super::util::{
     selected_diff_load_plan,
 };
 use crate::model::{
-    AppState, ConflictFileLoadMode, DiagnosticKind, Loadable, RepoId, RepoLoadsInFlight, RepoState,
+    AppState, CommitFileSearch, ConflictFileLoadMode, DiagnosticKind, Loadable, PatchSeriesPreview,
+    RepoId, RepoLoadsInFlight, RepoState, RevisionFilePreview, RevisionTreeBrowser,
     SidebarDataRequest,
 };
 use crate::msg::Effect;
-use gitcomet_core::conflict_session::{ConflictPayload, ConflictSession};
+use gitcomet_core::conflict_session::{ConflictFileSummary, ConflictPayload, ConflictSession};
 use gitcomet_core::domain::{
-    Branch, CommitDetails, CommitId, FileStatusKind, LogPage, RecentCommitMessage, ReflogEntry,
-    Remote, RemoteBranch, RemoteTag, RepoStatus, StashEntry, Submodule, Tag, UpstreamDivergence,
-    Worktree,
+    Branch, CommitDetails, CommitId, FileStatusKind, LineRangeLogPage, LogPage,
+    RecentCommitMessage, ReflogEntry, Remote, RemoteBranch, RemoteTag, RepoStatus,
+    RevisionTreeEntry, StashEntry, Submodule, Tag, UpstreamDivergence, Worktree, WorktreeSummary,
 };
 use gitcomet_core::error::Error;
+use gitcomet_core::mailbox::MailboxPatch;
+use gitcomet_core::services::{BlameOptions, CommitFileSearchMatch, TextSearchOptions};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -37,16 +40,42 @@ pub(super) fn file_history_loaded(
     Vec::new()
 }
 
+pub(super) fn line_history_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    path: PathBuf,
+    range: (u32, u32),
+    rev: Option<String>,
+    result: std::result::Result<LineRangeLogPage, Error>,
+) -> Vec<Effect> {
+    if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id)
+        && repo_state.history_state.line_history_path.as_ref() == Some(&path)
+        && repo_state.history_state.line_history_range == Some(range)
+        && repo_state.history_state.line_history_rev == rev
+    {
+        repo_state.history_state.line_history = match result {
+            Ok(v) => Loadable::Ready(Arc::new(v)),
+            Err(e) => {
+                push_diagnostic(repo_state, DiagnosticKind::Error, e.to_string());
+                Loadable::Error(e.to_string())
+            }
+        };
+    }
+    Vec::new()
+}
+
 pub(super) fn blame_loaded(
     state: &mut AppState,
     repo_id: RepoId,
     path: PathBuf,
     rev: Option<String>,
+    options: BlameOptions,
     result: std::result::Result<Vec<gitcomet_core::services::BlameLine>, Error>,
 ) -> Vec<Effect> {
     if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id)
         && repo_state.history_state.blame_path.as_ref() == Some(&path)
         && repo_state.history_state.blame_rev == rev
+        && repo_state.history_state.blame_options == options
     {
         repo_state.history_state.blame = match result {
             Ok(v) => Loadable::Ready(Arc::new(v)),
@@ -59,6 +88,24 @@ pub(super) fn blame_loaded(
     Vec::new()
 }
 
+pub(super) fn conflict_summaries_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    result: std::result::Result<Vec<ConflictFileSummary>, Error>,
+) -> Vec<Effect> {
+    if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+        let summaries = match result {
+            Ok(v) => Loadable::Ready(v),
+            Err(e) => {
+                push_diagnostic(repo_state, DiagnosticKind::Error, e.to_string());
+                Loadable::Error(e.to_string())
+            }
+        };
+        repo_state.set_conflict_summaries(summaries);
+    }
+    Vec::new()
+}
+
 pub(super) fn conflict_file_loaded(
     state: &mut AppState,
     repo_id: RepoId,
@@ -179,6 +226,17 @@ pub(super) fn worktrees_loaded(
     effects
 }
 
+pub(super) fn worktree_summaries_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    summaries: Vec<WorktreeSummary>,
+) -> Vec<Effect> {
+    if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+        repo_state.set_worktree_summaries(Loadable::Ready(summaries));
+    }
+    Vec::new()
+}
+
 pub(super) fn submodules_loaded(
     state: &mut AppState,
     repo_id: RepoId,
@@ -211,6 +269,7 @@ pub(super) fn select_commit(
     }
 
     repo_state.set_selected_commit(Some(commit_id.clone()));
+    repo_state.set_commit_file_search(None);
     let already_loaded = matches!(
         &repo_state.history_state.commit_details,
         Loadable::Ready(details) if details.id == commit_id
@@ -235,6 +294,7 @@ pub(super) fn clear_commit_selection(state: &mut AppState, repo_id: RepoId) -> V
 
     repo_state.set_selected_commit(None);
     repo_state.set_commit_details(Loadable::NotLoaded);
+    repo_state.set_commit_file_search(None);
     Vec::new()
 }
 
@@ -456,6 +516,32 @@ pub(super) fn load_file_history(
     }]
 }
 
+pub(super) fn load_line_history(
+    state: &mut AppState,
+    repo_id: RepoId,
+    path: PathBuf,
+    start: u32,
+    end: u32,
+    rev: Option<String>,
+    limit: usize,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    repo_state.history_state.line_history_path = Some(path.clone());
+    repo_state.history_state.line_history_range = Some((start, end));
+    repo_state.history_state.line_history_rev = rev.clone();
+    repo_state.history_state.line_history = Loadable::Loading;
+    vec![Effect::LoadLineHistory {
+        repo_id,
+        path,
+        start,
+        end,
+        rev,
+        limit,
+    }]
+}
+
 pub(super) fn load_blame(
     state: &mut AppState,
     repo_id: RepoId,
@@ -468,7 +554,274 @@ pub(super) fn load_blame(
     repo_state.history_state.blame_path = Some(path.clone());
     repo_state.history_state.blame_rev = rev.clone();
     repo_state.history_state.blame = Loadable::Loading;
-    vec![Effect::LoadBlame { repo_id, path, rev }]
+    let options = repo_state.history_state.blame_options;
+    vec![Effect::LoadBlame {
+        repo_id,
+        path,
+        rev,
+        options,
+    }]
+}
+
+pub(super) fn set_blame_options(
+    state: &mut AppState,
+    repo_id: RepoId,
+    options: BlameOptions,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    if repo_state.history_state.blame_options == options {
+        return Vec::new();
+    }
+    repo_state.history_state.blame_options = options;
+    match repo_state.history_state.blame_path.clone() {
+        Some(path) => {
+            let rev = repo_state.history_state.blame_rev.clone();
+            load_blame(state, repo_id, path, rev)
+        }
+        None => Vec::new(),
+    }
+}
+
+pub(super) fn search_commit_files(
+    state: &mut AppState,
+    repo_id: RepoId,
+    commit_id: CommitId,
+    query: String,
+    options: TextSearchOptions,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    if query.is_empty() {
+        repo_state.set_commit_file_search(None);
+        return Vec::new();
+    }
+    let Loadable::Ready(details) = &repo_state.history_state.commit_details else {
+        return Vec::new();
+    };
+    if details.id != commit_id {
+        return Vec::new();
+    }
+    let paths = details
+        .files
+        .iter()
+        .map(|file| file.path.clone())
+        .collect::<Vec<_>>();
+    repo_state.set_commit_file_search(Some(CommitFileSearch {
+        commit_id: commit_id.clone(),
+        query: query.clone(),
+        options,
+        matches: Loadable::Loading,
+    }));
+    vec![Effect::SearchCommitFiles {
+        repo_id,
+        commit_id,
+        paths,
+        query,
+        options,
+    }]
+}
+
+pub(super) fn commit_files_searched(
+    state: &mut AppState,
+    repo_id: RepoId,
+    commit_id: CommitId,
+    query: String,
+    options: TextSearchOptions,
+    result: std::result::Result<Vec<CommitFileSearchMatch>, Error>,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(mut search) = repo_state.history_state.commit_file_search.clone() else {
+        return Vec::new();
+    };
+    if search.commit_id != commit_id || search.query != query || search.options != options {
+        return Vec::new();
+    }
+    search.matches = match result {
+        Ok(v) => Loadable::Ready(Arc::new(v)),
+        Err(e) => {
+            push_diagnostic(repo_state, DiagnosticKind::Error, e.to_string());
+            Loadable::Error(e.to_string())
+        }
+    };
+    repo_state.set_commit_file_search(Some(search));
+    Vec::new()
+}
+
+pub(super) fn load_patch_series_preview(
+    state: &mut AppState,
+    repo_id: RepoId,
+    source: Option<PathBuf>,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(source) = source else {
+        repo_state.set_patch_series_preview(None);
+        return Vec::new();
+    };
+    repo_state.set_patch_series_preview(Some(PatchSeriesPreview {
+        source: source.clone(),
+        patches: Loadable::Loading,
+    }));
+    vec![Effect::LoadPatchSeriesPreview { repo_id, source }]
+}
+
+pub(super) fn patch_series_preview_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    source: PathBuf,
+    result: std::result::Result<Vec<MailboxPatch>, Error>,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(mut preview) = repo_state.patch_series_preview.clone() else {
+        return Vec::new();
+    };
+    if preview.source != source {
+        return Vec::new();
+    }
+    preview.patches = match result {
+        Ok(patches) => Loadable::Ready(Arc::new(patches)),
+        Err(e) => Loadable::Error(e.to_string()),
+    };
+    repo_state.set_patch_series_preview(Some(preview));
+    Vec::new()
+}
+
+pub(super) fn load_revision_tree(
+    state: &mut AppState,
+    repo_id: RepoId,
+    rev: String,
+    dir: PathBuf,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    repo_state.set_revision_tree(Some(RevisionTreeBrowser {
+        rev: rev.clone(),
+        dir: dir.clone(),
+        entries: Loadable::Loading,
+        file: None,
+    }));
+    vec![Effect::LoadRevisionTree { repo_id, rev, dir }]
+}
+
+pub(super) fn revision_tree_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    rev: String,
+    dir: PathBuf,
+    result: std::result::Result<Vec<RevisionTreeEntry>, Error>,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(mut browser) = repo_state.revision_tree.clone() else {
+        return Vec::new();
+    };
+    if browser.rev != rev || browser.dir != dir {
+        return Vec::new();
+    }
+    browser.entries = match result {
+        Ok(entries) => Loadable::Ready(Arc::new(entries)),
+        Err(e) => Loadable::Error(e.to_string()),
+    };
+    repo_state.set_revision_tree(Some(browser));
+    Vec::new()
+}
+
+pub(super) fn load_revision_file(
+    state: &mut AppState,
+    repo_id: RepoId,
+    path: Option<PathBuf>,
+    against_worktree: bool,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(mut browser) = repo_state.revision_tree.clone() else {
+        return Vec::new();
+    };
+    let Some(path) = path else {
+        browser.file = None;
+        repo_state.set_revision_tree(Some(browser));
+        return Vec::new();
+    };
+    browser.file = Some(RevisionFilePreview {
+        path: path.clone(),
+        against_worktree,
+        text: Loadable::Loading,
+    });
+    let rev = browser.rev.clone();
+    repo_state.set_revision_tree(Some(browser));
+    vec![Effect::LoadRevisionFile {
+        repo_id,
+        rev,
+        path,
+        against_worktree,
+    }]
+}
+
+pub(super) fn revision_file_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    rev: String,
+    path: PathBuf,
+    against_worktree: bool,
+    result: std::result::Result<String, Error>,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    let Some(mut browser) = repo_state.revision_tree.clone() else {
+        return Vec::new();
+    };
+    let Some(file) = browser.file.as_mut() else {
+        return Vec::new();
+    };
+    if browser.rev != rev || file.path != path || file.against_worktree != against_worktree {
+        return Vec::new();
+    }
+    file.text = match result {
+        Ok(text) => Loadable::Ready(Arc::new(text)),
+        Err(e) => Loadable::Error(e.to_string()),
+    };
+    repo_state.set_revision_tree(Some(browser));
+    Vec::new()
+}
+
+pub(super) fn load_conflict_summaries(state: &mut AppState, repo_id: RepoId) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    if !matches!(repo_state.open, Loadable::Ready(())) {
+        return Vec::new();
+    }
+    repo_state.set_conflict_summaries(Loadable::Loading);
+    vec![Effect::LoadConflictSummaries { repo_id }]
+}
+
+/// Re-read the conflict dashboard after a status change, but only once the
+/// dashboard has been requested for this repo.
+fn reload_conflict_summaries_if_requested(
+    repo_state: &mut RepoState,
+    repo_id: RepoId,
+    effects: &mut Vec<Effect>,
+) {
+    if matches!(
+        repo_state.conflict_state.conflict_summaries,
+        Loadable::NotLoaded | Loadable::Loading
+    ) {
+        return;
+    }
+    repo_state.set_conflict_summaries(Loadable::Loading);
+    effects.push(Effect::LoadConflictSummaries { repo_id });
 }
 
 pub(super) fn load_worktrees(state: &mut AppState, repo_id: RepoId) -> Vec<Effect> {
@@ -590,6 +943,7 @@ pub(super) fn status_loaded(
                 );
                 if !status_unchanged {
                     repo_state.set_status(Loadable::Ready(Arc::new(next)));
+                    reload_conflict_summaries_if_requested(repo_state, repo_id, &mut effects);
                 }
                 clear_resolved_conflict_context(repo_state);
                 !status_unchanged
@@ -632,6 +986,7 @@ pub(super) fn worktree_status_loaded(
                 let status_unchanged = matches!(&repo_state.worktree_status, Loadable::Ready(prev) if prev.as_slice() == next.as_slice());
                 if !status_unchanged {
                     repo_state.set_worktree_status(Loadable::Ready(next));
+                    reload_conflict_summaries_if_requested(repo_state, repo_id, &mut effects);
                 }
                 clear_resolved_conflict_context(repo_state);
                 !status_unchanged
@@ -934,11 +1289,45 @@ pub(super) fn commit_details_loaded(
     Vec::new()
 }
 
+pub(super) fn load_reword_blocker(
+    state: &mut AppState,
+    repo_id: RepoId,
+    commit_id: CommitId,
+) -> Vec<Effect> {
+    let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    repo_state.history_state.reword_blocker_commit = Some(commit_id.clone());
+    repo_state.history_state.reword_blocker = Loadable::Loading;
+    vec![Effect::LoadRewordBlocker { repo_id, commit_id }]
+}
+
+/// A failed check only keeps "Edit message…" disabled, so it isn't surfaced
+/// as a diagnostic; rewording reports its own errors.
+pub(super) fn reword_blocker_loaded(
+    state: &mut AppState,
+    repo_id: RepoId,
+    commit_id: CommitId,
+    result: std::result::Result<Option<String>, Error>,
+) -> Vec<Effect> {
+    if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id)
+        && repo_state.history_state.reword_blocker_commit.as_ref() == Some(&commit_id)
+    {
+        repo_state.history_state.reword_blocker = match result {
+            Ok(blocker) => Loadable::Ready(blocker),
+            Err(e) => Loadable::Error(e.to_string()),
+        };
+    }
+    Vec::new()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::model::{ConflictFile, RepoState, SidebarDataRequest};
-    use gitcomet_core::domain::{FileConflictKind, FileStatus, LogScope, RepoSpec};
+    use gitcomet_core::domain::{
+        CommitFileChange, FileConflictKind, FileStatus, LogScope, RepoSpec,
+    };
     use gitcomet_core::error::{Error, ErrorKind};
     use std::path::{Path, PathBuf};
     use std::sync::Arc;
@@ -975,6 +1364,8 @@ mod tests {
                 path: path.to_path_buf(),
                 kind: FileStatusKind::Conflicted,
                 conflict: Some(conflict),
+                old_path: None,
+                similarity: None,
             }],
         }
     }
@@ -1032,7 +1423,17 @@ mod tests {
         assert!(
             file_history_loaded(&mut state, repo_id, path.clone(), Ok(empty_log_page())).is_empty()
         );
-        assert!(blame_loaded(&mut state, repo_id, path.clone(), None, Ok(Vec::new())).is_empty());
+        assert!(
+            blame_loaded(
+                &mut state,
+                repo_id,
+                path.clone(),
+                None,
+                BlameOptions::default(),
+                Ok(Vec::new()),
+            )
+            .is_empty()
+        );
         assert!(conflict_file_loaded(&mut state, repo_id, path.clone(), Ok(None), None).is_empty());
         assert!(worktrees_loaded(&mut state, repo_id, Ok(Vec::new())).is_empty());
         assert!(submodules_loaded(&mut state, repo_id, Ok(Vec::new())).is_empty());
@@ -1051,6 +1452,7 @@ mod tests {
         );
         assert!(load_reflog(&mut state, repo_id).is_empty());
         assert!(load_file_history(&mut state, repo_id, path.clone(), 25).is_empty());
+        assert!(load_line_history(&mut state, repo_id, path.clone(), 1, 5, None, 25).is_empty());
         assert!(load_blame(&mut state, repo_id, path.clone(), Some("HEAD".to_string())).is_empty());
         assert!(load_worktrees(&mut state, repo_id).is_empty());
         assert!(load_submodules(&mut state, repo_id).is_empty());
@@ -1115,6 +1517,173 @@ mod tests {
         assert_eq!(repo.diagnostics.len(), 1);
     }
 
+    #[test]
+    fn reword_blocker_loaded_requires_matching_commit() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let a = CommitId("a".into());
+        let b = CommitId("b".into());
+
+        let effects = load_reword_blocker(&mut state, repo_id, a.clone());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadRewordBlocker { repo_id: rid, commit_id }]
+                if *rid == repo_id && commit_id == &a
+        ));
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .reword_blocker
+                .is_loading()
+        );
+
+        reword_blocker_loaded(&mut state, repo_id, b, Ok(None));
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .reword_blocker
+                .is_loading()
+        );
+
+        reword_blocker_loaded(
+            &mut state,
+            repo_id,
+            a,
+            Ok(Some("a is already in origin/main.".to_string())),
+        );
+        assert_eq!(
+            repo_mut(&mut state, repo_id).history_state.reword_blocker,
+            Loadable::Ready(Some("a is already in origin/main.".to_string()))
+        );
+    }
+
+    #[test]
+    fn line_history_loaded_requires_matching_path_range_and_rev() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let path = PathBuf::from("src/lib.rs");
+        let rev = Some("abc".to_string());
+
+        let effects = load_line_history(&mut state, repo_id, path.clone(), 10, 20, rev.clone(), 50);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadLineHistory {
+                repo_id: rid,
+                path: p,
+                start: 10,
+                end: 20,
+                rev: Some(r),
+                limit: 50,
+            }] if *rid == repo_id && p == &path && r == "abc"
+        ));
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .line_history
+                .is_loading()
+        );
+
+        let page = || LineRangeLogPage {
+            entries: Vec::new(),
+            next_cursor: None,
+        };
+        line_history_loaded(
+            &mut state,
+            repo_id,
+            path.clone(),
+            (10, 21),
+            rev.clone(),
+            Ok(page()),
+        );
+        line_history_loaded(
+            &mut state,
+            repo_id,
+            path.clone(),
+            (10, 20),
+            None,
+            Ok(page()),
+        );
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .line_history
+                .is_loading()
+        );
+
+        line_history_loaded(
+            &mut state,
+            repo_id,
+            path.clone(),
+            (10, 20),
+            rev.clone(),
+            Ok(page()),
+        );
+        assert!(matches!(
+            repo_mut(&mut state, repo_id).history_state.line_history,
+            Loadable::Ready(_)
+        ));
+
+        line_history_loaded(
+            &mut state,
+            repo_id,
+            path,
+            (10, 20),
+            rev,
+            Err(backend_error("log -L failed")),
+        );
+        let repo = repo_mut(&mut state, repo_id);
+        assert!(matches!(
+            repo.history_state.line_history,
+            Loadable::Error(_)
+        ));
+        assert_eq!(repo.diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn set_blame_options_reloads_open_blame() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let options = BlameOptions {
+            detect_moves: true,
+            ..BlameOptions::default()
+        };
+
+        assert!(set_blame_options(&mut state, repo_id, options).is_empty());
+        assert_eq!(
+            repo_mut(&mut state, repo_id).history_state.blame_options,
+            options
+        );
+
+        let path = PathBuf::from("src/lib.rs");
+        {
+            let repo = repo_mut(&mut state, repo_id);
+            repo.history_state.blame_path = Some(path.clone());
+            repo.history_state.blame_rev = Some("abc^".to_string());
+        }
+        assert!(set_blame_options(&mut state, repo_id, options).is_empty());
+
+        let options = BlameOptions {
+            detect_copies: true,
+            ..options
+        };
+        let effects = set_blame_options(&mut state, repo_id, options);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadBlame {
+                repo_id: rid,
+                path: p,
+                rev: Some(rev),
+                options: o,
+            }] if *rid == repo_id && p == &path && rev == "abc^" && *o == options
+        ));
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .blame
+                .is_loading()
+        );
+    }
+
     #[test]
     fn blame_loaded_requires_matching_path_and_rev() {
         let repo_id = RepoId(1);
@@ -1133,6 +1702,23 @@ mod tests {
             repo_id,
             path.clone(),
             Some("different".to_string()),
+            BlameOptions::default(),
+            Ok(Vec::new()),
+        );
+        assert!(matches!(
+            repo_mut(&mut state, repo_id).history_state.blame,
+            Loadable::NotLoaded
+        ));
+
+        blame_loaded(
+            &mut state,
+            repo_id,
+            path.clone(),
+            rev.clone(),
+            BlameOptions {
+                ignore_whitespace: true,
+                ..BlameOptions::default()
+            },
             Ok(Vec::new()),
         );
         assert!(matches!(
@@ -1145,6 +1731,7 @@ mod tests {
             repo_id,
             path.clone(),
             rev.clone(),
+            BlameOptions::default(),
             Ok(Vec::new()),
         );
         assert!(matches!(
@@ -1157,6 +1744,7 @@ mod tests {
             repo_id,
             path,
             rev,
+            BlameOptions::default(),
             Err(backend_error("blame failed")),
         );
         let repo = repo_mut(&mut state, repo_id);
@@ -1391,7 +1979,8 @@ mod tests {
             Effect::LoadBlame {
                 repo_id: rid,
                 ref path,
-                ref rev
+                ref rev,
+                ..
             } if rid == repo_id && path == &blame_path && rev.as_deref() == Some("HEAD")
         ));
         {
@@ -1954,6 +2543,67 @@ mod tests {
         assert!(!repo.diagnostics.is_empty());
     }
 
+    #[test]
+    fn conflict_summaries_load_and_refresh_after_status_changes() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let path = PathBuf::from("conflict.txt");
+
+        assert!(load_conflict_summaries(&mut state, repo_id).is_empty());
+        mark_repo_open_ready(&mut state, repo_id);
+
+        // Status changes do not load the dashboard until it was requested.
+        let effects = status_loaded(
+            &mut state,
+            repo_id,
+            Ok(conflicted_status(&path, FileConflictKind::BothAdded)),
+        );
+        assert!(effects.is_empty());
+
+        let effects = load_conflict_summaries(&mut state, repo_id);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadConflictSummaries { repo_id: rid }] if *rid == repo_id
+        ));
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .conflict_state
+                .conflict_summaries
+                .is_loading()
+        );
+
+        let summary = ConflictSession::new(
+            path.clone(),
+            FileConflictKind::BothModified,
+            ConflictPayload::Text("base\n".to_string().into()),
+            ConflictPayload::Text("ours\n".to_string().into()),
+            ConflictPayload::Text("theirs\n".to_string().into()),
+        )
+        .summary();
+        assert!(conflict_summaries_loaded(&mut state, repo_id, Ok(vec![summary])).is_empty());
+        assert!(matches!(
+            &repo_mut(&mut state, repo_id).conflict_state.conflict_summaries,
+            Loadable::Ready(summaries) if summaries.len() == 1 && summaries[0].path == path
+        ));
+
+        let effects = status_loaded(&mut state, repo_id, Ok(RepoStatus::default()));
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadConflictSummaries { repo_id: rid }] if *rid == repo_id
+        ));
+
+        assert!(
+            conflict_summaries_loaded(&mut state, repo_id, Err(backend_error("summaries")))
+                .is_empty()
+        );
+        let repo = repo_mut(&mut state, repo_id);
+        assert!(matches!(
+            repo.conflict_state.conflict_summaries,
+            Loadable::Error(_)
+        ));
+        assert_eq!(repo.diagnostics.len(), 1);
+    }
+
     #[test]
     fn tags_and_remote_tags_handle_unsupported_as_empty_ready() {
         let repo_id = RepoId(1);
@@ -2027,4 +2677,140 @@ mod tests {
         ));
         assert_eq!(repo.diagnostics.len(), 1);
     }
+
+    #[test]
+    fn commit_file_search_runs_over_loaded_details_and_drops_stale_results() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let commit_id = CommitId("abc".into());
+        let options = TextSearchOptions {
+            case_sensitive: true,
+            ..TextSearchOptions::default()
+        };
+
+        assert!(
+            search_commit_files(
+                &mut state,
+                repo_id,
+                commit_id.clone(),
+                "needle".to_string(),
+                options
+            )
+            .is_empty()
+        );
+
+        let mut details = commit_details_for(commit_id.clone());
+        details.files.push(CommitFileChange {
+            path: PathBuf::from("src/lib.rs"),
+            kind: FileStatusKind::Modified,
+            is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
+        });
+        repo_mut(&mut state, repo_id).set_commit_details(Loadable::Ready(Arc::new(details)));
+
+        let effects = search_commit_files(
+            &mut state,
+            repo_id,
+            commit_id.clone(),
+            "needle".to_string(),
+            options,
+        );
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::SearchCommitFiles { paths, query, .. }]
+                if paths == &vec![PathBuf::from("src/lib.rs")] && query == "needle"
+        ));
+
+        commit_files_searched(
+            &mut state,
+            repo_id,
+            commit_id.clone(),
+            "need".to_string(),
+            options,
+            Ok(Vec::new()),
+        );
+        assert!(matches!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .commit_file_search
+                .as_ref()
+                .map(|search| &search.matches),
+            Some(Loadable::Loading)
+        ));
+
+        let found = CommitFileSearchMatch {
+            path: PathBuf::from("src/lib.rs"),
+            line: 3,
+            text: "let needle = 1;".to_string(),
+        };
+        commit_files_searched(
+            &mut state,
+            repo_id,
+            commit_id.clone(),
+            "needle".to_string(),
+            options,
+            Ok(vec![found.clone()]),
+        );
+        assert!(matches!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .commit_file_search
+                .as_ref()
+                .map(|search| &search.matches),
+            Some(Loadable::Ready(matches)) if matches.as_slice() == [found]
+        ));
+
+        search_commit_files(&mut state, repo_id, commit_id, String::new(), options);
+        assert!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .commit_file_search
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn commit_file_search_forwards_search_options() {
+        let repo_id = RepoId(1);
+        let mut state = new_state_with_repo(repo_id);
+        let commit_id = CommitId("abc".into());
+        let mut details = commit_details_for(commit_id.clone());
+        details.files.push(CommitFileChange {
+            path: PathBuf::from("src/lib.rs"),
+            kind: FileStatusKind::Modified,
+            is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
+        });
+        repo_mut(&mut state, repo_id).set_commit_details(Loadable::Ready(Arc::new(details)));
+        let options = TextSearchOptions {
+            case_sensitive: true,
+            whole_word: true,
+            regex: true,
+        };
+
+        let effects = search_commit_files(
+            &mut state,
+            repo_id,
+            commit_id,
+            r"need(le)?".to_string(),
+            options,
+        );
+
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::SearchCommitFiles { options: forwarded, .. }] if *forwarded == options
+        ));
+        assert_eq!(
+            repo_mut(&mut state, repo_id)
+                .history_state
+                .commit_file_search
+                .as_ref()
+                .map(|search| search.options),
+            Some(options)
+        );
+    }
 }
@@ -5,7 +5,9 @@ use super::util::{
 };
 use crate::model::{AppState, DiagnosticKind, Loadable, RepoLoadsInFlight};
 use crate::msg::{Effect, RepoActionKind, RepoExternalChange};
-use gitcomet_core::domain::{DiffArea, DiffTarget, LogCursor, LogPage, LogScope};
+use gitcomet_core::domain::{
+    DiffArea, DiffTarget, LogCursor, LogPage, LogScope, RepoOperationState,
+};
 use gitcomet_core::error::Error;
 use std::sync::Arc;
 
@@ -73,12 +75,17 @@ pub(super) fn reload_repo(state: &mut AppState, repo_id: crate::model::RepoId) -
     repo_state.set_stashes(Loadable::NotLoaded);
     repo_state.reflog = Loadable::NotLoaded;
     repo_state.set_rebase_in_progress(Loadable::Loading);
+    repo_state.set_operation_state(Loadable::Loading);
     repo_state.set_merge_commit_message(Loadable::Loading);
     repo_state.history_state.file_history_path = None;
     repo_state.history_state.file_history = Loadable::NotLoaded;
     repo_state.history_state.blame_path = None;
     repo_state.history_state.blame_rev = None;
     repo_state.history_state.blame = Loadable::NotLoaded;
+    repo_state.history_state.line_history_path = None;
+    repo_state.history_state.line_history_range = None;
+    repo_state.history_state.line_history_rev = None;
+    repo_state.history_state.line_history = Loadable::NotLoaded;
     repo_state.set_worktrees(Loadable::NotLoaded);
     repo_state.set_submodules(Loadable::NotLoaded);
     repo_state.clear_head_dependent_cached_state();
@@ -273,6 +280,20 @@ pub(super) fn rebase_state_loaded(
     effects
 }
 
+pub(super) fn operation_state_loaded(
+    state: &mut AppState,
+    repo_id: crate::model::RepoId,
+    result: std::result::Result<Option<RepoOperationState>, Error>,
+) -> Vec<Effect> {
+    if let Some(repo_state) = state.repos.iter_mut().find(|r| r.id == repo_id) {
+        repo_state.set_operation_state(match result {
+            Ok(v) => Loadable::Ready(v),
+            Err(e) => Loadable::Error(e.to_string()),
+        });
+    }
+    Vec::new()
+}
+
 pub(super) fn merge_commit_message_loaded(
     state: &mut AppState,
     repo_id: crate::model::RepoId,
@@ -1,11 +1,12 @@
 use super::effects::append_ensure_sidebar_data_effects;
 use super::util::{
     SelectedConflictTarget, append_refresh_full_effects, append_refresh_primary_effects,
-    append_start_conflict_target_reload, append_start_current_conflict_target_reload,
-    clear_banner_error_for_repo, dedup_paths_in_order, format_failure_summary,
-    handle_session_persist_result, normalize_repo_path, push_diagnostic, push_notification,
-    refresh_full_effect_capacity, refresh_full_effects, refresh_primary_effect_capacity,
-    selected_conflict_target, selected_diff_load_plan,
+    append_requested_status_refresh_effects, append_start_conflict_target_reload,
+    append_start_current_conflict_target_reload, clear_banner_error_for_repo, dedup_paths_in_order,
+    diff_reload_effects, format_failure_summary, handle_session_persist_result,
+    normalize_repo_path, push_diagnostic, push_notification, refresh_full_effect_capacity,
+    refresh_full_effects, refresh_primary_effect_capacity, selected_conflict_target,
+    selected_diff_load_plan,
 };
 use crate::model::{
     AppNotificationKind, AppState, CloneOpState, CloneOpStatus, CloneProgressMeter,
@@ -16,7 +17,9 @@ use crate::msg::Effect;
 use crate::session;
 use gitcomet_core::domain::RepoSpec;
 use gitcomet_core::error::{Error, ErrorKind};
-use gitcomet_core::services::{CommandOutput, GitRepository};
+use gitcomet_core::services::{
+    CommandOutput, DiffIgnoreOptions, DiffWhitespaceIgnore, GitRepository, RenameDetection,
+};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use smallvec::SmallVec;
 use std::collections::VecDeque;
@@ -135,6 +138,9 @@ pub(super) fn open_repo(id_alloc: &AtomicU64, state: &mut AppState, path: PathBu
     let repo_id = RepoId(id_alloc.fetch_add(1, Ordering::Relaxed));
     let spec = RepoSpec { workdir: path };
     let session_preferences = session::load_repo_session_preferences();
+    if state.rename_detection.is_none() {
+        state.rename_detection = session_preferences.rename_detection;
+    }
     let workdir_key = session::path_storage_key(&spec.workdir);
     let saved_history_mode = session_preferences
         .repo_history_modes
@@ -150,6 +156,7 @@ pub(super) fn open_repo(id_alloc: &AtomicU64, state: &mut AppState, path: PathBu
         .or(session_preferences.default_history_mode)
         .unwrap_or_default();
 
+    let diff_whitespace = state.diff_whitespace;
     state.repos.push({
         let mut repo_state = crate::model::RepoState::new_opening(repo_id, spec.clone());
         repo_state.history_state.history_scope = history_mode;
@@ -160,6 +167,13 @@ pub(super) fn open_repo(id_alloc: &AtomicU64, state: &mut AppState, path: PathBu
         {
             repo_state.fetch_prune_deleted_remote_tracking_branches = enabled;
         }
+        if let Some(options) = session_preferences
+            .repo_diff_ignore_options
+            .get(&workdir_key)
+        {
+            repo_state.diff_ignore_options = options.clone();
+        }
+        repo_state.diff_ignore_options.whitespace = diff_whitespace;
         repo_state.last_active_at = Some(now);
         repo_state
     });
@@ -200,6 +214,9 @@ pub(super) fn restore_session(
     state.active_repo = None;
 
     let session_preferences = session::load_repo_session_preferences();
+    if state.rename_detection.is_none() {
+        state.rename_detection = session_preferences.rename_detection;
+    }
     let default_history_mode = session_preferences.default_history_mode.unwrap_or_default();
     let active_repo = active_repo.map(normalize_repo_path);
     let mut active_repo_id: Option<RepoId> = None;
@@ -247,6 +264,13 @@ pub(super) fn restore_session(
             {
                 repo_state.fetch_prune_deleted_remote_tracking_branches = enabled;
             }
+            if let Some(options) = session_preferences
+                .repo_diff_ignore_options
+                .get(&workdir_key)
+            {
+                repo_state.diff_ignore_options = options.clone();
+            }
+            repo_state.diff_ignore_options.whitespace = state.diff_whitespace;
             repo_state
         });
         if saved_history_mode.is_none() {
@@ -471,6 +495,67 @@ pub(super) fn set_fetch_prune_deleted_remote_tracking_branches(
     Vec::new()
 }
 
+pub(super) fn set_diff_ignore_options(
+    repos: &mut HashMap<RepoId, Arc<dyn GitRepository>>,
+    state: &mut AppState,
+    repo_id: RepoId,
+    mut options: DiffIgnoreOptions,
+) -> Vec<Effect> {
+    let Some(repo_ix) = state.repos.iter().position(|r| r.id == repo_id) else {
+        return Vec::new();
+    };
+    // Whitespace follows the app-wide mode, not the per-repository options.
+    options.whitespace = state.diff_whitespace;
+
+    let repo_state = &mut state.repos[repo_ix];
+    if repo_state.diff_ignore_options == options {
+        return Vec::new();
+    }
+    repo_state.diff_ignore_options = options.clone();
+    if let Some(repo) = repos.get(&repo_id) {
+        repo.set_diff_ignore_options(options.clone());
+    }
+
+    // The open patch diff was produced with the previous options.
+    let effects = match repo_state.diff_state.diff_target.clone() {
+        Some(target) => diff_reload_effects(repo_state, repo_id, target),
+        None => Vec::new(),
+    };
+    let workdir = repo_state.spec.workdir.clone();
+
+    let persist_result = session::persist_repo_diff_ignore_options(&workdir, &options);
+    handle_session_persist_result(
+        state,
+        Some(repo_id),
+        "updating diff ignore options",
+        persist_result,
+    );
+    effects
+}
+
+pub(super) fn set_diff_whitespace(
+    repos: &mut HashMap<RepoId, Arc<dyn GitRepository>>,
+    state: &mut AppState,
+    whitespace: DiffWhitespaceIgnore,
+) -> Vec<Effect> {
+    if state.diff_whitespace == whitespace {
+        return Vec::new();
+    }
+    state.diff_whitespace = whitespace;
+
+    let mut effects = Vec::new();
+    for repo_state in &mut state.repos {
+        repo_state.diff_ignore_options.whitespace = whitespace;
+        if let Some(repo) = repos.get(&repo_state.id) {
+            repo.set_diff_ignore_options(repo_state.diff_ignore_options.clone());
+        }
+        if let Some(target) = repo_state.diff_state.diff_target.clone() {
+            effects.extend(diff_reload_effects(repo_state, repo_state.id, target));
+        }
+    }
+    effects
+}
+
 pub(super) fn reorder_repo_tabs(
     state: &mut AppState,
     repo_id: RepoId,
@@ -677,6 +762,14 @@ pub(super) fn repo_opened_ok(
     spec: RepoSpec,
     repo: Arc<dyn GitRepository>,
 ) -> Vec<Effect> {
+    if let Some(detection) = state.rename_detection {
+        repo.set_rename_detection(detection);
+    }
+    if let Some(repo_state) = state.repos.iter().find(|r| r.id == repo_id)
+        && !repo_state.diff_ignore_options.is_empty()
+    {
+        repo.set_diff_ignore_options(repo_state.diff_ignore_options.clone());
+    }
     repos.insert(repo_id, repo);
     let git_log_settings = state.git_log_settings;
 
@@ -708,16 +801,22 @@ pub(super) fn repo_opened_ok(
         repo_state.set_stashes(Loadable::NotLoaded);
         repo_state.reflog = Loadable::NotLoaded;
         repo_state.set_rebase_in_progress(Loadable::Loading);
+        repo_state.set_operation_state(Loadable::Loading);
         repo_state.set_merge_commit_message(Loadable::Loading);
         repo_state.history_state.file_history_path = None;
         repo_state.history_state.file_history = Loadable::NotLoaded;
         repo_state.history_state.blame_path = None;
         repo_state.history_state.blame_rev = None;
         repo_state.history_state.blame = Loadable::NotLoaded;
+        repo_state.history_state.line_history_path = None;
+        repo_state.history_state.line_history_range = None;
+        repo_state.history_state.line_history_rev = None;
+        repo_state.history_state.line_history = Loadable::NotLoaded;
         repo_state.set_worktrees(Loadable::NotLoaded);
         repo_state.set_submodules(Loadable::NotLoaded);
         repo_state.set_selected_commit(None);
         repo_state.set_commit_details(Loadable::NotLoaded);
+        repo_state.set_commit_file_search(None);
         repo_state.set_diff_target(None);
         repo_state.diff_state.diff = Loadable::NotLoaded;
         repo_state.diff_state.diff_file = Loadable::NotLoaded;
@@ -754,6 +853,40 @@ pub(super) fn repo_opened_ok(
     Vec::new()
 }
 
+pub(super) fn set_rename_detection(
+    repos: &mut HashMap<RepoId, Arc<dyn GitRepository>>,
+    state: &mut AppState,
+    detection: RenameDetection,
+) -> Vec<Effect> {
+    if state.rename_detection == Some(detection) {
+        return Vec::new();
+    }
+    state.rename_detection = Some(detection);
+    for repo in repos.values() {
+        repo.set_rename_detection(detection);
+    }
+
+    // Rename pairing changes what status and commit details report, so refresh
+    // both for every open repository.
+    let mut effects = Vec::new();
+    for repo_state in &mut state.repos {
+        if !matches!(repo_state.open, Loadable::Ready(())) {
+            continue;
+        }
+        append_requested_status_refresh_effects(repo_state, &mut effects);
+        if let Some(commit_id) = repo_state.history_state.selected_commit.clone() {
+            effects.push(Effect::LoadCommitDetails {
+                repo_id: repo_state.id,
+                commit_id,
+            });
+        }
+    }
+
+    let persist_result = session::persist_rename_detection(detection);
+    handle_session_persist_result(state, None, "updating rename detection", persist_result);
+    effects
+}
+
 pub(super) fn repo_opened_err(
     repos: &mut HashMap<RepoId, Arc<dyn GitRepository>>,
     state: &mut AppState,
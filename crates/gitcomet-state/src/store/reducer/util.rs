@@ -7,9 +7,11 @@ use crate::msg::{ConflictAutosolveMode, ConflictAutosolveStats, Effect, RepoComm
 #[cfg(test)]
 use gitcomet_core::auth::stage_git_auth;
 use gitcomet_core::auth::{GitAuthKind, StagedGitAuth, clear_staged_git_auth};
-use gitcomet_core::domain::{DiffArea, DiffTarget, FileStatusKind};
+use gitcomet_core::domain::{DiffArea, DiffTarget, FileStatusKind, RepoOperationAction};
 use gitcomet_core::error::{Error, ErrorKind, GitFailure};
-use gitcomet_core::services::CommandOutput;
+use gitcomet_core::services::{
+    CommandOutput, FixupCommitKind, SubmoduleBatchCommand, SubmoduleCommandResult,
+};
 use rustc_hash::FxHashSet;
 use smallvec::{Array, SmallVec};
 use std::io;
@@ -134,7 +136,7 @@ pub(super) fn diff_target_is_svg(target: &DiffTarget) -> bool {
 
 fn diff_target_is_preview_only(repo_state: &RepoState, target: &DiffTarget) -> bool {
     match target {
-        DiffTarget::WorkingTree { path, area } => {
+        DiffTarget::WorkingTree { path, area, .. } => {
             let Some(entries) = repo_state.status_entries_for_area(*area) else {
                 return false;
             };
@@ -150,6 +152,7 @@ fn diff_target_is_preview_only(repo_state: &RepoState, target: &DiffTarget) -> b
         DiffTarget::Commit {
             commit_id,
             path: Some(path),
+            ..
         } => {
             let Loadable::Ready(details) = &repo_state.history_state.commit_details else {
                 return false;
@@ -173,7 +176,7 @@ fn diff_target_preview_text_side(
     target: &DiffTarget,
 ) -> Option<gitcomet_core::domain::DiffPreviewTextSide> {
     match target {
-        DiffTarget::WorkingTree { path, area } => {
+        DiffTarget::WorkingTree { path, area, .. } => {
             let entries = repo_state.status_entries_for_area(*area)?;
 
             entries.iter().find_map(|entry| {
@@ -186,6 +189,7 @@ fn diff_target_preview_text_side(
                     }
                     FileStatusKind::Modified
                     | FileStatusKind::Renamed
+                    | FileStatusKind::Copied
                     | FileStatusKind::Conflicted => None,
                 })?
             })
@@ -193,6 +197,7 @@ fn diff_target_preview_text_side(
         DiffTarget::Commit {
             commit_id,
             path: Some(path),
+            ..
         } => {
             let Loadable::Ready(details) = &repo_state.history_state.commit_details else {
                 return None;
@@ -209,6 +214,7 @@ fn diff_target_preview_text_side(
                     }
                     FileStatusKind::Modified
                     | FileStatusKind::Renamed
+                    | FileStatusKind::Copied
                     | FileStatusKind::Conflicted
                     | FileStatusKind::Untracked => None,
                 })?
@@ -286,7 +292,7 @@ pub(super) fn apply_selected_diff_load_plan_state(
 
 fn diff_target_is_submodule(repo_state: &RepoState, target: &DiffTarget) -> bool {
     match target {
-        DiffTarget::WorkingTree { path, area } => {
+        DiffTarget::WorkingTree { path, area, .. } => {
             let Some(entry) = repo_state.status_entry_for_path(*area, path) else {
                 return false;
             };
@@ -310,6 +316,7 @@ fn diff_target_is_submodule(repo_state: &RepoState, target: &DiffTarget) -> bool
         DiffTarget::Commit {
             commit_id,
             path: Some(path),
+            ..
         } => {
             let Loadable::Ready(details) = &repo_state.history_state.commit_details else {
                 return false;
@@ -359,7 +366,7 @@ pub(super) fn selected_conflict_target<'a>(
     repo_state: &RepoState,
     target: &'a DiffTarget,
 ) -> Option<SelectedConflictTarget<'a>> {
-    let DiffTarget::WorkingTree { path, area } = target else {
+    let DiffTarget::WorkingTree { path, area, .. } = target else {
         return None;
     };
     if *area != DiffArea::Unstaged {
@@ -830,6 +837,79 @@ pub(super) fn push_command_log(
     }
 }
 
+/// Logs each submodule of a bulk submodule command as its own entry.
+pub(super) fn push_submodule_batch_log(
+    repo_state: &mut RepoState,
+    command: &SubmoduleBatchCommand,
+    results: &[SubmoduleCommandResult],
+) {
+    const MAX_COMMAND_LOG: usize = 200;
+
+    for outcome in results {
+        let path = outcome.path.display();
+        let entry = match &outcome.result {
+            Ok(output) => CommandLogEntry {
+                time: SystemTime::now(),
+                ok: true,
+                command: output.command.clone(),
+                summary: match command {
+                    SubmoduleBatchCommand::Fetch => format!("Submodule fetched → {path}"),
+                    SubmoduleBatchCommand::Pull => format!("Submodule pulled → {path}"),
+                    SubmoduleBatchCommand::Git { .. } => {
+                        format!("{} → {path}", command.display())
+                    }
+                },
+                stdout: output.stdout.clone(),
+                stderr: output.stderr.clone(),
+            },
+            Err(error) => CommandLogEntry {
+                time: SystemTime::now(),
+                ok: false,
+                command: format!("{} in {path}", command.display()),
+                summary: format_failure_summary(&format!("Submodule {path}"), error),
+                stdout: String::new(),
+                stderr: format_error_for_user(error),
+            },
+        };
+        repo_state.command_log.push(entry);
+    }
+    if repo_state.command_log.len() > MAX_COMMAND_LOG {
+        let extra = repo_state.command_log.len() - MAX_COMMAND_LOG;
+        repo_state.command_log.drain(0..extra);
+    }
+}
+
+/// Overall outcome of a bulk submodule command. On success `stdout` lists the
+/// submodules one per line; otherwise the error names the ones that failed.
+pub(super) fn submodule_batch_outcome(
+    command: &SubmoduleBatchCommand,
+    results: &[SubmoduleCommandResult],
+) -> Result<CommandOutput, Error> {
+    let failed = results
+        .iter()
+        .filter(|outcome| outcome.result.is_err())
+        .map(|outcome| outcome.path.display().to_string())
+        .collect::<Vec<_>>();
+    if !failed.is_empty() {
+        return Err(Error::new(ErrorKind::Backend(format!(
+            "{} of {} submodules failed: {}",
+            failed.len(),
+            results.len(),
+            failed.join(", ")
+        ))));
+    }
+    Ok(CommandOutput {
+        command: format!("{} in each submodule", command.display()),
+        stdout: results
+            .iter()
+            .map(|outcome| outcome.path.display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        stderr: String::new(),
+        exit_code: Some(0),
+    })
+}
+
 pub(super) fn push_action_log(
     repo_state: &mut RepoState,
     ok: bool,
@@ -942,12 +1022,14 @@ fn summarize_command(
             RepoCommandKind::SetUpstreamBranch { .. } => "Set as tracking upstream",
             RepoCommandKind::UnsetUpstreamBranch { .. } => "Unlink upstream branch",
             RepoCommandKind::DeleteRemoteBranch { .. } => "Delete remote branch",
+            RepoCommandKind::RenameBranch { .. } => "Rename branch",
             RepoCommandKind::PushTag { .. } => "Push tag",
             RepoCommandKind::DeleteRemoteTag { .. } => "Delete remote tag",
             RepoCommandKind::Reset { .. } => "Reset",
-            RepoCommandKind::Rebase { .. } => "Rebase",
+            RepoCommandKind::Rebase { .. } | RepoCommandKind::AutosquashRebase => "Rebase",
             RepoCommandKind::RebaseContinue => "Rebase",
             RepoCommandKind::RebaseAbort => "Rebase",
+            RepoCommandKind::OperationAction { kind, .. } => kind.label(),
             RepoCommandKind::MergeAbort => "Merge",
             RepoCommandKind::CreateTag { .. } => "Tag",
             RepoCommandKind::DeleteTag { .. } => "Tag",
@@ -961,16 +1043,40 @@ fn summarize_command(
             RepoCommandKind::AcceptConflictDeletion { .. } => "Accept deletion",
             RepoCommandKind::CheckoutConflictBase { .. } => "Checkout base",
             RepoCommandKind::LaunchMergetool { .. } => "Mergetool",
+            RepoCommandKind::CheckoutConflictPaths { side, .. } => match side {
+                ConflictSide::Ours => "Checkout ours",
+                ConflictSide::Theirs => "Checkout theirs",
+            },
+            RepoCommandKind::AutosolveConflicts { .. } => "Autosolve",
             RepoCommandKind::SaveWorktreeFile { .. } => "Save file",
             RepoCommandKind::ExportPatch { .. } | RepoCommandKind::ApplyPatch { .. } => "Patch",
+            RepoCommandKind::ExportPatchSeries { .. }
+            | RepoCommandKind::ApplyPatchSeries { .. } => "Patch series",
+            RepoCommandKind::SaveRevisionPath { .. } => "Save from revision",
+            RepoCommandKind::CherryPickCommits { .. } => "Cherry-pick",
+            RepoCommandKind::RevertCommits { .. } => "Revert",
+            RepoCommandKind::SquashCommits { .. } => "Squash commits",
+            RepoCommandKind::RewordCommit { .. } => "Reword",
+            RepoCommandKind::CreateFixupCommit { kind, .. } => match kind {
+                FixupCommitKind::Fixup => "Fixup commit",
+                FixupCommitKind::Squash => "Squash commit",
+            },
             RepoCommandKind::AddWorktree { .. }
             | RepoCommandKind::RemoveWorktree { .. }
-            | RepoCommandKind::ForceRemoveWorktree { .. } => "Worktree",
+            | RepoCommandKind::ForceRemoveWorktree { .. }
+            | RepoCommandKind::LockWorktree { .. }
+            | RepoCommandKind::UnlockWorktree { .. }
+            | RepoCommandKind::MoveWorktree { .. }
+            | RepoCommandKind::PruneWorktrees => "Worktree",
             RepoCommandKind::AddSubmodule { .. }
             | RepoCommandKind::UpdateSubmodules { .. }
             | RepoCommandKind::LoadSubmodule { .. }
             | RepoCommandKind::ChangeSubmodulePointer { .. }
-            | RepoCommandKind::RemoveSubmodule { .. } => "Submodule",
+            | RepoCommandKind::RemoveSubmodule { .. }
+            | RepoCommandKind::SyncSubmodules
+            | RepoCommandKind::SetSubmoduleBranch { .. }
+            | RepoCommandKind::UpdateSubmoduleRemote { .. }
+            | RepoCommandKind::RunInSubmodules { .. } => "Submodule",
             RepoCommandKind::StageHunk | RepoCommandKind::UnstageHunk => "Hunk",
             RepoCommandKind::ApplyWorktreePatch { reverse } => {
                 if *reverse {
@@ -1107,6 +1213,18 @@ fn summarize_command(
         RepoCommandKind::DeleteRemoteBranch { remote, branch } => {
             format!("Remote branch {remote}/{branch}: Deleted")
         }
+        RepoCommandKind::RenameBranch {
+            name,
+            new_name,
+            upstream,
+            ..
+        } => match upstream {
+            Some(upstream) => format!(
+                "Branch {name}: Renamed to {new_name}, tracking {}/{new_name}",
+                upstream.remote
+            ),
+            None => format!("Branch {name}: Renamed to {new_name}"),
+        },
         RepoCommandKind::PushTag { remote, name } => {
             if output.stderr.contains("Everything up-to-date") {
                 format!("Tag {name} → {remote}: Already up-to-date")
@@ -1130,6 +1248,18 @@ fn summarize_command(
         RepoCommandKind::LaunchMergetool { path } => {
             format!("Mergetool: Resolved {}", path.display())
         }
+        RepoCommandKind::CheckoutConflictPaths { paths, side } => match side {
+            ConflictSide::Ours => format!("Resolved {} files using ours", paths.len()),
+            ConflictSide::Theirs => format!("Resolved {} files using theirs", paths.len()),
+        },
+        RepoCommandKind::AutosolveConflicts { paths } => {
+            let resolved = output
+                .stdout
+                .lines()
+                .filter(|line| line.starts_with("Resolved "))
+                .count();
+            format!("Autosolve: Resolved {resolved} of {} files", paths.len())
+        }
         RepoCommandKind::SaveWorktreeFile { path, stage } => {
             if *stage {
                 format!("Saved and staged → {}", path.display())
@@ -1146,8 +1276,17 @@ fn summarize_command(
             format!("Reset (--{mode}) {target}: Completed")
         }
         RepoCommandKind::Rebase { onto } => format!("Rebase onto {onto}: Completed"),
+        RepoCommandKind::AutosquashRebase => "Autosquash onto upstream: Completed".to_string(),
         RepoCommandKind::RebaseContinue => "Rebase: Continued".to_string(),
         RepoCommandKind::RebaseAbort => "Rebase: Aborted".to_string(),
+        RepoCommandKind::OperationAction { kind, action } => {
+            let done = match action {
+                RepoOperationAction::Continue => "Continued",
+                RepoOperationAction::Skip => "Skipped",
+                RepoOperationAction::Abort => "Aborted",
+            };
+            format!("{}: {done}", kind.label())
+        }
         RepoCommandKind::MergeAbort => "Merge: Aborted".to_string(),
         RepoCommandKind::CreateTag { name, target } => format!("Tag {name} → {target}: Created"),
         RepoCommandKind::DeleteTag { name } => format!("Tag {name}: Deleted"),
@@ -1163,7 +1302,52 @@ fn summarize_command(
         RepoCommandKind::ExportPatch { dest, .. } => {
             format!("Patch exported → {}", dest.display())
         }
+        RepoCommandKind::ExportPatchSeries {
+            commit_ids,
+            dest,
+            cover_letter,
+        } => {
+            if *cover_letter {
+                format!(
+                    "{} patches with cover letter exported → {}",
+                    commit_ids.len(),
+                    dest.display()
+                )
+            } else {
+                format!("{} patches exported → {}", commit_ids.len(), dest.display())
+            }
+        }
+        RepoCommandKind::CherryPickCommits { commit_ids } => {
+            format!("Cherry-picked {} commits", commit_ids.len())
+        }
+        RepoCommandKind::RevertCommits { commit_ids } => {
+            format!("Reverted {} commits", commit_ids.len())
+        }
+        RepoCommandKind::SquashCommits { commit_ids } => {
+            format!("Squashed {} commits into one", commit_ids.len())
+        }
+        RepoCommandKind::RewordCommit { commit_id, .. } => {
+            let sha = commit_id.as_ref();
+            format!("Reworded {}", sha.get(0..8).unwrap_or(sha))
+        }
+        RepoCommandKind::CreateFixupCommit { commit_id, kind } => {
+            let marker = match kind {
+                FixupCommitKind::Fixup => "fixup!",
+                FixupCommitKind::Squash => "squash!",
+            };
+            let sha = commit_id.as_ref();
+            format!(
+                "Created {marker} commit for {}",
+                sha.get(0..8).unwrap_or(sha)
+            )
+        }
         RepoCommandKind::ApplyPatch { patch } => format!("Patch applied → {}", patch.display()),
+        RepoCommandKind::ApplyPatchSeries { source, .. } => {
+            format!("Patch series applied → {}", source.display())
+        }
+        RepoCommandKind::SaveRevisionPath { rev, dest, .. } => {
+            format!("Saved from {rev} → {}", dest.display())
+        }
         RepoCommandKind::AddWorktree { path, reference } => {
             if let Some(reference) = reference {
                 format!("Worktree added → {} ({reference})", path.display())
@@ -1177,6 +1361,28 @@ fn summarize_command(
         RepoCommandKind::ForceRemoveWorktree { path } => {
             format!("Worktree force removed → {}", path.display())
         }
+        RepoCommandKind::LockWorktree { path, .. } => {
+            format!("Worktree locked → {}", path.display())
+        }
+        RepoCommandKind::UnlockWorktree { path } => {
+            format!("Worktree unlocked → {}", path.display())
+        }
+        RepoCommandKind::MoveWorktree { new_path, .. } => {
+            format!("Worktree moved → {}", new_path.display())
+        }
+        RepoCommandKind::PruneWorktrees => {
+            let pruned = output
+                .stdout
+                .lines()
+                .chain(output.stderr.lines())
+                .filter(|line| line.starts_with("Removing "))
+                .count();
+            match pruned {
+                0 => "Worktrees: Nothing to prune".to_string(),
+                1 => "Worktrees: Pruned 1 stale worktree".to_string(),
+                n => format!("Worktrees: Pruned {n} stale worktrees"),
+            }
+        }
         RepoCommandKind::AddSubmodule { path, .. } => {
             format!("Submodule added → {}", path.display())
         }
@@ -1193,6 +1399,39 @@ fn summarize_command(
         RepoCommandKind::RemoveSubmodule { path } => {
             format!("Submodule removed → {}", path.display())
         }
+        RepoCommandKind::SyncSubmodules => "Submodules: URLs synchronized".to_string(),
+        RepoCommandKind::SetSubmoduleBranch { path, branch } => match branch {
+            Some(branch) => format!("Submodule tracks {branch} → {}", path.display()),
+            None => format!(
+                "Submodule tracks the remote default branch → {}",
+                path.display()
+            ),
+        },
+        RepoCommandKind::UpdateSubmoduleRemote { path } => {
+            format!("Submodule updated from remote → {}", path.display())
+        }
+        RepoCommandKind::RunInSubmodules { command } => {
+            let count = output
+                .stdout
+                .lines()
+                .filter(|line| !line.is_empty())
+                .count();
+            let noun = if count == 1 {
+                "submodule"
+            } else {
+                "submodules"
+            };
+            match (count, command) {
+                (0, _) => "Submodules: No initialized submodules".to_string(),
+                (_, SubmoduleBatchCommand::Fetch) => {
+                    format!("Submodules: Fetched {count} {noun}")
+                }
+                (_, SubmoduleBatchCommand::Pull) => format!("Submodules: Pulled {count} {noun}"),
+                (_, SubmoduleBatchCommand::Git { .. }) => {
+                    format!("Submodules: Ran {} in {count} {noun}", command.display())
+                }
+            }
+        }
         RepoCommandKind::StageHunk => "Hunk staged".to_string(),
         RepoCommandKind::UnstageHunk => "Hunk unstaged".to_string(),
         RepoCommandKind::ApplyWorktreePatch { reverse } => {
@@ -1422,7 +1661,7 @@ mod tests {
     use super::*;
     use crate::model::{AppNotificationKind, DiagnosticKind};
     use crate::msg::RepoCommandKind;
-    use gitcomet_core::domain::{CommitId, DiffArea, DiffTarget, RepoSpec};
+    use gitcomet_core::domain::{CommitId, DiffArea, DiffTarget, RepoOperationKind, RepoSpec};
     use gitcomet_core::error::{GitFailure, GitFailureId};
     use gitcomet_core::services::{PullMode, RemoteUrlKind, ResetMode};
     use std::path::Path;
@@ -1463,6 +1702,7 @@ mod tests {
         let png = DiffTarget::WorkingTree {
             path: PathBuf::from("img.PNG"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let png_effects = diff_reload_effects(&repo_state, repo_id, png.clone());
         assert!(diff_target_wants_image_preview(&png));
@@ -1474,6 +1714,7 @@ mod tests {
         let svg = DiffTarget::WorkingTree {
             path: PathBuf::from("diagram.svg"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let svg_effects = diff_reload_effects(&repo_state, repo_id, svg.clone());
         assert!(diff_target_wants_image_preview(&svg));
@@ -1484,6 +1725,7 @@ mod tests {
         let text_no_ext = DiffTarget::WorkingTree {
             path: PathBuf::from("README"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         assert!(!diff_target_wants_image_preview(&text_no_ext));
         assert_eq!(
@@ -1494,6 +1736,7 @@ mod tests {
         let commit_without_path = DiffTarget::Commit {
             commit_id: CommitId("abc123".into()),
             path: None,
+            old_path: None,
         };
         assert!(!diff_target_wants_image_preview(&commit_without_path));
         assert!(!diff_target_is_svg(&commit_without_path));
@@ -1744,6 +1987,33 @@ mod tests {
                 },
                 "Delete remote branch",
             ),
+            (
+                RepoCommandKind::RenameBranch {
+                    name: "old".into(),
+                    new_name: "new".into(),
+                    force: false,
+                    upstream: None,
+                },
+                "Rename branch",
+            ),
+            (
+                RepoCommandKind::CherryPickCommits {
+                    commit_ids: vec![CommitId("a".into()), CommitId("b".into())],
+                },
+                "Cherry-pick",
+            ),
+            (
+                RepoCommandKind::RevertCommits {
+                    commit_ids: vec![CommitId("a".into())],
+                },
+                "Revert",
+            ),
+            (
+                RepoCommandKind::SquashCommits {
+                    commit_ids: vec![CommitId("a".into()), CommitId("b".into())],
+                },
+                "Squash commits",
+            ),
             (
                 RepoCommandKind::PushTag {
                     remote: "origin".into(),
@@ -1771,8 +2041,67 @@ mod tests {
                 },
                 "Rebase",
             ),
+            (RepoCommandKind::AutosquashRebase, "Rebase"),
+            (
+                RepoCommandKind::RewordCommit {
+                    commit_id: CommitId("a".into()),
+                    message: "new".into(),
+                },
+                "Reword",
+            ),
+            (
+                RepoCommandKind::ApplyPatchSeries {
+                    source: PathBuf::from("series.mbox"),
+                    three_way: true,
+                },
+                "Patch series",
+            ),
+            (
+                RepoCommandKind::SaveRevisionPath {
+                    rev: "abc".into(),
+                    path: PathBuf::from("src"),
+                    dest: PathBuf::from("/tmp/src"),
+                },
+                "Save from revision",
+            ),
+            (
+                RepoCommandKind::MoveWorktree {
+                    path: PathBuf::from("/tmp/a"),
+                    new_path: PathBuf::from("/tmp/b"),
+                },
+                "Worktree",
+            ),
+            (RepoCommandKind::PruneWorktrees, "Worktree"),
+            (RepoCommandKind::SyncSubmodules, "Submodule"),
+            (
+                RepoCommandKind::RunInSubmodules {
+                    command: SubmoduleBatchCommand::Pull,
+                },
+                "Submodule",
+            ),
+            (
+                RepoCommandKind::CreateFixupCommit {
+                    commit_id: CommitId("a".into()),
+                    kind: FixupCommitKind::Fixup,
+                },
+                "Fixup commit",
+            ),
+            (
+                RepoCommandKind::CreateFixupCommit {
+                    commit_id: CommitId("a".into()),
+                    kind: FixupCommitKind::Squash,
+                },
+                "Squash commit",
+            ),
             (RepoCommandKind::RebaseContinue, "Rebase"),
             (RepoCommandKind::RebaseAbort, "Rebase"),
+            (
+                RepoCommandKind::OperationAction {
+                    kind: RepoOperationKind::CherryPick,
+                    action: RepoOperationAction::Skip,
+                },
+                "Cherry-pick",
+            ),
             (RepoCommandKind::MergeAbort, "Merge"),
             (
                 RepoCommandKind::CreateTag {
@@ -2022,6 +2351,109 @@ mod tests {
         );
         assert_eq!(rebase_continue_summary, "Rebase: Continued");
 
+        let (_, autosquash_summary) = summarize_command(
+            &RepoCommandKind::AutosquashRebase,
+            &command_output("git rebase --autosquash @{upstream}", "", ""),
+            true,
+            None,
+        );
+        assert_eq!(autosquash_summary, "Autosquash onto upstream: Completed");
+
+        let (_, fixup_summary) = summarize_command(
+            &RepoCommandKind::CreateFixupCommit {
+                commit_id: CommitId("0123456789abcdef".into()),
+                kind: FixupCommitKind::Fixup,
+            },
+            &command_output("git commit --fixup=0123456789abcdef", "", ""),
+            true,
+            None,
+        );
+        assert_eq!(fixup_summary, "Created fixup! commit for 01234567");
+
+        let (_, reword_summary) = summarize_command(
+            &RepoCommandKind::RewordCommit {
+                commit_id: CommitId("0123456789abcdef".into()),
+                message: "Better subject".into(),
+            },
+            &command_output("Reword 01234567", "Rewrote 2 commit(s)", ""),
+            true,
+            None,
+        );
+        assert_eq!(reword_summary, "Reworded 01234567");
+
+        let (_, prune_worktrees_summary) = summarize_command(
+            &RepoCommandKind::PruneWorktrees,
+            &command_output(
+                "git worktree prune --verbose",
+                "",
+                "Removing worktrees/a: gitdir file points to non-existent location\n\
+                 Removing worktrees/b: gitdir file points to non-existent location\n",
+            ),
+            true,
+            None,
+        );
+        assert_eq!(
+            prune_worktrees_summary,
+            "Worktrees: Pruned 2 stale worktrees"
+        );
+
+        let (_, submodule_branch_summary) = summarize_command(
+            &RepoCommandKind::SetSubmoduleBranch {
+                path: PathBuf::from("libs/a"),
+                branch: Some("stable".into()),
+            },
+            &command_output("git submodule set-branch", "", ""),
+            true,
+            None,
+        );
+        assert_eq!(submodule_branch_summary, "Submodule tracks stable → libs/a");
+
+        let (_, submodule_foreach_summary) = summarize_command(
+            &RepoCommandKind::RunInSubmodules {
+                command: SubmoduleBatchCommand::Git {
+                    args: vec!["gc".into()],
+                },
+            },
+            &command_output("git gc in each submodule", "libs/a\nlibs/b", ""),
+            true,
+            None,
+        );
+        assert_eq!(
+            submodule_foreach_summary,
+            "Submodules: Ran git gc in 2 submodules"
+        );
+
+        let (_, format_patch_summary) = summarize_command(
+            &RepoCommandKind::ExportPatchSeries {
+                commit_ids: vec![CommitId("a".into()), CommitId("b".into())],
+                dest: PathBuf::from("outgoing"),
+                cover_letter: true,
+            },
+            &command_output(
+                "git format-patch (2 commits)",
+                "outgoing/0000-cover-letter.patch",
+                "",
+            ),
+            true,
+            None,
+        );
+        assert_eq!(
+            format_patch_summary,
+            "2 patches with cover letter exported → outgoing"
+        );
+
+        let (_, save_revision_summary) = summarize_command(
+            &RepoCommandKind::SaveRevisionPath {
+                rev: "v1.0".into(),
+                path: PathBuf::from("docs"),
+                dest: PathBuf::from("/tmp/docs"),
+            },
+            &command_output("Save v1.0:docs", "Saved 3 files to /tmp/docs", ""),
+            true,
+            None,
+        );
+        assert_eq!(save_revision_summary, "Saved from v1.0 → /tmp/docs");
+
         let (_, rebase_abort_summary) = summarize_command(
             &RepoCommandKind::RebaseAbort,
             &command_output("git rebase --abort", "", ""),
@@ -2030,6 +2462,17 @@ mod tests {
         );
         assert_eq!(rebase_abort_summary, "Rebase: Aborted");
 
+        let (_, revert_continue_summary) = summarize_command(
+            &RepoCommandKind::OperationAction {
+                kind: RepoOperationKind::Revert,
+                action: RepoOperationAction::Continue,
+            },
+            &command_output("git revert --continue", "", ""),
+            true,
+            None,
+        );
+        assert_eq!(revert_continue_summary, "Revert: Continued");
+
         let (_, merge_abort_summary) = summarize_command(
             &RepoCommandKind::MergeAbort,
             &command_output("git merge --abort", "", ""),
@@ -3,7 +3,7 @@ use crate::model::{CloneOpStatus, CloneProgressStage, DiagnosticKind, Loadable,
 use crate::msg::{Effect, RepoActionKind, RepoCommandKind};
 use gitcomet_core::domain::{
     Branch, Commit, CommitDetails, CommitId, DiffArea, DiffTarget, LogCursor, LogPage, LogScope,
-    ReflogEntry, Remote, RemoteBranch, RepoSpec, RepoStatus, StashEntry,
+    ReflogEntry, Remote, RemoteBranch, RepoSpec, RepoStatus, StashEntry, Upstream,
 };
 use gitcomet_core::error::{Error, ErrorKind};
 use gitcomet_core::path_utils::canonicalize_or_original;
@@ -625,6 +625,268 @@ fn worktree_commands_reload_worktrees_on_success() {
     );
 }
 
+#[test]
+fn worktree_lock_move_and_prune_track_in_flight_and_reload() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+
+    let repo_id = RepoId(1);
+    repos.insert(repo_id, Arc::new(DummyRepo::new("/tmp/repo")));
+    state.repos.push(RepoState::new_opening(
+        repo_id,
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.repos.push(RepoState::new_opening(
+        RepoId(2),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/worktree"),
+        },
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::LockWorktree {
+            repo_id,
+            path: PathBuf::from("/tmp/worktree"),
+            reason: Some("on a usb drive".to_string()),
+        },
+    );
+    assert_eq!(state.repos[0].worktrees_in_flight, 1);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::LockWorktree { repo_id: RepoId(1), reason: Some(reason), .. }]
+            if reason == "on a usb drive"
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::MoveWorktree {
+            repo_id,
+            path: PathBuf::from("/tmp/worktree"),
+            new_path: PathBuf::from("/tmp/moved"),
+        },
+    );
+    assert_eq!(state.repos[0].worktrees_in_flight, 2);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::MoveWorktree { repo_id: RepoId(1), new_path, .. }]
+            if new_path == &PathBuf::from("/tmp/moved")
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RepoCommandFinished {
+            repo_id,
+            command: RepoCommandKind::MoveWorktree {
+                path: PathBuf::from("/tmp/worktree"),
+                new_path: PathBuf::from("/tmp/moved"),
+            },
+            result: Ok(CommandOutput::empty_success("git worktree move")),
+        }),
+    );
+    assert_eq!(state.repos[0].worktrees_in_flight, 1);
+    assert_eq!(state.repos.len(), 1, "tab at the old path should be closed");
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::LoadWorktrees { repo_id: id } if *id == repo_id))
+    );
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::PruneWorktrees { repo_id },
+    );
+    assert_eq!(state.repos[0].worktrees_in_flight, 2);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::PruneWorktrees { repo_id: RepoId(1) }]
+    ));
+}
+
+#[test]
+fn worktree_summaries_loaded_bumps_sidebar_rev() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+
+    let repo_id = RepoId(1);
+    state.repos.push(RepoState::new_opening(
+        repo_id,
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    let sidebar_rev = state.repos[0].branch_sidebar_rev;
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::WorktreeSummariesLoaded {
+            repo_id,
+            summaries: vec![gitcomet_core::domain::WorktreeSummary {
+                path: PathBuf::from("/tmp/worktree"),
+                dirty_files: 3,
+                upstream: Some(gitcomet_core::domain::UpstreamDivergence {
+                    ahead: 1,
+                    behind: 0,
+                }),
+            }],
+        }),
+    );
+
+    assert_ne!(state.repos[0].branch_sidebar_rev, sidebar_rev);
+    let summary = state.repos[0]
+        .worktree_summary(Path::new("/tmp/worktree"))
+        .expect("summary stored");
+    assert_eq!(summary.dirty_files, 3);
+    assert!(
+        state.repos[0]
+            .worktree_summary(Path::new("/tmp/repo"))
+            .is_none()
+    );
+}
+
+#[test]
+fn squash_commits_tracks_local_action_and_clears_rewritten_diff_target() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+
+    let repo_id = RepoId(1);
+    repos.insert(repo_id, Arc::new(DummyRepo::new("/tmp/repo")));
+    state.repos.push(RepoState::new_opening(
+        repo_id,
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    let commit_ids = vec![CommitId("aaaa".into()), CommitId("bbbb".into())];
+    state.repos[0].diff_state.diff_target = Some(DiffTarget::Commit {
+        commit_id: commit_ids[0].clone(),
+        path: None,
+        old_path: None,
+    });
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SquashCommits {
+            repo_id,
+            commit_ids: commit_ids.clone(),
+        },
+    );
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::SquashCommits { repo_id: RepoId(1), commit_ids: ids }] if ids == &commit_ids
+    ));
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RepoCommandFinished {
+            repo_id,
+            command: RepoCommandKind::SquashCommits { commit_ids },
+            result: Ok(CommandOutput::empty_success("git commit-tree")),
+        }),
+    );
+    assert_eq!(state.repos[0].local_actions_in_flight, 0);
+    assert!(state.repos[0].diff_state.diff_target.is_none());
+}
+
+#[test]
+fn rename_branch_counts_upstream_move_as_push_and_reloads_worktrees() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+
+    let repo_id = RepoId(1);
+    repos.insert(repo_id, Arc::new(DummyRepo::new("/tmp/repo")));
+    state.repos.push(RepoState::new_opening(
+        repo_id,
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.repos[0].set_worktrees(Loadable::Ready(Vec::new()));
+
+    let upstream = Upstream {
+        remote: "origin".to_string(),
+        branch: "feature".to_string(),
+    };
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::RenameBranch {
+            repo_id,
+            name: "feature".to_string(),
+            new_name: "topic".to_string(),
+            force: false,
+            upstream: Some(upstream.clone()),
+        },
+    );
+    assert_eq!(state.repos[0].push_in_flight, 1);
+    assert_eq!(state.repos[0].local_actions_in_flight, 0);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::RenameBranch { new_name, upstream: Some(_), auth: None, .. }]
+            if new_name == "topic"
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RepoCommandFinished {
+            repo_id,
+            command: RepoCommandKind::RenameBranch {
+                name: "feature".to_string(),
+                new_name: "topic".to_string(),
+                force: false,
+                upstream: Some(upstream),
+            },
+            result: Ok(CommandOutput::empty_success("git branch -m feature topic")),
+        }),
+    );
+    assert_eq!(state.repos[0].push_in_flight, 0);
+    assert!(state.repos[0].worktrees.is_loading());
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::LoadWorktrees { repo_id: id } if *id == repo_id))
+    );
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::RenameBranch {
+            repo_id,
+            name: "topic".to_string(),
+            new_name: "main".to_string(),
+            force: true,
+            upstream: None,
+        },
+    );
+    assert_eq!(state.repos[0].push_in_flight, 0);
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+}
+
 #[test]
 fn worktree_remove_closes_tab_for_removed_worktree() {
     let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
@@ -785,6 +1047,8 @@ fn selected_submodule_command_reloads_selected_summary() {
                 path: command_path.to_path_buf(),
                 kind: FileStatusKind::Modified,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
             staged: Vec::new(),
         })));
@@ -797,6 +1061,7 @@ fn selected_submodule_command_reloads_selected_summary() {
         let target = DiffTarget::WorkingTree {
             path: command_path.to_path_buf(),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         repo.diff_state.diff_target = Some(target.clone());
         repo.diff_state.submodule_summary = Loadable::Ready(Arc::new(SubmoduleDiffSummary {
@@ -813,6 +1078,7 @@ fn selected_submodule_command_reloads_selected_summary() {
         let inline_target = DiffTarget::WorkingTree {
             path: PathBuf::from("inner.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         repo.diff_state.inline_submodule_diff = Some(crate::model::InlineSubmoduleDiffState {
             submodule_repo_path: PathBuf::from("/tmp/repo/vendor/lib"),
@@ -853,6 +1119,10 @@ fn selected_submodule_command_reloads_selected_summary() {
             path: path.clone(),
             reference: "main".to_string(),
         },
+        RepoCommandKind::UpdateSubmoduleRemote { path: path.clone() },
+        RepoCommandKind::RunInSubmodules {
+            command: gitcomet_core::services::SubmoduleBatchCommand::Pull,
+        },
     ] {
         let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
         let id_alloc = AtomicU64::new(1);
@@ -881,6 +1151,106 @@ fn selected_submodule_command_reloads_selected_summary() {
     }
 }
 
+#[test]
+fn submodule_batch_logs_each_submodule_and_reports_failures() {
+    use gitcomet_core::services::{SubmoduleBatchCommand, SubmoduleCommandResult};
+
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+
+    let repo_id = RepoId(1);
+    state.repos.push(RepoState::new_opening(
+        repo_id,
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::RunInSubmodules {
+            repo_id,
+            command: SubmoduleBatchCommand::Fetch,
+        },
+    );
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::RunInSubmodules {
+            repo_id: RepoId(1),
+            command: SubmoduleBatchCommand::Fetch,
+            auth: None,
+        }]
+    ));
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::SubmoduleBatchFinished {
+            repo_id,
+            command: SubmoduleBatchCommand::Fetch,
+            result: Ok(vec![
+                SubmoduleCommandResult {
+                    path: PathBuf::from("libs/a"),
+                    result: Ok(CommandOutput::empty_success("git fetch in libs/a")),
+                },
+                SubmoduleCommandResult {
+                    path: PathBuf::from("libs/b"),
+                    result: Err(Error::new(ErrorKind::Backend(
+                        "could not reach origin".to_string(),
+                    ))),
+                },
+            ]),
+        }),
+    );
+
+    let repo = &state.repos[0];
+    assert_eq!(repo.local_actions_in_flight, 0);
+    assert_eq!(repo.command_log.len(), 3);
+    assert!(repo.command_log[0].ok);
+    assert_eq!(repo.command_log[0].command, "git fetch in libs/a");
+    assert_eq!(repo.command_log[0].summary, "Submodule fetched → libs/a");
+    assert!(!repo.command_log[1].ok);
+    assert_eq!(repo.command_log[1].stderr, "could not reach origin");
+    assert!(!repo.command_log[2].ok);
+    assert!(
+        repo.command_log[2]
+            .summary
+            .contains("1 of 2 submodules failed: libs/b")
+    );
+    assert!(repo.last_error.is_some());
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::SubmoduleBatchFinished {
+            repo_id,
+            command: SubmoduleBatchCommand::Fetch,
+            result: Ok(vec![SubmoduleCommandResult {
+                path: PathBuf::from("libs/a"),
+                result: Ok(CommandOutput::empty_success("git fetch in libs/a")),
+            }]),
+        }),
+    );
+
+    let repo = &state.repos[0];
+    assert!(repo.last_error.is_none());
+    assert_eq!(
+        repo.command_log.last().map(|entry| entry.summary.as_str()),
+        Some("Submodules: Fetched 1 submodule")
+    );
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::LoadSubmodules { repo_id: id } if *id == repo_id))
+    );
+}
+
 #[test]
 fn merge_ref_emits_effect() {
     let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
@@ -969,6 +1339,328 @@ fn rebase_emits_effect() {
     ));
 }
 
+#[test]
+fn fixup_commit_and_autosquash_emit_effects() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    state.repos.push(RepoState::new_opening(
+        RepoId(1),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.active_repo = Some(RepoId(1));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::CreateFixupCommit {
+            repo_id: RepoId(1),
+            commit_id: CommitId("abc".into()),
+            kind: gitcomet_core::services::FixupCommitKind::Squash,
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::CreateFixupCommit {
+            repo_id: RepoId(1),
+            commit_id,
+            kind: gitcomet_core::services::FixupCommitKind::Squash,
+        }] if commit_id.as_ref() == "abc"
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::AutosquashRebase { repo_id: RepoId(1) },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::AutosquashRebase { repo_id: RepoId(1) }]
+    ));
+    assert_eq!(state.repos[0].local_actions_in_flight, 2);
+}
+
+#[test]
+fn reword_commit_emits_effect_and_tracks_local_action() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    state.repos.push(RepoState::new_opening(
+        RepoId(1),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.active_repo = Some(RepoId(1));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::RewordCommit {
+            repo_id: RepoId(1),
+            commit_id: CommitId("abc".into()),
+            message: "Better subject\n\nBody".into(),
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::RewordCommit {
+            repo_id: RepoId(1),
+            commit_id,
+            message,
+        }] if commit_id.as_ref() == "abc" && message == "Better subject\n\nBody"
+    ));
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+}
+
+#[test]
+fn patch_series_preview_loads_and_apply_clears_it() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    state.repos.push(RepoState::new_opening(
+        RepoId(1),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.active_repo = Some(RepoId(1));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::LoadPatchSeriesPreview {
+            repo_id: RepoId(1),
+            source: Some(PathBuf::from("/tmp/series.mbox")),
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::LoadPatchSeriesPreview { repo_id: RepoId(1), source }]
+            if source == Path::new("/tmp/series.mbox")
+    ));
+
+    // A result for a source that is no longer previewed is dropped.
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::PatchSeriesPreviewLoaded {
+            repo_id: RepoId(1),
+            source: PathBuf::from("/tmp/other.mbox"),
+            result: Ok(Vec::new()),
+        }),
+    );
+    assert!(matches!(
+        state.repos[0]
+            .patch_series_preview
+            .as_ref()
+            .map(|p| &p.patches),
+        Some(Loadable::Loading)
+    ));
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::PatchSeriesPreviewLoaded {
+            repo_id: RepoId(1),
+            source: PathBuf::from("/tmp/series.mbox"),
+            result: Ok(vec![gitcomet_core::mailbox::MailboxPatch {
+                subject: "Fix parser".into(),
+                ..Default::default()
+            }]),
+        }),
+    );
+    assert!(matches!(
+        state.repos[0].patch_series_preview.as_ref().map(|p| &p.patches),
+        Some(Loadable::Ready(patches)) if patches.len() == 1
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::ApplyPatchSeries {
+            repo_id: RepoId(1),
+            source: PathBuf::from("/tmp/series.mbox"),
+            three_way: true,
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::ApplyPatchSeries {
+            repo_id: RepoId(1),
+            three_way: true,
+            ..
+        }]
+    ));
+    assert!(state.repos[0].patch_series_preview.is_none());
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+}
+
+#[test]
+fn revision_tree_browses_directories_and_files_and_drops_stale_results() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    state.repos.push(RepoState::new_opening(
+        RepoId(1),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.active_repo = Some(RepoId(1));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::LoadRevisionTree {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            dir: PathBuf::from("src"),
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::LoadRevisionTree { repo_id: RepoId(1), rev, dir }]
+            if rev == "abc" && dir == Path::new("src")
+    ));
+
+    // A listing for a directory the browser has already left is dropped.
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RevisionTreeLoaded {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            dir: PathBuf::new(),
+            result: Ok(Vec::new()),
+        }),
+    );
+    assert!(matches!(
+        state.repos[0].revision_tree.as_ref().map(|b| &b.entries),
+        Some(Loadable::Loading)
+    ));
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RevisionTreeLoaded {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            dir: PathBuf::from("src"),
+            result: Ok(vec![gitcomet_core::domain::RevisionTreeEntry {
+                path: PathBuf::from("src/lib.rs"),
+                kind: gitcomet_core::domain::RevisionTreeEntryKind::File,
+                size: Some(12),
+            }]),
+        }),
+    );
+    assert!(matches!(
+        state.repos[0].revision_tree.as_ref().map(|b| &b.entries),
+        Some(Loadable::Ready(entries)) if entries.len() == 1
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::LoadRevisionFile {
+            repo_id: RepoId(1),
+            path: Some(PathBuf::from("src/lib.rs")),
+            against_worktree: true,
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::LoadRevisionFile {
+            repo_id: RepoId(1),
+            rev,
+            path,
+            against_worktree: true,
+        }] if rev == "abc" && path == Path::new("src/lib.rs")
+    ));
+
+    // The plain contents arrive after the reader switched to the diff.
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RevisionFileLoaded {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            path: PathBuf::from("src/lib.rs"),
+            against_worktree: false,
+            result: Ok("fn one() {}\n".to_string()),
+        }),
+    );
+    let file_text = |state: &AppState| {
+        state.repos[0]
+            .revision_tree
+            .as_ref()
+            .and_then(|b| b.file.as_ref())
+            .map(|f| f.text.clone())
+    };
+    assert!(matches!(file_text(&state), Some(Loadable::Loading)));
+
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::RevisionFileLoaded {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            path: PathBuf::from("src/lib.rs"),
+            against_worktree: true,
+            result: Ok("-fn one() {}\n+fn two() {}\n".to_string()),
+        }),
+    );
+    assert!(matches!(
+        file_text(&state),
+        Some(Loadable::Ready(text)) if text.contains("+fn two")
+    ));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::LoadRevisionFile {
+            repo_id: RepoId(1),
+            path: None,
+            against_worktree: false,
+        },
+    );
+    assert!(effects.is_empty());
+    assert!(file_text(&state).is_none());
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SaveRevisionPath {
+            repo_id: RepoId(1),
+            rev: "abc".to_string(),
+            path: PathBuf::from("src"),
+            dest: PathBuf::from("/tmp/out/src"),
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::SaveRevisionPath { repo_id: RepoId(1), rev, .. }] if rev == "abc"
+    ));
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+}
+
 #[test]
 fn create_and_delete_branch_emit_effects() {
     let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
@@ -1684,6 +2376,7 @@ fn commit_finished_clears_commit_state_and_requests_primary_refreshes() {
     state.repos[0].diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("README.md"),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
     state.repos[0].diff_state.diff = Loadable::Loading;
     state.repos[0].diff_state.diff_file = Loadable::Loading;
@@ -1746,6 +2439,7 @@ fn repo_command_finished_stage_hunk_triggers_diff_reload_effects() {
     state.repos[0].diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1798,6 +2492,7 @@ fn repo_command_finished_stage_hunk_with_svg_diff_triggers_text_and_image_reload
     state.repos[0].diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("icon.svg"),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -2579,6 +3274,7 @@ fn apply_worktree_patch_command_finished_reloads_png_diff_preview() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("image.png"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.diff_state.diff_target = Some(target.clone());
     repo_state.diff_state.diff = Loadable::NotLoaded;
@@ -3058,6 +3754,7 @@ fn commit_and_amend_finished_cover_success_error_and_unknown_repo_paths() {
         repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
             path: PathBuf::from("a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         });
         repo.diff_state.diff = Loadable::Loading;
         repo.diff_state.diff_file = Loadable::Loading;
@@ -3681,6 +4378,7 @@ fn repo_command_finished_reset_clears_diff_state_and_unknown_repo_is_noop() {
     repo_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("a.txt"),
         area: DiffArea::Staged,
+        old_path: None,
     });
     repo_state.diff_state.diff = Loadable::Loading;
     repo_state.diff_state.diff_file = Loadable::Loading;
@@ -3737,6 +4435,7 @@ fn stage_hunk_command_finished_reloads_commit_png_image_preview_only() {
     let target = DiffTarget::Commit {
         commit_id: CommitId("abc123".into()),
         path: Some(PathBuf::from("assets/icon.png")),
+        old_path: None,
     };
     let mut repo_state = RepoState::new_opening(
         repo_id,
@@ -39,6 +39,8 @@ fn setup_repo_with_conflict(
             path: PathBuf::from(path),
             kind: FileStatusKind::Conflicted,
             conflict: Some(conflict_kind),
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -478,6 +480,8 @@ fn status_loaded_keeps_conflict_context_for_same_conflicted_path() {
                     path: PathBuf::from("file.txt"),
                     kind: FileStatusKind::Conflicted,
                     conflict: Some(FileConflictKind::BothModified),
+                    old_path: None,
+                    similarity: None,
                 }],
                 staged: vec![],
             }),
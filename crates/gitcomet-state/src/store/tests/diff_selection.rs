@@ -21,6 +21,7 @@ fn select_diff_sets_loading_and_emits_effect() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let effects = reduce(
@@ -66,6 +67,7 @@ fn select_diff_for_image_sets_loading_and_emits_effect() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("img.png"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let effects = reduce(
@@ -115,6 +117,7 @@ fn select_diff_for_ico_sets_loading_and_emits_effect() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("app.ico"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let effects = reduce(
@@ -164,6 +167,7 @@ fn select_diff_for_svg_loads_image_and_text() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("icon.svg"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let effects = reduce(
@@ -208,12 +212,15 @@ fn select_diff_for_untracked_file_skips_patch_diff_and_loads_file_preview() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("report.json"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
         unstaged: vec![FileStatus {
             path: PathBuf::from("report.json"),
             kind: FileStatusKind::Untracked,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -271,12 +278,15 @@ fn select_diff_for_deleted_file_replaced_by_directory_loads_deleted_preview() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("report.json"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
         unstaged: vec![FileStatus {
             path: PathBuf::from("report.json"),
             kind: FileStatusKind::Deleted,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -340,12 +350,15 @@ fn select_diff_for_checked_out_submodule_marker_loads_summary_before_submodules_
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: submodule_path.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
         unstaged: vec![FileStatus {
             path: submodule_path,
             kind: FileStatusKind::Modified,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -413,6 +426,7 @@ fn select_diff_for_staged_deleted_head_gitlink_loads_submodule_summary() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: submodule_path.clone(),
         area: gitcomet_core::domain::DiffArea::Staged,
+        old_path: None,
     };
     repo_state.set_submodules(Loadable::Ready(Vec::new()));
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
@@ -420,6 +434,8 @@ fn select_diff_for_staged_deleted_head_gitlink_loads_submodule_summary() {
             path: submodule_path,
             kind: FileStatusKind::Deleted,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
         unstaged: vec![],
     })));
@@ -471,6 +487,7 @@ fn select_diff_for_deleted_commit_file_skips_patch_diff_and_loads_file_preview()
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(PathBuf::from("report.json")),
+        old_path: None,
     };
     repo_state.history_state.commit_details = Loadable::Ready(Arc::new(CommitDetails {
         id: commit_id,
@@ -481,6 +498,9 @@ fn select_diff_for_deleted_commit_file_skips_patch_diff_and_loads_file_preview()
             path: PathBuf::from("report.json"),
             kind: FileStatusKind::Deleted,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         }],
     }));
     state.repos.push(repo_state);
@@ -598,6 +618,7 @@ fn open_inline_submodule_diff_loads_patch_file_and_image_for_svg_targets() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("icons/logo.svg"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     let effects = reduce(
         &mut repos,
@@ -834,10 +855,12 @@ fn submodule_summary_refresh_reloads_open_inline_diff_when_selected_target_remai
     let parent_target = DiffTarget::WorkingTree {
         path: parent_path.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let inline_target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let mut repo_state = RepoState::new_opening(
         RepoId(1),
@@ -850,6 +873,8 @@ fn submodule_summary_refresh_reloads_open_inline_diff_when_selected_target_remai
             path: parent_path.clone(),
             kind: FileStatusKind::Modified,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -980,6 +1005,7 @@ fn commit_details_loaded_replans_selected_deleted_commit_file_to_preview_text_fi
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(PathBuf::from("report.json")),
+        old_path: None,
     };
     repo_state.set_selected_commit(Some(commit_id.clone()));
     repo_state.diff_state.diff_target = Some(target.clone());
@@ -1004,6 +1030,9 @@ fn commit_details_loaded_replans_selected_deleted_commit_file_to_preview_text_fi
                     path: PathBuf::from("report.json"),
                     kind: FileStatusKind::Deleted,
                     is_submodule: false,
+                    old_path: None,
+                    similarity: None,
+                    generated: false,
                 }],
             }),
         }),
@@ -1064,12 +1093,15 @@ fn select_diff_for_conflicted_file_skips_patch_and_file_diff_loads() {
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("index.html"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
         unstaged: vec![FileStatus {
             path: PathBuf::from("index.html"),
             kind: FileStatusKind::Conflicted,
             conflict: Some(FileConflictKind::BothModified),
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -1125,12 +1157,15 @@ fn select_diff_for_conflicted_svg_prefers_conflict_loader_over_preview_effects()
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("icon.svg"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.set_status(Loadable::Ready(Arc::new(RepoStatus {
         unstaged: vec![FileStatus {
             path: PathBuf::from("icon.svg"),
             kind: FileStatusKind::Conflicted,
             conflict: Some(FileConflictKind::BothModified),
+            old_path: None,
+            similarity: None,
         }],
         staged: vec![],
     })));
@@ -1182,6 +1217,7 @@ fn select_diff_for_commit_without_path_only_loads_patch() {
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: CommitId("deadbeef".into()),
         path: None,
+        old_path: None,
     };
 
     let effects = reduce(
@@ -1234,6 +1270,7 @@ fn select_diff_for_commit_svg_path_loads_text_and_image_previews() {
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: CommitId("deadbeef".into()),
         path: Some(PathBuf::from("diagram.svg")),
+        old_path: None,
     };
 
     let effects = reduce(
@@ -1342,6 +1379,7 @@ fn stage_hunk_command_finished_reloads_current_diff() {
     repo_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("a.txt"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
     repo_state.diff_state.diff = Loadable::NotLoaded;
     repo_state.diff_state.diff_file = Loadable::NotLoaded;
@@ -1363,7 +1401,7 @@ fn stage_hunk_command_finished_reloads_current_diff() {
     assert!(repo_state.diff_state.diff.is_loading());
     assert!(repo_state.diff_state.diff_file.is_loading());
     assert!(effects.iter().any(|e| {
-        matches!(e, Effect::LoadDiff { repo_id: RepoId(1), target: DiffTarget::WorkingTree { path, area: gitcomet_core::domain::DiffArea::Unstaged } } if path == &PathBuf::from("a.txt"))
+        matches!(e, Effect::LoadDiff { repo_id: RepoId(1), target: DiffTarget::WorkingTree { path, area: gitcomet_core::domain::DiffArea::Unstaged, .. } } if path == &PathBuf::from("a.txt"))
     }));
     assert!(effects.iter().any(|e| matches!(
         e,
@@ -1388,6 +1426,7 @@ fn clear_diff_selection_resets_diff_state() {
     repo_state.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
     repo_state.diff_state.diff = Loadable::Loading;
     repo_state.diff_state.diff_file = Loadable::Loading;
@@ -1425,6 +1464,7 @@ fn diff_loaded_err_records_diagnostic_when_target_matches() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.diff_state.diff_target = Some(target.clone());
     repo_state.diff_state.diff = Loadable::Loading;
@@ -1473,6 +1513,7 @@ fn select_diff_bumps_diff_state_rev() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     reduce(
         &mut repos,
@@ -1506,10 +1547,12 @@ fn select_and_clear_diff_update_diff_target_rev_only_when_target_changes() {
     let first = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let second = DiffTarget::WorkingTree {
         path: PathBuf::from("src/main.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
 
     reduce(
@@ -1574,6 +1617,7 @@ fn clear_diff_selection_bumps_diff_state_rev() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     reduce(
         &mut repos,
@@ -1620,6 +1664,7 @@ fn select_diff_does_not_bump_unrelated_revs() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     reduce(
         &mut repos,
@@ -1645,6 +1690,7 @@ fn select_and_clear_diff_are_noops_for_unknown_repo() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let select = reduce(
         &mut repos,
@@ -1717,6 +1763,7 @@ fn diff_loaded_ok_sets_ready_when_target_matches() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.diff_state.diff_target = Some(target.clone());
     repo_state.diff_state.diff = Loadable::Loading;
@@ -1757,6 +1804,7 @@ fn diff_file_loaded_and_image_loaded_cover_success_and_error_paths() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("icon.svg"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.diff_state.diff_target = Some(target.clone());
     repo_state.diff_state.diff_file = Loadable::Loading;
@@ -1863,10 +1911,12 @@ fn diff_results_are_ignored_for_non_matching_target() {
     let selected = DiffTarget::WorkingTree {
         path: PathBuf::from("selected.txt"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let other = DiffTarget::WorkingTree {
         path: PathBuf::from("other.txt"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo_state.diff_state.diff_target = Some(selected.clone());
     repo_state.diff_state.diff = Loadable::Loading;
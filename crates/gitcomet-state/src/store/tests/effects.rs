@@ -3998,6 +3998,7 @@ fn schedule_effect_dispatches_many_variants_with_repo_present() {
     let target = DiffTarget::WorkingTree {
         path: PathBuf::from("tracked.txt"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     let mut state = AppState::default();
     let mut repo_state = crate::model::RepoState::new_opening(
@@ -4052,11 +4053,23 @@ fn schedule_effect_dispatches_many_variants_with_repo_present() {
             },
             1,
         ),
+        (
+            Effect::LoadLineHistory {
+                repo_id,
+                path: PathBuf::from("tracked.txt"),
+                start: 1,
+                end: 2,
+                rev: None,
+                limit: 10,
+            },
+            1,
+        ),
         (
             Effect::LoadBlame {
                 repo_id,
                 path: PathBuf::from("tracked.txt"),
                 rev: Some("HEAD".to_string()),
+                options: gitcomet_core::services::BlameOptions::default(),
             },
             1,
         ),
@@ -4566,6 +4579,22 @@ fn schedule_effect_dispatches_many_variants_with_repo_present() {
             },
             1,
         ),
+        (Effect::LoadConflictSummaries { repo_id }, 1),
+        (
+            Effect::CheckoutConflictSidePaths {
+                repo_id,
+                paths: vec![PathBuf::from("conflicted.txt")].into(),
+                side: gitcomet_core::services::ConflictSide::Theirs,
+            },
+            1,
+        ),
+        (
+            Effect::AutosolveConflictPaths {
+                repo_id,
+                paths: vec![PathBuf::from("conflicted.txt")].into(),
+            },
+            1,
+        ),
         (
             Effect::Stash {
                 repo_id,
@@ -53,6 +53,7 @@ fn external_worktree_change_refreshes_status_and_selected_diff() {
             target: DiffTarget::WorkingTree {
                 path: PathBuf::from("a.txt"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
         },
     );
@@ -207,6 +208,7 @@ fn external_git_state_change_refreshes_history_and_selected_diff() {
             target: DiffTarget::WorkingTree {
                 path: PathBuf::from("a.txt"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
         },
     );
@@ -641,6 +643,7 @@ fn external_worktree_refresh_coalesces_status_while_status_is_in_flight() {
             target: DiffTarget::WorkingTree {
                 path: PathBuf::from("crates/gitcomet-ui-gpui/src/smoke_tests.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
         },
     );
@@ -1596,3 +1599,60 @@ fn status_loaded_bumps_status_rev() {
         "status_rev should bump after StatusLoaded"
     );
 }
+
+#[test]
+fn operation_state_loaded_updates_repo_and_actions_emit_effects() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    state.repos.push(RepoState::new_opening(
+        RepoId(1),
+        RepoSpec {
+            workdir: PathBuf::from("/tmp/repo"),
+        },
+    ));
+    state.active_repo = Some(RepoId(1));
+
+    let state_in_progress = gitcomet_core::domain::RepoOperationState::CherryPick(
+        gitcomet_core::domain::OperationProgress {
+            step: None,
+            subject: Some("Fix parser".to_string()),
+        },
+    );
+    let rev_before = state.repos[0].operation_state_rev;
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::Internal(crate::msg::InternalMsg::OperationStateLoaded {
+            repo_id: RepoId(1),
+            result: Ok(Some(state_in_progress.clone())),
+        }),
+    );
+    assert!(effects.is_empty());
+    assert_eq!(
+        state.repos[0].operation_state,
+        Loadable::Ready(Some(state_in_progress))
+    );
+    assert!(state.repos[0].operation_state_rev > rev_before);
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::OperationAction {
+            repo_id: RepoId(1),
+            kind: gitcomet_core::domain::RepoOperationKind::CherryPick,
+            action: gitcomet_core::domain::RepoOperationAction::Skip,
+        },
+    );
+    assert!(matches!(
+        effects.as_slice(),
+        [Effect::OperationAction {
+            repo_id: RepoId(1),
+            kind: gitcomet_core::domain::RepoOperationKind::CherryPick,
+            action: gitcomet_core::domain::RepoOperationAction::Skip,
+        }]
+    ));
+    assert_eq!(state.repos[0].local_actions_in_flight, 1);
+}
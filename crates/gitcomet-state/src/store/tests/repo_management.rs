@@ -1670,6 +1670,7 @@ fn set_active_repo_full_refresh_with_sidebar_request_and_selected_diff_does_not_
     repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1734,6 +1735,7 @@ fn set_active_repo_refreshes_repo_state_and_selected_diff() {
     repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("src/lib.rs"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1807,6 +1809,7 @@ fn set_active_repo_reloads_selected_image_diff_via_image_effect() {
     repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("icon.png"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1847,6 +1850,7 @@ fn set_active_repo_png_diff_enqueues_image_preview_only() {
     repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("image.png"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1889,6 +1893,7 @@ fn set_active_repo_svg_diff_enqueues_image_and_text_previews() {
     repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: PathBuf::from("vector.svg"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     });
 
     let effects = reduce(
@@ -1933,6 +1938,7 @@ fn set_active_repo_selected_conflict_target_reuses_existing_conflict_state() {
         repo1_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
             path: conflict_path.clone(),
             area: gitcomet_core::domain::DiffArea::Unstaged,
+            old_path: None,
         });
         repo1_state.conflict_state.conflict_file_path = Some(conflict_path.clone());
         let content: Arc<str> = Arc::from("conflict contents");
@@ -2163,6 +2169,107 @@ fn set_fetch_prune_deleted_remote_tracking_branches_updates_and_noops() {
     );
 }
 
+#[test]
+fn set_diff_ignore_options_reloads_selected_diff_and_noops_when_unchanged() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    let repo_id = open_repo_ready(&mut repos, &id_alloc, &mut state, "/tmp/repo");
+    state.repos[0].set_diff_target(Some(DiffTarget::WorkingTree {
+        path: PathBuf::from("src/lib.rs"),
+        area: DiffArea::Unstaged,
+        old_path: None,
+    }));
+
+    let options = gitcomet_core::services::DiffIgnoreOptions {
+        ignore_matching_lines: vec!["^// generated".to_string()],
+        ..Default::default()
+    };
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetDiffIgnoreOptions {
+            repo_id,
+            options: options.clone(),
+        },
+    );
+    assert_eq!(state.repos[0].diff_ignore_options, options);
+    assert!(has_effect_for_repo(&effects, repo_id, |effect, repo_id| {
+        matches!(effect, Effect::LoadDiff { repo_id: id, .. } if *id == repo_id)
+    }));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetDiffIgnoreOptions { repo_id, options },
+    );
+    assert!(effects.is_empty());
+}
+
+#[test]
+fn set_diff_whitespace_overrides_repo_options_and_reloads_diffs() {
+    use gitcomet_core::services::{DiffIgnoreOptions, DiffWhitespaceIgnore};
+
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    let repo_id = open_repo_ready(&mut repos, &id_alloc, &mut state, "/tmp/repo");
+    state.repos[0].set_diff_target(Some(DiffTarget::WorkingTree {
+        path: PathBuf::from("src/lib.rs"),
+        area: DiffArea::Unstaged,
+        old_path: None,
+    }));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetDiffWhitespace {
+            whitespace: DiffWhitespaceIgnore::Change,
+        },
+    );
+    assert_eq!(state.diff_whitespace, DiffWhitespaceIgnore::Change);
+    assert_eq!(
+        state.repos[0].diff_ignore_options.whitespace,
+        DiffWhitespaceIgnore::Change
+    );
+    assert!(has_effect_for_repo(&effects, repo_id, |effect, repo_id| {
+        matches!(effect, Effect::LoadDiff { repo_id: id, .. } if *id == repo_id)
+    }));
+
+    // Per-repository options cannot override the app-wide whitespace mode.
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetDiffIgnoreOptions {
+            repo_id,
+            options: DiffIgnoreOptions {
+                whitespace: DiffWhitespaceIgnore::None,
+                ignore_blank_lines: true,
+                ..Default::default()
+            },
+        },
+    );
+    assert_eq!(
+        state.repos[0].diff_ignore_options.whitespace,
+        DiffWhitespaceIgnore::Change
+    );
+    assert!(state.repos[0].diff_ignore_options.ignore_blank_lines);
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetDiffWhitespace {
+            whitespace: DiffWhitespaceIgnore::Change,
+        },
+    );
+    assert!(effects.is_empty());
+}
+
 #[test]
 fn repo_opened_ok_sets_loading_and_emits_refresh_effects() {
     let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
@@ -2691,3 +2798,66 @@ fn session_persist_failed_msg_reports_notification_and_repo_diagnostic() {
             .any(|d| d.message.contains("disk full"))
     );
 }
+
+#[test]
+fn set_rename_detection_refreshes_status_for_open_repos_once() {
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    let repo_id = open_repo_ready(&mut repos, &id_alloc, &mut state, "/tmp/repo");
+
+    let detection = gitcomet_core::services::RenameDetection {
+        rename_threshold: 70,
+        detect_copies: true,
+        copy_threshold: 80,
+    };
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetRenameDetection { detection },
+    );
+
+    assert_eq!(state.rename_detection, Some(detection));
+    assert!(has_effect_for_repo(&effects, repo_id, |effect, repo_id| {
+        matches!(effect, Effect::LoadStatus { repo_id: id } if *id == repo_id)
+    }));
+
+    let effects = reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetRenameDetection { detection },
+    );
+    assert!(effects.is_empty());
+}
+
+#[test]
+fn rename_detection_is_persisted_and_restored_on_open() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let repo_path = dir.path().join("repo");
+    let session_file = dir.path().join("session.json");
+    std::fs::create_dir_all(&repo_path).expect("create repo path");
+    let _session_file_override =
+        crate::session::push_test_session_file_path_override(Some(session_file.clone()));
+
+    let detection = gitcomet_core::services::RenameDetection {
+        rename_threshold: 90,
+        detect_copies: true,
+        copy_threshold: 70,
+    };
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let id_alloc = AtomicU64::new(1);
+    let mut state = AppState::default();
+    reduce(
+        &mut repos,
+        &id_alloc,
+        &mut state,
+        Msg::SetRenameDetection { detection },
+    );
+
+    let mut repos: HashMap<RepoId, Arc<dyn GitRepository>> = HashMap::default();
+    let mut state = AppState::default();
+    reduce(&mut repos, &id_alloc, &mut state, Msg::OpenRepo(repo_path));
+    assert_eq!(state.rename_detection, Some(detection));
+}
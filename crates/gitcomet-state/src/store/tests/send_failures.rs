@@ -415,6 +415,7 @@ fn selected_diff_results_after_store_drop_do_not_emit_store_event_failures() {
         target: DiffTarget::WorkingTree {
             path: PathBuf::from("tracked.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         },
     });
 
@@ -40,7 +40,6 @@ const WINDOW_MIN_HEIGHT_PX: f32 = 560.0;
 const WINDOW_DEFAULT_WIDTH_PX: f32 = 1100.0;
 const WINDOW_DEFAULT_HEIGHT_PX: f32 = 720.0;
 const FOCUSED_MERGETOOL_EXIT_CANCELED: i32 = 1;
-#[cfg(test)]
 const FOCUSED_MERGETOOL_EXIT_SUCCESS: i32 = 0;
 const FOCUSED_MERGETOOL_EXIT_ERROR: i32 = 2;
 
@@ -84,6 +83,9 @@ pub struct FocusedMergetoolConfig {
     pub label_local: String,
     pub label_remote: String,
     pub label_base: String,
+    /// Show the inputs side by side without resolution actions, for
+    /// comparing files rather than resolving a merge.
+    pub compare_only: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -139,7 +141,12 @@ pub fn run_focused_mergetool(backend: Arc<dyn GitBackend>, config: FocusedMerget
         return FOCUSED_MERGETOOL_EXIT_ERROR;
     }
 
-    let exit_code = Arc::new(AtomicI32::new(FOCUSED_MERGETOOL_EXIT_CANCELED));
+    // Closing a comparison is its normal end, not a cancelled merge.
+    let exit_code = Arc::new(AtomicI32::new(if config.compare_only {
+        FOCUSED_MERGETOOL_EXIT_SUCCESS
+    } else {
+        FOCUSED_MERGETOOL_EXIT_CANCELED
+    }));
     let launch = focused_mergetool_launch_config(&config, Some(exit_code.clone()));
     if let Err(err) = run_with_panic_guard("focused mergetool GPUI launch", move || {
         run_windowed_app(backend, launch)
@@ -182,7 +189,7 @@ fn focused_mergetool_launch_config(
     exit_code: Option<Arc<AtomicI32>>,
 ) -> WindowLaunchConfig {
     WindowLaunchConfig {
-        title: focused_mergetool_window_title(&config.conflicted_file_path),
+        title: focused_mergetool_window_title(&config.conflicted_file_path, config.compare_only),
         app_id: "gitcomet-mergetool".to_string(),
         view_config: GitCometViewConfig {
             initial_path: Some(config.repo_path.clone()),
@@ -196,6 +203,7 @@ fn focused_mergetool_launch_config(
                     remote: config.label_remote.clone(),
                     base: config.label_base.clone(),
                 },
+                compare_only: config.compare_only,
             }),
             focused_mergetool_exit_code: exit_code,
             startup_crash_report: None,
@@ -203,12 +211,13 @@ fn focused_mergetool_launch_config(
     }
 }
 
-fn focused_mergetool_window_title(conflicted_file_path: &Path) -> String {
+fn focused_mergetool_window_title(conflicted_file_path: &Path, compare_only: bool) -> String {
     let display = conflicted_file_path
         .file_name()
         .and_then(|name| name.to_str().map(ToOwned::to_owned))
         .unwrap_or_else(|| format!("{conflicted_file_path:?}"));
-    format!("GitComet - Mergetool ({display})")
+    let tool = if compare_only { "Compare" } else { "Mergetool" };
+    format!("GitComet - {tool} ({display})")
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1599,8 +1608,10 @@ mod tests {
 
     #[test]
     fn focused_mergetool_title_uses_file_name_when_available() {
-        let title = focused_mergetool_window_title(Path::new("/repo/src/conflict.txt"));
+        let title = focused_mergetool_window_title(Path::new("/repo/src/conflict.txt"), false);
         assert_eq!(title, "GitComet - Mergetool (conflict.txt)");
+        let title = focused_mergetool_window_title(Path::new("/repo/src/conflict.txt"), true);
+        assert_eq!(title, "GitComet - Compare (conflict.txt)");
     }
 
     #[test]
@@ -1611,6 +1622,7 @@ mod tests {
             label_local: "LOCAL".to_string(),
             label_remote: "REMOTE".to_string(),
             label_base: "BASE".to_string(),
+            compare_only: false,
         };
 
         let launch = focused_mergetool_launch_config(&config, None);
@@ -1631,6 +1643,7 @@ mod tests {
                     remote: "REMOTE".to_string(),
                     base: "BASE".to_string(),
                 },
+                compare_only: false,
             })
         );
         assert!(launch.view_config.focused_mergetool_exit_code.is_none());
@@ -1890,6 +1903,7 @@ mod tests {
                 label_local: "LOCAL".to_string(),
                 label_remote: "REMOTE".to_string(),
                 label_base: "BASE".to_string(),
+                compare_only: false,
             },
             None,
         );
@@ -2,17 +2,24 @@
 //!
 //! Opens a GPUI window that displays a unified diff with color-coded lines.
 //! The user reviews the diff and closes the window (exit 0).
+//!
+//! [`SideBySideFileDiff`] renders a single file pair side by side with syntax
+//! and word-level highlighting for the directory-compare window.
 
 use crate::assets::GitCometAssets;
 use crate::launch_guard::run_with_panic_guard;
 use crate::theme::AppTheme;
+use crate::view::conflict_resolver::compute_word_highlights_for_row;
+use gitcomet_core::file_diff::{FileDiffRow, FileDiffRowKind, side_by_side_rows};
 use gitcomet_state::session;
 use gpui::prelude::*;
 use gpui::{
-    App, Bounds, FocusHandle, Focusable, FontWeight, KeyBinding, Pixels, Render, ScrollHandle,
-    SharedString, TitlebarOptions, Window, WindowBounds, WindowDecorations, WindowOptions, actions,
-    div, point,
+    AnyElement, App, Bounds, FocusHandle, Focusable, FontWeight, HighlightStyle, KeyBinding,
+    Pixels, Render, ScrollHandle, SharedString, StyledText, TitlebarOptions, Window, WindowBounds,
+    WindowDecorations, WindowOptions, actions, div, point,
 };
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI32, Ordering};
 
@@ -480,6 +487,160 @@ fn render_diff_line(
     el
 }
 
+// ── Side-by-side file rows ───────────────────────────────────────────
+
+/// A single file pair laid out side by side, with word ranges for modified
+/// rows computed up front.
+pub(crate) struct SideBySideFileDiff {
+    path: PathBuf,
+    rows: Vec<FileDiffRow>,
+    word_ranges: Vec<(Vec<Range<usize>>, Vec<Range<usize>>)>,
+}
+
+impl SideBySideFileDiff {
+    pub(crate) fn new(path: &Path, old: &str, new: &str) -> Self {
+        let rows = side_by_side_rows(old, new);
+        let word_ranges = rows
+            .iter()
+            .map(|row| {
+                compute_word_highlights_for_row(row)
+                    .map(|(old, new)| (old.as_slice().to_vec(), new.as_slice().to_vec()))
+                    .unwrap_or_default()
+            })
+            .collect();
+        Self {
+            path: path.to_path_buf(),
+            rows,
+            word_ranges,
+        }
+    }
+
+    pub(crate) fn render_rows(&self, theme: &AppTheme, window: &Window) -> Vec<AnyElement> {
+        self.rows
+            .iter()
+            .zip(&self.word_ranges)
+            .map(|(row, (old_words, new_words))| {
+                render_side_by_side_row(&self.path, row, old_words, new_words, theme, window)
+                    .into_any_element()
+            })
+            .collect()
+    }
+}
+
+/// Syntax highlights for `text` with a translucent background laid over the
+/// changed word ranges, matching the main diff view.
+fn side_by_side_line_highlights(
+    theme: &AppTheme,
+    path: &Path,
+    text: &str,
+    word_ranges: &[Range<usize>],
+    word_color: gpui::Rgba,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    let syntax = crate::view::rows::syntax_highlights_for_path_line(*theme, path, text);
+    if word_ranges.is_empty() {
+        return syntax;
+    }
+
+    let mut word_bg = word_color;
+    word_bg.a = if theme.is_dark { 0.22 } else { 0.16 };
+    let mut boundaries = vec![0, text.len()];
+    for range in syntax.iter().map(|(range, _)| range).chain(word_ranges) {
+        boundaries.push(range.start.min(text.len()));
+        boundaries.push(range.end.min(text.len()));
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut highlights = Vec::with_capacity(boundaries.len());
+    for pair in boundaries.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let covers = |range: &Range<usize>| range.start <= start && end <= range.end;
+        let mut style = syntax
+            .iter()
+            .find(|(range, _)| covers(range))
+            .map(|(_, style)| *style)
+            .unwrap_or_default();
+        if word_ranges.iter().any(covers) {
+            style.background_color = Some(word_bg.into());
+        }
+        if style != HighlightStyle::default() {
+            highlights.push((start..end, style));
+        }
+    }
+    highlights
+}
+
+fn render_side_by_side_row(
+    path: &Path,
+    row: &FileDiffRow,
+    old_words: &[Range<usize>],
+    new_words: &[Range<usize>],
+    theme: &AppTheme,
+    window: &Window,
+) -> impl IntoElement {
+    let scaled_px = |value| crate::ui_scale::design_px_from_window(value, window);
+    let (old_bg, new_bg) = match row.kind {
+        FileDiffRowKind::Context => (None, None),
+        FileDiffRowKind::Add => (None, Some(theme.colors.diff_add_bg)),
+        FileDiffRowKind::Remove => (Some(theme.colors.diff_remove_bg), None),
+        FileDiffRowKind::Modify => (
+            Some(theme.colors.diff_remove_bg),
+            Some(theme.colors.diff_add_bg),
+        ),
+    };
+    let cell = |line: Option<u32>,
+                text: Option<&str>,
+                bg: Option<gpui::Rgba>,
+                words: &[Range<usize>],
+                word_color: gpui::Rgba| {
+        let text = text.unwrap_or_default();
+        let highlights = side_by_side_line_highlights(theme, path, text, words, word_color);
+        let mut el = div()
+            .w(gpui::relative(0.5))
+            .flex()
+            .flex_row()
+            .overflow_hidden()
+            .child(
+                div()
+                    .text_color(theme.colors.text_muted)
+                    .text_size(scaled_px(11.0))
+                    .min_w(scaled_px(40.0))
+                    .child(SharedString::from(
+                        line.map(|n| format!("{n:>4} ")).unwrap_or_default(),
+                    )),
+            )
+            .child(
+                div().flex_grow().whitespace_nowrap().child(
+                    StyledText::new(SharedString::from(text.to_string()))
+                        .with_default_highlights(&window.text_style(), highlights),
+                ),
+            );
+        if let Some(bg) = bg {
+            el = el.bg(bg);
+        }
+        el
+    };
+
+    div()
+        .w_full()
+        .flex()
+        .flex_row()
+        .child(cell(
+            row.old_line,
+            row.old.as_deref(),
+            old_bg,
+            old_words,
+            theme.colors.diff_remove_text,
+        ))
+        .child(cell(
+            row.new_line,
+            row.new.as_deref(),
+            new_bg,
+            new_words,
+            theme.colors.diff_add_text,
+        ))
+}
+
 fn bind_focused_diff_keys(cx: &mut App) {
     cx.bind_keys([
         KeyBinding::new("escape", Close, Some("FocusedDiff")),
@@ -691,6 +852,38 @@ index 1234567..abcdef0 100644
         assert_eq!(lines[3].visual_kind, DiffLineKind::Context);
     }
 
+    #[test]
+    fn side_by_side_line_highlights_overlay_word_ranges_on_syntax() {
+        let theme = AppTheme::gitcomet_light();
+        let text = "let value = 1;";
+        let word_color = theme.colors.diff_add_text;
+
+        let plain =
+            side_by_side_line_highlights(&theme, Path::new("a.txt"), text, &[4..9], word_color);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].0, 4..9);
+        assert!(plain[0].1.background_color.is_some());
+
+        let syntax = side_by_side_line_highlights(&theme, Path::new("a.rs"), text, &[], word_color);
+        assert!(syntax.iter().any(|(_, style)| style.color.is_some()));
+
+        let combined =
+            side_by_side_line_highlights(&theme, Path::new("a.rs"), text, &[0..3], word_color);
+        let keyword = combined
+            .iter()
+            .find(|(range, _)| range.start == 0)
+            .expect("highlight at the start of the line");
+        assert_eq!(keyword.0, 0..3);
+        assert!(keyword.1.color.is_some());
+        assert!(keyword.1.background_color.is_some());
+        assert!(
+            combined
+                .iter()
+                .filter(|(range, _)| range.start >= 3)
+                .all(|(_, style)| style.background_color.is_none())
+        );
+    }
+
     #[gpui::test]
     fn focused_diff_keybindings_dispatch_close(cx: &mut gpui::TestAppContext) {
         let observed_actions: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
@@ -0,0 +1,724 @@
+//! Directory-compare window for `gitcomet difftool --gui` with two directories.
+//!
+//! Shows the union of both trees with per-entry status, lets the user filter
+//! by status, opens a side-by-side diff of the selected file, and can copy an
+//! entry from one side to the other to sync the folders.
+
+use crate::assets::GitCometAssets;
+use crate::focused_diff::SideBySideFileDiff;
+use crate::launch_guard::run_with_panic_guard;
+use crate::theme::AppTheme;
+use gitcomet_core::dir_compare::{
+    DirCompareEntry, DirCompareSide, DirCompareStatus, compare_directories, sync_entry,
+};
+use gitcomet_state::session;
+use gpui::prelude::*;
+use gpui::{
+    App, Bounds, FocusHandle, Focusable, FontWeight, KeyBinding, Pixels, Render, ScrollHandle,
+    SharedString, TitlebarOptions, Window, WindowBounds, WindowDecorations, WindowOptions, actions,
+    div, point,
+};
+use rustc_hash::FxHashSet as HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+// ── Actions ──────────────────────────────────────────────────────────
+
+actions!(focused_dir_diff, [Close, Refresh]);
+const FOCUSED_DIR_DIFF_EXIT_ERROR: i32 = 2;
+const FOCUSED_DIR_DIFF_MIN_WIDTH_PX: f32 = 700.0;
+const FOCUSED_DIR_DIFF_MIN_HEIGHT_PX: f32 = 400.0;
+const FOCUSED_DIR_DIFF_DEFAULT_WIDTH_PX: f32 = 1200.0;
+const FOCUSED_DIR_DIFF_DEFAULT_HEIGHT_PX: f32 = 760.0;
+
+fn focused_dir_diff_min_size_for_percent(percent: u32) -> gpui::Size<Pixels> {
+    crate::ui_scale::design_size_from_percent(
+        FOCUSED_DIR_DIFF_MIN_WIDTH_PX,
+        FOCUSED_DIR_DIFF_MIN_HEIGHT_PX,
+        percent,
+    )
+}
+
+fn focused_dir_diff_default_size_for_percent(percent: u32) -> gpui::Size<Pixels> {
+    crate::ui_scale::design_size_from_percent(
+        FOCUSED_DIR_DIFF_DEFAULT_WIDTH_PX,
+        FOCUSED_DIR_DIFF_DEFAULT_HEIGHT_PX,
+        percent,
+    )
+}
+
+// ── Public config ────────────────────────────────────────────────────
+
+/// Configuration for the directory-compare window.
+#[derive(Clone, Debug)]
+pub struct FocusedDirDiffConfig {
+    pub label_left: String,
+    pub label_right: String,
+    pub left: PathBuf,
+    pub right: PathBuf,
+}
+
+// ── View state ───────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct StatusFilter {
+    added: bool,
+    removed: bool,
+    modified: bool,
+    identical: bool,
+}
+
+impl Default for StatusFilter {
+    fn default() -> Self {
+        Self {
+            added: true,
+            removed: true,
+            modified: true,
+            identical: false,
+        }
+    }
+}
+
+impl StatusFilter {
+    fn allows(self, status: DirCompareStatus) -> bool {
+        match status {
+            DirCompareStatus::Added => self.added,
+            DirCompareStatus::Removed => self.removed,
+            DirCompareStatus::Modified => self.modified,
+            DirCompareStatus::Identical => self.identical,
+        }
+    }
+
+    fn toggle(&mut self, status: DirCompareStatus) {
+        let flag = match status {
+            DirCompareStatus::Added => &mut self.added,
+            DirCompareStatus::Removed => &mut self.removed,
+            DirCompareStatus::Modified => &mut self.modified,
+            DirCompareStatus::Identical => &mut self.identical,
+        };
+        *flag = !*flag;
+    }
+}
+
+enum SelectedDiff {
+    File(SideBySideFileDiff),
+    Message(String),
+}
+
+struct FocusedDirDiffView {
+    config: FocusedDirDiffConfig,
+    entries: Result<Vec<DirCompareEntry>, String>,
+    filter: StatusFilter,
+    collapsed: HashSet<PathBuf>,
+    selected: Option<PathBuf>,
+    selected_diff: Option<SelectedDiff>,
+    last_error: Option<String>,
+    exit_code: Arc<AtomicI32>,
+    focus_handle: FocusHandle,
+    tree_scroll: ScrollHandle,
+    diff_scroll: ScrollHandle,
+    theme: AppTheme,
+    ui_font_family: String,
+    editor_font_family: String,
+    use_font_ligatures: bool,
+}
+
+fn status_label(status: DirCompareStatus) -> &'static str {
+    match status {
+        DirCompareStatus::Added => "Added",
+        DirCompareStatus::Removed => "Removed",
+        DirCompareStatus::Modified => "Modified",
+        DirCompareStatus::Identical => "Identical",
+    }
+}
+
+fn status_color(theme: &AppTheme, status: DirCompareStatus) -> gpui::Rgba {
+    match status {
+        DirCompareStatus::Added => theme.colors.success,
+        DirCompareStatus::Removed => theme.colors.danger,
+        DirCompareStatus::Modified => theme.colors.warning,
+        DirCompareStatus::Identical => theme.colors.text_muted,
+    }
+}
+
+/// Entries that pass the status filter and are not hidden by a collapsed
+/// ancestor directory.
+fn visible_entries<'a>(
+    entries: &'a [DirCompareEntry],
+    filter: StatusFilter,
+    collapsed: &HashSet<PathBuf>,
+) -> Vec<&'a DirCompareEntry> {
+    entries
+        .iter()
+        .filter(|entry| filter.allows(entry.status))
+        .filter(|entry| {
+            !entry
+                .path
+                .ancestors()
+                .skip(1)
+                .any(|dir| collapsed.contains(dir))
+        })
+        .collect()
+}
+
+fn read_side(root: &Path, rel: &Path) -> Result<Option<String>, ()> {
+    match std::fs::read(root.join(rel)) {
+        Ok(bytes) => String::from_utf8(bytes).map(Some).map_err(|_| ()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(_) => Err(()),
+    }
+}
+
+fn load_selected_diff(config: &FocusedDirDiffConfig, entry: &DirCompareEntry) -> SelectedDiff {
+    if entry.is_dir {
+        return SelectedDiff::Message(format!(
+            "{} directory. Select a file to compare its contents.",
+            status_label(entry.status)
+        ));
+    }
+    match (
+        read_side(&config.left, &entry.path),
+        read_side(&config.right, &entry.path),
+    ) {
+        (Ok(left), Ok(right)) => SelectedDiff::File(SideBySideFileDiff::new(
+            &entry.path,
+            left.as_deref().unwrap_or_default(),
+            right.as_deref().unwrap_or_default(),
+        )),
+        _ => SelectedDiff::Message(match entry.status {
+            DirCompareStatus::Identical => "Binary files are identical.".to_string(),
+            _ => "Binary files differ.".to_string(),
+        }),
+    }
+}
+
+impl FocusedDirDiffView {
+    fn new(
+        config: FocusedDirDiffConfig,
+        exit_code: Arc<AtomicI32>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let ui_session = session::load();
+        let theme = AppTheme::default_for_window_appearance(window.appearance());
+        let font_preferences =
+            crate::font_preferences::current_or_initialize_from_session(window, &ui_session, cx);
+        let entries = compare_directories(&config.left, &config.right).map_err(|e| e.to_string());
+
+        Self {
+            config,
+            entries,
+            filter: StatusFilter::default(),
+            collapsed: HashSet::default(),
+            selected: None,
+            selected_diff: None,
+            last_error: None,
+            exit_code,
+            focus_handle: cx.focus_handle(),
+            tree_scroll: ScrollHandle::new(),
+            diff_scroll: ScrollHandle::new(),
+            theme,
+            ui_font_family: crate::font_preferences::applied_ui_font_family(
+                &font_preferences.ui_font_family,
+            ),
+            editor_font_family: crate::font_preferences::applied_editor_font_family(
+                &font_preferences.editor_font_family,
+            ),
+            use_font_ligatures: font_preferences.use_font_ligatures,
+        }
+    }
+
+    fn close(&mut self, cx: &mut Context<Self>) {
+        self.exit_code.store(0, Ordering::SeqCst);
+        cx.quit();
+    }
+
+    fn selected_entry(&self) -> Option<&DirCompareEntry> {
+        let selected = self.selected.as_ref()?;
+        self.entries
+            .as_ref()
+            .ok()?
+            .iter()
+            .find(|entry| &entry.path == selected)
+    }
+
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.entries =
+            compare_directories(&self.config.left, &self.config.right).map_err(|e| e.to_string());
+        self.selected_diff = self
+            .selected_entry()
+            .map(|entry| load_selected_diff(&self.config, entry));
+        if self.selected_diff.is_none() {
+            self.selected = None;
+        }
+        cx.notify();
+    }
+
+    fn select(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.selected = Some(path);
+        self.selected_diff = self
+            .selected_entry()
+            .map(|entry| load_selected_diff(&self.config, entry));
+        cx.notify();
+    }
+
+    fn toggle_collapsed(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        if !self.collapsed.remove(&path) {
+            self.collapsed.insert(path);
+        }
+        cx.notify();
+    }
+
+    fn sync_selected(&mut self, target: DirCompareSide, cx: &mut Context<Self>) {
+        let Some(path) = self.selected.clone() else {
+            return;
+        };
+        self.last_error = sync_entry(&self.config.left, &self.config.right, &path, target)
+            .err()
+            .map(|e| format!("Failed to copy {}: {e}", path.display()));
+        self.refresh(cx);
+    }
+}
+
+impl Focusable for FocusedDirDiffView {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+fn toolbar_button(
+    id: &'static str,
+    label: impl Into<SharedString>,
+    enabled: bool,
+    theme: &AppTheme,
+    window: &Window,
+) -> gpui::Stateful<gpui::Div> {
+    let scaled_px = |value| crate::ui_scale::design_px_from_window(value, window);
+    div()
+        .id(id)
+        .px(scaled_px(10.0))
+        .py(scaled_px(4.0))
+        .border_1()
+        .border_color(theme.colors.border)
+        .rounded(scaled_px(2.0))
+        .when(enabled, |d| d.cursor_pointer())
+        .when(!enabled, |d| d.text_color(theme.colors.text_muted))
+        .child(label.into())
+}
+
+impl Render for FocusedDirDiffView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = self.theme;
+        let scaled_px = |value| crate::ui_scale::design_px_from_window(value, window);
+        let title = format!("{} vs {}", self.config.label_left, self.config.label_right);
+        let has_selection = self.selected.is_some();
+
+        let mut filters = div().flex().flex_row().items_center().gap(scaled_px(4.0));
+        for status in [
+            DirCompareStatus::Added,
+            DirCompareStatus::Removed,
+            DirCompareStatus::Modified,
+            DirCompareStatus::Identical,
+        ] {
+            let count = self
+                .entries
+                .as_ref()
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .filter(|e| !e.is_dir && e.status == status)
+                        .count()
+                })
+                .unwrap_or(0);
+            let enabled = self.filter.allows(status);
+            filters = filters.child(
+                div()
+                    .id(status_label(status))
+                    .px(scaled_px(8.0))
+                    .py(scaled_px(3.0))
+                    .rounded(scaled_px(2.0))
+                    .border_1()
+                    .border_color(theme.colors.border)
+                    .cursor_pointer()
+                    .when(enabled, |d| d.bg(theme.colors.active))
+                    .text_color(status_color(&theme, status))
+                    .on_click(
+                        cx.listener(move |this, _e: &gpui::ClickEvent, _window, cx| {
+                            this.filter.toggle(status);
+                            cx.notify();
+                        }),
+                    )
+                    .child(SharedString::from(format!(
+                        "{} ({count})",
+                        status_label(status)
+                    ))),
+            );
+        }
+
+        let toolbar = div()
+            .w_full()
+            .px(scaled_px(12.0))
+            .py(scaled_px(8.0))
+            .bg(theme.colors.surface_bg)
+            .border_b_1()
+            .border_color(theme.colors.border)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(scaled_px(8.0))
+            .child(
+                div()
+                    .font_weight(FontWeight::BOLD)
+                    .text_size(scaled_px(14.0))
+                    .child(SharedString::from(title)),
+            )
+            .child(filters)
+            .child(div().flex_grow())
+            .child(
+                toolbar_button(
+                    "btn-copy-to-left",
+                    "← Copy to left",
+                    has_selection,
+                    &theme,
+                    window,
+                )
+                .on_click(cx.listener(
+                    |this, _e: &gpui::ClickEvent, _window, cx| {
+                        this.sync_selected(DirCompareSide::Left, cx);
+                    },
+                )),
+            )
+            .child(
+                toolbar_button(
+                    "btn-copy-to-right",
+                    "Copy to right →",
+                    has_selection,
+                    &theme,
+                    window,
+                )
+                .on_click(cx.listener(
+                    |this, _e: &gpui::ClickEvent, _window, cx| {
+                        this.sync_selected(DirCompareSide::Right, cx);
+                    },
+                )),
+            )
+            .child(
+                toolbar_button("btn-refresh", "Refresh", true, &theme, window).on_click(
+                    |_: &gpui::ClickEvent, _window, cx| {
+                        cx.dispatch_action(&Refresh);
+                    },
+                ),
+            )
+            .child(
+                div()
+                    .id("btn-close")
+                    .px(scaled_px(10.0))
+                    .py(scaled_px(4.0))
+                    .bg(theme.colors.accent)
+                    .text_color(theme.colors.accent_text)
+                    .rounded(scaled_px(2.0))
+                    .cursor_pointer()
+                    .font_weight(FontWeight::BOLD)
+                    .on_click(|_: &gpui::ClickEvent, _window, cx| {
+                        cx.dispatch_action(&Close);
+                    })
+                    .child("Close"),
+            );
+
+        let tree = match &self.entries {
+            Err(err) => div()
+                .p(scaled_px(12.0))
+                .text_color(theme.colors.danger)
+                .child(SharedString::from(err.clone()))
+                .into_any_element(),
+            Ok(entries) => {
+                let visible = visible_entries(entries, self.filter, &self.collapsed);
+                let rows = visible
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, entry)| {
+                        let path = entry.path.clone();
+                        let is_dir = entry.is_dir;
+                        let selected = self.selected.as_ref() == Some(&entry.path);
+                        let name = entry
+                            .path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        let caret = match (is_dir, self.collapsed.contains(&entry.path)) {
+                            (true, true) => "▸ ",
+                            (true, false) => "▾ ",
+                            (false, _) => "  ",
+                        };
+                        div()
+                            .id(("dir-diff-row", ix))
+                            .w_full()
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .pl(scaled_px(8.0 + 14.0 * entry.depth() as f32))
+                            .pr(scaled_px(8.0))
+                            .py(scaled_px(2.0))
+                            .cursor_pointer()
+                            .when(selected, |d| d.bg(theme.colors.active))
+                            .hover(move |s| s.bg(theme.colors.hover))
+                            .on_click(cx.listener(move |this, _e: &gpui::ClickEvent, _w, cx| {
+                                if is_dir && this.selected.as_ref() == Some(&path) {
+                                    this.toggle_collapsed(path.clone(), cx);
+                                } else {
+                                    this.select(path.clone(), cx);
+                                }
+                            }))
+                            .child(
+                                div()
+                                    .flex_grow()
+                                    .whitespace_nowrap()
+                                    .overflow_hidden()
+                                    .child(SharedString::from(format!("{caret}{name}"))),
+                            )
+                            .child(
+                                div()
+                                    .text_size(scaled_px(11.0))
+                                    .text_color(status_color(&theme, entry.status))
+                                    .child(status_label(entry.status)),
+                            )
+                    })
+                    .collect::<Vec<_>>();
+                if rows.is_empty() {
+                    div()
+                        .p(scaled_px(12.0))
+                        .text_color(theme.colors.text_muted)
+                        .child("No entries match the current filters.")
+                        .into_any_element()
+                } else {
+                    div()
+                        .id("dir-diff-tree-rows")
+                        .size_full()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.tree_scroll)
+                        .children(rows)
+                        .into_any_element()
+                }
+            }
+        };
+
+        let diff_pane = match &self.selected_diff {
+            None => div()
+                .p(scaled_px(12.0))
+                .text_color(theme.colors.text_muted)
+                .child("Select a file to compare.")
+                .into_any_element(),
+            Some(SelectedDiff::Message(message)) => div()
+                .p(scaled_px(12.0))
+                .text_color(theme.colors.text_muted)
+                .child(SharedString::from(message.clone()))
+                .into_any_element(),
+            Some(SelectedDiff::File(diff)) => div()
+                .id("dir-diff-file-rows")
+                .size_full()
+                .overflow_y_scroll()
+                .track_scroll(&self.diff_scroll)
+                .font_family(self.editor_font_family.clone())
+                .children(diff.render_rows(&theme, window))
+                .into_any_element(),
+        };
+
+        div()
+            .id("focused-dir-diff-root")
+            .key_context("FocusedDirDiff")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(|this, _: &Close, _window, cx| this.close(cx)))
+            .on_action(cx.listener(|this, _: &Refresh, _window, cx| this.refresh(cx)))
+            .size_full()
+            .bg(theme.colors.window_bg)
+            .text_color(theme.colors.text)
+            .font(gpui::Font {
+                family: self.ui_font_family.clone().into(),
+                features: crate::font_preferences::applied_font_features(self.use_font_ligatures),
+                fallbacks: None,
+                weight: FontWeight::default(),
+                style: gpui::FontStyle::default(),
+            })
+            .text_size(scaled_px(13.0))
+            .flex()
+            .flex_col()
+            .child(toolbar)
+            .when_some(self.last_error.clone(), |d, err| {
+                d.child(
+                    div()
+                        .px(scaled_px(12.0))
+                        .py(scaled_px(4.0))
+                        .text_color(theme.colors.danger)
+                        .child(SharedString::from(err)),
+                )
+            })
+            .child(
+                div()
+                    .flex_grow()
+                    .min_h(scaled_px(0.0))
+                    .flex()
+                    .flex_row()
+                    .child(
+                        div()
+                            .w(gpui::relative(0.35))
+                            .h_full()
+                            .border_r_1()
+                            .border_color(theme.colors.border)
+                            .child(tree),
+                    )
+                    .child(
+                        div()
+                            .flex_grow()
+                            .h_full()
+                            .min_w(scaled_px(0.0))
+                            .child(diff_pane),
+                    ),
+            )
+    }
+}
+
+fn bind_focused_dir_diff_keys(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Close, Some("FocusedDirDiff")),
+        KeyBinding::new("q", Close, Some("FocusedDirDiff")),
+        KeyBinding::new("ctrl-w", Close, Some("FocusedDirDiff")),
+        KeyBinding::new("cmd-w", Close, Some("FocusedDirDiff")),
+        KeyBinding::new("f5", Refresh, Some("FocusedDirDiff")),
+        KeyBinding::new("secondary-r", Refresh, Some("FocusedDirDiff")),
+    ]);
+}
+
+// ── Public entry point ───────────────────────────────────────────────
+
+/// Launch the directory-compare window.
+///
+/// Returns process exit code (0 on success, 2 when the window fails to launch).
+pub fn run_focused_dir_diff(config: FocusedDirDiffConfig) -> i32 {
+    if let Err(err) =
+        crate::app::ensure_graphics_device_available("focused directory diff GPUI launch")
+    {
+        eprintln!("Failed to launch directory diff window: {err}");
+        return FOCUSED_DIR_DIFF_EXIT_ERROR;
+    }
+
+    let exit_code = Arc::new(AtomicI32::new(0));
+    let exit_code_for_app = exit_code.clone();
+
+    if let Err(err) = run_with_panic_guard("focused directory diff GPUI launch", move || {
+        crate::app::application()
+            .with_assets(GitCometAssets)
+            .run(move |cx: &mut App| {
+                if let Err(err) = crate::bundled_fonts::register(cx) {
+                    eprintln!("Failed to register bundled fonts: {err:#}");
+                }
+                let ui_session = session::load();
+                let ui_scale = crate::ui_scale::current_or_initialize_from_session(&ui_session, cx);
+                cx.on_window_closed(|cx| {
+                    if cx.windows().is_empty() {
+                        cx.quit();
+                    }
+                })
+                .detach();
+
+                bind_focused_dir_diff_keys(cx);
+
+                let exit_code_clone = exit_code_for_app.clone();
+                let bounds = Bounds::centered(
+                    None,
+                    focused_dir_diff_default_size_for_percent(ui_scale.percent),
+                    cx,
+                );
+                let ui_scale_percent = ui_scale.percent;
+
+                cx.open_window(
+                    WindowOptions {
+                        window_bounds: Some(WindowBounds::Windowed(bounds)),
+                        window_min_size: Some(focused_dir_diff_min_size_for_percent(
+                            ui_scale_percent,
+                        )),
+                        titlebar: Some(TitlebarOptions {
+                            title: Some("GitComet — Directory Diff".into()),
+                            appears_transparent: false,
+                            traffic_light_position: Some(point(
+                                crate::ui_scale::design_px_from_percent(9.0, ui_scale_percent),
+                                crate::ui_scale::design_px_from_percent(9.0, ui_scale_percent),
+                            )),
+                        }),
+                        app_id: Some("gitcomet-diff".to_string()),
+                        window_decorations: Some(WindowDecorations::Server),
+                        is_movable: true,
+                        is_resizable: true,
+                        ..Default::default()
+                    },
+                    move |window, cx| {
+                        crate::ui_scale::apply_to_window(window, ui_scale_percent);
+                        cx.new(|cx| {
+                            let view = FocusedDirDiffView::new(config, exit_code_clone, window, cx);
+                            cx.focus_self(window);
+                            view
+                        })
+                    },
+                )
+                .expect("failed to open directory diff window");
+
+                cx.activate(true);
+            });
+    }) {
+        eprintln!("Failed to launch directory diff window: {err}");
+        return FOCUSED_DIR_DIFF_EXIT_ERROR;
+    }
+
+    exit_code.load(Ordering::SeqCst)
+}
+
+// ── Tests ────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, is_dir: bool, status: DirCompareStatus) -> DirCompareEntry {
+        DirCompareEntry {
+            path: PathBuf::from(path),
+            is_dir,
+            status,
+        }
+    }
+
+    #[test]
+    fn visible_entries_apply_filter_and_collapsed_dirs() {
+        let entries = vec![
+            entry("src", true, DirCompareStatus::Modified),
+            entry("src/a.rs", false, DirCompareStatus::Modified),
+            entry("src/b.rs", false, DirCompareStatus::Identical),
+            entry("z.txt", false, DirCompareStatus::Added),
+        ];
+
+        let visible = visible_entries(&entries, StatusFilter::default(), &HashSet::default());
+        let paths = visible.iter().map(|e| e.path.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("src"),
+                PathBuf::from("src/a.rs"),
+                PathBuf::from("z.txt")
+            ]
+        );
+
+        let mut collapsed = HashSet::default();
+        collapsed.insert(PathBuf::from("src"));
+        let visible = visible_entries(&entries, StatusFilter::default(), &collapsed);
+        assert_eq!(visible.len(), 2);
+    }
+
+    #[test]
+    fn status_filter_toggle_flips_single_status() {
+        let mut filter = StatusFilter::default();
+        assert!(!filter.allows(DirCompareStatus::Identical));
+        filter.toggle(DirCompareStatus::Identical);
+        assert!(filter.allows(DirCompareStatus::Identical));
+        filter.toggle(DirCompareStatus::Added);
+        assert!(!filter.allows(DirCompareStatus::Added));
+    }
+}
@@ -3,6 +3,7 @@ mod assets;
 mod bundled_fonts;
 mod clipboard;
 pub mod focused_diff;
+pub mod focused_dir_diff;
 mod font_preferences;
 mod kit;
 mod launch_guard;
@@ -22,6 +23,7 @@ mod view;
 
 pub use app::{FocusedMergetoolConfig, run, run_focused_mergetool, run_with_startup_crash_report};
 pub use focused_diff::{FocusedDiffConfig, run_focused_diff};
+pub use focused_dir_diff::{FocusedDirDiffConfig, run_focused_dir_diff};
 pub use launch_guard::UiLaunchError;
 pub use view::StartupCrashReport;
 
@@ -2051,6 +2051,8 @@ fn listed_workspace_badge_double_click_opens_closed_repo_tab(cx: &mut gpui::Test
                 head: None,
                 branch: Some("feature/workspace".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             }]),
         },
     ));
@@ -2110,6 +2112,8 @@ fn branch_worktree_badge_aligns_to_edge_and_branch_menu_opens_on_right_click(
                 head: None,
                 branch: Some("feature/workspace".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             }]),
         },
     ));
@@ -2186,6 +2190,8 @@ fn worktree_branch_badge_shows_full_tooltip_when_truncated(cx: &mut gpui::TestAp
                 head: None,
                 branch: Some(branch.clone()),
                 detached: false,
+                locked: None,
+                prunable: None,
             }]),
         },
     ));
@@ -2243,6 +2249,8 @@ fn worktree_branch_and_path_stay_within_one_sidebar_row(cx: &mut gpui::TestAppCo
                 head: None,
                 branch: Some(branch.clone()),
                 detached: false,
+                locked: None,
+                prunable: None,
             }]),
         },
     ));
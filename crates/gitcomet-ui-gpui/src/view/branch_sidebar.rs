@@ -119,6 +119,13 @@ pub(super) enum BranchSidebarRow {
         branch: Option<SharedString>,
         detached: bool,
         is_active: bool,
+        /// Lock reason, empty when the worktree was locked without one.
+        locked: Option<SharedString>,
+        prunable: bool,
+        /// `None` until the worktree overview has been loaded.
+        dirty_files: Option<usize>,
+        divergence_ahead: Option<NonZeroU32>,
+        divergence_behind: Option<NonZeroU32>,
     },
     SubmodulesHeader {
         top_border: bool,
@@ -902,6 +909,8 @@ pub(super) fn branch_sidebar_rows(
                 let mut any = false;
                 for worktree in worktrees.iter() {
                     any = true;
+                    let summary = repo.worktree_summary(&worktree.path);
+                    let upstream = summary.and_then(|summary| summary.upstream);
                     rows.push(BranchSidebarRow::WorktreeItem {
                         path: worktree.path.clone(),
                         branch: worktree
@@ -910,6 +919,16 @@ pub(super) fn branch_sidebar_rows(
                             .map(|branch| SharedString::new(branch.as_str())),
                         detached: worktree.detached,
                         is_active: worktree.path == repo.spec.workdir,
+                        locked: worktree
+                            .locked
+                            .as_ref()
+                            .map(|reason| SharedString::new(reason.as_str())),
+                        prunable: worktree.is_prunable(),
+                        dirty_files: summary.map(|summary| summary.dirty_files),
+                        divergence_ahead: upstream
+                            .and_then(|upstream| branch_sidebar_divergence_count(upstream.ahead)),
+                        divergence_behind: upstream
+                            .and_then(|upstream| branch_sidebar_divergence_count(upstream.behind)),
                     });
                 }
                 if !any {
@@ -1616,12 +1635,16 @@ mod tests {
                 head: Some(commit_id("aaaaaaaa")),
                 branch: Some("main".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: PathBuf::from("/tmp/repo-linked"),
                 head: Some(commit_id("bbbbbbbb")),
                 branch: Some("feature/topic".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
         ]));
         repo.worktrees_rev = 1;
@@ -1653,6 +1676,8 @@ mod tests {
                 path: PathBuf::from("src/lib.rs"),
                 kind: FileStatusKind::Modified,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
         }));
 
@@ -1674,12 +1699,16 @@ mod tests {
                 head: Some(commit_id("aaaaaaaa")),
                 branch: Some("main".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: PathBuf::from("/tmp/repo-linked"),
                 head: Some(commit_id("eeeeeeee")),
                 branch: None,
                 detached: true,
+                locked: None,
+                prunable: None,
             },
         ]));
         repo.worktrees_rev = repo.worktrees_rev.wrapping_add(1);
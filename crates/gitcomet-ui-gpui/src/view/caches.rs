@@ -3,7 +3,7 @@ use super::branch_sidebar::{
     branch_sidebar_source_matches_cached,
 };
 use super::*;
-use gitcomet_core::domain::{Branch, LogScope, RemoteBranch, StashEntry, Tag};
+use gitcomet_core::domain::{Branch, CommitParentIds, LogScope, RemoteBranch, StashEntry, Tag};
 use rustc_hash::FxHasher;
 use smallvec::SmallVec;
 use std::cell::RefCell;
@@ -293,6 +293,7 @@ pub(super) struct HistoryBaseRowVm {
     pub(super) short_sha: HistoryShortShaVm,
     pub(super) is_head: bool,
     pub(super) is_stash: bool,
+    pub(super) is_fixup: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -310,6 +311,133 @@ pub(in crate::view) fn history_commit_is_probable_stash_tip(commit: &Commit) ->
     (summary.starts_with("WIP on ") || summary.starts_with("On ")) && summary.contains(": ")
 }
 
+/// The subject a `fixup!`/`squash!`/`amend!` commit points at, with the
+/// markers of nested fixups stripped.
+fn history_fixup_target_subject(summary: &str) -> Option<&str> {
+    let mut rest = summary;
+    while let Some(next) = ["fixup! ", "squash! ", "amend! "]
+        .iter()
+        .find_map(|marker| rest.strip_prefix(marker))
+    {
+        rest = next;
+    }
+    (rest.len() < summary.len() && !rest.trim().is_empty()).then_some(rest)
+}
+
+/// Maps the index of every autosquash commit whose target (matched by subject
+/// or by abbreviated id) is an older commit in `commits`, which is newest
+/// first, to the index of that target.
+pub(in crate::view) fn history_fixup_targets(commits: &[Commit]) -> HashMap<usize, usize> {
+    let mut fixups = HashMap::default();
+    let mut pending: HashMap<&str, Vec<usize>> = HashMap::default();
+    for (ix, commit) in commits.iter().enumerate() {
+        let summary: &str = &commit.summary;
+        if let Some(target) = history_fixup_target_subject(summary) {
+            pending.entry(target).or_default().push(ix);
+            continue;
+        }
+        if pending.is_empty() {
+            continue;
+        }
+        if let Some(ixs) = pending.remove(summary) {
+            fixups.extend(ixs.into_iter().map(|fixup_ix| (fixup_ix, ix)));
+        }
+        let id: &str = commit.id.as_ref();
+        pending.retain(|target, ixs| {
+            let is_id_prefix = target.len() >= 4
+                && target.bytes().all(|b| b.is_ascii_hexdigit())
+                && id.starts_with(*target);
+            if is_id_prefix {
+                fixups.extend(ixs.iter().map(|&fixup_ix| (fixup_ix, ix)));
+            }
+            !is_id_prefix
+        });
+    }
+    fixups
+}
+
+/// Display order for `visible` (commit indices, newest first) with each
+/// fixup whose target is also visible moved directly below that target.
+/// Fixups of one target keep their relative order. Returns the reordered
+/// commit indices and the set of fixups that moved under a target, or `None`
+/// when nothing is grouped.
+pub(in crate::view) fn history_fixup_grouped_order(
+    visible: impl IntoIterator<Item = usize>,
+    targets: &HashMap<usize, usize>,
+) -> Option<(Vec<usize>, HashSet<usize>)> {
+    if targets.is_empty() {
+        return None;
+    }
+    let visible: Vec<usize> = visible.into_iter().collect();
+    let visible_set: HashSet<usize> = visible.iter().copied().collect();
+    let mut below: HashMap<usize, Vec<usize>> = HashMap::default();
+    let mut grouped = HashSet::default();
+    for &ix in &visible {
+        if let Some(&target) = targets.get(&ix)
+            && visible_set.contains(&target)
+        {
+            below.entry(target).or_default().push(ix);
+            grouped.insert(ix);
+        }
+    }
+    if grouped.is_empty() {
+        return None;
+    }
+
+    let mut order = Vec::with_capacity(visible.len());
+    for ix in visible {
+        if grouped.contains(&ix) {
+            continue;
+        }
+        order.push(ix);
+        if let Some(fixups) = below.get(&ix) {
+            order.extend(fixups.iter().copied());
+        }
+    }
+    Some((order, grouped))
+}
+
+/// Commits in display `order` for the history graph. Grouped fixups become
+/// isolated nodes next to their target, and edges through them are rerouted
+/// to their own parents so the surrounding line of history stays unbroken.
+pub(in crate::view) fn history_graph_commits_with_grouped_fixups(
+    commits: &[Commit],
+    order: &[usize],
+    grouped: &HashSet<usize>,
+) -> Vec<Commit> {
+    let grouped_parents: HashMap<&CommitId, &[CommitId]> = grouped
+        .iter()
+        .filter_map(|&ix| commits.get(ix))
+        .map(|commit| (&commit.id, commit.parent_ids.as_slice()))
+        .collect();
+    let resolve_parents = |parent_ids: &[CommitId]| {
+        let mut resolved = CommitParentIds::new();
+        let mut stack: Vec<&CommitId> = parent_ids.iter().rev().collect();
+        while let Some(parent) = stack.pop() {
+            match grouped_parents.get(parent) {
+                Some(parents) => stack.extend(parents.iter().rev()),
+                None if !resolved.contains(parent) => resolved.push(parent.clone()),
+                None => {}
+            }
+        }
+        resolved
+    };
+
+    order
+        .iter()
+        .filter_map(|&ix| {
+            let commit = commits.get(ix)?;
+            let mut commit = commit.clone();
+            commit.parent_ids = if grouped.contains(&ix) {
+                CommitParentIds::new()
+            } else {
+                resolve_parents(&commit.parent_ids)
+            };
+            Some(commit)
+        })
+        .collect()
+}
+
 pub(in crate::view) fn analyze_history_stashes<'a>(
     commits: &'a [Commit],
     stashes: &'a [StashEntry],
@@ -848,6 +976,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn history_fixup_commits_group_under_loaded_targets() {
+        let commits = vec![
+            commit("f3", &["f2"], "fixup! missing target"),
+            commit("f2", &["f1"], "squash! fixup! b"),
+            commit("f1", &["c"], "fixup! abcd1234"),
+            commit("c", &["b"], "c"),
+            commit("b", &["abcd1234ff"], "b"),
+            commit("abcd1234ff", &[], "a"),
+        ];
+        let mut fixups: Vec<(usize, usize)> = history_fixup_targets(&commits).into_iter().collect();
+        fixups.sort_unstable();
+        assert_eq!(fixups, vec![(1, 4), (2, 5)]);
+        assert_eq!(history_fixup_target_subject("fixup! "), None);
+        assert_eq!(history_fixup_target_subject("plain"), None);
+    }
+
+    #[test]
+    fn history_fixups_move_below_interleaved_targets() {
+        let commits = vec![
+            commit("f2", &["x"], "fixup! a"),
+            commit("x", &["f1"], "x"),
+            commit("f1", &["b"], "squash! b"),
+            commit("b", &["a"], "b"),
+            commit("a", &["root"], "a"),
+        ];
+        let targets = history_fixup_targets(&commits);
+        let (order, grouped) =
+            history_fixup_grouped_order(0..commits.len(), &targets).expect("fixups are grouped");
+        assert_eq!(order, vec![1, 3, 2, 4, 0]);
+        let mut grouped_ixs: Vec<usize> = grouped.iter().copied().collect();
+        grouped_ixs.sort_unstable();
+        assert_eq!(grouped_ixs, vec![0, 2]);
+
+        let graph = history_graph_commits_with_grouped_fixups(&commits, &order, &grouped);
+        let edges: Vec<(&str, Vec<&str>)> = graph
+            .iter()
+            .map(|commit| {
+                (
+                    commit.id.as_ref(),
+                    commit.parent_ids.iter().map(AsRef::as_ref).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            edges,
+            vec![
+                ("x", vec!["b"]),
+                ("b", vec!["a"]),
+                ("f1", vec![]),
+                ("a", vec!["root"]),
+                ("f2", vec![]),
+            ]
+        );
+
+        let hidden_target = [0, 1, 2, 3];
+        let (order, _) =
+            history_fixup_grouped_order(hidden_target, &targets).expect("f1 still groups under b");
+        assert_eq!(order, vec![0, 1, 3, 2]);
+        assert!(history_fixup_grouped_order([0, 1], &targets).is_none());
+    }
+
     #[test]
     fn history_branch_text_cache_precomputes_head_and_remote_labels() {
         let commit_a = commit_id("a");
@@ -923,7 +923,6 @@ impl ConflictSplitRowIndex {
     /// Searches old (ours) and new (theirs) text for each row without
     /// allocating `FileDiffRow` objects, making this much cheaper than
     /// iterating `row_at()` for every row in a giant file.
-    #[cfg(test)]
     pub fn search_matching_rows(
         &self,
         segments: &[ConflictSegment],
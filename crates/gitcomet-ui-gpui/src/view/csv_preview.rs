@@ -0,0 +1,575 @@
+use gitcomet_core::file_diff::{FileDiffPlanRun, side_by_side_plan_from_lines};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::collections::VecDeque;
+
+/// Maximum combined source size (bytes) for a two-sided table preview.
+pub(super) const MAX_DIFF_PREVIEW_SOURCE_BYTES: usize = 4 * 1_024 * 1_024; // 4 MiB
+
+/// Maximum number of data records per side.
+pub(super) const MAX_PREVIEW_ROWS: usize = 50_000;
+
+/// Separates fields when a record is flattened into one comparable line.
+const RECORD_KEY_FIELD_SEPARATOR: char = '\u{1f}';
+
+// ── Core types ──────────────────────────────────────────────────────────
+
+/// How records of the old and new table are paired up.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub(super) enum CsvRowAlignment {
+    /// Diff records in file order, like a line diff over whole records.
+    #[default]
+    Position,
+    /// Pair records whose value in the named column matches.
+    KeyColumn(String),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum CsvChangeKind {
+    Unchanged,
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct CsvDiffColumn {
+    pub(super) name: String,
+    /// `Unchanged` for columns in both headers, `Added`/`Removed` otherwise.
+    pub(super) kind: CsvChangeKind,
+    old_ix: Option<usize>,
+    new_ix: Option<usize>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct CsvDiffCell {
+    pub(super) old: Option<String>,
+    pub(super) new: Option<String>,
+}
+
+impl CsvDiffCell {
+    pub(super) fn is_changed(&self) -> bool {
+        self.old != self.new
+    }
+
+    pub(super) fn display_text(&self) -> String {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) if old != new => format!("{old} → {new}"),
+            (_, Some(new)) => new.clone(),
+            (Some(old), None) => old.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct CsvDiffRow {
+    pub(super) kind: CsvChangeKind,
+    /// 1-based record numbers, excluding the header record.
+    pub(super) old_record: Option<usize>,
+    pub(super) new_record: Option<usize>,
+    pub(super) cells: Vec<CsvDiffCell>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct CsvDiffPreview {
+    pub(super) columns: Vec<CsvDiffColumn>,
+    pub(super) rows: Vec<CsvDiffRow>,
+    /// Column used to pair records, when alignment by key was possible.
+    pub(super) key_column: Option<usize>,
+}
+
+impl CsvDiffPreview {
+    pub(super) fn count(&self, kind: CsvChangeKind) -> usize {
+        self.rows.iter().filter(|row| row.kind == kind).count()
+    }
+}
+
+/// Scrollbar markers for changed records, bucketed like other diff views.
+pub(super) fn scrollbar_markers(
+    preview: &CsvDiffPreview,
+) -> Vec<crate::view::components::ScrollbarMarker> {
+    super::diff_utils::scrollbar_markers_from_flags(preview.rows.len(), |ix| {
+        match preview.rows.get(ix).map(|row| row.kind) {
+            Some(CsvChangeKind::Added) => 1,
+            Some(CsvChangeKind::Removed) => 2,
+            Some(CsvChangeKind::Modified) => 3,
+            Some(CsvChangeKind::Unchanged) | None => 0,
+        }
+    })
+}
+
+// ── Parsing ─────────────────────────────────────────────────────────────
+
+pub(super) fn is_delimited_table_path(path: &std::path::Path) -> bool {
+    delimiter_for_path(path).is_some()
+}
+
+pub(super) fn delimiter_for_path(path: &std::path::Path) -> Option<char> {
+    let ext = path.extension()?.to_str()?;
+    if ext.eq_ignore_ascii_case("csv") {
+        Some(',')
+    } else if ext.eq_ignore_ascii_case("tsv") || ext.eq_ignore_ascii_case("tab") {
+        Some('\t')
+    } else {
+        None
+    }
+}
+
+/// Parses RFC 4180 style records: quoted fields may contain delimiters,
+/// doubled quotes and line breaks; both `\n` and `\r\n` end a record.
+pub(super) fn parse_delimited(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut field_started = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(ch);
+            }
+            continue;
+        }
+        match ch {
+            '"' if !field_started => {
+                in_quotes = true;
+                field_started = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                field_started = false;
+            }
+            ch if ch == delimiter => {
+                record.push(std::mem::take(&mut field));
+                field_started = false;
+            }
+            ch => {
+                field.push(ch);
+                field_started = true;
+            }
+        }
+    }
+    if field_started || !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+// ── Diffing ─────────────────────────────────────────────────────────────
+
+/// Keys header names by occurrence, so duplicate names like `a,a` still pair up.
+fn column_occurrence_keys(header: &[String]) -> Vec<(&str, usize)> {
+    let mut seen: HashMap<&str, usize> = HashMap::default();
+    header
+        .iter()
+        .map(|name| {
+            let count = seen.entry(name.as_str()).or_default();
+            *count += 1;
+            (name.as_str(), *count)
+        })
+        .collect()
+}
+
+fn align_columns(old_header: &[String], new_header: &[String]) -> Vec<CsvDiffColumn> {
+    let old_keys = column_occurrence_keys(old_header);
+    let new_keys = column_occurrence_keys(new_header);
+    let old_ix_by_key: HashMap<(&str, usize), usize> = old_keys
+        .iter()
+        .enumerate()
+        .map(|(ix, key)| (*key, ix))
+        .collect();
+    let new_key_set: HashSet<(&str, usize)> = new_keys.iter().copied().collect();
+
+    let mut columns: Vec<CsvDiffColumn> = new_keys
+        .iter()
+        .enumerate()
+        .map(|(new_ix, key)| {
+            let old_ix = old_ix_by_key.get(key).copied();
+            CsvDiffColumn {
+                name: new_header[new_ix].clone(),
+                kind: if old_ix.is_some() {
+                    CsvChangeKind::Unchanged
+                } else {
+                    CsvChangeKind::Added
+                },
+                old_ix,
+                new_ix: Some(new_ix),
+            }
+        })
+        .collect();
+
+    // Removed columns go right after the nearest surviving column that
+    // preceded them in the old header.
+    for (old_ix, key) in old_keys.iter().enumerate() {
+        if new_key_set.contains(key) {
+            continue;
+        }
+        let insert_at = columns
+            .iter()
+            .rposition(|column| column.old_ix.is_some_and(|ix| ix < old_ix))
+            .map_or(0, |pos| pos + 1);
+        columns.insert(
+            insert_at,
+            CsvDiffColumn {
+                name: old_header[old_ix].clone(),
+                kind: CsvChangeKind::Removed,
+                old_ix: Some(old_ix),
+                new_ix: None,
+            },
+        );
+    }
+    columns
+}
+
+fn field(record: &[String], ix: Option<usize>) -> Option<&str> {
+    ix.map(|ix| record.get(ix).map_or("", String::as_str))
+}
+
+/// Flattens the fields of columns present on both sides, so added or removed
+/// columns alone do not mark every record as changed.
+fn shared_record_key(columns: &[CsvDiffColumn], record: &[String], old: bool) -> String {
+    let mut key = String::new();
+    for column in columns
+        .iter()
+        .filter(|column| column.kind == CsvChangeKind::Unchanged)
+    {
+        let ix = if old { column.old_ix } else { column.new_ix };
+        key.extend(
+            field(record, ix)
+                .unwrap_or_default()
+                .chars()
+                .map(|ch| if ch == '\n' { '\u{1e}' } else { ch }),
+        );
+        key.push(RECORD_KEY_FIELD_SEPARATOR);
+    }
+    key
+}
+
+fn diff_row(
+    columns: &[CsvDiffColumn],
+    old: Option<(usize, &[String])>,
+    new: Option<(usize, &[String])>,
+) -> CsvDiffRow {
+    let cells: Vec<CsvDiffCell> = columns
+        .iter()
+        .map(|column| CsvDiffCell {
+            old: old.and_then(|(_, record)| field(record, column.old_ix).map(str::to_string)),
+            new: new.and_then(|(_, record)| field(record, column.new_ix).map(str::to_string)),
+        })
+        .collect();
+    let kind = match (old, new) {
+        (Some(_), None) => CsvChangeKind::Removed,
+        (None, _) => CsvChangeKind::Added,
+        (Some(_), Some(_)) => {
+            let shared_changed = columns
+                .iter()
+                .zip(&cells)
+                .any(|(column, cell)| column.kind == CsvChangeKind::Unchanged && cell.is_changed());
+            if shared_changed {
+                CsvChangeKind::Modified
+            } else {
+                CsvChangeKind::Unchanged
+            }
+        }
+    };
+    CsvDiffRow {
+        kind,
+        old_record: old.map(|(ix, _)| ix + 1),
+        new_record: new.map(|(ix, _)| ix + 1),
+        cells,
+    }
+}
+
+fn align_rows_by_position(
+    columns: &[CsvDiffColumn],
+    old_records: &[Vec<String>],
+    new_records: &[Vec<String>],
+) -> Vec<CsvDiffRow> {
+    let old_keys: Vec<String> = old_records
+        .iter()
+        .map(|record| shared_record_key(columns, record, true))
+        .collect();
+    let new_keys: Vec<String> = new_records
+        .iter()
+        .map(|record| shared_record_key(columns, record, false))
+        .collect();
+    let old_lines: Vec<&str> = old_keys.iter().map(String::as_str).collect();
+    let new_lines: Vec<&str> = new_keys.iter().map(String::as_str).collect();
+    let plan = side_by_side_plan_from_lines("", "", &old_lines, &new_lines);
+
+    let old_at = |ix: usize| Some((ix, old_records[ix].as_slice()));
+    let new_at = |ix: usize| Some((ix, new_records[ix].as_slice()));
+    let mut rows = Vec::with_capacity(plan.row_count);
+    for run in &plan.runs {
+        match *run {
+            FileDiffPlanRun::Context {
+                old_start,
+                new_start,
+                len,
+            }
+            | FileDiffPlanRun::Modify {
+                old_start,
+                new_start,
+                len,
+            } => rows.extend(
+                (0..len).map(|i| diff_row(columns, old_at(old_start + i), new_at(new_start + i))),
+            ),
+            FileDiffPlanRun::Remove { old_start, len } => {
+                rows.extend((0..len).map(|i| diff_row(columns, old_at(old_start + i), None)))
+            }
+            FileDiffPlanRun::Add { new_start, len } => {
+                rows.extend((0..len).map(|i| diff_row(columns, None, new_at(new_start + i))))
+            }
+        }
+    }
+    rows
+}
+
+fn align_rows_by_key(
+    columns: &[CsvDiffColumn],
+    key_column: &CsvDiffColumn,
+    old_records: &[Vec<String>],
+    new_records: &[Vec<String>],
+) -> Vec<CsvDiffRow> {
+    let mut old_by_key: HashMap<&str, VecDeque<usize>> = HashMap::default();
+    for (ix, record) in old_records.iter().enumerate() {
+        let key = field(record, key_column.old_ix).unwrap_or_default();
+        old_by_key.entry(key).or_default().push_back(ix);
+    }
+
+    let mut matched_old = vec![false; old_records.len()];
+    let mut pairs = Vec::with_capacity(new_records.len());
+    for (new_ix, record) in new_records.iter().enumerate() {
+        let key = field(record, key_column.new_ix).unwrap_or_default();
+        let old_ix = old_by_key.get_mut(key).and_then(VecDeque::pop_front);
+        if let Some(old_ix) = old_ix {
+            matched_old[old_ix] = true;
+        }
+        pairs.push((old_ix, new_ix));
+    }
+
+    // Unmatched old records are emitted just before the first matched record
+    // that followed them, keeping removals near where they used to be.
+    let mut rows = Vec::with_capacity(old_records.len().max(new_records.len()));
+    let mut next_old = 0;
+    let flush_removed = |rows: &mut Vec<CsvDiffRow>, until: usize, next_old: &mut usize| {
+        while *next_old < until {
+            if !matched_old[*next_old] {
+                rows.push(diff_row(
+                    columns,
+                    Some((*next_old, old_records[*next_old].as_slice())),
+                    None,
+                ));
+            }
+            *next_old += 1;
+        }
+    };
+    for (old_ix, new_ix) in pairs {
+        let new = Some((new_ix, new_records[new_ix].as_slice()));
+        match old_ix {
+            Some(old_ix) => {
+                if old_ix >= next_old {
+                    flush_removed(&mut rows, old_ix, &mut next_old);
+                    next_old = old_ix + 1;
+                }
+                rows.push(diff_row(
+                    columns,
+                    Some((old_ix, old_records[old_ix].as_slice())),
+                    new,
+                ));
+            }
+            None => rows.push(diff_row(columns, None, new)),
+        }
+    }
+    flush_removed(&mut rows, old_records.len(), &mut next_old);
+    rows
+}
+
+/// Builds a cell-level diff of two delimited tables. The first record of each
+/// side is the header; columns pair up by name. Returns `None` when either
+/// side exceeds [`MAX_PREVIEW_ROWS`].
+pub(super) fn build_csv_diff_preview(
+    old_text: &str,
+    new_text: &str,
+    delimiter: char,
+    alignment: &CsvRowAlignment,
+) -> Option<CsvDiffPreview> {
+    let mut old_records = parse_delimited(old_text, delimiter);
+    let mut new_records = parse_delimited(new_text, delimiter);
+    if old_records.len() > MAX_PREVIEW_ROWS + 1 || new_records.len() > MAX_PREVIEW_ROWS + 1 {
+        return None;
+    }
+    let old_header = if old_records.is_empty() {
+        Vec::new()
+    } else {
+        old_records.remove(0)
+    };
+    let new_header = if new_records.is_empty() {
+        Vec::new()
+    } else {
+        new_records.remove(0)
+    };
+    let columns = align_columns(&old_header, &new_header);
+
+    let key_column = match alignment {
+        CsvRowAlignment::Position => None,
+        CsvRowAlignment::KeyColumn(name) => columns
+            .iter()
+            .position(|column| column.kind == CsvChangeKind::Unchanged && &column.name == name),
+    };
+    let rows = match key_column {
+        Some(key_ix) => align_rows_by_key(&columns, &columns[key_ix], &old_records, &new_records),
+        None => align_rows_by_position(&columns, &old_records, &new_records),
+    };
+    Some(CsvDiffPreview {
+        columns,
+        rows,
+        key_column,
+    })
+}
+
+pub(super) fn diff_preview_unavailable_reason(combined_len: usize) -> &'static str {
+    if combined_len > MAX_DIFF_PREVIEW_SOURCE_BYTES {
+        "Table preview unavailable: diff exceeds the 4 MiB preview limit."
+    } else {
+        "Table preview unavailable: record limit exceeded."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(preview: &CsvDiffPreview) -> Vec<CsvChangeKind> {
+        preview.rows.iter().map(|row| row.kind).collect()
+    }
+
+    #[test]
+    fn parse_delimited_handles_quotes_crlf_and_embedded_newlines() {
+        let records = parse_delimited(
+            "\u{feff}id,note\r\n1,\"a, \"\"quoted\"\"\nline\"\r\n2,\n",
+            ',',
+        );
+        assert_eq!(
+            records,
+            vec![
+                vec!["id".to_string(), "note".to_string()],
+                vec!["1".to_string(), "a, \"quoted\"\nline".to_string()],
+                vec!["2".to_string(), String::new()],
+            ]
+        );
+        assert_eq!(
+            parse_delimited("a\tb\n1\t2", '\t'),
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["1".to_string(), "2".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn columns_pair_by_name_and_report_added_and_removed() {
+        let preview = build_csv_diff_preview(
+            "id,name,legacy,qty\n1,a,x,5\n",
+            "id,qty,name,color\n1,6,a,red\n",
+            ',',
+            &CsvRowAlignment::Position,
+        )
+        .expect("preview");
+
+        let columns: Vec<(&str, CsvChangeKind)> = preview
+            .columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.kind))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", CsvChangeKind::Unchanged),
+                ("qty", CsvChangeKind::Unchanged),
+                ("name", CsvChangeKind::Unchanged),
+                ("legacy", CsvChangeKind::Removed),
+                ("color", CsvChangeKind::Added),
+            ]
+        );
+        let row = &preview.rows[0];
+        assert_eq!(row.kind, CsvChangeKind::Modified);
+        let changed: Vec<bool> = row.cells.iter().map(CsvDiffCell::is_changed).collect();
+        assert_eq!(changed, vec![false, true, false, true, true]);
+        assert_eq!(row.cells[1].display_text(), "5 → 6");
+    }
+
+    #[test]
+    fn position_alignment_keeps_inserted_records_from_shifting_the_rest() {
+        let preview = build_csv_diff_preview(
+            "id,v\n1,a\n2,b\n3,c\n",
+            "id,v\n1,a\n9,z\n2,b\n3,C\n",
+            ',',
+            &CsvRowAlignment::Position,
+        )
+        .expect("preview");
+        assert_eq!(
+            kinds(&preview),
+            vec![
+                CsvChangeKind::Unchanged,
+                CsvChangeKind::Added,
+                CsvChangeKind::Unchanged,
+                CsvChangeKind::Modified,
+            ]
+        );
+        assert_eq!(preview.key_column, None);
+    }
+
+    #[test]
+    fn key_alignment_pairs_reordered_records_and_keeps_removals_in_place() {
+        let preview = build_csv_diff_preview(
+            "id,v\n1,a\n2,b\n3,c\n",
+            "id,v\n3,c\n1,A\n4,d\n",
+            ',',
+            &CsvRowAlignment::KeyColumn("id".to_string()),
+        )
+        .expect("preview");
+        assert_eq!(preview.key_column, Some(0));
+        let summary: Vec<(CsvChangeKind, Option<usize>, Option<usize>)> = preview
+            .rows
+            .iter()
+            .map(|row| (row.kind, row.old_record, row.new_record))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (CsvChangeKind::Removed, Some(2), None),
+                (CsvChangeKind::Unchanged, Some(3), Some(1)),
+                (CsvChangeKind::Modified, Some(1), Some(2)),
+                (CsvChangeKind::Added, None, Some(3)),
+            ]
+        );
+        assert_eq!(preview.count(CsvChangeKind::Modified), 1);
+    }
+
+    #[test]
+    fn key_alignment_falls_back_to_position_for_unknown_columns() {
+        let preview = build_csv_diff_preview(
+            "id,v\n1,a\n",
+            "id,v\n1,b\n",
+            ',',
+            &CsvRowAlignment::KeyColumn("missing".to_string()),
+        )
+        .expect("preview");
+        assert_eq!(preview.key_column, None);
+        assert_eq!(kinds(&preview), vec![CsvChangeKind::Modified]);
+    }
+}
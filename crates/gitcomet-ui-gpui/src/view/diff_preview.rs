@@ -161,6 +161,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("new.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/new.txt b/new.txt"),
@@ -184,6 +185,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("new.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/new.txt b/new.txt"),
@@ -201,6 +203,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("old.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/old.txt b/old.txt"),
@@ -224,6 +227,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("old.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/old.txt b/old.txt"),
@@ -241,6 +245,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("new.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/new.txt b/new.txt"),
@@ -260,6 +265,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("old.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let diff = vec![
             line(DiffLineKind::Header, "diff --git a/old.txt b/old.txt"),
@@ -6,18 +6,28 @@ use std::sync::Arc;
 
 pub(super) fn hash_diff_target<H: Hasher>(target: &DiffTarget, hasher: &mut H) {
     match target {
-        DiffTarget::WorkingTree { path, area } => {
+        DiffTarget::WorkingTree {
+            path,
+            area,
+            old_path,
+        } => {
             0u8.hash(hasher);
             path.hash(hasher);
             match area {
                 DiffArea::Staged => 0u8.hash(hasher),
                 DiffArea::Unstaged => 1u8.hash(hasher),
             }
+            old_path.hash(hasher);
         }
-        DiffTarget::Commit { commit_id, path } => {
+        DiffTarget::Commit {
+            commit_id,
+            path,
+            old_path,
+        } => {
             1u8.hash(hasher);
             commit_id.hash(hasher);
             path.hash(hasher);
+            old_path.hash(hasher);
         }
         DiffTarget::CommitRange {
             from_commit_id,
@@ -0,0 +1,357 @@
+use gitcomet_core::domain::FileDiffTextSource;
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Bytes shown per hex dump row.
+pub(super) const HEX_DIFF_BYTES_PER_ROW: usize = 16;
+
+/// Bytes read to sniff a side's MIME type.
+const MIME_SNIFF_LEN: u64 = 512;
+
+// ── Core types ──────────────────────────────────────────────────────────
+
+/// One side of a binary diff. The bytes stay in the blob cache file and are
+/// read a window at a time as rows scroll into view.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct HexDiffSide {
+    pub(super) path: PathBuf,
+    pub(super) size: u64,
+    pub(super) sha: Option<Arc<str>>,
+    pub(super) mime: &'static str,
+}
+
+impl HexDiffSide {
+    pub(super) fn from_source(source: &FileDiffTextSource) -> std::io::Result<Self> {
+        let size = std::fs::metadata(&source.path)?.len();
+        let mut head = Vec::with_capacity(MIME_SNIFF_LEN as usize);
+        std::fs::File::open(&source.path)?
+            .take(MIME_SNIFF_LEN)
+            .read_to_end(&mut head)?;
+        Ok(Self {
+            path: source.path.clone(),
+            size,
+            sha: source.blob_id().map(Arc::from),
+            mime: sniff_mime(&head),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct HexDiffPreview {
+    pub(super) old: Option<HexDiffSide>,
+    pub(super) new: Option<HexDiffSide>,
+}
+
+impl HexDiffPreview {
+    pub(super) fn row_count(&self) -> usize {
+        let len = |side: &Option<HexDiffSide>| side.as_ref().map_or(0, |side| side.size);
+        let bytes = len(&self.old).max(len(&self.new));
+        usize::try_from(bytes.div_ceil(HEX_DIFF_BYTES_PER_ROW as u64)).unwrap_or(usize::MAX)
+    }
+
+    /// Byte windows of both sides covering `rows`, aligned at the same offset.
+    pub(super) fn read_rows(&self, rows: Range<usize>) -> std::io::Result<HexDiffWindow> {
+        let offset = rows.start as u64 * HEX_DIFF_BYTES_PER_ROW as u64;
+        let len = rows.len() * HEX_DIFF_BYTES_PER_ROW;
+        let read = |side: &Option<HexDiffSide>| match side {
+            Some(side) => read_window(&side.path, offset, len).map(Some),
+            None => Ok(None),
+        };
+        Ok(HexDiffWindow {
+            first_row: rows.start,
+            old: read(&self.old)?,
+            new: read(&self.new)?,
+        })
+    }
+}
+
+/// Bytes of both sides for a run of consecutive rows.
+pub(super) struct HexDiffWindow {
+    first_row: usize,
+    old: Option<Vec<u8>>,
+    new: Option<Vec<u8>>,
+}
+
+impl HexDiffWindow {
+    pub(super) fn row(&self, row_ix: usize) -> Option<HexDiffRow<'_>> {
+        let start = row_ix.checked_sub(self.first_row)? * HEX_DIFF_BYTES_PER_ROW;
+        let (old, new) = (
+            row_slice(self.old.as_deref(), start),
+            row_slice(self.new.as_deref(), start),
+        );
+        (!old.is_empty() || !new.is_empty()).then_some(HexDiffRow {
+            offset: row_ix as u64 * HEX_DIFF_BYTES_PER_ROW as u64,
+            old,
+            new,
+        })
+    }
+}
+
+fn row_slice(bytes: Option<&[u8]>, start: usize) -> &[u8] {
+    let bytes = bytes.unwrap_or_default();
+    let start = start.min(bytes.len());
+    &bytes[start..(start + HEX_DIFF_BYTES_PER_ROW).min(bytes.len())]
+}
+
+/// One 16-byte row, compared byte-for-byte at equal offsets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct HexDiffRow<'a> {
+    pub(super) offset: u64,
+    pub(super) old: &'a [u8],
+    pub(super) new: &'a [u8],
+}
+
+/// Text of one side of a row with the ranges of bytes that differ from the
+/// other side.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct HexDiffRowText {
+    pub(super) hex: String,
+    pub(super) hex_changed: Vec<Range<usize>>,
+    pub(super) ascii: String,
+    pub(super) ascii_changed: Vec<Range<usize>>,
+}
+
+impl HexDiffRow<'_> {
+    pub(super) fn has_changes(&self) -> bool {
+        self.old != self.new
+    }
+
+    pub(super) fn old_text(&self) -> HexDiffRowText {
+        hex_row_text(self.old, self.new)
+    }
+
+    pub(super) fn new_text(&self) -> HexDiffRowText {
+        hex_row_text(self.new, self.old)
+    }
+}
+
+// ── Formatting ──────────────────────────────────────────────────────────
+
+pub(super) fn format_offset(offset: u64) -> String {
+    format!("{offset:08x}")
+}
+
+fn ascii_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        char::from(byte)
+    } else {
+        '.'
+    }
+}
+
+/// Adjacent changed bytes share one range, spanning the separator between.
+fn push_changed(ranges: &mut Vec<Range<usize>>, range: Range<usize>, extends_previous: bool) {
+    match ranges.last_mut() {
+        Some(last) if extends_previous => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+/// Hex and ASCII columns of `bytes`, padded to a full row so both sides line
+/// up. A byte is changed when `other` has a different byte or none at all.
+fn hex_row_text(bytes: &[u8], other: &[u8]) -> HexDiffRowText {
+    let mut text = HexDiffRowText {
+        hex: String::with_capacity(HEX_DIFF_BYTES_PER_ROW * 3 + 1),
+        ascii: String::with_capacity(HEX_DIFF_BYTES_PER_ROW),
+        ..HexDiffRowText::default()
+    };
+    let mut previous_changed = false;
+    for ix in 0..HEX_DIFF_BYTES_PER_ROW {
+        if ix > 0 {
+            text.hex.push(' ');
+            if ix == HEX_DIFF_BYTES_PER_ROW / 2 {
+                text.hex.push(' ');
+            }
+        }
+        let Some(&byte) = bytes.get(ix) else {
+            text.hex.push_str("  ");
+            text.ascii.push(' ');
+            previous_changed = false;
+            continue;
+        };
+        let hex_start = text.hex.len();
+        text.hex.push_str(&format!("{byte:02x}"));
+        let ascii_start = text.ascii.len();
+        text.ascii.push(ascii_char(byte));
+        let changed = other.get(ix) != Some(&byte);
+        if changed {
+            push_changed(
+                &mut text.hex_changed,
+                hex_start..text.hex.len(),
+                previous_changed,
+            );
+            push_changed(
+                &mut text.ascii_changed,
+                ascii_start..text.ascii.len(),
+                previous_changed,
+            );
+        }
+        previous_changed = changed;
+    }
+    text
+}
+
+/// `12,345 bytes` with thousands separators.
+pub(super) fn format_size(size: u64) -> String {
+    let digits = size.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 + 6);
+    for (ix, ch) in digits.chars().enumerate() {
+        if ix > 0 && (digits.len() - ix).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+    out.push_str(if size == 1 { " byte" } else { " bytes" });
+    out
+}
+
+// ── Reading ─────────────────────────────────────────────────────────────
+
+fn read_window(path: &Path, offset: u64, len: usize) -> std::io::Result<Vec<u8>> {
+    let mut file = std::fs::File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut bytes = Vec::with_capacity(len);
+    file.take(len as u64).read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// MIME type from well-known magic numbers, falling back to
+/// `application/octet-stream`.
+pub(super) fn sniff_mime(head: &[u8]) -> &'static str {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"BZh", "application/x-bzip2"),
+        (b"\xfd7zXZ\x00", "application/x-xz"),
+        (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+        (b"\x28\xb5\x2f\xfd", "application/zstd"),
+        (b"\x7fELF", "application/x-elf"),
+        (b"MZ", "application/vnd.microsoft.portable-executable"),
+        (b"\xcf\xfa\xed\xfe", "application/x-mach-binary"),
+        (b"\xca\xfe\xba\xbe", "application/java-vm"),
+        (b"\x00asm", "application/wasm"),
+        (b"SQLite format 3\x00", "application/vnd.sqlite3"),
+        (b"OggS", "audio/ogg"),
+        (b"fLaC", "audio/flac"),
+        (b"ID3", "audio/mpeg"),
+        (b"wOFF", "font/woff"),
+        (b"wOF2", "font/woff2"),
+        (b"\x00\x01\x00\x00", "font/ttf"),
+        (b"OTTO", "font/otf"),
+    ];
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| head.starts_with(signature))
+    {
+        return mime;
+    }
+    if head.len() >= 12 && head.starts_with(b"RIFF") {
+        match &head[8..12] {
+            b"WEBP" => return "image/webp",
+            b"WAVE" => return "audio/wav",
+            b"AVI " => return "video/x-msvideo",
+            _ => {}
+        }
+    }
+    if head.len() >= 12 && &head[4..8] == b"ftyp" {
+        return "video/mp4";
+    }
+    if std::str::from_utf8(head).is_ok() && !head.contains(&0) {
+        return "text/plain";
+    }
+    "application/octet-stream"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_row_text_highlights_changed_and_missing_bytes() {
+        let old = b"Hello, world!\x00\x01\x02";
+        let new = b"Hello, World!\x00";
+        let row = HexDiffRow {
+            offset: 0,
+            old,
+            new,
+        };
+        assert!(row.has_changes());
+
+        let old_text = row.old_text();
+        assert_eq!(
+            old_text.hex,
+            "48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 00 01 02"
+        );
+        assert_eq!(old_text.ascii, "Hello, world!...");
+        // `w` at byte 7, then the two bytes missing from the new side.
+        assert_eq!(old_text.hex_changed, vec![21..23, 43..48]);
+        assert_eq!(old_text.ascii_changed, vec![7..8, 14..16]);
+
+        let new_text = row.new_text();
+        assert_eq!(
+            new_text.hex,
+            "48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 00      "
+        );
+        assert_eq!(new_text.ascii, "Hello, World!.  ");
+        assert_eq!(new_text.ascii_changed, vec![7..8]);
+    }
+
+    #[test]
+    fn preview_reads_aligned_windows_from_both_sides() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let old_path = tmp.path().join("old.bin");
+        let new_path = tmp.path().join("new.bin");
+        std::fs::write(&old_path, vec![0xaa; 40]).expect("write old");
+        std::fs::write(&new_path, vec![0xaa; 20]).expect("write new");
+        let side = |path: &Path| {
+            HexDiffSide::from_source(&FileDiffTextSource::with_identity(
+                path.to_path_buf(),
+                "blob:0123",
+            ))
+            .expect("side")
+        };
+        let preview = HexDiffPreview {
+            old: Some(side(&old_path)),
+            new: Some(side(&new_path)),
+        };
+        assert_eq!(preview.old.as_ref().unwrap().sha.as_deref(), Some("0123"));
+        assert_eq!(preview.row_count(), 3);
+
+        let window = preview.read_rows(1..3).expect("read rows");
+        let row = window.row(1).expect("row 1");
+        assert_eq!(row.offset, 16);
+        assert_eq!(row.old.len(), 16);
+        assert_eq!(row.new.len(), 4);
+        let row = window.row(2).expect("row 2");
+        assert_eq!(row.old.len(), 8);
+        assert!(row.new.is_empty());
+        assert!(window.row(0).is_none());
+        assert!(window.row(3).is_none());
+    }
+
+    #[test]
+    fn sniff_mime_detects_common_signatures() {
+        assert_eq!(sniff_mime(b"\x89PNG\r\n\x1a\n\x00\x00"), "image/png");
+        assert_eq!(sniff_mime(b"PK\x03\x04rest"), "application/zip");
+        assert_eq!(sniff_mime(b"RIFF\x00\x00\x00\x00WEBPVP8 "), "image/webp");
+        assert_eq!(sniff_mime(b"\x00\x00\x00\x18ftypmp42"), "video/mp4");
+        assert_eq!(
+            sniff_mime(b"\x00\x01\x02\x03\xff"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn format_size_groups_thousands() {
+        assert_eq!(format_size(1), "1 byte");
+        assert_eq!(format_size(999), "999 bytes");
+        assert_eq!(format_size(1_234_567), "1,234,567 bytes");
+    }
+}
@@ -3,15 +3,18 @@ use crate::ui_scale;
 use gitcomet_core::diff::AnnotatedDiffLine;
 #[cfg(test)]
 use gitcomet_core::diff::annotate_unified;
+use gitcomet_core::diff::{DiffIgnoreMatcher, MovedLineOptions, MovedLines, detect_moved_lines};
 #[cfg(test)]
 use gitcomet_core::domain::RepoStatus;
 use gitcomet_core::domain::{
-    Branch, Commit, CommitId, DiffArea, DiffTarget, FileStatus, FileStatusKind, Tag,
-    UpstreamDivergence,
+    Branch, Commit, CommitId, DiffArea, DiffTarget, FileStatus, FileStatusKind,
+    RepoOperationAction, RepoOperationKind, RepoOperationState, Tag, Upstream, UpstreamDivergence,
 };
 use gitcomet_core::file_diff::FileDiffRow;
 use gitcomet_core::process::refresh_git_runtime;
-use gitcomet_core::services::{PullMode, RemoteUrlKind, ResetMode};
+use gitcomet_core::services::{
+    FixupCommitKind, PullMode, RemoteUrlKind, ResetMode, TextSearchOptions,
+};
 use gitcomet_state::model::{
     AppNotificationKind, AppState, AuthPromptKind, CloneOpState, CloneOpStatus, DiagnosticKind,
     Loadable, RepoId, RepoState, SubmoduleTrustPromptOperation,
@@ -88,6 +91,7 @@ pub(crate) mod clone_progress;
 mod color;
 pub(crate) mod components;
 pub(crate) mod conflict_resolver;
+mod csv_preview;
 mod date_time;
 mod diff_navigation;
 mod diff_preview;
@@ -96,6 +100,7 @@ mod diff_text_selection;
 mod diff_utils;
 mod file_diff_display;
 mod fingerprint;
+mod hex_preview;
 mod history_graph;
 pub(crate) mod history_mode;
 mod icons;
@@ -103,6 +108,7 @@ mod icons;
 mod linux_desktop_integration;
 mod markdown_preview;
 mod mod_helpers;
+mod notebook_preview;
 mod open_source_licenses_data;
 mod panels;
 mod panes;
@@ -117,6 +123,7 @@ mod settings_window;
 mod sidebar_presentation;
 mod splash;
 mod state_apply;
+mod status_tree;
 #[cfg(test)]
 pub(crate) mod test_support;
 mod toast_host;
@@ -144,6 +151,7 @@ use date_time::{DateTimeFormat, Timezone, format_datetime_into};
 use diff_preview::build_new_file_preview_from_diff;
 use patch_split::build_patch_split_rows;
 use poller::Poller;
+use status_tree::{StatusTreeCounts, StatusTreeRow, build_status_tree_rows, status_paths_under};
 use word_diff::{capped_word_diff_ranges, capped_word_diff_ranges_for_file_diff_texts};
 
 #[cfg(test)]
@@ -590,6 +598,9 @@ impl GitCometView {
             initial_path = focused_mergetool.as_ref().map(|cfg| cfg.repo_path.clone());
         }
         let focused_mergetool_labels = focused_mergetool.as_ref().map(|cfg| cfg.labels.clone());
+        let focused_mergetool_compare_only = focused_mergetool
+            .as_ref()
+            .is_some_and(|cfg| cfg.compare_only);
         let focused_mergetool_bootstrap = if view_mode == GitCometViewMode::FocusedMergetool {
             focused_mergetool
                 .clone()
@@ -670,6 +681,9 @@ impl GitCometView {
             show_history_tags: history_show_tags,
             tag_fetch_mode: history_tag_fetch_mode,
         });
+        store.dispatch(Msg::SetDiffWhitespace {
+            whitespace: diff_whitespace_mode.ignore(),
+        });
         let saved_open_repos = ui_session.open_repos.clone();
         let saved_active_repo = ui_session.active_repo.clone();
         let mut startup_repo_bootstrap_pending = false;
@@ -802,6 +816,7 @@ impl GitCometView {
                 ),
                 view_mode,
                 focused_mergetool_labels,
+                focused_mergetool_compare_only,
                 focused_mergetool_exit_code.clone(),
                 weak_view.clone(),
                 tooltip_host.downgrade(),
@@ -1334,6 +1349,9 @@ impl GitCometView {
         self.diff_whitespace_mode = next;
         self.popover_host
             .update(cx, |host, cx| host.sync_diff_whitespace_mode(next, cx));
+        self.store.dispatch(Msg::SetDiffWhitespace {
+            whitespace: next.ignore(),
+        });
         self.schedule_ui_settings_persist(cx);
         true
     }
@@ -169,13 +169,22 @@ pub(super) enum DiffViewMode {
 pub(super) enum RenderedPreviewKind {
     Svg,
     Markdown,
+    Csv,
+    Notebook,
 }
 
 impl RenderedPreviewKind {
+    /// Kinds rendered through the markdown diff preview rows.
+    pub(super) fn uses_markdown_preview(self) -> bool {
+        matches!(self, Self::Markdown | Self::Notebook)
+    }
+
     pub(super) fn rendered_label(self) -> &'static str {
         match self {
             Self::Svg => "Image",
             Self::Markdown => "Preview",
+            Self::Csv => "Table",
+            Self::Notebook => "Cells",
         }
     }
 
@@ -183,6 +192,8 @@ impl RenderedPreviewKind {
         match self {
             Self::Svg => "Code",
             Self::Markdown => "Text",
+            Self::Csv => "Text",
+            Self::Notebook => "JSON",
         }
     }
 
@@ -190,6 +201,8 @@ impl RenderedPreviewKind {
         match self {
             Self::Svg => "svg_diff_view_image",
             Self::Markdown => "markdown_diff_view_preview",
+            Self::Csv => "csv_diff_view_table",
+            Self::Notebook => "notebook_diff_view_cells",
         }
     }
 
@@ -197,6 +210,8 @@ impl RenderedPreviewKind {
         match self {
             Self::Svg => "svg_diff_view_toggle",
             Self::Markdown => "markdown_diff_view_toggle",
+            Self::Csv => "csv_diff_view_toggle",
+            Self::Notebook => "notebook_diff_view_toggle",
         }
     }
 
@@ -204,6 +219,8 @@ impl RenderedPreviewKind {
         match self {
             Self::Svg => "svg_diff_view_code",
             Self::Markdown => "markdown_diff_view_text",
+            Self::Csv => "csv_diff_view_text",
+            Self::Notebook => "notebook_diff_view_json",
         }
     }
 }
@@ -218,6 +235,8 @@ pub(super) enum RenderedPreviewMode {
 pub(super) struct RenderedPreviewModes {
     pub(super) svg: RenderedPreviewMode,
     pub(super) markdown: RenderedPreviewMode,
+    pub(super) csv: RenderedPreviewMode,
+    pub(super) notebook: RenderedPreviewMode,
 }
 
 impl Default for RenderedPreviewModes {
@@ -225,6 +244,8 @@ impl Default for RenderedPreviewModes {
         Self {
             svg: RenderedPreviewMode::Rendered,
             markdown: RenderedPreviewMode::Rendered,
+            csv: RenderedPreviewMode::Rendered,
+            notebook: RenderedPreviewMode::Rendered,
         }
     }
 }
@@ -234,6 +255,8 @@ impl RenderedPreviewModes {
         match kind {
             RenderedPreviewKind::Svg => self.svg,
             RenderedPreviewKind::Markdown => self.markdown,
+            RenderedPreviewKind::Csv => self.csv,
+            RenderedPreviewKind::Notebook => self.notebook,
         }
     }
 
@@ -241,6 +264,8 @@ impl RenderedPreviewModes {
         match kind {
             RenderedPreviewKind::Svg => self.svg = mode,
             RenderedPreviewKind::Markdown => self.markdown = mode,
+            RenderedPreviewKind::Csv => self.csv = mode,
+            RenderedPreviewKind::Notebook => self.notebook = mode,
         }
     }
 }
@@ -290,7 +315,17 @@ pub(super) fn diff_target_rendered_preview_kind(
         } => path.as_path(),
         _ => return None,
     };
+    // Tables and notebooks only render as a two-sided diff, so they stay out of
+    // `preview_path_rendered_kind`, which also drives file and conflict previews.
     preview_path_rendered_kind(path)
+        .or_else(|| {
+            crate::view::csv_preview::is_delimited_table_path(path)
+                .then_some(RenderedPreviewKind::Csv)
+        })
+        .or_else(|| {
+            crate::view::notebook_preview::is_notebook_path(path)
+                .then_some(RenderedPreviewKind::Notebook)
+        })
 }
 
 pub(super) fn main_diff_rendered_preview_toggle_kind(
@@ -303,6 +338,8 @@ pub(super) fn main_diff_rendered_preview_toggle_kind(
         RenderedPreviewKind::Markdown if wants_file_diff || is_file_preview => {
             Some(RenderedPreviewKind::Markdown)
         }
+        RenderedPreviewKind::Csv if wants_file_diff => Some(RenderedPreviewKind::Csv),
+        RenderedPreviewKind::Notebook if wants_file_diff => Some(RenderedPreviewKind::Notebook),
         _ => None,
     }
 }
@@ -478,6 +515,13 @@ pub(super) struct ConflictDiffSplitResizeState {
 
 pub(super) use ResizeDragGhost as ConflictDiffSplitResizeDragGhost;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum ImageDiffDragHandle {
+    Viewport,
+}
+
+pub(super) use ResizeDragGhost as ImageDiffDragGhost;
+
 #[cfg(test)]
 mod resize_drag_ghost_tests {
     use super::{
@@ -1060,6 +1104,36 @@ pub(super) type LoadableMarkdownDoc =
 pub(super) type LoadableMarkdownDiff =
     Loadable<Arc<crate::view::markdown_preview::MarkdownPreviewDiff>>;
 
+/// Changed image output of a notebook diff, decoded once for display.
+#[derive(Clone, Debug)]
+pub(super) struct NotebookImageOutputPreview {
+    pub(super) label: SharedString,
+    pub(super) old: Option<Arc<gpui::Image>>,
+    pub(super) new: Option<Arc<gpui::Image>>,
+}
+
+impl NotebookImageOutputPreview {
+    pub(super) fn from_diff(diff: crate::view::notebook_preview::NotebookImageOutputDiff) -> Self {
+        let image = |output: crate::view::notebook_preview::NotebookImageOutput| {
+            let format = match output.mime {
+                "image/jpeg" => gpui::ImageFormat::Jpeg,
+                "image/gif" => gpui::ImageFormat::Gif,
+                "image/svg+xml" => gpui::ImageFormat::Svg,
+                _ => gpui::ImageFormat::Png,
+            };
+            Arc::new(gpui::Image::from_bytes(format, output.bytes))
+        };
+        Self {
+            label: diff.label.into(),
+            old: diff.old.map(image),
+            new: diff.new.map(image),
+        }
+    }
+}
+
+pub(super) type LoadableCsvDiff = Loadable<Arc<crate::view::csv_preview::CsvDiffPreview>>;
+pub(super) type LoadableHexDiff = Loadable<Arc<crate::view::hex_preview::HexDiffPreview>>;
+
 pub(super) type LoadableImagePreview = Loadable<Option<Arc<gpui::Image>>>;
 
 #[derive(Clone, Debug)]
@@ -1538,7 +1612,7 @@ impl ConflictResolverUiState {
     ) -> gitcomet_core::file_diff::FileDiffRowKind {
         use gitcomet_core::file_diff::FileDiffRowKind as RK;
 
-        if whitespace_mode == DiffWhitespaceMode::Show || matches!(row.kind, RK::Context) {
+        if whitespace_mode != DiffWhitespaceMode::Ignore || matches!(row.kind, RK::Context) {
             return row.kind;
         }
 
@@ -2417,6 +2491,16 @@ pub(super) enum PopoverKind {
         repo_id: RepoId,
         target: String,
     },
+    DiffIgnorePatternsPrompt {
+        repo_id: RepoId,
+    },
+    RenameBranchPrompt {
+        repo_id: RepoId,
+        branch: String,
+    },
+    PatchSeriesImport {
+        repo_id: RepoId,
+    },
     Repo {
         repo_id: RepoId,
         kind: RepoPopoverKind,
@@ -2425,6 +2509,22 @@ pub(super) enum PopoverKind {
         repo_id: RepoId,
         path: std::path::PathBuf,
     },
+    Blame {
+        repo_id: RepoId,
+        path: std::path::PathBuf,
+        rev: Option<String>,
+    },
+    RevisionTree {
+        repo_id: RepoId,
+        rev: String,
+    },
+    LineHistory {
+        repo_id: RepoId,
+        path: std::path::PathBuf,
+        start: u32,
+        end: u32,
+        rev: Option<String>,
+    },
     PushSetUpstreamPrompt {
         repo_id: RepoId,
         remote: String,
@@ -2435,6 +2535,9 @@ pub(super) enum PopoverKind {
     MergeAbortConfirm {
         repo_id: RepoId,
     },
+    ConflictDashboard {
+        repo_id: RepoId,
+    },
     ConflictSaveStageConfirm {
         repo_id: RepoId,
         path: std::path::PathBuf,
@@ -2483,6 +2586,9 @@ pub(super) enum PopoverKind {
         lines_count: usize,
         copy_text: Option<String>,
         copy_target: Option<(usize, DiffTextRegion)>,
+        /// Selected lines and the revision they are numbered against, for
+        /// line history (`None` revision means `HEAD`).
+        line_range: Option<(u32, u32, Option<String>)>,
     },
     ConflictResolverInputRowMenu {
         line_label: SharedString,
@@ -2509,6 +2615,10 @@ pub(super) enum PopoverKind {
         repo_id: RepoId,
         commit_id: CommitId,
     },
+    CommitSelectionMenu {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
     StatusFileMenu {
         repo_id: RepoId,
         area: DiffArea,
@@ -2575,6 +2685,12 @@ pub(super) enum WorktreePopoverKind {
         path: std::path::PathBuf,
         branch: Option<String>,
     },
+    LockPrompt {
+        path: std::path::PathBuf,
+    },
+    MovePrompt {
+        path: std::path::PathBuf,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -2583,6 +2699,8 @@ pub(super) enum SubmodulePopoverKind {
     Menu { path: std::path::PathBuf },
     AddPrompt,
     ChangePointerPrompt { path: std::path::PathBuf },
+    TrackBranchPrompt { path: std::path::PathBuf },
+    RunCommandPrompt,
     TrustConfirm,
     OpenPicker,
     RemovePicker,
@@ -2709,6 +2827,7 @@ pub struct FocusedMergetoolViewConfig {
     pub repo_path: std::path::PathBuf,
     pub conflicted_file_path: std::path::PathBuf,
     pub labels: FocusedMergetoolLabels,
+    pub compare_only: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -2805,13 +2924,23 @@ fn normalize_bootstrap_target_path(
 
 fn normalize_bootstrap_diff_target(repo_path: &std::path::Path, target: DiffTarget) -> DiffTarget {
     match target {
-        DiffTarget::WorkingTree { path, area } => DiffTarget::WorkingTree {
+        DiffTarget::WorkingTree {
+            path,
+            area,
+            old_path,
+        } => DiffTarget::WorkingTree {
             path: normalize_bootstrap_target_path(repo_path, path),
             area,
+            old_path: old_path.map(|path| normalize_bootstrap_target_path(repo_path, path)),
         },
-        DiffTarget::Commit { commit_id, path } => DiffTarget::Commit {
+        DiffTarget::Commit {
+            commit_id,
+            path,
+            old_path,
+        } => DiffTarget::Commit {
             commit_id,
             path: path.map(|path| normalize_bootstrap_target_path(repo_path, path)),
+            old_path: old_path.map(|path| normalize_bootstrap_target_path(repo_path, path)),
         },
         DiffTarget::CommitRange {
             from_commit_id,
@@ -2874,6 +3003,7 @@ pub(super) fn focused_mergetool_bootstrap_action(
     let target = DiffTarget::WorkingTree {
         area: DiffArea::Unstaged,
         path: bootstrap.target_path.clone(),
+        old_path: None,
     };
     if repo.diff_state.diff_target.as_ref() != Some(&target) {
         return Some(FocusedMergetoolBootstrapAction::SelectConflictDiff {
@@ -3030,6 +3160,9 @@ pub(super) enum ChangeTrackingView {
     #[default]
     Combined,
     SplitUntracked,
+    /// Untracked files stay with Unstaged; both lists are shown as a
+    /// collapsible directory tree.
+    Tree,
 }
 
 impl ChangeTrackingView {
@@ -3037,6 +3170,7 @@ impl ChangeTrackingView {
         match self {
             Self::Combined => "combined",
             Self::SplitUntracked => "split_untracked",
+            Self::Tree => "tree",
         }
     }
 
@@ -3044,6 +3178,7 @@ impl ChangeTrackingView {
         match raw {
             "combined" => Some(Self::Combined),
             "split_untracked" => Some(Self::SplitUntracked),
+            "tree" => Some(Self::Tree),
             _ => None,
         }
     }
@@ -3052,6 +3187,7 @@ impl ChangeTrackingView {
         match self {
             Self::Combined => "Combined with Unstaged",
             Self::SplitUntracked => "Separate section",
+            Self::Tree => "Directory tree",
         }
     }
 
@@ -3059,6 +3195,7 @@ impl ChangeTrackingView {
         match self {
             Self::Combined => "Combine with Unstaged",
             Self::SplitUntracked => "Show separate Untracked block",
+            Self::Tree => "Show as directory tree",
         }
     }
 
@@ -3066,6 +3203,7 @@ impl ChangeTrackingView {
         match self {
             Self::Combined => "Combined",
             Self::SplitUntracked => "Separate section",
+            Self::Tree => "Directory tree",
         }
     }
 }
@@ -3156,7 +3294,12 @@ impl DiffContentMode {
 pub(crate) enum DiffWhitespaceMode {
     #[default]
     Show,
+    /// Ignore all whitespace (`-w`).
     Ignore,
+    /// Ignore changes in the amount of whitespace (`-b`).
+    IgnoreChange,
+    /// Ignore whitespace at end of line (`--ignore-space-at-eol`).
+    IgnoreAtEol,
 }
 
 impl DiffWhitespaceMode {
@@ -3164,6 +3307,8 @@ impl DiffWhitespaceMode {
         match self {
             Self::Show => "show",
             Self::Ignore => "ignore",
+            Self::IgnoreChange => "ignore_change",
+            Self::IgnoreAtEol => "ignore_at_eol",
         }
     }
 
@@ -3171,6 +3316,8 @@ impl DiffWhitespaceMode {
         match raw {
             "show" => Some(Self::Show),
             "ignore" => Some(Self::Ignore),
+            "ignore_change" => Some(Self::IgnoreChange),
+            "ignore_at_eol" => Some(Self::IgnoreAtEol),
             _ => None,
         }
     }
@@ -3178,7 +3325,73 @@ impl DiffWhitespaceMode {
     pub(crate) const fn toggled(self) -> Self {
         match self {
             Self::Show => Self::Ignore,
-            Self::Ignore => Self::Show,
+            Self::Ignore | Self::IgnoreChange | Self::IgnoreAtEol => Self::Show,
+        }
+    }
+
+    /// The diff ignore option this mode applies to every repository.
+    pub(crate) const fn ignore(self) -> gitcomet_core::services::DiffWhitespaceIgnore {
+        use gitcomet_core::services::DiffWhitespaceIgnore;
+        match self {
+            Self::Show => DiffWhitespaceIgnore::None,
+            Self::Ignore => DiffWhitespaceIgnore::All,
+            Self::IgnoreChange => DiffWhitespaceIgnore::Change,
+            Self::IgnoreAtEol => DiffWhitespaceIgnore::AtEol,
+        }
+    }
+}
+
+/// How file diff rows are aligned: by raw lines, or by syntax tokens so
+/// formatting-only edits read as unchanged.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum DiffAlignmentMode {
+    #[default]
+    Lines,
+    Structural,
+}
+
+impl DiffAlignmentMode {
+    pub(crate) const fn toggled(self) -> Self {
+        match self {
+            Self::Lines => Self::Structural,
+            Self::Structural => Self::Lines,
+        }
+    }
+}
+
+/// How the before and after sides of an image diff are compared.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ImageDiffMode {
+    #[default]
+    SideBySide,
+    Swipe,
+    OnionSkin,
+    Difference,
+}
+
+impl ImageDiffMode {
+    pub(crate) const ALL: [Self; 4] = [
+        Self::SideBySide,
+        Self::Swipe,
+        Self::OnionSkin,
+        Self::Difference,
+    ];
+
+    pub(crate) const fn label(self) -> &'static str {
+        match self {
+            Self::SideBySide => "Side by side",
+            Self::Swipe => "Swipe",
+            Self::OnionSkin => "Onion skin",
+            Self::Difference => "Difference",
+        }
+    }
+
+    pub(crate) const fn button_id(self) -> &'static str {
+        match self {
+            Self::SideBySide => "image_diff_mode_side_by_side",
+            Self::Swipe => "image_diff_mode_swipe",
+            Self::OnionSkin => "image_diff_mode_onion_skin",
+            Self::Difference => "image_diff_mode_difference",
         }
     }
 }
@@ -0,0 +1,513 @@
+use base64::Engine as _;
+use gitcomet_core::file_diff::{FileDiffRowKind, side_by_side_rows};
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::Value;
+
+/// Maximum combined `.ipynb` size (bytes) for a two-sided notebook preview.
+///
+/// Notebooks embed outputs as base64, so the raw file is usually far larger
+/// than the markdown the cells are converted to.
+pub(super) const MAX_DIFF_PREVIEW_SOURCE_BYTES: usize = 16 * 1_024 * 1_024; // 16 MiB
+
+/// Language assumed for code cells when the notebook metadata names none.
+const DEFAULT_CODE_CELL_LANGUAGE: &str = "python";
+
+/// Image MIME types extracted from outputs, in display preference order.
+const IMAGE_OUTPUT_MIME_TYPES: [&str; 4] =
+    ["image/png", "image/jpeg", "image/gif", "image/svg+xml"];
+
+// ── Core types ──────────────────────────────────────────────────────────
+
+/// Which noisy notebook parts are rendered alongside cell sources.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub(super) struct NotebookPreviewOptions {
+    /// Render text outputs instead of a one-line "N outputs" summary.
+    pub(super) show_outputs: bool,
+    /// Include `In [n]` execution counts, which change on every re-run.
+    pub(super) show_execution_counts: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct NotebookImageOutput {
+    /// One-based position of the output within its cell.
+    pub(super) output_number: usize,
+    pub(super) mime: &'static str,
+    pub(super) bytes: Vec<u8>,
+}
+
+/// What a rendered cell keeps for pairing it with the other side.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct NotebookCell {
+    /// nbformat 4.5+ cell `id`, stable across moves and edits.
+    pub(super) id: Option<String>,
+    pub(super) source: String,
+    pub(super) images: Vec<NotebookImageOutput>,
+}
+
+/// A notebook flattened into markdown plus the cells it was built from.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct NotebookRendering {
+    pub(super) markdown: String,
+    pub(super) cells: Vec<NotebookCell>,
+}
+
+/// An image output that differs between the two sides of a notebook diff.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct NotebookImageOutputDiff {
+    pub(super) label: String,
+    pub(super) old: Option<NotebookImageOutput>,
+    pub(super) new: Option<NotebookImageOutput>,
+}
+
+pub(super) fn is_notebook_path(path: &std::path::Path) -> bool {
+    path.extension()
+        .and_then(|s| s.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ipynb"))
+}
+
+// ── Conversion ──────────────────────────────────────────────────────────
+
+/// Cell sources are stored either as one string or as a list of lines.
+fn multiline_text(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(lines)) => lines.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+fn notebook_language(notebook: &Value) -> String {
+    let metadata = notebook.get("metadata");
+    metadata
+        .and_then(|metadata| metadata.pointer("/language_info/name"))
+        .or_else(|| metadata.and_then(|metadata| metadata.pointer("/kernelspec/language")))
+        .and_then(Value::as_str)
+        .filter(|language| !language.trim().is_empty())
+        .unwrap_or(DEFAULT_CODE_CELL_LANGUAGE)
+        .to_ascii_lowercase()
+}
+
+/// Picks a fence longer than any backtick run inside `text`.
+fn code_fence_for(text: &str) -> String {
+    let mut longest = 0usize;
+    let mut run = 0usize;
+    for ch in text.chars() {
+        if ch == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    "`".repeat(longest.max(2) + 1)
+}
+
+fn push_fenced_block(out: &mut String, info: &str, text: &str) {
+    let fence = code_fence_for(text);
+    out.push_str(&fence);
+    out.push_str(info);
+    out.push('\n');
+    out.push_str(text.strip_suffix('\n').unwrap_or(text));
+    out.push('\n');
+    out.push_str(&fence);
+    out.push_str("\n\n");
+}
+
+/// Notebook writers wrap base64 payloads across lines.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let compact: Vec<u8> = text
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(compact)
+        .ok()
+}
+
+fn image_output(data: &Value, output_number: usize) -> Option<NotebookImageOutput> {
+    IMAGE_OUTPUT_MIME_TYPES.iter().find_map(|&mime| {
+        let text = multiline_text(Some(data.get(mime)?));
+        let bytes = if mime == "image/svg+xml" {
+            text.into_bytes()
+        } else {
+            decode_base64(&text)?
+        };
+        Some(NotebookImageOutput {
+            output_number,
+            mime,
+            bytes,
+        })
+    })
+}
+
+fn output_text(output: &Value) -> Option<String> {
+    match output.get("output_type").and_then(Value::as_str)? {
+        "stream" => Some(multiline_text(output.get("text"))),
+        "error" => {
+            let name = output
+                .get("ename")
+                .and_then(Value::as_str)
+                .unwrap_or("Error");
+            let value = output.get("evalue").and_then(Value::as_str).unwrap_or("");
+            Some(format!("{name}: {value}"))
+        }
+        _ => output
+            .pointer("/data/text~1plain")
+            .map(|text| multiline_text(Some(text))),
+    }
+}
+
+fn push_cell_outputs(
+    out: &mut String,
+    images: &mut Vec<NotebookImageOutput>,
+    outputs: &[Value],
+    options: NotebookPreviewOptions,
+) {
+    for (output_ix, output) in outputs.iter().enumerate() {
+        let image = output
+            .get("data")
+            .and_then(|data| image_output(data, output_ix + 1));
+        if options.show_outputs {
+            if let Some(image) = image.as_ref() {
+                out.push_str(&format!("*{} image output*\n\n", image.mime));
+            } else if let Some(text) = output_text(output).filter(|text| !text.is_empty()) {
+                push_fenced_block(out, "text", &text);
+            }
+        }
+        images.extend(image);
+    }
+    if !options.show_outputs && !outputs.is_empty() {
+        let noun = if outputs.len() == 1 {
+            "output"
+        } else {
+            "outputs"
+        };
+        out.push_str(&format!("*{} {noun} collapsed*\n\n", outputs.len()));
+    }
+}
+
+/// Flattens a notebook into markdown: markdown cells verbatim, code cells as
+/// fenced blocks in the kernel language, and outputs collapsed or rendered as
+/// text according to `options`. Cells are not numbered in the markdown, so an
+/// inserted cell does not mark every later header as changed. Returns `None`
+/// when `text` is not a notebook.
+pub(super) fn render_notebook_markdown(
+    text: &str,
+    options: NotebookPreviewOptions,
+) -> Option<NotebookRendering> {
+    if text.trim().is_empty() {
+        return Some(NotebookRendering::default());
+    }
+    let notebook: Value = serde_json::from_str(text).ok()?;
+    let cells = notebook.get("cells")?.as_array()?;
+    let language = notebook_language(&notebook);
+
+    let mut rendering = NotebookRendering::default();
+    for (cell_ix, cell) in cells.iter().enumerate() {
+        let cell_type = cell
+            .get("cell_type")
+            .and_then(Value::as_str)
+            .unwrap_or("raw");
+        let source = multiline_text(cell.get("source"));
+        let mut images = Vec::new();
+        let out = &mut rendering.markdown;
+
+        if cell_ix > 0 {
+            out.push_str("---\n\n");
+        }
+        out.push_str(&format!("**{cell_type} cell**"));
+        if options.show_execution_counts
+            && let Some(count) = cell.get("execution_count").and_then(Value::as_u64)
+        {
+            out.push_str(&format!(" · In [{count}]"));
+        }
+        out.push_str("\n\n");
+
+        match cell_type {
+            "markdown" => {
+                out.push_str(source.strip_suffix('\n').unwrap_or(&source));
+                out.push_str("\n\n");
+            }
+            "code" => {
+                push_fenced_block(out, &language, &source);
+                let outputs = cell
+                    .get("outputs")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                push_cell_outputs(out, &mut images, outputs, options);
+            }
+            _ => push_fenced_block(out, "text", &source),
+        }
+        rendering.cells.push(NotebookCell {
+            id: cell.get("id").and_then(Value::as_str).map(str::to_owned),
+            source,
+            images,
+        });
+    }
+    Some(rendering)
+}
+
+/// Pairs old and new cells: first by nbformat `id`, then by aligning the
+/// sources of the remaining cells as a line diff where each cell is one
+/// line. When both sides carry ids, an unmatched id means an added or removed
+/// cell, so only cells without one are aligned. Returns `(old_ix, new_ix)`
+/// pairs in new-side order, followed by the removed cells.
+fn pair_cells(old: &[NotebookCell], new: &[NotebookCell]) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut old_for_new: Vec<Option<usize>> = vec![None; new.len()];
+    let mut old_paired = vec![false; old.len()];
+
+    let mut new_by_id: HashMap<&str, usize> = HashMap::default();
+    for (new_ix, cell) in new.iter().enumerate() {
+        if let Some(id) = cell.id.as_deref() {
+            new_by_id.entry(id).or_insert(new_ix);
+        }
+    }
+    for (old_ix, cell) in old.iter().enumerate() {
+        if let Some(new_ix) = cell.id.as_deref().and_then(|id| new_by_id.remove(id)) {
+            old_for_new[new_ix] = Some(old_ix);
+            old_paired[old_ix] = true;
+        }
+    }
+
+    let has_ids = |cells: &[NotebookCell]| cells.iter().any(|cell| cell.id.is_some());
+    let ids_on_both_sides = has_ids(old) && has_ids(new);
+    let alignable = |cell: &NotebookCell| !ids_on_both_sides || cell.id.is_none();
+    let old_rest: Vec<usize> = (0..old.len())
+        .filter(|&ix| !old_paired[ix] && alignable(&old[ix]))
+        .collect();
+    let new_rest: Vec<usize> = (0..new.len())
+        .filter(|&ix| old_for_new[ix].is_none() && alignable(&new[ix]))
+        .collect();
+    if !old_rest.is_empty() && !new_rest.is_empty() {
+        let cell_lines = |cells: &[NotebookCell], rest: &[usize]| -> String {
+            rest.iter()
+                .map(|&ix| cells[ix].source.replace(['\r', '\n'], " ") + "\n")
+                .collect()
+        };
+        let rows = side_by_side_rows(&cell_lines(old, &old_rest), &cell_lines(new, &new_rest));
+        for row in rows {
+            if !matches!(row.kind, FileDiffRowKind::Context | FileDiffRowKind::Modify) {
+                continue;
+            }
+            let (Some(old_line), Some(new_line)) = (row.old_line, row.new_line) else {
+                continue;
+            };
+            let old_ix = old_rest[old_line as usize - 1];
+            let new_ix = new_rest[new_line as usize - 1];
+            old_for_new[new_ix] = Some(old_ix);
+            old_paired[old_ix] = true;
+        }
+    }
+
+    let mut pairs: Vec<_> = old_for_new
+        .into_iter()
+        .enumerate()
+        .map(|(new_ix, old_ix)| (old_ix, Some(new_ix)))
+        .collect();
+    pairs.extend(
+        (0..old.len())
+            .filter(|&ix| !old_paired[ix])
+            .map(|ix| (Some(ix), None)),
+    );
+    pairs
+}
+
+/// Pairs image outputs within matched cells and keeps only the ones that
+/// changed. Labels use the new-side cell number, or the old one for removed
+/// cells.
+pub(super) fn image_output_diffs(
+    old: &[NotebookCell],
+    new: &[NotebookCell],
+) -> Vec<NotebookImageOutputDiff> {
+    let mut diffs = Vec::new();
+    for (old_ix, new_ix) in pair_cells(old, new) {
+        let old_images = old_ix.map_or(&[][..], |ix| old[ix].images.as_slice());
+        let new_images = new_ix.map_or(&[][..], |ix| new[ix].images.as_slice());
+        let cell_label = match (old_ix, new_ix) {
+            (_, Some(new_ix)) => format!("cell {}", new_ix + 1),
+            (Some(old_ix), None) => format!("removed cell {}", old_ix + 1),
+            (None, None) => continue,
+        };
+        for image_ix in 0..old_images.len().max(new_images.len()) {
+            let old_image = old_images.get(image_ix);
+            let new_image = new_images.get(image_ix);
+            if old_image == new_image {
+                continue;
+            }
+            let Some(output_number) = new_image.or(old_image).map(|image| image.output_number)
+            else {
+                continue;
+            };
+            diffs.push(NotebookImageOutputDiff {
+                label: format!("{cell_label} · output {output_number}"),
+                old: old_image.cloned(),
+                new: new_image.cloned(),
+            });
+        }
+    }
+    diffs
+}
+
+pub(super) fn diff_preview_unavailable_reason(combined_len: usize) -> &'static str {
+    if combined_len > MAX_DIFF_PREVIEW_SOURCE_BYTES {
+        "Notebook preview unavailable: diff exceeds the 16 MiB preview limit."
+    } else {
+        "Notebook preview unavailable: file is not a valid notebook."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTEBOOK: &str = r##"{
+      "metadata": {"kernelspec": {"language": "python"}},
+      "cells": [
+        {"cell_type": "markdown", "source": ["# Title\n", "Intro"]},
+        {"cell_type": "code", "execution_count": 7, "source": "print('```')\n",
+         "outputs": [
+           {"output_type": "stream", "text": ["hello\n"]},
+           {"output_type": "display_data", "data": {"image/png": "iVBO\nRw==", "text/plain": "<Figure>"}}
+         ]}
+      ]
+    }"##;
+
+    #[test]
+    fn collapsed_outputs_hide_counts_and_text() {
+        let rendering =
+            render_notebook_markdown(NOTEBOOK, NotebookPreviewOptions::default()).unwrap();
+        assert_eq!(
+            rendering.markdown,
+            "**markdown cell**\n\n# Title\nIntro\n\n---\n\n\
+             **code cell**\n\n````python\nprint('```')\n````\n\n\
+             *2 outputs collapsed*\n\n"
+        );
+        assert_eq!(
+            rendering.cells[1].images,
+            vec![NotebookImageOutput {
+                output_number: 2,
+                mime: "image/png",
+                bytes: vec![0x89, 0x50, 0x4e, 0x47],
+            }]
+        );
+    }
+
+    #[test]
+    fn expanded_outputs_render_text_and_execution_counts() {
+        let rendering = render_notebook_markdown(
+            NOTEBOOK,
+            NotebookPreviewOptions {
+                show_outputs: true,
+                show_execution_counts: true,
+            },
+        )
+        .unwrap();
+        assert!(rendering.markdown.contains("**code cell** · In [7]\n\n"));
+        assert!(rendering.markdown.contains("```text\nhello\n```\n\n"));
+        assert!(rendering.markdown.contains("*image/png image output*\n\n"));
+    }
+
+    #[test]
+    fn invalid_json_is_not_a_notebook() {
+        assert_eq!(
+            render_notebook_markdown("{not json", NotebookPreviewOptions::default()),
+            None
+        );
+        assert_eq!(
+            render_notebook_markdown("", NotebookPreviewOptions::default()),
+            Some(NotebookRendering::default())
+        );
+    }
+
+    fn cell(id: Option<&str>, source: &str, images: &[&[u8]]) -> NotebookCell {
+        NotebookCell {
+            id: id.map(str::to_owned),
+            source: source.to_string(),
+            images: images
+                .iter()
+                .enumerate()
+                .map(|(ix, bytes)| NotebookImageOutput {
+                    output_number: ix + 1,
+                    mime: "image/png",
+                    bytes: bytes.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn cells_pair_by_id_even_when_moved_and_edited() {
+        let old = [
+            cell(Some("a"), "import os", &[]),
+            cell(Some("b"), "plot()", &[]),
+        ];
+        let new = [
+            cell(Some("b"), "plot(x)", &[]),
+            cell(Some("c"), "import os", &[]),
+            cell(Some("a"), "import sys", &[]),
+        ];
+        assert_eq!(
+            pair_cells(&old, &new),
+            vec![(Some(1), Some(0)), (None, Some(1)), (Some(0), Some(2))]
+        );
+    }
+
+    #[test]
+    fn cells_without_ids_align_by_source() {
+        let old = [
+            cell(None, "load()", &[]),
+            cell(None, "plot()", &[]),
+            cell(None, "save()", &[]),
+        ];
+        let new = [
+            cell(None, "# Notes", &[]),
+            cell(None, "load()", &[]),
+            cell(None, "save()", &[]),
+        ];
+        assert_eq!(
+            pair_cells(&old, &new),
+            vec![
+                (None, Some(0)),
+                (Some(0), Some(1)),
+                (Some(2), Some(2)),
+                (Some(1), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn image_output_diffs_follow_matched_cells() {
+        // A new cell is inserted first; without pairing every image would
+        // shift to a different "cell N" and show as changed.
+        let old = [
+            cell(Some("a"), "plot(a)", &[b"1"]),
+            cell(Some("b"), "plot(b)", &[b"2"]),
+            cell(Some("c"), "plot(c)", &[b"3"]),
+        ];
+        let new = [
+            cell(Some("d"), "plot(d)", &[b"4"]),
+            cell(Some("a"), "plot(a)", &[b"1"]),
+            cell(Some("b"), "plot(b)", &[b"22"]),
+        ];
+        let diffs = image_output_diffs(&old, &new);
+        let labels: Vec<_> = diffs
+            .iter()
+            .map(|diff| (diff.label.as_str(), diff.old.is_some(), diff.new.is_some()))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("cell 1 · output 1", false, true),
+                ("cell 3 · output 1", true, true),
+                ("removed cell 3 · output 1", true, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn base64_decoding_ignores_line_breaks() {
+        assert_eq!(decode_base64("aGVs\nbG8=").unwrap(), b"hello");
+        assert_eq!(decode_base64("a$"), None);
+    }
+}
@@ -51,6 +51,50 @@ fn push_tooltip_text(push_count: usize, tracking_branch_name: Option<&str>) -> S
     }
 }
 
+/// The interrupted operation to surface in the action bar. Falls back to the
+/// merge/rebase flags while the detailed state is still loading.
+pub(super) fn active_repo_operation(repo: &RepoState) -> Option<RepoOperationState> {
+    match &repo.operation_state {
+        Loadable::Ready(state) => state.clone(),
+        _ if matches!(&repo.merge_commit_message, Loadable::Ready(Some(_))) => {
+            Some(RepoOperationState::Merge)
+        }
+        _ if matches!(&repo.rebase_in_progress, Loadable::Ready(true)) => {
+            Some(RepoOperationState::Rebase(Default::default()))
+        }
+        _ => None,
+    }
+}
+
+fn operation_banner_label(state: &RepoOperationState) -> String {
+    const MAX_SUBJECT_CHARS: usize = 60;
+
+    let (verb, unit) = match state.kind() {
+        RepoOperationKind::Rebase => ("REBASING", "commit"),
+        RepoOperationKind::Merge => ("MERGING", "commit"),
+        RepoOperationKind::CherryPick => ("CHERRY-PICKING", "commit"),
+        RepoOperationKind::Revert => ("REVERTING", "commit"),
+        RepoOperationKind::ApplyMailbox => ("APPLYING PATCHES", "patch"),
+        RepoOperationKind::Bisect => ("BISECTING", "commit"),
+    };
+    let mut label = verb.to_string();
+    if let Some(progress) = state.progress() {
+        if let Some((current, total)) = progress.step {
+            label.push_str(&format!(" · {unit} {current} of {total}"));
+        }
+        if let Some(subject) = progress.subject.as_deref() {
+            label.push_str(" · ");
+            if subject.chars().count() > MAX_SUBJECT_CHARS {
+                label.extend(subject.chars().take(MAX_SUBJECT_CHARS - 1));
+                label.push('…');
+            } else {
+                label.push_str(subject);
+            }
+        }
+    }
+    label
+}
+
 pub(in super::super) struct ActionBarView {
     store: Arc<AppStore>,
     state: Arc<AppState>,
@@ -74,6 +118,7 @@ impl ActionBarView {
             repo.branches_rev.hash(&mut hasher);
             repo.upstream_divergence_rev.hash(&mut hasher);
             repo.merge_message_rev.hash(&mut hasher);
+            repo.operation_state_rev.hash(&mut hasher);
             repo.ops_rev.hash(&mut hasher);
             repo.status_cache_rev().hash(&mut hasher);
             repo.loads_in_flight.any_in_flight().hash(&mut hasher);
@@ -223,12 +268,7 @@ impl Render for ActionBarView {
             })
             .unwrap_or_else(|| "—".into());
 
-        let is_merging = self
-            .active_repo()
-            .is_some_and(|r| matches!(&r.merge_commit_message, Loadable::Ready(Some(_))));
-        let is_rebase_or_apply_in_progress = self
-            .active_repo()
-            .is_some_and(|r| matches!(&r.rebase_in_progress, Loadable::Ready(true)));
+        let operation = self.active_repo().and_then(active_repo_operation);
 
         let (pull_count, push_count) = self
             .active_repo()
@@ -602,52 +642,93 @@ impl Render for ActionBarView {
                     .flex_1()
                     .child(repo_picker)
                     .child(branch_picker)
-                    .when(is_merging, |d| {
-                        d.child(
-                            div()
-                                .flex()
-                                .items_center()
-                                .gap_1()
-                                .child(
-                                    div()
-                                        .text_xs()
-                                        .text_color(theme.colors.warning)
-                                        .font_weight(FontWeight::BOLD)
-                                        .child("MERGING"),
-                                )
-                                .child(
-                                    components::Button::new("abort_merge", "Abort merge")
-                                        .style(components::ButtonStyle::Danger)
-                                        .on_click(theme, cx, |this, e: &ClickEvent, window, cx| {
-                                            if let Some(repo_id) = this.active_repo_id() {
-                                                this.open_popover_at(
-                                                    PopoverKind::MergeAbortConfirm { repo_id },
-                                                    e.position(),
-                                                    window,
-                                                    cx,
-                                                );
-                                            }
-                                        }),
-                                ),
-                        )
-                    })
-                    .when(!is_merging && is_rebase_or_apply_in_progress, |d| {
+                    .when_some(operation, |d, operation| {
+                        let kind = operation.kind();
+                        let label = operation_banner_label(&operation);
+                        let action_button =
+                            |action: RepoOperationAction,
+                             id: &'static str,
+                             text: &'static str,
+                             cx: &mut gpui::Context<Self>| {
+                                components::Button::new(id, text)
+                                    .style(components::ButtonStyle::Outlined)
+                                    .on_click(theme, cx, move |this, _e: &ClickEvent, _w, cx| {
+                                        if let Some(repo_id) = this.active_repo_id() {
+                                            this.store.dispatch(Msg::OperationAction {
+                                                repo_id,
+                                                kind,
+                                                action,
+                                            });
+                                        }
+                                        cx.notify();
+                                    })
+                            };
                         d.child(
                             div()
                                 .flex()
                                 .items_center()
                                 .gap_1()
+                                .min_w(px(0.0))
                                 .child(
                                     div()
                                         .text_xs()
                                         .text_color(theme.colors.warning)
                                         .font_weight(FontWeight::BOLD)
-                                        .child("APPLY/REBASE"),
+                                        .truncate()
+                                        .child(label),
                                 )
+                                .when(kind != RepoOperationKind::Bisect, |d| {
+                                    d.child(
+                                        components::Button::new("operation_conflicts", "Conflicts")
+                                            .style(components::ButtonStyle::Outlined)
+                                            .on_click(
+                                                theme,
+                                                cx,
+                                                |this, e: &ClickEvent, window, cx| {
+                                                    if let Some(repo_id) = this.active_repo_id() {
+                                                        this.open_popover_at(
+                                                            PopoverKind::ConflictDashboard {
+                                                                repo_id,
+                                                            },
+                                                            e.position(),
+                                                            window,
+                                                            cx,
+                                                        );
+                                                    }
+                                                },
+                                            ),
+                                    )
+                                })
+                                .when(kind.supports(RepoOperationAction::Continue), |d| {
+                                    d.child(action_button(
+                                        RepoOperationAction::Continue,
+                                        "operation_continue",
+                                        "Continue",
+                                        cx,
+                                    ))
+                                })
+                                .when(kind.supports(RepoOperationAction::Skip), |d| {
+                                    d.child(action_button(
+                                        RepoOperationAction::Skip,
+                                        "operation_skip",
+                                        "Skip",
+                                        cx,
+                                    ))
+                                })
                                 .child(
-                                    components::Button::new("abort_rebase_or_apply", "Abort")
-                                        .style(components::ButtonStyle::Danger)
-                                        .on_click(theme, cx, |this, e: &ClickEvent, window, cx| {
+                                    components::Button::new(
+                                        "operation_abort",
+                                        if kind == RepoOperationKind::Bisect {
+                                            "Reset"
+                                        } else {
+                                            "Abort"
+                                        },
+                                    )
+                                    .style(components::ButtonStyle::Danger)
+                                    .on_click(
+                                        theme,
+                                        cx,
+                                        |this, e: &ClickEvent, window, cx| {
                                             if let Some(repo_id) = this.active_repo_id() {
                                                 this.open_popover_at(
                                                     PopoverKind::MergeAbortConfirm { repo_id },
@@ -656,7 +737,8 @@ impl Render for ActionBarView {
                                                     cx,
                                                 );
                                             }
-                                        }),
+                                        },
+                                    ),
                                 ),
                         )
                     }),
@@ -740,6 +822,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn operation_banner_label_includes_step_and_subject() {
+        use gitcomet_core::domain::OperationProgress;
+
+        assert_eq!(
+            operation_banner_label(&RepoOperationState::Rebase(OperationProgress {
+                step: Some((2, 5)),
+                subject: Some("Fix parser".to_string()),
+            })),
+            "REBASING · commit 2 of 5 · Fix parser"
+        );
+        assert_eq!(
+            operation_banner_label(&RepoOperationState::ApplyMailbox(OperationProgress {
+                step: Some((1, 3)),
+                subject: None,
+            })),
+            "APPLYING PATCHES · patch 1 of 3"
+        );
+        assert_eq!(
+            operation_banner_label(&RepoOperationState::Merge),
+            "MERGING"
+        );
+        let long = operation_banner_label(&RepoOperationState::CherryPick(OperationProgress {
+            step: None,
+            subject: Some("x".repeat(100)),
+        }));
+        assert!(long.ends_with('…'));
+        assert_eq!(
+            long.chars().count(),
+            "CHERRY-PICKING · ".chars().count() + 60
+        );
+    }
+
     #[test]
     fn pull_tooltip_text_includes_tracking_branch_on_second_line() {
         assert_eq!(
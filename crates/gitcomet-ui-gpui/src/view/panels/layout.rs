@@ -125,7 +125,7 @@ fn active_status_section_action_path(
     diff_target: Option<&DiffTarget>,
     section: StatusSection,
 ) -> Option<std::path::PathBuf> {
-    let DiffTarget::WorkingTree { path, area } = diff_target? else {
+    let DiffTarget::WorkingTree { path, area, .. } = diff_target? else {
         return None;
     };
     if *area != section.diff_area() {
@@ -445,6 +445,18 @@ impl DetailsPaneView {
         let message = self
             .commit_message_input
             .read_with(cx, |input, _| input.text().to_string());
+        if let Some(reword) = self.commit_reword.clone() {
+            if reword.repo_id != repo_id || message.trim().is_empty() {
+                return false;
+            }
+            self.store.dispatch(Msg::RewordCommit {
+                repo_id,
+                commit_id: reword.commit_id,
+                message: message.trim().to_string(),
+            });
+            self.finish_reword_commit(cx);
+            return true;
+        }
         let amend = self.commit_amend_enabled;
         if !Self::can_submit_commit(self.active_repo(), &message, amend) {
             return false;
@@ -503,6 +515,75 @@ impl DetailsPaneView {
         }
     }
 
+    /// Results of "find in commit files", shown in place of the file list
+    /// while a search is active.
+    fn commit_file_search_results(&self, cx: &mut gpui::Context<Self>) -> Option<AnyElement> {
+        let theme = self.theme;
+        let repo = self.active_repo()?;
+        let search = repo.history_state.commit_file_search.as_ref()?;
+        let message = match &search.matches {
+            Loadable::NotLoaded | Loadable::Loading => Some(SharedString::from("Searching…")),
+            Loadable::Error(e) => Some(e.clone().into()),
+            Loadable::Ready(matches) if matches.is_empty() => Some("No matches.".into()),
+            Loadable::Ready(_) => None,
+        };
+        if let Some(message) = message {
+            return Some(
+                div()
+                    .text_sm()
+                    .text_color(theme.colors.text_muted)
+                    .child(message)
+                    .into_any_element(),
+            );
+        }
+        let count = match &search.matches {
+            Loadable::Ready(matches) => matches.len(),
+            _ => 0,
+        };
+
+        let list = uniform_list(
+            ("commit_file_search_results", repo.id.0),
+            count,
+            cx.processor(Self::render_commit_file_search_rows),
+        )
+        .w_full()
+        .h_full()
+        .min_h(px(0.0))
+        .track_scroll(&self.commit_file_search_scroll);
+        Some(
+            div()
+                .id(("commit_file_search_results_container", repo.id.0))
+                .relative()
+                .flex()
+                .flex_col()
+                .flex_1()
+                .h_full()
+                .min_h(px(0.0))
+                .w_full()
+                .overflow_hidden()
+                .child(
+                    div()
+                        .w_full()
+                        .flex_1()
+                        .h_full()
+                        .min_h(px(0.0))
+                        .pr(components::Scrollbar::visible_gutter(
+                            self.commit_file_search_scroll.clone(),
+                            components::ScrollbarAxis::Vertical,
+                        ))
+                        .child(list),
+                )
+                .child(
+                    components::Scrollbar::new(
+                        ("commit_file_search_results_scrollbar", repo.id.0),
+                        self.commit_file_search_scroll.clone(),
+                    )
+                    .render(theme),
+                )
+                .into_any_element(),
+        )
+    }
+
     pub(in super::super) fn commit_details_view(
         &mut self,
         cx: &mut gpui::Context<Self>,
@@ -599,7 +680,11 @@ impl DetailsPaneView {
                                     .child("No files.")
                                     .into_any_element()
                             } else {
-                                let total_files = details.files.len();
+                                let total_files = crate::view::rows::CommitFileListRows::new(
+                                    &details.files,
+                                    self.commit_files_generated_expanded,
+                                )
+                                .len();
                                 let list = uniform_list(
                                     ("commit_details_files_list", repo_id.0),
                                     total_files,
@@ -750,14 +835,20 @@ impl DetailsPaneView {
                             .map(|p: &CommitId| p.as_ref().to_string())
                             .unwrap_or_else(|| "—".to_string());
 
-                        let files = if details.files.is_empty() {
+                        let files = if let Some(results) = self.commit_file_search_results(cx) {
+                            results
+                        } else if details.files.is_empty() {
                             div()
                                 .text_sm()
                                 .text_color(theme.colors.text_muted)
                                 .child("No files.")
                                 .into_any_element()
                         } else {
-                            let total_files = details.files.len();
+                            let total_files = crate::view::rows::CommitFileListRows::new(
+                                &details.files,
+                                self.commit_files_generated_expanded,
+                            )
+                            .len();
                             let list = uniform_list(
                                 ("commit_details_files_list", repo_id.0),
                                 total_files,
@@ -893,9 +984,22 @@ impl DetailsPaneView {
                                     .min_h(commit_files_section_min_height)
                                     .child(
                                         div()
-                                            .text_sm()
-                                            .text_color(theme.colors.text_muted)
-                                            .child("Committed files"),
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .child(
+                                                div()
+                                                    .flex_1()
+                                                    .text_sm()
+                                                    .text_color(theme.colors.text_muted)
+                                                    .child("Committed files"),
+                                            )
+                                            .child(
+                                                div()
+                                                    .w(px(180.0))
+                                                    .min_w(px(96.0))
+                                                    .child(self.commit_file_search_input.clone()),
+                                            ),
                                     )
                                     .child(files),
                             )
@@ -1783,13 +1887,25 @@ impl DetailsPaneView {
         if count == 0 {
             return components::empty_state(theme, "Status", "Clean.").into_any_element();
         }
+        let tree_rows = self
+            .active_repo()
+            .filter(|_| self.change_tracking_view == ChangeTrackingView::Tree)
+            .map(|repo| self.status_tree_rows(repo, section).len());
         match section {
             StatusSection::CombinedUnstaged => {
-                let list =
-                    uniform_list("unstaged", count, cx.processor(Self::render_unstaged_rows))
-                        .h_full()
-                        .min_h(px(0.0))
-                        .track_scroll(&self.unstaged_scroll);
+                let list = match tree_rows {
+                    Some(rows) => uniform_list(
+                        "unstaged",
+                        rows,
+                        cx.processor(Self::render_unstaged_tree_rows),
+                    ),
+                    None => {
+                        uniform_list("unstaged", count, cx.processor(Self::render_unstaged_rows))
+                    }
+                }
+                .h_full()
+                .min_h(px(0.0))
+                .track_scroll(&self.unstaged_scroll);
                 let list = div()
                     .flex_1()
                     .h_full()
@@ -1893,10 +2009,15 @@ impl DetailsPaneView {
                     .into_any_element()
             }
             StatusSection::Staged => {
-                let list = uniform_list("staged", count, cx.processor(Self::render_staged_rows))
-                    .h_full()
-                    .min_h(px(0.0))
-                    .track_scroll(&self.staged_scroll);
+                let list = match tree_rows {
+                    Some(rows) => {
+                        uniform_list("staged", rows, cx.processor(Self::render_staged_tree_rows))
+                    }
+                    None => uniform_list("staged", count, cx.processor(Self::render_staged_rows)),
+                }
+                .h_full()
+                .min_h(px(0.0))
+                .track_scroll(&self.staged_scroll);
                 let list = div()
                     .flex_1()
                     .h_full()
@@ -1932,11 +2053,15 @@ impl DetailsPaneView {
             .active_repo()
             .is_some_and(|repo| repo.commit_in_flight > 0);
         let commit_message_text = self.commit_message_input.read(cx).text().to_string();
-        let can_submit_commit = Self::can_submit_commit(
-            self.active_repo(),
-            &commit_message_text,
-            self.commit_amend_enabled,
-        );
+        let can_submit_commit = if self.commit_reword.is_some() {
+            !commit_message_text.trim().is_empty()
+        } else {
+            Self::can_submit_commit(
+                self.active_repo(),
+                &commit_message_text,
+                self.commit_amend_enabled,
+            )
+        };
         let repo_key = self.active_repo_id().map(|id| id.0).unwrap_or(0);
         let icon_color = theme.colors.accent;
         let icon = |path: &'static str| svg_icon(path, icon_color, px(14.0));
@@ -1955,6 +2080,19 @@ impl DetailsPaneView {
                 "Amend the previous commit; published amends require explicit force push with lease"
             }
         };
+        let (commit_label, commit_tooltip): (SharedString, SharedString) =
+            match self.commit_reword.as_ref() {
+                Some(reword) => {
+                    let sha = reword.commit_id.as_ref();
+                    let short = sha.get(0..8).unwrap_or(sha);
+                    (
+                        format!("Reword {short}").into(),
+                        format!("Replace the message of {short} and rewrite the commits above it")
+                            .into(),
+                    )
+                }
+                None => (commit_label.into(), commit_tooltip.into()),
+            };
         let commit_options_invoker: SharedString = "commit_options".into();
         let commit_options_active = self
             .active_context_menu_invoker
@@ -2018,7 +2156,7 @@ impl DetailsPaneView {
                 let _ = this.submit_commit(cx);
             })
             .debug_selector(|| "commit_button".to_string())
-            .gitcomet_tooltip(theme, commit_tooltip.into());
+            .gitcomet_tooltip(theme, commit_tooltip);
         let commit_menu = components::Button::new("commit_options", "")
             .borderless()
             .start_slot(svg_icon(
@@ -2068,12 +2206,22 @@ impl DetailsPaneView {
             })
             .debug_selector(|| "previous_commit_messages_button".to_string())
             .gitcomet_tooltip(theme, "Previous commit messages".into());
+        let cancel_reword = self.commit_reword.is_some().then(|| {
+            components::Button::new("cancel_reword", "Cancel")
+                .style(components::ButtonStyle::Outlined)
+                .on_click(theme, cx, |this, _e, _w, cx| {
+                    this.finish_reword_commit(cx);
+                })
+                .debug_selector(|| "cancel_reword_button".to_string())
+                .gitcomet_tooltip(theme, "Keep the current commit message".into())
+        });
         div().flex().flex_col().gap_2().child(commit_message).child(
             div().flex().items_center().justify_end().child(
                 div()
                     .flex()
                     .items_center()
                     .gap_2()
+                    .children(cancel_reword)
                     .child(previous_messages_menu)
                     .child(
                         components::SplitButton::new(commit_main, commit_menu)
@@ -2108,6 +2256,8 @@ mod tests {
             path: PathBuf::from(path),
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -2266,6 +2416,7 @@ mod tests {
         let diff_target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let selection = status_section_action_selection(
@@ -2297,6 +2448,7 @@ mod tests {
         let diff_target = DiffTarget::WorkingTree {
             path: PathBuf::from("new.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let untracked = status_section_action_selection(
@@ -2342,6 +2494,7 @@ mod tests {
         let diff_target = DiffTarget::WorkingTree {
             path: PathBuf::from("src/other.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let selection = StatusMultiSelection {
             unstaged: vec![selected_a.clone(), selected_b.clone()],
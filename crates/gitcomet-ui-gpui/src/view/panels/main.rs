@@ -1,12 +1,16 @@
 use super::*;
 
 mod binary_conflict;
+mod csv_diff;
 mod decision_conflict;
 mod diff;
 mod diff_view;
 mod diff_view_helpers;
+mod hex_diff;
 mod history;
+mod image_diff;
 mod keep_delete_conflict;
+mod notebook_diff;
 mod status_nav;
 
 pub(super) fn show_external_mergetool_actions(view_mode: GitCometViewMode) -> bool {
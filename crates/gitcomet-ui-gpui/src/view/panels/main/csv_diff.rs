@@ -0,0 +1,322 @@
+use super::*;
+use crate::view::csv_preview::{CsvChangeKind, CsvDiffPreview, CsvRowAlignment};
+
+const CSV_DIFF_CELL_WIDTH_PX: f32 = 160.0;
+const CSV_DIFF_GUTTER_WIDTH_PX: f32 = 48.0;
+
+fn csv_diff_record_label(record: Option<usize>) -> SharedString {
+    record
+        .map(|record| SharedString::from(record.to_string()))
+        .unwrap_or_default()
+}
+
+fn csv_diff_summary(preview: &CsvDiffPreview) -> SharedString {
+    let added = preview.count(CsvChangeKind::Added);
+    let removed = preview.count(CsvChangeKind::Removed);
+    let modified = preview.count(CsvChangeKind::Modified);
+    let mut summary = format!("{added} added · {removed} removed · {modified} modified rows");
+    let added_columns = preview
+        .columns
+        .iter()
+        .filter(|column| column.kind == CsvChangeKind::Added)
+        .count();
+    let removed_columns = preview
+        .columns
+        .iter()
+        .filter(|column| column.kind == CsvChangeKind::Removed)
+        .count();
+    if added_columns > 0 || removed_columns > 0 {
+        summary.push_str(&format!(
+            " · {added_columns} added · {removed_columns} removed columns"
+        ));
+    }
+    summary.into()
+}
+
+/// Clicking the current key column switches back to positional alignment.
+fn csv_diff_alignment_after_header_click(
+    current: &CsvRowAlignment,
+    column: &str,
+) -> CsvRowAlignment {
+    match current {
+        CsvRowAlignment::KeyColumn(key) if key == column => CsvRowAlignment::Position,
+        _ => CsvRowAlignment::KeyColumn(column.to_string()),
+    }
+}
+
+pub(super) fn csv_diff_alignment_label(alignment: &CsvRowAlignment) -> SharedString {
+    match alignment {
+        CsvRowAlignment::Position => "Rows: by position".into(),
+        CsvRowAlignment::KeyColumn(column) => format!("Rows: by key “{column}”").into(),
+    }
+}
+
+impl MainPaneView {
+    fn csv_diff_gutter(&self, text: SharedString, theme: AppTheme) -> gpui::Div {
+        div()
+            .flex_none()
+            .w(px(CSV_DIFF_GUTTER_WIDTH_PX))
+            .px_1()
+            .flex()
+            .justify_end()
+            .text_color(theme.colors.text_muted)
+            .border_r_1()
+            .border_color(theme.colors.border)
+            .child(text)
+    }
+
+    fn render_csv_diff_header(
+        &self,
+        preview: &CsvDiffPreview,
+        theme: AppTheme,
+        cx: &mut gpui::Context<Self>,
+    ) -> AnyElement {
+        let offset_x = self.diff_scroll.0.borrow().base_handle.offset().x;
+        let key_column = preview.key_column;
+        let mut row = div()
+            .flex()
+            .flex_none()
+            .left(offset_x)
+            .relative()
+            .child(self.csv_diff_gutter("old".into(), theme))
+            .child(self.csv_diff_gutter("new".into(), theme));
+        for (ix, column) in preview.columns.iter().enumerate() {
+            let name = column.name.clone();
+            let text_color = match column.kind {
+                CsvChangeKind::Added => theme.colors.diff_add_text,
+                CsvChangeKind::Removed => theme.colors.diff_remove_text,
+                CsvChangeKind::Unchanged | CsvChangeKind::Modified => theme.colors.text,
+            };
+            let label: SharedString = if key_column == Some(ix) {
+                format!("{name} (key)").into()
+            } else {
+                name.clone().into()
+            };
+            row = row.child(
+                div()
+                    .id(("csv_diff_column_header", ix))
+                    .flex_none()
+                    .w(px(CSV_DIFF_CELL_WIDTH_PX))
+                    .px_2()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .font_weight(FontWeight::BOLD)
+                    .text_color(text_color)
+                    .border_r_1()
+                    .border_color(theme.colors.border)
+                    .when(column.kind == CsvChangeKind::Removed, |d| d.line_through())
+                    .cursor(CursorStyle::PointingHand)
+                    .hover(move |s| s.bg(theme.colors.hover))
+                    .child(label)
+                    .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                        let alignment = csv_diff_alignment_after_header_click(
+                            &this.csv_preview_alignment,
+                            &name,
+                        );
+                        this.set_csv_preview_alignment(alignment, cx);
+                    })),
+            );
+        }
+
+        div()
+            .id("diff_csv_preview_header")
+            .flex_none()
+            .overflow_hidden()
+            .text_xs()
+            .bg(theme.colors.surface_bg_elevated)
+            .border_b_1()
+            .border_color(theme.colors.border)
+            .child(row)
+            .into_any_element()
+    }
+
+    fn render_csv_diff_rows(
+        this: &mut Self,
+        range: Range<usize>,
+        _window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Vec<AnyElement> {
+        let theme = this.theme;
+        let editor_font_family: SharedString =
+            crate::font_preferences::current_editor_font_family(cx).into();
+        let Loadable::Ready(preview) = &this.file_csv_preview else {
+            return Vec::new();
+        };
+        let preview = Arc::clone(preview);
+        let selection = this.diff_selection_range.map(|(a, b)| (a.min(b), a.max(b)));
+
+        range
+            .filter_map(|row_ix| {
+                let row = preview.rows.get(row_ix)?;
+                let row_bg = match row.kind {
+                    CsvChangeKind::Unchanged => None,
+                    CsvChangeKind::Added => Some(theme.colors.diff_add_bg),
+                    CsvChangeKind::Removed => Some(theme.colors.diff_remove_bg),
+                    CsvChangeKind::Modified => Some(with_alpha(theme.colors.warning, 0.10)),
+                };
+                let selected =
+                    selection.is_some_and(|(start, end)| (start..=end).contains(&row_ix));
+                let mut element = div()
+                    .id(("csv_diff_row", row_ix))
+                    .flex()
+                    .font_family(editor_font_family.clone())
+                    .text_xs()
+                    .when_some(row_bg, |d, bg| d.bg(bg))
+                    .when(selected, |d| d.bg(with_alpha(theme.colors.accent, 0.18)))
+                    .child(this.csv_diff_gutter(csv_diff_record_label(row.old_record), theme))
+                    .child(this.csv_diff_gutter(csv_diff_record_label(row.new_record), theme));
+                for (cell, column) in row.cells.iter().zip(preview.columns.iter()) {
+                    let changed = row.kind == CsvChangeKind::Modified && cell.is_changed();
+                    let text_color = match (row.kind, column.kind) {
+                        (CsvChangeKind::Added, _) | (_, CsvChangeKind::Added) => {
+                            theme.colors.diff_add_text
+                        }
+                        (CsvChangeKind::Removed, _) | (_, CsvChangeKind::Removed) => {
+                            theme.colors.diff_remove_text
+                        }
+                        _ => theme.colors.text,
+                    };
+                    element = element.child(
+                        div()
+                            .flex_none()
+                            .w(px(CSV_DIFF_CELL_WIDTH_PX))
+                            .px_2()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .text_color(text_color)
+                            .border_r_1()
+                            .border_color(theme.colors.border)
+                            .when(changed, |d| {
+                                d.bg(with_alpha(theme.colors.warning, 0.28))
+                                    .font_weight(FontWeight::BOLD)
+                            })
+                            .child(cell.display_text()),
+                    );
+                }
+                Some(
+                    element
+                        .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                            this.diff_selection_anchor = Some(row_ix);
+                            this.diff_selection_range = Some((row_ix, row_ix));
+                            cx.notify();
+                        }))
+                        .into_any_element(),
+                )
+            })
+            .collect()
+    }
+
+    pub(super) fn render_csv_diff_preview(
+        &mut self,
+        theme: AppTheme,
+        preview: Arc<CsvDiffPreview>,
+        cx: &mut gpui::Context<Self>,
+    ) -> AnyElement {
+        if preview.columns.is_empty() && preview.rows.is_empty() {
+            return components::empty_state(theme, "Preview", "Empty file.").into_any_element();
+        }
+
+        self.maybe_autoscroll_diff_to_first_change();
+
+        let scroll_handle = self.diff_scroll.0.borrow().base_handle.clone();
+        let list = uniform_list(
+            "diff_csv_preview_rows",
+            preview.rows.len(),
+            cx.processor(Self::render_csv_diff_rows),
+        )
+        .h_full()
+        .min_h(px(0.0))
+        .track_scroll(&self.diff_scroll)
+        .with_horizontal_sizing_behavior(gpui::ListHorizontalSizingBehavior::Unconstrained);
+
+        div()
+            .id("diff_csv_preview_container")
+            .relative()
+            .h_full()
+            .min_h(px(0.0))
+            .flex()
+            .flex_col()
+            .bg(theme.colors.window_bg)
+            .child(
+                div()
+                    .flex_none()
+                    .px_2()
+                    .py_0p5()
+                    .text_xs()
+                    .text_color(theme.colors.text_muted)
+                    .border_b_1()
+                    .border_color(theme.colors.border)
+                    .child(csv_diff_summary(&preview)),
+            )
+            .child(self.render_csv_diff_header(&preview, theme, cx))
+            .child(
+                div()
+                    .id("diff_csv_preview_rows_container")
+                    .relative()
+                    .flex_1()
+                    .min_h(px(0.0))
+                    .child(
+                        div()
+                            .h_full()
+                            .min_h(px(0.0))
+                            .pr(components::Scrollbar::visible_gutter(
+                                self.diff_scroll.clone(),
+                                components::ScrollbarAxis::Vertical,
+                            ))
+                            .child(list),
+                    )
+                    .child(
+                        components::Scrollbar::horizontal(
+                            "diff_csv_preview_hscrollbar",
+                            scroll_handle,
+                        )
+                        .always_visible()
+                        .render(theme),
+                    )
+                    .child(
+                        components::Scrollbar::new(
+                            "diff_csv_preview_scrollbar",
+                            self.diff_scroll.clone(),
+                        )
+                        .markers(crate::view::csv_preview::scrollbar_markers(&preview))
+                        .always_visible()
+                        .render(theme),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_click_toggles_key_alignment() {
+        let position = CsvRowAlignment::Position;
+        let by_id = csv_diff_alignment_after_header_click(&position, "id");
+        assert_eq!(by_id, CsvRowAlignment::KeyColumn("id".to_string()));
+        assert_eq!(
+            csv_diff_alignment_after_header_click(&by_id, "name"),
+            CsvRowAlignment::KeyColumn("name".to_string())
+        );
+        assert_eq!(
+            csv_diff_alignment_after_header_click(&by_id, "id"),
+            CsvRowAlignment::Position
+        );
+    }
+
+    #[test]
+    fn alignment_label_names_key_column() {
+        assert_eq!(
+            csv_diff_alignment_label(&CsvRowAlignment::Position).as_ref(),
+            "Rows: by position"
+        );
+        assert_eq!(
+            csv_diff_alignment_label(&CsvRowAlignment::KeyColumn("id".to_string())).as_ref(),
+            "Rows: by key “id”"
+        );
+    }
+}
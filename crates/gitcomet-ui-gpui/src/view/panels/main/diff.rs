@@ -65,11 +65,15 @@ impl MainPaneView {
                 || self.rendered_preview_modes.get(RenderedPreviewKind::Svg)
                     == RenderedPreviewMode::Rendered);
         let wants_markdown_preview = wants_rendered_file_content
-            && rendered_preview_kind == Some(RenderedPreviewKind::Markdown)
-            && self
-                .rendered_preview_modes
-                .get(RenderedPreviewKind::Markdown)
+            && rendered_preview_kind.is_some_and(|kind| {
+                kind.uses_markdown_preview()
+                    && self.rendered_preview_modes.get(kind) == RenderedPreviewMode::Rendered
+            });
+        let wants_csv_preview = wants_rendered_file_content
+            && rendered_preview_kind == Some(RenderedPreviewKind::Csv)
+            && self.rendered_preview_modes.get(RenderedPreviewKind::Csv)
                 == RenderedPreviewMode::Rendered;
+        let wants_table_or_markdown_preview = wants_markdown_preview || wants_csv_preview;
 
         if wants_image {
             enum DiffFileImageState {
@@ -129,126 +133,7 @@ impl MainPaneView {
                         components::empty_state(theme, "Diff", "Processing image...")
                             .into_any_element()
                     } else {
-                        enum CachedDiffImageSource {
-                            Path(std::path::PathBuf),
-                            Render(Arc<gpui::RenderImage>),
-                        }
-
-                        let old = self
-                            .file_image_diff_cache_old_svg_path
-                            .clone()
-                            .map(CachedDiffImageSource::Path)
-                            .or_else(|| {
-                                self.file_image_diff_cache_old
-                                    .clone()
-                                    .map(CachedDiffImageSource::Render)
-                            });
-                        let new = self
-                            .file_image_diff_cache_new_svg_path
-                            .clone()
-                            .map(CachedDiffImageSource::Path)
-                            .or_else(|| {
-                                self.file_image_diff_cache_new
-                                    .clone()
-                                    .map(CachedDiffImageSource::Render)
-                            });
-
-                        let cell = |id: &'static str, image: Option<CachedDiffImageSource>| {
-                            let muted = theme.colors.text_muted;
-                            div()
-                                .id(id)
-                                .flex_1()
-                                .min_w(px(0.0))
-                                .h_full()
-                                .overflow_hidden()
-                                .flex()
-                                .items_center()
-                                .justify_center()
-                                .child(match image {
-                                    Some(CachedDiffImageSource::Path(path)) => {
-                                        let clamp_preview_size = path
-                                            .extension()
-                                            .and_then(|s| s.to_str())
-                                            .is_some_and(|ext| ext.eq_ignore_ascii_case("ico"));
-                                        gpui::img(path)
-                                            .w_full()
-                                            .h_full()
-                                            .object_fit(if clamp_preview_size {
-                                                gpui::ObjectFit::ScaleDown
-                                            } else {
-                                                gpui::ObjectFit::Contain
-                                            })
-                                            .with_loading(move || {
-                                                div()
-                                                    .text_sm()
-                                                    .text_color(muted)
-                                                    .child("Processing image...")
-                                                    .into_any_element()
-                                            })
-                                            .with_fallback(move || {
-                                                div()
-                                                    .text_sm()
-                                                    .text_color(muted)
-                                                    .child("Preview unavailable.")
-                                                    .into_any_element()
-                                            })
-                                            .into_any_element()
-                                    }
-                                    Some(CachedDiffImageSource::Render(img_data)) => {
-                                        gpui::img(img_data)
-                                            .w_full()
-                                            .h_full()
-                                            .object_fit(gpui::ObjectFit::Contain)
-                                            .with_loading(move || {
-                                                div()
-                                                    .text_sm()
-                                                    .text_color(muted)
-                                                    .child("Processing image...")
-                                                    .into_any_element()
-                                            })
-                                            .with_fallback(move || {
-                                                div()
-                                                    .text_sm()
-                                                    .text_color(muted)
-                                                    .child("Preview unavailable.")
-                                                    .into_any_element()
-                                            })
-                                            .into_any_element()
-                                    }
-                                    None => div()
-                                        .text_sm()
-                                        .text_color(theme.colors.text_muted)
-                                        .child("No image")
-                                        .into_any_element(),
-                                })
-                        };
-
-                        let columns_header = components::split_columns_header(
-                            theme,
-                            ui_scale_percent,
-                            "A (before)",
-                            "B (after)",
-                        );
-
-                        div()
-                            .id("diff_image_container")
-                            .relative()
-                            .h_full()
-                            .min_h(px(0.0))
-                            .flex()
-                            .flex_col()
-                            .bg(theme.colors.window_bg)
-                            .child(columns_header)
-                            .child(
-                                div()
-                                    .flex_1()
-                                    .min_h(px(0.0))
-                                    .flex()
-                                    .child(cell("diff_image_left", old))
-                                    .child(div().w(px(1.0)).h_full().bg(theme.colors.border))
-                                    .child(cell("diff_image_right", new)),
-                            )
-                            .into_any_element()
+                        self.render_file_image_diff(theme, ui_scale_percent, cx)
                     }
                 }
             }
@@ -257,6 +142,7 @@ impl MainPaneView {
                 NotLoaded,
                 Loading,
                 Error(String),
+                Binary,
                 Ready { has_file: bool },
             }
 
@@ -268,6 +154,7 @@ impl MainPaneView {
                 Some(Loadable::NotLoaded) => DiffFileState::NotLoaded,
                 Some(Loadable::Loading) => DiffFileState::Loading,
                 Some(Loadable::Error(e)) => DiffFileState::Error(e.clone()),
+                Some(Loadable::Ready(Some(file))) if file.is_binary() => DiffFileState::Binary,
                 Some(Loadable::Ready(file)) => DiffFileState::Ready {
                     has_file: file.is_some(),
                 },
@@ -281,7 +168,8 @@ impl MainPaneView {
                     .into_any_element();
             }
 
-            if !wants_markdown_preview {
+            if !wants_table_or_markdown_preview && !matches!(diff_file_state, DiffFileState::Binary)
+            {
                 self.ensure_file_diff_cache(cx);
             }
 
@@ -290,7 +178,7 @@ impl MainPaneView {
                     components::empty_state(theme, "Diff", "Select a file.").into_any_element()
                 }
                 DiffFileState::Loading => {
-                    let label = if wants_markdown_preview {
+                    let label = if wants_table_or_markdown_preview {
                         "Preview"
                     } else {
                         "Diff"
@@ -298,7 +186,7 @@ impl MainPaneView {
                     components::empty_state(theme, label, "Loading").into_any_element()
                 }
                 DiffFileState::Error(e) => {
-                    if wants_markdown_preview {
+                    if wants_table_or_markdown_preview {
                         components::empty_state(theme, "Preview", e).into_any_element()
                     } else {
                         self.diff_raw_input.update(cx, |input, cx| {
@@ -319,6 +207,26 @@ impl MainPaneView {
                             .into_any_element()
                     }
                 }
+                DiffFileState::Binary => {
+                    self.ensure_file_hex_preview_cache();
+                    match &self.file_hex_preview {
+                        Loadable::Ready(preview)
+                            if preview.old.is_some() || preview.new.is_some() =>
+                        {
+                            let preview = Arc::clone(preview);
+                            self.render_hex_diff_preview(theme, preview, cx)
+                        }
+                        Loadable::Error(e) => {
+                            components::empty_state(theme, "Diff", e.clone()).into_any_element()
+                        }
+                        _ => components::empty_state(
+                            theme,
+                            "Diff",
+                            "Binary file; no contents available.",
+                        )
+                        .into_any_element(),
+                    }
+                }
                 DiffFileState::Ready { has_file } if wants_markdown_preview => {
                     if !has_file {
                         components::empty_state(theme, "Preview", "No file contents available.")
@@ -338,9 +246,42 @@ impl MainPaneView {
                                 let old_len = preview.old.rows.len();
                                 let new_len = preview.new.rows.len();
                                 let inline_len = preview.inline.rows.len();
-                                self.render_markdown_diff_preview(
+                                let preview = self.render_markdown_diff_preview(
                                     theme, old_len, new_len, inline_len, cx,
-                                )
+                                );
+                                match self.render_notebook_image_outputs(theme) {
+                                    Some(image_outputs) => div()
+                                        .flex()
+                                        .flex_col()
+                                        .h_full()
+                                        .min_h(px(0.0))
+                                        .child(div().flex_1().min_h(px(0.0)).child(preview))
+                                        .child(image_outputs)
+                                        .into_any_element(),
+                                    None => preview,
+                                }
+                            }
+                        }
+                    }
+                }
+                DiffFileState::Ready { has_file } if wants_csv_preview => {
+                    if !has_file {
+                        components::empty_state(theme, "Preview", "No file contents available.")
+                            .into_any_element()
+                    } else {
+                        self.ensure_file_csv_preview_cache(cx);
+                        match &self.file_csv_preview {
+                            Loadable::NotLoaded | Loadable::Loading => {
+                                components::empty_state(theme, "Preview", "Processing preview...")
+                                    .into_any_element()
+                            }
+                            Loadable::Error(e) => {
+                                components::empty_state(theme, "Preview", e.clone())
+                                    .into_any_element()
+                            }
+                            Loadable::Ready(preview) => {
+                                let preview = Arc::clone(preview);
+                                self.render_csv_diff_preview(theme, preview, cx)
                             }
                         }
                     }
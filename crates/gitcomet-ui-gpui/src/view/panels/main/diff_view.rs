@@ -59,6 +59,7 @@ fn inline_submodule_entries(summary: &SubmoduleDiffSummary) -> Vec<InlineSubmodu
                 target: DiffTarget::WorkingTree {
                     path: change.path.clone(),
                     area: DiffArea::Staged,
+                    old_path: None,
                 },
                 section: InlineSubmoduleDiffSection::LiveStaged,
             }),
@@ -73,6 +74,7 @@ fn inline_submodule_entries(summary: &SubmoduleDiffSummary) -> Vec<InlineSubmodu
                 target: DiffTarget::WorkingTree {
                     path: change.path.clone(),
                     area: DiffArea::Unstaged,
+                    old_path: None,
                 },
                 section: InlineSubmoduleDiffSection::LiveUnstaged,
             }),
@@ -155,10 +157,15 @@ impl MainPaneView {
                 .read(cx)
                 .focus_handle()
                 .is_focused(window)
+            && !self
+                .diff_search_replace_input
+                .read(cx)
+                .focus_handle()
+                .is_focused(window)
             && let Some(repo_id) = self.active_repo_id()
             && let Some(repo) = self.active_repo()
             && let Some(diff_target) = repo.diff_state.diff_target.clone()
-            && let DiffTarget::WorkingTree { path, area } = &diff_target
+            && let DiffTarget::WorkingTree { path, area, .. } = &diff_target
         {
             let path = path.clone();
             let area = *area;
@@ -183,6 +190,7 @@ impl MainPaneView {
                             target: DiffTarget::WorkingTree {
                                 path: next_path,
                                 area: DiffArea::Unstaged,
+                                old_path: None,
                             },
                         });
                     } else {
@@ -200,6 +208,7 @@ impl MainPaneView {
                             target: DiffTarget::WorkingTree {
                                 path: next_path,
                                 area: DiffArea::Staged,
+                                old_path: None,
                             },
                         });
                     } else {
@@ -269,7 +278,8 @@ impl MainPaneView {
         }
 
         let conflict_resolver_active = self.is_conflict_resolver_active();
-        let markdown_preview_active = self.is_markdown_preview_active();
+        let rendered_preview_active =
+            self.is_markdown_preview_active() || self.is_csv_preview_active();
         let conflict_preview_active = self.is_conflict_rendered_preview_active();
 
         if mods.alt && !mods.control && !mods.platform && !mods.function {
@@ -281,7 +291,7 @@ impl MainPaneView {
                         self.diff_view = DiffViewMode::Split;
                         self.clear_diff_text_style_caches();
                         handled = true;
-                    } else if !markdown_preview_active && !self.is_file_preview_active() {
+                    } else if !rendered_preview_active && !self.is_file_preview_active() {
                         self.diff_view = if key == "i" {
                             DiffViewMode::Inline
                         } else {
@@ -291,10 +301,13 @@ impl MainPaneView {
                         handled = true;
                     }
                 }
-                "w" if !markdown_preview_active && !conflict_preview_active => {
+                "w" if !rendered_preview_active && !conflict_preview_active => {
                     self.toggle_reveal_whitespace_chars();
                     handled = true;
                 }
+                "m" if !conflict_resolver_active && !rendered_preview_active => {
+                    handled = self.diff_jump_to_moved_counterpart();
+                }
                 "up" => {
                     handled = self.navigate_prev_diff_change(cx);
                 }
@@ -343,6 +356,7 @@ impl MainPaneView {
                 .focus_handle()
                 .is_focused(window)
             && self.conflict_resolver_conflict_count() > 0
+            && !self.conflict_resolver_compare_only()
             && let Some(choice) = conflict_resolver::conflict_quick_pick_choice_for_key(key)
         {
             self.conflict_resolver_pick_active_conflict(choice, cx);
@@ -385,13 +399,21 @@ impl MainPaneView {
 
     fn prepare_source_mode_for_diff_search(&mut self, cx: &mut gpui::Context<Self>) {
         if self.is_markdown_preview_active() {
+            let kind = super::super::diff_target_rendered_preview_kind(self.rendered_diff_target())
+                .filter(|kind| kind.uses_markdown_preview())
+                .unwrap_or(RenderedPreviewKind::Markdown);
             self.rendered_preview_modes
-                .set(RenderedPreviewKind::Markdown, RenderedPreviewMode::Source);
+                .set(kind, RenderedPreviewMode::Source);
             let wants_file_diff = self.wants_file_diff_view(self.is_file_preview_active());
             if wants_file_diff {
                 self.ensure_file_diff_cache(cx);
             }
         }
+        if self.is_csv_preview_active() {
+            self.rendered_preview_modes
+                .set(RenderedPreviewKind::Csv, RenderedPreviewMode::Source);
+            self.ensure_file_diff_cache(cx);
+        }
         if self.is_conflict_rendered_preview_active() {
             self.conflict_resolver.resolver_preview_mode = ConflictResolverPreviewMode::Text;
         }
@@ -419,6 +441,7 @@ impl MainPaneView {
     fn deactivate_diff_search(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
         self.diff_search_active = false;
         self.diff_search_query = SharedString::default();
+        self.diff_search_regex = None;
         self.diff_search_matches.clear();
         self.diff_search_match_ix = None;
         self.diff_search_input
@@ -429,7 +452,7 @@ impl MainPaneView {
         window.focus(&self.diff_panel_focus_handle, cx);
     }
 
-    fn restore_diff_panel_focus_after_toolbar_action(
+    pub(super) fn restore_diff_panel_focus_after_toolbar_action(
         &self,
         window: &mut Window,
         cx: &mut gpui::Context<Self>,
@@ -443,6 +466,18 @@ impl MainPaneView {
         });
     }
 
+    /// Open the search bar with `query` already entered.
+    pub(in crate::view) fn open_diff_search_with_query(
+        &mut self,
+        query: &str,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.diff_search_input
+            .update(cx, |input, cx| input.set_text(query.to_string(), cx));
+        self.activate_diff_search(window, cx);
+    }
+
     pub(in crate::view) fn open_search_for_active_view(
         &mut self,
         window: &mut Window,
@@ -473,6 +508,8 @@ impl MainPaneView {
         let query = self.diff_search_query.as_ref().trim();
         let match_label: SharedString = if query.is_empty() {
             "Type to search".into()
+        } else if self.diff_search_invalid_pattern {
+            "Invalid pattern".into()
         } else if self.diff_search_matches.is_empty() {
             "No matches".into()
         } else {
@@ -483,17 +520,100 @@ impl MainPaneView {
             format!("{}/{}", ix + 1, self.diff_search_matches.len()).into()
         };
 
-        let panel = div()
+        let options = self.diff_search_options;
+        let option_toggle = |id: &'static str,
+                             label: &'static str,
+                             tooltip: &'static str,
+                             selected: bool,
+                             next: TextSearchOptions,
+                             cx: &mut gpui::Context<Self>| {
+            components::Button::new(id, label)
+                .borderless()
+                .style(components::ButtonStyle::Subtle)
+                .selected(selected)
+                .selected_bg(theme.colors.active)
+                .on_click(theme, cx, move |this, _e, _window, cx| {
+                    this.set_diff_search_options(next);
+                    cx.notify();
+                })
+                .gitcomet_tooltip(theme, tooltip.into())
+        };
+        let case_toggle = option_toggle(
+            "diff_search_case_sensitive",
+            "Aa",
+            "Match case",
+            options.case_sensitive,
+            TextSearchOptions {
+                case_sensitive: !options.case_sensitive,
+                ..options
+            },
+            cx,
+        );
+        let word_toggle = option_toggle(
+            "diff_search_whole_word",
+            "W",
+            "Match whole word",
+            options.whole_word,
+            TextSearchOptions {
+                whole_word: !options.whole_word,
+                ..options
+            },
+            cx,
+        );
+        let regex_toggle = option_toggle(
+            "diff_search_regex",
+            ".*",
+            "Use regular expression",
+            options.regex,
+            TextSearchOptions {
+                regex: !options.regex,
+                ..options
+            },
+            cx,
+        );
+
+        let replace_row = self.diff_search_replace_available().then(|| {
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(
+                    div()
+                        .w(px(240.0))
+                        .min_w(px(120.0))
+                        .debug_selector(|| "diff_search_replace_input_slot".to_string())
+                        .child(self.diff_search_replace_input.clone()),
+                )
+                .child(
+                    components::Button::new("diff_search_replace_next", "Replace")
+                        .style(components::ButtonStyle::Subtle)
+                        .on_click(theme, cx, |this, _e, _window, cx| {
+                            this.diff_search_replace_next(cx);
+                            cx.notify();
+                        })
+                        .gitcomet_tooltip(
+                            theme,
+                            "Replace the next match in the resolved output".into(),
+                        ),
+                )
+                .child(
+                    components::Button::new("diff_search_replace_all", "Replace all")
+                        .style(components::ButtonStyle::Subtle)
+                        .on_click(theme, cx, |this, _e, _window, cx| {
+                            this.diff_search_replace_all(cx);
+                            cx.notify();
+                        })
+                        .gitcomet_tooltip(
+                            theme,
+                            "Replace every match in the resolved output".into(),
+                        ),
+                )
+        });
+
+        let search_row = div()
             .flex()
             .items_center()
             .gap_1()
-            .px_2()
-            .py_1()
-            .rounded(px(theme.radii.row))
-            .border_1()
-            .border_color(theme.colors.border)
-            .bg(theme.colors.surface_bg_elevated)
-            .shadow_sm()
             .child(
                 div()
                     .w(px(240.0))
@@ -501,6 +621,9 @@ impl MainPaneView {
                     .debug_selector(|| "diff_search_input_slot".to_string())
                     .child(self.diff_search_input.clone()),
             )
+            .child(case_toggle)
+            .child(word_toggle)
+            .child(regex_toggle)
             .child(
                 div()
                     .w(px(96.0))
@@ -529,7 +652,21 @@ impl MainPaneView {
                         cx.notify();
                     })
                     .debug_selector(|| "diff_search_close".to_string()),
-            )
+            );
+
+        let panel = div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .px_2()
+            .py_1()
+            .rounded(px(theme.radii.row))
+            .border_1()
+            .border_color(theme.colors.border)
+            .bg(theme.colors.surface_bg_elevated)
+            .shadow_sm()
+            .child(search_row)
+            .children(replace_row)
             .with_animation(
                 "diff_search_overlay_mount",
                 Animation::new(Duration::from_millis(120)).with_easing(gpui::quadratic),
@@ -591,7 +728,7 @@ impl MainPaneView {
                 .into_any_element();
         };
         let (submodule_path, selected_area) = match &selected_target {
-            DiffTarget::WorkingTree { path, area } => (path.clone(), Some(*area)),
+            DiffTarget::WorkingTree { path, area, .. } => (path.clone(), Some(*area)),
             DiffTarget::Commit {
                 path: Some(path), ..
             } => (path.clone(), None),
@@ -678,6 +815,7 @@ impl MainPaneView {
                     FileStatusKind::Modified => ("icons/pencil.svg", theme.colors.warning),
                     FileStatusKind::Deleted => ("icons/minus.svg", theme.colors.danger),
                     FileStatusKind::Renamed => ("icons/swap.svg", theme.colors.accent),
+                    FileStatusKind::Copied => ("icons/copy.svg", theme.colors.accent),
                     FileStatusKind::Conflicted => ("icons/warning.svg", theme.colors.danger),
                 };
 
@@ -718,6 +856,7 @@ impl MainPaneView {
                                         live_area.map(|area| DiffTarget::WorkingTree {
                                             path: change_path.clone(),
                                             area,
+                                            old_path: None,
                                         })
                                     },
                                     |(from_commit_id, to_commit_id)| {
@@ -1192,7 +1331,7 @@ impl MainPaneView {
             .then_some(())
             .and(repo)
             .and_then(|repo| {
-                let DiffTarget::WorkingTree { path, area } =
+                let DiffTarget::WorkingTree { path, area, .. } =
                     repo.diff_state.diff_target.as_ref()?
                 else {
                     return None;
@@ -1236,11 +1375,12 @@ impl MainPaneView {
             is_file_preview,
             rendered_preview_kind,
         );
-        let is_markdown_preview_view = rendered_view_toggle_kind
-            == Some(RenderedPreviewKind::Markdown)
-            && self
-                .rendered_preview_modes
-                .get(RenderedPreviewKind::Markdown)
+        let is_markdown_preview_view = rendered_view_toggle_kind.is_some_and(|kind| {
+            kind.uses_markdown_preview()
+                && self.rendered_preview_modes.get(kind) == RenderedPreviewMode::Rendered
+        });
+        let is_csv_preview_view = rendered_view_toggle_kind == Some(RenderedPreviewKind::Csv)
+            && self.rendered_preview_modes.get(RenderedPreviewKind::Csv)
                 == RenderedPreviewMode::Rendered;
         let is_image_diff_loaded = wants_file_diff
             && self
@@ -1351,101 +1491,116 @@ impl MainPaneView {
                 })
                 .when_some(next_file_btn, |d, btn| d.child(btn));
 
-            let stage_safety = if self.conflict_resolved_output_is_streamed() {
-                // Streamed mode: output is not materialized in the TextInput,
-                // so skip the text-based marker check. Unresolved blocks are
-                // still tracked via segments.
-                conflict_resolver::conflict_stage_safety_check(
-                    "",
-                    &self.conflict_resolver.marker_segments,
-                )
-            } else {
-                let resolved_output_text = self
-                    .conflict_resolver_input
-                    .read_with(cx, |i, _| i.text().to_string());
-                conflict_resolver::conflict_stage_safety_check(
-                    &resolved_output_text,
-                    &self.conflict_resolver.marker_segments,
-                )
-            };
-
-            if stage_safety.has_conflict_markers {
-                controls = controls.child(
-                    div()
-                        .text_xs()
-                        .text_color(theme.colors.danger)
-                        .child("markers remain"),
-                );
-            }
-
-            if let (Some(repo_id), Some(path)) = (repo_id, conflict_target_path.clone()) {
-                let focused_mergetool_mode = self.view_mode == GitCometViewMode::FocusedMergetool;
-                let save_label = if focused_mergetool_mode {
-                    "Save & close"
-                } else {
-                    "Save"
-                };
-                let save_path = path.clone();
-                controls = controls
-                    .child(
-                        components::Button::new("conflict_save", save_label)
+            if self.conflict_resolver_compare_only() {
+                if let Some(repo_id) = repo_id {
+                    controls = controls.child(
+                        components::Button::new("conflict_compare_close", "Close")
+                            .separated_end_slot(Self::diff_nav_hotkey_hint(theme, "Esc"))
                             .style(components::ButtonStyle::Outlined)
                             .on_click(theme, cx, move |this, _e, _w, cx| {
-                                if this.view_mode == GitCometViewMode::FocusedMergetool {
-                                    this.focused_mergetool_save_and_exit(
-                                        repo_id,
-                                        save_path.clone(),
-                                        cx,
-                                    );
-                                    return;
-                                }
-                                let text = this.conflict_resolver_save_contents(cx);
-                                this.store.dispatch(Msg::SaveWorktreeFile {
-                                    repo_id,
-                                    path: save_path.clone(),
-                                    contents: text,
-                                    stage: false,
-                                });
+                                this.clear_diff_selection_or_exit(repo_id, cx);
                             }),
+                    );
+                }
+            } else {
+                let stage_safety = if self.conflict_resolved_output_is_streamed() {
+                    // Streamed mode: output is not materialized in the TextInput,
+                    // so skip the text-based marker check. Unresolved blocks are
+                    // still tracked via segments.
+                    conflict_resolver::conflict_stage_safety_check(
+                        "",
+                        &self.conflict_resolver.marker_segments,
                     )
-                    .when(show_conflict_save_stage_action(self.view_mode), |d| {
-                        let save_path = path.clone();
-                        d.child(
-                            components::Button::new("conflict_save_stage", "Save & stage")
-                                .style(components::ButtonStyle::Filled)
-                                .on_click(theme, cx, move |this, e, window, cx| {
-                                    let text = this.current_conflict_resolved_output_text(cx);
-                                    let stage_safety =
-                                        conflict_resolver::conflict_stage_safety_check(
-                                            &text,
-                                            &this.conflict_resolver.marker_segments,
-                                        );
-                                    if stage_safety.requires_confirmation() {
-                                        this.open_popover_at(
-                                            PopoverKind::ConflictSaveStageConfirm {
-                                                repo_id,
-                                                path: save_path.clone(),
-                                                has_conflict_markers: stage_safety
-                                                    .has_conflict_markers,
-                                                unresolved_blocks: stage_safety.unresolved_blocks,
-                                            },
-                                            e.position(),
-                                            window,
+                } else {
+                    let resolved_output_text = self
+                        .conflict_resolver_input
+                        .read_with(cx, |i, _| i.text().to_string());
+                    conflict_resolver::conflict_stage_safety_check(
+                        &resolved_output_text,
+                        &self.conflict_resolver.marker_segments,
+                    )
+                };
+
+                if stage_safety.has_conflict_markers {
+                    controls = controls.child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.colors.danger)
+                            .child("markers remain"),
+                    );
+                }
+
+                if let (Some(repo_id), Some(path)) = (repo_id, conflict_target_path.clone()) {
+                    let focused_mergetool_mode =
+                        self.view_mode == GitCometViewMode::FocusedMergetool;
+                    let save_label = if focused_mergetool_mode {
+                        "Save & close"
+                    } else {
+                        "Save"
+                    };
+                    let save_path = path.clone();
+                    controls = controls
+                        .child(
+                            components::Button::new("conflict_save", save_label)
+                                .style(components::ButtonStyle::Outlined)
+                                .on_click(theme, cx, move |this, _e, _w, cx| {
+                                    if this.view_mode == GitCometViewMode::FocusedMergetool {
+                                        this.focused_mergetool_save_and_exit(
+                                            repo_id,
+                                            save_path.clone(),
                                             cx,
                                         );
-                                    } else {
-                                        let text =
-                                            this.conflict_resolver_save_contents_from_text(text);
-                                        this.store.dispatch(Msg::SaveWorktreeFile {
-                                            repo_id,
-                                            path: save_path.clone(),
-                                            contents: text,
-                                            stage: true,
-                                        });
+                                        return;
                                     }
+                                    let text = this.conflict_resolver_save_contents(cx);
+                                    this.store.dispatch(Msg::SaveWorktreeFile {
+                                        repo_id,
+                                        path: save_path.clone(),
+                                        contents: text,
+                                        stage: false,
+                                    });
                                 }),
                         )
-                    });
+                        .when(show_conflict_save_stage_action(self.view_mode), |d| {
+                            let save_path = path.clone();
+                            d.child(
+                                components::Button::new("conflict_save_stage", "Save & stage")
+                                    .style(components::ButtonStyle::Filled)
+                                    .on_click(theme, cx, move |this, e, window, cx| {
+                                        let text = this.current_conflict_resolved_output_text(cx);
+                                        let stage_safety =
+                                            conflict_resolver::conflict_stage_safety_check(
+                                                &text,
+                                                &this.conflict_resolver.marker_segments,
+                                            );
+                                        if stage_safety.requires_confirmation() {
+                                            this.open_popover_at(
+                                                PopoverKind::ConflictSaveStageConfirm {
+                                                    repo_id,
+                                                    path: save_path.clone(),
+                                                    has_conflict_markers: stage_safety
+                                                        .has_conflict_markers,
+                                                    unresolved_blocks: stage_safety
+                                                        .unresolved_blocks,
+                                                },
+                                                e.position(),
+                                                window,
+                                                cx,
+                                            );
+                                        } else {
+                                            let text = this
+                                                .conflict_resolver_save_contents_from_text(text);
+                                            this.store.dispatch(Msg::SaveWorktreeFile {
+                                                repo_id,
+                                                path: save_path.clone(),
+                                                contents: text,
+                                                stage: true,
+                                            });
+                                        }
+                                    }),
+                            )
+                        });
+                }
             }
         } else if !is_file_preview {
             let view_toggle_selected_bg =
@@ -1603,10 +1758,64 @@ impl MainPaneView {
                 controls = controls
                     .child(prev_hunk_btn)
                     .child(next_hunk_btn)
-                    .when_some(next_file_btn, |d, btn| d.child(btn))
-                    .child(view_toggle);
+                    .when_some(next_file_btn, |d, btn| d.child(btn));
+                if is_csv_preview_view {
+                    let keyed = matches!(
+                        self.csv_preview_alignment,
+                        crate::view::csv_preview::CsvRowAlignment::KeyColumn(_)
+                    );
+                    controls = controls.child(
+                        components::Button::new(
+                            "csv_diff_row_alignment",
+                            super::csv_diff::csv_diff_alignment_label(&self.csv_preview_alignment),
+                        )
+                        .style(components::ButtonStyle::Outlined)
+                        .disabled(!keyed)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.set_csv_preview_alignment(
+                                crate::view::csv_preview::CsvRowAlignment::Position,
+                                cx,
+                            );
+                            this.restore_diff_panel_focus_after_toolbar_action(window, cx);
+                        })
+                        .gitcomet_tooltip(
+                            theme,
+                            "Click a column header to pair rows by that key; click here to pair by position"
+                                .into(),
+                        ),
+                    );
+                } else {
+                    controls = controls.child(view_toggle);
+                }
+                if is_markdown_preview_view
+                    && rendered_view_toggle_kind == Some(RenderedPreviewKind::Notebook)
+                {
+                    let notebook_controls = self.notebook_toolbar_controls(
+                        theme,
+                        ui_scale_percent,
+                        [
+                            view_toggle_selected_bg,
+                            view_toggle_border,
+                            view_toggle_divider,
+                        ],
+                        cx,
+                    );
+                    controls = controls.child(notebook_controls);
+                }
             } else {
-                controls = controls.when_some(next_file_btn, |d, btn| d.child(btn));
+                let image_controls = self.image_diff_toolbar_controls(
+                    theme,
+                    ui_scale_percent,
+                    [
+                        view_toggle_selected_bg,
+                        view_toggle_border,
+                        view_toggle_divider,
+                    ],
+                    cx,
+                );
+                controls = controls
+                    .when_some(next_file_btn, |d, btn| d.child(btn))
+                    .child(image_controls);
             }
         } else {
             controls = controls
@@ -1900,8 +2109,13 @@ impl MainPaneView {
                         .into_any_element()
                 }
                 (Some(repo), Some(path)) => {
+                    let title_prefix = if self.conflict_resolver_compare_only() {
+                        "Compare"
+                    } else {
+                        "Resolve conflict"
+                    };
                     let title: SharedString =
-                        format!("Resolve conflict: {}", self.cached_path_display(&path)).into();
+                        format!("{title_prefix}: {}", self.cached_path_display(&path)).into();
                     if let Some(repo_id) = repo_id {
                         match renderable_conflict_file(repo, &self.conflict_resolver, &path) {
                             RenderableConflictFile::Loading => {
@@ -2475,6 +2689,12 @@ impl MainPaneView {
                                         )
                                 };
 
+                            let compare_titles = self.conflict_resolver_compare_titles();
+                            let column_title = |ix: usize, default: &'static str| -> SharedString {
+                                compare_titles
+                                    .as_ref()
+                                    .map_or_else(|| default.into(), |titles| titles[ix].clone())
+                            };
                             let top_title_row = div()
                                 .h(px(22.0))
                                 .w_full()
@@ -2493,7 +2713,7 @@ impl MainPaneView {
                                             .text_color(theme.colors.text_muted)
                                             .whitespace_nowrap()
                                             .child(div().w(px(38.0)).flex_shrink_0())
-                                            .child("Base (A, index :1)"),
+                                            .child(column_title(0, "Base (A, index :1)")),
                                     )
                                     .child(conflict_hsplit_resize_handle(
                                         "conflict_hsplit_handle_first",
@@ -2511,7 +2731,7 @@ impl MainPaneView {
                                             .text_color(theme.colors.text_muted)
                                             .whitespace_nowrap()
                                             .child(div().w(px(38.0)).flex_shrink_0())
-                                            .child("Local (B, index :2)"),
+                                            .child(column_title(1, "Local (B, index :2)")),
                                     )
                                     .child(conflict_hsplit_resize_handle(
                                         "conflict_hsplit_handle_second",
@@ -2530,7 +2750,7 @@ impl MainPaneView {
                                             .text_color(theme.colors.text_muted)
                                             .whitespace_nowrap()
                                             .child(div().w(px(38.0)).flex_shrink_0())
-                                            .child("Remote (C, index :3)"),
+                                            .child(column_title(2, "Remote (C, index :3)")),
                                     )
                                 })
                                 .when(view_mode == ConflictResolverViewMode::TwoWayDiff, |d| {
@@ -2547,7 +2767,7 @@ impl MainPaneView {
                                             .text_color(theme.colors.text_muted)
                                             .whitespace_nowrap()
                                             .child(div().w(px(38.0)).flex_shrink_0())
-                                            .child("Local (index :2)"),
+                                            .child(column_title(1, "Local (index :2)")),
                                     )
                                     .child(
                                         div()
@@ -2647,7 +2867,7 @@ impl MainPaneView {
                                             .text_color(theme.colors.text_muted)
                                             .whitespace_nowrap()
                                             .child(div().w(px(38.0)).flex_shrink_0())
-                                            .child("Remote (index :3)"),
+                                            .child(column_title(2, "Remote (index :3)")),
                                     )
                                 });
 
@@ -2660,7 +2880,8 @@ impl MainPaneView {
                                         .render_conflict_resolver_svg_preview(theme, cx),
                                     Some(RenderedPreviewKind::Markdown) => self
                                         .render_conflict_resolver_markdown_preview(theme, cx),
-                                    None => components::empty_state(
+                                    Some(RenderedPreviewKind::Csv | RenderedPreviewKind::Notebook)
+                                    | None => components::empty_state(
                                         theme,
                                         "Preview",
                                         "Preview is not available for this file.",
@@ -3167,7 +3388,7 @@ impl MainPaneView {
                                     }),
                                 );
 
-                            div()
+                            let panel = div()
                                 .id("conflict_resolver_panel")
                                 .flex()
                                 .flex_col()
@@ -3195,7 +3416,13 @@ impl MainPaneView {
                                     top_section.style().flex_shrink = Some(1.0);
                                     top_section.style().flex_basis = Some(relative(0.).into());
                                     top_section
-                                })
+                                });
+                            // A comparison has no output to edit, so the
+                            // inputs take the whole pane.
+                            if self.conflict_resolver_compare_only() {
+                                panel.into_any_element()
+                            } else {
+                            panel
                                 .child(vsplit_handle)
                                 .child(output_header)
                                 .when_some(autosolve_summary, |d, summary| {
@@ -3357,6 +3584,7 @@ impl MainPaneView {
                                 })
                                 .into_any_element()
                             }
+                            }
                         }
                     } else {
                         debug_assert!(false, "conflict resolver rendered without active repo id");
@@ -6,7 +6,7 @@ impl MainPaneView {
             .map(|t| {
                 let (icon, color, text): (Option<&'static str>, gpui::Rgba, SharedString) = match t
                 {
-                    DiffTarget::WorkingTree { path, area } => {
+                    DiffTarget::WorkingTree { path, area, .. } => {
                         let kind = if self.is_inline_submodule_diff_active() {
                             self.selected_inline_submodule_diff_entry()
                                 .map(|entry| entry.kind)
@@ -24,13 +24,16 @@ impl MainPaneView {
                             FileStatusKind::Modified => ("icons/pencil.svg", theme.colors.warning),
                             FileStatusKind::Deleted => ("icons/minus.svg", theme.colors.danger),
                             FileStatusKind::Renamed => ("icons/swap.svg", theme.colors.accent),
+                            FileStatusKind::Copied => ("icons/copy.svg", theme.colors.accent),
                             FileStatusKind::Conflicted => {
                                 ("icons/warning.svg", theme.colors.danger)
                             }
                         };
                         (Some(icon), color, self.cached_path_display(path))
                     }
-                    DiffTarget::Commit { commit_id: _, path } => match path {
+                    DiffTarget::Commit {
+                        commit_id: _, path, ..
+                    } => match path {
                         Some(path) => (
                             Some("icons/pencil.svg"),
                             theme.colors.text_muted,
@@ -59,6 +62,36 @@ impl MainPaneView {
                         ),
                     },
                 };
+                let rename_label = match t {
+                    DiffTarget::WorkingTree {
+                        path,
+                        area,
+                        old_path,
+                    } => crate::view::rows::rename_source_label(
+                        old_path.as_deref(),
+                        self.active_repo()
+                            .and_then(|repo| repo.status_entry_for_path(*area, path.as_path()))
+                            .and_then(|entry| entry.similarity),
+                    ),
+                    DiffTarget::Commit {
+                        commit_id,
+                        path,
+                        old_path,
+                    } => crate::view::rows::rename_source_label(
+                        old_path.as_deref(),
+                        self.active_repo().and_then(|repo| {
+                            match &repo.history_state.commit_details {
+                                Loadable::Ready(details) if details.id == *commit_id => details
+                                    .files
+                                    .iter()
+                                    .find(|file| Some(&file.path) == path.as_ref())
+                                    .and_then(|file| file.similarity),
+                                _ => None,
+                            }
+                        }),
+                    ),
+                    DiffTarget::CommitRange { .. } => None,
+                };
 
                 div()
                     .flex()
@@ -89,6 +122,16 @@ impl MainPaneView {
                                     .render(cx),
                             ),
                     )
+                    .when_some(rename_label, |this, label| {
+                        this.child(
+                            div()
+                                .flex_none()
+                                .text_xs()
+                                .text_color(theme.colors.text_muted)
+                                .whitespace_nowrap()
+                                .child(label),
+                        )
+                    })
                     .into_any_element()
             })
             .unwrap_or_else(|| {
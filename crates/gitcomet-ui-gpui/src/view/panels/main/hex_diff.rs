@@ -0,0 +1,224 @@
+use super::*;
+use crate::view::hex_preview::{
+    HexDiffPreview, HexDiffRowText, HexDiffSide, format_offset, format_size,
+};
+
+const HEX_DIFF_OFFSET_WIDTH_PX: f32 = 80.0;
+
+fn hex_diff_side_summary(label: &str, side: Option<&HexDiffSide>) -> SharedString {
+    match side {
+        None => format!("{label}: (absent)").into(),
+        Some(side) => {
+            let mut summary = format!("{label}: {} · {}", format_size(side.size), side.mime);
+            if let Some(sha) = side.sha.as_deref() {
+                summary.push_str(&format!(" · {sha}"));
+            }
+            summary.into()
+        }
+    }
+}
+
+fn hex_diff_text(
+    text: HexDiffRowText,
+    changed: gpui::Rgba,
+    window: &Window,
+) -> (gpui::StyledText, gpui::StyledText) {
+    let style = gpui::HighlightStyle {
+        color: Some(changed.into()),
+        background_color: Some(with_alpha(changed, 0.22).into()),
+        font_weight: Some(FontWeight::BOLD),
+        ..gpui::HighlightStyle::default()
+    };
+    let text_style = window.text_style();
+    let hex = gpui::StyledText::new(text.hex).with_default_highlights(
+        &text_style,
+        text.hex_changed.into_iter().map(|range| (range, style)),
+    );
+    let ascii = gpui::StyledText::new(text.ascii).with_default_highlights(
+        &text_style,
+        text.ascii_changed.into_iter().map(|range| (range, style)),
+    );
+    (hex, ascii)
+}
+
+impl MainPaneView {
+    fn render_hex_diff_rows(
+        this: &mut Self,
+        range: Range<usize>,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Vec<AnyElement> {
+        let theme = this.theme;
+        let editor_font_family: SharedString =
+            crate::font_preferences::current_editor_font_family(cx).into();
+        let Loadable::Ready(preview) = &this.file_hex_preview else {
+            return Vec::new();
+        };
+        let window_bytes = match preview.read_rows(range.clone()) {
+            Ok(window_bytes) => window_bytes,
+            Err(err) => {
+                return vec![
+                    div()
+                        .px_2()
+                        .text_xs()
+                        .text_color(theme.colors.danger)
+                        .child(err.to_string())
+                        .into_any_element(),
+                ];
+            }
+        };
+        let side_column = |hex: gpui::StyledText, ascii: gpui::StyledText| {
+            div()
+                .flex()
+                .flex_none()
+                .gap_3()
+                .px_2()
+                .border_r_1()
+                .border_color(theme.colors.border)
+                .child(div().flex_none().whitespace_nowrap().child(hex))
+                .child(div().flex_none().whitespace_nowrap().child(ascii))
+        };
+
+        range
+            .filter_map(|row_ix| {
+                let row = window_bytes.row(row_ix)?;
+                let (old_hex, old_ascii) =
+                    hex_diff_text(row.old_text(), theme.colors.diff_remove_text, window);
+                let (new_hex, new_ascii) =
+                    hex_diff_text(row.new_text(), theme.colors.diff_add_text, window);
+                Some(
+                    div()
+                        .id(("hex_diff_row", row_ix))
+                        .flex()
+                        .font_family(editor_font_family.clone())
+                        .text_xs()
+                        .when(row.has_changes(), |d| {
+                            d.bg(with_alpha(theme.colors.warning, 0.08))
+                        })
+                        .child(
+                            div()
+                                .flex_none()
+                                .w(px(HEX_DIFF_OFFSET_WIDTH_PX))
+                                .px_2()
+                                .text_color(theme.colors.text_muted)
+                                .border_r_1()
+                                .border_color(theme.colors.border)
+                                .child(format_offset(row.offset)),
+                        )
+                        .child(side_column(old_hex, old_ascii))
+                        .child(side_column(new_hex, new_ascii))
+                        .into_any_element(),
+                )
+            })
+            .collect()
+    }
+
+    /// Hex dump of both sides aligned at byte offsets, with an ASCII column.
+    pub(super) fn render_hex_diff_preview(
+        &mut self,
+        theme: AppTheme,
+        preview: Arc<HexDiffPreview>,
+        cx: &mut gpui::Context<Self>,
+    ) -> AnyElement {
+        let scroll_handle = self.diff_scroll.0.borrow().base_handle.clone();
+        let list = uniform_list(
+            "diff_hex_preview_rows",
+            preview.row_count(),
+            cx.processor(Self::render_hex_diff_rows),
+        )
+        .h_full()
+        .min_h(px(0.0))
+        .track_scroll(&self.diff_scroll)
+        .with_horizontal_sizing_behavior(gpui::ListHorizontalSizingBehavior::Unconstrained);
+
+        let summary_line = |text: SharedString| {
+            div()
+                .whitespace_nowrap()
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(text)
+        };
+
+        div()
+            .id("diff_hex_preview_container")
+            .relative()
+            .h_full()
+            .min_h(px(0.0))
+            .flex()
+            .flex_col()
+            .bg(theme.colors.window_bg)
+            .child(
+                div()
+                    .flex_none()
+                    .flex()
+                    .flex_col()
+                    .px_2()
+                    .py_0p5()
+                    .text_xs()
+                    .text_color(theme.colors.text_muted)
+                    .border_b_1()
+                    .border_color(theme.colors.border)
+                    .child(summary_line(hex_diff_side_summary(
+                        "Old",
+                        preview.old.as_ref(),
+                    )))
+                    .child(summary_line(hex_diff_side_summary(
+                        "New",
+                        preview.new.as_ref(),
+                    ))),
+            )
+            .child(
+                div()
+                    .id("diff_hex_preview_rows_container")
+                    .relative()
+                    .flex_1()
+                    .min_h(px(0.0))
+                    .child(
+                        div()
+                            .h_full()
+                            .min_h(px(0.0))
+                            .pr(components::Scrollbar::visible_gutter(
+                                self.diff_scroll.clone(),
+                                components::ScrollbarAxis::Vertical,
+                            ))
+                            .child(list),
+                    )
+                    .child(
+                        components::Scrollbar::horizontal(
+                            "diff_hex_preview_hscrollbar",
+                            scroll_handle,
+                        )
+                        .render(theme),
+                    )
+                    .child(
+                        components::Scrollbar::new(
+                            "diff_hex_preview_scrollbar",
+                            self.diff_scroll.clone(),
+                        )
+                        .always_visible()
+                        .render(theme),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn side_summary_lists_size_mime_and_sha() {
+        let side = HexDiffSide {
+            path: std::path::PathBuf::from("/tmp/blob"),
+            size: 2048,
+            sha: Some(Arc::from("0123abcd")),
+            mime: "application/zip",
+        };
+        assert_eq!(
+            hex_diff_side_summary("Old", Some(&side)).as_ref(),
+            "Old: 2,048 bytes · application/zip · 0123abcd"
+        );
+        assert_eq!(hex_diff_side_summary("New", None).as_ref(), "New: (absent)");
+    }
+}
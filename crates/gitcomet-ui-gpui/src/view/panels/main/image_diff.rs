@@ -0,0 +1,524 @@
+use super::*;
+
+const IMAGE_DIFF_MIN_ZOOM: f32 = 0.1;
+const IMAGE_DIFF_MAX_ZOOM: f32 = 16.0;
+const IMAGE_DIFF_ZOOM_STEP: f32 = 1.25;
+/// Keeps the swipe divider off the viewport edges so both sides stay grabbable.
+const IMAGE_DIFF_RATIO_MARGIN: f32 = 0.02;
+
+fn stepped_image_diff_zoom(zoom: f32, zoom_in: bool) -> f32 {
+    let next = if zoom_in {
+        zoom * IMAGE_DIFF_ZOOM_STEP
+    } else {
+        zoom / IMAGE_DIFF_ZOOM_STEP
+    };
+    next.clamp(IMAGE_DIFF_MIN_ZOOM, IMAGE_DIFF_MAX_ZOOM)
+}
+
+fn image_diff_ratio_at(bounds: Bounds<Pixels>, x: Pixels) -> Option<f32> {
+    if bounds.size.width <= px(0.0) {
+        return None;
+    }
+    let ratio = (x - bounds.left()) / bounds.size.width;
+    Some(ratio.clamp(IMAGE_DIFF_RATIO_MARGIN, 1.0 - IMAGE_DIFF_RATIO_MARGIN))
+}
+
+enum CachedDiffImageSource {
+    Path(std::path::PathBuf),
+    Render(Arc<gpui::RenderImage>),
+}
+
+fn image_diff_image(image: Option<CachedDiffImageSource>, theme: AppTheme) -> AnyElement {
+    let muted = theme.colors.text_muted;
+    let (img, object_fit) = match image {
+        Some(CachedDiffImageSource::Path(path)) => {
+            let clamp_preview_size = path
+                .extension()
+                .and_then(|s| s.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("ico"));
+            let object_fit = if clamp_preview_size {
+                gpui::ObjectFit::ScaleDown
+            } else {
+                gpui::ObjectFit::Contain
+            };
+            (gpui::img(path), object_fit)
+        }
+        Some(CachedDiffImageSource::Render(img_data)) => {
+            (gpui::img(img_data), gpui::ObjectFit::Contain)
+        }
+        None => {
+            return div()
+                .text_sm()
+                .text_color(muted)
+                .child("No image")
+                .into_any_element();
+        }
+    };
+    img.w_full()
+        .h_full()
+        .object_fit(object_fit)
+        .with_loading(move || {
+            div()
+                .text_sm()
+                .text_color(muted)
+                .child("Processing image...")
+                .into_any_element()
+        })
+        .with_fallback(move || {
+            div()
+                .text_sm()
+                .text_color(muted)
+                .child("Preview unavailable.")
+                .into_any_element()
+        })
+        .into_any_element()
+}
+
+/// One image positioned by the shared zoom and pan. Every side is laid out
+/// through this, so layers in the same viewport stay pixel-aligned.
+fn image_diff_layer(
+    image: Option<CachedDiffImageSource>,
+    zoom: f32,
+    pan: gpui::Point<Pixels>,
+    theme: AppTheme,
+) -> gpui::Div {
+    let inset = (1.0 - zoom) / 2.0;
+    div().absolute().left(pan.x).top(pan.y).size_full().child(
+        div()
+            .absolute()
+            .left(gpui::relative(inset))
+            .top(gpui::relative(inset))
+            .w(gpui::relative(zoom))
+            .h(gpui::relative(zoom))
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(image_diff_image(image, theme)),
+    )
+}
+
+impl MainPaneView {
+    fn image_diff_source(&self, old: bool) -> Option<CachedDiffImageSource> {
+        let (svg_path, render) = if old {
+            (
+                &self.file_image_diff_cache_old_svg_path,
+                &self.file_image_diff_cache_old,
+            )
+        } else {
+            (
+                &self.file_image_diff_cache_new_svg_path,
+                &self.file_image_diff_cache_new,
+            )
+        };
+        svg_path
+            .clone()
+            .map(CachedDiffImageSource::Path)
+            .or_else(|| render.clone().map(CachedDiffImageSource::Render))
+    }
+
+    pub(in crate::view) fn set_image_diff_mode(&mut self, mode: ImageDiffMode) {
+        self.image_diff_mode = mode;
+        self.image_diff_pan_drag_last = None;
+    }
+
+    pub(in crate::view) fn step_image_diff_zoom(&mut self, zoom_in: bool) {
+        let zoom = stepped_image_diff_zoom(self.image_diff_zoom, zoom_in);
+        if zoom != self.image_diff_zoom {
+            let scale = zoom / self.image_diff_zoom;
+            self.image_diff_pan =
+                gpui::point(self.image_diff_pan.x * scale, self.image_diff_pan.y * scale);
+            self.image_diff_zoom = zoom;
+        }
+    }
+
+    pub(in crate::view) fn reset_image_diff_zoom(&mut self) {
+        self.image_diff_zoom = 1.0;
+        self.image_diff_pan = gpui::Point::default();
+    }
+
+    fn image_diff_viewport(
+        &self,
+        id: &'static str,
+        theme: AppTheme,
+        cx: &mut gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
+        div()
+            .id(id)
+            .relative()
+            .flex_1()
+            .min_w(px(0.0))
+            .h_full()
+            .overflow_hidden()
+            .bg(theme.colors.window_bg)
+            .cursor(match self.image_diff_mode {
+                ImageDiffMode::Swipe | ImageDiffMode::OnionSkin => CursorStyle::ResizeLeftRight,
+                ImageDiffMode::SideBySide | ImageDiffMode::Difference => CursorStyle::OpenHand,
+            })
+            .on_scroll_wheel(cx.listener(|this, e: &gpui::ScrollWheelEvent, _w, cx| {
+                let delta = e.delta.pixel_delta(px(20.0));
+                if e.modifiers.secondary() {
+                    if delta.y != px(0.0) {
+                        this.step_image_diff_zoom(delta.y > px(0.0));
+                        cx.notify();
+                    }
+                    return;
+                }
+                this.image_diff_pan = this.image_diff_pan + delta;
+                cx.notify();
+            }))
+            .on_drag(ImageDiffDragHandle::Viewport, |_, _, _, cx| {
+                cx.new(|_| ImageDiffDragGhost)
+            })
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, e: &MouseDownEvent, _w, _cx| {
+                    this.image_diff_pan_drag_last = Some(e.position);
+                }),
+            )
+            .on_drag_move(cx.listener(
+                |this, e: &gpui::DragMoveEvent<ImageDiffDragHandle>, _w, cx| {
+                    let position = e.event.position;
+                    match this.image_diff_mode {
+                        ImageDiffMode::Swipe => {
+                            let Some(ratio) = image_diff_ratio_at(e.bounds, position.x) else {
+                                return;
+                            };
+                            this.image_diff_swipe_ratio = ratio;
+                        }
+                        ImageDiffMode::OnionSkin => {
+                            let Some(ratio) = image_diff_ratio_at(e.bounds, position.x) else {
+                                return;
+                            };
+                            this.image_diff_onion_opacity = ratio;
+                        }
+                        ImageDiffMode::SideBySide | ImageDiffMode::Difference => {
+                            let Some(last) = this.image_diff_pan_drag_last.replace(position) else {
+                                return;
+                            };
+                            this.image_diff_pan = this.image_diff_pan + (position - last);
+                        }
+                    }
+                    cx.notify();
+                },
+            ))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _e, _w, _cx| {
+                    this.image_diff_pan_drag_last = None;
+                }),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _e, _w, _cx| {
+                    this.image_diff_pan_drag_last = None;
+                }),
+            )
+    }
+
+    fn image_diff_side_label(&self, side: &'static str, old: bool) -> SharedString {
+        let metadata = if old {
+            &self.file_image_diff_cache_old_metadata
+        } else {
+            &self.file_image_diff_cache_new_metadata
+        };
+        match metadata {
+            Some(metadata) => format!("{side} · {}", metadata.label()).into(),
+            None => format!("{side} · absent").into(),
+        }
+    }
+
+    pub(super) fn render_file_image_diff(
+        &mut self,
+        theme: AppTheme,
+        ui_scale_percent: u32,
+        cx: &mut gpui::Context<Self>,
+    ) -> AnyElement {
+        let zoom = self.image_diff_zoom;
+        let pan = self.image_diff_pan;
+        let muted = theme.colors.text_muted;
+        let hint = |text: SharedString| {
+            div()
+                .absolute()
+                .bottom(px(6.0))
+                .left_0()
+                .right_0()
+                .flex()
+                .justify_center()
+                .child(
+                    div()
+                        .px_2()
+                        .rounded(px(theme.radii.row))
+                        .bg(with_alpha(theme.colors.surface_bg_elevated, 0.85))
+                        .text_xs()
+                        .text_color(muted)
+                        .child(text),
+                )
+        };
+
+        let body = match self.image_diff_mode {
+            ImageDiffMode::SideBySide => div()
+                .flex_1()
+                .min_h(px(0.0))
+                .flex()
+                .child(
+                    self.image_diff_viewport("diff_image_left", theme, cx)
+                        .child(image_diff_layer(
+                            self.image_diff_source(true),
+                            zoom,
+                            pan,
+                            theme,
+                        )),
+                )
+                .child(div().w(px(1.0)).h_full().bg(theme.colors.border))
+                .child(
+                    self.image_diff_viewport("diff_image_right", theme, cx)
+                        .child(image_diff_layer(
+                            self.image_diff_source(false),
+                            zoom,
+                            pan,
+                            theme,
+                        )),
+                ),
+            ImageDiffMode::Swipe => {
+                let ratio = self.image_diff_swipe_ratio;
+                div().flex_1().min_h(px(0.0)).flex().child(
+                    self.image_diff_viewport("diff_image_swipe", theme, cx)
+                        .child(image_diff_layer(
+                            self.image_diff_source(false),
+                            zoom,
+                            pan,
+                            theme,
+                        ))
+                        .child(
+                            div()
+                                .absolute()
+                                .left_0()
+                                .top_0()
+                                .bottom_0()
+                                .w(gpui::relative(ratio))
+                                .overflow_hidden()
+                                .child(
+                                    div()
+                                        .absolute()
+                                        .left_0()
+                                        .top_0()
+                                        .h_full()
+                                        .w(gpui::relative(1.0 / ratio))
+                                        .child(image_diff_layer(
+                                            self.image_diff_source(true),
+                                            zoom,
+                                            pan,
+                                            theme,
+                                        )),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .absolute()
+                                .top_0()
+                                .bottom_0()
+                                .left(gpui::relative(ratio))
+                                .w(px(2.0))
+                                .bg(theme.colors.accent),
+                        )
+                        .child(hint("A ◀ drag to swipe ▶ B".into())),
+                )
+            }
+            ImageDiffMode::OnionSkin => {
+                let opacity = self.image_diff_onion_opacity;
+                div().flex_1().min_h(px(0.0)).flex().child(
+                    self.image_diff_viewport("diff_image_onion_skin", theme, cx)
+                        .child(image_diff_layer(
+                            self.image_diff_source(true),
+                            zoom,
+                            pan,
+                            theme,
+                        ))
+                        .child(
+                            image_diff_layer(self.image_diff_source(false), zoom, pan, theme)
+                                .opacity(opacity),
+                        )
+                        .child(hint(
+                            format!(
+                                "B at {}% · drag horizontally to blend",
+                                (opacity * 100.0).round() as u32
+                            )
+                            .into(),
+                        )),
+                )
+            }
+            ImageDiffMode::Difference => {
+                let viewport = self.image_diff_viewport("diff_image_difference", theme, cx);
+                let viewport = match self.file_image_diff_cache_difference.clone() {
+                    Some((render, changed)) => {
+                        let size = render.size(0);
+                        let total = (size.width.0.max(0) as u64) * (size.height.0.max(0) as u64);
+                        let summary: SharedString = if changed == 0 {
+                            "No pixel differences".into()
+                        } else {
+                            format!("{changed} of {total} pixels differ").into()
+                        };
+                        viewport
+                            .child(image_diff_layer(
+                                Some(CachedDiffImageSource::Render(render)),
+                                zoom,
+                                pan,
+                                theme,
+                            ))
+                            .child(hint(summary))
+                    }
+                    None => {
+                        let message = if self.file_image_diff_cache_old_metadata.is_some()
+                            && self.file_image_diff_cache_new_metadata.is_some()
+                        {
+                            "Pixel difference is not available for this image format."
+                        } else {
+                            "Pixel difference needs both an old and a new image."
+                        };
+                        viewport
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .child(div().text_sm().text_color(muted).child(message))
+                    }
+                };
+                div().flex_1().min_h(px(0.0)).flex().child(viewport)
+            }
+        };
+
+        let columns_header = components::split_columns_header(
+            theme,
+            ui_scale_percent,
+            self.image_diff_side_label("A (before)", true),
+            self.image_diff_side_label("B (after)", false),
+        );
+
+        div()
+            .id("diff_image_container")
+            .relative()
+            .h_full()
+            .min_h(px(0.0))
+            .flex()
+            .flex_col()
+            .bg(theme.colors.window_bg)
+            .child(columns_header)
+            .child(body)
+            .into_any_element()
+    }
+
+    /// Comparison mode toggle and zoom controls for the diff toolbar.
+    pub(super) fn image_diff_toolbar_controls(
+        &mut self,
+        theme: AppTheme,
+        ui_scale_percent: u32,
+        [selected_bg, border, divider]: [gpui::Rgba; 3],
+        cx: &mut gpui::Context<Self>,
+    ) -> gpui::Div {
+        let mut mode_toggle = div()
+            .id("image_diff_mode_toggle")
+            .flex()
+            .items_center()
+            .h(components::control_height(ui_scale_percent))
+            .rounded(px(theme.radii.row))
+            .border_1()
+            .border_color(border)
+            .bg(gpui::rgba(0x00000000))
+            .overflow_hidden()
+            .p(px(1.0));
+        for (ix, mode) in ImageDiffMode::ALL.into_iter().enumerate() {
+            if ix > 0 {
+                mode_toggle = mode_toggle.child(div().h_full().w(px(1.0)).bg(divider));
+            }
+            mode_toggle = mode_toggle.child(
+                components::Button::new(mode.button_id(), mode.label())
+                    .borderless()
+                    .style(components::ButtonStyle::Subtle)
+                    .selected(self.image_diff_mode == mode)
+                    .selected_bg(selected_bg)
+                    .on_click(theme, cx, move |this, _e, window, cx| {
+                        this.set_image_diff_mode(mode);
+                        this.restore_diff_panel_focus_after_toolbar_action(window, cx);
+                        cx.notify();
+                    })
+                    .debug_selector(move || mode.button_id().to_string()),
+            );
+        }
+
+        let zoom_label: SharedString =
+            format!("{}%", (self.image_diff_zoom * 100.0).round() as u32).into();
+        div()
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(mode_toggle)
+            .child(
+                components::Button::new("image_diff_zoom_out", "")
+                    .start_slot(svg_icon("icons/minus.svg", theme.colors.text, px(14.0)))
+                    .style(components::ButtonStyle::Outlined)
+                    .disabled(self.image_diff_zoom <= IMAGE_DIFF_MIN_ZOOM)
+                    .on_click(theme, cx, |this, _e, _w, cx| {
+                        this.step_image_diff_zoom(false);
+                        cx.notify();
+                    })
+                    .gitcomet_tooltip(theme, "Zoom out (Ctrl+scroll)".into()),
+            )
+            .child(
+                components::Button::new("image_diff_zoom_reset", zoom_label)
+                    .style(components::ButtonStyle::Outlined)
+                    .on_click(theme, cx, |this, _e, _w, cx| {
+                        this.reset_image_diff_zoom();
+                        cx.notify();
+                    })
+                    .gitcomet_tooltip(theme, "Fit to view".into()),
+            )
+            .child(
+                components::Button::new("image_diff_zoom_in", "")
+                    .start_slot(svg_icon("icons/plus.svg", theme.colors.text, px(14.0)))
+                    .style(components::ButtonStyle::Outlined)
+                    .disabled(self.image_diff_zoom >= IMAGE_DIFF_MAX_ZOOM)
+                    .on_click(theme, cx, |this, _e, _w, cx| {
+                        this.step_image_diff_zoom(true);
+                        cx.notify();
+                    })
+                    .gitcomet_tooltip(theme, "Zoom in (Ctrl+scroll)".into()),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_diff_zoom_steps_are_clamped() {
+        assert_eq!(stepped_image_diff_zoom(1.0, true), 1.25);
+        assert_eq!(stepped_image_diff_zoom(1.25, false), 1.0);
+        assert_eq!(
+            stepped_image_diff_zoom(IMAGE_DIFF_MAX_ZOOM, true),
+            IMAGE_DIFF_MAX_ZOOM
+        );
+        assert_eq!(
+            stepped_image_diff_zoom(IMAGE_DIFF_MIN_ZOOM, false),
+            IMAGE_DIFF_MIN_ZOOM
+        );
+    }
+
+    #[test]
+    fn image_diff_ratio_tracks_pointer_within_viewport() {
+        let bounds = Bounds::new(
+            gpui::point(px(100.0), px(0.0)),
+            gpui::size(px(200.0), px(50.0)),
+        );
+        assert_eq!(image_diff_ratio_at(bounds, px(150.0)), Some(0.25));
+        assert_eq!(
+            image_diff_ratio_at(bounds, px(0.0)),
+            Some(IMAGE_DIFF_RATIO_MARGIN)
+        );
+        assert_eq!(
+            image_diff_ratio_at(bounds, px(400.0)),
+            Some(1.0 - IMAGE_DIFF_RATIO_MARGIN)
+        );
+        let empty = Bounds::new(gpui::point(px(0.0), px(0.0)), gpui::size(px(0.0), px(0.0)));
+        assert_eq!(image_diff_ratio_at(empty, px(10.0)), None);
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::view::notebook_preview::NotebookPreviewOptions;
+
+const NOTEBOOK_IMAGE_OUTPUT_HEIGHT_PX: f32 = 160.0;
+const NOTEBOOK_IMAGE_OUTPUT_WIDTH_PX: f32 = 220.0;
+
+fn notebook_image_output_side(
+    image: Option<Arc<gpui::Image>>,
+    border: gpui::Rgba,
+    theme: AppTheme,
+) -> AnyElement {
+    let muted = theme.colors.text_muted;
+    let body = match image {
+        Some(image) => gpui::img(image)
+            .w_full()
+            .h_full()
+            .object_fit(gpui::ObjectFit::Contain)
+            .with_fallback(move || {
+                div()
+                    .text_xs()
+                    .text_color(muted)
+                    .child("Preview unavailable.")
+                    .into_any_element()
+            })
+            .into_any_element(),
+        None => div()
+            .text_xs()
+            .text_color(muted)
+            .child("No output")
+            .into_any_element(),
+    };
+    div()
+        .w(px(NOTEBOOK_IMAGE_OUTPUT_WIDTH_PX))
+        .h(px(NOTEBOOK_IMAGE_OUTPUT_HEIGHT_PX))
+        .flex()
+        .items_center()
+        .justify_center()
+        .border_1()
+        .border_color(border)
+        .rounded(px(theme.radii.row))
+        .bg(theme.colors.window_bg)
+        .child(body)
+        .into_any_element()
+}
+
+impl MainPaneView {
+    /// Old/new pairs of the notebook image outputs that changed, if any.
+    pub(super) fn render_notebook_image_outputs(&self, theme: AppTheme) -> Option<AnyElement> {
+        if self.file_notebook_image_outputs.is_empty() {
+            return None;
+        }
+        let outputs = self.file_notebook_image_outputs.iter().map(|output| {
+            div()
+                .flex()
+                .flex_col()
+                .flex_none()
+                .gap_1()
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .child(output.label.clone()),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .gap_1()
+                        .child(notebook_image_output_side(
+                            output.old.clone(),
+                            theme.colors.diff_remove_text,
+                            theme,
+                        ))
+                        .child(notebook_image_output_side(
+                            output.new.clone(),
+                            theme.colors.diff_add_text,
+                            theme,
+                        )),
+                )
+        });
+
+        Some(
+            div()
+                .id("diff_notebook_image_outputs")
+                .flex_none()
+                .flex()
+                .gap_3()
+                .p_2()
+                .overflow_x_scroll()
+                .bg(theme.colors.surface_bg_elevated)
+                .border_t_1()
+                .border_color(theme.colors.border)
+                .children(outputs)
+                .into_any_element(),
+        )
+    }
+
+    pub(super) fn notebook_toolbar_controls(
+        &self,
+        theme: AppTheme,
+        ui_scale_percent: u32,
+        [selected_bg, border, divider]: [gpui::Rgba; 3],
+        cx: &mut gpui::Context<Self>,
+    ) -> AnyElement {
+        let options = self.notebook_preview_options;
+        let outputs_btn = components::Button::new("notebook_diff_outputs", "Outputs")
+            .borderless()
+            .style(components::ButtonStyle::Subtle)
+            .selected(options.show_outputs)
+            .selected_bg(selected_bg)
+            .on_click(theme, cx, move |this, _e, window, cx| {
+                this.set_notebook_preview_options(
+                    NotebookPreviewOptions {
+                        show_outputs: !options.show_outputs,
+                        ..options
+                    },
+                    cx,
+                );
+                this.restore_diff_panel_focus_after_toolbar_action(window, cx);
+            })
+            .gitcomet_tooltip(
+                theme,
+                "Show cell text outputs instead of collapsing them".into(),
+            );
+        let counts_btn = components::Button::new("notebook_diff_execution_counts", "In [n]")
+            .borderless()
+            .style(components::ButtonStyle::Subtle)
+            .selected(options.show_execution_counts)
+            .selected_bg(selected_bg)
+            .on_click(theme, cx, move |this, _e, window, cx| {
+                this.set_notebook_preview_options(
+                    NotebookPreviewOptions {
+                        show_execution_counts: !options.show_execution_counts,
+                        ..options
+                    },
+                    cx,
+                );
+                this.restore_diff_panel_focus_after_toolbar_action(window, cx);
+            })
+            .gitcomet_tooltip(theme, "Compare execution counts".into());
+
+        div()
+            .id("notebook_diff_options")
+            .flex()
+            .items_center()
+            .h(components::control_height(ui_scale_percent))
+            .rounded(px(theme.radii.row))
+            .border_1()
+            .border_color(border)
+            .overflow_hidden()
+            .p(px(1.0))
+            .child(outputs_btn)
+            .child(div().h_full().w(px(1.0)).bg(divider))
+            .child(counts_btn)
+            .into_any_element()
+    }
+}
@@ -41,7 +41,9 @@ fn status_navigation_section_for_target(
     match area {
         DiffArea::Staged => Some(StatusSection::Staged),
         DiffArea::Unstaged => match change_tracking_view {
-            ChangeTrackingView::Combined => Some(StatusSection::CombinedUnstaged),
+            ChangeTrackingView::Combined | ChangeTrackingView::Tree => {
+                Some(StatusSection::CombinedUnstaged)
+            }
             ChangeTrackingView::SplitUntracked => status
                 .unstaged
                 .iter()
@@ -84,7 +86,7 @@ pub(super) fn status_navigation_context<'a>(
     diff_target: &DiffTarget,
     change_tracking_view: ChangeTrackingView,
 ) -> Option<StatusNavigationContext<'a>> {
-    let DiffTarget::WorkingTree { path, area } = diff_target else {
+    let DiffTarget::WorkingTree { path, area, .. } = diff_target else {
         return None;
     };
     let section =
@@ -103,13 +105,15 @@ pub(super) fn status_navigation_context_for_repo<'a>(
     diff_target: &DiffTarget,
     change_tracking_view: ChangeTrackingView,
 ) -> Option<StatusNavigationContext<'a>> {
-    let DiffTarget::WorkingTree { path, area } = diff_target else {
+    let DiffTarget::WorkingTree { path, area, .. } = diff_target else {
         return None;
     };
     let section = match area {
         DiffArea::Staged => StatusSection::Staged,
         DiffArea::Unstaged => match change_tracking_view {
-            ChangeTrackingView::Combined => StatusSection::CombinedUnstaged,
+            ChangeTrackingView::Combined | ChangeTrackingView::Tree => {
+                StatusSection::CombinedUnstaged
+            }
             ChangeTrackingView::SplitUntracked => {
                 let entry = repo.status_entry_for_path(DiffArea::Unstaged, path.as_path())?;
                 if entry.kind == gitcomet_core::domain::FileStatusKind::Untracked {
@@ -138,12 +142,14 @@ pub(super) enum AdjacentDiffFileTarget {
         area: DiffArea,
         target_ix: usize,
         path: std::path::PathBuf,
+        old_path: Option<std::path::PathBuf>,
         is_conflicted: bool,
     },
     Commit {
         commit_id: CommitId,
         target_ix: usize,
         path: std::path::PathBuf,
+        old_path: Option<std::path::PathBuf>,
     },
 }
 
@@ -164,6 +170,7 @@ pub(super) fn adjacent_diff_file_target_for_repo(
             let target_ix = navigation.adjacent_ix(direction)?;
             let entry = navigation.entries.get(target_ix)?;
             let path = entry.path.clone();
+            let old_path = entry.old_path.clone();
             let area = navigation.section.diff_area();
             let is_conflicted = area == DiffArea::Unstaged
                 && entry.kind == gitcomet_core::domain::FileStatusKind::Conflicted;
@@ -173,12 +180,14 @@ pub(super) fn adjacent_diff_file_target_for_repo(
                 area,
                 target_ix,
                 path,
+                old_path,
                 is_conflicted,
             })
         }
         DiffTarget::Commit {
             commit_id,
             path: Some(path),
+            ..
         } => {
             let Loadable::Ready(details) = &repo.history_state.commit_details else {
                 return None;
@@ -187,18 +196,23 @@ pub(super) fn adjacent_diff_file_target_for_repo(
                 return None;
             }
 
-            let current_ix = details.files.iter().position(|file| file.path == *path)?;
-            let target_ix = if direction < 0 {
-                current_ix.checked_sub(1)?
+            let order = crate::view::rows::CommitFileListRows::navigation_order(&details.files);
+            let current = order
+                .iter()
+                .position(|&ix| details.files[ix].path == *path)?;
+            let target = if direction < 0 {
+                current.checked_sub(1)?
             } else {
-                (current_ix + 1 < details.files.len()).then_some(current_ix + 1)?
+                (current + 1 < order.len()).then_some(current + 1)?
             };
-            let path = details.files.get(target_ix)?.path.clone();
+            let target_ix = order[target];
+            let file = details.files.get(target_ix)?;
 
             Some(AdjacentDiffFileTarget::Commit {
                 commit_id: commit_id.clone(),
                 target_ix,
-                path,
+                path: file.path.clone(),
+                old_path: file.old_path.clone(),
             })
         }
         DiffTarget::Commit { path: None, .. } => None,
@@ -254,6 +268,7 @@ impl MainPaneView {
                 area,
                 target_ix,
                 path,
+                old_path,
                 is_conflicted,
             } => {
                 self.clear_status_multi_selection(repo_id, cx);
@@ -263,7 +278,11 @@ impl MainPaneView {
                 } else {
                     self.store.dispatch(Msg::SelectDiff {
                         repo_id,
-                        target: DiffTarget::WorkingTree { path, area },
+                        target: DiffTarget::WorkingTree {
+                            path,
+                            area,
+                            old_path,
+                        },
                     });
                 }
                 self.scroll_status_section_to_ix(section, target_ix, cx);
@@ -272,12 +291,14 @@ impl MainPaneView {
                 commit_id,
                 target_ix,
                 path,
+                old_path,
             } => {
                 self.store.dispatch(Msg::SelectDiff {
                     repo_id,
                     target: DiffTarget::Commit {
                         commit_id,
                         path: Some(path),
+                        old_path,
                     },
                 });
                 self.scroll_commit_details_file_to_ix(target_ix, cx);
@@ -328,6 +349,8 @@ mod tests {
             path: pb(path),
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -353,6 +376,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: pb("new-a.txt"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let navigation =
@@ -394,6 +418,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: pb("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let navigation =
@@ -436,6 +461,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: pb("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let navigation = status_navigation_context(&status, &target, ChangeTrackingView::Combined)
@@ -466,16 +492,25 @@ mod tests {
                         path: file_a.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     },
                     gitcomet_core::domain::CommitFileChange {
                         path: file_b.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     },
                     gitcomet_core::domain::CommitFileChange {
                         path: file_c.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     },
                 ],
             }));
@@ -483,6 +518,7 @@ mod tests {
         let target = DiffTarget::Commit {
             commit_id: commit_id.clone(),
             path: Some(file_b.clone()),
+            old_path: None,
         };
 
         assert_eq!(
@@ -491,6 +527,7 @@ mod tests {
                 commit_id: commit_id.clone(),
                 target_ix: 0,
                 path: file_a,
+                old_path: None,
             })
         );
         assert_eq!(
@@ -499,6 +536,7 @@ mod tests {
                 commit_id,
                 target_ix: 2,
                 path: file_c,
+                old_path: None,
             })
         );
     }
@@ -44,6 +44,36 @@ pub(in crate::view) enum ContextMenuAction {
         repo_id: RepoId,
         commit_id: CommitId,
     },
+    ExportPatchSeries {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+        cover_letter: bool,
+    },
+    CherryPickCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    RevertCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    SquashCommits {
+        repo_id: RepoId,
+        commit_ids: Vec<CommitId>,
+    },
+    CreateFixupCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        kind: FixupCommitKind,
+    },
+    RewordCommit {
+        repo_id: RepoId,
+        commit_id: CommitId,
+        message: String,
+    },
+    AutosquashRebase {
+        repo_id: RepoId,
+    },
     CheckoutBranch {
         repo_id: RepoId,
         name: String,
@@ -62,6 +92,23 @@ pub(in crate::view) enum ContextMenuAction {
     SetDiffWhitespaceMode {
         mode: DiffWhitespaceMode,
     },
+    SetDiffAlignmentMode {
+        mode: DiffAlignmentMode,
+    },
+    SetDiffHighlightMovedLines {
+        enabled: bool,
+    },
+    SetDiffMovedLinesIgnoreWhitespace {
+        enabled: bool,
+    },
+    JumpToMovedCounterpart,
+    SetDiffIgnoreOptions {
+        repo_id: RepoId,
+        options: gitcomet_core::services::DiffIgnoreOptions,
+    },
+    SetRenameDetection {
+        detection: gitcomet_core::services::RenameDetection,
+    },
     SetChangeTrackingView {
         view: ChangeTrackingView,
     },
@@ -118,9 +165,27 @@ pub(in crate::view) enum ContextMenuAction {
         repo_id: RepoId,
         path: std::path::PathBuf,
     },
+    SyncSubmodules {
+        repo_id: RepoId,
+    },
+    UpdateSubmoduleRemote {
+        repo_id: RepoId,
+        path: std::path::PathBuf,
+    },
+    RunInSubmodules {
+        repo_id: RepoId,
+        command: gitcomet_core::services::SubmoduleBatchCommand,
+    },
     LoadWorktrees {
         repo_id: RepoId,
     },
+    UnlockWorktree {
+        repo_id: RepoId,
+        path: std::path::PathBuf,
+    },
+    PruneWorktrees {
+        repo_id: RepoId,
+    },
     Pull {
         repo_id: RepoId,
         mode: PullMode,
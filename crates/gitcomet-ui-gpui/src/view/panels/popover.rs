@@ -1,30 +1,37 @@
 use super::*;
 
 mod app_menu;
+mod blame;
 mod branch_picker;
 mod checkout_remote_branch_prompt;
 mod clone_repo;
+mod conflict_dashboard;
 mod conflict_save_stage_confirm;
 pub(in super::super) mod context_menu;
 mod create_branch;
 mod create_branch_from_ref_prompt;
 mod create_tag_prompt;
 mod delete_remote_branch_confirm;
+mod diff_ignore_patterns_prompt;
 mod discard_changes_confirm;
 mod file_history;
 mod fingerprint;
 mod force_delete_branch_confirm;
 mod force_push_confirm;
 mod force_remove_worktree_confirm;
+mod line_history;
 mod merge_abort_confirm;
+mod patch_series_import;
 mod pull_reconcile_prompt;
 mod push_set_upstream_prompt;
 mod recent_repo_picker;
 mod remote_add_prompt;
 mod remote_edit_url_prompt;
 mod remote_remove_confirm;
+mod rename_branch_prompt;
 mod repo_picker;
 mod reset_prompt;
+mod revision_tree;
 mod search_inputs;
 mod stash_drop_confirm;
 mod stash_prompt;
@@ -33,8 +40,12 @@ mod submodule_change_pointer_prompt;
 mod submodule_open_picker;
 mod submodule_remove_confirm;
 mod submodule_remove_picker;
+mod submodule_run_command_prompt;
+mod submodule_track_branch_prompt;
 mod submodule_trust_confirm;
 mod worktree_add_prompt;
+mod worktree_lock_prompt;
+mod worktree_move_prompt;
 mod worktree_open_picker;
 mod worktree_remove_confirm;
 mod worktree_remove_picker;
@@ -102,6 +113,8 @@ const DIALOG_440_WIDTH: PopoverWidthSpec = PopoverWidthSpec::fixed(440.0);
 const DIALOG_460_WIDTH: PopoverWidthSpec = PopoverWidthSpec::fixed(460.0);
 const DIALOG_540_WIDTH: PopoverWidthSpec = PopoverWidthSpec::fixed(540.0);
 const DIALOG_640_WIDTH: PopoverWidthSpec = PopoverWidthSpec::fixed(640.0);
+const BLAME_WIDTH: PopoverWidthSpec = PopoverWidthSpec::range(820.0, 720.0, 980.0);
+const LINE_HISTORY_WIDTH: PopoverWidthSpec = PopoverWidthSpec::range(640.0, 640.0, 920.0);
 const APP_MENU_WIDTH: PopoverWidthSpec = PopoverWidthSpec::fixed(200.0);
 
 pub(in super::super) struct PopoverHost {
@@ -121,11 +134,14 @@ pub(in super::super) struct PopoverHost {
     _clone_repo_url_input_subscription: gpui::Subscription,
     _clone_repo_parent_dir_input_subscription: gpui::Subscription,
     _create_tag_input_subscription: gpui::Subscription,
+    _diff_ignore_patterns_input_subscription: gpui::Subscription,
     _repo_picker_search_input_subscription: Option<gpui::Subscription>,
     _branch_picker_search_input_subscription: Option<gpui::Subscription>,
     _create_branch_input_subscription: gpui::Subscription,
+    _rename_branch_input_subscription: gpui::Subscription,
     _stash_message_input_subscription: gpui::Subscription,
     _submodule_ref_input_subscription: gpui::Subscription,
+    _worktree_lock_reason_input_subscription: gpui::Subscription,
     notify_fingerprint: u64,
     root_view: WeakEntity<GitCometView>,
     tooltip_host: WeakEntity<TooltipHost>,
@@ -147,11 +163,16 @@ pub(in super::super) struct PopoverHost {
     worktree_picker_search_input: Option<Entity<components::TextInput>>,
     submodule_picker_search_input: Option<Entity<components::TextInput>>,
     picker_prompt_scroll: ScrollHandle,
+    blame_scroll: UniformListScrollHandle,
+    revision_tree_scroll: UniformListScrollHandle,
+    blame_restore_scroll_offset: Option<Point<Pixels>>,
+    conflict_dashboard_selection: std::collections::BTreeSet<std::path::PathBuf>,
 
     clone_repo_url_input: Entity<components::TextInput>,
     clone_repo_parent_dir_input: Entity<components::TextInput>,
     rebase_onto_input: Entity<components::TextInput>,
     create_tag_input: Entity<components::TextInput>,
+    diff_ignore_patterns_input: Entity<components::TextInput>,
     remote_name_input: Entity<components::TextInput>,
     remote_url_input: Entity<components::TextInput>,
     remote_url_edit_input: Entity<components::TextInput>,
@@ -162,6 +183,11 @@ pub(in super::super) struct PopoverHost {
     create_branch_from_ref_checkout_focus_handle: FocusHandle,
     create_branch_from_ref_cancel_focus_handle: FocusHandle,
     create_branch_from_ref_submit_focus_handle: FocusHandle,
+    rename_branch_input: Entity<components::TextInput>,
+    rename_branch_remote_enabled: bool,
+    rename_branch_remote_focus_handle: FocusHandle,
+    rename_branch_cancel_focus_handle: FocusHandle,
+    rename_branch_submit_focus_handle: FocusHandle,
     checkout_remote_branch_cancel_focus_handle: FocusHandle,
     checkout_remote_branch_submit_focus_handle: FocusHandle,
     stash_message_input: Entity<components::TextInput>,
@@ -188,6 +214,7 @@ pub(in super::super) struct PopoverHost {
     push_upstream_branch_input: Entity<components::TextInput>,
     worktree_path_input: Entity<components::TextInput>,
     worktree_ref_input: Entity<components::TextInput>,
+    worktree_lock_reason_input: Entity<components::TextInput>,
     submodule_url_input: Entity<components::TextInput>,
     submodule_path_input: Entity<components::TextInput>,
     submodule_ref_input: Entity<components::TextInput>,
@@ -195,6 +222,9 @@ pub(in super::super) struct PopoverHost {
     submodule_name_input: Entity<components::TextInput>,
     submodule_add_advanced_expanded: bool,
     submodule_force_enabled: bool,
+    patch_series_selected_ix: usize,
+    patch_series_three_way_enabled: bool,
+    patch_series_three_way_focus_handle: FocusHandle,
 }
 
 pub(in super::super) fn popover_ui_scale(cx: &mut gpui::Context<PopoverHost>) -> ui_scale::UiScale {
@@ -272,6 +302,7 @@ fn popover_is_context_menu(kind: &PopoverKind) -> bool {
             | PopoverKind::ConflictResolverChunkMenu { .. }
             | PopoverKind::ConflictResolverOutputMenu { .. }
             | PopoverKind::CommitMenu { .. }
+            | PopoverKind::CommitSelectionMenu { .. }
             | PopoverKind::TagMenu { .. }
             | PopoverKind::StatusFileMenu { .. }
             | PopoverKind::BranchMenu { .. }
@@ -304,11 +335,13 @@ fn popover_anchor_corner(kind: &PopoverKind) -> Corner {
         | PopoverKind::PushPicker
         | PopoverKind::CreateBranch
         | PopoverKind::CreateBranchFromRefPrompt { .. }
+        | PopoverKind::RenameBranchPrompt { .. }
         | PopoverKind::StashPrompt
         | PopoverKind::StashDropConfirm { .. }
         | PopoverKind::CloneRepo
         | PopoverKind::ResetPrompt { .. }
         | PopoverKind::CreateTagPrompt { .. }
+        | PopoverKind::DiffIgnorePatternsPrompt { .. }
         | PopoverKind::Repo {
             kind:
                 RepoPopoverKind::Remote(
@@ -324,7 +357,9 @@ fn popover_anchor_corner(kind: &PopoverKind) -> Corner {
                     WorktreePopoverKind::AddPrompt
                     | WorktreePopoverKind::OpenPicker
                     | WorktreePopoverKind::RemovePicker
-                    | WorktreePopoverKind::RemoveConfirm { .. },
+                    | WorktreePopoverKind::RemoveConfirm { .. }
+                    | WorktreePopoverKind::LockPrompt { .. }
+                    | WorktreePopoverKind::MovePrompt { .. },
                 ),
             ..
         }
@@ -333,6 +368,8 @@ fn popover_anchor_corner(kind: &PopoverKind) -> Corner {
                 RepoPopoverKind::Submodule(
                     SubmodulePopoverKind::AddPrompt
                     | SubmodulePopoverKind::ChangePointerPrompt { .. }
+                    | SubmodulePopoverKind::TrackBranchPrompt { .. }
+                    | SubmodulePopoverKind::RunCommandPrompt
                     | SubmodulePopoverKind::TrustConfirm
                     | SubmodulePopoverKind::OpenPicker
                     | SubmodulePopoverKind::RemovePicker
@@ -343,6 +380,7 @@ fn popover_anchor_corner(kind: &PopoverKind) -> Corner {
         | PopoverKind::PushSetUpstreamPrompt { .. }
         | PopoverKind::ForcePushConfirm { .. }
         | PopoverKind::MergeAbortConfirm { .. }
+        | PopoverKind::ConflictDashboard { .. }
         | PopoverKind::ConflictSaveStageConfirm { .. }
         | PopoverKind::ForceDeleteBranchConfirm { .. }
         | PopoverKind::ForceRemoveWorktreeConfirm { .. }
@@ -365,8 +403,10 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
         PopoverKind::CreateBranch
         | PopoverKind::StashPrompt
         | PopoverKind::CloneRepo
-        | PopoverKind::CreateTagPrompt { .. } => Some(DIALOG_420_WIDTH),
+        | PopoverKind::CreateTagPrompt { .. }
+        | PopoverKind::DiffIgnorePatternsPrompt { .. } => Some(DIALOG_420_WIDTH),
         PopoverKind::CreateBranchFromRefPrompt { .. }
+        | PopoverKind::RenameBranchPrompt { .. }
         | PopoverKind::CheckoutRemoteBranchPrompt { .. } => Some(DIALOG_540_WIDTH),
         PopoverKind::StashDropConfirm { .. }
         | PopoverKind::Repo {
@@ -394,6 +434,9 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
             Some(DIALOG_360_WIDTH)
         }
         PopoverKind::ForceRemoveWorktreeConfirm { .. } => Some(DIALOG_460_WIDTH),
+        PopoverKind::ConflictDashboard { .. } | PopoverKind::PatchSeriesImport { .. } => {
+            Some(DIALOG_640_WIDTH)
+        }
         PopoverKind::PullReconcilePrompt { .. } => Some(DIALOG_440_WIDTH),
         PopoverKind::Repo {
             kind:
@@ -403,7 +446,10 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
             ..
         }
         | PopoverKind::Repo {
-            kind: RepoPopoverKind::Worktree(WorktreePopoverKind::AddPrompt),
+            kind:
+                RepoPopoverKind::Worktree(
+                    WorktreePopoverKind::AddPrompt | WorktreePopoverKind::MovePrompt { .. },
+                ),
             ..
         }
         | PopoverKind::Repo {
@@ -414,7 +460,16 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
             ..
         } => Some(DIALOG_640_WIDTH),
         PopoverKind::Repo {
-            kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::ChangePointerPrompt { .. }),
+            kind:
+                RepoPopoverKind::Submodule(
+                    SubmodulePopoverKind::ChangePointerPrompt { .. }
+                    | SubmodulePopoverKind::TrackBranchPrompt { .. }
+                    | SubmodulePopoverKind::RunCommandPrompt,
+                ),
+            ..
+        }
+        | PopoverKind::Repo {
+            kind: RepoPopoverKind::Worktree(WorktreePopoverKind::LockPrompt { .. }),
             ..
         } => Some(DIALOG_420_WIDTH),
         PopoverKind::Repo {
@@ -432,6 +487,8 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
             ..
         }
         | PopoverKind::FileHistory { .. } => Some(LARGE_PICKER_WIDTH),
+        PopoverKind::Blame { .. } | PopoverKind::RevisionTree { .. } => Some(BLAME_WIDTH),
+        PopoverKind::LineHistory { .. } => Some(LINE_HISTORY_WIDTH),
         PopoverKind::AppMenu => Some(APP_MENU_WIDTH),
         PopoverKind::DiffActionMenu => Some(DIFF_ACTION_MENU_WIDTH),
         PopoverKind::PullPicker
@@ -439,6 +496,7 @@ pub(in super::super) fn popover_width_spec(kind: &PopoverKind) -> Option<Popover
         | PopoverKind::CommitOptionsMenu { .. }
         | PopoverKind::PreviousCommitMessagesMenu { .. }
         | PopoverKind::CommitMenu { .. }
+        | PopoverKind::CommitSelectionMenu { .. }
         | PopoverKind::TagMenu { .. }
         | PopoverKind::StatusFileMenu { .. }
         | PopoverKind::BranchMenu { .. }
@@ -660,6 +718,34 @@ impl PopoverHost {
             )
         });
 
+        let rename_branch_input = cx.new(|cx| {
+            components::TextInput::new(
+                components::TextInputOptions {
+                    placeholder: "feature/new-name".into(),
+                    multiline: false,
+                    read_only: false,
+                    chromeless: false,
+                    soft_wrap: false,
+                },
+                window,
+                cx,
+            )
+        });
+
+        let diff_ignore_patterns_input = cx.new(|cx| {
+            components::TextInput::new(
+                components::TextInputOptions {
+                    placeholder: "^// Generated at .*$".into(),
+                    multiline: true,
+                    read_only: false,
+                    chromeless: false,
+                    soft_wrap: false,
+                },
+                window,
+                cx,
+            )
+        });
+
         let remote_name_input = cx.new(|cx| {
             components::TextInput::new(
                 components::TextInputOptions {
@@ -746,6 +832,33 @@ impl PopoverHost {
             cx.notify();
         });
 
+        let rename_branch_input_subscription =
+            cx.observe(&rename_branch_input, |this, input, cx| {
+                let enter_pressed = input.update(cx, |input, _| input.take_enter_pressed());
+                let _ = input.update(cx, |input, _| input.take_escape_pressed());
+
+                if !matches!(this.popover, Some(PopoverKind::RenameBranchPrompt { .. })) {
+                    return;
+                }
+
+                if enter_pressed {
+                    this.submit_rename_branch(cx);
+                    return;
+                }
+
+                cx.notify();
+            });
+
+        let diff_ignore_patterns_input_subscription =
+            cx.observe(&diff_ignore_patterns_input, |this, _input, cx| {
+                if matches!(
+                    this.popover,
+                    Some(PopoverKind::DiffIgnorePatternsPrompt { .. })
+                ) {
+                    cx.notify();
+                }
+            });
+
         let create_branch_input_subscription =
             cx.observe_in(&create_branch_input, window, |this, input, window, cx| {
                 let enter_pressed = input.update(cx, |input, _| input.take_enter_pressed());
@@ -827,6 +940,51 @@ impl PopoverHost {
             )
         });
 
+        let worktree_lock_reason_input = cx.new(|cx| {
+            components::TextInput::new(
+                components::TextInputOptions {
+                    placeholder: "Reason (optional)".into(),
+                    multiline: false,
+                    read_only: false,
+                    chromeless: false,
+                    soft_wrap: false,
+                },
+                window,
+                cx,
+            )
+        });
+
+        let worktree_lock_reason_input_subscription = cx.observe_in(
+            &worktree_lock_reason_input,
+            window,
+            move |this, input, window, cx| {
+                let enter_pressed = input.update(cx, |input, _| input.take_enter_pressed());
+                let escape_pressed = input.update(cx, |input, _| input.take_escape_pressed());
+
+                if !matches!(
+                    this.popover,
+                    Some(PopoverKind::Repo {
+                        kind: RepoPopoverKind::Worktree(WorktreePopoverKind::LockPrompt { .. }),
+                        ..
+                    })
+                ) {
+                    return;
+                }
+
+                if escape_pressed {
+                    this.dismiss_prompt_popover(window, cx);
+                    return;
+                }
+
+                if enter_pressed {
+                    this.submit_worktree_lock(cx);
+                    return;
+                }
+
+                cx.notify();
+            },
+        );
+
         let submodule_url_input = cx.new(|cx| {
             components::TextInput::new(
                 components::TextInputOptions {
@@ -904,14 +1062,18 @@ impl PopoverHost {
                 let enter_pressed = input.update(cx, |input, _| input.take_enter_pressed());
                 let escape_pressed = input.update(cx, |input, _| input.take_escape_pressed());
 
+                let Some(PopoverKind::Repo {
+                    kind: RepoPopoverKind::Submodule(submodule_kind),
+                    ..
+                }) = &this.popover
+                else {
+                    return;
+                };
                 if !matches!(
-                    this.popover,
-                    Some(PopoverKind::Repo {
-                        kind: RepoPopoverKind::Submodule(
-                            SubmodulePopoverKind::ChangePointerPrompt { .. }
-                        ),
-                        ..
-                    })
+                    submodule_kind,
+                    SubmodulePopoverKind::ChangePointerPrompt { .. }
+                        | SubmodulePopoverKind::TrackBranchPrompt { .. }
+                        | SubmodulePopoverKind::RunCommandPrompt
                 ) {
                     return;
                 }
@@ -922,7 +1084,15 @@ impl PopoverHost {
                 }
 
                 if enter_pressed {
-                    this.submit_submodule_change_pointer(window, cx);
+                    match submodule_kind {
+                        SubmodulePopoverKind::TrackBranchPrompt { .. } => {
+                            this.submit_submodule_track_branch(window, cx);
+                        }
+                        SubmodulePopoverKind::RunCommandPrompt => {
+                            this.submit_submodule_run_command(window, cx);
+                        }
+                        _ => this.submit_submodule_change_pointer(window, cx),
+                    }
                     return;
                 }
 
@@ -941,6 +1111,10 @@ impl PopoverHost {
             cx.focus_handle().tab_index(0).tab_stop(true);
         let create_branch_from_ref_submit_focus_handle =
             cx.focus_handle().tab_index(0).tab_stop(true);
+        let rename_branch_remote_focus_handle = cx.focus_handle().tab_index(0).tab_stop(true);
+        let patch_series_three_way_focus_handle = cx.focus_handle().tab_index(0).tab_stop(true);
+        let rename_branch_cancel_focus_handle = cx.focus_handle().tab_index(0).tab_stop(true);
+        let rename_branch_submit_focus_handle = cx.focus_handle().tab_index(0).tab_stop(true);
         let checkout_remote_branch_cancel_focus_handle =
             cx.focus_handle().tab_index(0).tab_stop(true);
         let checkout_remote_branch_submit_focus_handle =
@@ -983,11 +1157,14 @@ impl PopoverHost {
             _clone_repo_url_input_subscription: clone_repo_url_input_subscription,
             _clone_repo_parent_dir_input_subscription: clone_repo_parent_dir_input_subscription,
             _create_tag_input_subscription: create_tag_input_subscription,
+            _diff_ignore_patterns_input_subscription: diff_ignore_patterns_input_subscription,
             _repo_picker_search_input_subscription: None,
             _branch_picker_search_input_subscription: None,
             _create_branch_input_subscription: create_branch_input_subscription,
+            _rename_branch_input_subscription: rename_branch_input_subscription,
             _stash_message_input_subscription: stash_message_input_subscription,
             _submodule_ref_input_subscription: submodule_ref_input_subscription,
+            _worktree_lock_reason_input_subscription: worktree_lock_reason_input_subscription,
             notify_fingerprint: 0,
             root_view,
             tooltip_host,
@@ -1007,10 +1184,15 @@ impl PopoverHost {
             worktree_picker_search_input: None,
             submodule_picker_search_input: None,
             picker_prompt_scroll: ScrollHandle::new(),
+            blame_scroll: UniformListScrollHandle::default(),
+            revision_tree_scroll: UniformListScrollHandle::default(),
+            blame_restore_scroll_offset: None,
+            conflict_dashboard_selection: std::collections::BTreeSet::new(),
             clone_repo_url_input,
             clone_repo_parent_dir_input,
             rebase_onto_input,
             create_tag_input,
+            diff_ignore_patterns_input,
             remote_name_input,
             remote_url_input,
             remote_url_edit_input,
@@ -1021,6 +1203,11 @@ impl PopoverHost {
             create_branch_from_ref_checkout_focus_handle,
             create_branch_from_ref_cancel_focus_handle,
             create_branch_from_ref_submit_focus_handle,
+            rename_branch_input,
+            rename_branch_remote_enabled: true,
+            rename_branch_remote_focus_handle,
+            rename_branch_cancel_focus_handle,
+            rename_branch_submit_focus_handle,
             checkout_remote_branch_cancel_focus_handle,
             checkout_remote_branch_submit_focus_handle,
             stash_message_input,
@@ -1047,6 +1234,7 @@ impl PopoverHost {
             push_upstream_branch_input,
             worktree_path_input,
             worktree_ref_input,
+            worktree_lock_reason_input,
             submodule_url_input,
             submodule_path_input,
             submodule_ref_input,
@@ -1054,6 +1242,9 @@ impl PopoverHost {
             submodule_name_input,
             submodule_add_advanced_expanded: false,
             submodule_force_enabled: false,
+            patch_series_selected_ix: 0,
+            patch_series_three_way_enabled: true,
+            patch_series_three_way_focus_handle,
         }
     }
 
@@ -1068,6 +1259,10 @@ impl PopoverHost {
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.create_tag_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
+        self.diff_ignore_patterns_input
+            .update(cx, |input, cx| input.set_theme(theme, cx));
+        self.rename_branch_input
+            .update(cx, |input, cx| input.set_theme(theme, cx));
         self.remote_name_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.remote_url_input
@@ -1084,6 +1279,8 @@ impl PopoverHost {
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.worktree_ref_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
+        self.worktree_lock_reason_input
+            .update(cx, |input, cx| input.set_theme(theme, cx));
         self.submodule_url_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.submodule_path_input
@@ -1172,6 +1369,7 @@ impl PopoverHost {
                 | Some(PopoverKind::StashPrompt)
                 | Some(PopoverKind::CloneRepo)
                 | Some(PopoverKind::CreateTagPrompt { .. })
+                | Some(PopoverKind::RenameBranchPrompt { .. })
                 | Some(PopoverKind::PushSetUpstreamPrompt { .. })
                 | Some(PopoverKind::Repo {
                     kind: RepoPopoverKind::Remote(RemotePopoverKind::AddPrompt),
@@ -1182,7 +1380,9 @@ impl PopoverHost {
                     ..
                 })
                 | Some(PopoverKind::Repo {
-                    kind: RepoPopoverKind::Worktree(WorktreePopoverKind::AddPrompt),
+                    kind: RepoPopoverKind::Worktree(
+                        WorktreePopoverKind::AddPrompt | WorktreePopoverKind::MovePrompt { .. }
+                    ),
                     ..
                 })
                 | Some(PopoverKind::Repo {
@@ -1258,6 +1458,9 @@ impl PopoverHost {
             | Some(PopoverKind::StashPrompt) => self.dismiss_inline_popover(window, cx),
             Some(PopoverKind::CloneRepo)
             | Some(PopoverKind::CreateTagPrompt { .. })
+            | Some(PopoverKind::DiffIgnorePatternsPrompt { .. })
+            | Some(PopoverKind::RenameBranchPrompt { .. })
+            | Some(PopoverKind::PatchSeriesImport { .. })
             | Some(PopoverKind::CheckoutRemoteBranchPrompt { .. })
             | Some(PopoverKind::PushSetUpstreamPrompt { .. })
             | Some(PopoverKind::Repo {
@@ -1269,7 +1472,12 @@ impl PopoverHost {
                 ..
             })
             | Some(PopoverKind::Repo {
-                kind: RepoPopoverKind::Worktree(WorktreePopoverKind::AddPrompt),
+                kind:
+                    RepoPopoverKind::Worktree(
+                        WorktreePopoverKind::AddPrompt
+                        | WorktreePopoverKind::LockPrompt { .. }
+                        | WorktreePopoverKind::MovePrompt { .. },
+                    ),
                 ..
             })
             | Some(PopoverKind::Repo {
@@ -1339,6 +1547,31 @@ impl PopoverHost {
             .read_with(cx, |input, _| !input.text().trim().is_empty())
     }
 
+    fn can_submit_submodule_track_branch(&self, cx: &mut gpui::Context<Self>) -> bool {
+        matches!(
+            self.popover,
+            Some(PopoverKind::Repo {
+                kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::TrackBranchPrompt { .. }),
+                ..
+            })
+        ) && self
+            .submodule_ref_input
+            .read_with(cx, |input, _| !input.text().trim().is_empty())
+    }
+
+    fn can_submit_submodule_run_command(&self, cx: &mut gpui::Context<Self>) -> bool {
+        matches!(
+            self.popover,
+            Some(PopoverKind::Repo {
+                kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::RunCommandPrompt),
+                ..
+            })
+        ) && self.submodule_ref_input.read_with(cx, |input, _| {
+            gitcomet_core::services::SubmoduleBatchCommand::from_command_line(input.text())
+                .is_some()
+        })
+    }
+
     fn submit_create_tag(&mut self, cx: &mut gpui::Context<Self>) {
         let Some(PopoverKind::CreateTagPrompt { repo_id, target }) = self.popover.clone() else {
             return;
@@ -1359,6 +1592,189 @@ impl PopoverHost {
         self.close_popover(cx);
     }
 
+    fn rename_branch_upstream(&self, repo_id: RepoId, branch: &str) -> Option<Upstream> {
+        let repo = self.state.repos.iter().find(|r| r.id == repo_id)?;
+        let Loadable::Ready(branches) = &repo.branches else {
+            return None;
+        };
+        branches
+            .iter()
+            .find(|b| b.name == branch)
+            .and_then(|b| b.upstream.clone())
+    }
+
+    /// Whether renaming `branch` to `new_name` replaces another local branch.
+    fn rename_branch_overwrites(&self, repo_id: RepoId, branch: &str, new_name: &str) -> bool {
+        if new_name.is_empty() || new_name == branch {
+            return false;
+        }
+        self.state
+            .repos
+            .iter()
+            .find(|r| r.id == repo_id)
+            .is_some_and(|repo| match &repo.branches {
+                Loadable::Ready(branches) => branches.iter().any(|b| b.name == new_name),
+                _ => false,
+            })
+    }
+
+    fn can_submit_rename_branch(&self, cx: &mut gpui::Context<Self>) -> bool {
+        let Some(PopoverKind::RenameBranchPrompt { branch, .. }) = &self.popover else {
+            return false;
+        };
+        self.rename_branch_input.read_with(cx, |input, _| {
+            let new_name = input.text().trim();
+            !new_name.is_empty() && new_name != branch
+        })
+    }
+
+    fn submit_rename_branch(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::RenameBranchPrompt { repo_id, branch }) = self.popover.clone() else {
+            return;
+        };
+        if !self.can_submit_rename_branch(cx) {
+            return;
+        }
+
+        let new_name = self
+            .rename_branch_input
+            .read_with(cx, |input, _| input.text().trim().to_string());
+        let force = self.rename_branch_overwrites(repo_id, &branch, &new_name);
+        let upstream = self
+            .rename_branch_upstream(repo_id, &branch)
+            .filter(|_| self.rename_branch_remote_enabled);
+        self.store.dispatch(Msg::RenameBranch {
+            repo_id,
+            name: branch,
+            new_name,
+            force,
+            upstream,
+        });
+        self.close_popover(cx);
+    }
+
+    fn patch_series_ready_count(&self) -> usize {
+        let Some(PopoverKind::PatchSeriesImport { repo_id }) = &self.popover else {
+            return 0;
+        };
+        self.state
+            .repos
+            .iter()
+            .find(|r| r.id == *repo_id)
+            .and_then(|r| r.patch_series_preview.as_ref())
+            .map(|preview| match &preview.patches {
+                Loadable::Ready(patches) => patches.len(),
+                _ => 0,
+            })
+            .unwrap_or(0)
+    }
+
+    fn prompt_for_patch_series_source(
+        &mut self,
+        repo_id: RepoId,
+        directory: bool,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let view = cx.weak_entity();
+        let rx = cx.prompt_for_paths(gpui::PathPromptOptions {
+            files: !directory,
+            directories: directory,
+            multiple: false,
+            prompt: Some(if directory {
+                "Select folder of patches".into()
+            } else {
+                "Select mailbox".into()
+            }),
+        });
+        window
+            .spawn(cx, async move |cx| {
+                let result = rx.await;
+                let paths = match result {
+                    Ok(Ok(Some(paths))) => paths,
+                    Ok(Ok(None)) => return,
+                    Ok(Err(_)) | Err(_) => return,
+                };
+                let Some(source) = paths.into_iter().next() else {
+                    return;
+                };
+                let _ = view.update(cx, |this, cx| {
+                    this.patch_series_selected_ix = 0;
+                    this.store.dispatch(Msg::LoadPatchSeriesPreview {
+                        repo_id,
+                        source: Some(source),
+                    });
+                    cx.notify();
+                });
+            })
+            .detach();
+    }
+
+    fn submit_patch_series_import(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::PatchSeriesImport { repo_id }) = self.popover.clone() else {
+            return;
+        };
+        if self.patch_series_ready_count() == 0 {
+            return;
+        }
+        let Some(source) = self
+            .state
+            .repos
+            .iter()
+            .find(|r| r.id == repo_id)
+            .and_then(|r| r.patch_series_preview.as_ref())
+            .map(|preview| preview.source.clone())
+        else {
+            return;
+        };
+
+        self.store.dispatch(Msg::ApplyPatchSeries {
+            repo_id,
+            source,
+            three_way: self.patch_series_three_way_enabled,
+        });
+        self.close_popover(cx);
+    }
+
+    fn diff_ignore_patterns_from_input(&self, cx: &mut gpui::Context<Self>) -> Vec<String> {
+        self.diff_ignore_patterns_input.read_with(cx, |input, _| {
+            input
+                .text()
+                .lines()
+                .map(str::trim)
+                .filter(|pattern| !pattern.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+    }
+
+    fn submit_diff_ignore_patterns(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::DiffIgnorePatternsPrompt { repo_id }) = self.popover.clone() else {
+            return;
+        };
+
+        let mut options = self
+            .state
+            .repos
+            .iter()
+            .find(|r| r.id == repo_id)
+            .map(|r| r.diff_ignore_options.clone())
+            .unwrap_or_default();
+        options.ignore_matching_lines = self.diff_ignore_patterns_from_input(cx);
+        if let Some(pattern) = DiffIgnoreMatcher::invalid_pattern(&options) {
+            self.push_toast(
+                components::ToastKind::Error,
+                format!("Invalid POSIX extended regular expression: {pattern}"),
+                cx,
+            );
+            return;
+        }
+
+        self.store
+            .dispatch(Msg::SetDiffIgnoreOptions { repo_id, options });
+        self.close_popover(cx);
+    }
+
     fn submit_clone_repo(&mut self, cx: &mut gpui::Context<Self>) {
         if !matches!(self.popover, Some(PopoverKind::CloneRepo)) {
             return;
@@ -1408,6 +1824,73 @@ impl PopoverHost {
         self.dismiss_inline_popover(window, cx);
     }
 
+    fn submit_submodule_track_branch(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::Repo {
+            repo_id,
+            kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::TrackBranchPrompt { path }),
+        }) = self.popover.clone()
+        else {
+            return;
+        };
+
+        let branch = self
+            .submodule_ref_input
+            .read_with(cx, |input, _| input.text().trim().to_string());
+        if branch.is_empty() {
+            return;
+        }
+
+        self.store.dispatch(Msg::SetSubmoduleBranch {
+            repo_id,
+            path,
+            branch: Some(branch),
+        });
+        self.dismiss_inline_popover(window, cx);
+    }
+
+    fn submit_submodule_run_command(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::Repo {
+            repo_id,
+            kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::RunCommandPrompt),
+        }) = self.popover.clone()
+        else {
+            return;
+        };
+
+        let text = self
+            .submodule_ref_input
+            .read_with(cx, |input, _| input.text().to_string());
+        let Some(command) =
+            gitcomet_core::services::SubmoduleBatchCommand::from_command_line(&text)
+        else {
+            return;
+        };
+
+        self.store
+            .dispatch(Msg::RunInSubmodules { repo_id, command });
+        self.dismiss_inline_popover(window, cx);
+    }
+
+    fn submit_worktree_lock(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(PopoverKind::Repo {
+            repo_id,
+            kind: RepoPopoverKind::Worktree(WorktreePopoverKind::LockPrompt { path }),
+        }) = self.popover.clone()
+        else {
+            return;
+        };
+
+        let reason = self
+            .worktree_lock_reason_input
+            .read_with(cx, |input, _| input.text().trim().to_string());
+        self.store.dispatch(Msg::LockWorktree {
+            repo_id,
+            path,
+            reason: (!reason.is_empty()).then_some(reason),
+        });
+        self.close_popover(cx);
+    }
+
     fn can_submit_create_branch(&self, cx: &mut gpui::Context<Self>) -> bool {
         self.create_branch_prompt_repo_and_target().is_some()
             && self
@@ -1509,6 +1992,15 @@ impl PopoverHost {
     }
 
     fn request_lazy_popover_repo_data(&self, kind: &PopoverKind) {
+        if let PopoverKind::CommitMenu { repo_id, commit_id } = kind {
+            // A push or fetch can publish the commit at any time, so the
+            // reword check is redone every time the menu opens.
+            self.store.dispatch(Msg::LoadRewordBlocker {
+                repo_id: *repo_id,
+                commit_id: commit_id.clone(),
+            });
+            return;
+        }
         let repo_id = match kind {
             PopoverKind::TagMenu { repo_id, .. } => Some(*repo_id),
             PopoverKind::PreviousCommitMessagesMenu { repo_id } => Some(*repo_id),
@@ -1672,6 +2164,50 @@ impl PopoverHost {
                     let focus = self.create_tag_input.read_with(cx, |i, _| i.focus_handle());
                     window.focus(&focus, cx);
                 }
+                PopoverKind::RenameBranchPrompt { branch, .. } => {
+                    let theme = self.theme;
+                    let branch = branch.clone();
+                    self.rename_branch_remote_enabled = true;
+                    self.rename_branch_input.update(cx, |input, cx| {
+                        input.clear_transient_key_presses();
+                        input.set_theme(theme, cx);
+                        input.set_text(branch, cx);
+                        input.select_all_text(cx);
+                        cx.notify();
+                    });
+                    let focus = self
+                        .rename_branch_input
+                        .read_with(cx, |i, _| i.focus_handle());
+                    window.focus(&focus, cx);
+                }
+                PopoverKind::PatchSeriesImport { repo_id } => {
+                    self.patch_series_selected_ix = 0;
+                    self.patch_series_three_way_enabled = true;
+                    self.store.dispatch(Msg::LoadPatchSeriesPreview {
+                        repo_id: *repo_id,
+                        source: None,
+                    });
+                }
+                PopoverKind::DiffIgnorePatternsPrompt { repo_id } => {
+                    let theme = self.theme;
+                    let text = self
+                        .state
+                        .repos
+                        .iter()
+                        .find(|r| r.id == *repo_id)
+                        .map(|r| r.diff_ignore_options.ignore_matching_lines.join("\n"))
+                        .unwrap_or_default();
+                    self.diff_ignore_patterns_input.update(cx, |input, cx| {
+                        input.clear_transient_key_presses();
+                        input.set_theme(theme, cx);
+                        input.set_text(text, cx);
+                        cx.notify();
+                    });
+                    let focus = self
+                        .diff_ignore_patterns_input
+                        .read_with(cx, |i, _| i.focus_handle());
+                    window.focus(&focus, cx);
+                }
                 PopoverKind::Repo {
                     kind: RepoPopoverKind::Remote(RemotePopoverKind::AddPrompt),
                     ..
@@ -1740,6 +2276,37 @@ impl PopoverHost {
                         .read_with(cx, |i, _| i.focus_handle());
                     window.focus(&focus, cx);
                 }
+                PopoverKind::Repo {
+                    kind: RepoPopoverKind::Worktree(WorktreePopoverKind::LockPrompt { .. }),
+                    ..
+                } => {
+                    let theme = self.theme;
+                    self.worktree_lock_reason_input.update(cx, |input, cx| {
+                        input.set_theme(theme, cx);
+                        input.set_text("", cx);
+                        cx.notify();
+                    });
+                    let focus = self
+                        .worktree_lock_reason_input
+                        .read_with(cx, |i, _| i.focus_handle());
+                    window.focus(&focus, cx);
+                }
+                PopoverKind::Repo {
+                    kind: RepoPopoverKind::Worktree(WorktreePopoverKind::MovePrompt { path }),
+                    ..
+                } => {
+                    let theme = self.theme;
+                    let text = path.display().to_string();
+                    self.worktree_path_input.update(cx, |input, cx| {
+                        input.set_theme(theme, cx);
+                        input.set_text(text, cx);
+                        cx.notify();
+                    });
+                    let focus = self
+                        .worktree_path_input
+                        .read_with(cx, |i, _| i.focus_handle());
+                    window.focus(&focus, cx);
+                }
                 PopoverKind::Repo {
                     repo_id,
                     kind:
@@ -1785,7 +2352,11 @@ impl PopoverHost {
                 }
                 PopoverKind::Repo {
                     kind:
-                        RepoPopoverKind::Submodule(SubmodulePopoverKind::ChangePointerPrompt { .. }),
+                        RepoPopoverKind::Submodule(
+                            SubmodulePopoverKind::ChangePointerPrompt { .. }
+                            | SubmodulePopoverKind::TrackBranchPrompt { .. }
+                            | SubmodulePopoverKind::RunCommandPrompt,
+                        ),
                     ..
                 } => {
                     let theme = self.theme;
@@ -1822,6 +2393,43 @@ impl PopoverHost {
                         limit: 200,
                     });
                 }
+                PopoverKind::Blame { repo_id, path, rev } => {
+                    self.blame_scroll = UniformListScrollHandle::default();
+                    self.blame_restore_scroll_offset = None;
+                    self.store.dispatch(Msg::LoadBlame {
+                        repo_id: *repo_id,
+                        path: path.clone(),
+                        rev: rev.clone(),
+                    });
+                }
+                PopoverKind::RevisionTree { repo_id, rev } => {
+                    self.revision_tree_scroll = UniformListScrollHandle::default();
+                    self.store.dispatch(Msg::LoadRevisionTree {
+                        repo_id: *repo_id,
+                        rev: rev.clone(),
+                        dir: std::path::PathBuf::new(),
+                    });
+                }
+                PopoverKind::LineHistory {
+                    repo_id,
+                    path,
+                    start,
+                    end,
+                    rev,
+                } => {
+                    self.store.dispatch(Msg::LoadLineHistory {
+                        repo_id: *repo_id,
+                        path: path.clone(),
+                        start: *start,
+                        end: *end,
+                        rev: rev.clone(),
+                    });
+                }
+                PopoverKind::ConflictDashboard { repo_id } => {
+                    self.conflict_dashboard_selection.clear();
+                    self.store
+                        .dispatch(Msg::LoadConflictSummaries { repo_id: *repo_id });
+                }
                 PopoverKind::PushSetUpstreamPrompt { repo_id, .. } => {
                     let theme = self.theme;
                     let current_text = self
@@ -2153,6 +2761,15 @@ impl PopoverHost {
             PopoverKind::CreateTagPrompt { repo_id, target } => {
                 create_tag_prompt::panel(self, repo_id, target, cx)
             }
+            PopoverKind::DiffIgnorePatternsPrompt { .. } => {
+                diff_ignore_patterns_prompt::panel(self, cx)
+            }
+            PopoverKind::RenameBranchPrompt { repo_id, branch } => {
+                rename_branch_prompt::panel(self, repo_id, branch, cx)
+            }
+            PopoverKind::PatchSeriesImport { repo_id } => {
+                patch_series_import::panel(self, repo_id, cx)
+            }
             PopoverKind::Repo { repo_id, kind } => match kind {
                 RepoPopoverKind::Remote(remote_kind) => match remote_kind {
                     RemotePopoverKind::AddPrompt => remote_add_prompt::panel(self, repo_id, cx),
@@ -2189,6 +2806,12 @@ impl PopoverHost {
                     WorktreePopoverKind::RemoveConfirm { path, branch } => {
                         worktree_remove_confirm::panel(self, repo_id, path, branch, cx)
                     }
+                    WorktreePopoverKind::LockPrompt { path } => {
+                        worktree_lock_prompt::panel(self, &path, cx)
+                    }
+                    WorktreePopoverKind::MovePrompt { path } => {
+                        worktree_move_prompt::panel(self, repo_id, path, cx)
+                    }
                 },
                 RepoPopoverKind::Submodule(submodule_kind) => match submodule_kind {
                     SubmodulePopoverKind::SectionMenu => self.context_menu_view(
@@ -2205,6 +2828,12 @@ impl PopoverHost {
                     SubmodulePopoverKind::ChangePointerPrompt { path } => {
                         submodule_change_pointer_prompt::panel(self, repo_id, &path, cx)
                     }
+                    SubmodulePopoverKind::TrackBranchPrompt { path } => {
+                        submodule_track_branch_prompt::panel(self, &path, cx)
+                    }
+                    SubmodulePopoverKind::RunCommandPrompt => {
+                        submodule_run_command_prompt::panel(self, cx)
+                    }
                     SubmodulePopoverKind::TrustConfirm => {
                         submodule_trust_confirm::panel(self, repo_id, cx)
                     }
@@ -2222,6 +2851,17 @@ impl PopoverHost {
             PopoverKind::FileHistory { repo_id, path } => {
                 file_history::panel(self, repo_id, path, cx)
             }
+            PopoverKind::Blame { repo_id, path, rev } => blame::panel(self, repo_id, path, rev, cx),
+            PopoverKind::RevisionTree { repo_id, rev } => {
+                revision_tree::panel(self, repo_id, rev, cx)
+            }
+            PopoverKind::LineHistory {
+                repo_id,
+                path,
+                start,
+                end,
+                rev,
+            } => line_history::panel(self, repo_id, path, start, end, rev, cx),
             PopoverKind::PushSetUpstreamPrompt { repo_id, remote } => {
                 push_set_upstream_prompt::panel(self, repo_id, remote, cx)
             }
@@ -2231,6 +2871,9 @@ impl PopoverHost {
             PopoverKind::MergeAbortConfirm { repo_id } => {
                 merge_abort_confirm::panel(self, repo_id, cx)
             }
+            PopoverKind::ConflictDashboard { repo_id } => {
+                conflict_dashboard::panel(self, repo_id, cx)
+            }
             PopoverKind::ConflictSaveStageConfirm {
                 repo_id,
                 path,
@@ -2282,6 +2925,16 @@ impl PopoverHost {
             PopoverKind::CommitMenu { repo_id, commit_id } => {
                 self.context_menu_view(PopoverKind::CommitMenu { repo_id, commit_id }, cx)
             }
+            PopoverKind::CommitSelectionMenu {
+                repo_id,
+                commit_ids,
+            } => self.context_menu_view(
+                PopoverKind::CommitSelectionMenu {
+                    repo_id,
+                    commit_ids,
+                },
+                cx,
+            ),
             PopoverKind::TagMenu { repo_id, commit_id } => {
                 self.context_menu_view(PopoverKind::TagMenu { repo_id, commit_id }, cx)
             }
@@ -2299,6 +2952,7 @@ impl PopoverHost {
                 lines_count,
                 copy_text,
                 copy_target,
+                line_range,
             } => self.context_menu_view(
                 PopoverKind::DiffEditorMenu {
                     repo_id,
@@ -2311,6 +2965,7 @@ impl PopoverHost {
                     lines_count,
                     copy_text,
                     copy_target,
+                    line_range,
                 },
                 cx,
             ),
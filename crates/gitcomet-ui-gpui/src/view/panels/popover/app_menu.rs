@@ -135,6 +135,63 @@ pub(super) fn panel(this: &mut PopoverHost, cx: &mut gpui::Context<PopoverHost>)
                 this.close_popover(cx);
             })),
         )
+        .child(
+            entry(
+                "app_menu_import_patch_series",
+                "Import patch series…".into(),
+                active_repo_id.is_none(),
+            )
+            .on_click(cx.listener(move |this, _e: &ClickEvent, window, cx| {
+                let Some(repo_id) = active_repo_id else {
+                    return;
+                };
+                cx.stop_propagation();
+                let anchor = this
+                    .popover_anchor
+                    .as_ref()
+                    .map(|anchor| match anchor {
+                        PopoverAnchor::Point(point) => *point,
+                        PopoverAnchor::Bounds(bounds) => bounds.bottom_left(),
+                    })
+                    .unwrap_or_else(|| point(px(64.0), px(64.0)));
+                this.open_popover_at(
+                    PopoverKind::PatchSeriesImport { repo_id },
+                    anchor,
+                    window,
+                    cx,
+                );
+            })),
+        )
+        .child(
+            entry(
+                "app_menu_browse_head_files",
+                "Browse files at HEAD…".into(),
+                active_repo_id.is_none(),
+            )
+            .on_click(cx.listener(move |this, _e: &ClickEvent, window, cx| {
+                let Some(repo_id) = active_repo_id else {
+                    return;
+                };
+                cx.stop_propagation();
+                let anchor = this
+                    .popover_anchor
+                    .as_ref()
+                    .map(|anchor| match anchor {
+                        PopoverAnchor::Point(point) => *point,
+                        PopoverAnchor::Bounds(bounds) => bounds.bottom_left(),
+                    })
+                    .unwrap_or_else(|| point(px(64.0), px(64.0)));
+                this.open_popover_at(
+                    PopoverKind::RevisionTree {
+                        repo_id,
+                        rev: "HEAD".to_string(),
+                    },
+                    anchor,
+                    window,
+                    cx,
+                );
+            })),
+        )
         .child(separator())
         .child(install_desktop)
         .child(
@@ -1,4 +1,45 @@
 use super::*;
+use gitcomet_core::services::{BlameLine, BlameOptions};
+
+/// Oldest and newest author timestamps in a blame, used to scale the heatmap.
+fn blame_time_range(lines: &[BlameLine]) -> Option<(i64, i64)> {
+    lines
+        .iter()
+        .filter_map(|line| line.author_time_unix)
+        .fold(None, |range, time| match range {
+            None => Some((time, time)),
+            Some((oldest, newest)) => Some((oldest.min(time), newest.max(time))),
+        })
+}
+
+/// 0.0 for the oldest line in the file, 1.0 for the newest.
+fn blame_heat(time: Option<i64>, range: Option<(i64, i64)>) -> f32 {
+    match (time, range) {
+        (Some(time), Some((oldest, newest))) if newest > oldest => {
+            (time - oldest) as f32 / (newest - oldest) as f32
+        }
+        (Some(_), Some(_)) => 1.0,
+        _ => 0.0,
+    }
+}
+
+/// Where "Prior" re-blames a line: its source path at the commit's parent.
+/// `None` for uncommitted lines and commits with no parent to go back to.
+pub(super) fn blame_prior_target(
+    line: &BlameLine,
+    path: &std::path::Path,
+) -> Option<(std::path::PathBuf, String)> {
+    let uncommitted = line.commit_id.bytes().all(|b| b == b'0');
+    if uncommitted || !line.has_parent {
+        return None;
+    }
+    let prior_path = line
+        .original_path
+        .as_deref()
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| path.to_path_buf());
+    Some((prior_path, format!("{}^", line.commit_id)))
+}
 
 pub(super) fn panel(
     this: &mut PopoverHost,
@@ -16,6 +57,73 @@ pub(super) fn panel(
         .unwrap_or_else(|| "rev: HEAD".into());
     let ui_scale_percent = super::popover_ui_scale_percent(cx);
     let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let options = repo
+        .map(|r| r.history_state.blame_options)
+        .unwrap_or_default();
+
+    let option_toggle = |id: &'static str,
+                         label: &'static str,
+                         enabled: bool,
+                         toggle: fn(BlameOptions) -> BlameOptions,
+                         cx: &mut gpui::Context<PopoverHost>| {
+        components::Button::new(id, label)
+            .style(components::ButtonStyle::Subtle)
+            .selected(enabled)
+            .on_click(theme, cx, move |this, _e, _w, cx| {
+                this.store.dispatch(Msg::SetBlameOptions {
+                    repo_id,
+                    options: toggle(options),
+                });
+                cx.notify();
+            })
+    };
+    let toggles = div()
+        .px_2()
+        .pb_1()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1()
+        .child(option_toggle(
+            "blame_ignore_whitespace",
+            "Ignore whitespace",
+            options.ignore_whitespace,
+            |o| BlameOptions {
+                ignore_whitespace: !o.ignore_whitespace,
+                ..o
+            },
+            cx,
+        ))
+        .child(option_toggle(
+            "blame_detect_moves",
+            "Detect moves",
+            options.detect_moves,
+            |o| BlameOptions {
+                detect_moves: !o.detect_moves,
+                ..o
+            },
+            cx,
+        ))
+        .child(option_toggle(
+            "blame_detect_copies",
+            "Detect copies",
+            options.detect_copies,
+            |o| BlameOptions {
+                detect_copies: !o.detect_copies,
+                ..o
+            },
+            cx,
+        ))
+        .child(option_toggle(
+            "blame_use_ignore_revs",
+            "Use ignore-revs",
+            options.use_ignore_revs,
+            |o| BlameOptions {
+                use_ignore_revs: !o.use_ignore_revs,
+                ..o
+            },
+            cx,
+        ));
 
     let header = div()
         .px_2()
@@ -30,15 +138,12 @@ pub(super) fn panel(
                 .min_w(px(0.0))
                 .child(div().text_sm().font_weight(FontWeight::BOLD).child("Blame"))
                 .child(
-                    div()
-                        .text_xs()
-                        .text_color(theme.colors.text_muted)
-                        .child(
-                            components::TruncatedText::path(title.clone())
-                                .id(("blame_title_path", repo_id.0))
-                                .full_text_tooltip(this.tooltip_host.clone())
-                                .render(cx),
-                        ),
+                    div().text_xs().text_color(theme.colors.text_muted).child(
+                        components::TruncatedText::path(title.clone())
+                            .id(("blame_title_path", repo_id.0))
+                            .full_text_tooltip(this.tooltip_host.clone())
+                            .render(cx),
+                    ),
                 )
                 .child(
                     div()
@@ -64,37 +169,35 @@ pub(super) fn panel(
             cx,
         )
         .into_any_element(),
-        Some(Loadable::Loading) => {
-            components::context_menu_label(
-                theme,
-                ui_scale_percent,
-                "Loading",
-                Some(this.tooltip_host.clone()),
-                cx,
-            )
-            .into_any_element()
-        }
-        Some(Loadable::Error(e)) => {
-            components::context_menu_label(
-                theme,
-                ui_scale_percent,
-                e.clone(),
-                Some(this.tooltip_host.clone()),
-                cx,
-            )
-            .into_any_element()
-        }
-        Some(Loadable::NotLoaded) => {
-            components::context_menu_label(
-                theme,
-                ui_scale_percent,
-                "Not loaded",
-                Some(this.tooltip_host.clone()),
-                cx,
-            )
-            .into_any_element()
-        }
+        Some(Loadable::Loading) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "Loading",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Error(e)) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            e.clone(),
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::NotLoaded) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "Not loaded",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
         Some(Loadable::Ready(lines)) => {
+            // Re-blaming at a prior revision keeps the reader's place.
+            if let Some(offset) = this.blame_restore_scroll_offset.take() {
+                this.blame_scroll.0.borrow().base_handle.set_offset(offset);
+            }
             let count = lines.len();
             let list = uniform_list(
                 "blame_popover",
@@ -128,6 +231,7 @@ pub(super) fn panel(
         .min_w(scaled_px(720.0))
         .max_w(scaled_px(980.0))
         .child(header)
+        .child(toggles)
         .child(div().border_t_1().border_color(theme.colors.border))
         .child(body)
 }
@@ -156,6 +260,7 @@ fn render_blame_popover_rows(
     let theme = this.theme;
     let ui_scale_percent = super::popover_ui_scale_percent(cx);
     let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let time_range = blame_time_range(lines);
     let mut rows = Vec::with_capacity(range.len());
     for ix in range {
         let Some(line) = lines.get(ix) else {
@@ -166,6 +271,15 @@ fn render_blame_popover_rows(
         let short = sha.get(0..8).unwrap_or(sha.as_ref()).to_string();
         let author: SharedString = line.author.clone().into();
         let code: SharedString = line.line.clone().into();
+        let origin: SharedString = match (&line.original_path, line.original_line) {
+            (Some(original_path), Some(original_line)) => {
+                format!("{original_path}:{original_line}").into()
+            }
+            (Some(original_path), None) => original_path.to_string().into(),
+            (None, _) => SharedString::default(),
+        };
+        let heat = blame_heat(line.author_time_unix, time_range);
+        let prior_target = blame_prior_target(line, &path);
         let commit_id = CommitId(sha);
         let path = path.clone();
 
@@ -179,6 +293,10 @@ fn render_blame_popover_rows(
                 .gap_2()
                 .hover(move |s| s.bg(theme.colors.hover))
                 .active(move |s| s.bg(theme.colors.active))
+                .child(div().w(px(4.0)).h_full().bg(crate::theme::with_alpha(
+                    theme.colors.accent,
+                    0.1 + 0.8 * heat,
+                )))
                 .child(
                     div()
                         .w(scaled_px(44.0))
@@ -215,6 +333,40 @@ fn render_blame_popover_rows(
                         .overflow_hidden()
                         .child(code),
                 )
+                .child(
+                    div()
+                        .w(scaled_px(160.0))
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .line_clamp(1)
+                        .whitespace_nowrap()
+                        .overflow_hidden()
+                        .child(origin),
+                )
+                .child(
+                    components::Button::new(format!("blame_prior_{ix}"), "Prior")
+                        .style(components::ButtonStyle::Transparent)
+                        .disabled(prior_target.is_none())
+                        .on_click(theme, cx, move |this, _e, _w, cx| {
+                            cx.stop_propagation();
+                            let Some((prior_path, prior_rev)) = prior_target.clone() else {
+                                return;
+                            };
+                            this.blame_restore_scroll_offset =
+                                Some(this.blame_scroll.0.borrow().base_handle.offset());
+                            this.popover = Some(PopoverKind::Blame {
+                                repo_id,
+                                path: prior_path.clone(),
+                                rev: Some(prior_rev.clone()),
+                            });
+                            this.store.dispatch(Msg::LoadBlame {
+                                repo_id,
+                                path: prior_path,
+                                rev: Some(prior_rev),
+                            });
+                            cx.notify();
+                        }),
+                )
                 .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
                     this.store.dispatch(Msg::SelectCommit {
                         repo_id,
@@ -225,6 +377,7 @@ fn render_blame_popover_rows(
                         target: DiffTarget::Commit {
                             commit_id: commit_id.clone(),
                             path: Some(path.clone()),
+                            old_path: None,
                         },
                     });
                     this.close_popover(cx);
@@ -0,0 +1,388 @@
+use super::*;
+use gitcomet_core::conflict_session::ConflictFileSummary;
+use gitcomet_core::domain::FileConflictKind;
+use gitcomet_core::services::ConflictSide;
+
+fn conflict_kind_label(kind: FileConflictKind) -> &'static str {
+    match kind {
+        FileConflictKind::BothDeleted => "both deleted",
+        FileConflictKind::AddedByUs => "added by us",
+        FileConflictKind::DeletedByThem => "deleted by them",
+        FileConflictKind::AddedByThem => "added by them",
+        FileConflictKind::DeletedByUs => "deleted by us",
+        FileConflictKind::BothAdded => "both added",
+        FileConflictKind::BothModified => "both modified",
+    }
+}
+
+fn region_label(summary: &ConflictFileSummary) -> String {
+    let mut label = format!(
+        "{}/{} resolved",
+        summary.resolved_regions, summary.total_regions
+    );
+    if summary.auto_resolvable_regions > 0 {
+        label.push_str(&format!(", {} auto", summary.auto_resolvable_regions));
+    }
+    label
+}
+
+/// Paths the bulk actions apply to: the current selection, or every listed
+/// conflict when nothing is selected.
+fn bulk_target_paths(
+    summaries: &[ConflictFileSummary],
+    selection: &std::collections::BTreeSet<std::path::PathBuf>,
+) -> Vec<std::path::PathBuf> {
+    summaries
+        .iter()
+        .map(|summary| summary.path.clone())
+        .filter(|path| selection.is_empty() || selection.contains(path))
+        .collect()
+}
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let repo = this.state.repos.iter().find(|r| r.id == repo_id);
+
+    let is_rebase = repo.is_some_and(|repo| {
+        matches!(&repo.rebase_in_progress, Loadable::Ready(true))
+            && !matches!(&repo.merge_commit_message, Loadable::Ready(Some(_)))
+    });
+    let merge_message = repo.and_then(|repo| match &repo.merge_commit_message {
+        Loadable::Ready(Some(message)) => Some(message.clone()),
+        _ => None,
+    });
+    let summaries = repo.and_then(|repo| match &repo.conflict_state.conflict_summaries {
+        Loadable::Ready(summaries) => Some(Arc::clone(summaries)),
+        _ => None,
+    });
+    let has_unmerged_paths = repo.is_none_or(|repo| {
+        repo.has_unstaged_conflicts || summaries.as_ref().is_none_or(|s| !s.is_empty())
+    });
+    let pick_operation = repo
+        .and_then(super::super::action_bar::active_repo_operation)
+        .map(|operation| operation.kind())
+        .filter(|kind| {
+            matches!(
+                kind,
+                RepoOperationKind::CherryPick | RepoOperationKind::Revert
+            )
+        });
+    let can_continue =
+        !has_unmerged_paths && (is_rebase || pick_operation.is_some() || merge_message.is_some());
+
+    let count_label: SharedString = match summaries.as_ref() {
+        Some(summaries) if summaries.is_empty() => "All conflicts resolved".into(),
+        Some(summaries) => format!("{} conflicted files", summaries.len()).into(),
+        None => "".into(),
+    };
+
+    let header = div()
+        .px_2()
+        .py_1()
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::BOLD)
+                        .child("Conflicts"),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .child(count_label),
+                ),
+        )
+        .child(
+            components::Button::new("conflict_dashboard_close", "Close")
+                .style(components::ButtonStyle::Outlined)
+                .on_click(theme, cx, |this, _e, _w, cx| this.close_popover(cx)),
+        );
+
+    let body: AnyElement = match repo.map(|r| &r.conflict_state.conflict_summaries) {
+        None => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "No repository",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Loading) | Some(Loadable::NotLoaded) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "Loading",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Error(e)) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            e.clone(),
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Ready(summaries)) => {
+            let mut list = div()
+                .id("conflict_dashboard_list")
+                .flex()
+                .flex_col()
+                .max_h(scaled_px(360.0))
+                .overflow_y_scroll();
+            for (ix, summary) in summaries.iter().enumerate() {
+                let path = summary.path.clone();
+                let selected = this.conflict_dashboard_selection.contains(&path);
+                let mut flags = Vec::new();
+                if summary.is_binary {
+                    flags.push("binary");
+                }
+                if summary.is_lfs {
+                    flags.push("LFS");
+                }
+                let open_path = path.clone();
+                list = list.child(
+                    div()
+                        .id(("conflict_dashboard_row", ix))
+                        .h(scaled_px(22.0))
+                        .flex()
+                        .items_center()
+                        .px_2()
+                        .gap_2()
+                        .when(selected, |d| d.bg(theme.colors.active))
+                        .hover(move |s| s.bg(theme.colors.hover))
+                        .child(div().w(scaled_px(14.0)).text_xs().child(if selected {
+                            "✓"
+                        } else {
+                            ""
+                        }))
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w(px(0.0))
+                                .text_xs()
+                                .font_family(crate::font_preferences::EDITOR_MONOSPACE_FONT_FAMILY)
+                                .line_clamp(1)
+                                .whitespace_nowrap()
+                                .overflow_hidden()
+                                .child(path.display().to_string()),
+                        )
+                        .child(
+                            div()
+                                .w(scaled_px(110.0))
+                                .text_xs()
+                                .text_color(theme.colors.text_muted)
+                                .whitespace_nowrap()
+                                .child(conflict_kind_label(summary.conflict_kind)),
+                        )
+                        .child(
+                            div()
+                                .w(scaled_px(130.0))
+                                .text_xs()
+                                .text_color(theme.colors.text_muted)
+                                .whitespace_nowrap()
+                                .child(region_label(summary)),
+                        )
+                        .child(
+                            div()
+                                .w(scaled_px(70.0))
+                                .text_xs()
+                                .text_color(theme.colors.warning)
+                                .whitespace_nowrap()
+                                .child(flags.join(", ")),
+                        )
+                        .child(
+                            components::Button::new(
+                                format!("conflict_dashboard_open_{ix}"),
+                                "Open",
+                            )
+                            .style(components::ButtonStyle::Transparent)
+                            .on_click(
+                                theme,
+                                cx,
+                                move |this, _e, _w, cx| {
+                                    this.store.dispatch(Msg::SelectConflictDiff {
+                                        repo_id,
+                                        path: open_path.clone(),
+                                    });
+                                    this.close_popover(cx);
+                                },
+                            ),
+                        )
+                        .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                            if !this.conflict_dashboard_selection.remove(&path) {
+                                this.conflict_dashboard_selection.insert(path.clone());
+                            }
+                            cx.notify();
+                        })),
+                );
+            }
+            list.into_any_element()
+        }
+    };
+
+    let target_paths = summaries
+        .as_ref()
+        .map(|summaries| bulk_target_paths(summaries, &this.conflict_dashboard_selection))
+        .unwrap_or_default();
+    let has_targets = !target_paths.is_empty();
+    let auto_paths = summaries
+        .as_ref()
+        .map(|summaries| {
+            summaries
+                .iter()
+                .filter(|summary| summary.is_fully_auto_resolvable())
+                .map(|summary| summary.path.clone())
+                .filter(|path| {
+                    this.conflict_dashboard_selection.is_empty()
+                        || this.conflict_dashboard_selection.contains(path)
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let scope_label = if this.conflict_dashboard_selection.is_empty() {
+        "all"
+    } else {
+        "selected"
+    };
+
+    let bulk_button = |id: &'static str,
+                       label: String,
+                       enabled: bool,
+                       paths: Vec<std::path::PathBuf>,
+                       make_msg: fn(RepoId, Vec<std::path::PathBuf>) -> Msg,
+                       cx: &mut gpui::Context<PopoverHost>| {
+        components::Button::new(id, label)
+            .style(components::ButtonStyle::Outlined)
+            .disabled(!enabled)
+            .on_click(theme, cx, move |this, _e, _w, cx| {
+                this.store.dispatch(make_msg(repo_id, paths.clone()));
+                this.conflict_dashboard_selection.clear();
+                cx.notify();
+            })
+    };
+
+    let actions = div()
+        .px_2()
+        .py_1()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1()
+        .child(bulk_button(
+            "conflict_dashboard_autosolve",
+            format!("Autosolve {scope_label}"),
+            !auto_paths.is_empty(),
+            auto_paths,
+            |repo_id, paths| Msg::AutosolveConflictPaths {
+                repo_id,
+                paths: paths.into(),
+            },
+            cx,
+        ))
+        .child(bulk_button(
+            "conflict_dashboard_ours",
+            format!("Take ours ({scope_label})"),
+            has_targets,
+            target_paths.clone(),
+            |repo_id, paths| Msg::CheckoutConflictSidePaths {
+                repo_id,
+                paths: paths.into(),
+                side: ConflictSide::Ours,
+            },
+            cx,
+        ))
+        .child(bulk_button(
+            "conflict_dashboard_theirs",
+            format!("Take theirs ({scope_label})"),
+            has_targets,
+            target_paths.clone(),
+            |repo_id, paths| Msg::CheckoutConflictSidePaths {
+                repo_id,
+                paths: paths.into(),
+                side: ConflictSide::Theirs,
+            },
+            cx,
+        ))
+        .child(bulk_button(
+            "conflict_dashboard_mark_resolved",
+            format!("Mark resolved ({scope_label})"),
+            has_targets,
+            target_paths,
+            |repo_id, paths| Msg::StagePaths {
+                repo_id,
+                paths: paths.into(),
+            },
+            cx,
+        ));
+
+    let continue_label = match pick_operation {
+        _ if is_rebase => "Continue rebase",
+        Some(RepoOperationKind::CherryPick) => "Continue cherry-pick",
+        Some(RepoOperationKind::Revert) => "Continue revert",
+        _ => "Continue merge",
+    };
+    let footer = div()
+        .px_2()
+        .py_1()
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child(if can_continue {
+                    "Everything is staged."
+                } else {
+                    "Resolve and stage every file to continue."
+                }),
+        )
+        .child(
+            components::Button::new("conflict_dashboard_continue", continue_label)
+                .style(components::ButtonStyle::Filled)
+                .disabled(!can_continue)
+                .on_click(theme, cx, move |this, _e, _w, cx| {
+                    if is_rebase {
+                        this.store.dispatch(Msg::RebaseContinue { repo_id });
+                    } else if let Some(kind) = pick_operation {
+                        this.store.dispatch(Msg::OperationAction {
+                            repo_id,
+                            kind,
+                            action: RepoOperationAction::Continue,
+                        });
+                    } else if let Some(message) = merge_message.clone() {
+                        this.store.dispatch(Msg::Commit {
+                            repo_id,
+                            message,
+                            push_after_commit: false,
+                        });
+                    }
+                    this.close_popover(cx);
+                }),
+        );
+
+    div()
+        .flex()
+        .flex_col()
+        .child(header)
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(body)
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(actions)
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(footer)
+}
@@ -6,6 +6,7 @@ mod change_tracking_settings;
 mod commit;
 mod commit_file;
 mod commit_options;
+mod commit_selection;
 mod conflict_resolver_chunk;
 mod conflict_resolver_input_row;
 mod conflict_resolver_output;
@@ -236,6 +237,10 @@ impl PopoverHost {
             PopoverKind::CommitMenu { repo_id, commit_id } => {
                 Some(commit::model(self, *repo_id, commit_id))
             }
+            PopoverKind::CommitSelectionMenu {
+                repo_id,
+                commit_ids,
+            } => Some(commit_selection::model(self, *repo_id, commit_ids)),
             PopoverKind::TagMenu { repo_id, commit_id } => {
                 Some(tag::model(self, *repo_id, commit_id))
             }
@@ -268,7 +273,12 @@ impl PopoverHost {
             PopoverKind::Repo {
                 repo_id,
                 kind: RepoPopoverKind::Worktree(WorktreePopoverKind::Menu { path, branch }),
-            } => Some(worktree::model(*repo_id, path, branch.as_deref())),
+            } => Some(worktree::model(
+                *repo_id,
+                path,
+                branch.as_deref(),
+                &worktree::menu_state(self, *repo_id, path),
+            )),
             PopoverKind::Repo {
                 repo_id,
                 kind: RepoPopoverKind::Submodule(SubmodulePopoverKind::SectionMenu),
@@ -305,6 +315,7 @@ impl PopoverHost {
                 lines_count,
                 copy_text,
                 copy_target,
+                line_range,
             } => Some(diff_editor::model(
                 *repo_id,
                 *area,
@@ -316,6 +327,7 @@ impl PopoverHost {
                 *lines_count,
                 copy_text,
                 *copy_target,
+                line_range,
             )),
             PopoverKind::ConflictResolverInputRowMenu {
                 line_label,
@@ -359,7 +371,7 @@ impl PopoverHost {
             PopoverKind::HistoryBranchFilter { repo_id } => {
                 Some(history_branch_filter::model(self, *repo_id))
             }
-            PopoverKind::DiffActionMenu => Some(diff_actions::model(self)),
+            PopoverKind::DiffActionMenu => Some(diff_actions::model(self, cx)),
             PopoverKind::DiffContentModeSettings => Some(diff_content_mode_settings::model(self)),
             PopoverKind::ChangeTrackingSettings => Some(change_tracking_settings::model(self)),
             PopoverKind::UiScalePicker => Some(ui_scale_picker::model(cx)),
@@ -499,6 +511,96 @@ impl PopoverHost {
                 self.store
                     .dispatch(Msg::RevertCommit { repo_id, commit_id });
             }
+            ContextMenuAction::ExportPatchSeries {
+                repo_id,
+                commit_ids,
+                cover_letter,
+            } => {
+                cx.stop_propagation();
+                let view = cx.weak_entity();
+                let file_name = format!("commits-{}.mbox", commit_ids.len());
+                let prompt = if cover_letter {
+                    "Write patch files to folder"
+                } else {
+                    "Export patch series to folder"
+                };
+                let rx = cx.prompt_for_paths(gpui::PathPromptOptions {
+                    files: false,
+                    directories: true,
+                    multiple: false,
+                    prompt: Some(prompt.into()),
+                });
+                window
+                    .spawn(cx, async move |cx| {
+                        let result = rx.await;
+                        let paths = match result {
+                            Ok(Ok(Some(paths))) => paths,
+                            Ok(Ok(None)) => return,
+                            Ok(Err(_)) | Err(_) => return,
+                        };
+                        let Some(folder) = paths.into_iter().next() else {
+                            return;
+                        };
+                        let dest = if cover_letter {
+                            folder
+                        } else {
+                            folder.join(file_name)
+                        };
+                        let _ = view.update(cx, |this, cx| {
+                            this.store.dispatch(Msg::ExportPatchSeries {
+                                repo_id,
+                                commit_ids: commit_ids.clone(),
+                                dest,
+                                cover_letter,
+                            });
+                            cx.notify();
+                        });
+                    })
+                    .detach();
+                self.close_popover(cx);
+                return;
+            }
+            ContextMenuAction::CherryPickCommits {
+                repo_id,
+                commit_ids,
+            } => {
+                self.store.dispatch(Msg::CherryPickCommits {
+                    repo_id,
+                    commit_ids,
+                });
+            }
+            ContextMenuAction::RevertCommits {
+                repo_id,
+                commit_ids,
+            } => {
+                self.store.dispatch(Msg::RevertCommits {
+                    repo_id,
+                    commit_ids,
+                });
+            }
+            ContextMenuAction::SquashCommits {
+                repo_id,
+                commit_ids,
+            } => {
+                self.store.dispatch(Msg::SquashCommits {
+                    repo_id,
+                    commit_ids,
+                });
+            }
+            ContextMenuAction::CreateFixupCommit {
+                repo_id,
+                commit_id,
+                kind,
+            } => {
+                self.store.dispatch(Msg::CreateFixupCommit {
+                    repo_id,
+                    commit_id,
+                    kind,
+                });
+            }
+            ContextMenuAction::AutosquashRebase { repo_id } => {
+                self.store.dispatch(Msg::AutosquashRebase { repo_id });
+            }
             ContextMenuAction::CheckoutBranch { repo_id, name } => {
                 self.store.dispatch(Msg::CheckoutBranch { repo_id, name });
             }
@@ -528,6 +630,54 @@ impl PopoverHost {
                     });
                 });
             }
+            ContextMenuAction::SetDiffIgnoreOptions { repo_id, options } => {
+                self.store
+                    .dispatch(Msg::SetDiffIgnoreOptions { repo_id, options });
+            }
+            ContextMenuAction::SetRenameDetection { detection } => {
+                self.store.dispatch(Msg::SetRenameDetection { detection });
+            }
+            ContextMenuAction::SetDiffAlignmentMode { mode } => {
+                close_after_action = false;
+                restore_diff_panel_focus_after_action = true;
+                let main_pane = self.main_pane.clone();
+                cx.defer(move |cx| {
+                    main_pane.update(cx, |pane, cx| {
+                        pane.set_diff_alignment_mode(mode, cx);
+                    });
+                });
+            }
+            ContextMenuAction::SetDiffHighlightMovedLines { enabled } => {
+                close_after_action = false;
+                restore_diff_panel_focus_after_action = true;
+                let main_pane = self.main_pane.clone();
+                cx.defer(move |cx| {
+                    main_pane.update(cx, |pane, cx| {
+                        pane.set_diff_highlight_moved_lines(enabled, cx);
+                    });
+                });
+            }
+            ContextMenuAction::SetDiffMovedLinesIgnoreWhitespace { enabled } => {
+                close_after_action = false;
+                restore_diff_panel_focus_after_action = true;
+                let main_pane = self.main_pane.clone();
+                cx.defer(move |cx| {
+                    main_pane.update(cx, |pane, cx| {
+                        pane.set_diff_moved_lines_ignore_whitespace(enabled, cx);
+                    });
+                });
+            }
+            ContextMenuAction::JumpToMovedCounterpart => {
+                restore_diff_panel_focus_after_action = true;
+                let main_pane = self.main_pane.clone();
+                cx.defer(move |cx| {
+                    main_pane.update(cx, |pane, cx| {
+                        if pane.diff_jump_to_moved_counterpart() {
+                            cx.notify();
+                        }
+                    });
+                });
+            }
             ContextMenuAction::SetChangeTrackingView { view } => {
                 self.change_tracking_view = view;
                 let root_view = self.root_view.clone();
@@ -562,6 +712,15 @@ impl PopoverHost {
                     pane.set_commit_message_from_history(message, window, cx);
                 });
             }
+            ContextMenuAction::RewordCommit {
+                repo_id,
+                commit_id,
+                message,
+            } => {
+                self.details_pane.update(cx, |pane, cx| {
+                    pane.start_reword_commit(repo_id, commit_id, message, window, cx);
+                });
+            }
             ContextMenuAction::StageSelectionOrPath {
                 repo_id,
                 area,
@@ -581,6 +740,7 @@ impl PopoverHost {
                         target: DiffTarget::WorkingTree {
                             path: path.clone(),
                             area,
+                            old_path: None,
                         },
                     });
                     self.store.dispatch(Msg::StagePath { repo_id, path });
@@ -605,6 +765,7 @@ impl PopoverHost {
                         target: DiffTarget::WorkingTree {
                             path: path.clone(),
                             area,
+                            old_path: None,
                         },
                     });
                     self.store.dispatch(Msg::UnstagePath { repo_id, path });
@@ -673,9 +834,26 @@ impl PopoverHost {
             ContextMenuAction::LoadSubmodule { repo_id, path } => {
                 self.store.dispatch(Msg::LoadSubmodule { repo_id, path });
             }
+            ContextMenuAction::SyncSubmodules { repo_id } => {
+                self.store.dispatch(Msg::SyncSubmodules { repo_id });
+            }
+            ContextMenuAction::UpdateSubmoduleRemote { repo_id, path } => {
+                self.store
+                    .dispatch(Msg::UpdateSubmoduleRemote { repo_id, path });
+            }
+            ContextMenuAction::RunInSubmodules { repo_id, command } => {
+                self.store
+                    .dispatch(Msg::RunInSubmodules { repo_id, command });
+            }
             ContextMenuAction::LoadWorktrees { repo_id } => {
                 self.store.dispatch(Msg::LoadWorktrees { repo_id });
             }
+            ContextMenuAction::UnlockWorktree { repo_id, path } => {
+                self.store.dispatch(Msg::UnlockWorktree { repo_id, path });
+            }
+            ContextMenuAction::PruneWorktrees { repo_id } => {
+                self.store.dispatch(Msg::PruneWorktrees { repo_id });
+            }
             ContextMenuAction::Pull { repo_id, mode } => {
                 self.store.dispatch(Msg::Pull { repo_id, mode });
             }
@@ -988,6 +1166,7 @@ impl PopoverHost {
                 target: DiffTarget::WorkingTree {
                     path: path.clone(),
                     area: DiffArea::Unstaged,
+                    old_path: None,
                 },
             });
         }
@@ -113,6 +113,18 @@ pub(super) fn model(
                 }),
             });
         }
+        items.push(ContextMenuItem::Entry {
+            label: "Rename branch…".into(),
+            icon: Some("icons/pencil.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::OpenPopover {
+                kind: PopoverKind::RenameBranchPrompt {
+                    repo_id,
+                    branch: name.clone(),
+                },
+            }),
+        });
         items.push(ContextMenuItem::Entry {
             label: "Delete branch".into(),
             icon: Some("icons/trash.svg".into()),
@@ -28,8 +28,17 @@ fn model_for_view(view: ChangeTrackingView) -> ContextMenuModel {
                 view: ChangeTrackingView::SplitUntracked,
             }),
         },
+        ContextMenuItem::Entry {
+            label: ChangeTrackingView::Tree.menu_label().into(),
+            icon: check(view == ChangeTrackingView::Tree),
+            shortcut: Some("T".into()),
+            disabled: false,
+            action: Box::new(ContextMenuAction::SetChangeTrackingView {
+                view: ChangeTrackingView::Tree,
+            }),
+        },
         ContextMenuItem::Separator,
-        ContextMenuItem::Label("Controls how Untracked files are grouped".into()),
+        ContextMenuItem::Label("Controls how changed files are grouped".into()),
     ])
 }
 
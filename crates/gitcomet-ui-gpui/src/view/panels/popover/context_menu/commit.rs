@@ -1,5 +1,32 @@
 use super::*;
 
+/// The full message of `commit_id` to open the commit message editor with,
+/// once its details are loaded and the backend found nothing blocking a
+/// reword. The log only carries summaries, so there is no fallback.
+fn reword_commit_message(repo: &RepoState, commit_id: &CommitId) -> Option<String> {
+    let history = &repo.history_state;
+    if history.reword_blocker_commit.as_ref() != Some(commit_id)
+        || history.reword_blocker != Loadable::Ready(None)
+    {
+        return None;
+    }
+    match &history.commit_details {
+        Loadable::Ready(details) if details.id == *commit_id => Some(details.message.clone()),
+        _ => None,
+    }
+}
+
+fn reword_commit_blocker<'a>(repo: &'a RepoState, commit_id: &CommitId) -> Option<&'a str> {
+    match &repo.history_state.reword_blocker {
+        Loadable::Ready(Some(reason))
+            if repo.history_state.reword_blocker_commit.as_ref() == Some(commit_id) =>
+        {
+            Some(reason)
+        }
+        _ => None,
+    }
+}
+
 pub(super) fn model(this: &PopoverHost, repo_id: RepoId, commit_id: &CommitId) -> ContextMenuModel {
     let sha = commit_id.as_ref().to_string();
     let short: SharedString = sha.get(0..8).unwrap_or(&sha).to_string().into();
@@ -16,6 +43,19 @@ pub(super) fn model(this: &PopoverHost, repo_id: RepoId, commit_id: &CommitId) -
         })
         .unwrap_or_default();
 
+    let repo = this.state.repos.iter().find(|r| r.id == repo_id);
+    let has_staged_changes = repo
+        .and_then(|r| r.staged_status_entries())
+        .is_some_and(|entries| !entries.is_empty());
+    let upstream = repo.and_then(|r| match (&r.head_branch, &r.branches) {
+        (Loadable::Ready(head), Loadable::Ready(branches)) => branches
+            .iter()
+            .find(|branch| branch.name == *head)
+            .and_then(|branch| branch.upstream.as_ref())
+            .map(|upstream| format!("{}/{}", upstream.remote, upstream.branch)),
+        _ => None,
+    });
+
     let mut items = vec![ContextMenuItem::Header(format!("Commit {short}").into())];
     if !commit_summary.is_empty() {
         items.push(ContextMenuItem::Label(commit_summary.into()));
@@ -31,6 +71,19 @@ pub(super) fn model(this: &PopoverHost, repo_id: RepoId, commit_id: &CommitId) -
             target: DiffTarget::Commit {
                 commit_id: commit_id.clone(),
                 path: None,
+                old_path: None,
+            },
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: "Browse files".into(),
+        icon: Some("icons/folder.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::RevisionTree {
+                repo_id,
+                rev: sha.clone(),
             },
         }),
     });
@@ -86,6 +139,53 @@ pub(super) fn model(this: &PopoverHost, repo_id: RepoId, commit_id: &CommitId) -
             commit_id: commit_id.clone(),
         }),
     });
+    let reword_message = repo.and_then(|r| reword_commit_message(r, commit_id));
+    items.push(ContextMenuItem::Entry {
+        label: "Edit message…".into(),
+        icon: Some("icons/pencil.svg".into()),
+        shortcut: Some("E".into()),
+        disabled: reword_message.is_none(),
+        action: Box::new(ContextMenuAction::RewordCommit {
+            repo_id,
+            commit_id: commit_id.clone(),
+            message: reword_message.unwrap_or_default(),
+        }),
+    });
+    if let Some(reason) = repo.and_then(|r| reword_commit_blocker(r, commit_id)) {
+        items.push(ContextMenuItem::Label(reason.to_string().into()));
+    }
+
+    items.push(ContextMenuItem::Separator);
+    for (label, shortcut, kind) in [
+        ("Create fixup! commit for this", "F", FixupCommitKind::Fixup),
+        (
+            "Create squash! commit for this",
+            "S",
+            FixupCommitKind::Squash,
+        ),
+    ] {
+        items.push(ContextMenuItem::Entry {
+            label: label.into(),
+            icon: Some("icons/plus.svg".into()),
+            shortcut: Some(shortcut.into()),
+            disabled: !has_staged_changes,
+            action: Box::new(ContextMenuAction::CreateFixupCommit {
+                repo_id,
+                commit_id: commit_id.clone(),
+                kind,
+            }),
+        });
+    }
+    items.push(ContextMenuItem::Entry {
+        label: match upstream.as_deref() {
+            Some(upstream) => format!("Autosquash onto {upstream}").into(),
+            None => "Autosquash onto upstream".into(),
+        },
+        icon: Some("icons/arrow_right.svg".into()),
+        shortcut: Some("A".into()),
+        disabled: upstream.is_none(),
+        action: Box::new(ContextMenuAction::AutosquashRebase { repo_id }),
+    });
 
     items.push(ContextMenuItem::Separator);
     for (label, icon, mode) in [
@@ -122,3 +222,71 @@ pub(super) fn model(this: &PopoverHost, repo_id: RepoId, commit_id: &CommitId) -
 
     ContextMenuModel::new(items)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gitcomet_core::domain::{CommitDetails, RepoSpec};
+    use gitcomet_state::model::RepoState;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn repo_with_details(commit_id: &CommitId, message: &str) -> RepoState {
+        let mut repo = RepoState::new_opening(
+            RepoId(1),
+            RepoSpec {
+                workdir: PathBuf::from("/tmp/repo"),
+            },
+        );
+        repo.history_state.commit_details = Loadable::Ready(Arc::new(CommitDetails {
+            id: commit_id.clone(),
+            message: message.to_string(),
+            committed_at: String::new(),
+            parent_ids: Vec::new(),
+            files: Vec::new(),
+        }));
+        repo.history_state.reword_blocker_commit = Some(commit_id.clone());
+        repo.history_state.reword_blocker = Loadable::Ready(None);
+        repo
+    }
+
+    #[test]
+    fn reword_uses_the_full_message_once_details_are_ready() {
+        let commit_id = CommitId("a".into());
+        let repo = repo_with_details(&commit_id, "subject\n\nbody\n");
+
+        assert_eq!(
+            reword_commit_message(&repo, &commit_id).as_deref(),
+            Some("subject\n\nbody\n")
+        );
+    }
+
+    #[test]
+    fn reword_waits_for_details_of_the_same_commit() {
+        let commit_id = CommitId("a".into());
+        let mut repo = repo_with_details(&CommitId("b".into()), "other");
+        repo.history_state.reword_blocker_commit = Some(commit_id.clone());
+        assert_eq!(reword_commit_message(&repo, &commit_id), None);
+
+        repo.history_state.commit_details = Loadable::Loading;
+        assert_eq!(reword_commit_message(&repo, &commit_id), None);
+    }
+
+    #[test]
+    fn reword_waits_for_an_unblocked_check() {
+        let commit_id = CommitId("a".into());
+        let mut repo = repo_with_details(&commit_id, "subject");
+
+        repo.history_state.reword_blocker = Loadable::Loading;
+        assert_eq!(reword_commit_message(&repo, &commit_id), None);
+        assert_eq!(reword_commit_blocker(&repo, &commit_id), None);
+
+        let reason = "a is already in origin/main.";
+        repo.history_state.reword_blocker = Loadable::Ready(Some(reason.to_string()));
+        assert_eq!(reword_commit_message(&repo, &commit_id), None);
+        assert_eq!(reword_commit_blocker(&repo, &commit_id), Some(reason));
+
+        repo.history_state.reword_blocker_commit = Some(CommitId("b".into()));
+        assert_eq!(reword_commit_blocker(&repo, &commit_id), None);
+    }
+}
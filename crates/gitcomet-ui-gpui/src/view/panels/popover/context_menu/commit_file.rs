@@ -6,7 +6,7 @@ pub(super) fn model(
     commit_id: &CommitId,
     path: &std::path::Path,
 ) -> ContextMenuModel {
-    let is_submodule = this
+    let (is_submodule, old_path) = this
         .state
         .repos
         .iter()
@@ -16,10 +16,10 @@ pub(super) fn model(
                 .files
                 .iter()
                 .find(|file| file.path == path)
-                .map(|file| file.is_submodule),
+                .map(|file| (file.is_submodule, file.old_path.clone())),
             _ => None,
         })
-        .unwrap_or(false);
+        .unwrap_or((false, None));
     let copy_path_text = this
         .resolve_workdir_path(repo_id, path)
         .map(|p| path_text_for_copy(&p))
@@ -50,6 +50,7 @@ pub(super) fn model(
                 target: DiffTarget::Commit {
                     commit_id: commit_id.clone(),
                     path: Some(path.to_path_buf()),
+                    old_path: None,
                 },
             }),
         });
@@ -97,6 +98,7 @@ pub(super) fn model(
             target: DiffTarget::Commit {
                 commit_id: commit_id.clone(),
                 path: Some(path.to_path_buf()),
+                old_path,
             },
         }),
     });
@@ -132,6 +134,19 @@ pub(super) fn model(
             },
         }),
     });
+    items.push(ContextMenuItem::Entry {
+        label: "Blame".into(),
+        icon: Some("icons/file.svg".into()),
+        shortcut: Some("B".into()),
+        disabled: false,
+        action: Box::new(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::Blame {
+                repo_id,
+                path: path.to_path_buf(),
+                rev: Some(commit_id.as_ref().to_string()),
+            },
+        }),
+    });
     items.push(ContextMenuItem::Entry {
         label: "Copy path".into(),
         icon: Some("icons/copy.svg".into()),
@@ -0,0 +1,95 @@
+use super::*;
+
+pub(super) fn model(
+    this: &PopoverHost,
+    repo_id: RepoId,
+    commit_ids: &[CommitId],
+) -> ContextMenuModel {
+    let count = commit_ids.len();
+    let (range, squash_range) = this
+        .active_repo()
+        .and_then(|r| match &r.log {
+            Loadable::Ready(page) => Some((
+                crate::view::panes::history_selection_commit_range(&page.commits, commit_ids),
+                crate::view::panes::history_selection_squash_range(&page.commits, commit_ids),
+            )),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    let mut items = vec![ContextMenuItem::Header(
+        format!("{count} commits selected").into(),
+    )];
+    items.push(ContextMenuItem::Separator);
+    if let Some((from_commit_id, to_commit_id)) = range {
+        items.push(ContextMenuItem::Entry {
+            label: "Open range diff".into(),
+            icon: Some("icons/open_external.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::SelectDiff {
+                repo_id,
+                target: DiffTarget::CommitRange {
+                    from_commit_id,
+                    to_commit_id,
+                    path: None,
+                },
+            }),
+        });
+    }
+    items.push(ContextMenuItem::Entry {
+        label: "Export patch series…".into(),
+        icon: Some("icons/arrow_down.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::ExportPatchSeries {
+            repo_id,
+            commit_ids: commit_ids.to_vec(),
+            cover_letter: false,
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: "Export for email with cover letter…".into(),
+        icon: Some("icons/arrow_down.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::ExportPatchSeries {
+            repo_id,
+            commit_ids: commit_ids.to_vec(),
+            cover_letter: true,
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: format!("Cherry-pick {count} commits").into(),
+        icon: Some("icons/arrow_up.svg".into()),
+        shortcut: Some("P".into()),
+        disabled: false,
+        action: Box::new(ContextMenuAction::CherryPickCommits {
+            repo_id,
+            commit_ids: commit_ids.to_vec(),
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: format!("Revert {count} commits").into(),
+        icon: Some("icons/undo.svg".into()),
+        shortcut: Some("R".into()),
+        disabled: false,
+        action: Box::new(ContextMenuAction::RevertCommits {
+            repo_id,
+            commit_ids: commit_ids.to_vec(),
+        }),
+    });
+    items.push(ContextMenuItem::Separator);
+    items.push(ContextMenuItem::Entry {
+        label: "Squash into one commit".into(),
+        icon: Some("icons/arrow_right.svg".into()),
+        shortcut: Some("S".into()),
+        disabled: squash_range.is_none(),
+        action: Box::new(ContextMenuAction::SquashCommits {
+            repo_id,
+            commit_ids: commit_ids.to_vec(),
+        }),
+    });
+
+    ContextMenuModel::new(items)
+}
@@ -1,12 +1,175 @@
 use super::*;
+use gitcomet_core::services::{DiffIgnoreOptions, RenameDetection};
 
-pub(super) fn model(host: &PopoverHost) -> ContextMenuModel {
-    model_for_whitespace_mode(host.diff_whitespace_mode)
+/// Similarity thresholds offered for rename and copy detection.
+const RENAME_THRESHOLDS: [u8; 4] = [30, 50, 75, 90];
+
+pub(super) fn model(host: &PopoverHost, cx: &gpui::Context<PopoverHost>) -> ContextMenuModel {
+    let mut model = model_for_whitespace_mode(host.diff_whitespace_mode);
+    let pane = host.main_pane.read(cx);
+    push_alignment_mode_item(&mut model, pane.diff_alignment_mode);
+    push_moved_lines_items(
+        &mut model,
+        pane.diff_highlight_moved_lines,
+        pane.diff_moved_lines_ignore_whitespace,
+        !pane.diff_moved_lines.is_empty(),
+    );
+    if let Some(repo) = host
+        .state
+        .active_repo
+        .and_then(|repo_id| host.state.repos.iter().find(|r| r.id == repo_id))
+    {
+        push_diff_ignore_items(&mut model, repo.id, &repo.diff_ignore_options);
+    }
+    push_rename_detection_items(&mut model, host.state.rename_detection.unwrap_or_default());
+    model
+}
+
+fn push_alignment_mode_item(model: &mut ContextMenuModel, mode: DiffAlignmentMode) {
+    model.items.push(ContextMenuItem::Entry {
+        label: "Structural diff".into(),
+        icon: (mode == DiffAlignmentMode::Structural).then_some("icons/check.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::SetDiffAlignmentMode {
+            mode: mode.toggled(),
+        }),
+    });
+}
+
+fn push_moved_lines_items(
+    model: &mut ContextMenuModel,
+    highlight: bool,
+    ignore_whitespace: bool,
+    has_moves: bool,
+) {
+    model.items.extend([
+        ContextMenuItem::Separator,
+        ContextMenuItem::Entry {
+            label: "Highlight moved lines".into(),
+            icon: highlight.then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::SetDiffHighlightMovedLines {
+                enabled: !highlight,
+            }),
+        },
+        ContextMenuItem::Entry {
+            label: "Ignore whitespace in moved lines".into(),
+            icon: ignore_whitespace.then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: !highlight,
+            action: Box::new(ContextMenuAction::SetDiffMovedLinesIgnoreWhitespace {
+                enabled: !ignore_whitespace,
+            }),
+        },
+        ContextMenuItem::Entry {
+            label: "Jump to other end of move".into(),
+            icon: None,
+            shortcut: Some("Alt+M".into()),
+            disabled: !has_moves,
+            action: Box::new(ContextMenuAction::JumpToMovedCounterpart),
+        },
+    ]);
+}
+
+fn push_diff_ignore_items(
+    model: &mut ContextMenuModel,
+    repo_id: RepoId,
+    options: &DiffIgnoreOptions,
+) {
+    let entry =
+        |label: &'static str, checked: bool, next: DiffIgnoreOptions| ContextMenuItem::Entry {
+            label: label.into(),
+            icon: checked.then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::SetDiffIgnoreOptions {
+                repo_id,
+                options: next,
+            }),
+        };
+
+    model.items.extend([
+        ContextMenuItem::Separator,
+        entry(
+            "Ignore blank lines",
+            options.ignore_blank_lines,
+            DiffIgnoreOptions {
+                ignore_blank_lines: !options.ignore_blank_lines,
+                ..options.clone()
+            },
+        ),
+        entry(
+            "Ignore CR at line end",
+            options.ignore_cr_at_eol,
+            DiffIgnoreOptions {
+                ignore_cr_at_eol: !options.ignore_cr_at_eol,
+                ..options.clone()
+            },
+        ),
+        ContextMenuItem::Entry {
+            label: "Ignore matching lines…".into(),
+            icon: (!options.ignore_matching_lines.is_empty()).then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::OpenPopover {
+                kind: PopoverKind::DiffIgnorePatternsPrompt { repo_id },
+            }),
+        },
+    ]);
+}
+
+fn push_rename_detection_items(model: &mut ContextMenuModel, detection: RenameDetection) {
+    let entry =
+        |label: SharedString, checked: bool, next: RenameDetection| ContextMenuItem::Entry {
+            label,
+            icon: checked.then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::SetRenameDetection { detection: next }),
+        };
+
+    model.items.push(ContextMenuItem::Separator);
+    for threshold in RENAME_THRESHOLDS {
+        model.items.push(entry(
+            format!("Rename similarity {threshold}%").into(),
+            detection.rename_threshold == threshold,
+            RenameDetection {
+                rename_threshold: threshold,
+                copy_threshold: threshold,
+                ..detection
+            },
+        ));
+    }
+    model.items.push(entry(
+        "Detect copies".into(),
+        detection.detect_copies,
+        RenameDetection {
+            detect_copies: !detection.detect_copies,
+            ..detection
+        },
+    ));
 }
 
 fn model_for_whitespace_mode(mode: DiffWhitespaceMode) -> ContextMenuModel {
     let show_whitespace = mode == DiffWhitespaceMode::Show;
-    let next_mode = mode.toggled();
+    let ignore_entry = |label: &'static str, ignore_mode: DiffWhitespaceMode| {
+        let checked = mode == ignore_mode;
+        ContextMenuItem::Entry {
+            label: label.into(),
+            icon: checked.then_some("icons/check.svg".into()),
+            shortcut: None,
+            disabled: false,
+            action: Box::new(ContextMenuAction::SetDiffWhitespaceMode {
+                mode: if checked {
+                    DiffWhitespaceMode::Show
+                } else {
+                    ignore_mode
+                },
+            }),
+        }
+    };
 
     ContextMenuModel::new(vec![
         ContextMenuItem::Header("Diff actions".into()),
@@ -16,8 +179,16 @@ fn model_for_whitespace_mode(mode: DiffWhitespaceMode) -> ContextMenuModel {
             icon: show_whitespace.then_some("icons/check.svg".into()),
             shortcut: None,
             disabled: false,
-            action: Box::new(ContextMenuAction::SetDiffWhitespaceMode { mode: next_mode }),
+            action: Box::new(ContextMenuAction::SetDiffWhitespaceMode {
+                mode: mode.toggled(),
+            }),
         },
+        ignore_entry("Ignore all whitespace", DiffWhitespaceMode::Ignore),
+        ignore_entry("Ignore whitespace amount", DiffWhitespaceMode::IgnoreChange),
+        ignore_entry(
+            "Ignore whitespace at line end",
+            DiffWhitespaceMode::IgnoreAtEol,
+        ),
     ])
 }
 
@@ -70,4 +241,202 @@ mod tests {
             )
         }));
     }
+
+    #[test]
+    fn whitespace_ignore_entries_select_one_mode() {
+        let model = model_for_whitespace_mode(DiffWhitespaceMode::IgnoreChange);
+
+        let entry_for = |wanted: &str| {
+            model.items.iter().find_map(|item| match item {
+                ContextMenuItem::Entry {
+                    label,
+                    icon,
+                    action,
+                    ..
+                } if label.as_ref() == wanted => Some((icon.is_some(), action.as_ref())),
+                _ => None,
+            })
+        };
+        assert!(matches!(
+            entry_for("Show whitespace changes"),
+            Some((
+                false,
+                ContextMenuAction::SetDiffWhitespaceMode {
+                    mode: DiffWhitespaceMode::Show
+                }
+            ))
+        ));
+        assert!(matches!(
+            entry_for("Ignore whitespace amount"),
+            Some((
+                true,
+                ContextMenuAction::SetDiffWhitespaceMode {
+                    mode: DiffWhitespaceMode::Show
+                }
+            ))
+        ));
+        assert!(matches!(
+            entry_for("Ignore whitespace at line end"),
+            Some((
+                false,
+                ContextMenuAction::SetDiffWhitespaceMode {
+                    mode: DiffWhitespaceMode::IgnoreAtEol
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn alignment_item_toggles_structural_mode() {
+        let mut model = model_for_whitespace_mode(DiffWhitespaceMode::Show);
+        push_alignment_mode_item(&mut model, DiffAlignmentMode::Lines);
+
+        assert!(model.items.iter().any(|item| {
+            matches!(
+                item,
+                ContextMenuItem::Entry {
+                    label,
+                    icon,
+                    action,
+                    ..
+                } if label.as_ref() == "Structural diff"
+                    && icon.is_none()
+                    && matches!(
+                        action.as_ref(),
+                        ContextMenuAction::SetDiffAlignmentMode {
+                            mode: DiffAlignmentMode::Structural
+                        }
+                    )
+            )
+        }));
+    }
+
+    #[test]
+    fn diff_ignore_items_toggle_repo_options() {
+        let mut model = model_for_whitespace_mode(DiffWhitespaceMode::Show);
+        let options = DiffIgnoreOptions {
+            ignore_cr_at_eol: true,
+            ..DiffIgnoreOptions::default()
+        };
+        push_diff_ignore_items(&mut model, RepoId(7), &options);
+
+        let action_for = |wanted: &str| {
+            model.items.iter().find_map(|item| match item {
+                ContextMenuItem::Entry { label, action, .. } if label.as_ref() == wanted => {
+                    Some(action.as_ref())
+                }
+                _ => None,
+            })
+        };
+        assert!(matches!(
+            action_for("Ignore CR at line end"),
+            Some(ContextMenuAction::SetDiffIgnoreOptions {
+                repo_id: RepoId(7),
+                options,
+            }) if !options.ignore_cr_at_eol
+        ));
+        assert!(matches!(
+            action_for("Ignore blank lines"),
+            Some(ContextMenuAction::SetDiffIgnoreOptions { options, .. })
+                if options.ignore_blank_lines && options.ignore_cr_at_eol
+        ));
+        assert!(action_for("Ignore whitespace amount").is_none());
+        assert!(matches!(
+            action_for("Ignore matching lines…"),
+            Some(ContextMenuAction::OpenPopover {
+                kind: PopoverKind::DiffIgnorePatternsPrompt { repo_id: RepoId(7) }
+            })
+        ));
+    }
+
+    #[test]
+    fn rename_detection_items_pick_threshold_and_toggle_copies() {
+        let mut model = model_for_whitespace_mode(DiffWhitespaceMode::Show);
+        push_rename_detection_items(&mut model, RenameDetection::default());
+
+        let entry_for = |wanted: &str| {
+            model.items.iter().find_map(|item| match item {
+                ContextMenuItem::Entry {
+                    label,
+                    icon,
+                    action,
+                    ..
+                } if label.as_ref() == wanted => Some((icon.is_some(), action.as_ref())),
+                _ => None,
+            })
+        };
+        assert!(matches!(
+            entry_for("Rename similarity 50%"),
+            Some((true, ContextMenuAction::SetRenameDetection { .. }))
+        ));
+        assert!(matches!(
+            entry_for("Rename similarity 90%"),
+            Some((
+                false,
+                ContextMenuAction::SetRenameDetection {
+                    detection: RenameDetection {
+                        rename_threshold: 90,
+                        detect_copies: false,
+                        copy_threshold: 90,
+                    }
+                }
+            ))
+        ));
+        assert!(matches!(
+            entry_for("Detect copies"),
+            Some((
+                false,
+                ContextMenuAction::SetRenameDetection {
+                    detection: RenameDetection {
+                        rename_threshold: 50,
+                        detect_copies: true,
+                        ..
+                    }
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn moved_lines_items_follow_highlight_state() {
+        let mut model = model_for_whitespace_mode(DiffWhitespaceMode::Show);
+        push_moved_lines_items(&mut model, false, false, false);
+
+        assert!(model.items.iter().any(|item| {
+            matches!(
+                item,
+                ContextMenuItem::Entry {
+                    label,
+                    icon,
+                    action,
+                    ..
+                } if label.as_ref() == "Highlight moved lines"
+                    && icon.is_none()
+                    && matches!(
+                        action.as_ref(),
+                        ContextMenuAction::SetDiffHighlightMovedLines { enabled: true }
+                    )
+            )
+        }));
+        assert!(model.items.iter().any(|item| {
+            matches!(
+                item,
+                ContextMenuItem::Entry {
+                    label,
+                    disabled: true,
+                    ..
+                } if label.as_ref() == "Ignore whitespace in moved lines"
+            )
+        }));
+        assert!(model.items.iter().any(|item| {
+            matches!(
+                item,
+                ContextMenuItem::Entry {
+                    label,
+                    disabled: true,
+                    ..
+                } if label.as_ref() == "Jump to other end of move"
+            )
+        }));
+    }
 }
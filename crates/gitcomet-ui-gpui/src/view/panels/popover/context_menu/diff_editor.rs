@@ -12,6 +12,7 @@ pub(super) fn model(
     lines_count: usize,
     copy_text: &Option<String>,
     copy_target: Option<(usize, DiffTextRegion)>,
+    line_range: &Option<(u32, u32, Option<String>)>,
 ) -> ContextMenuModel {
     let title: SharedString = path
         .as_ref()
@@ -130,6 +131,27 @@ pub(super) fn model(
                 path: path.clone(),
             }),
         });
+        if let Some((start, end, rev)) = line_range {
+            items.push(ContextMenuItem::Entry {
+                label: if start == end {
+                    "Line history".into()
+                } else {
+                    format!("Line history ({start}–{end})").into()
+                },
+                icon: Some("icons/refresh.svg".into()),
+                shortcut: None,
+                disabled: false,
+                action: Box::new(ContextMenuAction::OpenPopover {
+                    kind: PopoverKind::LineHistory {
+                        repo_id,
+                        path: path.clone(),
+                        start: *start,
+                        end: *end,
+                        rev: rev.clone(),
+                    },
+                }),
+            });
+        }
         items.push(ContextMenuItem::Separator);
     }
     items.push(ContextMenuItem::Entry {
@@ -87,6 +87,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: std::path::PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let (disabled, label, icon, shortcut) = diff_hunk_primary_metadata(Some(&target));
@@ -108,6 +109,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: std::path::PathBuf::from("src/lib.rs"),
             area: DiffArea::Staged,
+            old_path: None,
         };
 
         let (disabled, label, icon, shortcut) = diff_hunk_primary_metadata(Some(&target));
@@ -125,6 +125,13 @@ pub(super) fn model(
                 target: DiffTarget::WorkingTree {
                     path: path.to_path_buf(),
                     area,
+                    old_path: this
+                        .state
+                        .repos
+                        .iter()
+                        .find(|r| r.id == repo_id)
+                        .and_then(|repo| repo.status_entry_for_path(area, path))
+                        .and_then(|status| status.old_path.clone()),
                 },
             })
         },
@@ -161,6 +168,19 @@ pub(super) fn model(
             },
         }),
     });
+    items.push(ContextMenuItem::Entry {
+        label: "Blame".into(),
+        icon: Some("icons/file.svg".into()),
+        shortcut: Some("B".into()),
+        disabled: false,
+        action: Box::new(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::Blame {
+                repo_id,
+                path: path.to_path_buf(),
+                rev: None,
+            },
+        }),
+    });
     if is_conflicted {
         items.push(ContextMenuItem::Separator);
         let n = selected_count;
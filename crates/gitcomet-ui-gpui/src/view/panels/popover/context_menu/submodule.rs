@@ -7,6 +7,7 @@ pub(super) struct SubmoduleMenuState {
     pub(super) status: Option<SubmoduleStatus>,
     pub(super) can_open: bool,
     pub(super) can_change_pointer: bool,
+    pub(super) can_update_remote: bool,
     pub(super) show_load: bool,
 }
 
@@ -45,11 +46,18 @@ pub(super) fn menu_state(
             Some(SubmoduleStatus::MergeConflict | SubmoduleStatus::MissingMapping)
         );
 
+    let can_update_remote = open_path.is_some()
+        && !matches!(
+            status,
+            Some(SubmoduleStatus::MergeConflict | SubmoduleStatus::MissingMapping)
+        );
+
     SubmoduleMenuState {
         open_path,
         status,
         can_open,
         can_change_pointer,
+        can_update_remote,
         show_load,
     }
 }
@@ -118,6 +126,31 @@ pub(super) fn model(
         }),
     });
 
+    items.push(ContextMenuItem::Entry {
+        label: "Tracked branch…".into(),
+        icon: Some("icons/git_branch.svg".into()),
+        shortcut: None,
+        disabled: !state.can_update_remote,
+        action: Box::new(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::submodule(
+                repo_id,
+                SubmodulePopoverKind::TrackBranchPrompt {
+                    path: path.to_path_buf(),
+                },
+            ),
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: "Update from remote".into(),
+        icon: Some("icons/refresh.svg".into()),
+        shortcut: None,
+        disabled: !state.can_update_remote,
+        action: Box::new(ContextMenuAction::UpdateSubmoduleRemote {
+            repo_id,
+            path: path.to_path_buf(),
+        }),
+    });
+
     items.push(ContextMenuItem::Separator);
     items.push(ContextMenuItem::Entry {
         label: "Remove…".into(),
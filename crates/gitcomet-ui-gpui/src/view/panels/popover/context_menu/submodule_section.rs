@@ -1,4 +1,5 @@
 use super::*;
+use gitcomet_core::services::SubmoduleBatchCommand;
 
 pub(super) fn model(repo_id: RepoId) -> ContextMenuModel {
     let mut items = vec![ContextMenuItem::Header("Submodules".into())];
@@ -19,6 +20,44 @@ pub(super) fn model(repo_id: RepoId) -> ContextMenuModel {
         disabled: false,
         action: Box::new(ContextMenuAction::UpdateSubmodules { repo_id }),
     });
+    items.push(ContextMenuItem::Entry {
+        label: "Sync submodule URLs".into(),
+        icon: Some("icons/link.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::SyncSubmodules { repo_id }),
+    });
+    items.push(ContextMenuItem::Separator);
+    items.push(ContextMenuItem::Entry {
+        label: "Fetch all submodules".into(),
+        icon: Some("icons/arrow_down.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::RunInSubmodules {
+            repo_id,
+            command: SubmoduleBatchCommand::Fetch,
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: "Pull all submodules".into(),
+        icon: Some("icons/arrow_down.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::RunInSubmodules {
+            repo_id,
+            command: SubmoduleBatchCommand::Pull,
+        }),
+    });
+    items.push(ContextMenuItem::Entry {
+        label: "Run git command in each…".into(),
+        icon: Some("icons/menu.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::submodule(repo_id, SubmodulePopoverKind::RunCommandPrompt),
+        }),
+    });
+    items.push(ContextMenuItem::Separator);
     items.push(ContextMenuItem::Entry {
         label: "Open submodule…".into(),
         icon: Some("icons/open_external.svg".into()),
@@ -40,3 +79,35 @@ pub(super) fn model(repo_id: RepoId) -> ContextMenuModel {
 
     ContextMenuModel::new(items)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_menu_offers_bulk_submodule_commands() {
+        let repo_id = RepoId(3);
+        let model = super::model(repo_id);
+
+        let batch_commands: Vec<&SubmoduleBatchCommand> = model
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ContextMenuItem::Entry { action, .. } => match action.as_ref() {
+                    ContextMenuAction::RunInSubmodules { command, .. } => Some(command),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            batch_commands,
+            vec![&SubmoduleBatchCommand::Fetch, &SubmoduleBatchCommand::Pull]
+        );
+        assert!(model.items.iter().any(|item| matches!(
+            item,
+            ContextMenuItem::Entry { action, .. }
+                if matches!(action.as_ref(), ContextMenuAction::SyncSubmodules { repo_id: id } if *id == repo_id)
+        )));
+    }
+}
@@ -1,30 +1,122 @@
 use super::*;
 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct WorktreeMenuState {
+    /// The main worktree cannot be locked or moved.
+    pub(super) is_main: bool,
+    pub(super) locked: Option<String>,
+    pub(super) prunable: Option<String>,
+}
+
+pub(super) fn menu_state(
+    this: &PopoverHost,
+    repo_id: RepoId,
+    path: &std::path::Path,
+) -> WorktreeMenuState {
+    let worktrees = this
+        .state
+        .repos
+        .iter()
+        .find(|repo| repo.id == repo_id)
+        .and_then(|repo| match &repo.worktrees {
+            Loadable::Ready(worktrees) => Some(worktrees.clone()),
+            _ => None,
+        });
+    let Some(worktrees) = worktrees else {
+        return WorktreeMenuState::default();
+    };
+    worktrees
+        .iter()
+        .enumerate()
+        .find(|(_, worktree)| worktree.path == path)
+        .map(|(ix, worktree)| WorktreeMenuState {
+            is_main: ix == 0,
+            locked: worktree.locked.clone(),
+            prunable: worktree.prunable.clone(),
+        })
+        .unwrap_or_default()
+}
+
 pub(super) fn model(
     repo_id: RepoId,
     path: &std::path::Path,
     branch: Option<&str>,
+    state: &WorktreeMenuState,
 ) -> ContextMenuModel {
     let mut items = vec![ContextMenuItem::Header("Worktree".into())];
     items.push(ContextMenuItem::Label(
         components::ContextMenuText::path_single_line(path.display().to_string()),
     ));
+    if let Some(reason) = &state.locked {
+        items.push(ContextMenuItem::Label(if reason.is_empty() {
+            "Locked".into()
+        } else {
+            format!("Locked: {reason}").into()
+        }));
+    }
+    if let Some(reason) = &state.prunable {
+        items.push(ContextMenuItem::Label(format!("Prunable: {reason}").into()));
+    }
     items.push(ContextMenuItem::Separator);
     items.push(ContextMenuItem::Entry {
         label: "Open in new tab".into(),
         icon: Some("icons/open_external.svg".into()),
         shortcut: None,
-        disabled: false,
+        disabled: state.prunable.is_some(),
         action: Box::new(ContextMenuAction::OpenRepo {
             path: path.to_path_buf(),
         }),
     });
+    if !state.is_main {
+        items.push(ContextMenuItem::Separator);
+        if state.locked.is_some() {
+            items.push(ContextMenuItem::Entry {
+                label: "Unlock".into(),
+                icon: None,
+                shortcut: None,
+                disabled: false,
+                action: Box::new(ContextMenuAction::UnlockWorktree {
+                    repo_id,
+                    path: path.to_path_buf(),
+                }),
+            });
+        } else {
+            items.push(ContextMenuItem::Entry {
+                label: "Lock…".into(),
+                icon: None,
+                shortcut: None,
+                disabled: false,
+                action: Box::new(ContextMenuAction::OpenPopover {
+                    kind: PopoverKind::worktree(
+                        repo_id,
+                        WorktreePopoverKind::LockPrompt {
+                            path: path.to_path_buf(),
+                        },
+                    ),
+                }),
+            });
+        }
+        items.push(ContextMenuItem::Entry {
+            label: "Move…".into(),
+            icon: Some("icons/folder.svg".into()),
+            shortcut: None,
+            disabled: state.locked.is_some() || state.prunable.is_some(),
+            action: Box::new(ContextMenuAction::OpenPopover {
+                kind: PopoverKind::worktree(
+                    repo_id,
+                    WorktreePopoverKind::MovePrompt {
+                        path: path.to_path_buf(),
+                    },
+                ),
+            }),
+        });
+    }
     items.push(ContextMenuItem::Separator);
     items.push(ContextMenuItem::Entry {
         label: "Remove…".into(),
         icon: Some("icons/trash.svg".into()),
         shortcut: None,
-        disabled: false,
+        disabled: state.locked.is_some(),
         action: Box::new(ContextMenuAction::OpenPopover {
             kind: PopoverKind::worktree(
                 repo_id,
@@ -47,7 +139,7 @@ mod tests {
     fn model_includes_open_in_new_tab() {
         let repo_id = RepoId(1);
         let path = std::path::PathBuf::from("/tmp/worktree");
-        let model = model(repo_id, &path, None);
+        let model = model(repo_id, &path, None, &WorktreeMenuState::default());
 
         let open_action = model
             .items
@@ -72,7 +164,12 @@ mod tests {
     fn model_routes_remove_through_branch_aware_confirm_when_branch_is_provided() {
         let repo_id = RepoId(1);
         let path = std::path::PathBuf::from("/tmp/worktree");
-        let model = model(repo_id, &path, Some("feature/workspace"));
+        let model = model(
+            repo_id,
+            &path,
+            Some("feature/workspace"),
+            &WorktreeMenuState::default(),
+        );
 
         let remove_action = model
             .items
@@ -98,4 +195,66 @@ mod tests {
             } if rid == repo_id && remove_path == path && branch == "feature/workspace"
         ));
     }
+
+    #[test]
+    fn model_offers_unlock_and_blocks_move_for_locked_worktree() {
+        let repo_id = RepoId(1);
+        let path = std::path::PathBuf::from("/tmp/worktree");
+        let entry = |model: &ContextMenuModel, wanted: &str| {
+            model.items.iter().find_map(|item| match item {
+                ContextMenuItem::Entry {
+                    label,
+                    disabled,
+                    action,
+                    ..
+                } if label.as_ref() == wanted => Some((*disabled, (**action).clone())),
+                _ => None,
+            })
+        };
+
+        let unlocked = model(repo_id, &path, None, &WorktreeMenuState::default());
+        assert!(matches!(
+            entry(&unlocked, "Lock…"),
+            Some((
+                false,
+                ContextMenuAction::OpenPopover {
+                    kind: PopoverKind::Repo {
+                        kind: RepoPopoverKind::Worktree(WorktreePopoverKind::LockPrompt { .. }),
+                        ..
+                    },
+                },
+            ))
+        ));
+        assert!(matches!(entry(&unlocked, "Move…"), Some((false, _))));
+
+        let locked = model(
+            repo_id,
+            &path,
+            None,
+            &WorktreeMenuState {
+                locked: Some("on a usb drive".to_string()),
+                ..WorktreeMenuState::default()
+            },
+        );
+        assert!(entry(&locked, "Lock…").is_none());
+        assert!(matches!(
+            entry(&locked, "Unlock"),
+            Some((false, ContextMenuAction::UnlockWorktree { path: unlock_path, .. }))
+                if unlock_path == path
+        ));
+        assert!(matches!(entry(&locked, "Move…"), Some((true, _))));
+        assert!(matches!(entry(&locked, "Remove…"), Some((true, _))));
+
+        let main = model(
+            repo_id,
+            &path,
+            None,
+            &WorktreeMenuState {
+                is_main: true,
+                ..WorktreeMenuState::default()
+            },
+        );
+        assert!(entry(&main, "Lock…").is_none());
+        assert!(entry(&main, "Move…").is_none());
+    }
 }
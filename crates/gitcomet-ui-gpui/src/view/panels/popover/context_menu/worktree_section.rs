@@ -28,6 +28,13 @@ pub(super) fn model(repo_id: RepoId) -> ContextMenuModel {
             kind: PopoverKind::worktree(repo_id, WorktreePopoverKind::OpenPicker),
         }),
     });
+    items.push(ContextMenuItem::Entry {
+        label: "Prune stale worktrees".into(),
+        icon: Some("icons/broom.svg".into()),
+        shortcut: None,
+        disabled: false,
+        action: Box::new(ContextMenuAction::PruneWorktrees { repo_id }),
+    });
     items.push(ContextMenuItem::Entry {
         label: "Remove worktree…".into(),
         icon: Some("icons/trash.svg".into()),
@@ -0,0 +1,79 @@
+use super::*;
+use gitcomet_core::services::DiffIgnoreOptions;
+
+pub(super) fn panel(this: &mut PopoverHost, cx: &mut gpui::Context<PopoverHost>) -> gpui::Div {
+    let theme = this.theme;
+    let invalid_pattern = DiffIgnoreMatcher::invalid_pattern(&DiffIgnoreOptions {
+        ignore_matching_lines: this.diff_ignore_patterns_from_input(cx),
+        ..DiffIgnoreOptions::default()
+    })
+    .map(str::to_string);
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(420.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Ignore matching lines"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child(
+                    "One POSIX extended regular expression per line, as for git diff -I. Changes whose lines all match are hidden.",
+                ),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .h(scaled_px(120.0))
+                .child(this.diff_ignore_patterns_input.clone()),
+        )
+        .when_some(invalid_pattern, |d, pattern| {
+            d.child(
+                div()
+                    .px_2()
+                    .pb_1()
+                    .text_xs()
+                    .text_color(theme.colors.danger)
+                    .child(format!("Invalid POSIX extended regular expression: {pattern}")),
+            )
+        })
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("diff_ignore_patterns_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_prompt_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new("diff_ignore_patterns_save", "Save")
+                        .style(components::ButtonStyle::Filled)
+                        .on_click(theme, cx, |this, _e, _w, cx| {
+                            this.submit_diff_ignore_patterns(cx);
+                        }),
+                ),
+        )
+}
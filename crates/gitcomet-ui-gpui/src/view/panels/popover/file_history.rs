@@ -125,6 +125,7 @@ pub(super) fn panel(
                             target: DiffTarget::Commit {
                                 commit_id,
                                 path: Some(path.clone()),
+                                old_path: None,
                             },
                         });
                         this.close_popover(cx);
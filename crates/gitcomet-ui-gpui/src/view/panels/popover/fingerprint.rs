@@ -141,11 +141,18 @@ fn repo_for_popover<'a>(state: &'a AppState, popover: &PopoverKind) -> Option<&'
         | PopoverKind::StashDropConfirm { repo_id, .. }
         | PopoverKind::StashMenu { repo_id, .. }
         | PopoverKind::CreateTagPrompt { repo_id, .. }
+        | PopoverKind::DiffIgnorePatternsPrompt { repo_id }
+        | PopoverKind::RenameBranchPrompt { repo_id, .. }
+        | PopoverKind::PatchSeriesImport { repo_id }
         | PopoverKind::Repo { repo_id, .. }
         | PopoverKind::FileHistory { repo_id, .. }
+        | PopoverKind::Blame { repo_id, .. }
+        | PopoverKind::RevisionTree { repo_id, .. }
+        | PopoverKind::LineHistory { repo_id, .. }
         | PopoverKind::PushSetUpstreamPrompt { repo_id, .. }
         | PopoverKind::ForcePushConfirm { repo_id }
         | PopoverKind::MergeAbortConfirm { repo_id }
+        | PopoverKind::ConflictDashboard { repo_id }
         | PopoverKind::ConflictSaveStageConfirm { repo_id, .. }
         | PopoverKind::ForceDeleteBranchConfirm { repo_id, .. }
         | PopoverKind::ForceRemoveWorktreeConfirm { repo_id, .. }
@@ -156,6 +163,7 @@ fn repo_for_popover<'a>(state: &'a AppState, popover: &PopoverKind) -> Option<&'
         | PopoverKind::DiffHunkMenu { repo_id, .. }
         | PopoverKind::DiffEditorMenu { repo_id, .. }
         | PopoverKind::CommitMenu { repo_id, .. }
+        | PopoverKind::CommitSelectionMenu { repo_id, .. }
         | PopoverKind::StatusFileMenu { repo_id, .. }
         | PopoverKind::BranchMenu { repo_id, .. }
         | PopoverKind::BranchSectionMenu { repo_id, .. }
@@ -178,6 +186,7 @@ fn hash_repo_for_popover<H: Hasher>(repo: &RepoState, popover: &PopoverKind, has
         | PopoverKind::BranchMenu { .. }
         | PopoverKind::BranchSectionMenu { .. }
         | PopoverKind::ForceDeleteBranchConfirm { .. }
+        | PopoverKind::RenameBranchPrompt { .. }
         | PopoverKind::PushSetUpstreamPrompt { .. } => {
             repo.head_branch_rev.hash(hasher);
             repo.branches_rev.hash(hasher);
@@ -219,6 +228,18 @@ fn hash_repo_for_popover<H: Hasher>(repo: &RepoState, popover: &PopoverKind, has
             repo.history_state.file_history_path.hash(hasher);
             view_fingerprint::hash_loadable_arc(&repo.history_state.file_history, hasher);
         }
+        PopoverKind::LineHistory { .. } => {
+            repo.history_state.line_history_path.hash(hasher);
+            repo.history_state.line_history_range.hash(hasher);
+            repo.history_state.line_history_rev.hash(hasher);
+            view_fingerprint::hash_loadable_arc(&repo.history_state.line_history, hasher);
+        }
+        PopoverKind::Blame { .. } => {
+            repo.history_state.blame_path.hash(hasher);
+            repo.history_state.blame_rev.hash(hasher);
+            repo.history_state.blame_options.hash(hasher);
+            view_fingerprint::hash_loadable_arc(&repo.history_state.blame, hasher);
+        }
 
         PopoverKind::DiffHunkMenu { .. }
         | PopoverKind::DiffEditorMenu { .. }
@@ -263,6 +284,13 @@ fn hash_repo_for_popover<H: Hasher>(repo: &RepoState, popover: &PopoverKind, has
             repo.recent_commit_messages_rev.hash(hasher);
         }
 
+        PopoverKind::ConflictDashboard { .. } => {
+            repo.conflict_state.conflict_rev.hash(hasher);
+            repo.status_cache_rev().hash(hasher);
+            repo.merge_message_rev.hash(hasher);
+            repo.operation_state_rev.hash(hasher);
+        }
+
         PopoverKind::CommitOptionsMenu { .. } => {
             repo.log_rev.hash(hasher);
             repo.ops_rev.hash(hasher);
@@ -271,6 +299,22 @@ fn hash_repo_for_popover<H: Hasher>(repo: &RepoState, popover: &PopoverKind, has
             repo.branches_rev.hash(hasher);
         }
 
+        PopoverKind::PatchSeriesImport { .. } => {
+            repo.patch_series_preview_rev.hash(hasher);
+        }
+        PopoverKind::RevisionTree { .. } => {
+            repo.revision_tree_rev.hash(hasher);
+        }
+
+        PopoverKind::CommitMenu { .. } => {
+            repo.history_state.commit_details_rev.hash(hasher);
+            repo.history_state.reword_blocker_commit.hash(hasher);
+            view_fingerprint::hash_loadable_kind(&repo.history_state.reword_blocker, hasher);
+            if let Loadable::Ready(blocker) = &repo.history_state.reword_blocker {
+                blocker.hash(hasher);
+            }
+        }
+
         PopoverKind::TagMenu { .. } => {
             repo.tags_rev.hash(hasher);
             repo.remotes_rev.hash(hasher);
@@ -283,8 +327,9 @@ fn hash_repo_for_popover<H: Hasher>(repo: &RepoState, popover: &PopoverKind, has
         | PopoverKind::ResetPrompt { .. }
         | PopoverKind::CheckoutRemoteBranchPrompt { .. }
         | PopoverKind::CreateTagPrompt { .. }
+        | PopoverKind::DiffIgnorePatternsPrompt { .. }
         | PopoverKind::ForceRemoveWorktreeConfirm { .. }
-        | PopoverKind::CommitMenu { .. }
+        | PopoverKind::CommitSelectionMenu { .. }
         | PopoverKind::CommitFileMenu { .. }
         | PopoverKind::SubmoduleInnerDiffMenu { .. }
         | PopoverKind::StatusFileMenu { .. }
@@ -387,6 +432,19 @@ fn hash_popover_kind<H: Hasher>(kind: &PopoverKind, hasher: &mut H) {
             repo_id.hash(hasher);
             target.hash(hasher);
         }
+        PopoverKind::DiffIgnorePatternsPrompt { repo_id } => {
+            75u8.hash(hasher);
+            repo_id.hash(hasher);
+        }
+        PopoverKind::RenameBranchPrompt { repo_id, branch } => {
+            76u8.hash(hasher);
+            repo_id.hash(hasher);
+            branch.hash(hasher);
+        }
+        PopoverKind::PatchSeriesImport { repo_id } => {
+            79u8.hash(hasher);
+            repo_id.hash(hasher);
+        }
         PopoverKind::Repo { repo_id, kind } => {
             hash_repo_popover_kind(*repo_id, kind, hasher);
         }
@@ -448,6 +506,7 @@ fn hash_popover_kind<H: Hasher>(kind: &PopoverKind, hasher: &mut H) {
             path,
             hunks_count,
             lines_count,
+            line_range,
             ..
         } => {
             41u8.hash(hasher);
@@ -456,6 +515,7 @@ fn hash_popover_kind<H: Hasher>(kind: &PopoverKind, hasher: &mut H) {
             path.hash(hasher);
             hunks_count.hash(hasher);
             lines_count.hash(hasher);
+            line_range.hash(hasher);
         }
         PopoverKind::ConflictResolverInputRowMenu {
             line_label,
@@ -504,6 +564,14 @@ fn hash_popover_kind<H: Hasher>(kind: &PopoverKind, hasher: &mut H) {
             repo_id.hash(hasher);
             commit_id.hash(hasher);
         }
+        PopoverKind::CommitSelectionMenu {
+            repo_id,
+            commit_ids,
+        } => {
+            77u8.hash(hasher);
+            repo_id.hash(hasher);
+            commit_ids.hash(hasher);
+        }
         PopoverKind::StatusFileMenu {
             repo_id,
             area,
@@ -562,6 +630,35 @@ fn hash_popover_kind<H: Hasher>(kind: &PopoverKind, hasher: &mut H) {
             51u8.hash(hasher);
             repo_id.hash(hasher);
         }
+        PopoverKind::ConflictDashboard { repo_id } => {
+            72u8.hash(hasher);
+            repo_id.hash(hasher);
+        }
+        PopoverKind::Blame { repo_id, path, rev } => {
+            73u8.hash(hasher);
+            repo_id.hash(hasher);
+            path.hash(hasher);
+            rev.hash(hasher);
+        }
+        PopoverKind::RevisionTree { repo_id, rev } => {
+            84u8.hash(hasher);
+            repo_id.hash(hasher);
+            rev.hash(hasher);
+        }
+        PopoverKind::LineHistory {
+            repo_id,
+            path,
+            start,
+            end,
+            rev,
+        } => {
+            74u8.hash(hasher);
+            repo_id.hash(hasher);
+            path.hash(hasher);
+            start.hash(hasher);
+            end.hash(hasher);
+            rev.hash(hasher);
+        }
         PopoverKind::ConflictSaveStageConfirm {
             repo_id,
             path,
@@ -636,6 +733,16 @@ fn hash_repo_popover_kind<H: Hasher>(repo_id: RepoId, kind: &RepoPopoverKind, ha
                 path.hash(hasher);
                 branch.hash(hasher);
             }
+            WorktreePopoverKind::LockPrompt { path } => {
+                80u8.hash(hasher);
+                repo_id.hash(hasher);
+                path.hash(hasher);
+            }
+            WorktreePopoverKind::MovePrompt { path } => {
+                81u8.hash(hasher);
+                repo_id.hash(hasher);
+                path.hash(hasher);
+            }
         },
         RepoPopoverKind::Submodule(submodule_kind) => match submodule_kind {
             SubmodulePopoverKind::SectionMenu => {
@@ -656,6 +763,15 @@ fn hash_repo_popover_kind<H: Hasher>(repo_id: RepoId, kind: &RepoPopoverKind, ha
                 repo_id.hash(hasher);
                 path.hash(hasher);
             }
+            SubmodulePopoverKind::TrackBranchPrompt { path } => {
+                82u8.hash(hasher);
+                repo_id.hash(hasher);
+                path.hash(hasher);
+            }
+            SubmodulePopoverKind::RunCommandPrompt => {
+                83u8.hash(hasher);
+                repo_id.hash(hasher);
+            }
             SubmodulePopoverKind::TrustConfirm => {
                 28u8.hash(hasher);
                 repo_id.hash(hasher);
@@ -862,6 +978,39 @@ mod tests {
         assert_ne!(after_head_branch, notify_fingerprint(&state, &popover));
     }
 
+    #[test]
+    fn commit_menu_fingerprint_changes_when_reword_blocker_loads() {
+        let repo_id = RepoId(9);
+        let commit_id = CommitId("deadbeef".into());
+        let repo = RepoState::new_opening(
+            repo_id,
+            gitcomet_core::domain::RepoSpec {
+                workdir: std::env::temp_dir().join("gitcomet_commit_menu_fingerprint"),
+            },
+        );
+        let mut state = AppState {
+            active_repo: Some(repo_id),
+            ..AppState::default()
+        };
+        state.repos.push(repo);
+
+        let popover = PopoverKind::CommitMenu {
+            repo_id,
+            commit_id: commit_id.clone(),
+        };
+        state.repos[0].history_state.reword_blocker_commit = Some(commit_id);
+        state.repos[0].history_state.reword_blocker = Loadable::Loading;
+        let loading = notify_fingerprint(&state, &popover);
+
+        state.repos[0].history_state.reword_blocker = Loadable::Ready(None);
+        let ready = notify_fingerprint(&state, &popover);
+        assert_ne!(loading, ready);
+
+        state.repos[0].history_state.reword_blocker =
+            Loadable::Ready(Some("deadbeef is already in origin/main.".to_string()));
+        assert_ne!(ready, notify_fingerprint(&state, &popover));
+    }
+
     #[test]
     fn force_push_confirm_fingerprint_changes_when_pending_lease_changes() {
         let repo_id = RepoId(9);
@@ -0,0 +1,258 @@
+use super::*;
+use gitcomet_core::domain::LineRangeLogEntry;
+
+/// Path of the tracked file as of the entry's commit. `git log -L` follows
+/// renames, so older entries can name a different file than the one opened.
+fn entry_path(entry: &LineRangeLogEntry) -> Option<std::path::PathBuf> {
+    entry
+        .diff
+        .lines()
+        .find_map(|line| line.strip_prefix("+++ b/"))
+        .or_else(|| {
+            entry
+                .diff
+                .lines()
+                .find_map(|line| line.strip_prefix("--- a/"))
+        })
+        .map(std::path::PathBuf::from)
+}
+
+/// Shortens a full commit id, keeping any `^` parent suffix.
+fn short_rev(rev: &str) -> String {
+    let base = rev.trim_end_matches('^');
+    if base.len() == 40 && base.bytes().all(|b| b.is_ascii_hexdigit()) {
+        format!("{}{}", &base[..8], &rev[base.len()..])
+    } else {
+        rev.to_string()
+    }
+}
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    path: std::path::PathBuf,
+    start: u32,
+    end: u32,
+    rev: Option<String>,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let editor_font_family = crate::font_preferences::current_editor_font_family(cx);
+    let repo = this.state.repos.iter().find(|r| r.id == repo_id);
+    let title: SharedString = path.display().to_string().into();
+    let lines = if start == end {
+        format!("line {start}")
+    } else {
+        format!("lines {start}–{end}")
+    };
+    let range_label: SharedString = match rev.as_deref() {
+        Some(rev) => format!("{lines} at {}", short_rev(rev)).into(),
+        None => lines.into(),
+    };
+
+    let header = div()
+        .px(scaled_px(8.0))
+        .py(scaled_px(4.0))
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .min_w(px(0.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::BOLD)
+                        .child("Line history"),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .line_height(scaled_px(14.0))
+                        .child(
+                            components::TruncatedText::path(title.clone())
+                                .id(("line_history_title_path", repo_id.0))
+                                .full_text_tooltip(this.tooltip_host.clone())
+                                .render(cx),
+                        ),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .child(range_label),
+                ),
+        )
+        .child(
+            components::Button::new("line_history_close", "Close")
+                .style(components::ButtonStyle::Outlined)
+                .on_click(theme, cx, |this, _e, _w, cx| this.close_popover(cx)),
+        );
+
+    let body: AnyElement = match repo.map(|r| &r.history_state.line_history) {
+        None => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "No repository",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Loading) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "Loading",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Error(e)) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            e.clone(),
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::NotLoaded) => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "Not loaded",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Ready(page)) if page.entries.is_empty() => components::context_menu_label(
+            theme,
+            ui_scale_percent,
+            "No commits",
+            Some(this.tooltip_host.clone()),
+            cx,
+        )
+        .into_any_element(),
+        Some(Loadable::Ready(page)) => {
+            let mut list = div()
+                .id("line_history_list")
+                .flex()
+                .flex_col()
+                .max_h(scaled_px(440.0))
+                .overflow_y_scroll();
+            for (ix, entry) in page.entries.iter().enumerate() {
+                let sha = entry.commit.id.as_ref();
+                let short = sha.get(0..8).unwrap_or(sha).to_owned();
+                let commit_id = entry.commit.id.clone();
+                let target_path = entry_path(entry).unwrap_or_else(|| path.clone());
+
+                let commit_row = div()
+                    .id(("line_history_commit", ix))
+                    .px_2()
+                    .py_1()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .bg(theme.colors.surface_bg)
+                    .hover(move |s| s.bg(theme.colors.hover))
+                    .active(move |s| s.bg(theme.colors.active))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.colors.text_muted)
+                            .whitespace_nowrap()
+                            .child(short),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w(px(0.0))
+                            .text_xs()
+                            .line_clamp(1)
+                            .whitespace_nowrap()
+                            .overflow_hidden()
+                            .child(entry.commit.summary.to_string()),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.colors.text_muted)
+                            .whitespace_nowrap()
+                            .child(entry.commit.author.to_string()),
+                    )
+                    .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                        this.store.dispatch(Msg::SelectCommit {
+                            repo_id,
+                            commit_id: commit_id.clone(),
+                        });
+                        this.store.dispatch(Msg::SelectDiff {
+                            repo_id,
+                            target: DiffTarget::Commit {
+                                commit_id: commit_id.clone(),
+                                path: Some(target_path.clone()),
+                                old_path: None,
+                            },
+                        });
+                        this.close_popover(cx);
+                    }));
+
+                let mut diff = div()
+                    .px_2()
+                    .pb_1()
+                    .flex()
+                    .flex_col()
+                    .text_xs()
+                    .font_family(editor_font_family.clone());
+                // Skip the `diff --git` / `---` / `+++` preamble; the hunks are
+                // what the user asked to see.
+                for line in entry
+                    .diff
+                    .lines()
+                    .skip_while(|line| !line.starts_with("@@"))
+                {
+                    let (fg, bg) = if line.starts_with("@@") {
+                        (theme.colors.text_muted, None)
+                    } else if line.starts_with('+') {
+                        (theme.colors.diff_add_text, Some(theme.colors.diff_add_bg))
+                    } else if line.starts_with('-') {
+                        (
+                            theme.colors.diff_remove_text,
+                            Some(theme.colors.diff_remove_bg),
+                        )
+                    } else {
+                        (theme.colors.text, None)
+                    };
+                    diff = diff.child(
+                        div()
+                            .px_1()
+                            .whitespace_nowrap()
+                            .overflow_hidden()
+                            .text_color(fg)
+                            .when_some(bg, |d, bg| d.bg(bg))
+                            .child(line.to_string()),
+                    );
+                }
+
+                list = list
+                    .child(commit_row)
+                    .child(diff)
+                    .child(div().border_t_1().border_color(theme.colors.border));
+            }
+            list.into_any_element()
+        }
+    };
+
+    components::context_menu(
+        theme,
+        div()
+            .flex()
+            .flex_col()
+            .w(scaled_px(640.0))
+            .max_w(scaled_px(920.0))
+            .child(header)
+            .child(div().border_t_1().border_color(theme.colors.border))
+            .child(body),
+    )
+}
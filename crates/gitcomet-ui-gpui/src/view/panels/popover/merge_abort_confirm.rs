@@ -6,43 +6,51 @@ pub(super) fn panel(
     cx: &mut gpui::Context<PopoverHost>,
 ) -> gpui::Div {
     let theme = this.theme;
-    #[derive(Clone, Copy)]
-    enum AbortMode {
-        Merge,
-        RebaseOrApply,
-    }
-
-    let mode = this
+    let kind = this
         .state
         .repos
         .iter()
         .find(|repo| repo.id == repo_id)
-        .map(|repo| {
-            if matches!(&repo.merge_commit_message, Loadable::Ready(Some(_))) {
-                AbortMode::Merge
-            } else if matches!(&repo.rebase_in_progress, Loadable::Ready(true)) {
-                AbortMode::RebaseOrApply
-            } else {
-                AbortMode::Merge
-            }
-        })
-        .unwrap_or(AbortMode::Merge);
+        .and_then(super::super::action_bar::active_repo_operation)
+        .map(|operation| operation.kind())
+        .unwrap_or(RepoOperationKind::Merge);
 
-    let (title, body, command, button_id, button_label) = match mode {
-        AbortMode::Merge => (
+    let (title, body, command, button_id, button_label) = match kind {
+        RepoOperationKind::Merge => (
             "Abort merge?",
             "This will abort the current merge and restore the pre-merge state. Any resolved conflicts will be lost.",
             "git merge --abort",
             "merge_abort_go",
             "Abort merge",
         ),
-        AbortMode::RebaseOrApply => (
+        RepoOperationKind::Rebase | RepoOperationKind::ApplyMailbox => (
             "Abort apply/rebase?",
             "This will abort the in-progress patch apply or rebase and restore the previous state. Any resolved conflicts will be lost.",
             "git rebase --abort / git am --abort",
             "rebase_or_apply_abort_go",
             "Abort",
         ),
+        RepoOperationKind::CherryPick => (
+            "Abort cherry-pick?",
+            "This will abort the cherry-pick and restore the branch to where it started. Any resolved conflicts will be lost.",
+            "git cherry-pick --abort",
+            "cherry_pick_abort_go",
+            "Abort",
+        ),
+        RepoOperationKind::Revert => (
+            "Abort revert?",
+            "This will abort the revert and restore the branch to where it started. Any resolved conflicts will be lost.",
+            "git revert --abort",
+            "revert_abort_go",
+            "Abort",
+        ),
+        RepoOperationKind::Bisect => (
+            "End bisect?",
+            "This will end the bisect session and check out the commit you started from.",
+            "git bisect reset",
+            "bisect_reset_go",
+            "Reset",
+        ),
     };
     let ui_scale_percent = super::popover_ui_scale_percent(cx);
     let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
@@ -98,13 +106,19 @@ pub(super) fn panel(
                     components::Button::new(button_id, button_label)
                         .style(components::ButtonStyle::Danger)
                         .on_click(theme, cx, move |this, _e, _w, cx| {
-                            match mode {
-                                AbortMode::Merge => {
+                            match kind {
+                                RepoOperationKind::Merge => {
                                     this.store.dispatch(Msg::MergeAbort { repo_id })
                                 }
-                                AbortMode::RebaseOrApply => {
+                                // Rebase abort falls back to `git am --abort`.
+                                RepoOperationKind::Rebase | RepoOperationKind::ApplyMailbox => {
                                     this.store.dispatch(Msg::RebaseAbort { repo_id })
                                 }
+                                kind => this.store.dispatch(Msg::OperationAction {
+                                    repo_id,
+                                    kind,
+                                    action: RepoOperationAction::Abort,
+                                }),
                             }
                             this.popover = None;
                             this.popover_anchor = None;
@@ -0,0 +1,327 @@
+use super::*;
+use gitcomet_core::mailbox::MailboxPatch;
+
+fn three_way_toggle(
+    theme: AppTheme,
+    enabled: bool,
+    focus_handle: &FocusHandle,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Stateful<gpui::Div> {
+    let border = if enabled {
+        theme.colors.success
+    } else {
+        theme.colors.border
+    };
+    let background = if enabled {
+        with_alpha(
+            theme.colors.success,
+            if theme.is_dark { 0.18 } else { 0.12 },
+        )
+    } else {
+        gpui::rgba(0x00000000)
+    };
+
+    focusable_toggle_row(
+        "patch_series_three_way_toggle",
+        "patch_series_three_way_toggle",
+        theme,
+        focus_handle,
+        cx,
+    )
+    .flex()
+    .gap_2()
+    .child(
+        div()
+            .size(px(16.0))
+            .flex()
+            .items_center()
+            .justify_center()
+            .border_1()
+            .border_color(border)
+            .rounded(px(4.0))
+            .bg(background)
+            .when(enabled, |this| {
+                this.child(crate::view::icons::svg_icon(
+                    "icons/check.svg",
+                    theme.colors.success,
+                    px(10.0),
+                ))
+            }),
+    )
+    .child(
+        div()
+            .text_sm()
+            .child("Fall back to a 3-way merge when a patch does not apply cleanly"),
+    )
+}
+
+pub(super) fn diff_line_color(theme: AppTheme, line: &str) -> gpui::Rgba {
+    if line.starts_with("+++") || line.starts_with("---") || line.starts_with("diff --git") {
+        theme.colors.text_muted
+    } else if line.starts_with('+') {
+        theme.colors.success
+    } else if line.starts_with('-') {
+        theme.colors.danger
+    } else if line.starts_with("@@") {
+        theme.colors.accent
+    } else {
+        theme.colors.text
+    }
+}
+
+fn patch_details(
+    theme: AppTheme,
+    patch: &MailboxPatch,
+    scaled_px: impl Fn(f32) -> Pixels,
+) -> gpui::Stateful<gpui::Div> {
+    let mut meta = Vec::new();
+    if let Some(author) = &patch.author {
+        meta.push(author.clone());
+    }
+    if let Some(date) = &patch.date {
+        meta.push(date.clone());
+    }
+
+    let mut diff = div()
+        .flex()
+        .flex_col()
+        .pt_1()
+        .text_xs()
+        .font_family(crate::font_preferences::EDITOR_MONOSPACE_FONT_FAMILY);
+    for line in patch.diff.lines() {
+        diff = diff.child(
+            div()
+                .whitespace_nowrap()
+                .text_color(diff_line_color(theme, line))
+                .child(if line.is_empty() {
+                    " ".to_string()
+                } else {
+                    line.to_string()
+                }),
+        );
+    }
+
+    div()
+        .id("patch_series_details")
+        .flex_1()
+        .min_w(px(0.0))
+        .h(scaled_px(360.0))
+        .px_2()
+        .py_1()
+        .overflow_y_scroll()
+        .flex()
+        .flex_col()
+        .when(!meta.is_empty(), |d| {
+            d.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.colors.text_muted)
+                    .child(meta.join(" · ")),
+            )
+        })
+        .child(
+            div()
+                .pt_1()
+                .text_sm()
+                .whitespace_normal()
+                .child(patch.message.clone()),
+        )
+        .child(div().mt_1().border_t_1().border_color(theme.colors.border))
+        .child(diff)
+}
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let preview = this
+        .state
+        .repos
+        .iter()
+        .find(|r| r.id == repo_id)
+        .and_then(|r| r.patch_series_preview.clone());
+    let patch_count = this.patch_series_ready_count();
+
+    let source_label = preview
+        .as_ref()
+        .map(|p| p.source.display().to_string())
+        .unwrap_or_else(|| "Choose an mbox file or a folder of patch files".to_string());
+
+    let body = match preview.as_ref().map(|p| &p.patches) {
+        None | Some(Loadable::NotLoaded) => div(),
+        Some(Loadable::Loading) => div()
+            .px_2()
+            .py_1()
+            .text_sm()
+            .text_color(theme.colors.text_muted)
+            .child("Reading patches…"),
+        Some(Loadable::Error(e)) => div()
+            .px_2()
+            .py_1()
+            .text_sm()
+            .text_color(theme.colors.danger)
+            .child(e.clone()),
+        Some(Loadable::Ready(patches)) if patches.is_empty() => div()
+            .px_2()
+            .py_1()
+            .text_sm()
+            .text_color(theme.colors.warning)
+            .child("No patches found."),
+        Some(Loadable::Ready(patches)) => {
+            let selected_ix = this.patch_series_selected_ix.min(patches.len() - 1);
+            let mut list = div()
+                .id("patch_series_list")
+                .flex()
+                .flex_col()
+                .w(scaled_px(220.0))
+                .h(scaled_px(360.0))
+                .border_r_1()
+                .border_color(theme.colors.border)
+                .overflow_y_scroll();
+            for (ix, patch) in patches.iter().enumerate() {
+                list = list.child(
+                    div()
+                        .id(("patch_series_row", ix))
+                        .px_2()
+                        .py_1()
+                        .flex()
+                        .gap_2()
+                        .text_xs()
+                        .when(ix == selected_ix, |d| d.bg(theme.colors.active))
+                        .hover(move |s| s.bg(theme.colors.hover))
+                        .child(
+                            div()
+                                .text_color(theme.colors.text_muted)
+                                .child(format!("{}.", ix + 1)),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w(px(0.0))
+                                .line_clamp(2)
+                                .child(patch.subject.clone()),
+                        )
+                        .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                            this.patch_series_selected_ix = ix;
+                            cx.notify();
+                        })),
+                );
+            }
+            div()
+                .flex()
+                .child(list)
+                .child(patch_details(theme, &patches[selected_ix], scaled_px))
+        }
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(640.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Import patch series"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w(px(0.0))
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .line_clamp(1)
+                        .whitespace_nowrap()
+                        .overflow_hidden()
+                        .child(source_label),
+                )
+                .child(
+                    components::Button::new("patch_series_choose_file", "Mailbox…")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |this, _e, window, cx| {
+                            this.prompt_for_patch_series_source(repo_id, false, window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new("patch_series_choose_folder", "Folder…")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |this, _e, window, cx| {
+                            this.prompt_for_patch_series_source(repo_id, true, window, cx);
+                        }),
+                ),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(body)
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            three_way_toggle(
+                theme,
+                this.patch_series_three_way_enabled,
+                &this.patch_series_three_way_focus_handle,
+                cx,
+            )
+            .on_click(cx.listener(|this, _e: &ClickEvent, _w, cx| {
+                this.patch_series_three_way_enabled = !this.patch_series_three_way_enabled;
+                cx.notify();
+            })),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child(
+                    "Patches are applied in order with git am. If one conflicts, the series \
+                     stops so it can be resolved, then continued, skipped or aborted.",
+                ),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("patch_series_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |this, _e, window, cx| {
+                            this.store.dispatch(Msg::LoadPatchSeriesPreview {
+                                repo_id,
+                                source: None,
+                            });
+                            this.dismiss_prompt_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new(
+                        "patch_series_apply",
+                        match patch_count {
+                            1 => "Apply 1 patch".to_string(),
+                            n => format!("Apply {n} patches"),
+                        },
+                    )
+                    .style(components::ButtonStyle::Filled)
+                    .disabled(patch_count == 0)
+                    .on_click(theme, cx, |this, _e, _w, cx| {
+                        this.submit_patch_series_import(cx);
+                    }),
+                ),
+        )
+}
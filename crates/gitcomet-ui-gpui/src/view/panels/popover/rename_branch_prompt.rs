@@ -0,0 +1,167 @@
+use super::*;
+
+fn rename_remote_toggle(
+    theme: AppTheme,
+    enabled: bool,
+    label: String,
+    focus_handle: &FocusHandle,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Stateful<gpui::Div> {
+    let border = if enabled {
+        theme.colors.success
+    } else {
+        theme.colors.border
+    };
+    let background = if enabled {
+        with_alpha(
+            theme.colors.success,
+            if theme.is_dark { 0.18 } else { 0.12 },
+        )
+    } else {
+        gpui::rgba(0x00000000)
+    };
+
+    focusable_toggle_row(
+        "rename_branch_remote_toggle",
+        "rename_branch_remote_toggle",
+        theme,
+        focus_handle,
+        cx,
+    )
+    .flex()
+    .gap_2()
+    .child(
+        div()
+            .size(px(16.0))
+            .flex()
+            .items_center()
+            .justify_center()
+            .border_1()
+            .border_color(border)
+            .rounded(px(4.0))
+            .bg(background)
+            .when(enabled, |this| {
+                this.child(crate::view::icons::svg_icon(
+                    "icons/check.svg",
+                    theme.colors.success,
+                    px(10.0),
+                ))
+            }),
+    )
+    .child(div().text_sm().child(label))
+}
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    branch: String,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let can_rename = this.can_submit_rename_branch(cx);
+    let new_name = this
+        .rename_branch_input
+        .read_with(cx, |input, _| input.text().trim().to_string());
+    let overwrites = this.rename_branch_overwrites(repo_id, &branch, &new_name);
+    let upstream = this.rename_branch_upstream(repo_id, &branch);
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(540.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Rename branch"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .text_color(theme.colors.text_muted)
+                .child(format!("Branch: {branch}")),
+        )
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child("New branch name"),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .child(this.rename_branch_input.clone()),
+        )
+        .when(overwrites, |d| {
+            d.child(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_xs()
+                    .text_color(theme.colors.warning)
+                    .child(format!(
+                        "A branch named {new_name} already exists and will be overwritten."
+                    )),
+            )
+        })
+        .when_some(upstream, |d, upstream| {
+            d.child(
+                rename_remote_toggle(
+                    theme,
+                    this.rename_branch_remote_enabled,
+                    format!("Also rename {}/{}", upstream.remote, upstream.branch),
+                    &this.rename_branch_remote_focus_handle,
+                    cx,
+                )
+                .on_click(cx.listener(|this, _e: &ClickEvent, _w, cx| {
+                    this.rename_branch_remote_enabled = !this.rename_branch_remote_enabled;
+                    cx.notify();
+                })),
+            )
+        })
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("rename_branch_cancel", "Cancel")
+                        .focus_handle(this.rename_branch_cancel_focus_handle.clone())
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_prompt_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new(
+                        "rename_branch_go",
+                        if overwrites { "Overwrite" } else { "Rename" },
+                    )
+                    .focus_handle(this.rename_branch_submit_focus_handle.clone())
+                    .style(if overwrites {
+                        components::ButtonStyle::Danger
+                    } else {
+                        components::ButtonStyle::Filled
+                    })
+                    .disabled(!can_rename)
+                    .on_click(theme, cx, |this, _e, _w, cx| {
+                        this.submit_rename_branch(cx);
+                    }),
+                ),
+        )
+}
@@ -0,0 +1,614 @@
+use super::*;
+use gitcomet_core::domain::{RevisionTreeEntry, RevisionTreeEntryKind};
+use gitcomet_state::model::RevisionTreeBrowser;
+
+const LIST_HEIGHT_PX: f32 = 360.0;
+
+/// Full commit ids are shortened for display; symbolic revisions are kept.
+fn display_rev(rev: &str) -> &str {
+    if rev.len() == 40 && rev.bytes().all(|b| b.is_ascii_hexdigit()) {
+        &rev[..8]
+    } else {
+        rev
+    }
+}
+
+fn active_browser(this: &PopoverHost) -> Option<(RepoId, RevisionTreeBrowser)> {
+    let Some(PopoverKind::RevisionTree { repo_id, rev }) = this.popover.as_ref() else {
+        return None;
+    };
+    let repo = this.state.repos.iter().find(|r| r.id == *repo_id)?;
+    let browser = repo.revision_tree.as_ref().filter(|b| b.rev == *rev)?;
+    Some((*repo_id, browser.clone()))
+}
+
+fn current_anchor(this: &PopoverHost) -> Point<Pixels> {
+    this.popover_anchor
+        .as_ref()
+        .map(|anchor| match anchor {
+            PopoverAnchor::Point(point) => *point,
+            PopoverAnchor::Bounds(bounds) => bounds.bottom_left(),
+        })
+        .unwrap_or_else(|| point(px(64.0), px(64.0)))
+}
+
+/// Asks for a destination folder and saves `path` of `rev` under it as `name`.
+fn prompt_save(
+    repo_id: RepoId,
+    rev: String,
+    path: std::path::PathBuf,
+    name: String,
+    window: &mut Window,
+    cx: &mut gpui::Context<PopoverHost>,
+) {
+    let view = cx.weak_entity();
+    let rx = cx.prompt_for_paths(gpui::PathPromptOptions {
+        files: false,
+        directories: true,
+        multiple: false,
+        prompt: Some("Save to folder".into()),
+    });
+    window
+        .spawn(cx, async move |cx| {
+            let result = rx.await;
+            let paths = match result {
+                Ok(Ok(Some(paths))) => paths,
+                Ok(Ok(None)) => return,
+                Ok(Err(_)) | Err(_) => return,
+            };
+            let Some(folder) = paths.into_iter().next() else {
+                return;
+            };
+            let dest = folder.join(name);
+            let _ = view.update(cx, |this, cx| {
+                this.store.dispatch(Msg::SaveRevisionPath {
+                    repo_id,
+                    rev,
+                    path,
+                    dest,
+                });
+                cx.notify();
+            });
+        })
+        .detach();
+}
+
+fn status_label(
+    this: &PopoverHost,
+    text: impl Into<SharedString>,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> AnyElement {
+    components::context_menu_label(
+        this.theme,
+        super::popover_ui_scale_percent(cx),
+        text,
+        Some(this.tooltip_host.clone()),
+        cx,
+    )
+    .into_any_element()
+}
+
+fn scrolling_list(
+    this: &PopoverHost,
+    list: gpui::UniformList,
+    scrollbar_id: &'static str,
+) -> AnyElement {
+    let scrollbar_gutter = components::Scrollbar::visible_gutter(
+        this.revision_tree_scroll.clone(),
+        components::ScrollbarAxis::Vertical,
+    );
+    div()
+        .relative()
+        .child(
+            div().h(px(LIST_HEIGHT_PX)).pr(scrollbar_gutter).child(
+                list.h(px(LIST_HEIGHT_PX))
+                    .track_scroll(&this.revision_tree_scroll),
+            ),
+        )
+        .child(
+            components::Scrollbar::new(scrollbar_id, this.revision_tree_scroll.clone())
+                .render(this.theme),
+        )
+        .into_any_element()
+}
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    rev: String,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let repo = this.state.repos.iter().find(|r| r.id == repo_id);
+    let repo_name = repo
+        .and_then(|r| r.spec.workdir.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "repository".to_string());
+    let browser = repo
+        .and_then(|r| r.revision_tree.as_ref())
+        .filter(|b| b.rev == rev)
+        .cloned();
+    let dir = browser.as_ref().map(|b| b.dir.clone()).unwrap_or_default();
+    let file = browser.as_ref().and_then(|b| b.file.clone());
+
+    let location: SharedString = match &file {
+        Some(file) => file.path.display().to_string().into(),
+        None if dir.as_os_str().is_empty() => "/".into(),
+        None => format!("{}/", dir.display()).into(),
+    };
+
+    let header = div()
+        .px_2()
+        .py_1()
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .min_w(px(0.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::BOLD)
+                        .child(format!("Files at {}", display_rev(&rev))),
+                )
+                .child(
+                    div().text_xs().text_color(theme.colors.text_muted).child(
+                        components::TruncatedText::path(location)
+                            .id(("revision_tree_location", repo_id.0))
+                            .full_text_tooltip(this.tooltip_host.clone())
+                            .render(cx),
+                    ),
+                ),
+        )
+        .child(
+            components::Button::new("revision_tree_close", "Close")
+                .style(components::ButtonStyle::Outlined)
+                .on_click(theme, cx, |this, _e, _w, cx| this.close_popover(cx)),
+        );
+
+    let toolbar = div()
+        .px_2()
+        .pb_1()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1();
+    let (toolbar, body) = match file {
+        Some(file) => {
+            let path = file.path.clone();
+            let against_worktree = file.against_worktree;
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "file".to_string());
+            let toolbar = toolbar
+                .child(
+                    components::Button::new("revision_tree_back", "Back")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |this, _e, _w, cx| {
+                            this.revision_tree_scroll = UniformListScrollHandle::default();
+                            this.store.dispatch(Msg::LoadRevisionFile {
+                                repo_id,
+                                path: None,
+                                against_worktree: false,
+                            });
+                            cx.notify();
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_contents", "Contents")
+                        .style(components::ButtonStyle::Subtle)
+                        .selected(!against_worktree)
+                        .on_click(theme, cx, {
+                            let path = path.clone();
+                            move |this, _e, _w, cx| {
+                                this.store.dispatch(Msg::LoadRevisionFile {
+                                    repo_id,
+                                    path: Some(path.clone()),
+                                    against_worktree: false,
+                                });
+                                cx.notify();
+                            }
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_worktree_diff", "Diff vs working tree")
+                        .style(components::ButtonStyle::Subtle)
+                        .selected(against_worktree)
+                        .on_click(theme, cx, {
+                            let path = path.clone();
+                            move |this, _e, _w, cx| {
+                                this.store.dispatch(Msg::LoadRevisionFile {
+                                    repo_id,
+                                    path: Some(path.clone()),
+                                    against_worktree: true,
+                                });
+                                cx.notify();
+                            }
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_blame", "Blame")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, {
+                            let path = path.clone();
+                            let rev = rev.clone();
+                            move |this, _e, window, cx| {
+                                let anchor = current_anchor(this);
+                                this.open_popover_at(
+                                    PopoverKind::Blame {
+                                        repo_id,
+                                        path: path.clone(),
+                                        rev: Some(rev.clone()),
+                                    },
+                                    anchor,
+                                    window,
+                                    cx,
+                                );
+                            }
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_history", "History")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, {
+                            let path = path.clone();
+                            move |this, _e, window, cx| {
+                                let anchor = current_anchor(this);
+                                this.open_popover_at(
+                                    PopoverKind::FileHistory {
+                                        repo_id,
+                                        path: path.clone(),
+                                    },
+                                    anchor,
+                                    window,
+                                    cx,
+                                );
+                            }
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_save_file", "Save file…")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, {
+                            let rev = rev.clone();
+                            move |_this, _e, window, cx| {
+                                prompt_save(
+                                    repo_id,
+                                    rev.clone(),
+                                    path.clone(),
+                                    file_name.clone(),
+                                    window,
+                                    cx,
+                                );
+                            }
+                        }),
+                );
+
+            let body = match &file.text {
+                Loadable::NotLoaded | Loadable::Loading => status_label(this, "Loading", cx),
+                Loadable::Error(e) => status_label(this, e.clone(), cx),
+                Loadable::Ready(text) if text.is_empty() => {
+                    let label = if against_worktree {
+                        "No differences from the working tree"
+                    } else {
+                        "Empty file"
+                    };
+                    status_label(this, label, cx)
+                }
+                Loadable::Ready(text) => {
+                    let count = text.lines().count();
+                    let list = uniform_list(
+                        "revision_tree_file",
+                        count,
+                        cx.processor(render_revision_file_rows),
+                    );
+                    scrolling_list(this, list, "revision_tree_file_scrollbar")
+                }
+            };
+            (toolbar, body)
+        }
+        None => {
+            let parent = dir.parent().map(std::path::Path::to_path_buf);
+            let save_name = match dir.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => format!("{repo_name}-{}", display_rev(&rev)),
+            };
+            let save_label = if dir.as_os_str().is_empty() {
+                "Save tree…"
+            } else {
+                "Save folder…"
+            };
+            let toolbar = toolbar
+                .child(
+                    components::Button::new("revision_tree_up", "Up")
+                        .style(components::ButtonStyle::Outlined)
+                        .disabled(parent.is_none())
+                        .on_click(theme, cx, {
+                            let rev = rev.clone();
+                            move |this, _e, _w, cx| {
+                                let Some(parent) = parent.clone() else {
+                                    return;
+                                };
+                                this.revision_tree_scroll = UniformListScrollHandle::default();
+                                this.store.dispatch(Msg::LoadRevisionTree {
+                                    repo_id,
+                                    rev: rev.clone(),
+                                    dir: parent,
+                                });
+                                cx.notify();
+                            }
+                        }),
+                )
+                .child(
+                    components::Button::new("revision_tree_save_dir", save_label)
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, {
+                            let rev = rev.clone();
+                            let dir = dir.clone();
+                            move |_this, _e, window, cx| {
+                                prompt_save(
+                                    repo_id,
+                                    rev.clone(),
+                                    dir.clone(),
+                                    save_name.clone(),
+                                    window,
+                                    cx,
+                                );
+                            }
+                        }),
+                );
+
+            let body = match browser.as_ref().map(|b| &b.entries) {
+                None | Some(Loadable::NotLoaded | Loadable::Loading) => {
+                    status_label(this, "Loading", cx)
+                }
+                Some(Loadable::Error(e)) => status_label(this, e.clone(), cx),
+                Some(Loadable::Ready(entries)) if entries.is_empty() => {
+                    status_label(this, "Empty directory", cx)
+                }
+                Some(Loadable::Ready(entries)) => {
+                    let list = uniform_list(
+                        "revision_tree_entries",
+                        entries.len(),
+                        cx.processor(render_revision_tree_rows),
+                    );
+                    scrolling_list(this, list, "revision_tree_entries_scrollbar")
+                }
+            };
+            (toolbar, body)
+        }
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .min_w(scaled_px(720.0))
+        .max_w(scaled_px(980.0))
+        .child(header)
+        .child(toolbar)
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(body)
+}
+
+fn entry_icon(kind: RevisionTreeEntryKind) -> &'static str {
+    match kind {
+        RevisionTreeEntryKind::Directory => "icons/folder.svg",
+        RevisionTreeEntryKind::Submodule => "icons/box.svg",
+        RevisionTreeEntryKind::Symlink => "icons/link.svg",
+        RevisionTreeEntryKind::File | RevisionTreeEntryKind::Executable => "icons/file.svg",
+    }
+}
+
+fn entry_detail(entry: &RevisionTreeEntry) -> String {
+    match (entry.kind, entry.size) {
+        (RevisionTreeEntryKind::Submodule, _) => "submodule".to_string(),
+        (RevisionTreeEntryKind::Symlink, _) => "symlink".to_string(),
+        (_, Some(size)) => crate::view::hex_preview::format_size(size),
+        (_, None) => String::new(),
+    }
+}
+
+fn render_revision_tree_rows(
+    this: &mut PopoverHost,
+    range: std::ops::Range<usize>,
+    _window: &mut Window,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> Vec<AnyElement> {
+    let Some((repo_id, browser)) = active_browser(this) else {
+        return Vec::new();
+    };
+    let Loadable::Ready(entries) = &browser.entries else {
+        return Vec::new();
+    };
+
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let mut rows = Vec::with_capacity(range.len());
+    for ix in range {
+        let Some(entry) = entries.get(ix) else {
+            continue;
+        };
+        let kind = entry.kind;
+        let is_directory = kind == RevisionTreeEntryKind::Directory;
+        let is_submodule = kind == RevisionTreeEntryKind::Submodule;
+        let name: SharedString = if is_directory {
+            format!("{}/", entry.name()).into()
+        } else {
+            entry.name().into_owned().into()
+        };
+        let detail: SharedString = entry_detail(entry).into();
+        let path = entry.path.clone();
+        let rev = browser.rev.clone();
+        let save_name = entry.name().into_owned();
+
+        let mut row = div()
+            .id(("revision_tree_entry", ix))
+            .h(px(22.0))
+            .flex()
+            .items_center()
+            .px_2()
+            .gap_2()
+            .child(svg_icon(
+                entry_icon(kind),
+                if is_directory {
+                    theme.colors.accent
+                } else {
+                    theme.colors.text_muted
+                },
+                scaled_px(13.0),
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(px(0.0))
+                    .text_sm()
+                    .when(is_submodule, |d| d.text_color(theme.colors.text_muted))
+                    .line_clamp(1)
+                    .whitespace_nowrap()
+                    .overflow_hidden()
+                    .child(name),
+            )
+            .child(
+                div()
+                    .w(scaled_px(120.0))
+                    .text_xs()
+                    .text_color(theme.colors.text_muted)
+                    .whitespace_nowrap()
+                    .child(detail),
+            )
+            .child(
+                components::Button::new(format!("revision_tree_save_{ix}"), "Save…")
+                    .style(components::ButtonStyle::Transparent)
+                    .disabled(is_submodule)
+                    .on_click(theme, cx, {
+                        let path = path.clone();
+                        let rev = rev.clone();
+                        move |_this, _e, window, cx| {
+                            cx.stop_propagation();
+                            prompt_save(
+                                repo_id,
+                                rev.clone(),
+                                path.clone(),
+                                save_name.clone(),
+                                window,
+                                cx,
+                            );
+                        }
+                    }),
+            );
+        if !is_submodule {
+            row = row
+                .hover(move |s| s.bg(theme.colors.hover))
+                .active(move |s| s.bg(theme.colors.active))
+                .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+                    this.revision_tree_scroll = UniformListScrollHandle::default();
+                    if is_directory {
+                        this.store.dispatch(Msg::LoadRevisionTree {
+                            repo_id,
+                            rev: rev.clone(),
+                            dir: path.clone(),
+                        });
+                    } else {
+                        this.store.dispatch(Msg::LoadRevisionFile {
+                            repo_id,
+                            path: Some(path.clone()),
+                            against_worktree: false,
+                        });
+                    }
+                    cx.notify();
+                }));
+        }
+        rows.push(row.into_any_element());
+    }
+
+    rows
+}
+
+fn render_revision_file_rows(
+    this: &mut PopoverHost,
+    range: std::ops::Range<usize>,
+    _window: &mut Window,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> Vec<AnyElement> {
+    let Some((_, browser)) = active_browser(this) else {
+        return Vec::new();
+    };
+    let Some(file) = browser.file.as_ref() else {
+        return Vec::new();
+    };
+    let Loadable::Ready(text) = &file.text else {
+        return Vec::new();
+    };
+
+    let theme = this.theme;
+    let editor_font_family = crate::font_preferences::current_editor_font_family(cx);
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let language = rows::diff_syntax_language_for_path(&file.path);
+    let mut rows = Vec::with_capacity(range.len());
+    for (ix, line) in text.lines().enumerate().skip(range.start).take(range.len()) {
+        let line: SharedString = line.to_string().into();
+        let code: AnyElement = if file.against_worktree {
+            div()
+                .text_color(super::patch_series_import::diff_line_color(theme, &line))
+                .child(line)
+                .into_any_element()
+        } else {
+            match language {
+                Some(language) => {
+                    let highlights = rows::syntax_highlights_for_line(
+                        theme,
+                        &line,
+                        language,
+                        rows::DiffSyntaxMode::HeuristicOnly,
+                    );
+                    gpui::StyledText::new(line)
+                        .with_highlights(highlights)
+                        .into_any_element()
+                }
+                None => line.into_any_element(),
+            }
+        };
+
+        rows.push(
+            div()
+                .id(("revision_tree_line", ix))
+                .h(px(20.0))
+                .flex()
+                .items_center()
+                .px_2()
+                .gap_2()
+                .child(
+                    div()
+                        .w(scaled_px(44.0))
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .whitespace_nowrap()
+                        .when(!file.against_worktree, |d| {
+                            d.child(format!("{:>4}", ix + 1))
+                        }),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w(px(0.0))
+                        .text_xs()
+                        .font_family(editor_font_family.clone())
+                        .line_clamp(1)
+                        .whitespace_nowrap()
+                        .overflow_hidden()
+                        .child(code),
+                )
+                .into_any_element(),
+        );
+    }
+
+    rows
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+pub(super) fn panel(this: &mut PopoverHost, cx: &mut gpui::Context<PopoverHost>) -> gpui::Div {
+    let theme = this.theme;
+    let can_submit = this.can_submit_submodule_run_command(cx);
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(420.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Run git command in each submodule"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child("git arguments (e.g. status --short)"),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .child(this.submodule_ref_input.clone()),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child("Runs in every initialized submodule; output is logged per submodule."),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("submodule_run_command_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_inline_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new("submodule_run_command_go", "Run")
+                        .style(components::ButtonStyle::Filled)
+                        .disabled(!can_submit)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.submit_submodule_run_command(window, cx);
+                        }),
+                ),
+        )
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    path: &std::path::Path,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let can_submit = this.can_submit_submodule_track_branch(cx);
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(420.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Submodule tracked branch"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .text_color(theme.colors.text_muted)
+                .child(format!("Submodule: {}", path.display())),
+        )
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child("Remote branch used by \"Update from remote\""),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .child(this.submodule_ref_input.clone()),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("submodule_track_branch_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_inline_popover(window, cx);
+                        }),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .child(
+                            components::Button::new(
+                                "submodule_track_branch_default",
+                                "Use remote default",
+                            )
+                            .style(components::ButtonStyle::Outlined)
+                            .on_click(
+                                theme,
+                                cx,
+                                |this, _e, window, cx| {
+                                    let Some(PopoverKind::Repo {
+                                        repo_id,
+                                        kind:
+                                            RepoPopoverKind::Submodule(
+                                                SubmodulePopoverKind::TrackBranchPrompt { path },
+                                            ),
+                                    }) = this.popover.clone()
+                                    else {
+                                        return;
+                                    };
+                                    this.store.dispatch(Msg::SetSubmoduleBranch {
+                                        repo_id,
+                                        path,
+                                        branch: None,
+                                    });
+                                    this.dismiss_inline_popover(window, cx);
+                                },
+                            ),
+                        )
+                        .child(
+                            components::Button::new("submodule_track_branch_go", "Track")
+                                .style(components::ButtonStyle::Filled)
+                                .disabled(!can_submit)
+                                .on_click(theme, cx, |this, _e, window, cx| {
+                                    this.submit_submodule_track_branch(window, cx);
+                                }),
+                        ),
+                ),
+        )
+}
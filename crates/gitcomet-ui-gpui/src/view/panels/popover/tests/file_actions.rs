@@ -85,6 +85,21 @@ fn commit_menu_has_add_tag_entry(cx: &mut gpui::TestAppContext) {
 
         assert_eq!(rid, repo_id);
         assert_eq!(target, commit_id.as_ref().to_string());
+
+        let browse_action = model.items.iter().find_map(|item| match item {
+            ContextMenuItem::Entry { label, action, .. } if label.as_ref() == "Browse files" => {
+                Some((**action).clone())
+            }
+            _ => None,
+        });
+        let Some(ContextMenuAction::OpenPopover {
+            kind: PopoverKind::RevisionTree { repo_id: rid, rev },
+        }) = browse_action
+        else {
+            panic!("expected Browse files to open the revision tree browser");
+        };
+        assert_eq!(rid, repo_id);
+        assert_eq!(rev, commit_id.as_ref().to_string());
     });
 }
 
@@ -180,6 +195,8 @@ fn status_file_menu_has_open_file_entries(cx: &mut gpui::TestAppContext) {
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -275,6 +292,8 @@ fn status_file_menu_copy_path_uses_os_native_separators(cx: &mut gpui::TestAppCo
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -530,6 +549,8 @@ fn status_file_menu_copy_path_supports_right_button_release(cx: &mut gpui::TestA
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -624,6 +645,7 @@ fn diff_editor_menu_has_open_file_entries(cx: &mut gpui::TestAppContext) {
                             lines_count: 0,
                             copy_text: Some("x".to_string()),
                             copy_target: None,
+                            line_range: None,
                         },
                         cx,
                     )
@@ -759,12 +781,14 @@ fn file_preview_context_menu_matches_diff_editor_actions(cx: &mut gpui::TestAppC
                         area,
                         path: menu_path,
                         copy_text,
+                        line_range,
                         ..
                     } => {
                         assert_eq!(*rid, repo_id);
                         assert_eq!(*area, DiffArea::Staged);
                         assert_eq!(menu_path, &Some(path.clone()));
                         assert_eq!(copy_text, &Some("beta".to_string()));
+                        assert_eq!(line_range, &Some((2, 2, None)));
                     }
                     _ => panic!("expected DiffEditorMenu popover for file preview"),
                 }
@@ -786,6 +810,7 @@ fn file_preview_context_menu_matches_diff_editor_actions(cx: &mut gpui::TestAppC
                     "Unstage hunk",
                     "Open file",
                     "Open file location",
+                    "Line history",
                     "Copy",
                 ] {
                     assert!(
@@ -829,3 +854,38 @@ fn file_preview_context_menu_matches_diff_editor_actions(cx: &mut gpui::TestAppC
         });
     });
 }
+
+#[test]
+fn blame_prior_target_requires_a_committed_line_with_a_parent() {
+    let line = gitcomet_core::services::BlameLine {
+        commit_id: "1111111111111111111111111111111111111111".into(),
+        author: "Alice".into(),
+        author_time_unix: None,
+        summary: "Move helpers".into(),
+        line: "fn helper() {}".to_string(),
+        original_path: Some("src/old.rs".into()),
+        original_line: Some(3),
+        has_parent: true,
+    };
+    let path = Path::new("src/new.rs");
+
+    assert_eq!(
+        blame::blame_prior_target(&line, path),
+        Some((
+            std::path::PathBuf::from("src/old.rs"),
+            "1111111111111111111111111111111111111111^".to_string()
+        ))
+    );
+
+    let root = gitcomet_core::services::BlameLine {
+        has_parent: false,
+        ..line.clone()
+    };
+    assert_eq!(blame::blame_prior_target(&root, path), None);
+
+    let uncommitted = gitcomet_core::services::BlameLine {
+        commit_id: "0000000000000000000000000000000000000000".into(),
+        ..line
+    };
+    assert_eq!(blame::blame_prior_target(&uncommitted, path), None);
+}
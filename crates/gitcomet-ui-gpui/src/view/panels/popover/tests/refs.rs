@@ -1507,6 +1507,24 @@ fn local_branch_menu_excludes_pull_merge_and_squash_for_current_branch(
             _ => false,
         });
 
+        let rename_enabled = model.items.iter().any(|item| match item {
+            ContextMenuItem::Entry {
+                label,
+                action,
+                disabled,
+                ..
+            } if label.as_ref() == "Rename branch…" => {
+                !*disabled
+                    && matches!(
+                        action.as_ref(),
+                        ContextMenuAction::OpenPopover {
+                            kind: PopoverKind::RenameBranchPrompt { repo_id: rid, branch }
+                        } if *rid == repo_id && branch == &branch_name
+                    )
+            }
+            _ => false,
+        });
+
         let delete_disabled = model.items.iter().any(|item| match item {
             ContextMenuItem::Entry {
                 label,
@@ -1528,5 +1546,6 @@ fn local_branch_menu_excludes_pull_merge_and_squash_for_current_branch(
         assert!(!has_merge, "expected merge entry to be excluded");
         assert!(!has_squash, "expected squash entry to be excluded");
         assert!(delete_disabled, "expected delete entry to be disabled");
+        assert!(rename_enabled, "expected current branch to be renameable");
     });
 }
@@ -30,11 +30,15 @@ fn status_file_menu_uses_multi_selection_for_stage(cx: &mut gpui::TestAppContext
                             path: a.clone(),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                         gitcomet_core::domain::FileStatus {
                             path: b.clone(),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                     ],
                 }
@@ -135,11 +139,15 @@ fn status_file_menu_uses_multi_selection_for_unstage(cx: &mut gpui::TestAppConte
                             path: a.clone(),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                         gitcomet_core::domain::FileStatus {
                             path: b.clone(),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                     ],
                     unstaged: vec![],
@@ -239,6 +247,8 @@ fn status_file_menu_offers_resolve_actions_for_conflicts(cx: &mut gpui::TestAppC
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Conflicted,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -365,6 +375,8 @@ fn status_file_menu_hides_external_mergetool_for_staged_conflicts(cx: &mut gpui:
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Conflicted,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: vec![],
                 }
@@ -442,6 +454,8 @@ fn status_file_menu_open_from_details_pane_does_not_double_lease_panic(
                         path: path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Conflicted,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
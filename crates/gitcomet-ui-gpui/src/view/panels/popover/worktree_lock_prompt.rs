@@ -0,0 +1,75 @@
+use super::*;
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    path: &std::path::Path,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(420.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Lock worktree"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .text_color(theme.colors.text_muted)
+                .child(path.display().to_string()),
+        )
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child(
+                    "A locked worktree is kept by prune and cannot be moved or removed \
+                     until it is unlocked.",
+                ),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .child(this.worktree_lock_reason_input.clone()),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("worktree_lock_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_prompt_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new("worktree_lock_go", "Lock")
+                        .style(components::ButtonStyle::Filled)
+                        .on_click(theme, cx, |this, _e, _w, cx| {
+                            this.submit_worktree_lock(cx);
+                        }),
+                ),
+        )
+}
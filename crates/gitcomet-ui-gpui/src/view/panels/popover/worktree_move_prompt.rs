@@ -0,0 +1,142 @@
+use super::*;
+
+pub(super) fn panel(
+    this: &mut PopoverHost,
+    repo_id: RepoId,
+    path: std::path::PathBuf,
+    cx: &mut gpui::Context<PopoverHost>,
+) -> gpui::Div {
+    let theme = this.theme;
+    let ui_scale_percent = super::popover_ui_scale_percent(cx);
+    let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
+    let worktree_name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+
+    div()
+        .flex()
+        .flex_col()
+        .w(scaled_px(640.0))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Move worktree"),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_sm()
+                .text_color(theme.colors.text_muted)
+                .child(path.display().to_string()),
+        )
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(theme.colors.text_muted)
+                .child("New location"),
+        )
+        .child(
+            div()
+                .px_2()
+                .pb_1()
+                .w_full()
+                .min_w(px(0.0))
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w(px(0.0))
+                        .child(this.worktree_path_input.clone()),
+                )
+                .child(
+                    components::Button::new("worktree_move_browse", "Browse")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |_this, _e, window, cx| {
+                            cx.stop_propagation();
+                            let view = cx.weak_entity();
+                            let worktree_name = worktree_name.clone();
+                            let rx = cx.prompt_for_paths(gpui::PathPromptOptions {
+                                files: false,
+                                directories: true,
+                                multiple: false,
+                                prompt: Some("Move worktree into folder".into()),
+                            });
+
+                            window
+                                .spawn(cx, async move |cx| {
+                                    let result = rx.await;
+                                    let paths = match result {
+                                        Ok(Ok(Some(paths))) => paths,
+                                        Ok(Ok(None)) => return,
+                                        Ok(Err(_)) | Err(_) => return,
+                                    };
+                                    let Some(parent) = paths.into_iter().next() else {
+                                        return;
+                                    };
+                                    let new_path = parent.join(&worktree_name);
+                                    let _ = view.update(cx, |this, cx| {
+                                        this.worktree_path_input.update(cx, |input, cx| {
+                                            input.set_text(new_path.display().to_string(), cx);
+                                        });
+                                        cx.notify();
+                                    });
+                                })
+                                .detach();
+                        }),
+                ),
+        )
+        .child(div().border_t_1().border_color(theme.colors.border))
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    components::Button::new("worktree_move_cancel", "Cancel")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, |this, _e, window, cx| {
+                            this.dismiss_prompt_popover(window, cx);
+                        }),
+                )
+                .child(
+                    components::Button::new("worktree_move_go", "Move")
+                        .style(components::ButtonStyle::Filled)
+                        .on_click(theme, cx, move |this, _e, _w, cx| {
+                            let new_path = this
+                                .worktree_path_input
+                                .read_with(cx, |i, _| i.text().trim().to_string());
+                            if new_path.is_empty() {
+                                this.push_toast(
+                                    components::ToastKind::Error,
+                                    "New worktree location is required".to_string(),
+                                    cx,
+                                );
+                                return;
+                            }
+                            let new_path = std::path::PathBuf::from(new_path);
+                            if new_path == path {
+                                this.close_popover(cx);
+                                return;
+                            }
+                            this.store.dispatch(Msg::MoveWorktree {
+                                repo_id,
+                                path: path.clone(),
+                                new_path,
+                            });
+                            this.close_popover(cx);
+                        }),
+                ),
+        )
+}
@@ -18,6 +18,14 @@ pub(super) fn panel(
         format!("This will remove the worktree folder and delete the local branch '{branch}'.")
             .into()
     });
+    let dirty_files = this
+        .state
+        .repos
+        .iter()
+        .find(|repo| repo.id == repo_id)
+        .and_then(|repo| repo.worktree_summary(&path))
+        .map(|summary| summary.dirty_files)
+        .filter(|count| *count > 0);
     let ui_scale_percent = super::popover_ui_scale_percent(cx);
     let scaled_px = |value: f32| super::popover_scaled_px_from_percent(value, ui_scale_percent);
 
@@ -53,6 +61,20 @@ pub(super) fn panel(
                         .child(description),
                 )
         })
+        .when_some(dirty_files, |this, count| {
+            this.child(
+                div()
+                    .px_2()
+                    .pb_1()
+                    .text_sm()
+                    .text_color(theme.colors.warning)
+                    .child(if count == 1 {
+                        "1 uncommitted file in this worktree.".to_string()
+                    } else {
+                        format!("{count} uncommitted files in this worktree.")
+                    }),
+            )
+        })
         .child(div().border_t_1().border_color(theme.colors.border))
         .child(
             div()
@@ -54,6 +54,7 @@ index 1111111..2222222 100644
             repo.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::WorkingTree {
                 path: PathBuf::from("vendor/submodule"),
                 area: gitcomet_core::domain::DiffArea::Unstaged,
+                old_path: None,
             });
             repo.diff_state.inline_submodule_diff =
                 Some(gitcomet_state::model::InlineSubmoduleDiffState {
@@ -127,6 +128,7 @@ fn push_regular_diff_content_mode_state_with_rev(
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId("deadbeef".into()),
         path: Some(path.clone()),
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
     let file_diff =
@@ -527,6 +529,7 @@ fn push_collapsed_diff_loading_fixture_state(
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId("deadbeef".into()),
         path: Some(path.clone()),
+        old_path: None,
     };
     let (unified, old_text, new_text) = build_collapsed_diff_fixture_texts();
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
@@ -1643,6 +1646,7 @@ fn diff_content_mode_switches_regular_file_diff_between_patch_and_content(
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId("deadbeef".into()),
         path: Some(path.clone()),
+        old_path: None,
     };
     let unified = "\
 diff --git a/src/lib.rs b/src/lib.rs
@@ -3581,6 +3585,7 @@ fn diff_search_f3_continues_from_previous_location_after_patch_refresh(
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: path.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let push_patch = |cx: &mut gpui::VisualTestContext, diff_rev: u64, unified: &str| {
@@ -3838,6 +3843,7 @@ fn push_raw_patch_diff_state_with_rev(
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: gitcomet_core::domain::CommitId("feedface".into()),
         path: None,
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
 
@@ -3961,6 +3967,7 @@ fn push_working_tree_full_file_horizontal_scroll_fixture_state(
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: path.clone(),
         area,
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
     let file_diff =
@@ -7124,6 +7131,7 @@ fn patch_view_applies_syntax_highlighting_to_context_lines(cx: &mut gpui::TestAp
             let target = gitcomet_core::domain::DiffTarget::Commit {
                 commit_id: gitcomet_core::domain::CommitId("deadbeef".into()),
                 path: None,
+                old_path: None,
             };
 
             let diff = gitcomet_core::domain::Diff {
@@ -7551,6 +7559,7 @@ fn yaml_commit_file_diff_keeps_consistent_highlighting_for_added_paths_and_keys(
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(path.clone()),
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
 
@@ -8052,6 +8061,7 @@ fn yaml_commit_patch_diff_keeps_consistent_highlighting_for_added_paths_and_keys
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: None,
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
 
@@ -8890,6 +8900,7 @@ fn yaml_commit_patch_diff_full_fixture_keeps_consistent_highlighting_across_file
     let target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: None,
+        old_path: None,
     };
     let diff = gitcomet_core::domain::Diff::from_unified(target.clone(), &unified);
 
@@ -9826,11 +9837,13 @@ fn yaml_commit_patch_diff_matches_commit_file_diff_for_build_release_artifacts(
     let file_target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(path.clone()),
+        old_path: None,
     };
     let file_diff = gitcomet_core::domain::Diff::from_unified(file_target.clone(), &unified);
     let patch_target = gitcomet_core::domain::DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: None,
+        old_path: None,
     };
     let patch_diff = gitcomet_core::domain::Diff::from_unified(patch_target.clone(), &unified);
     let (visible_old_lines, visible_new_lines) = patch_visible_line_numbers(&patch_diff);
@@ -10786,6 +10799,7 @@ fn file_diff_cache_rebuilds_when_patch_arrives_after_same_file_refresh(
     let target = DiffTarget::WorkingTree {
         path: path.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     let old_text = "fn main() {\n    let value = 1;\n    let stable = 10;\n}\n";
     let new_text = "fn main() {\n    let value = 2;\n    let stable = 10;\n    let added = value + stable;\n}\n";
@@ -14551,6 +14565,7 @@ fn yaml_commit_file_diff_transition_from_patch_clears_stale_split_cache(
     let patch_target = DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: None,
+        old_path: None,
     };
     let patch_diff = gitcomet_core::domain::Diff::from_unified(patch_target.clone(), &patch_text);
 
@@ -14615,6 +14630,7 @@ fn yaml_commit_file_diff_transition_from_patch_clears_stale_split_cache(
     let file_target = DiffTarget::Commit {
         commit_id,
         path: Some(path.clone()),
+        old_path: None,
     };
     let file_diff = gitcomet_core::domain::Diff::from_unified(file_target.clone(), &unified);
 
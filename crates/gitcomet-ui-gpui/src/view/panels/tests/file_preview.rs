@@ -1235,6 +1235,7 @@ fn committed_deleted_minified_utf8_json_preview_streams_from_indexed_source(
             repo.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::Commit {
                 commit_id: commit_id.clone(),
                 path: Some(file_rel.clone()),
+                old_path: None,
             });
             repo.diff_state.diff_state_rev = 1;
             repo.diff_state.diff = gitcomet_state::model::Loadable::Error(
@@ -1260,6 +1261,9 @@ fn committed_deleted_minified_utf8_json_preview_streams_from_indexed_source(
                         path: file_rel.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Deleted,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     }],
                 },
             ));
@@ -18,6 +18,7 @@ fn patch_diff_search_query_keeps_stable_style_cache_entries(cx: &mut gpui::TestA
             let target = gitcomet_core::domain::DiffTarget::Commit {
                 commit_id: gitcomet_core::domain::CommitId("feedface".into()),
                 path: None,
+                old_path: None,
             };
 
             let diff = gitcomet_core::domain::Diff {
@@ -617,6 +618,7 @@ fn committed_deleted_file_preview_uses_preview_text_file_without_patch_fallback(
             repo.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::Commit {
                 commit_id: commit_id.clone(),
                 path: Some(file_rel.clone()),
+                old_path: None,
             });
             repo.diff_state.diff = gitcomet_state::model::Loadable::Error(
                 "parsed patch diff should not be consulted for deleted file preview".into(),
@@ -646,6 +648,9 @@ fn committed_deleted_file_preview_uses_preview_text_file_without_patch_fallback(
                         path: file_rel.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Deleted,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     }],
                 },
             ));
@@ -901,6 +906,7 @@ fn unstaged_deleted_gitlink_preview_does_not_stay_loading(cx: &mut gpui::TestApp
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     let unified = format!(
         "diff --git a/{0} b/{0}\nindex 1234567..0000000 160000\n--- a/{0}\n+++ /dev/null\n@@ -1 +0,0 @@\n-Subproject commit c35be02cd52b18c7b2894dc570825b43c94130ed\n",
@@ -969,6 +975,7 @@ fn unstaged_modified_gitlink_target_uses_unified_diff_mode(cx: &mut gpui::TestAp
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
     let unified = format!(
         "diff --git a/{0} b/{0}\nindex 1234567..89abcde 160000\n--- a/{0}\n+++ b/{0}\n@@ -1 +1 @@\n-Subproject commit 1234567890123456789012345678901234567890\n+Subproject commit 89abcdef0123456789abcdef0123456789abcdef\n",
@@ -985,11 +992,15 @@ fn unstaged_modified_gitlink_target_uses_unified_diff_mode(cx: &mut gpui::TestAp
                         path: file_rel.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Added,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: vec![gitcomet_core::domain::FileStatus {
                         path: file_rel.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -1113,11 +1124,15 @@ fn switching_diff_target_clears_stale_worktree_preview_loading(cx: &mut gpui::Te
                                 path: file_a.clone(),
                                 kind: gitcomet_core::domain::FileStatusKind::Untracked,
                                 conflict: None,
+                                old_path: None,
+                                similarity: None,
                             },
                             gitcomet_core::domain::FileStatus {
                                 path: file_b.clone(),
                                 kind: gitcomet_core::domain::FileStatusKind::Untracked,
                                 conflict: None,
+                                old_path: None,
+                                similarity: None,
                             },
                         ],
                     }
@@ -1127,6 +1142,7 @@ fn switching_diff_target_clears_stale_worktree_preview_loading(cx: &mut gpui::Te
                     Some(gitcomet_core::domain::DiffTarget::WorkingTree {
                         path: target_path,
                         area: gitcomet_core::domain::DiffArea::Unstaged,
+                        old_path: None,
                     });
                 repo.diff_state.diff_state_rev = diff_state_rev;
                 repo
@@ -1342,6 +1358,7 @@ fn untracked_directory_target_clears_stale_file_loading_state(cx: &mut gpui::Tes
                     gitcomet_core::domain::DiffTarget::WorkingTree {
                         path: file_rel.clone(),
                         area: gitcomet_core::domain::DiffArea::Unstaged,
+                        old_path: None,
                     },
                     "",
                 ),
@@ -1406,6 +1423,7 @@ fn directory_target_with_loading_status_clears_stale_file_loading_state(
             repo.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::WorkingTree {
                 path: file_rel.clone(),
                 area: gitcomet_core::domain::DiffArea::Unstaged,
+                old_path: None,
             });
             repo.diff_state.diff = gitcomet_state::model::Loadable::Loading;
 
@@ -1593,6 +1611,9 @@ fn commit_details_added_file_copy_path_works_after_left_clicking_menu_entry(
                         path: added_path.clone(),
                         kind: gitcomet_core::domain::FileStatusKind::Added,
                         is_submodule: false,
+                        old_path: None,
+                        similarity: None,
+                        generated: false,
                     }],
                 },
             ));
@@ -1688,6 +1709,7 @@ fn commit_details_file_right_click_only_opens_menu_for_added_modified_and_delete
     let initial_target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: std::path::PathBuf::from("src/current.rs"),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     cx.update(|_window, app| {
@@ -1710,6 +1732,9 @@ fn commit_details_file_right_click_only_opens_menu_for_added_modified_and_delete
                             path: path.clone(),
                             kind: *kind,
                             is_submodule: false,
+                            old_path: None,
+                            similarity: None,
+                            generated: false,
                         })
                         .collect(),
                 },
@@ -1795,6 +1820,9 @@ fn commit_details_file_list_keeps_visible_viewport_when_overflowing(cx: &mut gpu
             path: std::path::PathBuf::from(format!("src/commit_details/dir_{ix}/file_{ix}.rs")),
             kind: gitcomet_core::domain::FileStatusKind::Modified,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         })
         .collect::<Vec<_>>();
 
@@ -1883,6 +1911,9 @@ fn ui_scale_commit_details_file_list_content_height_scales(cx: &mut gpui::TestAp
             path: std::path::PathBuf::from(format!("src/commit_zoom/dir_{ix}/file_{ix}.rs")),
             kind: gitcomet_core::domain::FileStatusKind::Modified,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         })
         .collect::<Vec<_>>();
 
@@ -1973,6 +2004,8 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                         gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from(
@@ -1980,6 +2013,8 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                     ],
                     unstaged: vec![
@@ -1989,6 +2024,8 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                         gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from(
@@ -1996,6 +2033,8 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                     ],
                 }
@@ -2018,6 +2057,9 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             is_submodule: false,
+                            old_path: None,
+                            similarity: None,
+                            generated: false,
                         },
                         gitcomet_core::domain::CommitFileChange {
                             path: std::path::PathBuf::from(
@@ -2025,6 +2067,9 @@ fn details_row_renderers_begin_separate_alignment_groups_for_status_and_commit_f
                             ),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             is_submodule: false,
+                            old_path: None,
+                            similarity: None,
+                            generated: false,
                         },
                     ],
                 },
@@ -2228,6 +2273,8 @@ fn commit_message_focus_after_initial_draw_accepts_typed_input(cx: &mut gpui::Te
                     path: std::path::PathBuf::from("staged.txt"),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 }],
                 unstaged: Vec::new(),
             }
@@ -2287,6 +2334,8 @@ fn commit_click_dispatches_after_state_update_without_intermediate_redraw(
                         path: std::path::PathBuf::from(format!("staged-{ix}.txt")),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     })
                     .collect(),
                 unstaged: Vec::new(),
@@ -2416,11 +2465,15 @@ fn status_section_drag_updates_saved_height(cx: &mut gpui::TestAppContext) {
                         path: std::path::PathBuf::from("staged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: vec![gitcomet_core::domain::FileStatus {
                         path: std::path::PathBuf::from("unstaged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                 }
                 .into(),
@@ -2529,12 +2582,16 @@ fn staged_section_remains_visible_after_window_resize_with_saved_split_height(
                         path: std::path::PathBuf::from("staged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: (0..30)
                         .map(|ix| gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from(format!("unstaged-{ix}.txt")),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         })
                         .collect(),
                 }
@@ -2646,17 +2703,23 @@ fn split_status_section_resize_moves_untracked_section(cx: &mut gpui::TestAppCon
                         path: std::path::PathBuf::from("staged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: vec![
                         gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from("new.txt"),
                             kind: gitcomet_core::domain::FileStatusKind::Untracked,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                         gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from("tracked.txt"),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         },
                     ],
                 }
@@ -2805,12 +2868,16 @@ fn unstaged_scroll_viewport_tracks_resized_section_height(cx: &mut gpui::TestApp
                         path: std::path::PathBuf::from("staged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: (0..30)
                         .map(|ix| gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from(format!("unstaged-{ix}.txt")),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         })
                         .collect(),
                 }
@@ -2904,6 +2971,8 @@ fn split_unstaged_scroll_viewport_tracks_resized_section_height(cx: &mut gpui::T
                             path: std::path::PathBuf::from(format!("unstaged-{ix}.txt")),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         })
                         .collect(),
                 }
@@ -3003,12 +3072,16 @@ fn split_unstaged_scroll_viewport_updates_after_outer_resize_shrink(cx: &mut gpu
                         path: std::path::PathBuf::from("staged.txt"),
                         kind: gitcomet_core::domain::FileStatusKind::Modified,
                         conflict: None,
+                        old_path: None,
+                        similarity: None,
                     }],
                     unstaged: (0..30)
                         .map(|ix| gitcomet_core::domain::FileStatus {
                             path: std::path::PathBuf::from(format!("unstaged-{ix}.txt")),
                             kind: gitcomet_core::domain::FileStatusKind::Modified,
                             conflict: None,
+                            old_path: None,
+                            similarity: None,
                         })
                         .collect(),
                 }
@@ -163,6 +163,7 @@ fn worktree_markdown_diff_defaults_to_preview_mode_and_shows_preview_toggle(
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let _ = std::fs::remove_dir_all(&workdir);
@@ -479,6 +480,7 @@ fn split_markdown_diff_scroll_sync_matrix_covers_all_modes_and_axes(cx: &mut gpu
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let _ = std::fs::remove_dir_all(&workdir);
@@ -681,6 +683,7 @@ fn worktree_markdown_preview_short_code_block_shell_spans_preview_width(
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let _ = std::fs::remove_dir_all(&workdir);
@@ -791,6 +794,7 @@ fn worktree_markdown_preview_list_text_box_stays_shorter_than_row_shell(
     let target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: file_rel.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let _ = std::fs::remove_dir_all(&workdir);
@@ -1169,6 +1173,7 @@ fn diff_target_change_clears_worktree_markdown_preview_cache_state(cx: &mut gpui
     let preview_target = gitcomet_core::domain::DiffTarget::WorkingTree {
         path: preview_path.clone(),
         area: gitcomet_core::domain::DiffArea::Unstaged,
+        old_path: None,
     };
 
     let set_state = |cx: &mut gpui::VisualTestContext,
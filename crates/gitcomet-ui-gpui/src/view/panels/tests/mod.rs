@@ -982,6 +982,8 @@ pub(super) fn set_test_file_status_with_conflict(
         path: path.clone(),
         kind,
         conflict,
+        old_path: None,
+        similarity: None,
     };
     let (staged, unstaged) = match area {
         gitcomet_core::domain::DiffArea::Staged => (vec![file_status], vec![]),
@@ -999,8 +1001,11 @@ pub(super) fn set_test_file_status_with_conflict(
             Some(_)
         )
     );
-    repo.diff_state.diff_target =
-        Some(gitcomet_core::domain::DiffTarget::WorkingTree { path, area });
+    repo.diff_state.diff_target = Some(gitcomet_core::domain::DiffTarget::WorkingTree {
+        path,
+        area,
+        old_path: None,
+    });
     repo.diff_state.diff_state_rev = repo.diff_state.diff_state_rev.wrapping_add(1);
 }
 
@@ -726,6 +726,8 @@ fn simple_worktree_repo(
                     path,
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 })
                 .collect(),
         }
@@ -734,6 +736,7 @@ fn simple_worktree_repo(
     let target = DiffTarget::WorkingTree {
         path: selected_path.to_path_buf(),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo.diff_state.diff_target = Some(target.clone());
     repo.diff_state.diff = Loadable::Ready(simple_hunk_diff(target).into());
@@ -1147,22 +1150,30 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
                 path: staged_path.clone(),
                 kind: gitcomet_core::domain::FileStatusKind::Added,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
             unstaged: vec![
                 gitcomet_core::domain::FileStatus {
                     path: unstaged_path.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: hunk_path.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: conflicted_path.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Conflicted,
                     conflict: Some(gitcomet_core::domain::FileConflictKind::BothModified),
+                    old_path: None,
+                    similarity: None,
                 },
             ],
         }
@@ -1171,11 +1182,13 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: hunk_path.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
     repo.diff_state.diff = Loadable::Ready(
         simple_hunk_diff(DiffTarget::WorkingTree {
             path: hunk_path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -1199,7 +1212,8 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
             repo_id: rid,
             target: DiffTarget::Commit {
                 commit_id: cid,
-                path: None
+                path: None,
+                ..
             }
         } if *rid == repo_id && cid == &commit_id
     );
@@ -1254,7 +1268,8 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
             repo_id: rid,
             target: DiffTarget::Commit {
                 commit_id: cid,
-                path: Some(path)
+                path: Some(path),
+                ..
             }
         } if *rid == repo_id && cid == &commit_id && path == &commit_file_path
     );
@@ -1288,7 +1303,7 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
         "Enter",
         ContextMenuAction::SelectDiff {
             repo_id: rid,
-            target: DiffTarget::WorkingTree { path, area }
+            target: DiffTarget::WorkingTree { path, area, .. }
         } if *rid == repo_id && path == &unstaged_path && *area == DiffArea::Unstaged
     );
     assert_shortcut_action!(
@@ -1339,7 +1354,7 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
         "Enter",
         ContextMenuAction::SelectDiff {
             repo_id: rid,
-            target: DiffTarget::WorkingTree { path, area }
+            target: DiffTarget::WorkingTree { path, area, .. }
         } if *rid == repo_id && path == &staged_path && *area == DiffArea::Staged
     );
     assert_shortcut_action!(
@@ -1464,6 +1479,7 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
                 lines_count: 3,
                 copy_text: Some("copied selection".into()),
                 copy_target: None,
+                line_range: None,
             },
         )
     });
@@ -1507,6 +1523,7 @@ fn file_and_diff_context_menu_shortcuts_match_expected_actions(cx: &mut gpui::Te
                 lines_count: 1,
                 copy_text: Some("staged copy".into()),
                 copy_target: None,
+                line_range: None,
             },
         )
     });
@@ -1606,16 +1623,22 @@ fn split_untracked_file_navigation_stays_within_untracked_section(cx: &mut gpui:
                     path: untracked_a.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Untracked,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: tracked.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: untracked_b.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Untracked,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             ],
         }
@@ -1624,6 +1647,7 @@ fn split_untracked_file_navigation_stays_within_untracked_section(cx: &mut gpui:
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: untracked_a.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -1669,16 +1693,22 @@ fn split_tracked_file_navigation_does_not_cross_into_untracked_section(
                     path: untracked.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Untracked,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: tracked_a.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: tracked_b.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             ],
         }
@@ -1687,6 +1717,7 @@ fn split_tracked_file_navigation_does_not_cross_into_untracked_section(
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: tracked_a.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -1723,6 +1754,9 @@ fn commit_details_file_navigation_scrolls_selected_row_into_view(cx: &mut gpui::
             path: std::path::PathBuf::from(format!("src/commit_nav/file_{ix:02}.rs")),
             kind: FileStatusKind::Modified,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         })
         .collect::<Vec<_>>();
     let start_ix = 40usize;
@@ -1738,6 +1772,7 @@ fn commit_details_file_navigation_scrolls_selected_row_into_view(cx: &mut gpui::
     repo.diff_state.diff_target = Some(DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(files[start_ix].path.clone()),
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -1796,11 +1831,17 @@ fn commit_details_text_input_f4_navigates_files_without_stealing_focus(
             path: std::path::PathBuf::from("src/commit_details/first.rs"),
             kind: FileStatusKind::Modified,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         },
         CommitFileChange {
             path: std::path::PathBuf::from("src/commit_details/second.rs"),
             kind: FileStatusKind::Modified,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         },
     ];
 
@@ -1816,6 +1857,7 @@ fn commit_details_text_input_f4_navigates_files_without_stealing_focus(
     repo.diff_state.diff_target = Some(DiffTarget::Commit {
         commit_id: commit_id.clone(),
         path: Some(files[0].path.clone()),
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -1879,6 +1921,8 @@ fn commit_message_text_input_f3_prefers_diff_search_matches(cx: &mut gpui::TestA
                 path: hunk_path.clone(),
                 kind: gitcomet_core::domain::FileStatusKind::Modified,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
         }
         .into(),
@@ -1886,11 +1930,13 @@ fn commit_message_text_input_f3_prefers_diff_search_matches(cx: &mut gpui::TestA
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: hunk_path.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
     repo.diff_state.diff = Loadable::Ready(
         simple_hunk_diff(DiffTarget::WorkingTree {
             path: hunk_path,
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -2019,6 +2065,8 @@ fn commit_message_text_input_secondary_enter_commits_staged_changes(cx: &mut gpu
                 path: staged_path,
                 kind: gitcomet_core::domain::FileStatusKind::Modified,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
             unstaged: vec![],
         }
@@ -2103,6 +2151,7 @@ fn commit_message_text_input_change_navigation_shortcuts_move_diff_without_steal
         three_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -2290,11 +2339,15 @@ fn create_branch_popover_text_input_f4_navigates_diff_without_closing_popover(
                     path: first.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: second.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             ],
         }
@@ -2303,6 +2356,7 @@ fn create_branch_popover_text_input_f4_navigates_diff_without_closing_popover(
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: first.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -2622,6 +2676,7 @@ fn diff_search_overlay_does_not_reflow_action_bar_or_content(cx: &mut gpui::Test
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -2779,6 +2834,7 @@ fn diff_action_menu_contains_whitespace_setting(cx: &mut gpui::TestAppContext) {
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -2910,6 +2966,7 @@ fn diff_view_toolbar_toggle_restores_diff_panel_focus(cx: &mut gpui::TestAppCont
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -2972,6 +3029,7 @@ fn diff_search_query_edit_selects_first_match_and_updates_count(cx: &mut gpui::T
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -3039,6 +3097,7 @@ fn diff_search_preserve_current_scrolls_when_matches_first_appear(cx: &mut gpui:
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -3115,6 +3174,7 @@ fn diff_search_passive_visible_refresh_preserves_scroll_and_match(cx: &mut gpui:
         searchable_scroll_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -3657,6 +3717,7 @@ fn diff_editor_staging_context_menu_restores_diff_panel_focus_for_f4(
             lines_count: 1,
             copy_text: None,
             copy_target: None,
+            line_range: None,
         },
     );
 
@@ -3849,6 +3910,7 @@ fn detached_window_focus_uses_global_diff_shortcut_fallback(cx: &mut gpui::TestA
         two_hunk_diff(DiffTarget::WorkingTree {
             path: first.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -4079,6 +4141,7 @@ fn switching_diff_content_mode_restores_diff_panel_focus_for_change_navigation(
         two_hunk_diff(DiffTarget::WorkingTree {
             path: path.clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         })
         .into(),
     );
@@ -4203,16 +4266,22 @@ fn switching_change_tracking_view_restores_diff_panel_focus_for_adjacent_navigat
                     path: untracked_a.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Untracked,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: tracked,
                     kind: gitcomet_core::domain::FileStatusKind::Modified,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
                 gitcomet_core::domain::FileStatus {
                     path: untracked_b.clone(),
                     kind: gitcomet_core::domain::FileStatusKind::Untracked,
                     conflict: None,
+                    old_path: None,
+                    similarity: None,
                 },
             ],
         }
@@ -4221,6 +4290,7 @@ fn switching_change_tracking_view_restores_diff_panel_focus_for_adjacent_navigat
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path: untracked_a.clone(),
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
@@ -4301,6 +4371,8 @@ fn dismissing_change_tracking_settings_with_escape_restores_diff_panel_focus(
                 path: path.clone(),
                 kind: gitcomet_core::domain::FileStatusKind::Modified,
                 conflict: None,
+                old_path: None,
+                similarity: None,
             }],
         }
         .into(),
@@ -4308,6 +4380,7 @@ fn dismissing_change_tracking_settings_with_escape_restores_diff_panel_focus(
     repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         path,
         area: DiffArea::Unstaged,
+        old_path: None,
     });
 
     apply_state(cx, &view, app_state_with_active_repo(repo));
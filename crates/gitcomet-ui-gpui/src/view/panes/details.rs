@@ -5,12 +5,30 @@ use gitcomet_state::model::{AuthRetryOperation, CommandLogEntry};
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
 
+/// Flattened tree rows for one status section, valid while the section's
+/// status revision and collapsed directories are unchanged.
+struct StatusTreeRowsCache {
+    repo_id: RepoId,
+    status_rev: u64,
+    rows: Arc<[StatusTreeRow]>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct PendingCommitAmend {
     repo_id: RepoId,
     last_command_log_entry: Option<CommandLogEntry>,
 }
 
+/// A reword started from the history context menu. The commit message editor
+/// holds the message of `commit_id` until the reword is submitted or
+/// cancelled, then gets back the draft it replaced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in super::super) struct CommitReword {
+    pub(in super::super) repo_id: RepoId,
+    pub(in super::super) commit_id: CommitId,
+    draft: SharedString,
+}
+
 pub(in super::super) struct DetailsPaneView {
     pub(in super::super) store: Arc<AppStore>,
     state: Arc<AppState>,
@@ -37,6 +55,8 @@ pub(in super::super) struct DetailsPaneView {
     pub(in super::super) unstaged_scroll: UniformListScrollHandle,
     pub(in super::super) staged_scroll: UniformListScrollHandle,
     pub(in super::super) commit_files_scroll: UniformListScrollHandle,
+    pub(in super::super) commit_files_generated_expanded: bool,
+    pub(in super::super) commit_file_search_scroll: UniformListScrollHandle,
     pub(in super::super) commit_message_scroll: ScrollHandle,
     pub(in super::super) commit_scroll: ScrollHandle,
 
@@ -45,16 +65,21 @@ pub(in super::super) struct DetailsPaneView {
     pub(in super::super) commit_details_sha_input: Entity<components::TextInput>,
     pub(in super::super) commit_details_date_input: Entity<components::TextInput>,
     pub(in super::super) commit_details_parent_input: Entity<components::TextInput>,
+    pub(in super::super) commit_file_search_input: Entity<components::TextInput>,
+    _commit_file_search_input_subscription: gpui::Subscription,
     pub(in super::super) commit_message_drafts: HashMap<RepoId, SharedString>,
     pub(in super::super) commit_amend_enabled: bool,
     pub(in super::super) commit_push_after_enabled: bool,
     pending_commit_amend: Option<PendingCommitAmend>,
+    pub(in super::super) commit_reword: Option<CommitReword>,
     pub(in super::super) commit_message_user_edited: bool,
     pub(in super::super) commit_message_last_text: SharedString,
     pub(in super::super) commit_message_programmatic_change: bool,
 
     pub(in super::super) status_multi_selection: HashMap<RepoId, StatusMultiSelection>,
     pub(in super::super) status_multi_selection_last_status: HashMap<RepoId, (u64, u64)>,
+    status_tree_collapsed: HashMap<(RepoId, StatusSection), HashSet<std::path::PathBuf>>,
+    status_tree_rows: std::cell::RefCell<HashMap<StatusSection, StatusTreeRowsCache>>,
 
     pub(in super::super) commit_details_delay: Option<CommitDetailsDelayState>,
     pub(in super::super) commit_details_delay_seq: u64,
@@ -186,6 +211,7 @@ impl DetailsPaneView {
             repo.ops_rev.hash(&mut hasher);
             repo.history_state.selected_commit_rev.hash(&mut hasher);
             repo.history_state.commit_details_rev.hash(&mut hasher);
+            repo.history_state.commit_file_search_rev.hash(&mut hasher);
             repo.merge_message_rev.hash(&mut hasher);
             repo.head_branch_rev.hash(&mut hasher);
             repo.branches_rev.hash(&mut hasher);
@@ -303,6 +329,28 @@ impl DetailsPaneView {
             input
         });
 
+        let commit_file_search_input = cx.new(|cx| {
+            components::TextInput::new(
+                components::TextInputOptions {
+                    placeholder: "Find in commit files".into(),
+                    multiline: false,
+                    read_only: false,
+                    chromeless: false,
+                    soft_wrap: false,
+                },
+                window,
+                cx,
+            )
+        });
+        let commit_file_search_subscription =
+            cx.observe(&commit_file_search_input, |this, input, cx| {
+                let submitted = input.update(cx, |input, _| input.take_enter_pressed());
+                let query = input.read(cx).text().trim().to_string();
+                if submitted || query.is_empty() {
+                    this.search_commit_files(query, cx);
+                }
+            });
+
         let commit_message_subscription = cx.observe(&commit_message_input, |this, input, cx| {
             let next: SharedString = input.read(cx).text().to_string().into();
             if this.commit_message_programmatic_change {
@@ -325,6 +373,7 @@ impl DetailsPaneView {
             ui_scale_percent,
             _ui_model_subscription: subscription,
             _commit_message_input_subscription: commit_message_subscription,
+            _commit_file_search_input_subscription: commit_file_search_subscription,
             root_view,
             tooltip_host,
             notify_fingerprint: initial_fingerprint,
@@ -345,6 +394,8 @@ impl DetailsPaneView {
             unstaged_scroll: UniformListScrollHandle::default(),
             staged_scroll: UniformListScrollHandle::default(),
             commit_files_scroll: UniformListScrollHandle::default(),
+            commit_files_generated_expanded: false,
+            commit_file_search_scroll: UniformListScrollHandle::default(),
             commit_message_scroll,
             commit_scroll: ScrollHandle::new(),
             commit_message_input,
@@ -352,15 +403,19 @@ impl DetailsPaneView {
             commit_details_sha_input,
             commit_details_date_input,
             commit_details_parent_input,
+            commit_file_search_input,
             commit_message_drafts: HashMap::default(),
             commit_amend_enabled: false,
             commit_push_after_enabled,
             pending_commit_amend: None,
+            commit_reword: None,
             commit_message_user_edited: false,
             commit_message_last_text: SharedString::default(),
             commit_message_programmatic_change: false,
             status_multi_selection: HashMap::default(),
             status_multi_selection_last_status: HashMap::default(),
+            status_tree_collapsed: HashMap::default(),
+            status_tree_rows: std::cell::RefCell::new(HashMap::default()),
             commit_details_delay: None,
             commit_details_delay_seq: 0,
             path_display_cache: std::cell::RefCell::new(path_display::PathDisplayCache::default()),
@@ -397,6 +452,8 @@ impl DetailsPaneView {
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.commit_details_parent_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
+        self.commit_file_search_input
+            .update(cx, |input, cx| input.set_theme(theme, cx));
         cx.notify();
     }
 
@@ -467,12 +524,57 @@ impl DetailsPaneView {
         }
 
         self.commit_amend_enabled = enabled;
-        if !enabled {
+        if enabled {
+            self.finish_reword_commit(cx);
+        } else {
             self.pending_commit_amend = None;
         }
         cx.notify();
     }
 
+    /// Load `message` into the commit message editor so that submitting it
+    /// rewords `commit_id` instead of committing.
+    pub(in super::super) fn start_reword_commit(
+        &mut self,
+        repo_id: RepoId,
+        commit_id: CommitId,
+        message: String,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.active_repo_id() != Some(repo_id) {
+            return;
+        }
+        let draft = match self.commit_reword.take() {
+            Some(reword) => reword.draft,
+            None => self.commit_message_input.read(cx).text().to_string().into(),
+        };
+        if self.commit_amend_enabled {
+            self.commit_amend_enabled = false;
+            self.pending_commit_amend = None;
+            self.sync_commit_amend_enabled_to_root(false, cx);
+        }
+        self.commit_reword = Some(CommitReword {
+            repo_id,
+            commit_id,
+            draft,
+        });
+        self.set_commit_message_from_history(message, window, cx);
+    }
+
+    pub(in super::super) fn finish_reword_commit(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(reword) = self.commit_reword.take() else {
+            return;
+        };
+        self.commit_message_programmatic_change = true;
+        self.commit_message_last_text = reword.draft.clone();
+        self.commit_message_input
+            .update(cx, |input, cx| input.set_text(reword.draft.to_string(), cx));
+        self.commit_message_scroll
+            .set_offset(point(px(0.0), px(0.0)));
+        cx.notify();
+    }
+
     pub(in super::super) fn set_commit_push_after_enabled(
         &mut self,
         enabled: bool,
@@ -659,6 +761,59 @@ impl DetailsPaneView {
         cache.rows_for(&(repo_id, commit_details_rev), files)
     }
 
+    /// Visible rows of `section` when the status lists are shown as a tree.
+    pub(in super::super) fn status_tree_rows(
+        &self,
+        repo: &RepoState,
+        section: StatusSection,
+    ) -> Arc<[StatusTreeRow]> {
+        let status_rev = status_section_rev(repo, section);
+        let mut cache = self.status_tree_rows.borrow_mut();
+        if let Some(cached) = cache.get(&section)
+            && cached.repo_id == repo.id
+            && cached.status_rev == status_rev
+        {
+            return Arc::clone(&cached.rows);
+        }
+
+        let rows: Arc<[StatusTreeRow]> = match StatusSectionEntries::from_repo(repo, section) {
+            Some(entries) => {
+                let no_collapsed = HashSet::default();
+                let collapsed = self
+                    .status_tree_collapsed
+                    .get(&(repo.id, section))
+                    .unwrap_or(&no_collapsed);
+                build_status_tree_rows(entries.iter(), collapsed).into()
+            }
+            None => Arc::from([]),
+        };
+        cache.insert(
+            section,
+            StatusTreeRowsCache {
+                repo_id: repo.id,
+                status_rev,
+                rows: Arc::clone(&rows),
+            },
+        );
+        rows
+    }
+
+    pub(in super::super) fn toggle_status_tree_directory(
+        &mut self,
+        repo_id: RepoId,
+        section: StatusSection,
+        dir: std::path::PathBuf,
+    ) {
+        let collapsed = self
+            .status_tree_collapsed
+            .entry((repo_id, section))
+            .or_default();
+        if !collapsed.remove(&dir) {
+            collapsed.insert(dir);
+        }
+        self.status_tree_rows.get_mut().remove(&section);
+    }
+
     pub(in super::super) fn status_path_alignment_group(
         &self,
         section: StatusSection,
@@ -782,6 +937,11 @@ impl DetailsPaneView {
 
         let switched_repo = prev_active_repo_id != next_repo_id;
         let mut restored_commit_message: Option<SharedString> = None;
+        let reword_draft = if switched_repo {
+            self.commit_reword.take().map(|reword| reword.draft)
+        } else {
+            None
+        };
         if switched_repo {
             let was_amend_enabled = self.commit_amend_enabled;
             self.commit_amend_enabled = false;
@@ -818,8 +978,9 @@ impl DetailsPaneView {
         }
         if switched_repo {
             if let Some(prev_repo_id) = prev_active_repo_id {
-                let current: SharedString =
-                    self.commit_message_input.read(cx).text().to_string().into();
+                let current: SharedString = reword_draft.unwrap_or_else(|| {
+                    self.commit_message_input.read(cx).text().to_string().into()
+                });
                 if current.is_empty() {
                     self.commit_message_drafts.remove(&prev_repo_id);
                 } else {
@@ -849,6 +1010,7 @@ impl DetailsPaneView {
             self.commit_scroll.set_offset(point(px(0.0), px(0.0)));
             self.commit_files_scroll
                 .scroll_to_item_strict(0, gpui::ScrollStrategy::Top);
+            self.commit_files_generated_expanded = false;
         }
 
         let merge_started = match (prev_active_repo_id, next_repo_id) {
@@ -994,6 +1156,50 @@ impl DetailsPaneView {
         });
     }
 
+    /// Search the selected commit's files for `query` with the diff search
+    /// options; an empty query clears the results.
+    fn search_commit_files(&mut self, query: String, cx: &mut gpui::Context<Self>) {
+        let Some(repo) = self.active_repo() else {
+            return;
+        };
+        let Some(commit_id) = repo.history_state.selected_commit.clone() else {
+            return;
+        };
+        if query.is_empty() && repo.history_state.commit_file_search.is_none() {
+            return;
+        }
+        let repo_id = repo.id;
+        let options = self
+            .root_view
+            .update(cx, |root, cx| root.main_pane.read(cx).diff_search_options)
+            .unwrap_or_default();
+        self.store.dispatch(Msg::SearchCommitFiles {
+            repo_id,
+            commit_id,
+            query,
+            options,
+        });
+    }
+
+    /// Open a commit file search hit in the diff view with the query
+    /// highlighted.
+    pub(in super::super) fn open_commit_file_search_match(
+        &mut self,
+        repo_id: RepoId,
+        target: DiffTarget,
+        query: String,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.store.dispatch(Msg::SelectDiff { repo_id, target });
+        let _ = self.root_view.update(cx, |root, cx| {
+            root.main_pane.update(cx, |pane, cx| {
+                pane.open_diff_search_with_query(&query, window, cx);
+                cx.notify();
+            });
+        });
+    }
+
     pub(in super::super) fn focus_diff_panel(
         &mut self,
         window: &mut Window,
@@ -2,7 +2,8 @@ use super::super::*;
 use crate::view::caches::{
     HistoryShortShaVm, HistoryVisibleIndices, HistoryWhenVm, analyze_history_stashes,
     build_history_branch_text_by_target, build_history_tag_names_by_target,
-    build_history_visible_indices, next_history_stash_tip_for_commit_ix,
+    build_history_visible_indices, history_fixup_grouped_order, history_fixup_targets,
+    history_graph_commits_with_grouped_fixups, next_history_stash_tip_for_commit_ix,
 };
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
@@ -606,6 +607,129 @@ struct HistorySelectedListIndexCache {
     list_ix: usize,
 }
 
+/// Commits picked with shift/ctrl clicks in the history list. Batch actions
+/// only apply once at least two commits are selected.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(in crate::view) struct HistoryMultiSelection {
+    pub(in crate::view) commits: Vec<CommitId>,
+    anchor: Option<CommitId>,
+}
+
+impl HistoryMultiSelection {
+    pub(in crate::view) fn is_batch(&self) -> bool {
+        self.commits.len() > 1
+    }
+
+    pub(in crate::view) fn contains(&self, commit_id: &CommitId) -> bool {
+        self.commits.iter().any(|id| id == commit_id)
+    }
+}
+
+/// Update `selection` for a click on `clicked`. Shift extends from the anchor
+/// (or the currently selected commit) over `visible_commits`, which is only
+/// built when needed; ctrl/cmd toggles the clicked commit.
+pub(in crate::view) fn apply_history_multi_selection_click(
+    selection: &mut HistoryMultiSelection,
+    clicked: &CommitId,
+    modifiers: gpui::Modifiers,
+    selected_commit: Option<&CommitId>,
+    visible_commits: impl FnOnce() -> Vec<CommitId>,
+) {
+    let set_single = |selection: &mut HistoryMultiSelection| {
+        selection.commits = vec![clicked.clone()];
+        selection.anchor = Some(clicked.clone());
+    };
+
+    if modifiers.shift {
+        let Some(anchor) = selection
+            .anchor
+            .clone()
+            .or_else(|| selected_commit.cloned())
+        else {
+            set_single(selection);
+            return;
+        };
+        let visible = visible_commits();
+        let (Some(anchor_ix), Some(clicked_ix)) = (
+            visible.iter().position(|id| *id == anchor),
+            visible.iter().position(|id| id == clicked),
+        ) else {
+            set_single(selection);
+            return;
+        };
+        let (a, b) = if anchor_ix <= clicked_ix {
+            (anchor_ix, clicked_ix)
+        } else {
+            (clicked_ix, anchor_ix)
+        };
+        selection.commits = visible[a..=b].to_vec();
+        selection.anchor = Some(anchor);
+        return;
+    }
+
+    if modifiers.secondary() || modifiers.control || modifiers.platform {
+        if selection.commits.is_empty()
+            && let Some(selected) = selected_commit
+        {
+            selection.commits.push(selected.clone());
+        }
+        if let Some(ix) = selection.commits.iter().position(|id| id == clicked) {
+            selection.commits.remove(ix);
+            if selection.commits.is_empty() {
+                selection.anchor = None;
+            }
+        } else {
+            selection.commits.push(clicked.clone());
+            selection.anchor = Some(clicked.clone());
+        }
+        return;
+    }
+
+    set_single(selection);
+}
+
+/// The `(from, to)` endpoints of the combined diff for `selected` when those
+/// commits form one unbroken first-parent run in `commits` (newest first).
+pub(in crate::view) fn history_selection_commit_range(
+    commits: &[Commit],
+    selected: &[CommitId],
+) -> Option<(CommitId, CommitId)> {
+    if selected.len() < 2 {
+        return None;
+    }
+    let mut picked: Vec<&Commit> = commits
+        .iter()
+        .filter(|commit| selected.contains(&commit.id))
+        .collect();
+    picked.dedup_by(|a, b| a.id == b.id);
+    if picked.len() != selected.len() {
+        return None;
+    }
+    for pair in picked.windows(2) {
+        if pair[0].parent_ids.first() != Some(&pair[1].id) {
+            return None;
+        }
+    }
+    let newest = picked.first()?;
+    let base = picked.last()?.parent_ids.first()?;
+    Some((base.clone(), newest.id.clone()))
+}
+
+/// Like [`history_selection_commit_range`], but `None` when the run contains a
+/// merge: squashing recreates the run as one single-parent commit, which would
+/// drop the merged side branch.
+pub(in crate::view) fn history_selection_squash_range(
+    commits: &[Commit],
+    selected: &[CommitId],
+) -> Option<(CommitId, CommitId)> {
+    let range = history_selection_commit_range(commits, selected)?;
+    commits
+        .iter()
+        .filter(|commit| selected.contains(&commit.id))
+        .all(|commit| commit.parent_ids.len() <= 1)
+        .then_some(range)
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct PendingHistoryReveal {
     repo_id: RepoId,
@@ -852,6 +976,7 @@ pub(in super::super) struct HistoryView {
     history_selected_list_index_cache: Option<HistorySelectedListIndexCache>,
     selected_branch: Option<SelectedBranch>,
     pending_history_reveal: Option<PendingHistoryReveal>,
+    pub(in super::super) history_multi_selection: HashMap<RepoId, HistoryMultiSelection>,
     pub(in super::super) history_worktree_summary_cache: Option<HistoryWorktreeSummaryCache>,
     pub(in super::super) history_stash_ids_cache: Option<HistoryStashIdsCache>,
     pub(in super::super) history_scroll: UniformListScrollHandle,
@@ -915,6 +1040,7 @@ impl HistoryView {
 
             this.notify_fingerprint = next_fingerprint;
             this.state = next;
+            this.reconcile_history_multi_selection();
             cx.notify();
         });
 
@@ -961,6 +1087,7 @@ impl HistoryView {
             history_selected_list_index_cache: None,
             selected_branch: None,
             pending_history_reveal: None,
+            history_multi_selection: HashMap::default(),
             history_worktree_summary_cache: None,
             history_stash_ids_cache: None,
             history_scroll: UniformListScrollHandle::default(),
@@ -968,6 +1095,60 @@ impl HistoryView {
         }
     }
 
+    /// Drop selected commits that are no longer in the loaded history, e.g.
+    /// after a squash rewrote them.
+    fn reconcile_history_multi_selection(&mut self) {
+        let state = &self.state;
+        self.history_multi_selection.retain(|repo_id, selection| {
+            let Some(page) = state
+                .repos
+                .iter()
+                .find(|repo| repo.id == *repo_id)
+                .and_then(Self::display_log_page_for_repo)
+            else {
+                return false;
+            };
+            selection
+                .commits
+                .retain(|id| page.commits.iter().any(|commit| commit.id == *id));
+            !selection.commits.is_empty()
+        });
+    }
+
+    pub(in super::super) fn history_multi_selection_for_repo(
+        &self,
+        repo_id: RepoId,
+    ) -> Option<&HistoryMultiSelection> {
+        self.history_multi_selection
+            .get(&repo_id)
+            .filter(|selection| selection.is_batch())
+    }
+
+    /// Commit ids in the order the history list currently shows them.
+    pub(in super::super) fn history_visible_commit_ids(&self, repo: &RepoState) -> Vec<CommitId> {
+        let Some(page) = Self::display_log_page_for_repo(repo) else {
+            return Vec::new();
+        };
+        let Some(cache) = self
+            .history_cache
+            .as_ref()
+            .filter(|cache| cache.base.request.repo_id == repo.id)
+        else {
+            return page
+                .commits
+                .iter()
+                .map(|commit| commit.id.clone())
+                .collect();
+        };
+        cache
+            .base
+            .visible_indices
+            .iter()
+            .filter_map(|ix| page.commits.get(ix))
+            .map(|commit| commit.id.clone())
+            .collect()
+    }
+
     pub(in super::super) fn active_repo_id(&self) -> Option<RepoId> {
         self.state.active_repo
     }
@@ -1575,6 +1756,7 @@ impl HistoryView {
                         FileStatusKind::Deleted => deleted += 1,
                         FileStatusKind::Modified
                         | FileStatusKind::Renamed
+                        | FileStatusKind::Copied
                         | FileStatusKind::Conflicted => modified += 1,
                     }
                 }
@@ -1926,8 +2108,19 @@ fn build_history_base_cache(
         &page.commits,
     );
 
+    // Fixups are listed directly below their target; rows are built in log
+    // order and permuted into this order at the end.
+    let fixup_targets = history_fixup_targets(&page.commits);
+    let fixup_grouping = history_fixup_grouped_order(visible_indices.iter(), &fixup_targets);
+
     let branch_heads = graph_branch_heads(request.history_scope, branches, remote_branches);
-    let graph_rows: Arc<[history_graph::GraphRow]> = if stash_helper_ids.is_empty() {
+    let graph_rows: Arc<[history_graph::GraphRow]> = if let Some((order, grouped)) =
+        fixup_grouping.as_ref()
+    {
+        let graph_commits =
+            history_graph_commits_with_grouped_fixups(&page.commits, order, grouped);
+        history_graph::compute_graph(&graph_commits, theme, branch_heads, head_target).into()
+    } else if stash_helper_ids.is_empty() {
         history_graph::compute_graph(&page.commits, theme, branch_heads, head_target).into()
     } else {
         let visible_commit_refs = visible_indices
@@ -1943,6 +2136,11 @@ fn build_history_base_cache(
         .max()
         .unwrap_or(1);
 
+    let is_grouped_fixup = |ix: usize| {
+        fixup_grouping
+            .as_ref()
+            .is_some_and(|(_, grouped)| grouped.contains(&ix))
+    };
     let has_stash_tips = !stash_tips.is_empty();
     let mut author_cache: HashMap<&str, HistoryTextVm> =
         HashMap::with_capacity_and_hasher(64, Default::default());
@@ -1982,6 +2180,7 @@ fn build_history_base_cache(
                 short_sha: HistoryShortShaVm::new(commit.id.as_ref()),
                 is_head: head_target == Some(commit_id),
                 is_stash,
+                is_fixup: is_grouped_fixup(ix),
             });
         }
     } else {
@@ -2000,10 +2199,27 @@ fn build_history_base_cache(
                 short_sha: HistoryShortShaVm::new(commit.id.as_ref()),
                 is_head: head_target == Some(commit.id.as_ref()),
                 is_stash: false,
+                is_fixup: is_grouped_fixup(ix),
             });
         }
     }
 
+    let (visible_indices, row_vms) = match fixup_grouping {
+        Some((order, _)) => {
+            let position: HashMap<usize, usize> = visible_indices
+                .iter()
+                .enumerate()
+                .map(|(pos, ix)| (ix, pos))
+                .collect();
+            let row_vms = order
+                .iter()
+                .filter_map(|ix| row_vms.get(*position.get(ix)?).cloned())
+                .collect();
+            (HistoryVisibleIndices::Filtered(order.into()), row_vms)
+        }
+        None => (visible_indices, row_vms),
+    };
+
     HistoryBaseCache {
         request,
         visible_indices,
@@ -2187,6 +2403,120 @@ mod tests {
         }
     }
 
+    fn ids(ids: &[&str]) -> Vec<CommitId> {
+        ids.iter().map(|id| CommitId((*id).into())).collect()
+    }
+
+    #[test]
+    fn history_multi_selection_click_supports_shift_ranges_and_ctrl_toggles() {
+        let visible = || ids(&["d", "c", "b", "a"]);
+        let shift = gpui::Modifiers {
+            shift: true,
+            ..Default::default()
+        };
+        let ctrl = gpui::Modifiers {
+            control: true,
+            ..Default::default()
+        };
+        let mut selection = HistoryMultiSelection::default();
+
+        let selected = CommitId("c".into());
+        let clicked = CommitId("a".into());
+        apply_history_multi_selection_click(
+            &mut selection,
+            &clicked,
+            shift,
+            Some(&selected),
+            visible,
+        );
+        assert_eq!(selection.commits, ids(&["c", "b", "a"]));
+        assert!(selection.is_batch());
+
+        apply_history_multi_selection_click(
+            &mut selection,
+            &CommitId("b".into()),
+            ctrl,
+            None,
+            visible,
+        );
+        assert_eq!(selection.commits, ids(&["c", "a"]));
+
+        apply_history_multi_selection_click(
+            &mut selection,
+            &CommitId("d".into()),
+            gpui::Modifiers::default(),
+            None,
+            visible,
+        );
+        assert_eq!(selection.commits, ids(&["d"]));
+        assert!(!selection.is_batch());
+
+        let mut selection = HistoryMultiSelection::default();
+        apply_history_multi_selection_click(
+            &mut selection,
+            &CommitId("b".into()),
+            ctrl,
+            Some(&CommitId("d".into())),
+            visible,
+        );
+        assert_eq!(selection.commits, ids(&["d", "b"]));
+    }
+
+    #[test]
+    fn history_selection_commit_range_requires_first_parent_run() {
+        let commits = vec![
+            commit("d", &["c"], "d"),
+            commit("c", &["b", "x"], "c"),
+            commit("b", &["a"], "b"),
+            commit("a", &[], "a"),
+        ];
+        assert_eq!(
+            history_selection_commit_range(&commits, &ids(&["b", "c", "d"])),
+            Some((CommitId("a".into()), CommitId("d".into())))
+        );
+        assert_eq!(
+            history_selection_commit_range(&commits, &ids(&["d", "b"])),
+            None
+        );
+        assert_eq!(
+            history_selection_commit_range(&commits, &ids(&["b", "a"])),
+            None
+        );
+        assert_eq!(history_selection_commit_range(&commits, &ids(&["d"])), None);
+    }
+
+    #[test]
+    fn history_selection_squash_range_rejects_merges() {
+        let commits = vec![
+            commit("d", &["c"], "d"),
+            commit("c", &["b", "x"], "c"),
+            commit("b", &["a"], "b"),
+            commit("a", &[], "a"),
+        ];
+        assert_eq!(
+            history_selection_squash_range(&commits, &ids(&["b", "c", "d"])),
+            None
+        );
+        assert_eq!(
+            history_selection_squash_range(&commits, &ids(&["c", "d"])),
+            None
+        );
+        assert_eq!(
+            history_selection_squash_range(&commits, &ids(&["a", "b"])),
+            None
+        );
+
+        let linear = vec![
+            commit("c", &["b"], "c"),
+            commit("b", &["a"], "b"),
+            commit("a", &["root"], "a"),
+        ];
+        assert_eq!(
+            history_selection_squash_range(&linear, &ids(&["a", "b", "c"])),
+            Some((CommitId("root".into()), CommitId("c".into())))
+        );
+    }
+
     #[test]
     fn stash_tip_detection_requires_stash_like_message_and_multiple_parents() {
         assert!(is_probable_stash_tip(&commit(
@@ -11,6 +11,7 @@ mod diff_text;
 mod helpers;
 mod preview;
 
+pub(in crate::view) use diff_search::DiffSearchQuery;
 #[cfg(feature = "benchmarks")]
 #[allow(unused_imports)]
 pub(in crate::view) use diff_search::{
@@ -408,6 +408,25 @@ impl MainPaneView {
         }
     }
 
+    fn csv_preview_visible_len(&self) -> usize {
+        match &self.file_csv_preview {
+            Loadable::Ready(preview) => preview.rows.len(),
+            _ => 0,
+        }
+    }
+
+    fn csv_preview_change_visible_indices(&self) -> Vec<usize> {
+        let Loadable::Ready(preview) = &self.file_csv_preview else {
+            return Vec::new();
+        };
+        diff_navigation::change_block_entries(preview.rows.len(), |visible_ix| {
+            preview
+                .rows
+                .get(visible_ix)
+                .is_some_and(|row| row.kind != crate::view::csv_preview::CsvChangeKind::Unchanged)
+        })
+    }
+
     pub(in crate::view) fn patch_hunk_entries(&self) -> Vec<(usize, usize)> {
         if self.is_collapsed_diff_projection_active() {
             debug_assert_eq!(
@@ -467,6 +486,9 @@ impl MainPaneView {
     }
 
     pub(in crate::view) fn diff_nav_entries(&self) -> Vec<usize> {
+        if self.is_csv_preview_active() {
+            return self.csv_preview_change_visible_indices();
+        }
         if self.is_markdown_preview_active() && !self.is_file_preview_active() {
             return self.markdown_preview_change_visible_indices();
         }
@@ -923,6 +945,36 @@ impl MainPaneView {
         self.diff_selection_range = Some((target, target));
     }
 
+    /// Moves the selection from a moved line to the matching line at the
+    /// other end of the move, which may be in another file of the diff.
+    pub(in crate::view) fn diff_jump_to_moved_counterpart(&mut self) -> bool {
+        if self.is_file_diff_view_active() || self.diff_moved_lines.is_empty() {
+            return false;
+        }
+        let Some((current, _)) = self.diff_focus_visible_range() else {
+            return false;
+        };
+        let Some(target_src_ix) = self
+            .diff_src_ixs_for_visible_ix(current)
+            .into_iter()
+            .find_map(|src_ix| self.diff_moved_lines.counterpart(src_ix))
+        else {
+            return false;
+        };
+        let Some(target) = (0..self.diff_visible_len()).find(|&visible_ix| {
+            self.diff_src_ixs_for_visible_ix(visible_ix)
+                .contains(&target_src_ix)
+        }) else {
+            return false;
+        };
+
+        self.scroll_diff_to_item_strict(target, gpui::ScrollStrategy::Center);
+        self.clear_diff_navigation_selection();
+        self.diff_selection_anchor = Some(target);
+        self.diff_selection_range = Some((target, target));
+        true
+    }
+
     pub(in crate::view) fn maybe_autoscroll_diff_to_first_change(&mut self) {
         if !self.diff_autoscroll_pending {
             return;
@@ -931,7 +983,9 @@ impl MainPaneView {
             self.diff_autoscroll_pending = false;
             return;
         }
-        let visible_len = if self.is_markdown_preview_active() && !self.is_file_preview_active() {
+        let visible_len = if self.is_csv_preview_active() {
+            self.csv_preview_visible_len()
+        } else if self.is_markdown_preview_active() && !self.is_file_preview_active() {
             self.markdown_preview_visible_len()
         } else {
             self.diff_visible_len()
@@ -965,7 +1019,7 @@ impl MainPaneView {
             return;
         };
 
-        let Some(DiffTarget::WorkingTree { path, area }) = repo.diff_state.diff_target.as_ref()
+        let Some(DiffTarget::WorkingTree { path, area, .. }) = repo.diff_state.diff_target.as_ref()
         else {
             self.clear_conflict_resolver_state();
             return;
@@ -605,7 +605,7 @@ impl MainPaneView {
             && let Some(repo) = state.repos.iter().find(|r| r.id == repo_id)
         {
             match repo.diff_state.diff_target.as_ref() {
-                Some(DiffTarget::WorkingTree { path, area }) => {
+                Some(DiffTarget::WorkingTree { path, area, .. }) => {
                     0u8.hash(&mut hasher);
                     path.hash(&mut hasher);
                     match area {
@@ -613,7 +613,9 @@ impl MainPaneView {
                         DiffArea::Unstaged => 1u8.hash(&mut hasher),
                     }
                 }
-                Some(DiffTarget::Commit { commit_id, path }) => {
+                Some(DiffTarget::Commit {
+                    commit_id, path, ..
+                }) => {
                     1u8.hash(&mut hasher);
                     commit_id.hash(&mut hasher);
                     path.hash(&mut hasher);
@@ -669,7 +671,11 @@ impl MainPaneView {
                 self.store.dispatch(Msg::ClearDiffSelection { repo_id });
             }
             ClearDiffSelectionAction::ExitFocusedMergetool => {
-                self.set_focused_mergetool_exit_code(FOCUSED_MERGETOOL_EXIT_CANCELED);
+                self.set_focused_mergetool_exit_code(if self.conflict_resolver_compare_only() {
+                    FOCUSED_MERGETOOL_EXIT_SUCCESS
+                } else {
+                    FOCUSED_MERGETOOL_EXIT_CANCELED
+                });
                 cx.quit();
             }
         }
@@ -729,6 +735,25 @@ impl MainPaneView {
             })
     }
 
+    /// Whether the focused resolver is comparing files rather than resolving
+    /// a merge. Resolution actions and the output pane are hidden then.
+    pub(in crate::view) fn conflict_resolver_compare_only(&self) -> bool {
+        self.view_mode == GitCometViewMode::FocusedMergetool && self.focused_mergetool_compare_only
+    }
+
+    /// Input labels in base, local, remote order for a comparison, which
+    /// titles its columns after the compared files instead of index stages.
+    pub(in crate::view) fn conflict_resolver_compare_titles(&self) -> Option<[SharedString; 3]> {
+        self.conflict_resolver_compare_only().then(|| {
+            let labels = self.focused_mergetool_labels_or_default();
+            [
+                labels.base.into(),
+                labels.local.into(),
+                labels.remote.into(),
+            ]
+        })
+    }
+
     pub(in crate::view) fn focused_mergetool_save_and_exit(
         &mut self,
         repo_id: RepoId,
@@ -810,6 +835,7 @@ impl MainPaneView {
         history_auto_fetch_tags_on_repo_activation: bool,
         view_mode: GitCometViewMode,
         focused_mergetool_labels: Option<FocusedMergetoolLabels>,
+        focused_mergetool_compare_only: bool,
         focused_mergetool_exit_code: Option<Arc<AtomicI32>>,
         root_view: WeakEntity<GitCometView>,
         tooltip_host: WeakEntity<TooltipHost>,
@@ -948,6 +974,26 @@ impl MainPaneView {
                 cx.notify();
             }
         });
+        let diff_search_replace_input = cx.new(|cx| {
+            components::TextInput::new(
+                components::TextInputOptions {
+                    placeholder: "Replace".into(),
+                    multiline: false,
+                    read_only: false,
+                    chromeless: false,
+                    soft_wrap: false,
+                },
+                window,
+                cx,
+            )
+        });
+        let diff_search_replace_subscription =
+            cx.observe(&diff_search_replace_input, |this, input, cx| {
+                if input.update(cx, |input, _| input.take_enter_pressed()) {
+                    this.diff_search_replace_next(cx);
+                    cx.notify();
+                }
+            });
 
         let diff_panel_focus_handle = cx.focus_handle().tab_index(0).tab_stop(false);
 
@@ -980,6 +1026,7 @@ impl MainPaneView {
             state,
             view_mode,
             focused_mergetool_labels,
+            focused_mergetool_compare_only,
             focused_mergetool_exit_code,
             theme,
             date_time_format,
@@ -1000,6 +1047,7 @@ impl MainPaneView {
             diff_scroll_sync,
             diff_content_mode,
             diff_whitespace_mode,
+            diff_alignment_mode: DiffAlignmentMode::default(),
             diff_split_ratio: 0.5,
             diff_split_resize: None,
             diff_split_last_synced_x: [px(0.0); 2],
@@ -1044,6 +1092,9 @@ impl MainPaneView {
             diff_word_highlights: Vec::new(),
             diff_word_highlights_inflight: None,
             diff_file_stats: Vec::new(),
+            diff_moved_lines: Arc::default(),
+            diff_highlight_moved_lines: true,
+            diff_moved_lines_ignore_whitespace: false,
             diff_text_segments_cache: Vec::new(),
             diff_text_query_segments_cache: Vec::new(),
             diff_text_query_cache_query: SharedString::default(),
@@ -1062,15 +1113,21 @@ impl MainPaneView {
             diff_text_layout_cache: HashMap::default(),
             diff_search_active: false,
             diff_search_query: "".into(),
+            diff_search_options: TextSearchOptions::default(),
+            diff_search_invalid_pattern: false,
+            diff_search_regex: None,
             diff_search_matches: Vec::new(),
             diff_search_inline_patch_trigram_index: None,
             diff_search_match_ix: None,
             diff_search_input,
             _diff_search_subscription: diff_search_subscription,
+            diff_search_replace_input,
+            _diff_search_replace_subscription: diff_search_replace_subscription,
             file_diff_cache_repo_id: None,
             file_diff_cache_rev: 0,
             file_diff_cache_content_signature: None,
             file_diff_cache_whitespace_mode: diff_whitespace_mode,
+            file_diff_cache_alignment_mode: DiffAlignmentMode::default(),
             file_diff_cache_target: None,
             file_diff_cache_error: None,
             file_diff_cache_path: None,
@@ -1109,6 +1166,18 @@ impl MainPaneView {
             file_markdown_preview: Loadable::NotLoaded,
             file_markdown_preview_seq: 0,
             file_markdown_preview_inflight: None,
+            file_csv_preview_cache_repo_id: None,
+            file_csv_preview_cache_rev: 0,
+            file_csv_preview_cache_content_signature: None,
+            file_csv_preview_cache_target: None,
+            file_csv_preview: Loadable::NotLoaded,
+            file_csv_preview_seq: 0,
+            file_csv_preview_inflight: None,
+            csv_preview_alignment: Default::default(),
+            file_hex_preview_cache_content_signature: None,
+            file_hex_preview: Loadable::NotLoaded,
+            notebook_preview_options: Default::default(),
+            file_notebook_image_outputs: Arc::from([]),
             file_image_diff_cache_repo_id: None,
             file_image_diff_cache_rev: 0,
             file_image_diff_cache_content_signature: None,
@@ -1120,6 +1189,15 @@ impl MainPaneView {
             file_image_diff_cache_new: None,
             file_image_diff_cache_old_svg_path: None,
             file_image_diff_cache_new_svg_path: None,
+            file_image_diff_cache_old_metadata: None,
+            file_image_diff_cache_new_metadata: None,
+            file_image_diff_cache_difference: None,
+            image_diff_mode: ImageDiffMode::default(),
+            image_diff_swipe_ratio: 0.5,
+            image_diff_onion_opacity: 0.5,
+            image_diff_zoom: 1.0,
+            image_diff_pan: gpui::Point::default(),
+            image_diff_pan_drag_last: None,
             worktree_preview_path: None,
             worktree_preview_source_path: None,
             worktree_preview: Loadable::NotLoaded,
@@ -1240,6 +1318,8 @@ impl MainPaneView {
             .update(cx, |input, cx| input.set_theme(theme, cx));
         self.diff_search_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
+        self.diff_search_replace_input
+            .update(cx, |input, cx| input.set_theme(theme, cx));
         self.conflict_resolver_input
             .update(cx, |input, cx| input.set_theme(theme, cx));
         if self.conflict_resolved_output_is_streamed() {
@@ -2864,6 +2944,7 @@ impl MainPaneView {
             && self.file_diff_cache_rev == diff_file_rev
             && self.file_diff_cache_target == Some(diff_target)
             && self.file_diff_cache_whitespace_mode == self.diff_whitespace_mode
+            && self.file_diff_cache_alignment_mode == self.diff_alignment_mode
             && self.file_diff_cache_path.as_ref() == Some(&abs_path)
     }
 
@@ -2891,6 +2972,7 @@ impl MainPaneView {
             diff_target,
             file_path: abs_path,
             diff_whitespace_mode: self.diff_whitespace_mode,
+            diff_alignment_mode: self.diff_alignment_mode,
             patch_content_signature: self.diff_cache_content_signature,
             file_content_signature: self.file_diff_cache_content_signature,
         })
@@ -2949,6 +3031,64 @@ impl MainPaneView {
         });
     }
 
+    pub(in crate::view) fn set_diff_alignment_mode(
+        &mut self,
+        next: DiffAlignmentMode,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.diff_alignment_mode == next {
+            return;
+        }
+
+        self.diff_alignment_mode = next;
+        self.diff_selection_anchor = None;
+        self.diff_selection_range = None;
+        self.file_diff_inline_word_highlights =
+            rows::new_lru_cache(FILE_DIFF_WORD_HIGHLIGHT_CACHE_MAX_ENTRIES);
+        self.file_diff_split_word_highlights =
+            rows::new_lru_cache(FILE_DIFF_WORD_HIGHLIGHT_CACHE_MAX_ENTRIES);
+        self.clear_diff_text_style_caches();
+        self.clear_diff_text_query_overlay_cache();
+        self.reset_collapsed_diff_projection(false);
+        self.diff_visible_cache_len = 0;
+        self.diff_visible_cache_projection_rev = u64::MAX;
+        self.diff_scrollbar_markers_cache.clear();
+        if self.current_main_diff_supports_diff_content_toggle() {
+            self.reset_file_diff_cache_data();
+            self.ensure_file_diff_cache(cx);
+        }
+        if self.diff_search_active && !self.diff_search_query.as_ref().trim().is_empty() {
+            self.diff_search_recompute_matches_preserving_current();
+        }
+        cx.notify();
+    }
+
+    pub(in crate::view) fn set_diff_highlight_moved_lines(
+        &mut self,
+        enabled: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.diff_highlight_moved_lines == enabled {
+            return;
+        }
+        self.diff_highlight_moved_lines = enabled;
+        self.rebuild_patch_moved_lines_from_current_diff();
+        cx.notify();
+    }
+
+    pub(in crate::view) fn set_diff_moved_lines_ignore_whitespace(
+        &mut self,
+        enabled: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.diff_moved_lines_ignore_whitespace == enabled {
+            return;
+        }
+        self.diff_moved_lines_ignore_whitespace = enabled;
+        self.rebuild_patch_moved_lines_from_current_diff();
+        cx.notify();
+    }
+
     fn rendered_diff_target_for_state(state: &AppState) -> Option<DiffTarget> {
         let repo_id = state.active_repo?;
         let repo = state.repos.iter().find(|repo| repo.id == repo_id)?;
@@ -3046,6 +3186,9 @@ impl MainPaneView {
         window: &mut Window,
         cx: &mut gpui::Context<Self>,
     ) {
+        if self.conflict_resolver_compare_only() {
+            return;
+        }
         self.activate_context_menu_invoker(invoker, cx);
         self.open_popover_at(
             PopoverKind::ConflictResolverInputRowMenu {
@@ -3073,6 +3216,9 @@ impl MainPaneView {
         window: &mut Window,
         cx: &mut gpui::Context<Self>,
     ) {
+        if self.conflict_resolver_compare_only() {
+            return;
+        }
         self.activate_context_menu_invoker(invoker, cx);
         self.open_popover_at(
             PopoverKind::ConflictResolverChunkMenu {
@@ -3343,8 +3489,25 @@ impl MainPaneView {
         let _ = self.root_view.update(cx, |root, cx| {
             root.details_pane
                 .update(cx, |pane: &mut DetailsPaneView, cx| {
+                    let details = pane.active_repo().and_then(|repo| {
+                        match &repo.history_state.commit_details {
+                            Loadable::Ready(details) => Some(Arc::clone(details)),
+                            _ => None,
+                        }
+                    });
+                    let row_ix = details.and_then(|details| {
+                        // Reveal a generated file that keyboard navigation landed on.
+                        if details.files.get(ix).is_some_and(|file| file.generated) {
+                            pane.commit_files_generated_expanded = true;
+                        }
+                        crate::view::rows::CommitFileListRows::new(
+                            &details.files,
+                            pane.commit_files_generated_expanded,
+                        )
+                        .row_of_file(ix)
+                    });
                     pane.commit_files_scroll
-                        .scroll_to_item_strict(ix, gpui::ScrollStrategy::Center);
+                        .scroll_to_item_strict(row_ix.unwrap_or(ix), gpui::ScrollStrategy::Center);
                     cx.notify();
                 });
         });
@@ -3506,12 +3669,21 @@ impl MainPaneView {
             .expect("just set")
     }
 
-    /// Returns the current diff search query, or an empty `SharedString` if search is inactive.
-    pub(in crate::view) fn diff_search_query_or_empty(&self) -> SharedString {
-        if self.diff_search_active {
-            self.diff_search_query.clone()
-        } else {
-            SharedString::default()
+    /// Returns the current diff search query, or an empty query if search is
+    /// inactive or its pattern does not compile.
+    pub(in crate::view) fn diff_search_query_or_empty(&self) -> DiffSearchQuery {
+        if !self.diff_search_active || self.diff_search_invalid_pattern {
+            return DiffSearchQuery::default();
+        }
+        if self.diff_search_regex.is_none()
+            && self.diff_search_options != TextSearchOptions::default()
+        {
+            // Options changed and the query has not been recompiled yet.
+            return DiffSearchQuery::default();
+        }
+        DiffSearchQuery {
+            text: self.diff_search_query.clone(),
+            regex: self.diff_search_regex.clone(),
         }
     }
 
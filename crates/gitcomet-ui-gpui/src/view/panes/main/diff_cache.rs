@@ -1,6 +1,9 @@
 use super::*;
+use crate::view::csv_preview;
 use crate::view::diff_utils::compute_diff_yaml_block_scalar_for_src_ix;
+use crate::view::hex_preview::{HexDiffPreview, HexDiffSide};
 use crate::view::markdown_preview;
+use crate::view::notebook_preview;
 use crate::view::perf::{self, ViewPerfSpan};
 use crate::view::rows;
 use gitcomet_core::domain::DiffRowProvider;
@@ -17,6 +20,7 @@ pub(in crate::view) use self::file_diff::{
     PagedFileDiffInlineRows, PagedFileDiffRows, build_file_diff_cache_rebuild_with_patch,
 };
 use self::file_diff::{build_inline_text, file_diff_text_signature};
+pub(in crate::view) use self::image_cache::ImageDiffSideMetadata;
 #[cfg(feature = "benchmarks")]
 pub(in crate::view) use self::image_cache::render_svg_image_diff_preview;
 
@@ -37,6 +41,14 @@ const COLLAPSED_DIFF_REVEAL_STEP: usize = 20;
 // syntax and fall back to plain/heuristic rendering until it is ready.
 const FULL_DOCUMENT_SYNTAX_MODE: rows::DiffSyntaxMode = rows::DiffSyntaxMode::Auto;
 
+fn diff_ignore_options_signature(options: &gitcomet_core::services::DiffIgnoreOptions) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = rustc_hash::FxHasher::default();
+    options.hash(&mut hasher);
+    hasher.finish()
+}
+
 fn patch_diff_content_signature(diff: &gitcomet_core::domain::Diff) -> u64 {
     use std::hash::Hasher;
 
@@ -96,7 +108,7 @@ fn visual_line_kinds_for_patch_diff(
     use gitcomet_core::domain::DiffLineKind as DK;
 
     let mut visual = diff.lines.iter().map(|line| line.kind).collect::<Vec<_>>();
-    if mode == DiffWhitespaceMode::Show {
+    if mode != DiffWhitespaceMode::Ignore {
         return visual;
     }
 
@@ -459,6 +471,31 @@ impl MainPaneView {
         }
     }
 
+    fn rebuild_patch_moved_lines_from_ready_diff(&mut self, diff: &gitcomet_core::domain::Diff) {
+        self.diff_moved_lines = if self.diff_highlight_moved_lines {
+            Arc::new(detect_moved_lines(
+                diff.lines.as_slice(),
+                MovedLineOptions {
+                    ignore_whitespace: self.diff_moved_lines_ignore_whitespace,
+                },
+            ))
+        } else {
+            Arc::default()
+        };
+    }
+
+    pub(in crate::view) fn rebuild_patch_moved_lines_from_current_diff(&mut self) {
+        let ready_diff = match self.rendered_patch_diff_loadable() {
+            Some(Loadable::Ready(diff)) => Some(Arc::clone(diff)),
+            _ => None,
+        };
+        if let Some(diff) = ready_diff {
+            self.rebuild_patch_moved_lines_from_ready_diff(diff.as_ref());
+        } else {
+            self.diff_moved_lines = Arc::default();
+        }
+    }
+
     pub(in crate::view) fn patch_diff_rows_slice(
         &self,
         start: usize,
@@ -2169,17 +2206,25 @@ impl MainPaneView {
         };
 
         let diff_target_for_task = diff_target.clone();
+        let mut ignore_options = self
+            .active_repo()
+            .map(|repo| repo.diff_ignore_options.clone())
+            .unwrap_or_default();
+        ignore_options.whitespace = self.diff_whitespace_mode.ignore();
         let file_content_signature = file.as_ref().map(|file| {
             let mut signature = file_diff_text_signature(file.as_ref());
             if let Some(patch_diff) = patch_diff.as_ref() {
                 signature ^= patch_diff_content_signature(patch_diff.as_ref()).rotate_left(1);
             }
-            signature ^= (self.diff_whitespace_mode.key().len() as u64).rotate_left(7);
+            signature ^= (self.diff_whitespace_mode as u64).rotate_left(7);
+            signature ^= (self.diff_alignment_mode as u64).rotate_left(11);
+            signature ^= diff_ignore_options_signature(&ignore_options).rotate_left(13);
             signature
         });
         let same_repo_and_target = self.file_diff_cache_repo_id == Some(repo_id)
             && self.file_diff_cache_target == Some(diff_target.clone())
             && self.file_diff_cache_whitespace_mode == self.diff_whitespace_mode
+            && self.file_diff_cache_alignment_mode == self.diff_alignment_mode
             && self.file_diff_cache_path.as_ref() == Some(&expected_abs_path);
         let previous_split_left_reparse_seed = same_repo_and_target
             .then(|| self.file_diff_split_prepared_syntax_document(DiffTextRegion::SplitLeft))
@@ -2204,6 +2249,7 @@ impl MainPaneView {
                 self.file_diff_cache_repo_id = Some(repo_id);
                 self.file_diff_cache_rev = diff_file_rev;
                 self.file_diff_cache_whitespace_mode = self.diff_whitespace_mode;
+                self.file_diff_cache_alignment_mode = self.diff_alignment_mode;
                 self.file_diff_cache_target = Some(diff_target);
                 self.reset_file_diff_cache_data();
                 self.clear_diff_text_style_caches();
@@ -2251,6 +2297,7 @@ impl MainPaneView {
         self.file_diff_cache_repo_id = Some(repo_id);
         self.file_diff_cache_rev = diff_file_rev;
         self.file_diff_cache_whitespace_mode = self.diff_whitespace_mode;
+        self.file_diff_cache_alignment_mode = self.diff_alignment_mode;
         self.file_diff_cache_target = Some(diff_target);
         self.reset_file_diff_cache_data();
 
@@ -2268,6 +2315,8 @@ impl MainPaneView {
         self.file_diff_cache_inflight = Some(seq);
         self.file_diff_syntax_generation = seq;
         let whitespace_mode = self.diff_whitespace_mode;
+        let alignment_mode = self.diff_alignment_mode;
+        let ignore_matcher = DiffIgnoreMatcher::new(&ignore_options);
 
         cx.spawn(
             async move |view: WeakEntity<MainPaneView>, cx: &mut gpui::AsyncApp| {
@@ -2277,6 +2326,8 @@ impl MainPaneView {
                         &workdir,
                         patch_diff.as_deref(),
                         whitespace_mode,
+                        alignment_mode,
+                        &ignore_matcher,
                     )
                 };
                 let rebuild_result = if crate::ui_runtime::current().uses_background_compute() {
@@ -2292,6 +2343,7 @@ impl MainPaneView {
                     if this.file_diff_cache_repo_id != Some(repo_id)
                         || this.file_diff_cache_rev != diff_file_rev
                         || this.file_diff_cache_whitespace_mode != whitespace_mode
+                        || this.file_diff_cache_alignment_mode != alignment_mode
                         || this.file_diff_cache_target != Some(diff_target_for_task.clone())
                     {
                         return;
@@ -2305,6 +2357,7 @@ impl MainPaneView {
                             this.file_diff_cache_repo_id = Some(repo_id);
                             this.file_diff_cache_rev = diff_file_rev;
                             this.file_diff_cache_whitespace_mode = whitespace_mode;
+                            this.file_diff_cache_alignment_mode = alignment_mode;
                             this.file_diff_cache_target = Some(diff_target_for_task.clone());
                             this.file_diff_cache_path = Some(expected_abs_path.clone());
                             this.file_diff_cache_content_signature = Some(content_signature);
@@ -2373,6 +2426,7 @@ impl MainPaneView {
             this.file_markdown_preview_cache_content_signature = None;
             this.file_markdown_preview = Loadable::NotLoaded;
             this.file_markdown_preview_inflight = None;
+            this.file_notebook_image_outputs = Arc::from([]);
         };
 
         let Some((repo_id, diff_file_rev, diff_target, expected_abs_path, file)) = (|| {
@@ -2394,6 +2448,7 @@ impl MainPaneView {
         let file_content_signature = file
             .as_ref()
             .map(|file| file_diff_text_signature(file.as_ref()));
+        let is_notebook = notebook_preview::is_notebook_path(&expected_abs_path);
         let same_repo_and_target = self.file_markdown_preview_cache_repo_id == Some(repo_id)
             && self.file_markdown_preview_cache_target == Some(diff_target.clone())
             && self.file_diff_cache_path.as_ref() == Some(&expected_abs_path);
@@ -2418,6 +2473,7 @@ impl MainPaneView {
         self.file_markdown_preview_cache_target = Some(diff_target);
         self.file_markdown_preview = Loadable::NotLoaded;
         self.file_markdown_preview_inflight = None;
+        self.file_notebook_image_outputs = Arc::from([]);
 
         let Some(file) = file else {
             return;
@@ -2432,7 +2488,14 @@ impl MainPaneView {
         let combined_len =
             file_diff_markdown_source_len(old_source.as_ref(), old_legacy_text.as_ref())
                 + file_diff_markdown_source_len(new_source.as_ref(), new_legacy_text.as_ref());
-        if combined_len > markdown_preview::MAX_DIFF_PREVIEW_SOURCE_BYTES {
+        if is_notebook && combined_len > notebook_preview::MAX_DIFF_PREVIEW_SOURCE_BYTES {
+            self.file_markdown_preview = Loadable::Error(
+                notebook_preview::diff_preview_unavailable_reason(combined_len).to_string(),
+            );
+            self.file_markdown_preview_cache_content_signature = Some(content_signature);
+            return;
+        }
+        if !is_notebook && combined_len > markdown_preview::MAX_DIFF_PREVIEW_SOURCE_BYTES {
             self.file_markdown_preview = Loadable::Error(
                 markdown_preview::diff_preview_unavailable_reason(combined_len).to_string(),
             );
@@ -2440,6 +2503,7 @@ impl MainPaneView {
             return;
         }
 
+        let notebook_options = self.notebook_preview_options;
         self.file_markdown_preview = Loadable::Loading;
         self.file_markdown_preview_seq = self.file_markdown_preview_seq.wrapping_add(1);
         let seq = self.file_markdown_preview_seq;
@@ -2457,11 +2521,30 @@ impl MainPaneView {
                         new_source.as_ref(),
                         new_legacy_text.as_ref(),
                     )?;
+                    let mut image_outputs = Vec::new();
+                    let (old_source, new_source) = if is_notebook {
+                        let render = |source: &str| {
+                            notebook_preview::render_notebook_markdown(source, notebook_options)
+                                .ok_or_else(|| {
+                                    notebook_preview::diff_preview_unavailable_reason(0).to_string()
+                                })
+                        };
+                        let old = render(&old_source)?;
+                        let new = render(&new_source)?;
+                        image_outputs =
+                            notebook_preview::image_output_diffs(&old.cells, &new.cells)
+                                .into_iter()
+                                .map(NotebookImageOutputPreview::from_diff)
+                                .collect();
+                        (old.markdown, new.markdown)
+                    } else {
+                        (old_source, new_source)
+                    };
                     markdown_preview::build_markdown_diff_preview(
                         old_source.as_ref(),
                         new_source.as_ref(),
                     )
-                    .map(Arc::new)
+                    .map(|preview| (Arc::new(preview), Arc::from(image_outputs)))
                     .ok_or_else(|| {
                         markdown_preview::diff_preview_unavailable_reason(
                             old_source.len() + new_source.len(),
@@ -2490,7 +2573,10 @@ impl MainPaneView {
                     this.file_markdown_preview_inflight = None;
                     this.file_markdown_preview_cache_content_signature = Some(content_signature);
                     match result {
-                        Ok(preview) => this.file_markdown_preview = Loadable::Ready(preview),
+                        Ok((preview, image_outputs)) => {
+                            this.file_markdown_preview = Loadable::Ready(preview);
+                            this.file_notebook_image_outputs = image_outputs;
+                        }
                         Err(error) => this.file_markdown_preview = Loadable::Error(error),
                     }
                     cx.notify();
@@ -2500,6 +2586,214 @@ impl MainPaneView {
         .detach();
     }
 
+    /// Side summaries for the hex dump of a binary file. Only the file headers
+    /// are read here; rows stream from the blob cache while scrolling.
+    pub(in super::super::super) fn ensure_file_hex_preview_cache(&mut self) {
+        let file = match self.rendered_file_diff_loadable() {
+            Some(Loadable::Ready(Some(file))) if file.is_binary() => Arc::clone(file),
+            _ => {
+                self.file_hex_preview_cache_content_signature = None;
+                self.file_hex_preview = Loadable::NotLoaded;
+                return;
+            }
+        };
+        let signature = file_diff_text_signature(file.as_ref());
+        if self.file_hex_preview_cache_content_signature == Some(signature) {
+            return;
+        }
+
+        let side = |source: Option<&gitcomet_core::domain::FileDiffTextSource>| {
+            source.map(HexDiffSide::from_source).transpose()
+        };
+        self.file_hex_preview = match (
+            side(file.old_source.as_ref()),
+            side(file.new_source.as_ref()),
+        ) {
+            (Ok(old), Ok(new)) => Loadable::Ready(Arc::new(HexDiffPreview { old, new })),
+            (Err(err), _) | (_, Err(err)) => Loadable::Error(err.to_string()),
+        };
+        self.file_hex_preview_cache_content_signature = Some(signature);
+    }
+
+    pub(in super::super::super) fn ensure_file_csv_preview_cache(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let clear_cache = |this: &mut Self| {
+            this.file_csv_preview_cache_repo_id = None;
+            this.file_csv_preview_cache_target = None;
+            this.file_csv_preview_cache_rev = 0;
+            this.file_csv_preview_cache_content_signature = None;
+            this.file_csv_preview = Loadable::NotLoaded;
+            this.file_csv_preview_inflight = None;
+        };
+
+        let Some((repo_id, diff_file_rev, diff_target, expected_abs_path, file)) = (|| {
+            let (repo_id, diff_file_rev, diff_target, _workdir, expected_abs_path) =
+                self.rendered_file_diff_identity()?;
+            let file: Option<Arc<gitcomet_core::domain::FileDiffText>> =
+                match self.rendered_file_diff_loadable()? {
+                    Loadable::Ready(Some(file)) => Some(Arc::clone(file)),
+                    _ => None,
+                };
+
+            Some((repo_id, diff_file_rev, diff_target, expected_abs_path, file))
+        })() else {
+            clear_cache(self);
+            return;
+        };
+        let Some(delimiter) = csv_preview::delimiter_for_path(&expected_abs_path) else {
+            clear_cache(self);
+            return;
+        };
+
+        let diff_target_for_task = diff_target.clone();
+        let file_content_signature = file
+            .as_ref()
+            .map(|file| file_diff_text_signature(file.as_ref()));
+        let same_repo_and_target = self.file_csv_preview_cache_repo_id == Some(repo_id)
+            && self.file_csv_preview_cache_target == Some(diff_target.clone());
+
+        if same_repo_and_target && self.file_csv_preview_cache_rev == diff_file_rev {
+            return;
+        }
+
+        if same_repo_and_target
+            && let Some(signature) = file_content_signature
+            && self.file_csv_preview_cache_content_signature == Some(signature)
+        {
+            if self.file_csv_preview_inflight.is_none() {
+                self.file_csv_preview_cache_rev = diff_file_rev;
+            }
+            return;
+        }
+
+        self.file_csv_preview_cache_repo_id = Some(repo_id);
+        self.file_csv_preview_cache_rev = diff_file_rev;
+        self.file_csv_preview_cache_content_signature = None;
+        self.file_csv_preview_cache_target = Some(diff_target);
+        self.file_csv_preview = Loadable::NotLoaded;
+        self.file_csv_preview_inflight = None;
+
+        let Some(file) = file else {
+            return;
+        };
+        let Some(content_signature) = file_content_signature else {
+            return;
+        };
+        let old_source = file.old_source.clone();
+        let new_source = file.new_source.clone();
+        let old_legacy_text = file.old.clone();
+        let new_legacy_text = file.new.clone();
+
+        let combined_len =
+            file_diff_markdown_source_len(old_source.as_ref(), old_legacy_text.as_ref())
+                + file_diff_markdown_source_len(new_source.as_ref(), new_legacy_text.as_ref());
+        if combined_len > csv_preview::MAX_DIFF_PREVIEW_SOURCE_BYTES {
+            self.file_csv_preview = Loadable::Error(
+                csv_preview::diff_preview_unavailable_reason(combined_len).to_string(),
+            );
+            self.file_csv_preview_cache_content_signature = Some(content_signature);
+            return;
+        }
+
+        let alignment = self.csv_preview_alignment.clone();
+        self.file_csv_preview = Loadable::Loading;
+        self.file_csv_preview_seq = self.file_csv_preview_seq.wrapping_add(1);
+        let seq = self.file_csv_preview_seq;
+        self.file_csv_preview_inflight = Some(seq);
+
+        cx.spawn(
+            async move |view: WeakEntity<MainPaneView>, cx: &mut gpui::AsyncApp| {
+                let build_preview = move || {
+                    let old_source = read_file_diff_markdown_source(
+                        old_source.as_ref(),
+                        old_legacy_text.as_ref(),
+                    )?;
+                    let new_source = read_file_diff_markdown_source(
+                        new_source.as_ref(),
+                        new_legacy_text.as_ref(),
+                    )?;
+                    csv_preview::build_csv_diff_preview(
+                        old_source.as_ref(),
+                        new_source.as_ref(),
+                        delimiter,
+                        &alignment,
+                    )
+                    .map(Arc::new)
+                    .ok_or_else(|| {
+                        csv_preview::diff_preview_unavailable_reason(
+                            old_source.len() + new_source.len(),
+                        )
+                        .to_string()
+                    })
+                };
+                let result = if crate::ui_runtime::current().uses_background_compute() {
+                    smol::unblock(build_preview).await
+                } else {
+                    build_preview()
+                };
+
+                let _ = view.update(cx, |this, cx| {
+                    if this.file_csv_preview_inflight != Some(seq) {
+                        return;
+                    }
+                    if this.file_csv_preview_cache_repo_id != Some(repo_id)
+                        || this.file_csv_preview_cache_rev != diff_file_rev
+                        || this.file_csv_preview_cache_target != Some(diff_target_for_task.clone())
+                    {
+                        return;
+                    }
+
+                    this.file_csv_preview_inflight = None;
+                    this.file_csv_preview_cache_content_signature = Some(content_signature);
+                    match result {
+                        Ok(preview) => this.file_csv_preview = Loadable::Ready(preview),
+                        Err(error) => this.file_csv_preview = Loadable::Error(error),
+                    }
+                    cx.notify();
+                });
+            },
+        )
+        .detach();
+    }
+
+    /// Changes which notebook parts the notebook preview shows and rebuilds it.
+    pub(in crate::view) fn set_notebook_preview_options(
+        &mut self,
+        options: notebook_preview::NotebookPreviewOptions,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.notebook_preview_options == options {
+            return;
+        }
+        self.notebook_preview_options = options;
+        self.file_markdown_preview_cache_repo_id = None;
+        self.file_markdown_preview_cache_target = None;
+        self.file_markdown_preview_cache_content_signature = None;
+        self.file_markdown_preview_inflight = None;
+        self.ensure_file_markdown_preview_cache(cx);
+        cx.notify();
+    }
+
+    /// Switches how the table preview pairs records and rebuilds it.
+    pub(in crate::view) fn set_csv_preview_alignment(
+        &mut self,
+        alignment: csv_preview::CsvRowAlignment,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.csv_preview_alignment == alignment {
+            return;
+        }
+        self.csv_preview_alignment = alignment;
+        self.file_csv_preview_cache_repo_id = None;
+        self.file_csv_preview_cache_target = None;
+        self.file_csv_preview_cache_content_signature = None;
+        self.file_csv_preview_inflight = None;
+        self.ensure_file_csv_preview_cache(cx);
+        cx.notify();
+    }
+
     pub(in super::super::super) fn ensure_rendered_patch_diff_cache(
         &mut self,
         cx: &mut gpui::Context<Self>,
@@ -2601,6 +2895,7 @@ impl MainPaneView {
         self.diff_word_highlights.clear();
         self.diff_word_highlights_inflight = None;
         self.diff_file_stats.clear();
+        self.diff_moved_lines = Arc::default();
         self.clear_diff_text_style_caches();
         self.diff_selection_anchor = None;
         self.diff_selection_range = None;
@@ -2708,6 +3003,7 @@ impl MainPaneView {
             }
         }
         self.diff_file_stats = compute_diff_file_stats(diff.lines.as_slice());
+        self.rebuild_patch_moved_lines_from_ready_diff(diff.as_ref());
         self.diff_word_highlights = vec![None; self.patch_diff_row_len()];
         self.diff_word_highlights_inflight = None;
 
@@ -3047,6 +3343,7 @@ mod tests {
             target: DiffTarget::WorkingTree {
                 path: PathBuf::from("demo.txt"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             lines: lines
                 .into_iter()
@@ -3338,6 +3635,7 @@ mod tests {
         let target = DiffTarget::WorkingTree {
             path: PathBuf::from("docs/table.md"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
 
         let preview = crate::view::diff_preview::build_deleted_file_preview_from_diff(
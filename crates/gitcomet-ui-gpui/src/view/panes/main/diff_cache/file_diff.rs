@@ -546,6 +546,7 @@ fn visual_kinds_for_file_diff_plan(
     old_source: &IndexedFileDiffSource,
     new_source: &IndexedFileDiffSource,
     whitespace_mode: DiffWhitespaceMode,
+    changed_lines: Option<&gitcomet_core::file_diff::StructuralDiff>,
 ) -> (
     Box<[gitcomet_core::file_diff::FileDiffRowKind]>,
     Box<[gitcomet_core::domain::DiffLineKind]>,
@@ -603,6 +604,86 @@ fn visual_kinds_for_file_diff_plan(
         }
     }
 
+    if let Some(changed_lines) = changed_lines {
+        // Rows whose lines only moved between tokens (re-indent, re-wrap) or
+        // whose changes are ignored read as context; a modified row keeps only
+        // the side that really changed.
+        let old_changed = |line_ix: usize| {
+            changed_lines
+                .old_changed_lines
+                .get(line_ix)
+                .copied()
+                .unwrap_or(true)
+        };
+        let new_changed = |line_ix: usize| {
+            changed_lines
+                .new_changed_lines
+                .get(line_ix)
+                .copied()
+                .unwrap_or(true)
+        };
+        for (run_ix, run) in plan.runs.iter().enumerate() {
+            let split_start = starts.split.get(run_ix).copied().unwrap_or(0);
+            let inline_start = starts.inline.get(run_ix).copied().unwrap_or(0);
+            match *run {
+                FileDiffPlanRun::Context { .. } => {}
+                FileDiffPlanRun::Remove { old_start, len } => {
+                    for offset in 0..len {
+                        if old_changed(old_start.saturating_add(offset)) {
+                            continue;
+                        }
+                        if let Some(kind) = split.get_mut(split_start.saturating_add(offset)) {
+                            *kind = RK::Context;
+                        }
+                        if let Some(kind) = inline.get_mut(inline_start.saturating_add(offset)) {
+                            *kind = DK::Context;
+                        }
+                    }
+                }
+                FileDiffPlanRun::Add { new_start, len } => {
+                    for offset in 0..len {
+                        if new_changed(new_start.saturating_add(offset)) {
+                            continue;
+                        }
+                        if let Some(kind) = split.get_mut(split_start.saturating_add(offset)) {
+                            *kind = RK::Context;
+                        }
+                        if let Some(kind) = inline.get_mut(inline_start.saturating_add(offset)) {
+                            *kind = DK::Context;
+                        }
+                    }
+                }
+                FileDiffPlanRun::Modify {
+                    old_start,
+                    new_start,
+                    len,
+                } => {
+                    for offset in 0..len {
+                        let old_is_changed = old_changed(old_start.saturating_add(offset));
+                        let new_is_changed = new_changed(new_start.saturating_add(offset));
+                        if let Some(kind) = split.get_mut(split_start.saturating_add(offset)) {
+                            *kind = match (old_is_changed, new_is_changed) {
+                                (true, true) => RK::Modify,
+                                (true, false) => RK::Remove,
+                                (false, true) => RK::Add,
+                                (false, false) => RK::Context,
+                            };
+                        }
+                        let old_ix = inline_start.saturating_add(offset.saturating_mul(2));
+                        if !old_is_changed && let Some(kind) = inline.get_mut(old_ix) {
+                            *kind = DK::Context;
+                        }
+                        if !new_is_changed
+                            && let Some(kind) = inline.get_mut(old_ix.saturating_add(1))
+                        {
+                            *kind = DK::Context;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     if whitespace_mode == DiffWhitespaceMode::Ignore {
         let mut run_ix = 0usize;
         while run_ix < plan.runs.len() {
@@ -666,12 +747,14 @@ impl StreamedFileDiffSource {
         old_source: IndexedFileDiffSource,
         new_source: IndexedFileDiffSource,
         whitespace_mode: DiffWhitespaceMode,
+        changed_lines: Option<&gitcomet_core::file_diff::StructuralDiff>,
     ) -> Self {
         let (split_visual_kinds, inline_visual_kinds) = visual_kinds_for_file_diff_plan(
             plan.as_ref(),
             &old_source,
             &new_source,
             whitespace_mode,
+            changed_lines,
         );
         Self {
             plan,
@@ -1577,6 +1660,33 @@ fn build_file_diff_plan_from_indexed_sources(
     ))
 }
 
+/// Structural alignment needs both full documents; sources that fail to load
+/// simply keep the line-based plan.
+fn build_structural_file_diff(
+    language: rows::DiffSyntaxLanguage,
+    old_source: &IndexedFileDiffSource,
+    new_source: &IndexedFileDiffSource,
+) -> Option<gitcomet_core::file_diff::StructuralDiff> {
+    let old_text = file_diff_source_plan_text(old_source).ok()?;
+    let new_text = file_diff_source_plan_text(new_source).ok()?;
+    rows::structural_file_diff(language, old_text.as_ref(), new_text.as_ref())
+}
+
+/// Diffs without a git patch apply the repository's ignore options in process.
+fn build_ignore_file_diff(
+    old_source: &IndexedFileDiffSource,
+    new_source: &IndexedFileDiffSource,
+    ignore_matcher: &DiffIgnoreMatcher,
+) -> Option<gitcomet_core::file_diff::StructuralDiff> {
+    let old_text = file_diff_source_plan_text(old_source).ok()?;
+    let new_text = file_diff_source_plan_text(new_source).ok()?;
+    Some(gitcomet_core::file_diff::side_by_side_plan_with_ignore(
+        old_text.as_ref(),
+        new_text.as_ref(),
+        ignore_matcher,
+    ))
+}
+
 fn index_file_diff_side(
     source: Option<&gitcomet_core::domain::FileDiffTextSource>,
     legacy_text: Option<&Arc<str>>,
@@ -1854,7 +1964,14 @@ pub(in crate::view) fn build_file_diff_cache_rebuild(
     file: &gitcomet_core::domain::FileDiffText,
     workdir: &std::path::Path,
 ) -> Result<FileDiffCacheRebuild, String> {
-    build_file_diff_cache_rebuild_with_patch(file, workdir, None, DiffWhitespaceMode::Show)
+    build_file_diff_cache_rebuild_with_patch(
+        file,
+        workdir,
+        None,
+        DiffWhitespaceMode::Show,
+        DiffAlignmentMode::Lines,
+        &DiffIgnoreMatcher::default(),
+    )
 }
 
 pub(in crate::view) fn build_file_diff_cache_rebuild_with_patch(
@@ -1862,6 +1979,8 @@ pub(in crate::view) fn build_file_diff_cache_rebuild_with_patch(
     workdir: &std::path::Path,
     patch_diff: Option<&gitcomet_core::domain::Diff>,
     whitespace_mode: DiffWhitespaceMode,
+    alignment_mode: DiffAlignmentMode,
+    ignore_matcher: &DiffIgnoreMatcher,
 ) -> Result<FileDiffCacheRebuild, String> {
     let old_source = index_file_diff_side(file.old_source.as_ref(), file.old.as_ref())?;
     let new_source = index_file_diff_side(file.new_source.as_ref(), file.new.as_ref())?;
@@ -1871,7 +1990,32 @@ pub(in crate::view) fn build_file_diff_cache_rebuild_with_patch(
     let new_line_starts = Arc::clone(&new_source.line_starts);
     let old_line_count = old_source.line_count();
     let new_line_count = new_source.line_count();
-    let plan = Arc::new(if let Some(patch_diff) = patch_diff {
+
+    let file_path = Some(if file.path.is_absolute() {
+        file.path.to_path_buf()
+    } else {
+        workdir.join(&file.path)
+    });
+    let language = file_path
+        .as_ref()
+        .and_then(rows::diff_syntax_language_for_path);
+
+    let structural = match (alignment_mode, language) {
+        (DiffAlignmentMode::Structural, Some(language)) => {
+            build_structural_file_diff(language, &old_source, &new_source)
+        }
+        _ => None,
+    };
+    // A git patch was already filtered by the ignore options.
+    let changed_lines = match structural {
+        None if patch_diff.is_none() && !ignore_matcher.is_empty() => {
+            build_ignore_file_diff(&old_source, &new_source, ignore_matcher)
+        }
+        structural => structural,
+    };
+    let plan = Arc::new(if let Some(changed_lines) = changed_lines.as_ref() {
+        changed_lines.plan.clone()
+    } else if let Some(patch_diff) = patch_diff {
         build_file_diff_plan_from_patch(patch_diff, old_line_count, new_line_count)
     } else {
         build_file_diff_plan_from_indexed_sources(&old_source, &new_source)?
@@ -1888,6 +2032,7 @@ pub(in crate::view) fn build_file_diff_cache_rebuild_with_patch(
         old_source,
         new_source,
         whitespace_mode,
+        changed_lines.as_ref(),
     ));
     let row_provider = Arc::new(PagedFileDiffRows::new(
         Arc::clone(&source),
@@ -1898,14 +2043,6 @@ pub(in crate::view) fn build_file_diff_cache_rebuild_with_patch(
         FILE_DIFF_PAGE_SIZE,
     ));
 
-    let file_path = Some(if file.path.is_absolute() {
-        file.path.to_path_buf()
-    } else {
-        workdir.join(&file.path)
-    });
-    let language = file_path
-        .as_ref()
-        .and_then(rows::diff_syntax_language_for_path);
     let inline_text = SharedString::default();
 
     #[cfg(test)]
@@ -1965,6 +2102,7 @@ mod tests {
             old_source,
             new_source,
             whitespace_mode,
+            None,
         ))
     }
 
@@ -12,6 +12,10 @@ const IMAGE_DIFF_CACHE_CLEANUP_WRITE_INTERVAL: usize = 16;
 const IMAGE_DIFF_RASTER_PREVIEW_MAX_EDGE_PX: u32 = 1920;
 const IMAGE_DIFF_SVG_PREVIEW_TARGET_WIDTH_PX: f32 = 640.0;
 const IMAGE_DIFF_SVG_PREVIEW_MAX_EDGE_PX: f32 = 1024.0;
+/// Per-channel delta below which two pixels still count as identical, so
+/// re-encoding noise does not light up the difference view.
+const IMAGE_DIFF_DIFFERENCE_TOLERANCE: u8 = 8;
+const IMAGE_DIFF_DIFFERENCE_HIGHLIGHT_BGRA: [u8; 4] = [255, 0, 255, 255];
 static IMAGE_DIFF_SVG_USVG_OPTIONS: std::sync::LazyLock<resvg::usvg::Options<'static>> =
     std::sync::LazyLock::new(resvg::usvg::Options::default);
 static IMAGE_DIFF_CACHE_STARTUP_CLEANUP: std::sync::Once = std::sync::Once::new();
@@ -321,12 +325,148 @@ fn cached_image_diff_path_pair(
     )
 }
 
+/// What the image diff header shows for one side of the comparison.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(in crate::view) struct ImageDiffSideMetadata {
+    pub(in crate::view) format: &'static str,
+    pub(in crate::view) byte_len: usize,
+    pub(in crate::view) dimensions: Option<(u32, u32)>,
+}
+
+impl ImageDiffSideMetadata {
+    pub(in crate::view) fn label(&self) -> String {
+        let size = match self.byte_len {
+            n if n < 1024 => format!("{n} B"),
+            n if n < 1024 * 1024 => format!("{:.1} KiB", n as f64 / 1024.0),
+            n => format!("{:.1} MiB", n as f64 / (1024.0 * 1024.0)),
+        };
+        match self.dimensions {
+            Some((width, height)) => format!("{width}×{height} · {size} · {}", self.format),
+            None => format!("{size} · {}", self.format),
+        }
+    }
+}
+
+fn image_diff_format_label(path: &std::path::Path) -> &'static str {
+    let Some(ext) = path.extension().and_then(|s| s.to_str()) else {
+        return "Image";
+    };
+    if ext.eq_ignore_ascii_case("ico") {
+        return "ICO";
+    }
+    match image_format_for_path(path) {
+        Some(gpui::ImageFormat::Png) => "PNG",
+        Some(gpui::ImageFormat::Jpeg) => "JPEG",
+        Some(gpui::ImageFormat::Gif) => "GIF",
+        Some(gpui::ImageFormat::Webp) => "WebP",
+        Some(gpui::ImageFormat::Bmp) => "BMP",
+        Some(gpui::ImageFormat::Tiff) => "TIFF",
+        Some(gpui::ImageFormat::Svg) => "SVG",
+        Some(gpui::ImageFormat::Ico) => "ICO",
+        None => "Image",
+    }
+}
+
+fn image_diff_side_metadata(path: &std::path::Path, bytes: &[u8]) -> ImageDiffSideMetadata {
+    let format = image_diff_format_label(path);
+    let dimensions = match format {
+        "SVG" => resvg::usvg::Tree::from_data(bytes, &IMAGE_DIFF_SVG_USVG_OPTIONS)
+            .ok()
+            .map(|tree| {
+                let size = tree.size();
+                (size.width().round() as u32, size.height().round() as u32)
+            }),
+        "ICO" => image_diff_raster_dimensions(image::ImageFormat::Ico, bytes),
+        _ => image_format_for_path(path)
+            .and_then(image_rs_format_for_diff_preview)
+            .and_then(|image_format| image_diff_raster_dimensions(image_format, bytes)),
+    };
+    ImageDiffSideMetadata {
+        format,
+        byte_len: bytes.len(),
+        dimensions,
+    }
+}
+
+fn image_diff_raster_dimensions(format: image::ImageFormat, bytes: &[u8]) -> Option<(u32, u32)> {
+    image::ImageReader::with_format(std::io::Cursor::new(bytes), format)
+        .into_dimensions()
+        .ok()
+}
+
+/// Builds the pixel-difference view: changed pixels in a solid highlight over
+/// a faded grayscale copy of the new image. Sides of different sizes are
+/// compared on the union of both canvases, so added or cropped areas count as
+/// changed. Buffers are BGRA, matching the decoded previews.
+fn image_difference_bgra(
+    old: (u32, u32, &[u8]),
+    new: (u32, u32, &[u8]),
+) -> (image::RgbaImage, u64) {
+    let (old_width, old_height, old_bytes) = old;
+    let (new_width, new_height, new_bytes) = new;
+    let width = old_width.max(new_width);
+    let height = old_height.max(new_height);
+    let pixel_at = |bytes: &[u8], side_width: u32, side_height: u32, x: u32, y: u32| {
+        if x >= side_width || y >= side_height {
+            return None;
+        }
+        let offset = (y as usize * side_width as usize + x as usize) * 4;
+        bytes.get(offset..offset + 4)
+    };
+
+    let mut changed = 0_u64;
+    let buffer = image::RgbaImage::from_fn(width, height, |x, y| {
+        let old_pixel = pixel_at(old_bytes, old_width, old_height, x, y);
+        let new_pixel = pixel_at(new_bytes, new_width, new_height, x, y);
+        let differs = match (old_pixel, new_pixel) {
+            (Some(a), Some(b)) => a
+                .iter()
+                .zip(b)
+                .any(|(a, b)| a.abs_diff(*b) > IMAGE_DIFF_DIFFERENCE_TOLERANCE),
+            _ => true,
+        };
+        if differs {
+            changed += 1;
+            return image::Rgba(IMAGE_DIFF_DIFFERENCE_HIGHLIGHT_BGRA);
+        }
+        let [b, g, r, _] = new_pixel.map_or([255; 4], |p| [p[0], p[1], p[2], p[3]]);
+        let luma = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+        let faded = (192 + luma / 4) as u8;
+        image::Rgba([faded, faded, faded, 255])
+    });
+    (buffer, changed)
+}
+
+fn render_image_difference(
+    old: &gpui::RenderImage,
+    new: &gpui::RenderImage,
+) -> Option<(Arc<gpui::RenderImage>, u64)> {
+    let old_size = old.size(0);
+    let new_size = new.size(0);
+    let (buffer, changed) = image_difference_bgra(
+        (
+            old_size.width.0.max(0) as u32,
+            old_size.height.0.max(0) as u32,
+            old.as_bytes(0)?,
+        ),
+        (
+            new_size.width.0.max(0) as u32,
+            new_size.height.0.max(0) as u32,
+            new.as_bytes(0)?,
+        ),
+    );
+    Some((render_image_from_bgra8(buffer), changed))
+}
+
 struct ImageDiffCacheRebuild {
     file_path: Option<std::path::PathBuf>,
     old: Option<Arc<gpui::RenderImage>>,
     new: Option<Arc<gpui::RenderImage>>,
     old_svg_path: Option<std::path::PathBuf>,
     new_svg_path: Option<std::path::PathBuf>,
+    old_metadata: Option<ImageDiffSideMetadata>,
+    new_metadata: Option<ImageDiffSideMetadata>,
+    difference: Option<(Arc<gpui::RenderImage>, u64)>,
 }
 
 fn decode_file_image_diff_preview_pair(
@@ -372,6 +512,14 @@ fn build_file_image_diff_cache_rebuild(
     } else {
         workdir.join(&file.path)
     });
+    let old_metadata = file
+        .old
+        .as_deref()
+        .map(|bytes| image_diff_side_metadata(&file.path, bytes));
+    let new_metadata = file
+        .new
+        .as_deref()
+        .map(|bytes| image_diff_side_metadata(&file.path, bytes));
 
     if is_ico {
         let (old_svg_path, new_svg_path) =
@@ -382,6 +530,9 @@ fn build_file_image_diff_cache_rebuild(
             new: None,
             old_svg_path,
             new_svg_path,
+            old_metadata,
+            new_metadata,
+            difference: None,
         };
     }
 
@@ -392,17 +543,27 @@ fn build_file_image_diff_cache_rebuild(
             new: None,
             old_svg_path: None,
             new_svg_path: None,
+            old_metadata,
+            new_metadata,
+            difference: None,
         };
     };
 
     let (old_preview, new_preview) =
         decode_file_image_diff_preview_pair(format, file.old.as_deref(), file.new.as_deref());
+    let difference = match (&old_preview.render, &new_preview.render) {
+        (Some(old), Some(new)) => render_image_difference(old, new),
+        _ => None,
+    };
     ImageDiffCacheRebuild {
         file_path,
         old: old_preview.render,
         new: new_preview.render,
         old_svg_path: old_preview.cached_path,
         new_svg_path: new_preview.cached_path,
+        old_metadata,
+        new_metadata,
+        difference,
     }
 }
 
@@ -415,6 +576,11 @@ impl MainPaneView {
         self.file_image_diff_cache_new = None;
         self.file_image_diff_cache_old_svg_path = None;
         self.file_image_diff_cache_new_svg_path = None;
+        self.file_image_diff_cache_old_metadata = None;
+        self.file_image_diff_cache_new_metadata = None;
+        self.file_image_diff_cache_difference = None;
+        self.image_diff_zoom = 1.0;
+        self.image_diff_pan = gpui::Point::default();
     }
 
     pub(in crate::view) fn ensure_file_image_diff_cache(&mut self, cx: &mut gpui::Context<Self>) {
@@ -496,6 +662,9 @@ impl MainPaneView {
                 self.file_image_diff_cache_new = rebuild.new;
                 self.file_image_diff_cache_old_svg_path = rebuild.old_svg_path;
                 self.file_image_diff_cache_new_svg_path = rebuild.new_svg_path;
+                self.file_image_diff_cache_old_metadata = rebuild.old_metadata;
+                self.file_image_diff_cache_new_metadata = rebuild.new_metadata;
+                self.file_image_diff_cache_difference = rebuild.difference;
                 cx.notify();
             }
             return;
@@ -526,6 +695,9 @@ impl MainPaneView {
                     this.file_image_diff_cache_new = rebuild.new;
                     this.file_image_diff_cache_old_svg_path = rebuild.old_svg_path;
                     this.file_image_diff_cache_new_svg_path = rebuild.new_svg_path;
+                    this.file_image_diff_cache_old_metadata = rebuild.old_metadata;
+                    this.file_image_diff_cache_new_metadata = rebuild.new_metadata;
+                    this.file_image_diff_cache_difference = rebuild.difference;
                     cx.notify();
                 });
             },
@@ -718,6 +890,74 @@ mod tests {
         assert!(Arc::ptr_eq(&old, &new));
     }
 
+    #[test]
+    fn build_file_image_diff_cache_rebuild_reports_metadata_and_pixel_difference() {
+        let encode = |image: image::RgbaImage| {
+            let mut encoded = std::io::Cursor::new(Vec::new());
+            image::DynamicImage::ImageRgba8(image)
+                .write_to(&mut encoded, image::ImageFormat::Png)
+                .expect("encode png");
+            encoded.into_inner()
+        };
+        let old = encode(image::ImageBuffer::from_pixel(
+            4,
+            2,
+            image::Rgba([10, 20, 30, 255]),
+        ));
+        let mut changed = image::ImageBuffer::from_pixel(4, 3, image::Rgba([10, 20, 30, 255]));
+        changed.put_pixel(1, 1, image::Rgba([250, 20, 30, 255]));
+        changed.put_pixel(2, 0, image::Rgba([12, 22, 32, 255]));
+        let new = encode(changed);
+
+        let file = gitcomet_core::domain::FileDiffImage {
+            path: Path::new("images/sample.png").to_path_buf(),
+            old: Some(old.clone()),
+            new: Some(new.clone()),
+        };
+        let rebuild = build_file_image_diff_cache_rebuild(&file, Path::new("/tmp"));
+
+        assert_eq!(
+            rebuild.old_metadata,
+            Some(ImageDiffSideMetadata {
+                format: "PNG",
+                byte_len: old.len(),
+                dimensions: Some((4, 2)),
+            })
+        );
+        assert_eq!(
+            rebuild.new_metadata.as_ref().and_then(|m| m.dimensions),
+            Some((4, 3))
+        );
+        let (difference, changed_pixels) = rebuild.difference.expect("difference render");
+        let size = difference.size(0);
+        assert_eq!((size.width.0, size.height.0), (4, 3));
+        // One recoloured pixel plus the four-pixel row only the new side has;
+        // the near-identical pixel stays within tolerance.
+        assert_eq!(changed_pixels, 5);
+        assert_eq!(
+            render_pixel_bgra(&difference, 1, 1),
+            IMAGE_DIFF_DIFFERENCE_HIGHLIGHT_BGRA
+        );
+        assert_ne!(
+            render_pixel_bgra(&difference, 2, 0),
+            IMAGE_DIFF_DIFFERENCE_HIGHLIGHT_BGRA
+        );
+    }
+
+    #[test]
+    fn image_diff_side_metadata_label_formats_size_and_dimensions() {
+        let metadata = ImageDiffSideMetadata {
+            format: "PNG",
+            byte_len: 2048,
+            dimensions: Some((640, 480)),
+        };
+        assert_eq!(metadata.label(), "640×480 · 2.0 KiB · PNG");
+        assert_eq!(
+            image_diff_side_metadata(Path::new("broken.svg"), b"not svg").label(),
+            "7 B · SVG"
+        );
+    }
+
     #[test]
     fn build_file_image_diff_cache_rebuild_reuses_identical_svg_render_preview() {
         let svg = solid_rect_svg(2048, 1024);
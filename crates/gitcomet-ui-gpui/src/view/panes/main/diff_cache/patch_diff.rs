@@ -910,6 +910,7 @@ mod tests {
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -942,6 +943,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -1006,6 +1008,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             text.as_str(),
         ));
@@ -1047,6 +1050,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             text.as_str(),
         ));
@@ -1076,6 +1080,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -1113,6 +1118,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -1146,6 +1152,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
@@ -1182,6 +1189,7 @@ index 1111111..2222222 100644\n\
             DiffTarget::WorkingTree {
                 path: PathBuf::from("src/lib.rs"),
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
             "\
 diff --git a/src/lib.rs b/src/lib.rs\n\
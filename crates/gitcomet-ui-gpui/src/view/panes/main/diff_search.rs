@@ -1,6 +1,7 @@
 use super::*;
 use gitcomet_core::domain::Diff;
 use memchr::memchr2_iter;
+use regex::{NoExpand, Regex, RegexBuilder};
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -68,6 +69,166 @@ impl<'a> AsciiCaseInsensitiveNeedle<'a> {
     }
 }
 
+/// Matches rows against the diff search query. The default search options use
+/// the ASCII case-insensitive needle; any other option compiles a regex.
+#[derive(Clone, Copy)]
+pub(in crate::view) enum DiffSearchMatcher<'a> {
+    Needle(AsciiCaseInsensitiveNeedle<'a>),
+    Regex(&'a Regex),
+}
+
+impl<'a> DiffSearchMatcher<'a> {
+    pub(in crate::view) fn new(query: &'a str, regex: Option<&'a Regex>) -> Option<Self> {
+        if query.is_empty() {
+            return None;
+        }
+        match regex {
+            Some(regex) => Some(Self::Regex(regex)),
+            None => AsciiCaseInsensitiveNeedle::new(query).map(Self::Needle),
+        }
+    }
+
+    #[inline]
+    pub(in crate::view) fn is_match(self, haystack: &str) -> bool {
+        match self {
+            Self::Needle(needle) => needle.is_match(haystack),
+            Self::Regex(regex) => regex.is_match(haystack),
+        }
+    }
+
+    /// Bytes every match contains, for the trigram prefilter. Regex queries
+    /// return nothing so every row is a candidate.
+    #[inline]
+    fn prefilter_bytes(self) -> &'a [u8] {
+        match self {
+            Self::Needle(needle) => needle.as_bytes(),
+            Self::Regex(_) => &[],
+        }
+    }
+
+    /// Length of the longest possible match, when it is bounded.
+    fn max_match_len(self) -> Option<usize> {
+        match self {
+            Self::Needle(needle) => Some(needle.as_bytes().len()),
+            Self::Regex(_) => None,
+        }
+    }
+}
+
+/// The diff search query the rows highlight, with the regex compiled for
+/// non-default search options.
+#[derive(Clone, Default)]
+pub(in crate::view) struct DiffSearchQuery {
+    pub(in crate::view) text: SharedString,
+    pub(in crate::view) regex: Option<Regex>,
+}
+
+impl DiffSearchQuery {
+    #[inline]
+    pub(in crate::view) fn text(&self) -> &str {
+        self.text.as_ref().trim()
+    }
+
+    #[inline]
+    pub(in crate::view) fn is_empty(&self) -> bool {
+        self.text().is_empty()
+    }
+
+    /// Query text for builders that only highlight plain needles. Empty when
+    /// the regex drives the highlight instead.
+    #[inline]
+    pub(in crate::view) fn plain_text(&self) -> &str {
+        if self.regex.is_some() {
+            ""
+        } else {
+            self.text()
+        }
+    }
+}
+
+impl AsRef<str> for DiffSearchQuery {
+    fn as_ref(&self) -> &str {
+        self.text()
+    }
+}
+
+/// Compile `query` for non-default search options. `None` means the plain
+/// case-insensitive needle applies.
+pub(in crate::view) fn diff_search_regex(
+    query: &str,
+    options: TextSearchOptions,
+) -> Option<Result<Regex, regex::Error>> {
+    if query.is_empty() || options == TextSearchOptions::default() {
+        return None;
+    }
+    Some(text_search_regex(query, options))
+}
+
+fn text_search_regex(query: &str, options: TextSearchOptions) -> Result<Regex, regex::Error> {
+    let mut pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    if options.whole_word {
+        pattern = format!(r"\b(?:{pattern})\b");
+    }
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .build()
+}
+
+/// Replace every match of `query` in `text`, returning the new text and the
+/// number of replacements. Regex queries expand `$1`-style references.
+pub(in crate::view) fn replace_all_search_matches(
+    text: &str,
+    query: &str,
+    options: TextSearchOptions,
+    replacement: &str,
+) -> Result<(String, usize), regex::Error> {
+    if query.is_empty() {
+        return Ok((text.to_string(), 0));
+    }
+    let regex = text_search_regex(query, options)?;
+    let count = regex.find_iter(text).count();
+    let replaced = if options.regex {
+        regex.replace_all(text, replacement)
+    } else {
+        regex.replace_all(text, NoExpand(replacement))
+    };
+    Ok((replaced.into_owned(), count))
+}
+
+/// Find the first match of `query` at or after byte `from`, wrapping to the
+/// start of `text`. Returns the matched range and its expanded replacement.
+pub(in crate::view) fn next_search_replacement(
+    text: &str,
+    query: &str,
+    options: TextSearchOptions,
+    replacement: &str,
+    from: usize,
+) -> Result<Option<(Range<usize>, String)>, regex::Error> {
+    if query.is_empty() {
+        return Ok(None);
+    }
+    let regex = text_search_regex(query, options)?;
+    let from = if text.is_char_boundary(from) { from } else { 0 };
+    let Some(captures) = regex
+        .captures_at(text, from)
+        .or_else(|| regex.captures(text))
+    else {
+        return Ok(None);
+    };
+    let found = captures.get(0).expect("capture group 0 is the whole match");
+    let mut expanded = String::new();
+    if options.regex {
+        captures.expand(replacement, &mut expanded);
+    } else {
+        expanded.push_str(replacement);
+    }
+    Ok(Some((found.range(), expanded)))
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(in crate::view) enum DiffSearchQueryReuse {
     None,
@@ -157,7 +318,7 @@ impl DiffSearchVisibleTrigramIndex {
 
 #[inline]
 fn diff_search_displayed_text_matches_query(
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
     text: &str,
     expanded_tabs: &mut String,
 ) -> bool {
@@ -176,7 +337,7 @@ fn diff_search_displayed_text_matches_query(
 }
 
 pub(in crate::view) fn diff_search_split_row_texts_match_query(
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
     left: Option<&str>,
     right: Option<&str>,
     expanded_tabs: &mut String,
@@ -303,7 +464,7 @@ fn inline_patch_diff_visible_ix_matches_query(
     diff_header_display_cache: &HashMap<usize, SharedString>,
     diff_visible_inline_map: Option<&super::diff_cache::PatchInlineVisibleMap>,
     diff_visible_indices: &[usize],
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
     visible_ix: usize,
 ) -> bool {
     let Some(src_ix) = inline_patch_diff_src_ix_for_visible_ix(
@@ -319,15 +480,18 @@ fn inline_patch_diff_visible_ix_matches_query(
 
 fn resolved_output_line_ix_matches_query(
     raw_text: &gitcomet_core::file_diff::FileDiffLineText,
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
 ) -> bool {
     const FILE_PREVIEW_SEARCH_SCAN_CHUNK_BYTES: usize = 32 * 1024;
 
+    let Some(max_match_len) = query.max_match_len() else {
+        return query.is_match(raw_text.as_ref());
+    };
     if raw_text.len() <= FILE_PREVIEW_SEARCH_SCAN_CHUNK_BYTES {
         return query.is_match(raw_text.as_ref());
     }
 
-    let overlap = query.as_bytes().len().saturating_sub(1);
+    let overlap = max_match_len.saturating_sub(1);
     let mut chunk_start = 0usize;
     while chunk_start < raw_text.len() {
         let scan_start = chunk_start.saturating_sub(overlap);
@@ -407,7 +571,8 @@ impl MainPaneView {
             return None;
         }
         let repo = self.active_repo()?;
-        let DiffTarget::WorkingTree { path, area } = repo.diff_state.diff_target.as_ref()? else {
+        let DiffTarget::WorkingTree { path, area, .. } = repo.diff_state.diff_target.as_ref()?
+        else {
             return None;
         };
         if *area != DiffArea::Unstaged {
@@ -466,6 +631,19 @@ impl MainPaneView {
         self.diff_search_recompute_matches_for_current_view_preserving_current();
     }
 
+    /// Compile `query` for the active search options. An invalid pattern
+    /// clears the matches so the search bar can report it.
+    fn diff_search_compile_query(&mut self, query: &str) -> Result<Option<Regex>, regex::Error> {
+        let compiled = diff_search_regex(query, self.diff_search_options).transpose();
+        self.diff_search_invalid_pattern = compiled.is_err();
+        self.diff_search_regex = compiled.as_ref().ok().cloned().flatten();
+        if compiled.is_err() {
+            self.diff_search_matches.clear();
+            self.diff_search_match_ix = None;
+        }
+        compiled
+    }
+
     pub(super) fn diff_search_recompute_matches_for_query_change(&mut self, previous_query: &str) {
         if !self.diff_search_active {
             self.diff_search_matches.clear();
@@ -477,12 +655,20 @@ impl MainPaneView {
         let query_text = self.diff_search_query.clone();
         let query_text = query_text.as_ref().trim();
 
-        let Some(query) = AsciiCaseInsensitiveNeedle::new(query_text) else {
+        let Ok(regex) = self.diff_search_compile_query(query_text) else {
+            return;
+        };
+        let Some(query) = DiffSearchMatcher::new(query_text, regex.as_ref()) else {
             self.diff_search_matches.clear();
             return;
         };
+        let reuse = if regex.is_some() {
+            DiffSearchQueryReuse::None
+        } else {
+            diff_search_query_reuse(previous_query, query_text)
+        };
 
-        match diff_search_query_reuse(previous_query, query_text) {
+        match reuse {
             DiffSearchQueryReuse::SameSemantics => {}
             DiffSearchQueryReuse::Refinement if self.diff_search_can_refine_current_matches() => {
                 let mut previous_matches = std::mem::take(&mut self.diff_search_matches);
@@ -538,8 +724,12 @@ impl MainPaneView {
         finalize: DiffSearchFinalizeMode,
     ) {
         let query_text = self.diff_search_query.clone();
+        let query_text = query_text.as_ref().trim();
 
-        let Some(query) = AsciiCaseInsensitiveNeedle::new(query_text.as_ref().trim()) else {
+        let Ok(regex) = self.diff_search_compile_query(query_text) else {
+            return;
+        };
+        let Some(query) = DiffSearchMatcher::new(query_text, regex.as_ref()) else {
             self.diff_search_matches.clear();
             self.diff_search_match_ix = None;
             return;
@@ -549,7 +739,7 @@ impl MainPaneView {
         self.diff_search_finalize_matches(finalize);
     }
 
-    fn diff_search_scan_current_view_with_needle(&mut self, query: AsciiCaseInsensitiveNeedle<'_>) {
+    fn diff_search_scan_current_view_with_needle(&mut self, query: DiffSearchMatcher<'_>) {
         self.diff_search_matches.clear();
 
         if self.is_file_preview_active() {
@@ -557,7 +747,7 @@ impl MainPaneView {
                 return;
             };
             if let Some(index) = self.worktree_preview_search_trigram_index.as_ref() {
-                match index.candidates(query.as_bytes()) {
+                match index.candidates(query.prefilter_bytes()) {
                     DiffSearchVisibleCandidates::None => {}
                     DiffSearchVisibleCandidates::All => {
                         for ix in 0..line_count {
@@ -655,7 +845,7 @@ impl MainPaneView {
 
     fn diff_search_scan_inline_patch_diff_with_needle(
         &mut self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
     ) -> bool {
         let diff = match self.rendered_patch_diff_loadable() {
             Some(Loadable::Ready(diff)) => Arc::clone(diff),
@@ -701,7 +891,7 @@ impl MainPaneView {
         let diff_visible_indices = &self.diff_visible_indices;
         let matches = &mut self.diff_search_matches;
 
-        match index.candidates(query.bytes) {
+        match index.candidates(query.prefilter_bytes()) {
             DiffSearchVisibleCandidates::None => {}
             DiffSearchVisibleCandidates::All => {
                 let total = diff_visible_inline_map
@@ -744,7 +934,7 @@ impl MainPaneView {
 
     fn diff_search_file_diff_split_visible_row_matches_query(
         &self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
         visible_ix: usize,
         expanded_tabs: &mut String,
     ) -> bool {
@@ -770,7 +960,7 @@ impl MainPaneView {
 
     fn diff_search_file_diff_inline_visible_row_matches_query(
         &self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
         visible_ix: usize,
     ) -> bool {
         if !self.is_file_diff_view_active() || self.diff_view != DiffViewMode::Inline {
@@ -789,7 +979,7 @@ impl MainPaneView {
 
     fn diff_search_try_refine_inline_patch_matches(
         &self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
         previous_matches: &mut Vec<usize>,
     ) -> bool {
         if self.is_file_preview_active()
@@ -817,7 +1007,7 @@ impl MainPaneView {
         let diff_visible_indices = &self.diff_visible_indices;
         retain_refined_visible_matches(
             previous_matches,
-            index.candidates(query.as_bytes()),
+            index.candidates(query.prefilter_bytes()),
             |visible_ix| {
                 inline_patch_diff_visible_ix_matches_query(
                     diff.as_ref(),
@@ -835,7 +1025,7 @@ impl MainPaneView {
 
     fn diff_search_try_refine_worktree_preview_matches(
         &self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
         previous_matches: &mut Vec<usize>,
     ) -> bool {
         if !self.is_file_preview_active() {
@@ -847,7 +1037,7 @@ impl MainPaneView {
 
         retain_refined_visible_matches(
             previous_matches,
-            index.candidates(query.as_bytes()),
+            index.candidates(query.prefilter_bytes()),
             |line_ix| {
                 self.worktree_preview_line_raw_text(line_ix)
                     .is_some_and(|line| resolved_output_line_ix_matches_query(&line, query))
@@ -858,7 +1048,7 @@ impl MainPaneView {
 
     fn diff_search_visible_row_matches_query(
         &self,
-        query: AsciiCaseInsensitiveNeedle<'_>,
+        query: DiffSearchMatcher<'_>,
         visible_ix: usize,
     ) -> bool {
         if self.is_file_preview_active() {
@@ -985,6 +1175,84 @@ impl MainPaneView {
         self.diff_search_scroll_to_visible_ix(target);
     }
 
+    pub(in super::super::super) fn set_diff_search_options(&mut self, options: TextSearchOptions) {
+        if self.diff_search_options == options {
+            return;
+        }
+        self.diff_search_options = options;
+        self.diff_search_invalid_pattern = false;
+        self.diff_search_regex = None;
+        self.clear_diff_text_query_overlay_cache();
+        self.clear_worktree_preview_segments_cache();
+        self.clear_conflict_diff_query_overlay_caches();
+        self.diff_search_recompute_matches_and_scroll_to_first();
+    }
+
+    /// Whether the search bar can replace into the resolved conflict output.
+    pub(in super::super::super) fn diff_search_replace_available(&self) -> bool {
+        self.diff_search_active && self.active_conflict_target().is_some()
+    }
+
+    /// Replace the next match at or after the cursor in the resolved conflict
+    /// output, leaving the cursor after the inserted text.
+    pub(in super::super::super) fn diff_search_replace_next(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let query = self.diff_search_query.clone();
+        let query = query.as_ref().trim();
+        if query.is_empty() || !self.diff_search_replace_available() {
+            return;
+        }
+        self.ensure_conflict_resolved_output_materialized(cx);
+        let replacement = self.diff_search_replace_input.read(cx).text().to_string();
+        let options = self.diff_search_options;
+        let replaced = self.conflict_resolver_input.update(cx, |input, cx| {
+            let from = input.selected_range().start;
+            let next = next_search_replacement(input.text(), query, options, &replacement, from);
+            let Ok(Some((range, expanded))) = next else {
+                return false;
+            };
+            let inserted = input.replace_utf8_range(range, &expanded, cx);
+            input.set_cursor_offset(inserted.end, cx);
+            true
+        });
+        if replaced {
+            self.diff_search_recompute_matches_preserving_current();
+        }
+    }
+
+    /// Replace every match in the resolved conflict output as one edit.
+    pub(in super::super::super) fn diff_search_replace_all(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let query = self.diff_search_query.clone();
+        let query = query.as_ref().trim();
+        if query.is_empty() || !self.diff_search_replace_available() {
+            return;
+        }
+        self.ensure_conflict_resolved_output_materialized(cx);
+        let replacement = self.diff_search_replace_input.read(cx).text().to_string();
+        let options = self.diff_search_options;
+        let replaced = self.conflict_resolver_input.update(cx, |input, cx| {
+            let Ok((text, count)) =
+                replace_all_search_matches(input.text(), query, options, &replacement)
+            else {
+                return false;
+            };
+            if count == 0 {
+                return false;
+            }
+            let len = input.text().len();
+            input.replace_utf8_range(0..len, &text, cx);
+            true
+        });
+        if replaced {
+            self.diff_search_recompute_matches();
+        }
+    }
+
     fn diff_search_scroll_to_visible_ix(&mut self, visible_ix: usize) {
         self.clear_diff_text_selection();
         self.diff_selection_range = None;
@@ -1146,14 +1414,14 @@ fn conflict_resolver_visible_match_indices(
     query: &str,
     ctx: &ConflictResolverSearchContext<'_>,
 ) -> Vec<usize> {
-    let Some(query) = AsciiCaseInsensitiveNeedle::new(query) else {
+    let Some(query) = DiffSearchMatcher::new(query, None) else {
         return Vec::new();
     };
     conflict_resolver_visible_match_indices_with_needle(query, ctx)
 }
 
 fn conflict_resolver_visible_match_indices_with_needle(
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
     ctx: &ConflictResolverSearchContext<'_>,
 ) -> Vec<usize> {
     let mut out = Vec::new();
@@ -1167,8 +1435,15 @@ fn conflict_resolver_visible_match_indices_with_needle(
                 split_row_index,
                 two_way_split_projection,
             } = ctx.two_way_rows;
-            let matching_rows = split_row_index
-                .search_ascii_case_insensitive_matching_rows(ctx.marker_segments, query.bytes);
+            let matching_rows = match query {
+                DiffSearchMatcher::Needle(needle) => split_row_index
+                    .search_ascii_case_insensitive_matching_rows(
+                        ctx.marker_segments,
+                        needle.as_bytes(),
+                    ),
+                DiffSearchMatcher::Regex(_) => split_row_index
+                    .search_matching_rows(ctx.marker_segments, |text| query.is_match(text)),
+            };
             for source_row in matching_rows {
                 if let Some(vis) = two_way_split_projection.source_to_visible(source_row) {
                     out.push(vis);
@@ -1186,7 +1461,7 @@ fn conflict_resolver_visible_match_indices_with_needle(
 fn search_three_way_via_spans(
     projection: &conflict_resolver::ThreeWayVisibleProjection,
     ctx: &ConflictResolverSearchContext<'_>,
-    query: AsciiCaseInsensitiveNeedle<'_>,
+    query: DiffSearchMatcher<'_>,
     out: &mut Vec<usize>,
 ) {
     fn line_text<'a>(text: &'a str, line_starts: &[usize], line_ix: usize) -> &'a str {
@@ -1329,12 +1604,13 @@ fn three_way_visible_item_matches_query(
 #[cfg(test)]
 mod tests {
     use super::{
-        AsciiCaseInsensitiveNeedle, ConflictResolverSearchContext,
-        ConflictResolverSearchTwoWayRows, ConflictResolverSearchVisibleRows, DiffSearchQueryReuse,
-        conflict_resolver_visible_match_indices, contains_ascii_case_insensitive,
-        diff_search_query_reuse, diff_search_resume_match_ix,
-        diff_search_split_row_texts_match_query, empty_conflict_resolver_search_two_way_rows,
-        three_way_visible_item_matches_query,
+        ConflictResolverSearchContext, ConflictResolverSearchTwoWayRows,
+        ConflictResolverSearchVisibleRows, DiffSearchMatcher, DiffSearchQueryReuse,
+        TextSearchOptions, conflict_resolver_visible_match_indices,
+        contains_ascii_case_insensitive, diff_search_query_reuse, diff_search_regex,
+        diff_search_resume_match_ix, diff_search_split_row_texts_match_query,
+        empty_conflict_resolver_search_two_way_rows, next_search_replacement,
+        replace_all_search_matches, three_way_visible_item_matches_query,
     };
     use crate::view::conflict_resolver;
     use crate::view::conflict_resolver::{
@@ -1383,6 +1659,82 @@ mod tests {
         assert!(!contains_ascii_case_insensitive("Hello", "world"));
     }
 
+    #[test]
+    fn search_options_control_case_word_and_regex_matching() {
+        assert!(diff_search_regex("Foo", TextSearchOptions::default()).is_none());
+
+        let matches = |query: &str, options: TextSearchOptions, haystack: &str| {
+            let regex = diff_search_regex(query, options).map(|regex| regex.expect("valid"));
+            DiffSearchMatcher::new(query, regex.as_ref())
+                .expect("non-empty query")
+                .is_match(haystack)
+        };
+        let case_sensitive = TextSearchOptions {
+            case_sensitive: true,
+            ..TextSearchOptions::default()
+        };
+        assert!(matches("Foo", TextSearchOptions::default(), "let foo = 1;"));
+        assert!(!matches("Foo", case_sensitive, "let foo = 1;"));
+        assert!(matches("Foo", case_sensitive, "let Foo = 1;"));
+
+        let whole_word = TextSearchOptions {
+            whole_word: true,
+            ..TextSearchOptions::default()
+        };
+        assert!(!matches("foo", whole_word, "foobar"));
+        assert!(matches("FOO", whole_word, "call foo()"));
+        assert!(matches("a.b", whole_word, "x a.b y"));
+        assert!(!matches("a.b", whole_word, "x axb y"));
+
+        let regex = TextSearchOptions {
+            regex: true,
+            ..TextSearchOptions::default()
+        };
+        assert!(matches(r"fn\s+\w+_test", regex, "pub fn parse_test()"));
+        assert!(!matches(r"^fn", regex, "pub fn parse_test()"));
+        assert!(diff_search_regex("(", regex).is_some_and(|regex| regex.is_err()));
+    }
+
+    #[test]
+    fn replace_all_expands_captures_only_in_regex_mode() {
+        let literal = TextSearchOptions::default();
+        assert_eq!(
+            replace_all_search_matches("Foo foo $1", "foo", literal, "$1bar").unwrap(),
+            ("$1bar $1bar $1".to_string(), 2)
+        );
+
+        let regex = TextSearchOptions {
+            regex: true,
+            case_sensitive: true,
+            ..TextSearchOptions::default()
+        };
+        assert_eq!(
+            replace_all_search_matches("a1 b2 A3", "([a-z])(\\d)", regex, "$2$1").unwrap(),
+            ("1a 2b A3".to_string(), 2)
+        );
+    }
+
+    #[test]
+    fn next_replacement_starts_at_offset_and_wraps() {
+        let options = TextSearchOptions {
+            whole_word: true,
+            ..TextSearchOptions::default()
+        };
+        let text = "one two one";
+        assert_eq!(
+            next_search_replacement(text, "one", options, "1", 1).unwrap(),
+            Some((8..11, "1".to_string()))
+        );
+        assert_eq!(
+            next_search_replacement(text, "one", options, "1", 9).unwrap(),
+            Some((0..3, "1".to_string()))
+        );
+        assert_eq!(
+            next_search_replacement(text, "three", options, "3", 0).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn diff_search_query_reuse_detects_same_semantics_and_refinements() {
         assert_eq!(
@@ -1432,7 +1784,7 @@ mod tests {
 
     #[test]
     fn split_row_text_search_matches_rendered_tab_expansion() {
-        let query = AsciiCaseInsensitiveNeedle::new("a    b").expect("query");
+        let query = DiffSearchMatcher::new("a    b", None).expect("query");
         let mut expanded_tabs = String::new();
 
         assert!(diff_search_split_row_texts_match_query(
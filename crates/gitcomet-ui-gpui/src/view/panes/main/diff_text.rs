@@ -1402,6 +1402,34 @@ impl MainPaneView {
                 (0, None, 0, None, None)
             };
 
+        let line_range = path.as_ref().and(selection).and_then(|(sel_a, sel_b)| {
+            let diff_target = self
+                .active_repo()
+                .and_then(|repo| repo.diff_state.diff_target.as_ref());
+            if is_file_preview {
+                // File previews show one side of the file, numbered from 1.
+                let old_side = self.deleted_file_preview_abs_path().is_some();
+                let lines = (sel_a..=sel_b).filter_map(|ix| {
+                    let n = u32::try_from(ix).ok()?.checked_add(1)?;
+                    Some(if old_side {
+                        (Some(n), None)
+                    } else {
+                        (None, Some(n))
+                    })
+                });
+                return line_history_range_for_selection(diff_target, lines);
+            }
+            let mut lines = Vec::new();
+            for vix in sel_a..=sel_b {
+                for src_ix in src_ixs_for_visible_ix(vix) {
+                    if let Some(line) = self.patch_diff_row(src_ix) {
+                        lines.push((line.old_line, line.new_line));
+                    }
+                }
+            }
+            line_history_range_for_selection(diff_target, lines)
+        });
+
         self.activate_context_menu_invoker("diff_editor_menu".into(), cx);
         self.open_popover_at(
             PopoverKind::DiffEditorMenu {
@@ -1415,6 +1443,7 @@ impl MainPaneView {
                 lines_count,
                 copy_text,
                 copy_target,
+                line_range,
             },
             anchor,
             window,
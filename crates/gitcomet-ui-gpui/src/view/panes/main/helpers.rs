@@ -1108,6 +1108,39 @@ pub(super) fn conflict_resolver_output_context_line(
         .unwrap_or_else(|| line_index_for_offset(content, cursor_offset))
 }
 
+/// Line range and revision for `git log -L` from the `(old_line, new_line)`
+/// numbers of the selected diff rows. New-side numbers name lines as of the
+/// diff's own revision; old-side numbers are only used when the selection is
+/// entirely deleted lines, resolved against the commit's parent. Working tree
+/// diffs have no revision of their own and resolve against `HEAD` (`None`).
+pub(super) fn line_history_range_for_selection(
+    target: Option<&DiffTarget>,
+    lines: impl IntoIterator<Item = (Option<u32>, Option<u32>)>,
+) -> Option<(u32, u32, Option<String>)> {
+    let mut new_range: Option<(u32, u32)> = None;
+    let mut old_range: Option<(u32, u32)> = None;
+    let extend = |range: Option<(u32, u32)>, n: u32| {
+        Some(range.map_or((n, n), |(lo, hi)| (lo.min(n), hi.max(n))))
+    };
+    for (old_line, new_line) in lines {
+        if let Some(n) = new_line {
+            new_range = extend(new_range, n);
+        } else if let Some(n) = old_line {
+            old_range = extend(old_range, n);
+        }
+    }
+
+    let commit_id = match target {
+        Some(DiffTarget::Commit { commit_id, .. }) => Some(commit_id.as_ref()),
+        _ => None,
+    };
+    if let Some((start, end)) = new_range {
+        return Some((start, end, commit_id.map(ToOwned::to_owned)));
+    }
+    let (start, end) = old_range?;
+    Some((start, end, commit_id.map(|id| format!("{id}^"))))
+}
+
 pub(super) fn slice_text_by_line_range(text: &str, line_range: Range<usize>) -> String {
     if line_range.start >= line_range.end || text.is_empty() {
         return String::new();
@@ -2208,6 +2241,7 @@ pub(in crate::view) struct CollapsedDiffProjectionIdentity {
     pub(in crate::view) diff_target: DiffTarget,
     pub(in crate::view) file_path: std::path::PathBuf,
     pub(in crate::view) diff_whitespace_mode: DiffWhitespaceMode,
+    pub(in crate::view) diff_alignment_mode: DiffAlignmentMode,
     pub(in crate::view) patch_content_signature: Option<u64>,
     pub(in crate::view) file_content_signature: Option<u64>,
 }
@@ -2299,6 +2333,7 @@ pub(crate) struct MainPaneView {
     pub(super) state: Arc<AppState>,
     pub(in crate::view) view_mode: GitCometViewMode,
     pub(in crate::view) focused_mergetool_labels: Option<FocusedMergetoolLabels>,
+    pub(in crate::view) focused_mergetool_compare_only: bool,
     pub(in crate::view) focused_mergetool_exit_code: Option<Arc<AtomicI32>>,
     pub(in crate::view) theme: AppTheme,
     pub(in crate::view) date_time_format: DateTimeFormat,
@@ -2321,6 +2356,7 @@ pub(crate) struct MainPaneView {
     pub(in crate::view) diff_scroll_sync: DiffScrollSync,
     pub(in crate::view) diff_content_mode: DiffContentMode,
     pub(in crate::view) diff_whitespace_mode: DiffWhitespaceMode,
+    pub(in crate::view) diff_alignment_mode: DiffAlignmentMode,
     pub(in crate::view) diff_split_ratio: f32,
     pub(in crate::view) diff_split_resize: Option<DiffSplitResizeState>,
     pub(in crate::view) diff_split_last_synced_x: [Pixels; 2],
@@ -2366,6 +2402,9 @@ pub(crate) struct MainPaneView {
     pub(in crate::view) diff_word_highlights: Vec<Option<Vec<Range<usize>>>>,
     pub(in crate::view) diff_word_highlights_inflight: Option<u64>,
     pub(in crate::view) diff_file_stats: Vec<Option<(usize, usize)>>,
+    pub(in crate::view) diff_moved_lines: Arc<MovedLines>,
+    pub(in crate::view) diff_highlight_moved_lines: bool,
+    pub(in crate::view) diff_moved_lines_ignore_whitespace: bool,
     pub(in crate::view) diff_text_segments_cache: Vec<Option<VersionedCachedDiffStyledText>>,
     pub(in crate::view) diff_text_query_segments_cache: Vec<Option<VersionedCachedDiffStyledText>>,
     pub(in crate::view) diff_text_query_cache_query: SharedString,
@@ -2384,17 +2423,23 @@ pub(crate) struct MainPaneView {
     pub(in crate::view) diff_text_layout_cache: HashMap<u64, DiffTextLayoutCacheEntry>,
     pub(in crate::view) diff_search_active: bool,
     pub(in crate::view) diff_search_query: SharedString,
+    pub(in crate::view) diff_search_options: TextSearchOptions,
+    pub(in crate::view) diff_search_invalid_pattern: bool,
+    pub(in crate::view) diff_search_regex: Option<regex::Regex>,
     pub(in crate::view) diff_search_matches: Vec<usize>,
     pub(in crate::view) diff_search_inline_patch_trigram_index:
         Option<super::diff_search::DiffSearchVisibleTrigramIndex>,
     pub(in crate::view) diff_search_match_ix: Option<usize>,
     pub(in crate::view) diff_search_input: Entity<components::TextInput>,
     pub(super) _diff_search_subscription: gpui::Subscription,
+    pub(in crate::view) diff_search_replace_input: Entity<components::TextInput>,
+    pub(super) _diff_search_replace_subscription: gpui::Subscription,
 
     pub(in crate::view) file_diff_cache_repo_id: Option<RepoId>,
     pub(in crate::view) file_diff_cache_rev: u64,
     pub(in crate::view) file_diff_cache_content_signature: Option<u64>,
     pub(in crate::view) file_diff_cache_whitespace_mode: DiffWhitespaceMode,
+    pub(in crate::view) file_diff_cache_alignment_mode: DiffAlignmentMode,
     pub(in crate::view) file_diff_cache_target: Option<DiffTarget>,
     pub(in crate::view) file_diff_cache_error: Option<String>,
     pub(in crate::view) file_diff_cache_path: Option<std::path::PathBuf>,
@@ -2436,6 +2481,24 @@ pub(crate) struct MainPaneView {
     pub(in crate::view) file_markdown_preview_seq: u64,
     pub(in crate::view) file_markdown_preview_inflight: Option<u64>,
 
+    pub(in crate::view) file_csv_preview_cache_repo_id: Option<RepoId>,
+    pub(in crate::view) file_csv_preview_cache_rev: u64,
+    pub(in crate::view) file_csv_preview_cache_content_signature: Option<u64>,
+    pub(in crate::view) file_csv_preview_cache_target: Option<DiffTarget>,
+    pub(in crate::view) file_csv_preview: LoadableCsvDiff,
+    pub(in crate::view) file_csv_preview_seq: u64,
+    pub(in crate::view) file_csv_preview_inflight: Option<u64>,
+    pub(in crate::view) file_hex_preview_cache_content_signature: Option<u64>,
+    pub(in crate::view) file_hex_preview: LoadableHexDiff,
+
+    /// How the table preview pairs records; picked by clicking a column header.
+    pub(in crate::view) csv_preview_alignment: crate::view::csv_preview::CsvRowAlignment,
+
+    /// Notebook parts shown next to cell sources; outputs are collapsed by default.
+    pub(in crate::view) notebook_preview_options:
+        crate::view::notebook_preview::NotebookPreviewOptions,
+    pub(in crate::view) file_notebook_image_outputs: Arc<[NotebookImageOutputPreview]>,
+
     pub(in crate::view) file_image_diff_cache_repo_id: Option<RepoId>,
     pub(in crate::view) file_image_diff_cache_rev: u64,
     pub(in crate::view) file_image_diff_cache_content_signature: Option<u64>,
@@ -2447,6 +2510,22 @@ pub(crate) struct MainPaneView {
     pub(in crate::view) file_image_diff_cache_new: Option<Arc<gpui::RenderImage>>,
     pub(in crate::view) file_image_diff_cache_old_svg_path: Option<std::path::PathBuf>,
     pub(in crate::view) file_image_diff_cache_new_svg_path: Option<std::path::PathBuf>,
+    pub(in crate::view) file_image_diff_cache_old_metadata:
+        Option<super::diff_cache::ImageDiffSideMetadata>,
+    pub(in crate::view) file_image_diff_cache_new_metadata:
+        Option<super::diff_cache::ImageDiffSideMetadata>,
+    /// Highlighted pixel-difference render and its changed pixel count.
+    pub(in crate::view) file_image_diff_cache_difference: Option<(Arc<gpui::RenderImage>, u64)>,
+
+    pub(in crate::view) image_diff_mode: ImageDiffMode,
+    /// Swipe divider position, as a fraction of the viewport width.
+    pub(in crate::view) image_diff_swipe_ratio: f32,
+    /// Opacity of the new image layered over the old one in onion-skin mode.
+    pub(in crate::view) image_diff_onion_opacity: f32,
+    /// Zoom and pan shared by every image layer so both sides stay aligned.
+    pub(in crate::view) image_diff_zoom: f32,
+    pub(in crate::view) image_diff_pan: gpui::Point<Pixels>,
+    pub(in crate::view) image_diff_pan_drag_last: Option<gpui::Point<Pixels>>,
 
     pub(in crate::view) worktree_preview_path: Option<std::path::PathBuf>,
     pub(in crate::view) worktree_preview_source_path: Option<std::path::PathBuf>,
@@ -309,10 +309,26 @@ impl MainPaneView {
             is_file_preview,
             rendered_preview_kind,
         );
-        toggle_kind == Some(RenderedPreviewKind::Markdown)
-            && self
-                .rendered_preview_modes
-                .get(RenderedPreviewKind::Markdown)
+        toggle_kind.is_some_and(|kind| {
+            kind.uses_markdown_preview()
+                && self.rendered_preview_modes.get(kind) == RenderedPreviewMode::Rendered
+        })
+    }
+
+    /// Returns `true` when the two-sided CSV/TSV table preview is shown.
+    pub(in crate::view) fn is_csv_preview_active(&self) -> bool {
+        if self.is_file_preview_active() || self.diff_content_mode != DiffContentMode::Full {
+            return false;
+        }
+        let rendered_preview_kind =
+            crate::view::diff_target_rendered_preview_kind(self.rendered_diff_target());
+        let toggle_kind = crate::view::main_diff_rendered_preview_toggle_kind(
+            self.wants_file_diff_view(false),
+            false,
+            rendered_preview_kind,
+        );
+        toggle_kind == Some(RenderedPreviewKind::Csv)
+            && self.rendered_preview_modes.get(RenderedPreviewKind::Csv)
                 == RenderedPreviewMode::Rendered
     }
 
@@ -320,7 +336,8 @@ impl MainPaneView {
     /// there is an applicable conflict resolver strategy.
     pub(in crate::view) fn is_conflict_resolver_active(&self) -> bool {
         self.active_repo().is_some_and(|repo| {
-            let Some(DiffTarget::WorkingTree { path, area }) = repo.diff_state.diff_target.as_ref()
+            let Some(DiffTarget::WorkingTree { path, area, .. }) =
+                repo.diff_state.diff_target.as_ref()
             else {
                 return false;
             };
@@ -498,7 +515,8 @@ impl MainPaneView {
 
     pub(in crate::view) fn untracked_directory_notice(&self) -> Option<SharedString> {
         let repo = self.active_repo()?;
-        let DiffTarget::WorkingTree { path, area } = repo.diff_state.diff_target.as_ref()? else {
+        let DiffTarget::WorkingTree { path, area, .. } = repo.diff_state.diff_target.as_ref()?
+        else {
             return None;
         };
         let abs_path = if path.is_absolute() {
@@ -663,7 +681,8 @@ impl MainPaneView {
     ) -> Option<std::path::PathBuf> {
         let repo = self.active_repo()?;
         let workdir = repo.spec.workdir.clone();
-        let DiffTarget::WorkingTree { path, area } = repo.diff_state.diff_target.as_ref()? else {
+        let DiffTarget::WorkingTree { path, area, .. } = repo.diff_state.diff_target.as_ref()?
+        else {
             return None;
         };
         if *area != DiffArea::Unstaged {
@@ -689,7 +708,7 @@ impl MainPaneView {
         let target = repo.diff_state.diff_target.as_ref()?;
 
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree { path, area, .. } => {
                 if *area != DiffArea::Staged {
                     return None;
                 }
@@ -708,6 +727,7 @@ impl MainPaneView {
             DiffTarget::Commit {
                 commit_id,
                 path: Some(path),
+                ..
             } => {
                 let details = match &repo.history_state.commit_details {
                     Loadable::Ready(d) => d,
@@ -737,7 +757,7 @@ impl MainPaneView {
         let target = repo.diff_state.diff_target.as_ref()?;
 
         match target {
-            DiffTarget::WorkingTree { path, area } => {
+            DiffTarget::WorkingTree { path, area, .. } => {
                 let is_deleted = repo
                     .status_entry_for_path(*area, path.as_path())
                     .is_some_and(|entry| entry.kind == FileStatusKind::Deleted);
@@ -753,6 +773,7 @@ impl MainPaneView {
             DiffTarget::Commit {
                 commit_id,
                 path: Some(path),
+                ..
             } => {
                 let details = match &repo.history_state.commit_details {
                     Loadable::Ready(d) => d,
@@ -10,12 +10,13 @@ use super::{
     conflict_file_is_binary, conflict_marker_nav_entries_from_markers,
     conflict_resolver_output_context_line, dirty_byte_range_to_line_range,
     first_output_marker_line_for_conflict, focused_mergetool_save_exit_code,
-    output_line_range_for_conflict_block_in_text, pane_content_width_for_layout,
-    parse_conflict_canvas_rows_env, remap_line_keyed_cache_for_delta, renderable_conflict_file,
-    replace_output_lines_in_range, resolved_outline_delta_between_texts,
-    resolved_outline_delta_for_snapshot_transition, resolved_output_conflict_block_ranges_in_text,
-    resolved_output_marker_for_line, resolved_output_markers_for_text,
-    split_target_conflict_block_into_subchunks, versioned_cached_diff_styled_text_is_current,
+    line_history_range_for_selection, output_line_range_for_conflict_block_in_text,
+    pane_content_width_for_layout, parse_conflict_canvas_rows_env,
+    remap_line_keyed_cache_for_delta, renderable_conflict_file, replace_output_lines_in_range,
+    resolved_outline_delta_between_texts, resolved_outline_delta_for_snapshot_transition,
+    resolved_output_conflict_block_ranges_in_text, resolved_output_marker_for_line,
+    resolved_output_markers_for_text, split_target_conflict_block_into_subchunks,
+    versioned_cached_diff_styled_text_is_current,
     versioned_query_cached_diff_styled_text_is_current,
 };
 use crate::kit::text_model::TextModel;
@@ -26,12 +27,53 @@ use crate::view::conflict_resolver::{
 };
 use crate::view::rows;
 use crate::view::{ConflictResolverUiState, GitCometViewMode};
-use gitcomet_core::domain::RepoSpec;
+use gitcomet_core::domain::{CommitId, DiffArea, DiffTarget, RepoSpec};
 use gitcomet_state::model::{ConflictFile, Loadable, RepoId, RepoState};
 use rustc_hash::FxHashMap as HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+#[test]
+fn line_history_range_uses_new_side_lines_of_the_diffed_commit() {
+    let target = DiffTarget::Commit {
+        commit_id: CommitId("1111111111111111111111111111111111111111".into()),
+        path: Some(PathBuf::from("a.txt")),
+        old_path: None,
+    };
+    // Context (12 -> 14), removed (13 -> _) and added (_ -> 15) rows: the
+    // removed row's old number must not widen the new-side range.
+    let lines = [(Some(12), Some(14)), (Some(13), None), (None, Some(15))];
+    assert_eq!(
+        line_history_range_for_selection(Some(&target), lines),
+        Some((
+            14,
+            15,
+            Some("1111111111111111111111111111111111111111".to_string())
+        ))
+    );
+
+    // An all-deleted selection only exists on the parent side.
+    assert_eq!(
+        line_history_range_for_selection(Some(&target), [(Some(7), None), (Some(9), None)]),
+        Some((
+            7,
+            9,
+            Some("1111111111111111111111111111111111111111^".to_string())
+        ))
+    );
+
+    let worktree = DiffTarget::WorkingTree {
+        path: PathBuf::from("a.txt"),
+        area: DiffArea::Unstaged,
+        old_path: None,
+    };
+    assert_eq!(
+        line_history_range_for_selection(Some(&worktree), [(Some(3), Some(4))]),
+        Some((4, 4, None))
+    );
+    assert_eq!(line_history_range_for_selection(None, []), None);
+}
+
 #[test]
 fn clear_diff_selection_action_is_clear_for_normal_mode() {
     assert_eq!(
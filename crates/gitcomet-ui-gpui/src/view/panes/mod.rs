@@ -11,6 +11,10 @@ pub(in crate::view) use history::{
     history_column_resize_state, history_resize_state_visible_columns,
     history_visible_columns_for_layout, history_visible_columns_for_layout_with_resize_state,
 };
+pub(in crate::view) use history::{
+    apply_history_multi_selection_click, history_selection_commit_range,
+    history_selection_squash_range,
+};
 #[cfg(test)]
 #[allow(unused_imports)]
 pub(in crate::view) use history::{
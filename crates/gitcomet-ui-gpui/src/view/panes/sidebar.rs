@@ -447,6 +447,8 @@ mod tests {
             head: None,
             branch: Some("feature/old".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let mut worktree_repo = repo_state(RepoId(2), "/tmp/repo-feature");
@@ -473,6 +475,8 @@ mod tests {
             head: None,
             branch: Some("feature".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let mut worktree_repo = repo_state(RepoId(2), "/tmp/repo-feature");
@@ -498,6 +502,8 @@ mod tests {
             head: None,
             branch: Some("feature".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let mut worktree_repo = repo_state(RepoId(2), "/tmp/repo-feature");
@@ -613,6 +619,8 @@ mod tests {
             head: None,
             branch: Some("feature".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
         let related = repo_state(RepoId(2), "/tmp/active-feature");
         let unrelated = repo_state(RepoId(3), "/tmp/unrelated");
@@ -53,6 +53,7 @@ impl PatchDiffPagedRowsFixture {
         let target = DiffTarget::WorkingTree {
             path: std::path::PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let text = build_synthetic_unified_patch(lines);
         let diff = Arc::new(Diff::from_unified(target, text.as_str()));
@@ -2184,7 +2185,7 @@ impl PatchDiffSearchQueryUpdateFixture {
             {
                 let base = self.stable_cache.get(src_ix).and_then(Option::as_ref)?;
                 let overlay = super::diff_text::build_cached_diff_query_overlay_styled_text(
-                    self.theme, base, query,
+                    self.theme, base, query, None,
                 );
                 if let Some(slot) = self.query_cache.get_mut(src_ix) {
                     *slot = Some(PatchDiffSearchQueryCacheEntry {
@@ -174,6 +174,7 @@ impl GitOpsFixture {
                 target: DiffTarget::Commit {
                     commit_id,
                     path: None,
+                    old_path: None,
                 },
                 changed_files: renamed_files,
                 renamed_files,
@@ -199,6 +200,7 @@ impl GitOpsFixture {
                 target: DiffTarget::Commit {
                     commit_id,
                     path: None,
+                    old_path: None,
                 },
                 changed_files: binary_files,
                 renamed_files: 0,
@@ -224,6 +226,7 @@ impl GitOpsFixture {
                 target: DiffTarget::Commit {
                     commit_id,
                     path: None,
+                    old_path: None,
                 },
                 changed_files: 1,
                 renamed_files: 0,
@@ -558,6 +561,7 @@ fn file_status_kind_code(kind: FileStatusKind) -> u8 {
         FileStatusKind::Deleted => 3,
         FileStatusKind::Renamed => 4,
         FileStatusKind::Conflicted => 5,
+        FileStatusKind::Copied => 6,
     }
 }
 
@@ -207,6 +207,7 @@ impl RealRepoFixture {
             Some(DiffTarget::Commit {
                 commit_id: CommitId(resolve_commitish(&worktree, &commitish).into()),
                 path: Some(diff_path),
+                old_path: None,
             })
         } else {
             None
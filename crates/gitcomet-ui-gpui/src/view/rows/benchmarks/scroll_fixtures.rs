@@ -857,6 +857,7 @@ impl KeyboardStageUnstageToggleFixture {
         repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
             path: paths[0].clone(),
             area: DiffArea::Unstaged,
+            old_path: None,
         });
         repo.diff_state.diff_state_rev = 1;
 
@@ -1017,6 +1018,7 @@ impl KeyboardStageUnstageToggleFixture {
             DiffTarget::WorkingTree {
                 path: path.to_path_buf(),
                 area: next_area,
+                old_path: None,
             },
             |_state, effects| {
                 record_keyboard_stage_unstage_select_effects(
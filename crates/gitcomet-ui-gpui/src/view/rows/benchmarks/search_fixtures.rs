@@ -409,6 +409,7 @@ impl InDiffTextSearchFixture {
         let target = DiffTarget::WorkingTree {
             path: std::path::PathBuf::from("src/lib.rs"),
             area: DiffArea::Unstaged,
+            old_path: None,
         };
         let text = build_synthetic_diff_search_unified_patch(total_lines);
         let diff = Arc::new(Diff::from_unified(target, text.as_str()));
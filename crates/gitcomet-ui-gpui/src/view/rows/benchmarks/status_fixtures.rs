@@ -290,11 +290,13 @@ impl StatusSelectDiffOpenMetrics {
 
 fn hash_status_select_diff_target(target: &DiffTarget, hasher: &mut FxHasher) {
     match target {
-        DiffTarget::WorkingTree { path, area } => {
+        DiffTarget::WorkingTree { path, area, .. } => {
             path.hash(hasher);
             (*area as u8).hash(hasher);
         }
-        DiffTarget::Commit { commit_id, path } => {
+        DiffTarget::Commit {
+            commit_id, path, ..
+        } => {
             commit_id.hash(hasher);
             path.hash(hasher);
         }
@@ -330,6 +332,7 @@ impl StatusSelectDiffOpenFixture {
             diff_target: DiffTarget::WorkingTree {
                 path: target_path,
                 area: DiffArea::Unstaged,
+                old_path: None,
             },
         }
     }
@@ -348,6 +351,7 @@ impl StatusSelectDiffOpenFixture {
             diff_target: DiffTarget::WorkingTree {
                 path: target_path,
                 area: DiffArea::Staged,
+                old_path: None,
             },
         }
     }
@@ -864,6 +868,8 @@ fn build_long_status_truncation_entries(entries: usize, section: StatusSection)
             path,
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         });
     }
     items
@@ -914,6 +920,7 @@ fn status_row_kind_key(kind: FileStatusKind) -> u8 {
         FileStatusKind::Deleted => 3,
         FileStatusKind::Renamed => 4,
         FileStatusKind::Conflicted => 5,
+        FileStatusKind::Copied => 6,
     }
 }
 
@@ -162,6 +162,8 @@ pub(crate) fn build_synthetic_repo_state(
             head: Some(target.clone()),
             branch: Some(format!("feature/worktree/{ix}")),
             detached: ix % 7 == 0,
+            locked: None,
+            prunable: None,
         });
     }
     repo.worktrees = Loadable::Ready(Arc::new(worktrees_vec));
@@ -325,6 +327,9 @@ pub(crate) fn build_repo_switch_repo_state(
                         FileStatusKind::Modified
                     },
                     is_submodule: false,
+                    old_path: None,
+                    similarity: None,
+                    generated: false,
                 })
                 .collect(),
         }));
@@ -335,6 +340,7 @@ pub(crate) fn build_repo_switch_repo_state(
         repo.diff_state.diff_target = Some(DiffTarget::WorkingTree {
             path: std::path::PathBuf::from(path),
             area: DiffArea::Unstaged,
+            old_path: None,
         });
         repo.diff_state.diff_state_rev = 1;
         repo.diff_state.diff_rev = 1;
@@ -349,6 +355,7 @@ pub(crate) fn populate_loaded_diff_state(repo: &mut RepoState, path: &str, diff_
     let target = DiffTarget::WorkingTree {
         path: std::path::PathBuf::from(path),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     repo.diff_state.diff = Loadable::Ready(Arc::new(Diff {
         target: target.clone(),
@@ -486,6 +493,8 @@ pub(crate) fn build_synthetic_status_entries(entries: usize, area: DiffArea) ->
             path,
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         });
     }
     items
@@ -501,6 +510,8 @@ pub(crate) fn build_synthetic_partially_staged_entries(entries: usize) -> Vec<Fi
             )),
             kind: FileStatusKind::Modified,
             conflict: None,
+            old_path: None,
+            similarity: None,
         });
     }
     items
@@ -543,6 +554,8 @@ pub(crate) fn build_synthetic_status_entries_mixed_depth(entries: usize) -> Vec<
             path,
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         });
     }
     items
@@ -783,6 +796,9 @@ pub(crate) fn build_synthetic_commit_details_with_message(
             path,
             kind,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         });
     }
 
@@ -837,6 +853,9 @@ pub(crate) fn build_synthetic_commit_details_unique_paths(
             path,
             kind,
             is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated: false,
         });
     }
     CommitDetails {
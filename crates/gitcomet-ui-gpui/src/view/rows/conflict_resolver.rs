@@ -3,6 +3,7 @@ use super::super::perf::{self, ViewPerfRenderLane, ViewPerfSpan};
 use super::conflict_canvas::{self, ConflictChunkContext};
 use super::diff_text::*;
 use super::*;
+use crate::view::panes::main::DiffSearchQuery;
 
 const CONFLICT_ROW_FONT_SCALE: f32 = 0.80;
 const CONFLICT_ROW_TEXT_TRAILING_PADDING_PX: f32 = 16.0;
@@ -718,15 +719,12 @@ impl MainPaneView {
     ) -> Vec<AnyElement> {
         let _perf_scope = perf::span(ViewPerfSpan::RenderResolverDiffRows);
         let query = this.diff_search_query_or_empty();
-        let query = query.as_ref().trim().to_string();
-        this.sync_conflict_diff_query_overlay_caches(query.as_str());
+        this.sync_conflict_diff_query_overlay_caches(query.text());
         let syntax_lang = this.conflict_row_syntax_language();
         let syntax_mode = DiffSyntaxMode::Auto;
         let theme = this.theme;
         let editor_font_family = crate::font_preferences::current_editor_font_family(cx);
         let show_ws = this.reveal_whitespace_chars;
-        let query_text = this.conflict_diff_query_cache_query.clone();
-        let query = query_text.as_ref();
 
         let (div_id_prefix, canvas_id_prefix, chunk_menu_prefix, input_menu_prefix) = match side {
             ConflictPickSide::Ours => (
@@ -814,7 +812,7 @@ impl MainPaneView {
                     side,
                     text_opt,
                     word_ranges,
-                    query,
+                    &query,
                     syntax_lang,
                     syntax_mode,
                     prepared_diff_syntax_line_for_one_based_line(document, line_no),
@@ -1423,8 +1421,7 @@ impl MainPaneView {
         cx: &mut gpui::Context<Self>,
     ) -> Vec<AnyElement> {
         let query = this.diff_search_query_or_empty();
-        let query = query.as_ref().trim().to_string();
-        this.sync_conflict_diff_query_overlay_caches(query.as_str());
+        this.sync_conflict_diff_query_overlay_caches(query.text());
         let syntax_lang = this.conflict_row_syntax_language();
         // Streamed conflicts may or may not have prepared side documents; Auto
         // remains the safe fallback when a row is not backed by one.
@@ -1467,7 +1464,7 @@ impl MainPaneView {
         side: ConflictPickSide,
         text: Option<&gitcomet_core::file_diff::FileDiffLineText>,
         word_ranges: &[Range<usize>],
-        query: &str,
+        query: &DiffSearchQuery,
         syntax_lang: Option<DiffSyntaxLanguage>,
         syntax_mode: DiffSyntaxMode,
         prepared_line: PreparedDiffSyntaxLine,
@@ -1483,7 +1480,6 @@ impl MainPaneView {
             return result;
         }
 
-        let query = query.trim();
         let query_active = !query.is_empty();
         let base_has_style = !word_ranges.is_empty() || syntax_lang.is_some();
 
@@ -1525,18 +1521,32 @@ impl MainPaneView {
                 Some(ConflictRowStyledTextValue::Owned(styled)) => Some(styled),
                 _ => stable_cache.get(&key),
             } {
-                build_cached_diff_query_overlay_styled_text(theme, base, query)
+                build_cached_diff_query_overlay_styled_text(
+                    theme,
+                    base,
+                    query.text(),
+                    query.regex.as_ref(),
+                )
             } else {
-                build_conflict_cached_diff_styled_text_with_source_identity(
+                let styled = build_conflict_cached_diff_styled_text_with_source_identity(
                     theme,
                     text,
                     source_identity,
                     word_ranges,
-                    query,
+                    query.plain_text(),
                     syntax_lang,
                     syntax_mode,
                     None,
-                )
+                );
+                match query.regex.as_ref() {
+                    Some(regex) => build_cached_diff_query_overlay_styled_text(
+                        theme,
+                        &styled,
+                        query.text(),
+                        Some(regex),
+                    ),
+                    None => styled,
+                }
             };
             if !result.pending {
                 query_cache.insert(key, styled);
@@ -1597,8 +1607,7 @@ impl MainPaneView {
         let word_hl = word_hl_computed.as_ref().or(word_hl_precomputed);
         let old_word_ranges = word_hl.map(|(o, _)| o.as_slice()).unwrap_or(&[]);
         let new_word_ranges = word_hl.map(|(_, n)| n.as_slice()).unwrap_or(&[]);
-        let query_text = self.conflict_diff_query_cache_query.clone();
-        let query = query_text.as_ref();
+        let query = self.diff_search_query_or_empty();
         let (left_styled, right_styled) = if styling_enabled {
             (
                 Self::conflict_split_row_styled(
@@ -1609,7 +1618,7 @@ impl MainPaneView {
                     ConflictPickSide::Ours,
                     row.old.as_ref(),
                     old_word_ranges,
-                    query,
+                    &query,
                     syntax_lang,
                     syntax_mode,
                     prepared_diff_syntax_line_for_one_based_line(ours_document, row.old_line),
@@ -1622,7 +1631,7 @@ impl MainPaneView {
                     ConflictPickSide::Theirs,
                     row.new.as_ref(),
                     new_word_ranges,
-                    query,
+                    &query,
                     syntax_lang,
                     syntax_mode,
                     prepared_diff_syntax_line_for_one_based_line(theirs_document, row.new_line),
@@ -3,7 +3,7 @@ use super::diff_text::*;
 use super::*;
 use crate::view::panes::main::{
     CollapsedDiffExpansionKind, CollapsedDiffHunk, CollapsedDiffVisibleRow,
-    DiffHorizontalScrollColumn,
+    DiffHorizontalScrollColumn, DiffSearchQuery,
 };
 use crate::view::panes::main::{
     VersionedCachedDiffStyledText, versioned_query_cached_diff_styled_text_is_current,
@@ -335,7 +335,7 @@ fn diff_placeholder_row(
 
 fn streamed_diff_text_spec_with_syntax(
     raw_text: gitcomet_core::file_diff::FileDiffLineText,
-    query: &SharedString,
+    query: &DiffSearchQuery,
     word_ranges: Vec<Range<usize>>,
     word_color: Option<gpui::Rgba>,
     syntax: diff_canvas::StreamedDiffTextSyntaxSource,
@@ -353,7 +353,7 @@ fn streamed_diff_text_spec_with_syntax(
 
 fn heuristic_streamed_diff_text_spec(
     raw_text: gitcomet_core::file_diff::FileDiffLineText,
-    query: &SharedString,
+    query: &DiffSearchQuery,
     word_ranges: Vec<Range<usize>>,
     word_color: Option<gpui::Rgba>,
     language: Option<rows::DiffSyntaxLanguage>,
@@ -369,7 +369,7 @@ fn heuristic_streamed_diff_text_spec(
 #[allow(clippy::too_many_arguments)]
 fn prepared_streamed_diff_text_spec(
     raw_text: gitcomet_core::file_diff::FileDiffLineText,
-    query: &SharedString,
+    query: &DiffSearchQuery,
     word_ranges: Vec<Range<usize>>,
     word_color: Option<gpui::Rgba>,
     language: Option<rows::DiffSyntaxLanguage>,
@@ -492,15 +492,14 @@ impl MainPaneView {
     fn diff_text_segments_cache_get_for_query(
         &mut self,
         key: usize,
-        query: &str,
+        query: &DiffSearchQuery,
         syntax_epoch: u64,
     ) -> Option<&CachedDiffStyledText> {
-        let query = query.trim();
         if query.is_empty() {
             return self.diff_text_segments_cache_get(key, syntax_epoch);
         }
 
-        self.sync_diff_text_query_overlay_cache(query);
+        self.sync_diff_text_query_overlay_cache(query.text());
         let query_generation = self.diff_text_query_cache_generation;
         if self.diff_text_query_segments_cache.len() <= key {
             self.diff_text_query_segments_cache
@@ -519,7 +518,12 @@ impl MainPaneView {
             let base = self
                 .diff_text_segments_cache_get(key, syntax_epoch)?
                 .clone();
-            let overlaid = build_cached_diff_query_overlay_styled_text(self.theme, &base, query);
+            let overlaid = build_cached_diff_query_overlay_styled_text(
+                self.theme,
+                &base,
+                query.text(),
+                query.regex.as_ref(),
+            );
             self.diff_text_query_segments_cache[key] = Some(VersionedCachedDiffStyledText {
                 syntax_epoch,
                 query_generation,
@@ -723,7 +727,7 @@ impl MainPaneView {
                                 }
                                 this.diff_text_segments_cache_get_for_query(
                                     row_ix,
-                                    query.as_ref(),
+                                    &query,
                                     cache_epoch,
                                 )
                             };
@@ -740,6 +744,7 @@ impl MainPaneView {
                                 visual_kind,
                                 None,
                                 None,
+                                None,
                                 styled,
                                 streamed_spec,
                                 false,
@@ -963,7 +968,7 @@ impl MainPaneView {
                         let styled = if streamed_spec.is_none() {
                             this.diff_text_segments_cache_get_for_query(
                                 inline_ix,
-                                query.as_ref(),
+                                &query,
                                 cache_epoch,
                             )
                         } else {
@@ -1016,7 +1021,7 @@ impl MainPaneView {
                         }
                         let styled = this.diff_text_segments_cache_get_for_query(
                             inline_ix,
-                            query.as_ref(),
+                            &query,
                             cache_epoch,
                         );
                         debug_assert!(
@@ -1039,6 +1044,7 @@ impl MainPaneView {
                         visual_kind,
                         None,
                         None,
+                        None,
                         styled,
                         streamed_spec,
                         false,
@@ -1086,6 +1092,7 @@ impl MainPaneView {
                     return diff_placeholder_row(("diff_oob", visible_ix), theme, ui_scale_percent);
                 };
                 let visual_kind = this.patch_visual_line_kind(src_ix);
+                let moved_block = this.diff_moved_lines.block_for_line(src_ix);
                 let streamed_spec = matches!(click_kind, DiffClickKind::Line)
                     .then(|| {
                         heuristic_streamed_diff_text_spec(
@@ -1149,7 +1156,7 @@ impl MainPaneView {
                         active_context_menu_invoker.as_ref() == Some(&invoker)
                     });
                 let styled = if should_style && streamed_spec.is_none() {
-                    this.diff_text_segments_cache_get_for_query(src_ix, query.as_ref(), cache_epoch)
+                    this.diff_text_segments_cache_get_for_query(src_ix, &query, cache_epoch)
                 } else {
                     None
                 };
@@ -1163,6 +1170,7 @@ impl MainPaneView {
                     min_width,
                     &line,
                     visual_kind,
+                    moved_block,
                     file_stat,
                     header_display,
                     styled,
@@ -1371,7 +1379,7 @@ impl MainPaneView {
                                 if let Some(key) = key {
                                     this.diff_text_segments_cache_get_for_query(
                                         key,
-                                        query.as_ref(),
+                                        &query,
                                         cache_epoch,
                                     )
                                 } else {
@@ -1390,6 +1398,7 @@ impl MainPaneView {
                                 min_width,
                                 &row,
                                 visual_kind,
+                                None,
                                 styled,
                                 streamed_spec,
                                 cx,
@@ -1484,7 +1493,7 @@ impl MainPaneView {
                         if let Some(key) = key {
                             this.diff_text_segments_cache_get_for_query(
                                 key,
-                                query.as_ref(),
+                                &query,
                                 cache_epoch,
                             )
                         } else {
@@ -1510,6 +1519,7 @@ impl MainPaneView {
                         min_width,
                         &row,
                         visual_kind,
+                        None,
                         styled,
                         streamed_spec,
                         cx,
@@ -1553,6 +1563,8 @@ impl MainPaneView {
                             (false, true) => FileDiffRowKind::Add,
                             (false, false) => FileDiffRowKind::Context,
                         };
+                        let moved_block =
+                            src_ix.and_then(|src_ix| this.diff_moved_lines.block_for_line(src_ix));
                         let (streamed_spec, styled) = if let Some(src_ix) = src_ix {
                             let language =
                                 this.diff_language_for_src_ix.get(src_ix).copied().flatten();
@@ -1609,7 +1621,7 @@ impl MainPaneView {
                             let styled = if streamed_spec.is_none() {
                                 this.diff_text_segments_cache_get_for_query(
                                     src_ix,
-                                    query.as_ref(),
+                                    &query,
                                     cache_epoch,
                                 )
                             } else {
@@ -1629,6 +1641,7 @@ impl MainPaneView {
                             min_width,
                             &row,
                             visual_kind,
+                            moved_block,
                             styled,
                             streamed_spec,
                             cx,
@@ -1682,11 +1695,7 @@ impl MainPaneView {
                             });
                         let header_display = this.diff_header_display_cache.get(&src_ix).cloned();
                         let styled = if should_style {
-                            this.diff_text_segments_cache_get_for_query(
-                                src_ix,
-                                query.as_ref(),
-                                cache_epoch,
-                            )
+                            this.diff_text_segments_cache_get_for_query(src_ix, &query, cache_epoch)
                         } else {
                             None
                         };
@@ -1723,6 +1732,7 @@ fn diff_row(
     min_width: Pixels,
     line: &AnnotatedDiffLine,
     visual_kind: DiffLineKind,
+    moved_block: Option<usize>,
     file_stat: Option<(usize, usize)>,
     header_display: Option<SharedString>,
     styled: Option<&CachedDiffStyledText>,
@@ -1844,7 +1854,7 @@ fn diff_row(
         return row.into_any_element();
     }
 
-    let (mut bg, fg, gutter_fg) = diff_line_colors(theme, visual_kind);
+    let (mut bg, fg, gutter_fg) = moved_diff_line_colors(theme, visual_kind, moved_block);
     if selected {
         bg = focused_diff_line_bg(theme, visual_kind);
     }
@@ -1880,8 +1890,10 @@ fn diff_row(
                 DiffLineKind::Context
             };
 
-            let (mut left_bg, left_fg, left_gutter) = diff_line_colors(theme, left_kind);
-            let (mut right_bg, right_fg, right_gutter) = diff_line_colors(theme, right_kind);
+            let (mut left_bg, left_fg, left_gutter) =
+                moved_diff_line_colors(theme, left_kind, moved_block);
+            let (mut right_bg, right_fg, right_gutter) =
+                moved_diff_line_colors(theme, right_kind, moved_block);
             if selected {
                 left_bg = focused_diff_line_bg(theme, left_kind);
                 right_bg = focused_diff_line_bg(theme, right_kind);
@@ -2195,6 +2207,7 @@ fn patch_split_column_row(
     min_width: Pixels,
     row: &gitcomet_core::file_diff::FileDiffRow,
     visual_kind: FileDiffRowKind,
+    moved_block: Option<usize>,
     styled: Option<&CachedDiffStyledText>,
     streamed_spec: Option<diff_canvas::StreamedDiffTextPaintSpec>,
     cx: &mut gpui::Context<MainPaneView>,
@@ -2208,7 +2221,7 @@ fn patch_split_column_row(
         }
         _ => DiffLineKind::Context,
     };
-    let (mut bg, fg, gutter_fg) = diff_line_colors(theme, line_kind);
+    let (mut bg, fg, gutter_fg) = moved_diff_line_colors(theme, line_kind, moved_block);
     if selected {
         bg = focused_diff_line_bg(theme, line_kind);
     }
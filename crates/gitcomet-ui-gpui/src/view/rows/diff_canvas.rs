@@ -5,7 +5,7 @@ use super::diff_text::{
     syntax_highlights_for_streamed_line_slice_heuristic,
 };
 use super::*;
-use crate::view::panes::main::DiffHorizontalScrollColumn;
+use crate::view::panes::main::{DiffHorizontalScrollColumn, DiffSearchQuery};
 use gpui::{
     App, Bounds, CursorStyle, DispatchPhase, HighlightStyle, Hitbox, HitboxBehavior, Pixels,
     Styled, TextRun, TextStyle, Window, fill, point, px, size,
@@ -59,7 +59,7 @@ pub(super) enum StreamedDiffTextSyntaxSource {
 #[derive(Clone)]
 pub(super) struct StreamedDiffTextPaintSpec {
     pub(super) raw_text: gitcomet_core::file_diff::FileDiffLineText,
-    pub(super) query: SharedString,
+    pub(super) query: DiffSearchQuery,
     pub(super) word_ranges: Arc<[Range<usize>]>,
     pub(super) word_color: Option<gpui::Rgba>,
     pub(super) syntax: StreamedDiffTextSyntaxSource,
@@ -343,7 +343,12 @@ fn streamed_diff_text_text_hash(spec: &StreamedDiffTextPaintSpec) -> u64 {
 
 fn streamed_diff_text_highlights_hash(spec: &StreamedDiffTextPaintSpec) -> u64 {
     let mut hasher = FxHasher::default();
-    spec.query.as_ref().hash(&mut hasher);
+    spec.query.text().hash(&mut hasher);
+    spec.query
+        .regex
+        .as_ref()
+        .map(regex::Regex::as_str)
+        .hash(&mut hasher);
     for range in spec.word_ranges.iter() {
         hash_range(&mut hasher, range);
     }
@@ -653,8 +658,13 @@ fn build_streamed_diff_slice_styled_text(
         }
     }
 
-    if !spec.query.as_ref().trim().is_empty() {
-        base = build_cached_diff_query_overlay_styled_text(theme, &base, spec.query.as_ref());
+    if !spec.query.is_empty() {
+        base = build_cached_diff_query_overlay_styled_text(
+            theme,
+            &base,
+            spec.query.text(),
+            spec.query.regex.as_ref(),
+        );
     }
 
     (base, pending, resolved_slice_range)
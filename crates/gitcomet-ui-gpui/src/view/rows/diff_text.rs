@@ -20,7 +20,7 @@ pub(in crate::view) use build::syntax_highlights_for_line;
 pub(super) use build::{
     build_cached_diff_query_overlay_styled_text, build_cached_diff_styled_text,
     build_cached_diff_styled_text_from_relative_highlights,
-    build_cached_diff_styled_text_with_source_identity, diff_line_colors,
+    build_cached_diff_styled_text_with_source_identity, diff_line_colors, moved_diff_line_colors,
     selectable_cached_diff_text,
 };
 #[cfg(any(test, feature = "benchmarks"))]
@@ -62,7 +62,7 @@ pub(in crate::view) use prepared::{
 pub(in crate::view) use syntax::{
     DiffSyntaxBudget, DiffSyntaxEdit, DiffSyntaxLanguage, DiffSyntaxMode,
     PREPARED_DIFF_SYNTAX_DOCUMENT_MAX_TEXT_BYTES, diff_syntax_language_for_code_fence_info,
-    diff_syntax_language_for_path,
+    diff_syntax_language_for_path, structural_file_diff,
 };
 
 pub(super) fn syntax_highlights_for_streamed_line_slice_heuristic(
@@ -2035,11 +2035,11 @@ mod tests {
             text_hash,
         };
 
-        let empty_query = build_cached_diff_query_overlay_styled_text(theme, &base, "");
+        let empty_query = build_cached_diff_query_overlay_styled_text(theme, &base, "", None);
         assert!(Arc::ptr_eq(&empty_query.highlights, &base.highlights));
         assert_eq!(empty_query.highlights_hash, base.highlights_hash);
 
-        let missing_query = build_cached_diff_query_overlay_styled_text(theme, &base, "xyz");
+        let missing_query = build_cached_diff_query_overlay_styled_text(theme, &base, "xyz", None);
         assert!(Arc::ptr_eq(&missing_query.highlights, &base.highlights));
         assert_eq!(missing_query.highlights_hash, base.highlights_hash);
     }
@@ -2062,7 +2062,7 @@ mod tests {
             text_hash,
         };
 
-        let overlaid = build_cached_diff_query_overlay_styled_text(theme, &base, "cd");
+        let overlaid = build_cached_diff_query_overlay_styled_text(theme, &base, "cd", None);
         assert_eq!(overlaid.highlights.len(), 3);
         assert_eq!(overlaid.highlights[1].0, 2..4);
         assert_eq!(
@@ -2095,7 +2095,7 @@ mod tests {
             text_hash,
         };
 
-        let overlaid = build_cached_diff_query_overlay_styled_text(theme, &base, "cdefg");
+        let overlaid = build_cached_diff_query_overlay_styled_text(theme, &base, "cdefg", None);
         assert_eq!(overlaid.highlights.len(), 5);
 
         assert_eq!(overlaid.highlights[0], (1..2, left));
@@ -2126,4 +2126,33 @@ mod tests {
         assert!(overlaid.highlights[3].1.background_color.is_some());
         assert_eq!(overlaid.highlights[4], (7..8, right));
     }
+
+    #[test]
+    fn query_overlay_highlights_regex_matches() {
+        let theme = AppTheme::gitcomet_dark();
+        let text: SharedString = "let foo = fooBar(foo);".into();
+        let mut text_hasher = FxHasher::default();
+        text.as_ref().hash(&mut text_hasher);
+        let base = CachedDiffStyledText {
+            text,
+            highlights: Arc::from(Vec::new()),
+            highlights_hash: 0,
+            text_hash: text_hasher.finish(),
+        };
+        let whole_word = regex::Regex::new(r"\b(?:foo)\b").expect("valid regex");
+
+        let overlaid =
+            build_cached_diff_query_overlay_styled_text(theme, &base, "foo", Some(&whole_word));
+        let ranges: Vec<_> = overlaid
+            .highlights
+            .iter()
+            .map(|(range, _)| range.clone())
+            .collect();
+        assert_eq!(ranges, vec![4..7, 17..20]);
+
+        let missing = regex::Regex::new("^bar").expect("valid regex");
+        let unchanged =
+            build_cached_diff_query_overlay_styled_text(theme, &base, "^bar", Some(&missing));
+        assert!(unchanged.highlights.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use regex::Regex;
 
 fn maybe_expand_tabs(s: &str) -> SharedString {
     if !s.contains('\t') {
@@ -794,10 +795,14 @@ fn build_cached_diff_styled_text_with_optional_palette(
     )
 }
 
+/// Highlights the matches of `query` over `base`. A compiled `regex` finds the
+/// matches for non-default search options; otherwise `query` is matched as a
+/// plain case-insensitive needle.
 pub(in super::super) fn build_cached_diff_query_overlay_styled_text(
     theme: AppTheme,
     base: &CachedDiffStyledText,
     query: &str,
+    regex: Option<&Regex>,
 ) -> CachedDiffStyledText {
     let query = query.trim();
     if query.is_empty() || base.text.is_empty() {
@@ -809,7 +814,10 @@ pub(in super::super) fn build_cached_diff_query_overlay_styled_text(
     }
 
     QUERY_OVERLAY_RANGES_BUF.with_borrow_mut(|query_ranges| {
-        find_all_ascii_case_insensitive_into(base.text.as_ref(), query, query_ranges);
+        match regex {
+            Some(regex) => find_all_regex_matches_into(base.text.as_ref(), regex, query_ranges),
+            None => find_all_ascii_case_insensitive_into(base.text.as_ref(), query, query_ranges),
+        }
         if query_ranges.is_empty() {
             return base.clone();
         }
@@ -1348,8 +1356,20 @@ fn find_all_ascii_case_insensitive(haystack: &str, needle: &str) -> Vec<Range<us
     out
 }
 
+const MAX_QUERY_MATCHES: usize = 64;
+
+fn find_all_regex_matches_into(haystack: &str, regex: &Regex, out: &mut Vec<Range<usize>>) {
+    out.clear();
+    out.extend(
+        regex
+            .find_iter(haystack)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.range())
+            .take(MAX_QUERY_MATCHES),
+    );
+}
+
 fn find_all_ascii_case_insensitive_into(haystack: &str, needle: &str, out: &mut Vec<Range<usize>>) {
-    const MAX_MATCHES: usize = 64;
     out.clear();
 
     let needle_bytes = needle.as_bytes();
@@ -1366,7 +1386,8 @@ fn find_all_ascii_case_insensitive_into(haystack: &str, needle: &str, out: &mut
     let first_lower = first.to_ascii_lowercase();
     let first_upper = first.to_ascii_uppercase();
     if needle_len == 1 {
-        for start in memchr2_iter(first_lower, first_upper, haystack_bytes).take(MAX_MATCHES) {
+        for start in memchr2_iter(first_lower, first_upper, haystack_bytes).take(MAX_QUERY_MATCHES)
+        {
             out.push(start..(start + 1));
         }
         return;
@@ -1395,7 +1416,7 @@ fn find_all_ascii_case_insensitive_into(haystack: &str, needle: &str, out: &mut
         }
 
         out.push(start..(start + needle_len));
-        if out.len() == MAX_MATCHES {
+        if out.len() == MAX_QUERY_MATCHES {
             break;
         }
         next_allowed_start = start + needle_len;
@@ -1436,3 +1457,30 @@ pub(in super::super) fn diff_line_colors(
         ),
     }
 }
+
+/// Colors for a line that belongs to a moved block. Adjacent blocks alternate
+/// between two tints so neighbouring moves stay distinguishable, like git's
+/// `--color-moved=zebra`.
+pub(in super::super) fn moved_diff_line_colors(
+    theme: AppTheme,
+    kind: gitcomet_core::domain::DiffLineKind,
+    moved_block: Option<usize>,
+) -> (gpui::Rgba, gpui::Rgba, gpui::Rgba) {
+    use gitcomet_core::domain::DiffLineKind::*;
+
+    let Some(block) = moved_block else {
+        return diff_line_colors(theme, kind);
+    };
+    let base = match kind {
+        Add => theme.colors.accent,
+        Remove => theme.colors.warning,
+        Header | Hunk | Context => return diff_line_colors(theme, kind),
+    };
+    let alpha = match (theme.is_dark, block % 2 == 0) {
+        (true, true) => 0.22,
+        (true, false) => 0.14,
+        (false, true) => 0.18,
+        (false, false) => 0.10,
+    };
+    (with_alpha(base, alpha), theme.colors.text, base)
+}
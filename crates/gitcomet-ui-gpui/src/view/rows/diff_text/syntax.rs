@@ -326,6 +326,7 @@ pub(super) enum PrepareTreesitterDocumentResult {
 mod heuristic;
 mod language;
 mod prepared;
+mod structural;
 
 use heuristic::*;
 use language::*;
@@ -359,6 +360,7 @@ pub(super) use prepared::{
 };
 #[cfg(test)]
 pub(super) use prepared::{prepared_document_parse_mode, prepared_document_source_version};
+pub(in crate::view) use structural::structural_file_diff;
 
 #[cfg(test)]
 pub(super) fn reset_prepared_syntax_cache() {
@@ -5683,6 +5685,27 @@ mod tests {
         assert!(tree_sitter_highlight_spec(DiffSyntaxLanguage::Xml).is_none());
     }
 
+    #[cfg(any(test, feature = "syntax-rust"))]
+    #[test]
+    fn structural_file_diff_ignores_rust_rewrapping() {
+        let old = "fn main() {\n    call(first, second);\n}\n";
+        let new = "fn main() {\n    call(\n        first,\n        second\n    );\n}\n";
+        let diff = structural_file_diff(DiffSyntaxLanguage::Rust, old, new)
+            .expect("rust sources should align structurally");
+        assert!(diff.old_changed_lines.iter().all(|changed| !changed));
+        assert!(diff.new_changed_lines.iter().all(|changed| !changed));
+
+        let edited = "fn main() {\n    call(first, third);\n}\n";
+        let diff = structural_file_diff(DiffSyntaxLanguage::Rust, old, edited)
+            .expect("rust sources should align structurally");
+        assert_eq!(diff.new_changed_lines, vec![false, true, false]);
+
+        assert!(
+            structural_file_diff(DiffSyntaxLanguage::Rust, old, "fn main( {\n").is_none(),
+            "parse errors should fall back to the line plan"
+        );
+    }
+
     #[cfg(any(test, feature = "syntax-rust"))]
     #[test]
     fn highlight_spec_exposes_ts_language() {
@@ -0,0 +1,100 @@
+use super::*;
+use gitcomet_core::file_diff::{StructuralDiff, StructuralToken, structural_side_by_side_plan};
+
+/// Larger documents skip structural alignment and use the line plan instead.
+const STRUCTURAL_DIFF_MAX_TEXT_BYTES: usize = 512 * 1024;
+const STRUCTURAL_DIFF_PARSE_BUDGET: Duration = Duration::from_millis(250);
+
+/// Align two versions of a file by their tree-sitter leaf tokens, so
+/// reformatting, re-indentation and argument wrapping stay unchanged.
+///
+/// Returns `None` when the language has no bundled grammar, either side has
+/// parse errors, or a budget is exceeded; callers keep the line-based plan.
+pub(in crate::view) fn structural_file_diff(
+    language: DiffSyntaxLanguage,
+    old_text: &str,
+    new_text: &str,
+) -> Option<StructuralDiff> {
+    if old_text.len().max(new_text.len()) > STRUCTURAL_DIFF_MAX_TEXT_BYTES {
+        return None;
+    }
+    let (ts_language, _) = tree_sitter_grammar(language)?;
+    let old_tree = parse_structural_tree(&ts_language, old_text)?;
+    let new_tree = parse_structural_tree(&ts_language, new_text)?;
+    let old_tokens = structural_tokens(&old_tree, old_text);
+    let new_tokens = structural_tokens(&new_tree, new_text);
+    structural_side_by_side_plan(old_text, new_text, &old_tokens, &new_tokens)
+}
+
+fn parse_structural_tree(
+    ts_language: &tree_sitter::Language,
+    text: &str,
+) -> Option<tree_sitter::Tree> {
+    let tree = with_ts_parser_parse_result(ts_language, |parser| {
+        parse_treesitter_tree(
+            parser,
+            text.as_bytes(),
+            None,
+            Some(STRUCTURAL_DIFF_PARSE_BUDGET),
+        )
+    })?;
+    (!tree.root_node().has_error()).then_some(tree)
+}
+
+/// Nodes whose children leave non-whitespace text uncovered (string bodies in
+/// some grammars, markdown paragraphs) are compared as a single atom.
+fn node_has_uncovered_text(node: tree_sitter::Node<'_>, text: &str) -> bool {
+    let is_gap = |start: usize, end: usize| {
+        text.get(start..end)
+            .is_some_and(|gap| !gap.trim().is_empty())
+    };
+    let mut pos = node.start_byte();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if is_gap(pos, child.start_byte()) {
+            return true;
+        }
+        pos = pos.max(child.end_byte());
+    }
+    is_gap(pos, node.end_byte())
+}
+
+fn structural_tokens<'a>(tree: &tree_sitter::Tree, text: &'a str) -> Vec<StructuralToken<'a>> {
+    let mut tokens = Vec::new();
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        let is_atom = node.child_count() == 0 || node_has_uncovered_text(node, text);
+        if !is_atom && cursor.goto_first_child() {
+            continue;
+        }
+        if let Some(token_text) = text
+            .get(node.byte_range())
+            .map(str::trim)
+            .filter(|token_text| !token_text.is_empty())
+        {
+            let first_line = node.start_position().row;
+            let end = node.end_position();
+            // A token that swallows its trailing newline still ends on its own line.
+            let last_line = if end.column == 0 && end.row > first_line {
+                end.row - 1
+            } else {
+                end.row
+            };
+            tokens.push(StructuralToken {
+                text: token_text,
+                first_line,
+                last_line,
+            });
+        }
+        loop {
+            if cursor.goto_next_sibling() {
+                continue 'walk;
+            }
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    tokens
+}
@@ -7,6 +7,7 @@ use crate::view::markdown_preview::{
     MarkdownAlertKind, MarkdownChangeHint, MarkdownInlineStyle, MarkdownPreviewDocument,
     MarkdownPreviewRow, MarkdownPreviewRowKind,
 };
+use crate::view::panes::main::DiffSearchQuery;
 use crate::view::perf::{self, ViewPerfRenderLane, ViewPerfSpan};
 use rustc_hash::FxHasher;
 
@@ -20,7 +21,7 @@ struct WorktreePreviewPreparedSyntaxSource {
 fn worktree_preview_streamed_spec(
     raw_text: gitcomet_core::file_diff::FileDiffLineText,
     line_ix: usize,
-    query: &SharedString,
+    query: &DiffSearchQuery,
     language: Option<rows::DiffSyntaxLanguage>,
     syntax_mode: rows::DiffSyntaxMode,
     prepared_syntax_source: Option<&WorktreePreviewPreparedSyntaxSource>,
@@ -135,7 +136,7 @@ impl MainPaneView {
                                 build: DiffTextBuildRequest {
                                     text: line,
                                     word_ranges: &[],
-                                    query: query.as_ref(),
+                                    query: query.plain_text(),
                                     syntax: DiffSyntaxConfig {
                                         language,
                                         mode: syntax_mode,
@@ -149,6 +150,16 @@ impl MainPaneView {
                             },
                         )
                         .into_parts();
+                    let styled = if query.regex.is_some() {
+                        build_cached_diff_query_overlay_styled_text(
+                            theme,
+                            &styled,
+                            query.text(),
+                            query.regex.as_ref(),
+                        )
+                    } else {
+                        styled
+                    };
                     if is_pending {
                         this.ensure_prepared_syntax_chunk_poll(cx);
                         pending_styled = Some(styled);
@@ -1722,6 +1733,9 @@ impl HistoryView {
                 let connect_from_top_col =
                     (show_working_tree_summary_row && visible_ix == 0).then_some(0);
                 let selected = repo.history_state.selected_commit.as_ref() == Some(&commit.id);
+                let multi_selected = this
+                    .history_multi_selection_for_repo(repo.id)
+                    .is_some_and(|selection| selection.contains(&commit.id));
                 let selected_branch_entry_text = this.selected_branch_entry_text_for_history_row(
                     repo.id,
                     base_row_vm.is_head,
@@ -1763,8 +1777,10 @@ impl HistoryView {
                     when,
                     short_sha,
                     selected,
+                    multi_selected,
                     base_row_vm.is_head,
                     is_stash_node,
+                    base_row_vm.is_fixup,
                     this.active_context_menu_invoker.as_ref(),
                     cx,
                 ))
@@ -1773,6 +1789,34 @@ impl HistoryView {
     }
 }
 
+impl HistoryView {
+    fn apply_history_selection_click(
+        &mut self,
+        repo_id: RepoId,
+        commit_id: &CommitId,
+        modifiers: gpui::Modifiers,
+    ) {
+        let Some(repo) = self.active_repo().filter(|repo| repo.id == repo_id) else {
+            return;
+        };
+        let selected_commit = repo.history_state.selected_commit.clone();
+        let visible_commits = || self.history_visible_commit_ids(repo);
+        let mut selection = self
+            .history_multi_selection
+            .get(&repo_id)
+            .cloned()
+            .unwrap_or_default();
+        crate::view::panes::apply_history_multi_selection_click(
+            &mut selection,
+            commit_id,
+            modifiers,
+            selected_commit.as_ref(),
+            visible_commits,
+        );
+        self.history_multi_selection.insert(repo_id, selection);
+    }
+}
+
 const HISTORY_ROW_HEIGHT_PX: f32 = 24.0;
 
 fn history_worktree_node_color(
@@ -1864,8 +1908,10 @@ fn history_table_row(
     when: HistoryTextVm,
     short_sha: HistoryTextVm,
     selected: bool,
+    multi_selected: bool,
     is_head: bool,
     is_stash_node: bool,
+    is_fixup: bool,
     active_context_menu_invoker: Option<&SharedString>,
     cx: &mut gpui::Context<HistoryView>,
 ) -> AnyElement {
@@ -1894,6 +1940,7 @@ fn history_table_row(
         show_sha,
         show_graph_color_marker,
         is_stash_node,
+        is_fixup,
         connect_from_top_col,
         graph_rows,
         graph_row_ix,
@@ -1923,7 +1970,8 @@ fn history_table_row(
         })
         .active(move |s| s.bg(theme.colors.active))
         .child(commit_row)
-        .on_click(cx.listener(move |this, _e: &ClickEvent, _w, cx| {
+        .on_click(cx.listener(move |this, e: &ClickEvent, _w, cx| {
+            this.apply_history_selection_click(repo_id, &commit_id, e.modifiers());
             this.store.dispatch(Msg::SelectCommit {
                 repo_id,
                 commit_id: commit_id.clone(),
@@ -1931,7 +1979,7 @@ fn history_table_row(
             cx.notify();
         }));
 
-    if selected {
+    if selected || multi_selected {
         row = row.bg(with_alpha(theme.colors.accent, 0.15));
     }
     if context_menu_active {
@@ -178,6 +178,7 @@ pub(super) fn history_commit_row_canvas(
     show_sha: bool,
     show_graph_color_marker: bool,
     is_stash_node: bool,
+    is_fixup: bool,
     connect_from_top_col: Option<usize>,
     graph_rows: Arc<[history_graph::GraphRow]>,
     graph_row_ix: usize,
@@ -472,10 +473,36 @@ pub(super) fn history_commit_row_canvas(
                 );
             }
 
+            // Autosquash commits are indented behind an elbow so they read as
+            // grouped under the commit they will be folded into.
+            let fixup_indent = if is_fixup {
+                let elbow_x = summary_bounds.left() + cell_pad_x + scaled_px(3.0);
+                let elbow_w = scaled_px(7.0);
+                let thickness = scaled_px(1.0);
+                let mid_y = bounds.top() + bounds.size.height * 0.5;
+                let color = with_alpha(theme.colors.text_muted, 0.7);
+                window.paint_quad(fill(
+                    Bounds::new(
+                        point(elbow_x, bounds.top()),
+                        size(thickness, mid_y - bounds.top()),
+                    ),
+                    color,
+                ));
+                window.paint_quad(fill(
+                    Bounds::new(point(elbow_x, mid_y), size(elbow_w, thickness)),
+                    color,
+                ));
+                scaled_px(14.0)
+            } else {
+                px(0.0)
+            };
             let summary_text_bounds = Bounds::new(
-                point(summary_bounds.left() + cell_pad_x, bounds.top()),
+                point(
+                    summary_bounds.left() + cell_pad_x + fixup_indent,
+                    bounds.top(),
+                ),
                 size(
-                    (summary_bounds.size.width - cell_pad_x * 2.0).max(px(0.0)),
+                    (summary_bounds.size.width - cell_pad_x * 2.0 - fixup_indent).max(px(0.0)),
                     bounds.size.height,
                 ),
             );
@@ -487,7 +514,11 @@ pub(super) fn history_commit_row_canvas(
                     summary.shared(),
                     summary.text_hash(),
                     summary_text_bounds.size.width.max(px(0.0)),
-                    theme.colors.text,
+                    if is_fixup {
+                        theme.colors.text_muted
+                    } else {
+                        theme.colors.text
+                    },
                     None,
                 );
                 window.with_content_mask(
@@ -638,11 +669,23 @@ pub(super) fn history_commit_row_canvas(
                             format!("history_commit_menu_{}_{}", repo_id.0, commit_id.as_ref())
                                 .into();
                         this.activate_context_menu_invoker(context_menu_invoker, cx);
+                        let batch = this
+                            .history_multi_selection_for_repo(repo_id)
+                            .filter(|selection| selection.contains(&commit_id))
+                            .map(|selection| selection.commits.clone());
+                        if batch.is_none() {
+                            this.history_multi_selection.remove(&repo_id);
+                        }
                         let kind = if is_tag {
                             PopoverKind::TagMenu {
                                 repo_id,
                                 commit_id: commit_id.clone(),
                             }
+                        } else if let Some(commit_ids) = batch {
+                            PopoverKind::CommitSelectionMenu {
+                                repo_id,
+                                commit_ids,
+                            }
                         } else {
                             PopoverKind::CommitMenu {
                                 repo_id,
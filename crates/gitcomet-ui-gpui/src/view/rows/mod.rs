@@ -135,6 +135,11 @@ fn commit_file_row_presentation_signature(
         ix.hash(&mut primary);
         kind_key.hash(&mut primary);
         path_bytes.hash(&mut primary);
+        file.old_path
+            .as_ref()
+            .map(|path| path.as_os_str().as_encoded_bytes())
+            .hash(&mut primary);
+        file.similarity.hash(&mut primary);
 
         kind_key.hash(&mut secondary);
         ix.hash(&mut secondary);
@@ -263,7 +268,7 @@ impl CommitFileKindVisuals {
     }
 }
 
-const COMMIT_FILE_KIND_VISUALS: [CommitFileKindVisuals; 6] = [
+const COMMIT_FILE_KIND_VISUALS: [CommitFileKindVisuals; 7] = [
     CommitFileKindVisuals {
         icon: "icons/question.svg",
         kind_key: 4,
@@ -289,6 +294,11 @@ const COMMIT_FILE_KIND_VISUALS: [CommitFileKindVisuals; 6] = [
         kind_key: 3,
         tone: CommitFileKindTone::Accent,
     },
+    CommitFileKindVisuals {
+        icon: "icons/copy.svg",
+        kind_key: 6,
+        tone: CommitFileKindTone::Accent,
+    },
     CommitFileKindVisuals {
         icon: "icons/warning.svg",
         kind_key: 5,
@@ -322,6 +332,123 @@ thread_local! {
         RefCell::new(vec![SharedString::default()]);
 }
 
+/// Muted "from <source> (<n>%)" note shown after renamed or copied paths.
+pub(in crate::view) fn rename_source_label(
+    old_path: Option<&std::path::Path>,
+    similarity: Option<u8>,
+) -> Option<SharedString> {
+    let old_path = old_path?;
+    Some(match similarity {
+        Some(similarity) => format!("from {} ({similarity}%)", old_path.display()).into(),
+        None => format!("from {}", old_path.display()).into(),
+    })
+}
+
+/// Heuristic syntax highlights for one line of `path`, for the standalone
+/// difftool windows outside `crate::view`.
+pub(crate) fn syntax_highlights_for_path_line(
+    theme: AppTheme,
+    path: &std::path::Path,
+    text: &str,
+) -> Vec<(std::ops::Range<usize>, gpui::HighlightStyle)> {
+    match diff_syntax_language_for_path(path) {
+        Some(language) => {
+            syntax_highlights_for_line(theme, text, language, DiffSyntaxMode::HeuristicOnly)
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Row of the commit file list.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(in crate::view) enum CommitFileListRow {
+    File(usize),
+    /// Toggles the `linguist-generated` files listed after it.
+    GeneratedHeader {
+        count: usize,
+    },
+}
+
+/// Commit file list layout. Files marked `linguist-generated` are moved after
+/// the others behind a header, and only listed when that group is expanded.
+pub(in crate::view) struct CommitFileListRows {
+    /// `None` when no file is generated and rows map 1:1 onto files.
+    rows: Option<Vec<CommitFileListRow>>,
+    len: usize,
+}
+
+impl CommitFileListRows {
+    pub(in crate::view) fn new(
+        files: &[gitcomet_core::domain::CommitFileChange],
+        generated_expanded: bool,
+    ) -> Self {
+        let generated = files.iter().filter(|file| file.generated).count();
+        if generated == 0 {
+            return Self {
+                rows: None,
+                len: files.len(),
+            };
+        }
+        let mut rows = files
+            .iter()
+            .enumerate()
+            .filter(|(_, file)| !file.generated)
+            .map(|(ix, _)| CommitFileListRow::File(ix))
+            .collect::<Vec<_>>();
+        rows.push(CommitFileListRow::GeneratedHeader { count: generated });
+        if generated_expanded {
+            rows.extend(
+                files
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, file)| file.generated)
+                    .map(|(ix, _)| CommitFileListRow::File(ix)),
+            );
+        }
+        Self {
+            len: rows.len(),
+            rows: Some(rows),
+        }
+    }
+
+    pub(in crate::view) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(in crate::view) fn get(&self, row_ix: usize) -> Option<CommitFileListRow> {
+        match &self.rows {
+            Some(rows) => rows.get(row_ix).copied(),
+            None => (row_ix < self.len).then_some(CommitFileListRow::File(row_ix)),
+        }
+    }
+
+    pub(in crate::view) fn row_of_file(&self, file_ix: usize) -> Option<usize> {
+        match &self.rows {
+            Some(rows) => rows
+                .iter()
+                .position(|row| *row == CommitFileListRow::File(file_ix)),
+            None => (file_ix < self.len).then_some(file_ix),
+        }
+    }
+
+    /// File indices in list order, including collapsed generated files.
+    pub(in crate::view) fn navigation_order(
+        files: &[gitcomet_core::domain::CommitFileChange],
+    ) -> Vec<usize> {
+        Self::new(files, true)
+            .rows
+            .map(|rows| {
+                rows.into_iter()
+                    .filter_map(|row| match row {
+                        CommitFileListRow::File(ix) => Some(ix),
+                        CommitFileListRow::GeneratedHeader { .. } => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(|| (0..files.len()).collect())
+    }
+}
+
 fn line_number_string(n: Option<u32>) -> SharedString {
     let Some(n) = n else {
         return SharedString::default();
@@ -377,7 +504,7 @@ pub(in crate::view) use diff_text::{
     prepare_diff_syntax_document_with_budget_reuse_text,
     prepared_diff_syntax_line_for_inline_diff_row, prepared_diff_syntax_line_for_one_based_line,
     prepared_diff_syntax_reparse_seed, request_syntax_highlights_for_prepared_document_byte_range,
-    resolved_output_line_text, syntax_highlights_for_line,
+    resolved_output_line_text, structural_file_diff, syntax_highlights_for_line,
 };
 
 pub(in crate::view) use self::diff_canvas::is_streamable_diff_text;
@@ -533,6 +660,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commit_file_list_rows_group_generated_files_after_header() {
+        let file = |path: &str, generated: bool| CommitFileChange {
+            path: PathBuf::from(path),
+            kind: FileStatusKind::Modified,
+            is_submodule: false,
+            old_path: None,
+            similarity: None,
+            generated,
+        };
+        let files = vec![
+            file("Cargo.lock", true),
+            file("src/lib.rs", false),
+            file("dist/app.js", true),
+            file("README.md", false),
+        ];
+
+        let collapsed = CommitFileListRows::new(&files, false);
+        assert_eq!(collapsed.len(), 3);
+        assert_eq!(collapsed.get(0), Some(CommitFileListRow::File(1)));
+        assert_eq!(collapsed.get(1), Some(CommitFileListRow::File(3)));
+        assert_eq!(
+            collapsed.get(2),
+            Some(CommitFileListRow::GeneratedHeader { count: 2 })
+        );
+        assert_eq!(collapsed.row_of_file(0), None);
+
+        let expanded = CommitFileListRows::new(&files, true);
+        assert_eq!(expanded.len(), 5);
+        assert_eq!(expanded.row_of_file(0), Some(3));
+        assert_eq!(expanded.row_of_file(2), Some(4));
+        assert_eq!(
+            CommitFileListRows::navigation_order(&files),
+            vec![1, 3, 0, 2]
+        );
+
+        let plain = CommitFileListRows::new(&files[1..2], false);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain.get(0), Some(CommitFileListRow::File(0)));
+        assert_eq!(plain.get(1), None);
+    }
+
     #[test]
     fn commit_file_row_presentation_cache_reuses_same_key_and_invalidates_on_new_key() {
         let mut cache: CommitFileRowPresentationCache<u64> =
@@ -542,11 +711,17 @@ mod tests {
                 path: PathBuf::from("src/lib.rs"),
                 kind: FileStatusKind::Modified,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             },
             CommitFileChange {
                 path: PathBuf::from("README.md"),
                 kind: FileStatusKind::Added,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             },
         ];
 
@@ -557,6 +732,9 @@ mod tests {
                 path: PathBuf::from("should/not/appear.rs"),
                 kind: FileStatusKind::Deleted,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             }],
         );
 
@@ -583,6 +761,9 @@ mod tests {
                 path: PathBuf::from("docs/guide.md"),
                 kind: FileStatusKind::Renamed,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             }],
         );
 
@@ -609,11 +790,17 @@ mod tests {
                 path: PathBuf::from("src/lib.rs"),
                 kind: FileStatusKind::Modified,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             },
             CommitFileChange {
                 path: PathBuf::from("README.md"),
                 kind: FileStatusKind::Added,
                 is_submodule: false,
+                old_path: None,
+                similarity: None,
+                generated: false,
             },
         ];
 
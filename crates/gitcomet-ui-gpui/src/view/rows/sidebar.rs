@@ -753,6 +753,11 @@ impl SidebarPaneView {
                     branch,
                     detached,
                     is_active,
+                    locked,
+                    prunable,
+                    dirty_files,
+                    divergence_ahead,
+                    divergence_behind,
                 } => {
                     let branch = branch.clone();
                     let path_for_open = path.clone();
@@ -776,6 +781,88 @@ impl SidebarPaneView {
                     let row_group: SharedString =
                         format!("worktree_row_{}_{}", repo_id.0, ix).into();
                     let row_debug_selector = row_group.as_ref().to_owned();
+                    let dirty_files = dirty_files.filter(|count| *count > 0);
+                    let status_badge =
+                        |icon_path: &'static str,
+                         color: gpui::Rgba,
+                         label: SharedString,
+                         debug_selector: String| {
+                            div()
+                                .flex_none()
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .text_xs()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(color)
+                                .debug_selector(move || debug_selector.clone())
+                                .child(svg_icon(icon_path, color, 11.0))
+                                .child(label)
+                        };
+                    let mut status_badges = div()
+                        .flex_none()
+                        .flex()
+                        .items_center()
+                        .gap(scaled_px(BRANCH_BADGE_GAP_PX));
+                    if let Some(count) = dirty_files {
+                        status_badges = status_badges.child(
+                            status_badge(
+                                "icons/pencil.svg",
+                                theme.colors.warning,
+                                count.to_string().into(),
+                                format!("worktree_dirty_badge_{ix}"),
+                            )
+                            .id(("worktree_dirty_badge", ix))
+                            .gitcomet_tooltip(
+                                theme,
+                                if count == 1 {
+                                    "1 uncommitted file".into()
+                                } else {
+                                    format!("{count} uncommitted files").into()
+                                },
+                            ),
+                        );
+                    }
+                    if let Some(behind) = divergence_behind {
+                        status_badges = status_badges.child(status_badge(
+                            "icons/arrow_down.svg",
+                            theme.colors.warning,
+                            super::super::branch_sidebar::branch_sidebar_divergence_label(behind),
+                            format!("worktree_pull_badge_{ix}"),
+                        ));
+                    }
+                    if let Some(ahead) = divergence_ahead {
+                        status_badges = status_badges.child(status_badge(
+                            "icons/arrow_up.svg",
+                            theme.colors.success,
+                            super::super::branch_sidebar::branch_sidebar_divergence_label(ahead),
+                            format!("worktree_push_badge_{ix}"),
+                        ));
+                    }
+                    if let Some(reason) = locked {
+                        let tooltip: SharedString = if reason.is_empty() {
+                            "Locked".into()
+                        } else {
+                            format!("Locked: {reason}").into()
+                        };
+                        status_badges = status_badges.child(
+                            div()
+                                .id(("worktree_locked_badge", ix))
+                                .debug_selector(move || format!("worktree_locked_badge_{ix}"))
+                                .text_xs()
+                                .text_color(theme.colors.text_muted)
+                                .child("locked")
+                                .gitcomet_tooltip(theme, tooltip),
+                        );
+                    }
+                    if prunable {
+                        status_badges = status_badges.child(status_badge(
+                            "icons/warning.svg",
+                            theme.colors.danger,
+                            "missing".into(),
+                            format!("worktree_prunable_badge_{ix}"),
+                        ));
+                    }
 
                     div()
                         .id(("worktree_item", ix))
@@ -876,7 +963,8 @@ impl SidebarPaneView {
                                                     ),
                                             ),
                                     )
-                                }),
+                                })
+                                .child(status_badges),
                         )
                         .on_click(cx.listener(move |this, e: &ClickEvent, _w, cx| {
                             if !e.standard_click() || e.click_count() < 2 {
@@ -1770,25 +1858,41 @@ impl DetailsPaneView {
             repo.history_state.commit_details_rev,
             &details.files,
         );
+        let generated_expanded = this.commit_files_generated_expanded;
+        let list_rows = CommitFileListRows::new(&details.files, generated_expanded);
         let visible_signature = this.commit_files_visible_signature(
             repo_id,
             repo.history_state.commit_details_rev,
             &range,
-            details.files.len(),
+            list_rows.len(),
         );
         let path_alignment_group = this
             .commit_files_path_alignment_group
             .visible_rows(visible_signature);
 
         range
-            .filter_map(|ix| {
-                details
+            .filter_map(|row_ix| match list_rows.get(row_ix)? {
+                CommitFileListRow::GeneratedHeader { count } => Some(Err((row_ix, count))),
+                CommitFileListRow::File(ix) => details
                     .files
                     .get(ix)
                     .zip(file_rows.get(ix))
-                    .map(|(f, row)| (ix, f, row.label.clone(), row.visuals))
+                    .map(|(f, row)| Ok((ix, f, row.label.clone(), row.visuals))),
             })
-            .map(|(ix, f, path_label, visuals)| {
+            .map(|row| {
+                let (ix, f, path_label, visuals) = match row {
+                    Ok(file_row) => file_row,
+                    Err((row_ix, count)) => {
+                        return commit_generated_files_header(
+                            row_ix,
+                            count,
+                            generated_expanded,
+                            theme,
+                            ui_scale_percent,
+                            cx,
+                        );
+                    }
+                };
                 let commit_id = details.id.clone();
                 let icon = Some(visuals.icon);
                 let color = visuals.color(&theme);
@@ -1811,11 +1915,14 @@ impl DetailsPaneView {
                         DiffTarget::Commit {
                             commit_id: t_commit_id,
                             path: Some(t_path),
+                            ..
                         } => t_commit_id == &commit_id && t_path == &f.path,
                         _ => false,
                     });
                 let commit_id_for_click = commit_id.clone();
                 let path_for_click = f.path.clone();
+                let old_path_for_click = f.old_path.clone();
+                let rename_label = rename_source_label(f.old_path.as_deref(), f.similarity);
                 let commit_id_for_menu = commit_id.clone();
                 let path_for_menu = f.path.clone();
                 let tooltip = path_label.clone();
@@ -1866,6 +1973,16 @@ impl DetailsPaneView {
                                 .render(cx),
                             ),
                     )
+                    .when_some(rename_label, |row, label| {
+                        row.child(
+                            div()
+                                .flex_none()
+                                .text_xs()
+                                .text_color(theme.colors.text_muted)
+                                .whitespace_nowrap()
+                                .child(label),
+                        )
+                    })
                     .on_click(cx.listener(move |this, e: &ClickEvent, window, cx| {
                         if !e.standard_click() {
                             return;
@@ -1873,6 +1990,7 @@ impl DetailsPaneView {
                         let target = DiffTarget::Commit {
                             commit_id: commit_id_for_click.clone(),
                             path: Some(path_for_click.clone()),
+                            old_path: old_path_for_click.clone(),
                         };
                         let selected = this.active_repo().is_some_and(|repo| {
                             repo.id == repo_id
@@ -1928,6 +2046,155 @@ impl DetailsPaneView {
             })
             .collect()
     }
+
+    pub(in super::super) fn render_commit_file_search_rows(
+        this: &mut Self,
+        range: Range<usize>,
+        _window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Vec<AnyElement> {
+        let Some(repo) = this.active_repo() else {
+            return Vec::new();
+        };
+        let Loadable::Ready(details) = &repo.history_state.commit_details else {
+            return Vec::new();
+        };
+        let Some(search) = repo.history_state.commit_file_search.as_ref() else {
+            return Vec::new();
+        };
+        let Loadable::Ready(matches) = &search.matches else {
+            return Vec::new();
+        };
+
+        let theme = this.theme;
+        let ui_scale_percent = this.ui_scale_percent;
+        let scaled_px =
+            |value: f32| crate::ui_scale::design_px_from_percent(value, ui_scale_percent);
+        let repo_id = repo.id;
+        range
+            .filter_map(|ix| {
+                let hit = matches.get(ix)?;
+                let target = DiffTarget::Commit {
+                    commit_id: search.commit_id.clone(),
+                    path: Some(hit.path.clone()),
+                    old_path: details
+                        .files
+                        .iter()
+                        .find(|f| f.path == hit.path)
+                        .and_then(|f| f.old_path.clone()),
+                };
+                let location: SharedString = format!("{}:{}", hit.path.display(), hit.line).into();
+                let query = search.query.clone();
+                Some(
+                    div()
+                        .id(("commit_file_search_match", ix))
+                        .debug_selector(move || {
+                            format!("commit_file_search_match_{}_{}", repo_id.0, ix)
+                        })
+                        .h(scaled_px(24.0))
+                        .flex()
+                        .items_center()
+                        .gap(scaled_px(8.0))
+                        .px(scaled_px(8.0))
+                        .w_full()
+                        .rounded(px(theme.radii.row))
+                        .cursor(CursorStyle::PointingHand)
+                        .hover(move |s| s.bg(theme.colors.hover))
+                        .active(move |s| s.bg(theme.colors.active))
+                        .text_sm()
+                        .whitespace_nowrap()
+                        .child(
+                            div()
+                                .flex_none()
+                                .max_w(scaled_px(200.0))
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .text_color(theme.colors.text_muted)
+                                .child(location.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w(px(0.0))
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .child(hit.text.trim().to_string()),
+                        )
+                        .on_click(cx.listener(move |this, e: &ClickEvent, window, cx| {
+                            if !e.standard_click() {
+                                return;
+                            }
+                            this.open_commit_file_search_match(
+                                repo_id,
+                                target.clone(),
+                                query.clone(),
+                                window,
+                                cx,
+                            );
+                            cx.notify();
+                        }))
+                        .gitcomet_tooltip(theme, location)
+                        .into_any_element(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Collapsible header above the commit's `linguist-generated` files.
+fn commit_generated_files_header(
+    row_ix: usize,
+    count: usize,
+    expanded: bool,
+    theme: AppTheme,
+    ui_scale_percent: u32,
+    cx: &mut gpui::Context<DetailsPaneView>,
+) -> AnyElement {
+    let scaled_px = |value: f32| crate::ui_scale::design_px_from_percent(value, ui_scale_percent);
+    let label: SharedString = if count == 1 {
+        "1 generated file".into()
+    } else {
+        format!("{count} generated files").into()
+    };
+    div()
+        .id(("commit_generated_files_header", row_ix))
+        .h(scaled_px(24.0))
+        .flex()
+        .items_center()
+        .gap(scaled_px(8.0))
+        .px(scaled_px(8.0))
+        .w_full()
+        .rounded(px(theme.radii.row))
+        .cursor(CursorStyle::PointingHand)
+        .hover(move |s| s.bg(theme.colors.hover))
+        .text_sm()
+        .text_color(theme.colors.text_muted)
+        .child(
+            div()
+                .w(scaled_px(16.0))
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(svg_icon(
+                    if expanded {
+                        "icons/chevron_down.svg"
+                    } else {
+                        "icons/arrow_right.svg"
+                    },
+                    theme.colors.text_muted,
+                    scaled_px(10.0),
+                )),
+        )
+        .child(label)
+        .on_click(cx.listener(|this, _e: &ClickEvent, _window, cx| {
+            this.commit_files_generated_expanded = !this.commit_files_generated_expanded;
+            cx.notify();
+        }))
+        .gitcomet_tooltip(
+            theme,
+            "Files marked linguist-generated in .gitattributes".into(),
+        )
+        .into_any_element()
 }
 
 #[cfg(test)]
@@ -2096,18 +2363,24 @@ mod tests {
                 head: None,
                 branch: Some("main".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-feature"),
                 head: None,
                 branch: Some("feature".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-detached"),
                 head: None,
                 branch: None,
                 detached: true,
+                locked: None,
+                prunable: None,
             },
         ]));
 
@@ -2135,12 +2408,16 @@ mod tests {
                 head: None,
                 branch: Some("feature/shared".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-feature-b"),
                 head: None,
                 branch: Some("feature/shared".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
         ]));
 
@@ -2166,18 +2443,24 @@ mod tests {
                 head: None,
                 branch: Some("main".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-feature"),
                 head: None,
                 branch: Some("feature".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-detached"),
                 head: None,
                 branch: None,
                 detached: true,
+                locked: None,
+                prunable: None,
             },
         ]));
 
@@ -2222,6 +2505,8 @@ mod tests {
             head: None,
             branch: Some("feature".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let active = active_workspace_paths_by_branch(&repo, &[]);
@@ -2242,6 +2527,8 @@ mod tests {
             head: None,
             branch: Some("feature/old".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let mut open_worktree = RepoState::new_opening(
@@ -2275,6 +2562,8 @@ mod tests {
             head: None,
             branch: Some("feature/listed".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let open_worktree = RepoState::new_opening(
@@ -2305,6 +2594,8 @@ mod tests {
             head: None,
             branch: Some("feature/old".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
 
         let mut open_worktree = RepoState::new_opening(
@@ -2336,12 +2627,16 @@ mod tests {
                 head: None,
                 branch: Some("feature/shared".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
             Worktree {
                 path: std::path::PathBuf::from("/tmp/repo-feature-b"),
                 head: None,
                 branch: Some("feature/shared".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             },
         ]));
 
@@ -2636,12 +2931,16 @@ mod tests {
                     head: None,
                     branch: Some("main".to_string()),
                     detached: false,
+                    locked: None,
+                    prunable: None,
                 },
                 Worktree {
                     path: PathBuf::from("/tmp/repo-feature"),
                     head: None,
                     branch: Some("feature".to_string()),
                     detached: false,
+                    locked: None,
+                    prunable: None,
                 },
             ]),
         }));
@@ -2932,6 +3231,7 @@ mod tests {
             target: DiffTarget::Commit {
                 commit_id: commit_id("previous"),
                 path: None,
+                old_path: None,
             },
         });
         wait_until(cx, "sidebar repo data", |_cx| {
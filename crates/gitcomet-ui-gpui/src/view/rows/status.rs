@@ -5,6 +5,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 const STATUS_ROW_HEIGHT_PX: f32 = 24.0;
+const STATUS_TREE_INDENT_PX: f32 = 16.0;
 
 #[cfg(test)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -381,6 +382,55 @@ impl DetailsPaneView {
     ) -> Vec<AnyElement> {
         render_status_rows_for_section(this, range, StatusSection::Staged, cx)
     }
+
+    pub(in super::super) fn render_unstaged_tree_rows(
+        this: &mut Self,
+        range: Range<usize>,
+        _window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Vec<AnyElement> {
+        render_status_tree_rows_for_section(this, range, StatusSection::CombinedUnstaged, cx)
+    }
+
+    pub(in super::super) fn render_staged_tree_rows(
+        this: &mut Self,
+        range: Range<usize>,
+        _window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Vec<AnyElement> {
+        render_status_tree_rows_for_section(this, range, StatusSection::Staged, cx)
+    }
+
+    fn status_tree_directory_paths(
+        &self,
+        repo_id: RepoId,
+        section: StatusSection,
+        dir: &std::path::Path,
+    ) -> Vec<std::path::PathBuf> {
+        self.active_repo()
+            .filter(|repo| repo.id == repo_id)
+            .and_then(|repo| StatusSectionEntries::from_repo(repo, section))
+            .map(|entries| status_paths_under(entries.iter(), dir))
+            .unwrap_or_default()
+    }
+}
+
+fn status_entry_is_selected(
+    entry: &FileStatus,
+    section: StatusSection,
+    selected: Option<&DiffTarget>,
+    selected_paths: &[std::path::PathBuf],
+) -> bool {
+    if !selected_paths.is_empty() {
+        selected_paths.iter().any(|p| p == &entry.path)
+    } else {
+        selected.is_some_and(|t| match t {
+            DiffTarget::WorkingTree { path, area, .. } => {
+                *area == section.diff_area() && path == &entry.path
+            }
+            _ => false,
+        })
+    }
 }
 
 fn render_status_rows_for_section(
@@ -397,7 +447,6 @@ fn render_status_rows_for_section(
     };
     let selected = repo.diff_state.diff_target.as_ref();
     let selected_paths = this.status_selected_paths_for_area(repo.id, section.diff_area());
-    let multi_select_active = !selected_paths.is_empty();
     let submodule_statuses = submodule_status_lookup(repo);
     let theme = this.theme;
     let ui_scale = this.ui_scale();
@@ -409,16 +458,7 @@ fn render_status_rows_for_section(
         .filter_map(|ix| entries.get(ix).map(|entry| (ix, entry)))
         .map(|(ix, entry)| {
             let path_display = this.cached_path_display(&entry.path);
-            let is_selected = if multi_select_active {
-                selected_paths.iter().any(|p| p == &entry.path)
-            } else {
-                selected.is_some_and(|t| match t {
-                    DiffTarget::WorkingTree { path, area } => {
-                        *area == section.diff_area() && path == &entry.path
-                    }
-                    _ => false,
-                })
-            };
+            let is_selected = status_entry_is_selected(entry, section, selected, selected_paths);
             let submodule_status = (entry.kind != FileStatusKind::Untracked)
                 .then(|| submodule_statuses.get(entry.path.as_path()).copied())
                 .flatten();
@@ -443,6 +483,280 @@ fn render_status_rows_for_section(
         .collect()
 }
 
+fn render_status_tree_rows_for_section(
+    this: &mut DetailsPaneView,
+    range: Range<usize>,
+    section: StatusSection,
+    cx: &mut gpui::Context<DetailsPaneView>,
+) -> Vec<AnyElement> {
+    let Some(repo) = this.active_repo() else {
+        return Vec::new();
+    };
+    let Some(entries) = StatusSectionEntries::from_repo(repo, section) else {
+        return Vec::new();
+    };
+    let rows = this.status_tree_rows(repo, section);
+    let selected = repo.diff_state.diff_target.as_ref();
+    let selected_paths = this.status_selected_paths_for_area(repo.id, section.diff_area());
+    let submodule_statuses = submodule_status_lookup(repo);
+    let theme = this.theme;
+    let ui_scale = this.ui_scale();
+    let visible_signature = this.status_visible_signature(repo, section, &range, rows.len());
+    let path_alignment_group = this
+        .status_path_alignment_group(section)
+        .visible_rows(visible_signature);
+    range
+        .filter_map(|ix| rows.get(ix).map(|row| (ix, row)))
+        .filter_map(|(ix, row)| match row {
+            StatusTreeRow::Directory {
+                path,
+                label,
+                depth,
+                collapsed,
+                counts,
+            } => Some(status_tree_directory_row(
+                theme, ui_scale, ix, path, label, *depth, *collapsed, *counts, section, repo.id, cx,
+            )),
+            StatusTreeRow::File {
+                entry_ix,
+                name,
+                depth,
+            } => {
+                let entry = entries.get(*entry_ix)?;
+                let is_selected =
+                    status_entry_is_selected(entry, section, selected, selected_paths);
+                let submodule_status = (entry.kind != FileStatusKind::Untracked)
+                    .then(|| submodule_statuses.get(entry.path.as_path()).copied())
+                    .flatten();
+                let row = status_row(
+                    theme,
+                    ui_scale,
+                    *entry_ix,
+                    entry,
+                    submodule_status.is_some(),
+                    submodule_status,
+                    name.clone().into(),
+                    section,
+                    repo.id,
+                    is_selected,
+                    this.tooltip_host.clone(),
+                    path_alignment_group.clone(),
+                    this.active_context_menu_invoker.as_ref(),
+                    cx,
+                );
+                Some(
+                    div()
+                        .pl(ui_scale.px(*depth as f32 * STATUS_TREE_INDENT_PX))
+                        .child(row)
+                        .into_any_element(),
+                )
+            }
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn status_tree_directory_row(
+    theme: AppTheme,
+    ui_scale: crate::ui_scale::UiScale,
+    ix: usize,
+    dir: &std::path::Path,
+    label: &str,
+    depth: usize,
+    collapsed: bool,
+    counts: StatusTreeCounts,
+    section: StatusSection,
+    repo_id: RepoId,
+    cx: &mut gpui::Context<DetailsPaneView>,
+) -> AnyElement {
+    let scaled_px = |value: f32| ui_scale.px(value);
+    let area = section.diff_area();
+    let dir = Arc::new(dir.to_path_buf());
+    let dir_for_toggle = Arc::clone(&dir);
+    let row_group: SharedString = format!(
+        "status_tree_dir_{}_{}_{}",
+        repo_id.0,
+        section.id_label(),
+        ix
+    )
+    .into();
+    let icon_muted = with_alpha(theme.colors.accent, if theme.is_dark { 0.72 } else { 0.82 });
+    let label: SharedString = format!("{label}/").into();
+    let count_badge = |count: usize, prefix: &'static str, color: gpui::Rgba| {
+        (count > 0).then(|| {
+            div()
+                .text_xs()
+                .text_color(color)
+                .whitespace_nowrap()
+                .child(format!("{prefix}{count}"))
+        })
+    };
+
+    let actions = div()
+        .absolute()
+        .right(scaled_px(6.0))
+        .top_0()
+        .bottom_0()
+        .flex()
+        .items_center()
+        .invisible()
+        .group_hover(row_group.clone(), |d| d.visible())
+        .gap(scaled_px(4.0));
+    let actions = match area {
+        DiffArea::Unstaged => {
+            let dir_for_discard = Arc::clone(&dir);
+            let dir_for_stage = Arc::clone(&dir);
+            actions
+                .child(
+                    components::Button::new(format!("status_tree_discard_{ix}"), "Discard")
+                        .style(components::ButtonStyle::Outlined)
+                        .on_click(theme, cx, move |this, e, window, cx| {
+                            cx.stop_propagation();
+                            let paths = this.status_tree_directory_paths(
+                                repo_id,
+                                section,
+                                &dir_for_discard,
+                            );
+                            if paths.is_empty() {
+                                return;
+                            }
+                            *this.status_multi_selection_for_repo_mut(repo_id) =
+                                StatusMultiSelection {
+                                    unstaged: paths,
+                                    ..StatusMultiSelection::default()
+                                };
+                            this.open_popover_at(
+                                PopoverKind::DiscardChangesConfirm {
+                                    repo_id,
+                                    area,
+                                    path: None,
+                                },
+                                e.position(),
+                                window,
+                                cx,
+                            );
+                            cx.notify();
+                        })
+                        .gitcomet_tooltip(theme, "Discard changes in folder".into()),
+                )
+                .child(
+                    components::Button::new(format!("status_tree_stage_{ix}"), "Stage")
+                        .style(components::ButtonStyle::Solid)
+                        .on_click(theme, cx, move |this, _e, _window, cx| {
+                            cx.stop_propagation();
+                            let paths =
+                                this.status_tree_directory_paths(repo_id, section, &dir_for_stage);
+                            if paths.is_empty() {
+                                return;
+                            }
+                            this.clear_status_multi_selection(repo_id);
+                            this.store.dispatch(Msg::StagePaths {
+                                repo_id,
+                                paths: paths.into(),
+                            });
+                            this.store.dispatch(Msg::ClearDiffSelection { repo_id });
+                            cx.notify();
+                        })
+                        .gitcomet_tooltip(theme, "Stage folder".into()),
+                )
+        }
+        DiffArea::Staged => {
+            let dir_for_unstage = Arc::clone(&dir);
+            actions.child(
+                components::Button::new(format!("status_tree_unstage_{ix}"), "Unstage")
+                    .style(components::ButtonStyle::Solid)
+                    .on_click(theme, cx, move |this, _e, _window, cx| {
+                        cx.stop_propagation();
+                        let paths =
+                            this.status_tree_directory_paths(repo_id, section, &dir_for_unstage);
+                        if paths.is_empty() {
+                            return;
+                        }
+                        this.clear_status_multi_selection(repo_id);
+                        this.store.dispatch(Msg::UnstagePaths {
+                            repo_id,
+                            paths: paths.into(),
+                        });
+                        this.store.dispatch(Msg::ClearDiffSelection { repo_id });
+                        cx.notify();
+                    })
+                    .gitcomet_tooltip(theme, "Unstage folder".into()),
+            )
+        }
+    };
+
+    div()
+        .id(("status_tree_dir", ix))
+        .relative()
+        .group(row_group)
+        .flex()
+        .items_center()
+        .gap(scaled_px(8.0))
+        .pl(scaled_px(8.0 + depth as f32 * STATUS_TREE_INDENT_PX))
+        .pr(scaled_px(8.0))
+        .h(scaled_px(STATUS_ROW_HEIGHT_PX))
+        .w_full()
+        .rounded(px(theme.radii.row))
+        .cursor(CursorStyle::PointingHand)
+        .hover(move |s| s.bg(theme.colors.hover))
+        .active(move |s| s.bg(theme.colors.active))
+        .child(
+            div()
+                .w(scaled_px(16.0))
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(svg_icon(
+                    if collapsed {
+                        "icons/arrow_right.svg"
+                    } else {
+                        "icons/chevron_down.svg"
+                    },
+                    icon_muted,
+                    scaled_px(10.0),
+                )),
+        )
+        .child(svg_icon("icons/folder.svg", icon_muted, scaled_px(14.0)))
+        .child(
+            div()
+                .text_sm()
+                .line_height(scaled_px(18.0))
+                .flex_1()
+                .min_w(px(0.0))
+                .line_clamp(1)
+                .whitespace_nowrap()
+                .overflow_hidden()
+                .child(label),
+        )
+        .child(
+            div()
+                .flex_none()
+                .flex()
+                .items_center()
+                .gap(scaled_px(6.0))
+                .children(count_badge(counts.added, "+", theme.colors.success))
+                .children(count_badge(counts.modified, "~", theme.colors.warning))
+                .children(count_badge(counts.deleted, "−", theme.colors.danger))
+                .children(count_badge(counts.conflicted, "!", theme.colors.danger))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.colors.text_muted)
+                        .whitespace_nowrap()
+                        .child(match counts.total() {
+                            1 => "1 file".to_string(),
+                            n => format!("{n} files"),
+                        }),
+                ),
+        )
+        .child(actions)
+        .on_click(cx.listener(move |this, _e: &ClickEvent, _window, cx| {
+            this.toggle_status_tree_directory(repo_id, section, (*dir_for_toggle).clone());
+            cx.notify();
+        }))
+        .into_any_element()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn status_row(
     theme: AppTheme,
@@ -487,6 +801,7 @@ fn status_row(
             FileStatusKind::Added => ("icons/plus.svg", theme.colors.success),
             FileStatusKind::Deleted => ("icons/minus.svg", theme.colors.danger),
             FileStatusKind::Renamed => ("icons/swap.svg", theme.colors.accent),
+            FileStatusKind::Copied => ("icons/copy.svg", theme.colors.accent),
             FileStatusKind::Conflicted => ("icons/warning.svg", theme.colors.danger),
         }
     };
@@ -495,6 +810,9 @@ fn status_row(
     let path_for_stage = Arc::clone(&path);
     let path_for_row = Arc::clone(&path);
     let path_for_menu = Arc::clone(&path);
+    let old_path = entry.old_path.clone();
+    let old_path_for_menu = old_path.clone();
+    let rename_label = rename_source_label(entry.old_path.as_deref(), entry.similarity);
     let is_conflicted = entry.kind == FileStatusKind::Conflicted;
     let stage_label = if is_conflicted {
         "Resolve…"
@@ -623,6 +941,7 @@ fn status_row(
                         target: DiffTarget::WorkingTree {
                             path: clicked_path.clone(),
                             area,
+                            old_path: old_path_for_menu.clone(),
                         },
                     });
                 }
@@ -664,6 +983,16 @@ fn status_row(
                     .render(cx),
                 ),
         )
+        .when_some(rename_label, |row, label| {
+            row.child(
+                div()
+                    .flex_none()
+                    .text_xs()
+                    .text_color(theme.colors.text_muted)
+                    .whitespace_nowrap()
+                    .child(label),
+            )
+        })
         .child(
             div()
                 .absolute()
@@ -682,6 +1011,7 @@ fn status_row(
             let target = DiffTarget::WorkingTree {
                 path: (*path_for_row).clone(),
                 area,
+                old_path: old_path.clone(),
             };
             let should_unselect = _e.standard_click()
                 && this.active_repo().is_some_and(|repo| {
@@ -732,6 +1062,8 @@ mod tests {
             path: pb(path),
             kind,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }
     }
 
@@ -39,6 +39,11 @@ const CHANGE_TRACKING_OPTIONS: &[(&str, ChangeTrackingView, &str)] = &[
         ChangeTrackingView::SplitUntracked,
         "Show an Untracked block above Unstaged",
     ),
+    (
+        "settings_window_change_tracking_tree",
+        ChangeTrackingView::Tree,
+        "Group changed files into collapsible directories",
+    ),
 ];
 
 const DIFF_SCROLL_SYNC_OPTIONS: &[(&str, DiffScrollSync, &str)] = &[
@@ -5192,7 +5197,9 @@ mod tests {
                 .expect("settings window should be readable");
             match current {
                 ChangeTrackingView::Combined => ChangeTrackingView::SplitUntracked,
-                ChangeTrackingView::SplitUntracked => ChangeTrackingView::Combined,
+                ChangeTrackingView::SplitUntracked | ChangeTrackingView::Tree => {
+                    ChangeTrackingView::Combined
+                }
             }
         });
 
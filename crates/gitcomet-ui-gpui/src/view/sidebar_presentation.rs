@@ -238,6 +238,8 @@ mod tests {
             head: None,
             branch: Some("feature/old".to_string()),
             detached: false,
+            locked: None,
+            prunable: None,
         }]));
         repo.worktrees_rev = 1;
         repo.branch_sidebar_rev = 1;
@@ -269,6 +271,8 @@ mod tests {
                 head: None,
                 branch: Some("feature/new".to_string()),
                 detached: false,
+                locked: None,
+                prunable: None,
             }]));
         state.repos[0].worktrees_rev = state.repos[0].worktrees_rev.wrapping_add(1);
         state.repos[0].branch_sidebar_rev = state.repos[0].branch_sidebar_rev.wrapping_add(1);
@@ -0,0 +1,287 @@
+use gitcomet_core::domain::{FileStatus, FileStatusKind};
+use rustc_hash::FxHashSet as HashSet;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+
+/// Change counts aggregated over every file below a directory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(super) struct StatusTreeCounts {
+    pub(super) added: usize,
+    pub(super) modified: usize,
+    pub(super) deleted: usize,
+    pub(super) conflicted: usize,
+}
+
+impl StatusTreeCounts {
+    fn record(&mut self, kind: FileStatusKind) {
+        match kind {
+            FileStatusKind::Added | FileStatusKind::Untracked => self.added += 1,
+            FileStatusKind::Modified | FileStatusKind::Renamed | FileStatusKind::Copied => {
+                self.modified += 1
+            }
+            FileStatusKind::Deleted => self.deleted += 1,
+            FileStatusKind::Conflicted => self.conflicted += 1,
+        }
+    }
+
+    pub(super) fn total(&self) -> usize {
+        self.added + self.modified + self.deleted + self.conflicted
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum StatusTreeRow {
+    /// A directory, or a chain of directories that each hold nothing but the
+    /// next one. `path` is the deepest directory of the chain and is the key
+    /// used for collapsing.
+    Directory {
+        path: PathBuf,
+        label: String,
+        depth: usize,
+        collapsed: bool,
+        counts: StatusTreeCounts,
+    },
+    /// A changed file; `entry_ix` indexes the section's flat entry list.
+    File {
+        entry_ix: usize,
+        name: String,
+        depth: usize,
+    },
+}
+
+#[derive(Default)]
+struct DirNode<'a> {
+    dirs: BTreeMap<&'a OsStr, DirNode<'a>>,
+    files: Vec<(&'a OsStr, usize)>,
+    counts: StatusTreeCounts,
+}
+
+/// Flattens a status section into visible tree rows: directories first, then
+/// files, each sorted by name, skipping the contents of collapsed directories.
+pub(super) fn build_status_tree_rows<'a>(
+    entries: impl IntoIterator<Item = &'a FileStatus>,
+    collapsed: &HashSet<PathBuf>,
+) -> Vec<StatusTreeRow> {
+    let mut root = DirNode::default();
+    for (entry_ix, entry) in entries.into_iter().enumerate() {
+        let mut names = entry
+            .path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name),
+                _ => None,
+            });
+        let Some(mut name) = names.next() else {
+            continue;
+        };
+        let mut node = &mut root;
+        for next in names {
+            node = node.dirs.entry(name).or_default();
+            node.counts.record(entry.kind);
+            name = next;
+        }
+        node.files.push((name, entry_ix));
+    }
+
+    let mut rows = Vec::new();
+    push_rows(&root, Path::new(""), 0, collapsed, &mut rows);
+    rows
+}
+
+fn push_rows(
+    node: &DirNode<'_>,
+    prefix: &Path,
+    depth: usize,
+    collapsed: &HashSet<PathBuf>,
+    rows: &mut Vec<StatusTreeRow>,
+) {
+    for (name, child) in &node.dirs {
+        let mut path = prefix.join(name);
+        let mut label = name.to_string_lossy().into_owned();
+        let mut child = child;
+        while child.files.is_empty() && child.dirs.len() == 1 {
+            let Some((name, next)) = child.dirs.iter().next() else {
+                break;
+            };
+            path.push(name);
+            label.push('/');
+            label.push_str(&name.to_string_lossy());
+            child = next;
+        }
+
+        let is_collapsed = collapsed.contains(&path);
+        rows.push(StatusTreeRow::Directory {
+            path: path.clone(),
+            label,
+            depth,
+            collapsed: is_collapsed,
+            counts: child.counts,
+        });
+        if !is_collapsed {
+            push_rows(child, &path, depth + 1, collapsed, rows);
+        }
+    }
+
+    let mut files: Vec<_> = node.files.iter().collect();
+    files.sort_by_key(|(name, _)| *name);
+    for (name, entry_ix) in files {
+        rows.push(StatusTreeRow::File {
+            entry_ix: *entry_ix,
+            name: name.to_string_lossy().into_owned(),
+            depth,
+        });
+    }
+}
+
+/// Paths of every entry at or below `dir`, in entry order.
+pub(super) fn status_paths_under<'a>(
+    entries: impl IntoIterator<Item = &'a FileStatus>,
+    dir: &Path,
+) -> Vec<PathBuf> {
+    entries
+        .into_iter()
+        .filter(|entry| entry.path.starts_with(dir))
+        .map(|entry| entry.path.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, kind: FileStatusKind) -> FileStatus {
+        FileStatus {
+            path: PathBuf::from(path),
+            kind,
+            conflict: None,
+            old_path: None,
+            similarity: None,
+        }
+    }
+
+    fn labels(rows: &[StatusTreeRow]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                StatusTreeRow::Directory { label, depth, .. } => {
+                    format!("{}{label}/", "  ".repeat(*depth))
+                }
+                StatusTreeRow::File { name, depth, .. } => format!("{}{name}", "  ".repeat(*depth)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn builds_directories_first_and_compacts_single_child_chains() {
+        let entries = vec![
+            entry("README.md", FileStatusKind::Modified),
+            entry("src/view/panels/a.rs", FileStatusKind::Modified),
+            entry("src/view/panels/b.rs", FileStatusKind::Added),
+            entry("src/lib.rs", FileStatusKind::Deleted),
+            entry("docs/guide/intro.md", FileStatusKind::Untracked),
+        ];
+
+        let rows = build_status_tree_rows(&entries, &HashSet::default());
+
+        assert_eq!(
+            labels(&rows),
+            vec![
+                "docs/guide/",
+                "  intro.md",
+                "src/",
+                "  view/panels/",
+                "    a.rs",
+                "    b.rs",
+                "  lib.rs",
+                "README.md",
+            ]
+        );
+        let StatusTreeRow::File { entry_ix, .. } = &rows[4] else {
+            panic!("expected a file row");
+        };
+        assert_eq!(*entry_ix, 1);
+    }
+
+    #[test]
+    fn aggregates_counts_for_every_ancestor() {
+        let entries = vec![
+            entry("src/a.rs", FileStatusKind::Modified),
+            entry("src/nested/b.rs", FileStatusKind::Added),
+            entry("src/nested/c.rs", FileStatusKind::Deleted),
+            entry("src/nested/d.rs", FileStatusKind::Conflicted),
+        ];
+
+        let rows = build_status_tree_rows(&entries, &HashSet::default());
+        let counts: Vec<_> = rows
+            .iter()
+            .filter_map(|row| match row {
+                StatusTreeRow::Directory { path, counts, .. } => Some((path.clone(), *counts)),
+                StatusTreeRow::File { .. } => None,
+            })
+            .collect();
+
+        assert_eq!(
+            counts,
+            vec![
+                (
+                    PathBuf::from("src"),
+                    StatusTreeCounts {
+                        added: 1,
+                        modified: 1,
+                        deleted: 1,
+                        conflicted: 1,
+                    }
+                ),
+                (
+                    PathBuf::from("src/nested"),
+                    StatusTreeCounts {
+                        added: 1,
+                        modified: 0,
+                        deleted: 1,
+                        conflicted: 1,
+                    }
+                ),
+            ]
+        );
+        assert_eq!(counts[0].1.total(), 4);
+    }
+
+    #[test]
+    fn collapsed_directories_hide_their_contents() {
+        let entries = vec![
+            entry("a/b/one.txt", FileStatusKind::Modified),
+            entry("a/two.txt", FileStatusKind::Modified),
+            entry("three.txt", FileStatusKind::Modified),
+        ];
+        let mut collapsed = HashSet::default();
+        collapsed.insert(PathBuf::from("a/b"));
+
+        let rows = build_status_tree_rows(&entries, &collapsed);
+        assert_eq!(labels(&rows), vec!["a/", "  b/", "  two.txt", "three.txt"]);
+        assert!(matches!(
+            &rows[1],
+            StatusTreeRow::Directory {
+                collapsed: true,
+                ..
+            }
+        ));
+
+        collapsed.insert(PathBuf::from("a"));
+        let rows = build_status_tree_rows(&entries, &collapsed);
+        assert_eq!(labels(&rows), vec!["a/", "three.txt"]);
+    }
+
+    #[test]
+    fn paths_under_directory_match_whole_components() {
+        let entries = vec![
+            entry("src/a.rs", FileStatusKind::Modified),
+            entry("src/nested/b.rs", FileStatusKind::Modified),
+            entry("src-old/c.rs", FileStatusKind::Modified),
+        ];
+
+        assert_eq!(
+            status_paths_under(&entries, Path::new("src")),
+            vec![PathBuf::from("src/a.rs"), PathBuf::from("src/nested/b.rs")]
+        );
+    }
+}
@@ -167,6 +167,8 @@ fn reconcile_status_multi_selection_prunes_missing_paths_and_anchors() {
             path: a.clone(),
             kind: FileStatusKind::Modified,
             conflict: None,
+            old_path: None,
+            similarity: None,
         }],
     };
 
@@ -572,6 +574,8 @@ fn worktree_tooltip_includes_branch_name() {
         head: None,
         branch: Some("feature/tooltip".to_string()),
         detached: false,
+        locked: None,
+        prunable: None,
     }]));
 
     let expanded_key = branch_sidebar::expanded_default_section_storage_key(
@@ -616,6 +620,8 @@ fn branch_sidebar_defaults_secondary_sections_to_collapsed() {
         head: None,
         branch: Some("main".to_string()),
         detached: false,
+        locked: None,
+        prunable: None,
     }]));
     repo.submodules = Loadable::Ready(Arc::new(vec![Submodule {
         path: PathBuf::from("vendor/lib"),
@@ -846,6 +852,8 @@ fn branch_sidebar_collapses_branch_sections_without_hiding_other_sections() {
         head: None,
         branch: Some("main".to_string()),
         detached: false,
+        locked: None,
+        prunable: None,
     }]));
     repo.submodules = Loadable::Ready(Arc::new(vec![Submodule {
         path: PathBuf::from("vendor/lib"),
@@ -1399,6 +1407,7 @@ fn diff_target_rendered_preview_kind_reads_diff_target_paths() {
     let svg_target = DiffTarget::WorkingTree {
         path: PathBuf::from("diagram.svg"),
         area: DiffArea::Unstaged,
+        old_path: None,
     };
     assert_eq!(
         diff_target_rendered_preview_kind(Some(&svg_target)),
@@ -1408,6 +1417,7 @@ fn diff_target_rendered_preview_kind_reads_diff_target_paths() {
     let markdown_target = DiffTarget::Commit {
         commit_id: CommitId("deadbeef".into()),
         path: Some(PathBuf::from("README.md")),
+        old_path: None,
     };
     assert_eq!(
         diff_target_rendered_preview_kind(Some(&markdown_target)),
@@ -1417,6 +1427,7 @@ fn diff_target_rendered_preview_kind_reads_diff_target_paths() {
     let no_path_target = DiffTarget::Commit {
         commit_id: CommitId("deadbeef".into()),
         path: None,
+        old_path: None,
     };
     assert_eq!(
         diff_target_rendered_preview_kind(Some(&no_path_target)),
@@ -1424,6 +1435,29 @@ fn diff_target_rendered_preview_kind_reads_diff_target_paths() {
     );
 }
 
+#[test]
+fn diff_target_rendered_preview_kind_detects_tables_and_notebooks() {
+    let target = |path: &str| DiffTarget::WorkingTree {
+        path: PathBuf::from(path),
+        area: DiffArea::Unstaged,
+        old_path: None,
+    };
+    assert_eq!(
+        diff_target_rendered_preview_kind(Some(&target("data/people.tsv"))),
+        Some(RenderedPreviewKind::Csv)
+    );
+    assert_eq!(
+        diff_target_rendered_preview_kind(Some(&target("analysis.IPYNB"))),
+        Some(RenderedPreviewKind::Notebook)
+    );
+    assert_eq!(
+        main_diff_rendered_preview_toggle_kind(false, true, Some(RenderedPreviewKind::Notebook)),
+        None
+    );
+    assert!(RenderedPreviewKind::Notebook.uses_markdown_preview());
+    assert!(!RenderedPreviewKind::Csv.uses_markdown_preview());
+}
+
 #[test]
 fn main_diff_rendered_preview_toggle_kind_matches_supported_modes() {
     assert_eq!(
@@ -1486,6 +1520,7 @@ fn focused_bootstrap(
             remote: "REMOTE".to_string(),
             base: "BASE".to_string(),
         },
+        compare_only: false,
     })
 }
 
@@ -1553,6 +1588,7 @@ fn focused_mergetool_bootstrap_loads_conflict_file_after_diff_target() {
     repo_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         area: DiffArea::Unstaged,
         path: PathBuf::from("src/conflict.txt"),
+        old_path: None,
     });
     state.repos.push(repo_state);
 
@@ -1578,6 +1614,7 @@ fn focused_mergetool_bootstrap_completes_after_conflict_file_target_set() {
     repo_state.diff_state.diff_target = Some(DiffTarget::WorkingTree {
         area: DiffArea::Unstaged,
         path: PathBuf::from("src/conflict.txt"),
+        old_path: None,
     });
     repo_state.conflict_state.conflict_file_path = Some(PathBuf::from("src/conflict.txt"));
     repo_state.conflict_state.conflict_file = Loadable::Loading;
@@ -70,6 +70,15 @@ pub struct DifftoolArgs {
     /// Label for the right pane.
     #[arg(long)]
     pub label_right: Option<String>,
+    /// Path to a third file for a three-way comparison.
+    ///
+    /// The remote file becomes the middle pane and is compared against both
+    /// the local file and this one. Only one third input is supported.
+    #[arg(long)]
+    pub third: Vec<PathBuf>,
+    /// Label for the third pane.
+    #[arg(long)]
+    pub label_third: Option<String>,
     /// Open an interactive GPUI diff window instead of printing to stdout.
     #[arg(long)]
     pub gui: bool,
@@ -147,6 +156,8 @@ pub struct DifftoolConfig {
     pub display_path: Option<String>,
     pub label_left: Option<String>,
     pub label_right: Option<String>,
+    pub third: Option<PathBuf>,
+    pub label_third: Option<String>,
     pub gui: bool,
 }
 
@@ -504,6 +515,28 @@ fn resolve_difftool_with_env(
         ));
     }
 
+    if args.third.len() > 1 {
+        return Err(format!(
+            "Comparing {} files is not supported; difftool compares at most three files (--local, --remote, and one --third).",
+            args.third.len() + 2
+        ));
+    }
+    let third = args
+        .third
+        .into_iter()
+        .next()
+        .map(|third| require_non_empty_path(third, "third"))
+        .transpose()?;
+    if let Some(third) = third.as_ref() {
+        let third_kind = classify_difftool_input(third, "Third")?;
+        if local_kind != DifftoolInputKind::FileLike || third_kind != DifftoolInputKind::FileLike {
+            return Err(
+                "Three-way difftool comparison requires three files; directories are not supported."
+                    .to_string(),
+            );
+        }
+    }
+
     // Display path: flag > MERGED env > BASE env (git difftool compat) > None.
     // Git custom difftool contracts historically pass MERGED and/or BASE as
     // optional compatibility variables; prefer MERGED when both are present.
@@ -519,6 +552,8 @@ fn resolve_difftool_with_env(
         display_path,
         label_left: args.label_left,
         label_right: args.label_right,
+        third,
+        label_third: args.label_third,
         gui: args.gui,
     })
 }
@@ -468,6 +468,8 @@ fn finish_compat_diff_mode(
                 path: None,
                 label_left: label_l1,
                 label_right: label_l2,
+                third: Vec::new(),
+                label_third: None,
                 gui: false,
             };
             resolve_difftool_with_env(args, env)
@@ -137,6 +137,8 @@ fn difftool_resolves_from_explicit_flags() {
         path: Some("display.txt".into()),
         label_left: Some("Ours".into()),
         label_right: Some("Theirs".into()),
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -148,6 +150,79 @@ fn difftool_resolves_from_explicit_flags() {
     assert_eq!(config.label_right.as_deref(), Some("Theirs"));
 }
 
+#[test]
+fn difftool_accepts_third_file_for_three_way_comparison() {
+    let dir = tempfile::tempdir().unwrap();
+    let local = tmp_file(&dir, "main.txt", "a");
+    let remote = tmp_file(&dir, "release.txt", "b");
+    let third = tmp_file(&dir, "patch.txt", "c");
+    let env = TestEnv::new();
+
+    let args = DifftoolArgs {
+        local: Some(local),
+        remote: Some(remote),
+        path: None,
+        label_left: None,
+        label_right: None,
+        third: vec![third.clone()],
+        label_third: Some("patch".into()),
+        gui: false,
+    };
+
+    let config = resolve_difftool_with_env(args, &env).unwrap();
+    assert_eq!(config.third, Some(third));
+    assert_eq!(config.label_third.as_deref(), Some("patch"));
+}
+
+#[test]
+fn difftool_rejects_third_input_for_directory_comparison() {
+    let dir = tempfile::tempdir().unwrap();
+    let left = dir.path().join("left");
+    let right = dir.path().join("right");
+    std::fs::create_dir_all(&left).unwrap();
+    std::fs::create_dir_all(&right).unwrap();
+    let third = tmp_file(&dir, "third.txt", "c");
+    let env = TestEnv::new();
+
+    let args = DifftoolArgs {
+        local: Some(left),
+        remote: Some(right),
+        path: None,
+        label_left: None,
+        label_right: None,
+        third: vec![third],
+        label_third: None,
+        gui: false,
+    };
+
+    let err = resolve_difftool_with_env(args, &env).unwrap_err();
+    assert!(err.contains("requires three files"), "{err}");
+}
+
+#[test]
+fn difftool_rejects_more_than_three_inputs() {
+    let dir = tempfile::tempdir().unwrap();
+    let local = tmp_file(&dir, "main.txt", "a");
+    let remote = tmp_file(&dir, "release.txt", "b");
+    let third = tmp_file(&dir, "patch.txt", "c");
+    let fourth = tmp_file(&dir, "hotfix.txt", "d");
+    let env = TestEnv::new();
+
+    let args = DifftoolArgs {
+        local: Some(local),
+        remote: Some(remote),
+        path: None,
+        label_left: None,
+        label_right: None,
+        third: vec![third, fourth],
+        label_third: None,
+        gui: false,
+    };
+
+    let err = resolve_difftool_with_env(args, &env).unwrap_err();
+    assert!(err.contains("Comparing 4 files is not supported"), "{err}");
+}
+
 #[test]
 fn difftool_resolves_from_env_fallback() {
     let dir = tempfile::tempdir().unwrap();
@@ -165,6 +240,8 @@ fn difftool_resolves_from_env_fallback() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -191,6 +268,8 @@ fn difftool_uses_base_env_as_display_path_fallback() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -216,6 +295,8 @@ fn difftool_prefers_merged_over_base_for_display_path() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -241,6 +322,8 @@ fn difftool_path_flag_overrides_merged_and_base_display_env() {
         path: Some("explicit-name.txt".into()),
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -266,6 +349,8 @@ fn difftool_flags_take_precedence_over_env() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -286,6 +371,8 @@ fn difftool_missing_local_errors() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -305,6 +392,8 @@ fn difftool_missing_remote_errors() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -324,6 +413,8 @@ fn difftool_nonexistent_local_errors() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -346,6 +437,8 @@ fn difftool_nonexistent_remote_errors() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -367,6 +460,8 @@ fn difftool_empty_local_path_errors() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -392,6 +487,8 @@ fn difftool_empty_merged_env_is_ignored_for_display_path() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -414,6 +511,8 @@ fn difftool_accepts_directories() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -435,6 +534,8 @@ fn difftool_rejects_file_vs_directory_mismatch() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -467,6 +568,8 @@ fn difftool_accepts_broken_symlink_inputs() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -497,6 +600,8 @@ fn difftool_accepts_symlinked_directory_inputs() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -525,6 +630,8 @@ fn difftool_rejects_fifo_input() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -562,6 +669,8 @@ fn difftool_rejects_symlink_to_fifo_input() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -1164,6 +1273,8 @@ fn difftool_handles_paths_with_spaces() {
         path: Some("path with spaces.txt".into()),
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -1217,6 +1328,8 @@ fn difftool_handles_unicode_paths() {
         path: None,
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -1250,6 +1363,8 @@ fn difftool_resolves_paths_in_nested_subdirectory() {
         path: Some("src/lib/module.rs".into()),
         label_left: None,
         label_right: None,
+        third: Vec::new(),
+        label_third: None,
         gui: false,
     };
 
@@ -41,19 +41,37 @@ pub struct DifftoolRunResult {
 /// Git exits with code `1` when files differ, which is not an operational
 /// failure for a diff tool. We normalize both `0` (no diff) and `1` (diff
 /// present) to process success for the app-level contract.
+///
+/// With a third input, the remote file is the pivot: the output is the
+/// local/remote diff followed by the remote/third diff.
 pub fn run_difftool(config: &DifftoolConfig) -> Result<DifftoolRunResult, String> {
     let prepared_inputs = prepare_diff_inputs(config)?;
+    let labels = resolve_labels(config);
+    let mut result = run_no_index_diff(&prepared_inputs.local, &prepared_inputs.remote, labels)?;
 
+    if let Some(third) = config.third.as_ref() {
+        let third_labels = resolve_third_labels(config);
+        let third_result = run_no_index_diff(&prepared_inputs.remote, third, third_labels)?;
+        result.stdout.push_str(&third_result.stdout);
+        result.stderr.push_str(&third_result.stderr);
+        result.exit_code = result.exit_code.max(third_result.exit_code);
+    }
+
+    Ok(result)
+}
+
+fn run_no_index_diff(
+    local: &Path,
+    remote: &Path,
+    labels: Option<(String, String)>,
+) -> Result<DifftoolRunResult, String> {
     let mut cmd = git_command();
     cmd.arg("diff").arg("--no-index").arg("--no-ext-diff");
     // When launched from `git difftool`, Git sets `GIT_EXTERNAL_DIFF` to its
     // helper. Remove it so this nested `git diff --no-index` cannot recurse.
     cmd.env_remove("GIT_EXTERNAL_DIFF");
-    let labels = resolve_labels(config);
 
-    cmd.arg("--")
-        .arg(&prepared_inputs.local)
-        .arg(&prepared_inputs.remote);
+    cmd.arg("--").arg(local).arg(remote);
 
     let output = cmd
         .output()
@@ -94,6 +112,169 @@ pub fn run_difftool(config: &DifftoolConfig) -> Result<DifftoolRunResult, String
     }
 }
 
+/// Whether both inputs are directories, as with `git difftool --dir-diff`.
+///
+/// GUI mode opens the directory-compare window for these instead of
+/// rendering the `git diff --no-index` output.
+#[cfg(any(feature = "ui-gpui-runtime", test))]
+pub fn is_directory_diff(config: &DifftoolConfig) -> bool {
+    matches!(
+        (
+            classify_difftool_input(&config.local, "Local"),
+            classify_difftool_input(&config.remote, "Remote"),
+        ),
+        (
+            Ok(DifftoolInputKind::Directory),
+            Ok(DifftoolInputKind::Directory)
+        )
+    )
+}
+
+/// Scratch repository holding a three-way difftool comparison as a conflict.
+///
+/// The GUI opens it in the focused conflict resolver. The directory is
+/// removed when this value is dropped.
+#[cfg(any(feature = "ui-gpui-runtime", test))]
+pub struct ThreeWayCompareRepo {
+    dir: TempDir,
+    file_name: String,
+}
+
+#[cfg(any(feature = "ui-gpui-runtime", test))]
+impl ThreeWayCompareRepo {
+    pub fn repo_path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    pub fn file_path(&self) -> PathBuf {
+        self.dir.path().join(&self.file_name)
+    }
+}
+
+/// Stage the three difftool inputs as an unmerged path in a scratch
+/// repository.
+///
+/// The remote file is the pivot and goes in the base stage, with the local
+/// and third files as the two sides. The worktree copy carries diff3 markers
+/// around every region where the inputs differ.
+#[cfg(any(feature = "ui-gpui-runtime", test))]
+pub fn prepare_three_way_compare_repo(
+    config: &DifftoolConfig,
+    labels: gitcomet_core::conflict_output::ConflictMarkerLabels<'_>,
+) -> Result<ThreeWayCompareRepo, String> {
+    let Some(third) = config.third.as_ref() else {
+        return Err("Three-way comparison requires a third input.".to_string());
+    };
+    let file_name = config
+        .display_path
+        .as_deref()
+        .map(Path::new)
+        .or(Some(config.local.as_path()))
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.is_empty() && *name != ".git")
+        .unwrap_or("compare")
+        .to_string();
+
+    let dir = Builder::new()
+        .prefix("gitcomet-compare-")
+        .tempdir()
+        .map_err(|e| io_err!("create comparison repository", e))?;
+    run_scratch_git(dir.path(), &["init", "-q"], None)?;
+    run_scratch_git(
+        dir.path(),
+        &[
+            "-c",
+            "user.name=gitcomet",
+            "-c",
+            "user.email=gitcomet@localhost",
+            "-c",
+            "commit.gpgsign=false",
+            "commit",
+            "-q",
+            "--allow-empty",
+            "--no-verify",
+            "-m",
+            "difftool comparison",
+        ],
+        None,
+    )?;
+
+    let mut index_info = String::new();
+    let mut texts = Vec::with_capacity(3);
+    for (stage, input) in [(1, &config.remote), (2, &config.local), (3, third)] {
+        let bytes = fs::read(input).map_err(|e| io_err!("read", input, e))?;
+        let input = input
+            .to_str()
+            .ok_or_else(|| format!("Input path is not valid UTF-8: {}", input.display()))?;
+        let oid = run_scratch_git(
+            dir.path(),
+            &["hash-object", "-w", "--no-filters", "--", input],
+            None,
+        )?;
+        index_info.push_str(&format!("100644 {} {stage}\t{file_name}\n", oid.trim()));
+        texts.push(String::from_utf8_lossy(&bytes).into_owned());
+    }
+    run_scratch_git(
+        dir.path(),
+        &["update-index", "--index-info"],
+        Some(&index_info),
+    )?;
+
+    let [base, local, remote] = texts.as_slice() else {
+        unreachable!("three inputs were read");
+    };
+    let marked =
+        gitcomet_core::file_diff::three_way_comparison_marker_text(local, base, remote, labels);
+    let file_path = dir.path().join(&file_name);
+    fs::write(&file_path, marked).map_err(|e| io_err!("write", file_path, e))?;
+
+    Ok(ThreeWayCompareRepo { dir, file_name })
+}
+
+#[cfg(any(feature = "ui-gpui-runtime", test))]
+fn run_scratch_git(dir: &Path, args: &[&str], stdin: Option<&str>) -> Result<String, String> {
+    use std::io::Write as _;
+    use std::process::Stdio;
+
+    let mut cmd = git_command();
+    cmd.arg("-C").arg(dir).args(args);
+    // Git exports these to tools it launches; they would point the commands
+    // at the invoking repository instead of the scratch one.
+    for var in [
+        "GIT_DIR",
+        "GIT_WORK_TREE",
+        "GIT_INDEX_FILE",
+        "GIT_OBJECT_DIRECTORY",
+    ] {
+        cmd.env_remove(var);
+    }
+    cmd.stdin(if stdin.is_some() {
+        Stdio::piped()
+    } else {
+        Stdio::null()
+    })
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| io_err!("launch `git`", e))?;
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        pipe.write_all(input.as_bytes())
+            .map_err(|e| io_err!("write to `git`", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| io_err!("wait for `git`", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 fn bytes_to_text_preserving_utf8(bytes: &[u8]) -> String {
     const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
 
@@ -664,6 +845,25 @@ fn resolve_labels(config: &DifftoolConfig) -> Option<(String, String)> {
     Some((left, right))
 }
 
+/// Header labels for the remote/third diff appended in three-way mode.
+fn resolve_third_labels(config: &DifftoolConfig) -> Option<(String, String)> {
+    if config.label_right.is_none() && config.label_third.is_none() {
+        return None;
+    }
+    let left = config
+        .label_right
+        .clone()
+        .unwrap_or_else(|| config.remote.display().to_string());
+    let right = config.label_third.clone().unwrap_or_else(|| {
+        config
+            .third
+            .as_ref()
+            .map(|third| third.display().to_string())
+            .unwrap_or_default()
+    });
+    Some((left, right))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -690,6 +890,8 @@ mod tests {
             display_path: None,
             label_left: None,
             label_right: None,
+            third: None,
+            label_third: None,
             gui: false,
         }
     }
@@ -797,6 +999,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_difftool_three_way_appends_remote_to_third_diff() {
+        let tmp = tempfile::tempdir().unwrap();
+        let local = tmp.path().join("local.txt");
+        let remote = tmp.path().join("remote.txt");
+        let third = tmp.path().join("third.txt");
+        write_file(&local, "one\n");
+        write_file(&remote, "two\n");
+        write_file(&third, "three\n");
+
+        let mut cfg = config(local, remote);
+        cfg.third = Some(third);
+        cfg.label_right = Some("release".to_string());
+        cfg.label_third = Some("patch".to_string());
+        let result = run_difftool(&cfg).expect("difftool run");
+
+        assert_eq!(result.exit_code, exit_code::SUCCESS);
+        assert!(result.stdout.contains("+two"), "{}", result.stdout);
+        assert!(result.stdout.contains("+three"), "{}", result.stdout);
+        assert!(result.stdout.contains("--- release"), "{}", result.stdout);
+        assert!(result.stdout.contains("+++ patch"), "{}", result.stdout);
+    }
+
+    #[test]
+    fn run_difftool_three_way_reports_third_only_differences() {
+        let tmp = tempfile::tempdir().unwrap();
+        let local = tmp.path().join("local.txt");
+        let remote = tmp.path().join("remote.txt");
+        let third = tmp.path().join("third.txt");
+        write_file(&local, "same\n");
+        write_file(&remote, "same\n");
+        write_file(&third, "patched\n");
+
+        let mut cfg = config(local, remote);
+        cfg.third = Some(third);
+        let result = run_difftool(&cfg).expect("difftool run");
+
+        assert_eq!(result.exit_code, exit_code::SUCCESS);
+        assert!(result.stdout.contains("-same"), "{}", result.stdout);
+        assert!(result.stdout.contains("+patched"), "{}", result.stdout);
+    }
+
+    #[test]
+    fn prepare_three_way_compare_repo_stages_inputs_as_conflict() {
+        let tmp = tempfile::tempdir().unwrap();
+        let local = tmp.path().join("main.rs");
+        let remote = tmp.path().join("release.rs");
+        let third = tmp.path().join("patch.rs");
+        write_file(&local, "a\nmain\nz\n");
+        write_file(&remote, "a\nrelease\nz\n");
+        write_file(&third, "a\npatch\nz\n");
+
+        let mut cfg = config(local, remote);
+        cfg.third = Some(third);
+        let labels = gitcomet_core::conflict_output::ConflictMarkerLabels {
+            local: "main",
+            remote: "patch",
+            base: "release",
+        };
+        let compare = prepare_three_way_compare_repo(&cfg, labels).expect("stage comparison");
+
+        assert_eq!(compare.file_path(), compare.repo_path().join("main.rs"));
+        let stages = run_scratch_git(compare.repo_path(), &["ls-files", "--stage"], None)
+            .expect("list stages");
+        let stage_numbers: Vec<_> = stages
+            .lines()
+            .filter_map(|line| line.split_whitespace().nth(2))
+            .collect();
+        assert_eq!(stage_numbers, ["1", "2", "3"], "{stages}");
+        let worktree = std::fs::read_to_string(compare.file_path()).unwrap();
+        assert_eq!(
+            worktree,
+            "a\n<<<<<<< main\nmain\n||||||| release\nrelease\n=======\npatch\n>>>>>>> patch\nz\n"
+        );
+
+        let repo_path = compare.repo_path().to_path_buf();
+        drop(compare);
+        assert!(!repo_path.exists());
+    }
+
+    #[test]
+    fn is_directory_diff_requires_two_directories() {
+        let tmp = tempfile::tempdir().unwrap();
+        let left = tmp.path().join("left");
+        let right = tmp.path().join("right");
+        std::fs::create_dir_all(&left).unwrap();
+        std::fs::create_dir_all(&right).unwrap();
+        let file = tmp.path().join("file.txt");
+        write_file(&file, "x\n");
+
+        assert!(is_directory_diff(&config(left.clone(), right)));
+        assert!(!is_directory_diff(&config(left, file)));
+    }
+
     #[test]
     fn prepare_diff_inputs_directory_without_symlinks_skips_staging_copy() {
         let tmp = tempfile::tempdir().unwrap();
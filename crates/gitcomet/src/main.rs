@@ -180,6 +180,34 @@ fn main() {
                 std::process::exit(exit_code::ERROR);
             }
 
+            // `--dir-diff` inputs open the directory-compare window directly;
+            // it diffs individual files on demand.
+            #[cfg(feature = "ui-gpui-runtime")]
+            if config.gui && difftool_mode::is_directory_diff(&config) {
+                let gui_config = gitcomet_ui_gpui::FocusedDirDiffConfig {
+                    label_left: config
+                        .label_left
+                        .clone()
+                        .unwrap_or_else(|| path_label(&config.local)),
+                    label_right: config
+                        .label_right
+                        .clone()
+                        .unwrap_or_else(|| path_label(&config.remote)),
+                    left: config.local.clone(),
+                    right: config.remote.clone(),
+                };
+                let code = gitcomet_ui_gpui::run_focused_dir_diff(gui_config);
+                std::process::exit(code);
+            }
+
+            // Three inputs open in the focused conflict resolver in compare
+            // mode, staged as a conflict in a scratch repository.
+            #[cfg(feature = "ui-gpui-runtime")]
+            if config.gui && config.third.is_some() {
+                let code = run_focused_three_way_compare(&config);
+                std::process::exit(code);
+            }
+
             let result = difftool_mode::run_difftool(&config);
 
             // When UI is available and --gui was requested, open a focused
@@ -592,9 +620,51 @@ fn build_focused_mergetool_gui_config(
                 .map(|path| path_label(path))
                 .unwrap_or_else(|| "empty tree".to_string())
         }),
+        compare_only: false,
     })
 }
 
+#[cfg(feature = "ui-gpui-runtime")]
+fn run_focused_three_way_compare(config: &cli::DifftoolConfig) -> i32 {
+    let Some(third) = config.third.as_ref() else {
+        return exit_code::ERROR;
+    };
+    let label_local = config
+        .label_left
+        .clone()
+        .unwrap_or_else(|| path_label(&config.local));
+    let label_base = config
+        .label_right
+        .clone()
+        .unwrap_or_else(|| path_label(&config.remote));
+    let label_remote = config
+        .label_third
+        .clone()
+        .unwrap_or_else(|| path_label(third));
+    let labels = gitcomet_core::conflict_output::ConflictMarkerLabels {
+        local: &label_local,
+        remote: &label_remote,
+        base: &label_base,
+    };
+    let compare = match difftool_mode::prepare_three_way_compare_repo(config, labels) {
+        Ok(compare) => compare,
+        Err(msg) => {
+            eprintln!("{msg}");
+            return exit_code::ERROR;
+        }
+    };
+
+    let gui_config = gitcomet_ui_gpui::FocusedMergetoolConfig {
+        repo_path: compare.repo_path().to_path_buf(),
+        conflicted_file_path: compare.file_path(),
+        label_local,
+        label_remote,
+        label_base,
+        compare_only: true,
+    };
+    gitcomet_ui_gpui::run_focused_mergetool(build_backend(), gui_config)
+}
+
 /// Extract a filename label from a path.
 #[cfg(feature = "ui-gpui-runtime")]
 fn path_label(path: &std::path::Path) -> String {
@@ -720,6 +790,8 @@ mod tests {
             display_path: None,
             label_left: None,
             label_right: None,
+            third: None,
+            label_third: None,
             gui: true,
         };
         let result = difftool_mode::DifftoolRunResult {
@@ -739,6 +811,8 @@ mod tests {
             display_path: None,
             label_left: None,
             label_right: None,
+            third: None,
+            label_third: None,
             gui: false,
         };
         let result = difftool_mode::DifftoolRunResult {
@@ -758,6 +832,8 @@ mod tests {
             display_path: None,
             label_left: None,
             label_right: None,
+            third: None,
+            label_third: None,
             gui: true,
         };
         let result = difftool_mode::DifftoolRunResult {
@@ -781,6 +857,8 @@ mod tests {
                 display_path: None,
                 label_left: None,
                 label_right: None,
+                third: None,
+                label_third: None,
                 gui: false,
             })
         ));